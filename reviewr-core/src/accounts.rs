@@ -0,0 +1,196 @@
+//! Platform usernames of employees known only by email. Each platform instance looks the
+//! email up once through its user API; the answer is kept in `accounts.json`.
+
+use crate::models::DataPath;
+use log::{info, warn};
+use std::collections::BTreeMap;
+use std::fs;
+use std::future::Future;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Platforms resolve concurrently; this keeps their read-modify-write of the file apart
+static FILE_LOCK: Mutex<()> = Mutex::new(());
+
+/// A user returned by a platform's user search
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountCandidate {
+    pub username: String,
+    /// Addresses the platform reveals for the user; often none without admin rights
+    pub emails: Vec<String>,
+}
+
+/// The candidate with `email` (ignoring case). Otherwise a single result is taken, as the
+/// search was for that email and platforms match secondary or hidden addresses too.
+pub fn pick_account(email: &str, candidates: &[AccountCandidate]) -> Option<String> {
+    let exact = candidates.iter().find(|candidate| {
+        candidate
+            .emails
+            .iter()
+            .any(|address| address.eq_ignore_ascii_case(email))
+    });
+    match (exact, candidates) {
+        (Some(candidate), _) => Some(candidate.username.clone()),
+        (None, [only]) => Some(only.username.clone()),
+        _ => None,
+    }
+}
+
+/// platform id -> lowercase email -> username
+type AccountsFile = BTreeMap<String, BTreeMap<String, String>>;
+
+/// Resolved usernames per platform instance, stored in `accounts.json`
+#[derive(Debug, Clone)]
+pub struct AccountCache {
+    path: PathBuf,
+}
+
+impl AccountCache {
+    pub fn new(data_path: &DataPath) -> Self {
+        Self {
+            path: data_path.root.join("accounts.json"),
+        }
+    }
+
+    fn load(&self) -> AccountsFile {
+        let Ok(content) = fs::read_to_string(&self.path) else {
+            return AccountsFile::new();
+        };
+        serde_json::from_str(&content).unwrap_or_else(|e| {
+            warn!("Ignoring unreadable {}: {e}", self.path.display());
+            AccountsFile::new()
+        })
+    }
+
+    pub fn get(&self, platform_id: &str, email: &str) -> Option<String> {
+        self.load()
+            .get(platform_id)?
+            .get(&email.to_lowercase())
+            .cloned()
+    }
+
+    pub fn insert(&self, platform_id: &str, email: &str, username: &str) -> io::Result<()> {
+        let _guard = FILE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let mut accounts = self.load();
+        accounts
+            .entry(platform_id.to_string())
+            .or_default()
+            .insert(email.to_lowercase(), username.to_string());
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(&accounts).map_err(io::Error::other)?;
+        fs::write(&self.path, content)
+    }
+
+    /// The username to query `platform_id` with. Anything but an email is used as is. An email
+    /// is looked up in the cache, then with `lookup`; when neither finds the account, the
+    /// email itself is returned, leaving the platform's own fallback in place.
+    pub async fn resolve<F, Fut>(&self, platform_id: &str, user: &str, lookup: F) -> String
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = io::Result<Vec<AccountCandidate>>>,
+    {
+        if !user.contains('@') {
+            return user.to_string();
+        }
+        if let Some(username) = self.get(platform_id, user) {
+            return username;
+        }
+        match lookup()
+            .await
+            .map(|candidates| pick_account(user, &candidates))
+        {
+            Ok(Some(username)) => {
+                info!("Resolved {user} to {username} on {platform_id}");
+                if let Err(e) = self.insert(platform_id, user, &username) {
+                    warn!("Could not store the {platform_id} account of {user}: {e}");
+                }
+                username
+            }
+            Ok(None) => {
+                warn!("No unique {platform_id} account found for {user}; querying by email");
+                user.to_string()
+            }
+            Err(e) => {
+                warn!("Looking up the {platform_id} account of {user} failed: {e}");
+                user.to_string()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn candidate(username: &str, emails: &[&str]) -> AccountCandidate {
+        AccountCandidate {
+            username: username.to_string(),
+            emails: emails.iter().map(|email| email.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_pick_account() {
+        let email = "Jane.Doe@example.com";
+        assert_eq!(
+            pick_account(
+                email,
+                &[
+                    candidate("jdoe2", &["jane.doe@other.com"]),
+                    candidate("jdoe", &["jane.doe@example.com"]),
+                ]
+            ),
+            Some("jdoe".to_string())
+        );
+        assert_eq!(
+            pick_account(email, &[candidate("jdoe", &[])]),
+            Some("jdoe".to_string())
+        );
+        assert_eq!(
+            pick_account(email, &[candidate("a", &[]), candidate("b", &[])]),
+            None
+        );
+        assert_eq!(
+            pick_account(email, &[candidate("jd", &["jane@old.example.com"])]),
+            Some("jd".to_string())
+        );
+        assert_eq!(pick_account(email, &[]), None);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_caches_per_platform() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = AccountCache::new(&data_path);
+        let lookups = Cell::new(0);
+        let lookup = || async {
+            lookups.set(lookups.get() + 1);
+            Ok(vec![candidate("jdoe", &[])])
+        };
+
+        assert_eq!(cache.resolve("gitlab:work", "jdoe", lookup).await, "jdoe");
+        assert_eq!(lookups.get(), 0);
+        let email = "jane@example.com";
+        assert_eq!(cache.resolve("gitlab:work", email, lookup).await, "jdoe");
+        assert_eq!(
+            cache
+                .resolve("gitlab:work", "JANE@example.com", lookup)
+                .await,
+            "jdoe"
+        );
+        assert_eq!(lookups.get(), 1);
+        assert_eq!(
+            AccountCache::new(&data_path).get("gitlab:work", email),
+            Some("jdoe".to_string())
+        );
+
+        // Another instance has its own accounts; failed lookups are not remembered
+        let failing = || async { Err(io::Error::other("HTTP 403")) };
+        assert_eq!(cache.resolve("gitlab:oss", email, failing).await, email);
+        assert_eq!(cache.get("gitlab:oss", email), None);
+    }
+}
@@ -0,0 +1,151 @@
+//! Short free-text annotations on activity items, kept across sessions in `annotations.json`
+
+use crate::models::DataPath;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Longest annotation kept, in characters
+pub const MAX_ANNOTATION_CHARS: usize = 200;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Annotation {
+    pub text: String,
+    /// When the annotation was last changed (RFC 3339)
+    pub updated: String,
+}
+
+/// Annotations keyed by `<platform_id>/<item id>`, e.g. `gerrit/12345`
+#[derive(Debug, Clone, Default)]
+pub struct AnnotationStore {
+    path: PathBuf,
+    annotations: BTreeMap<String, Annotation>,
+}
+
+impl AnnotationStore {
+    /// Read the stored annotations; a missing or unreadable file yields none
+    pub fn load(data_path: &DataPath) -> Self {
+        let path = data_path.root.join("annotations.json");
+        let annotations = match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                warn!("Ignoring unreadable {}: {e}", path.display());
+                BTreeMap::new()
+            }),
+            Err(_) => BTreeMap::new(),
+        };
+        Self { path, annotations }
+    }
+
+    fn key(platform_id: &str, item_id: &str) -> String {
+        format!("{platform_id}/{item_id}")
+    }
+
+    pub fn get(&self, platform_id: &str, item_id: &str) -> Option<&Annotation> {
+        self.annotations.get(&Self::key(platform_id, item_id))
+    }
+
+    /// Store `text` for an item, trimmed and cut to [`MAX_ANNOTATION_CHARS`]; blank text removes
+    /// the annotation. The file is written immediately.
+    pub fn set(&mut self, platform_id: &str, item_id: &str, text: &str) -> io::Result<()> {
+        let key = Self::key(platform_id, item_id);
+        let text: String = text.trim().chars().take(MAX_ANNOTATION_CHARS).collect();
+        if text.is_empty() {
+            if self.annotations.remove(&key).is_none() {
+                return Ok(());
+            }
+        } else {
+            self.annotations.insert(
+                key,
+                Annotation {
+                    text,
+                    updated: chrono::Utc::now().to_rfc3339(),
+                },
+            );
+        }
+        self.save()
+    }
+
+    /// Annotations of the given `(platform_id, item id)` pairs, keyed like the stored file
+    pub fn subset<'a>(
+        &self,
+        items: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> BTreeMap<String, Annotation> {
+        items
+            .into_iter()
+            .filter_map(|(platform_id, item_id)| {
+                let key = Self::key(platform_id, item_id);
+                let annotation = self.annotations.get(&key)?.clone();
+                Some((key, annotation))
+            })
+            .collect()
+    }
+
+    /// Add annotations, e.g. from another manager's `subset`; items annotated here already keep
+    /// their text. Returns how many were added.
+    pub fn merge(&mut self, annotations: BTreeMap<String, Annotation>) -> io::Result<usize> {
+        let mut added = 0;
+        for (key, annotation) in annotations {
+            if let Entry::Vacant(entry) = self.annotations.entry(key) {
+                entry.insert(annotation);
+                added += 1;
+            }
+        }
+        if added > 0 {
+            self.save()?;
+        }
+        Ok(added)
+    }
+
+    fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(&self.annotations).map_err(io::Error::other)?;
+        fs::write(&self.path, content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_annotations_persist() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+
+        let mut store = AnnotationStore::load(&data_path);
+        assert!(store.get("gerrit", "12345").is_none());
+        store.set("gerrit", "12345", "  needed 4 rounds  ").unwrap();
+        store.set("jira", "12345", "great design doc").unwrap();
+
+        let mut store = AnnotationStore::load(&data_path);
+        assert_eq!(
+            store.get("gerrit", "12345").unwrap().text,
+            "needed 4 rounds"
+        );
+        assert_eq!(store.get("jira", "12345").unwrap().text, "great design doc");
+
+        store.set("gerrit", "12345", "   ").unwrap();
+        let store = AnnotationStore::load(&data_path);
+        assert!(store.get("gerrit", "12345").is_none());
+        assert!(store.get("jira", "12345").is_some());
+    }
+
+    #[test]
+    fn test_long_annotations_are_cut() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let mut store = AnnotationStore::load(&data_path);
+        store.set("gitlab:company", "7", &"x".repeat(500)).unwrap();
+        assert_eq!(
+            store.get("gitlab:company", "7").unwrap().text.len(),
+            MAX_ANNOTATION_CHARS
+        );
+    }
+}
@@ -0,0 +1,335 @@
+//! Export-safe output: people become stable pseudonyms and links to internal hosts are dropped,
+//! so review data can be handed to tools outside the engineering network
+
+use crate::models::{DataPath, Employee};
+use crate::unified_config::PlatformConfigs;
+use std::collections::hash_map::RandomState;
+use std::fs;
+use std::hash::BuildHasher;
+use std::io;
+use std::net::IpAddr;
+use url::Url;
+
+/// Per data directory secret mixed into every pseudonym, so they cannot be recomputed from a
+/// list of names elsewhere
+const SALT_FILE: &str = "redact.salt";
+
+/// Host suffixes that never resolve outside a company network
+const INTERNAL_SUFFIXES: [&str; 6] = [
+    ".internal",
+    ".local",
+    ".corp",
+    ".lan",
+    ".intranet",
+    ".localdomain",
+];
+
+/// Replaces a link to an internal host in free text
+pub const INTERNAL_LINK: &str = "[internal link]";
+
+/// Identities shorter than this are left alone in free text, where they would match parts of
+/// ordinary words
+const MIN_IDENTITY_CHARS: usize = 3;
+
+#[derive(Debug, Clone)]
+pub struct Anonymizer {
+    salt: String,
+    /// Platform hosts and the domains they live in; subdomains count too
+    internal_domains: Vec<String>,
+    /// Lowercase name, email or username with the pseudonym it is replaced by, longest first
+    identities: Vec<(String, String)>,
+}
+
+impl Anonymizer {
+    pub fn new(salt: &str, internal_domains: Vec<String>) -> Self {
+        Self {
+            salt: salt.to_string(),
+            internal_domains: internal_domains
+                .into_iter()
+                .map(|domain| domain.trim().trim_start_matches('.').to_lowercase())
+                .filter(|domain| !domain.is_empty())
+                .collect(),
+            identities: Vec::new(),
+        }
+    }
+
+    /// Anonymizer for a data directory: its salt (created on first use) and the hosts of every
+    /// configured platform as internal domains
+    pub fn load(data_path: &DataPath, platforms: &PlatformConfigs) -> io::Result<Self> {
+        let salt_path = data_path.root.join(SALT_FILE);
+        let salt = match fs::read_to_string(&salt_path) {
+            Ok(salt) if !salt.trim().is_empty() => salt.trim().to_string(),
+            Ok(_) => Self::create_salt(&salt_path)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::create_salt(&salt_path)?,
+            Err(e) => return Err(e),
+        };
+        let urls = platforms
+            .gerrit
+            .values()
+            .map(|config| config.gerrit_url.as_str())
+            .chain(
+                platforms
+                    .jira
+                    .values()
+                    .map(|config| config.jira_url.as_str()),
+            )
+            .chain(platforms.gitlab.values().map(|config| config.url.as_str()));
+        let domains = urls
+            .filter_map(|url| Url::parse(url).ok()?.host_str().map(str::to_string))
+            .flat_map(|host| {
+                // gerrit.corp.example.com also marks jira.corp.example.com as internal
+                let parent = host
+                    .split_once('.')
+                    .map(|(_, parent)| parent.to_string())
+                    .filter(|parent| parent.matches('.').count() >= 1);
+                std::iter::once(host).chain(parent)
+            })
+            .collect();
+        Ok(Self::new(&salt, domains))
+    }
+
+    fn create_salt(path: &std::path::Path) -> io::Result<String> {
+        let state = RandomState::new();
+        let salt = format!(
+            "{:016x}{:016x}",
+            state.hash_one(std::process::id()),
+            state.hash_one(chrono::Utc::now().timestamp_nanos_opt())
+        );
+        fs::write(path, &salt)?;
+        Ok(salt)
+    }
+
+    /// Stable pseudonym for a person, e.g. `Person-3f9a2c`; case and surrounding whitespace of
+    /// the name do not matter
+    pub fn pseudonym(&self, name: &str) -> String {
+        let hash = fnv1a(
+            self.salt
+                .bytes()
+                .chain([0])
+                .chain(name.trim().to_lowercase().bytes()),
+        );
+        format!("Person-{:06x}", hash & 0xff_ffff)
+    }
+
+    /// Replace `name` and every `alias` (emails, usernames) of that person in free text with the
+    /// pseudonym of `name`
+    pub fn add_person<'a>(&mut self, name: &'a str, aliases: impl IntoIterator<Item = &'a str>) {
+        let pseudonym = self.pseudonym(name);
+        for identity in std::iter::once(name).chain(aliases) {
+            let identity = identity.trim().to_lowercase();
+            if identity.chars().count() >= MIN_IDENTITY_CHARS
+                && !self.identities.iter().any(|(known, _)| *known == identity)
+            {
+                self.identities.push((identity, pseudonym.clone()));
+            }
+        }
+        self.identities
+            .sort_by_key(|(identity, _)| std::cmp::Reverse(identity.len()));
+    }
+
+    /// An employee with their committer email and platform usernames
+    pub fn add_employee(&mut self, employee: &Employee) {
+        let aliases: Vec<&str> = employee
+            .committer_email
+            .as_deref()
+            .into_iter()
+            .chain(employee.usernames.values().map(String::as_str))
+            .collect();
+        self.add_person(&employee.name, aliases);
+    }
+
+    /// Whether a URL points at a configured platform, a subdomain of one, or a host that is only
+    /// reachable inside a network (single-label names, `.internal`/`.corp`/... and private IPs)
+    pub fn is_internal_url(&self, url: &str) -> bool {
+        let Ok(url) = Url::parse(url) else {
+            return false;
+        };
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+        let host = host.trim_matches(['[', ']']).to_lowercase();
+        if let Ok(ip) = host.parse::<IpAddr>() {
+            return match ip {
+                IpAddr::V4(ip) => ip.is_private() || ip.is_loopback() || ip.is_link_local(),
+                IpAddr::V6(ip) => ip.is_loopback() || (ip.segments()[0] & 0xfe00) == 0xfc00,
+            };
+        }
+        !host.contains('.')
+            || INTERNAL_SUFFIXES
+                .iter()
+                .any(|suffix| host.ends_with(suffix))
+            || self.internal_domains.iter().any(|domain| {
+                host == *domain
+                    || host
+                        .strip_suffix(domain.as_str())
+                        .is_some_and(|rest| rest.ends_with('.'))
+            })
+    }
+
+    /// The URL itself when it is safe to share, otherwise an empty string
+    pub fn url(&self, url: &str) -> String {
+        if self.is_internal_url(url) {
+            String::new()
+        } else {
+            url.to_string()
+        }
+    }
+
+    /// Free text with known people replaced by their pseudonyms, other email addresses masked
+    /// and links to internal hosts replaced by [`INTERNAL_LINK`]
+    pub fn text(&self, text: &str) -> String {
+        let text = self.replace_links(text);
+        let text = self.replace_identities(&text);
+        crate::redact::redact_emails(&text)
+    }
+
+    fn replace_links(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = ["https://", "http://"]
+            .iter()
+            .filter_map(|scheme| rest.find(scheme))
+            .min()
+        {
+            let end = rest[start..]
+                .find(|c: char| c.is_whitespace() || "()<>[]\"'`".contains(c))
+                .map_or(rest.len(), |end| start + end);
+            out.push_str(&rest[..start]);
+            // Sentence punctuation after a link is not part of it
+            let link = rest[start..end].trim_end_matches(['.', ',', ';', ':', '!', '?']);
+            let end = start + link.len();
+            if self.is_internal_url(link) {
+                out.push_str(INTERNAL_LINK);
+            } else {
+                out.push_str(link);
+            }
+            rest = &rest[end..];
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// Whole-word, ASCII case-insensitive replacement of every known identity
+    fn replace_identities(&self, text: &str) -> String {
+        let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+        let mut out = String::with_capacity(text.len());
+        let mut index = 0;
+        'scan: while index < text.len() {
+            let rest = &text[index..];
+            if !is_word(out.chars().next_back()) {
+                for (identity, pseudonym) in &self.identities {
+                    let Some(candidate) = rest.get(..identity.len()) else {
+                        continue;
+                    };
+                    if candidate.eq_ignore_ascii_case(identity)
+                        && !is_word(rest[identity.len()..].chars().next())
+                    {
+                        out.push_str(pseudonym);
+                        index += identity.len();
+                        continue 'scan;
+                    }
+                }
+            }
+            let c = rest.chars().next().unwrap_or_default();
+            out.push(c);
+            index += c.len_utf8();
+        }
+        out
+    }
+}
+
+/// 64-bit FNV-1a, stable across Rust releases unlike the standard library's hashers
+fn fnv1a(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    bytes.into_iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anonymizer() -> Anonymizer {
+        let mut anonymizer = Anonymizer::new("salt", vec!["corp.example.com".to_string()]);
+        anonymizer.add_person("Jane Doe", ["jane@example.com", "jdoe"]);
+        anonymizer
+    }
+
+    #[test]
+    fn test_pseudonyms_are_stable_and_salted() {
+        let anonymizer = anonymizer();
+        let jane = anonymizer.pseudonym("Jane Doe");
+        assert!(jane.starts_with("Person-"));
+        assert_eq!(jane.len(), "Person-".len() + 6);
+        assert_eq!(anonymizer.pseudonym("  jane doe "), jane);
+        assert_ne!(anonymizer.pseudonym("John Doe"), jane);
+        assert_ne!(
+            Anonymizer::new("other", Vec::new()).pseudonym("Jane Doe"),
+            jane
+        );
+    }
+
+    #[test]
+    fn test_internal_urls() {
+        let anonymizer = anonymizer();
+        assert!(anonymizer.is_internal_url("https://gerrit.corp.example.com/c/1"));
+        assert!(anonymizer.is_internal_url("https://corp.example.com/"));
+        assert!(anonymizer.is_internal_url("http://wiki/page"));
+        assert!(anonymizer.is_internal_url("http://build.team.internal/job/3"));
+        assert!(anonymizer.is_internal_url("http://10.1.2.3:8080/"));
+        assert!(!anonymizer.is_internal_url("https://github.com/rust-lang/rust"));
+        assert!(!anonymizer.is_internal_url("https://notcorp.example.com/"));
+        assert_eq!(anonymizer.url("https://gerrit.corp.example.com/c/1"), "");
+        assert_eq!(
+            anonymizer.url("https://github.com/a/b"),
+            "https://github.com/a/b"
+        );
+    }
+
+    #[test]
+    fn test_text() {
+        let anonymizer = anonymizer();
+        let jane = anonymizer.pseudonym("Jane Doe");
+        let text = "jane doe and JDOE fixed https://gerrit.corp.example.com/c/1 \
+                    (see https://github.com/a/b), cc bob@example.org; jdoes is someone else";
+        assert_eq!(
+            anonymizer.text(text),
+            format!(
+                "{jane} and {jane} fixed [internal link] (see https://github.com/a/b), \
+                 cc [email]; jdoes is someone else"
+            )
+        );
+        assert_eq!(
+            anonymizer.text("Mail jane@example.com"),
+            format!("Mail {jane}")
+        );
+    }
+
+    #[test]
+    fn test_load_creates_salt_and_platform_domains() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let mut platforms = PlatformConfigs::default();
+        platforms.jira.insert(
+            "default".to_string(),
+            crate::unified_config::JiraConfig {
+                jira_url: "https://jira.corp.example.com".to_string(),
+                username: "bot".to_string(),
+                api_token: "token".to_string(),
+                project_filter: Vec::new(),
+                custom_fields: Default::default(),
+                fields: Vec::new(),
+                lazy_details: false,
+                accept_invalid_certs: false,
+                limits: Default::default(),
+            },
+        );
+
+        let first = Anonymizer::load(&data_path, &platforms).unwrap();
+        let second = Anonymizer::load(&data_path, &platforms).unwrap();
+        assert!(temp_dir.path().join(SALT_FILE).exists());
+        assert_eq!(first.pseudonym("Jane"), second.pseudonym("Jane"));
+        assert!(first.is_internal_url("https://gerrit.corp.example.com/c/1"));
+        assert!(!first.is_internal_url("https://example.com/"));
+    }
+}
@@ -0,0 +1,97 @@
+//! Crash-safe replacement of configuration and employee files, keeping the previous version as
+//! `<file>.bak`
+
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// `notes.md` + `.tmp` -> `notes.md.tmp`
+pub(crate) fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().map(OsString::from).unwrap_or_default();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// `config.toml` -> `config.toml.bak`
+pub fn backup_path(path: &Path) -> PathBuf {
+    with_suffix(path, ".bak")
+}
+
+/// `config.toml` -> `config.toml.<pid>.tmp`, so concurrent processes never rename each other's
+/// half-written file away
+fn temp_path(path: &Path) -> PathBuf {
+    with_suffix(path, &format!(".{}.tmp", std::process::id()))
+}
+
+/// Write `contents` next to `path`, flush it to disk and rename it over `path`, so a crash
+/// leaves either the old or the new version. The old version is copied to [`backup_path`]
+/// first.
+pub fn write_with_backup(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let temp_path = temp_path(path);
+    let result = (|| {
+        let mut file = fs::File::create(&temp_path)?;
+        file.write_all(contents.as_ref())?;
+        file.sync_all()?;
+        if path.exists() {
+            fs::copy(path, backup_path(path))?;
+        }
+        fs::rename(&temp_path, path)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
+/// Put the backup back in place of `path`. The file being replaced is kept as `<file>.broken`.
+pub fn restore_backup(path: &Path) -> io::Result<()> {
+    let backup = fs::read(backup_path(path))?;
+    if path.exists() {
+        fs::copy(path, with_suffix(path, ".broken"))?;
+    }
+    let temp_path = temp_path(path);
+    fs::write(&temp_path, backup)?;
+    fs::rename(&temp_path, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_write_keeps_previous_version() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("config.toml");
+
+        write_with_backup(&path, "version = 1\n").unwrap();
+        assert!(!backup_path(&path).exists());
+        write_with_backup(&path, "version = 2\n").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "version = 2\n");
+        assert_eq!(
+            fs::read_to_string(backup_path(&path)).unwrap(),
+            "version = 1\n"
+        );
+        assert!(!temp_path(&path).exists());
+
+        // A crash mid-write corrupted the file; the backup brings the last version back
+        fs::write(&path, "versi").unwrap();
+        restore_backup(&path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "version = 1\n");
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("config.toml.broken")).unwrap(),
+            "versi"
+        );
+    }
+
+    #[test]
+    fn test_restore_without_backup() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("Jane Doe.toml");
+        fs::write(&path, "broken").unwrap();
+        let error = restore_backup(&path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "broken");
+    }
+}
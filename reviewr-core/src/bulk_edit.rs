@@ -0,0 +1,333 @@
+//! The same field change applied to every employee matching a filter, e.g. new titles or a new
+//! team after a reorg
+
+use crate::employee::EmployeeService;
+use crate::models::{DataPath, Employee};
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+/// Employee fields a bulk edit can set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditableField {
+    Title,
+    CommitterEmail,
+    Team,
+    Manager,
+    StartDate,
+    Location,
+    /// Comma-separated list
+    Tags,
+}
+
+impl EditableField {
+    pub const ALL: [EditableField; 7] = [
+        EditableField::Title,
+        EditableField::CommitterEmail,
+        EditableField::Team,
+        EditableField::Manager,
+        EditableField::StartDate,
+        EditableField::Location,
+        EditableField::Tags,
+    ];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            EditableField::Title => "title",
+            EditableField::CommitterEmail => "committer_email",
+            EditableField::Team => "team",
+            EditableField::Manager => "manager",
+            EditableField::StartDate => "start_date",
+            EditableField::Location => "location",
+            EditableField::Tags => "tags",
+        }
+    }
+
+    /// Current value as shown in a dry run; empty when unset
+    pub(crate) fn get(&self, employee: &Employee) -> String {
+        let profile = &employee.profile;
+        match self {
+            EditableField::Title => employee.title.clone(),
+            EditableField::CommitterEmail => employee.committer_email.clone().unwrap_or_default(),
+            EditableField::Team => profile.team.clone().unwrap_or_default(),
+            EditableField::Manager => profile.manager.clone().unwrap_or_default(),
+            EditableField::StartDate => profile.start_date.clone().unwrap_or_default(),
+            EditableField::Location => profile.location.clone().unwrap_or_default(),
+            EditableField::Tags => profile.tags.join(", "),
+        }
+    }
+
+    /// Set the field; an empty value clears optional fields
+    pub(crate) fn set(&self, employee: &mut Employee, value: &str) {
+        let value = value.trim();
+        let optional = (!value.is_empty()).then(|| value.to_string());
+        let profile = &mut employee.profile;
+        match self {
+            EditableField::Title => employee.title = value.to_string(),
+            EditableField::CommitterEmail => employee.committer_email = optional,
+            EditableField::Team => profile.team = optional,
+            EditableField::Manager => profile.manager = optional,
+            EditableField::StartDate => profile.start_date = optional,
+            EditableField::Location => profile.location = optional,
+            EditableField::Tags => {
+                profile.tags = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|tag| !tag.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            }
+        }
+    }
+}
+
+impl fmt::Display for EditableField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// `field=value`, as given to `reviewr bulk-edit --set`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: EditableField,
+    pub value: String,
+}
+
+impl FromStr for FieldChange {
+    type Err = String;
+
+    fn from_str(change: &str) -> Result<Self, Self::Err> {
+        let (key, value) = change
+            .split_once('=')
+            .ok_or_else(|| format!("expected field=value, got '{change}'"))?;
+        let key = key.trim();
+        let field = EditableField::ALL
+            .into_iter()
+            .find(|field| field.key() == key)
+            .ok_or_else(|| {
+                let keys: Vec<&str> = EditableField::ALL.iter().map(|f| f.key()).collect();
+                format!(
+                    "unknown field '{key}' (expected one of {})",
+                    keys.join(", ")
+                )
+            })?;
+        Ok(Self {
+            field,
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+/// Which employees a bulk edit applies to; all given criteria must match, ignoring case
+#[derive(Debug, Clone, Default)]
+pub struct EmployeeFilter {
+    pub team: Option<String>,
+    pub manager: Option<String>,
+    pub tag: Option<String>,
+}
+
+impl EmployeeFilter {
+    pub fn is_empty(&self) -> bool {
+        self.team.is_none() && self.manager.is_none() && self.tag.is_none()
+    }
+
+    pub fn matches(&self, employee: &Employee) -> bool {
+        let same = |wanted: &Option<String>, actual: Option<&str>| match wanted {
+            Some(wanted) => {
+                actual.is_some_and(|actual| actual.trim().eq_ignore_ascii_case(wanted.trim()))
+            }
+            None => true,
+        };
+        let profile = &employee.profile;
+        same(&self.team, profile.team.as_deref())
+            && same(&self.manager, profile.manager.as_deref())
+            && (self.tag.is_none()
+                || profile
+                    .tags
+                    .iter()
+                    .any(|tag| same(&self.tag, Some(tag.as_str()))))
+    }
+}
+
+/// One field of one employee going from `old` to `new`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldUpdate {
+    pub field: EditableField,
+    pub old: String,
+    pub new: String,
+}
+
+/// An employee whose file a bulk edit rewrites
+#[derive(Debug, Clone)]
+pub struct PlannedEdit {
+    /// File name without `.toml`
+    pub file_name: String,
+    pub updated: Employee,
+    pub updates: Vec<FieldUpdate>,
+}
+
+pub struct BulkEditService;
+
+impl BulkEditService {
+    /// Changes for every matching employee, validated but not written. Employees that already
+    /// have the values are left out.
+    pub fn plan(
+        data_path: &DataPath,
+        filter: &EmployeeFilter,
+        changes: &[FieldChange],
+    ) -> io::Result<Vec<PlannedEdit>> {
+        let mut plan = Vec::new();
+        for file_name in EmployeeService::list_employees(data_path)? {
+            let employee = EmployeeService::get_employee(data_path, &file_name)?;
+            if !filter.matches(&employee) {
+                continue;
+            }
+            let mut updated = employee.clone();
+            for change in changes {
+                change.field.set(&mut updated, &change.value);
+            }
+            let updates: Vec<FieldUpdate> = changes
+                .iter()
+                .map(|change| change.field)
+                .filter_map(|field| {
+                    let (old, new) = (field.get(&employee), field.get(&updated));
+                    (old != new).then_some(FieldUpdate { field, old, new })
+                })
+                .collect();
+            if updates.is_empty() {
+                continue;
+            }
+            if updated.title.trim().is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "Title cannot be empty",
+                ));
+            }
+            updated.profile.validate()?;
+            plan.push(PlannedEdit {
+                file_name,
+                updated,
+                updates,
+            });
+        }
+        Ok(plan)
+    }
+
+    /// Write every planned edit, keeping a `.bak` of each previous file
+    pub fn apply(data_path: &DataPath, plan: &[PlannedEdit]) -> io::Result<()> {
+        for edit in plan {
+            let toml = toml::to_string(&edit.updated).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Failed to serialize employee data: {e}"),
+                )
+            })?;
+            EmployeeService::write_employee_toml(data_path, &edit.file_name, &toml)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn write(data_path: &DataPath, file_name: &str, content: &str) {
+        fs::write(
+            data_path.employees_dir.join(format!("{file_name}.toml")),
+            content,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_parse_change() {
+        assert_eq!(
+            "title = Senior Engineer".parse::<FieldChange>(),
+            Ok(FieldChange {
+                field: EditableField::Title,
+                value: "Senior Engineer".to_string(),
+            })
+        );
+        assert_eq!("team=".parse::<FieldChange>().unwrap().value, String::new());
+        assert!("name=Bob".parse::<FieldChange>().is_err());
+        assert!("title".parse::<FieldChange>().is_err());
+    }
+
+    #[test]
+    fn test_plan_and_apply() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        write(
+            &data_path,
+            "Alice",
+            "name = \"Alice\"\ntitle = \"Engineer\"\nteam = \"Core\"\ntags = [\"oncall\"]\n",
+        );
+        write(
+            &data_path,
+            "Bob",
+            "name = \"Bob\"\ntitle = \"Senior Engineer\"\nteam = \"core\"\n",
+        );
+        write(
+            &data_path,
+            "Carol",
+            "name = \"Carol\"\ntitle = \"Engineer\"\nteam = \"Web\"\n",
+        );
+
+        let filter = EmployeeFilter {
+            team: Some("Core".to_string()),
+            ..Default::default()
+        };
+        let changes = vec![
+            "title=Senior Engineer".parse().unwrap(),
+            "team=Platform".parse().unwrap(),
+            "tags=".parse().unwrap(),
+        ];
+        let plan = BulkEditService::plan(&data_path, &filter, &changes).unwrap();
+        let names: Vec<&str> = plan.iter().map(|edit| edit.file_name.as_str()).collect();
+        assert_eq!(names, vec!["Alice", "Bob"]);
+        assert_eq!(
+            plan[0].updates,
+            vec![
+                FieldUpdate {
+                    field: EditableField::Title,
+                    old: "Engineer".to_string(),
+                    new: "Senior Engineer".to_string(),
+                },
+                FieldUpdate {
+                    field: EditableField::Team,
+                    old: "Core".to_string(),
+                    new: "Platform".to_string(),
+                },
+                FieldUpdate {
+                    field: EditableField::Tags,
+                    old: "oncall".to_string(),
+                    new: String::new(),
+                },
+            ]
+        );
+        // Bob's title is already right
+        assert_eq!(plan[1].updates.len(), 1);
+
+        BulkEditService::apply(&data_path, &plan).unwrap();
+        let alice = EmployeeService::get_employee(&data_path, "Alice").unwrap();
+        assert_eq!(alice.title, "Senior Engineer");
+        assert_eq!(alice.profile.team.as_deref(), Some("Platform"));
+        assert!(alice.profile.tags.is_empty());
+        let carol = EmployeeService::get_employee(&data_path, "Carol").unwrap();
+        assert_eq!(carol.profile.team.as_deref(), Some("Web"));
+        assert!(
+            BulkEditService::plan(&data_path, &filter, &changes)
+                .unwrap()
+                .is_empty()
+        );
+
+        // Nothing is planned when one change is invalid
+        let invalid = vec!["start_date=next monday".parse().unwrap()];
+        assert!(BulkEditService::plan(&data_path, &EmployeeFilter::default(), &invalid).is_err());
+    }
+}
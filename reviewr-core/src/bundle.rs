@@ -0,0 +1,356 @@
+//! Configuration bundles: `config.toml` and note templates in a tar archive, for setting up
+//! reviewr on another machine. Employee records, notes and goals are not included.
+
+use crate::models::DataPath;
+use crate::secrets::{SecretBackendKind, SecretService};
+use crate::unified_config::UnifiedConfigService;
+use log::info;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+const BLOCK_SIZE: usize = 512;
+const CONFIG_ENTRY: &str = "config.toml";
+const TEMPLATES_PREFIX: &str = "templates/";
+
+/// What an import would write, or did write
+#[derive(Debug, Clone, PartialEq)]
+pub struct BundleEntry {
+    /// Path relative to the data directory, e.g. `templates/one-on-one.md`
+    pub name: String,
+    /// A file with this name already existed
+    pub replaced: bool,
+}
+
+pub struct BundleService;
+
+impl BundleService {
+    /// Write `config.toml` and `templates/*.md` to the tar archive at `output`. Platform
+    /// secrets are blanked unless `include_secrets` is set, in which case keyring secrets are
+    /// written out as plaintext. Returns the archived file names.
+    pub fn export(
+        data_path: &DataPath,
+        output: &Path,
+        include_secrets: bool,
+    ) -> io::Result<Vec<String>> {
+        let mut entries: Vec<(String, Vec<u8>)> = Vec::new();
+
+        if data_path.config_path().exists() {
+            let mut config = UnifiedConfigService::load_config(data_path)?;
+            if include_secrets {
+                config.platforms = UnifiedConfigService::load_platform_configs(data_path)?;
+                if config.integrations.ldap.is_some() {
+                    config.integrations.ldap =
+                        Some(UnifiedConfigService::load_ldap_config(data_path)?);
+                }
+                config.global_settings.secret_backend = SecretBackendKind::Config;
+            } else {
+                SecretService::strip_platform_secrets(&mut config.platforms);
+                if let Some(ldap) = &mut config.integrations.ldap {
+                    ldap.bind_password.clear();
+                }
+            }
+            let content = toml::to_string_pretty(&config).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Failed to serialize config: {e}"),
+                )
+            })?;
+            entries.push((CONFIG_ENTRY.to_string(), content.into_bytes()));
+        }
+
+        let templates_dir = data_path.templates_dir();
+        if templates_dir.exists() {
+            let mut templates: Vec<_> = fs::read_dir(&templates_dir)?
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "md"))
+                .collect();
+            templates.sort();
+            for path in templates {
+                let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+                    continue;
+                };
+                entries.push((format!("{TEMPLATES_PREFIX}{file_name}"), fs::read(&path)?));
+            }
+        }
+
+        if entries.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "Nothing to export: no config.toml or templates in {}",
+                    data_path.root.display()
+                ),
+            ));
+        }
+
+        let mut file = fs::File::create(output)?;
+        for (name, content) in &entries {
+            write_entry(&mut file, name, content)?;
+        }
+        file.write_all(&[0; BLOCK_SIZE * 2])?;
+        info!("Exported {} files to {}", entries.len(), output.display());
+        Ok(entries.into_iter().map(|(name, _)| name).collect())
+    }
+
+    /// Unpack a bundle into the data directory. Existing files are only replaced with
+    /// `overwrite`; otherwise nothing is written and an `AlreadyExists` error names them.
+    pub fn import(
+        data_path: &DataPath,
+        bundle: &Path,
+        overwrite: bool,
+    ) -> io::Result<Vec<BundleEntry>> {
+        let mut file = fs::File::open(bundle)?;
+        let entries = read_entries(&mut file)?;
+        if entries.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} contains no files", bundle.display()),
+            ));
+        }
+
+        for (name, content) in &entries {
+            validate_entry_name(name)?;
+            if name == CONFIG_ENTRY {
+                let content = std::str::from_utf8(content).map_err(|_| invalid_config())?;
+                toml::from_str::<crate::unified_config::UnifiedConfig>(content)
+                    .map_err(|_| invalid_config())?;
+            }
+        }
+
+        let existing: Vec<&str> = entries
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .filter(|name| data_path.root.join(name).exists())
+            .collect();
+        if !existing.is_empty() && !overwrite {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "These files already exist: {}. Use --force to replace them.",
+                    existing.join(", ")
+                ),
+            ));
+        }
+
+        let mut imported = Vec::new();
+        for (name, content) in entries {
+            let path = data_path.root.join(&name);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let replaced = path.exists();
+            fs::write(&path, content)?;
+            imported.push(BundleEntry { name, replaced });
+        }
+        info!(
+            "Imported {} files from {}",
+            imported.len(),
+            bundle.display()
+        );
+        Ok(imported)
+    }
+}
+
+fn invalid_config() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "The bundle's config.toml is not a valid reviewr configuration",
+    )
+}
+
+/// Only `config.toml` and flat `templates/<name>.md` files may be unpacked
+fn validate_entry_name(name: &str) -> io::Result<()> {
+    let valid = name == CONFIG_ENTRY
+        || name.strip_prefix(TEMPLATES_PREFIX).is_some_and(|file| {
+            file.strip_suffix(".md").is_some_and(|stem| {
+                !stem.is_empty()
+                    && stem
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+            })
+        });
+    if valid {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Unexpected file '{name}' in bundle"),
+        ))
+    }
+}
+
+/// Append one regular file as a ustar header followed by its zero-padded content
+fn write_entry(writer: &mut impl Write, name: &str, content: &[u8]) -> io::Result<()> {
+    if name.len() > 100 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("File name too long for a bundle: {name}"),
+        ));
+    }
+    let mut header = [0u8; BLOCK_SIZE];
+    header[..name.len()].copy_from_slice(name.as_bytes());
+    header[100..108].copy_from_slice(b"0000644\0");
+    header[108..116].copy_from_slice(b"0000000\0");
+    header[116..124].copy_from_slice(b"0000000\0");
+    header[124..136].copy_from_slice(format!("{:011o}\0", content.len()).as_bytes());
+    let mtime = chrono::Utc::now().timestamp().max(0);
+    header[136..148].copy_from_slice(format!("{mtime:011o}\0").as_bytes());
+    header[156] = b'0';
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    let sum = checksum(&header);
+    header[148..156].copy_from_slice(format!("{sum:06o}\0 ").as_bytes());
+
+    writer.write_all(&header)?;
+    writer.write_all(content)?;
+    let padding = (BLOCK_SIZE - content.len() % BLOCK_SIZE) % BLOCK_SIZE;
+    writer.write_all(&vec![0; padding])
+}
+
+/// Sum of the header bytes with the checksum field counted as spaces
+fn checksum(header: &[u8; BLOCK_SIZE]) -> u32 {
+    header
+        .iter()
+        .enumerate()
+        .map(|(i, byte)| {
+            if (148..156).contains(&i) {
+                b' ' as u32
+            } else {
+                *byte as u32
+            }
+        })
+        .sum()
+}
+
+fn parse_octal(field: &[u8]) -> Option<u64> {
+    let text = std::str::from_utf8(field).ok()?;
+    let text = text.trim_matches(|c: char| c == '\0' || c == ' ');
+    if text.is_empty() {
+        return Some(0);
+    }
+    u64::from_str_radix(text, 8).ok()
+}
+
+/// Regular files of a tar archive; directories and other entry types are skipped
+fn read_entries(reader: &mut impl Read) -> io::Result<Vec<(String, Vec<u8>)>> {
+    let not_a_bundle = || io::Error::new(io::ErrorKind::InvalidData, "Not a reviewr bundle");
+    let mut entries = Vec::new();
+    loop {
+        let mut header = [0u8; BLOCK_SIZE];
+        if reader.read_exact(&mut header).is_err() {
+            break;
+        }
+        if header.iter().all(|byte| *byte == 0) {
+            break;
+        }
+        if parse_octal(&header[148..156]) != Some(checksum(&header) as u64) {
+            return Err(not_a_bundle());
+        }
+
+        let name_end = header[..100].iter().position(|b| *b == 0).unwrap_or(100);
+        let name = std::str::from_utf8(&header[..name_end])
+            .map_err(|_| not_a_bundle())?
+            .to_string();
+        let size = parse_octal(&header[124..136]).ok_or_else(not_a_bundle)? as usize;
+        let padded = size.div_ceil(BLOCK_SIZE) * BLOCK_SIZE;
+        let mut content = vec![0; padded];
+        reader.read_exact(&mut content)?;
+        content.truncate(size);
+
+        if matches!(header[156], b'0' | 0) {
+            entries.push((name, content));
+        }
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gerrit::GerritConfig;
+    use crate::unified_config::{DEFAULT_INSTANCE, RequestLimits, UnifiedConfig};
+    use tempfile::tempdir;
+
+    fn data_path(dir: &Path) -> DataPath {
+        DataPath::new(Some(dir.to_path_buf())).unwrap()
+    }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let source_dir = tempdir().unwrap();
+        let source = data_path(source_dir.path());
+        let mut config = UnifiedConfig::default();
+        config.global_settings.allowed_domains = vec!["example.com".to_string()];
+        config.platforms.gerrit.insert(
+            DEFAULT_INSTANCE.to_string(),
+            GerritConfig {
+                gerrit_url: "https://gerrit.example.com".to_string(),
+                username: "jane".to_string(),
+                http_password: "hunter2".to_string(),
+                accept_invalid_certs: false,
+                limits: RequestLimits::default(),
+            },
+        );
+        UnifiedConfigService::save_config(&config, &source).unwrap();
+        fs::create_dir_all(source.templates_dir()).unwrap();
+        fs::write(
+            source.templates_dir().join("skip-level.md"),
+            "## {{date}}\n",
+        )
+        .unwrap();
+
+        let bundle = source_dir.path().join("bundle.tar");
+        let exported = BundleService::export(&source, &bundle, false).unwrap();
+        assert_eq!(exported, vec!["config.toml", "templates/skip-level.md"]);
+
+        let target_dir = tempdir().unwrap();
+        let target = data_path(target_dir.path());
+        let imported = BundleService::import(&target, &bundle, false).unwrap();
+        assert_eq!(imported.len(), 2);
+        assert!(imported.iter().all(|entry| !entry.replaced));
+
+        let config = UnifiedConfigService::load_config(&target).unwrap();
+        assert_eq!(config.global_settings.allowed_domains, vec!["example.com"]);
+        let gerrit = &config.platforms.gerrit[DEFAULT_INSTANCE];
+        assert_eq!(gerrit.username, "jane");
+        assert!(gerrit.http_password.is_empty());
+        assert_eq!(
+            fs::read_to_string(target.templates_dir().join("skip-level.md")).unwrap(),
+            "## {{date}}\n"
+        );
+
+        // A second import needs permission to replace the files
+        let err = BundleService::import(&target, &bundle, false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        let imported = BundleService::import(&target, &bundle, true).unwrap();
+        assert!(imported.iter().all(|entry| entry.replaced));
+
+        BundleService::export(&source, &bundle, true).unwrap();
+        BundleService::import(&target, &bundle, true).unwrap();
+        let config = UnifiedConfigService::load_config(&target).unwrap();
+        assert_eq!(
+            config.platforms.gerrit[DEFAULT_INSTANCE].http_password,
+            "hunter2"
+        );
+    }
+
+    #[test]
+    fn test_import_rejects_unexpected_files() {
+        let dir = tempdir().unwrap();
+        let bundle = dir.path().join("bundle.tar");
+        let mut file = fs::File::create(&bundle).unwrap();
+        write_entry(&mut file, "employees/jane.toml", b"name = \"x\"").unwrap();
+        drop(file);
+
+        let target = data_path(dir.path());
+        let err = BundleService::import(&target, &bundle, false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(!dir.path().join("employees/jane.toml").exists());
+
+        fs::write(&bundle, "not a tar file").unwrap();
+        assert!(BundleService::import(&target, &bundle, false).is_err());
+        assert!(validate_entry_name("templates/../config.toml").is_err());
+    }
+}
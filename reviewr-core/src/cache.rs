@@ -0,0 +1,451 @@
+//! Cached `DetailedActivities` per employee and platform, so reopening a review skips the fetch
+
+use crate::models::DataPath;
+use crate::platform::{ActivityCategory, ActivityItem, DetailedActivities};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedActivities {
+    employee: String,
+    platform_id: String,
+    days: u32,
+    /// Seconds since the Unix epoch when the activities were fetched
+    fetched_at: u64,
+    // A list of pairs because `ActivityCategory::Other` cannot be a JSON object key
+    items_by_category: Vec<(ActivityCategory, Vec<ActivityItem>)>,
+}
+
+/// One stored entry of an employee together with its file content, e.g. to hand it off
+#[derive(Debug, Clone)]
+pub struct CachedSnapshot {
+    pub platform_id: String,
+    pub days: u32,
+    pub activities: DetailedActivities,
+    pub json: String,
+}
+
+/// Activity cache under `cache/activity/`, one JSON file per employee, platform and period
+#[derive(Debug, Clone)]
+pub struct ActivityCache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+impl ActivityCache {
+    pub fn new(data_path: &DataPath, ttl: Duration) -> Self {
+        Self {
+            dir: data_path.root.join("cache").join("activity"),
+            ttl,
+        }
+    }
+
+    fn entry_path(&self, employee: &str, platform_id: &str, days: u32) -> PathBuf {
+        // The digest only names the file; the stored key is compared on load. It must not
+        // change between Rust releases, or an upgrade would leave the old entry behind.
+        let digest = Sha256::digest(format!("{employee}\0{platform_id}\0{days}"));
+        self.dir.join(format!("{digest:x}.json"))
+    }
+
+    /// Cached activities that are younger than the TTL, with their age
+    pub fn load(
+        &self,
+        employee: &str,
+        platform_id: &str,
+        days: u32,
+    ) -> Option<(DetailedActivities, Duration)> {
+        if self.ttl.is_zero() {
+            return None;
+        }
+        self.load_any_age(employee, platform_id, days)
+            .filter(|(_, age)| *age < self.ttl)
+    }
+
+    /// The last stored activities however old, e.g. to compare a fresh fetch against
+    pub fn load_any_age(
+        &self,
+        employee: &str,
+        platform_id: &str,
+        days: u32,
+    ) -> Option<(DetailedActivities, Duration)> {
+        let content = fs::read_to_string(self.entry_path(employee, platform_id, days)).ok()?;
+        let entry: CachedActivities = serde_json::from_str(&content).ok()?;
+        if entry.employee != employee || entry.platform_id != platform_id || entry.days != days {
+            return None;
+        }
+        let age = Duration::from_secs(now_secs().saturating_sub(entry.fetched_at));
+        let activities = DetailedActivities {
+            items_by_category: entry.items_by_category.into_iter().collect(),
+            failed_categories: HashMap::new(),
+        };
+        Some((activities, age))
+    }
+
+    pub fn store(
+        &self,
+        employee: &str,
+        platform_id: &str,
+        days: u32,
+        activities: &DetailedActivities,
+    ) -> io::Result<()> {
+        if self.ttl.is_zero() {
+            return Ok(());
+        }
+        // A failed category would be served as an empty one until the entry expires
+        if activities.is_partial() {
+            info!("Not caching incomplete {platform_id} activity for {employee} ({days} days)");
+            return Ok(());
+        }
+        fs::create_dir_all(&self.dir)?;
+        let entry = CachedActivities {
+            employee: employee.to_string(),
+            platform_id: platform_id.to_string(),
+            days,
+            fetched_at: now_secs(),
+            items_by_category: activities
+                .items_by_category
+                .iter()
+                .map(|(category, items)| (category.clone(), items.clone()))
+                .collect(),
+        };
+        let json = serde_json::to_string(&entry).map_err(io::Error::other)?;
+        fs::write(self.entry_path(employee, platform_id, days), json)?;
+        info!("Cached {platform_id} activity for {employee} ({days} days)");
+        Ok(())
+    }
+
+    /// Every cached result for a review period regardless of age, as
+    /// `(employee, platform_id, activities)`
+    pub fn entries_for_period(
+        &self,
+        days: u32,
+    ) -> io::Result<Vec<(String, String, DetailedActivities)>> {
+        self.entries_where(|cached| cached.days == days)
+    }
+
+    /// Every cached result of any period and age, as `(employee, platform_id, activities)`
+    pub fn all_entries(&self) -> io::Result<Vec<(String, String, DetailedActivities)>> {
+        self.entries_where(|_| true)
+    }
+
+    /// The entry files, e.g. to tell whether anything was fetched since an index was built
+    pub fn entry_files(&self) -> io::Result<Vec<PathBuf>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut files: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+
+    fn entries_where(
+        &self,
+        keep: impl Fn(&CachedActivities) -> bool,
+    ) -> io::Result<Vec<(String, String, DetailedActivities)>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let cached = entries
+            .flatten()
+            .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+            .filter_map(|content| serde_json::from_str::<CachedActivities>(&content).ok())
+            .filter(|cached| keep(cached));
+        let mut results: Vec<_> = newest_per_key(cached.map(|cached| (cached, ())))
+            .into_iter()
+            .map(|(cached, ())| {
+                let activities = DetailedActivities {
+                    items_by_category: cached.items_by_category.into_iter().collect(),
+                    failed_categories: HashMap::new(),
+                };
+                (cached.employee, cached.platform_id, activities)
+            })
+            .collect();
+        results.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+        Ok(results)
+    }
+
+    /// Drop every cached entry of an employee, e.g. for `review --refresh`
+    pub fn invalidate_employee(&self, employee: &str) -> io::Result<()> {
+        for (path, _, _) in self.employee_entries(employee)? {
+            if let Err(e) = fs::remove_file(&path) {
+                warn!("Failed to remove cache entry {}: {e}", path.display());
+            }
+        }
+        Ok(())
+    }
+
+    /// Every stored entry of an employee regardless of age, by platform and period
+    pub fn employee_snapshots(&self, employee: &str) -> io::Result<Vec<CachedSnapshot>> {
+        let entries = self
+            .employee_entries(employee)?
+            .into_iter()
+            .map(|(_, cached, json)| (cached, json));
+        let mut snapshots: Vec<CachedSnapshot> = newest_per_key(entries)
+            .into_iter()
+            .map(|(cached, json)| CachedSnapshot {
+                platform_id: cached.platform_id,
+                days: cached.days,
+                activities: DetailedActivities {
+                    items_by_category: cached.items_by_category.into_iter().collect(),
+                    failed_categories: HashMap::new(),
+                },
+                json,
+            })
+            .collect();
+        snapshots.sort_by(|a, b| (&a.platform_id, a.days).cmp(&(&b.platform_id, b.days)));
+        Ok(snapshots)
+    }
+
+    /// Store an entry read by `employee_snapshots`, keeping its fetch time. Returns the employee
+    /// it belongs to.
+    pub fn import_snapshot(&self, json: &str) -> io::Result<String> {
+        let cached: CachedActivities = serde_json::from_str(json).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid activity snapshot: {e}"),
+            )
+        })?;
+        fs::create_dir_all(&self.dir)?;
+        fs::write(
+            self.entry_path(&cached.employee, &cached.platform_id, cached.days),
+            json,
+        )?;
+        Ok(cached.employee)
+    }
+
+    /// Store every entry of `old_name` under `new_name`, keeping fetch times. Returns how many
+    /// entries moved.
+    pub fn rename_employee(&self, old_name: &str, new_name: &str) -> io::Result<usize> {
+        let entries = self.employee_entries(old_name)?;
+        let (cached, paths): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .map(|(path, cached, _)| (cached, path))
+            .unzip();
+        let newest = newest_per_key(cached.into_iter().map(|cached| (cached, ())));
+        for (mut cached, ()) in newest.iter().cloned() {
+            cached.employee = new_name.to_string();
+            let json = serde_json::to_string(&cached)?;
+            fs::write(
+                self.entry_path(new_name, &cached.platform_id, cached.days),
+                json,
+            )?;
+        }
+        for path in paths {
+            fs::remove_file(path)?;
+        }
+        Ok(newest.len())
+    }
+
+    /// Paths, parsed entries and content of the entry files belonging to an employee
+    fn employee_entries(
+        &self,
+        employee: &str,
+    ) -> io::Result<Vec<(PathBuf, CachedActivities, String)>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        Ok(entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter_map(|path| {
+                let content = fs::read_to_string(&path).ok()?;
+                let cached: CachedActivities = serde_json::from_str(&content).ok()?;
+                (cached.employee == employee).then_some((path, cached, content))
+            })
+            .collect())
+    }
+}
+
+/// The most recently fetched entry per employee, platform and period. Files written under an
+/// earlier naming scheme can hold the same key as the current one and must not count twice.
+fn newest_per_key<T>(
+    entries: impl IntoIterator<Item = (CachedActivities, T)>,
+) -> Vec<(CachedActivities, T)> {
+    let mut newest: HashMap<(String, String, u32), (CachedActivities, T)> = HashMap::new();
+    for (cached, extra) in entries {
+        let key = (
+            cached.employee.clone(),
+            cached.platform_id.clone(),
+            cached.days,
+        );
+        match newest.get(&key) {
+            Some((kept, _)) if kept.fetched_at >= cached.fetched_at => {}
+            _ => {
+                newest.insert(key, (cached, extra));
+            }
+        }
+    }
+    newest.into_values().collect()
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::ActivityCategory;
+    use crate::test_support::ItemBuilder;
+    use tempfile::tempdir;
+
+    fn activities() -> DetailedActivities {
+        let mut activities = DetailedActivities::default();
+        activities.items_by_category.insert(
+            ActivityCategory::IssuesCreated,
+            vec![
+                ItemBuilder::new("PROJ-1")
+                    .with_title("Add login page")
+                    .with_status("Open")
+                    .with_created("2024-01-10T00:00:00Z")
+                    .with_updated("2024-01-11T00:00:00Z")
+                    .with_url("https://jira.example.com/browse/PROJ-1")
+                    .with_platform("jira")
+                    .with_category(ActivityCategory::IssuesCreated)
+                    .with_project("PROJ")
+                    .build(),
+            ],
+        );
+        activities.items_by_category.insert(
+            ActivityCategory::Other("Wiki Edits".to_string()),
+            Vec::new(),
+        );
+        activities
+    }
+
+    #[test]
+    fn test_store_and_load_round_trip() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = ActivityCache::new(&data_path, Duration::from_secs(3600));
+
+        cache.store("Jane Doe", "jira", 30, &activities()).unwrap();
+        let (loaded, _) = cache.load("Jane Doe", "jira", 30).unwrap();
+        assert_eq!(
+            loaded.items_by_category[&ActivityCategory::IssuesCreated].len(),
+            1
+        );
+        assert!(
+            loaded
+                .items_by_category
+                .contains_key(&ActivityCategory::Other("Wiki Edits".to_string()))
+        );
+
+        // Different period or platform is a separate entry
+        assert!(cache.load("Jane Doe", "jira", 7).is_none());
+        assert!(cache.load("Jane Doe", "gerrit", 30).is_none());
+
+        cache.invalidate_employee("Jane Doe").unwrap();
+        assert!(cache.load("Jane Doe", "jira", 30).is_none());
+    }
+
+    #[test]
+    fn test_partial_activities_are_not_cached() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = ActivityCache::new(&data_path, Duration::from_secs(3600));
+
+        let mut partial = activities();
+        partial.insert_result(
+            ActivityCategory::IssuesAssigned,
+            Err(io::Error::other("HTTP 403")),
+        );
+        cache.store("Jane Doe", "jira", 30, &partial).unwrap();
+        assert!(cache.load("Jane Doe", "jira", 30).is_none());
+    }
+
+    #[test]
+    fn test_zero_ttl_disables_cache() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = ActivityCache::new(&data_path, Duration::ZERO);
+
+        cache.store("Jane Doe", "jira", 30, &activities()).unwrap();
+        assert!(cache.load("Jane Doe", "jira", 30).is_none());
+    }
+
+    #[test]
+    fn test_entries_for_period_ignores_ttl() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = ActivityCache::new(&data_path, Duration::from_secs(60));
+        cache.store("Jane Doe", "jira", 90, &activities()).unwrap();
+        cache.store("John Roe", "jira", 30, &activities()).unwrap();
+
+        let expired = ActivityCache::new(&data_path, Duration::ZERO);
+        let entries = expired.entries_for_period(90).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].0, "Jane Doe");
+        assert_eq!(entries[0].2.items_by_category.len(), 2);
+    }
+
+    #[test]
+    fn test_entry_names_are_stable() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = ActivityCache::new(&data_path, Duration::from_secs(60));
+
+        let path = cache.entry_path("Jane Doe", "jira", 30);
+        assert_eq!(
+            path.file_name().unwrap().to_str().unwrap(),
+            "dd73d340310f659e174e6cbc6da9af82b1e80194a1c107f180aeeb059aff2b64.json"
+        );
+    }
+
+    #[test]
+    fn test_entries_of_an_older_file_name_count_once() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = ActivityCache::new(&data_path, Duration::from_secs(60));
+        cache.store("Jane Doe", "jira", 30, &activities()).unwrap();
+
+        // The same key stored earlier under another name, with one item less
+        let older = CachedActivities {
+            employee: "Jane Doe".to_string(),
+            platform_id: "jira".to_string(),
+            days: 30,
+            fetched_at: now_secs() - 3600,
+            items_by_category: vec![(ActivityCategory::IssuesCreated, Vec::new())],
+        };
+        fs::write(
+            cache.dir.join("0123456789abcdef.json"),
+            serde_json::to_string(&older).unwrap(),
+        )
+        .unwrap();
+
+        let entries = cache.entries_for_period(30).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].2.items_by_category[&ActivityCategory::IssuesCreated].len(),
+            1
+        );
+        assert_eq!(cache.employee_snapshots("Jane Doe").unwrap().len(), 1);
+
+        assert_eq!(cache.rename_employee("Jane Doe", "Jane Roe").unwrap(), 1);
+        assert_eq!(cache.entry_files().unwrap().len(), 1);
+        let (renamed, _) = cache.load("Jane Roe", "jira", 30).unwrap();
+        assert_eq!(
+            renamed.items_by_category[&ActivityCategory::IssuesCreated].len(),
+            1
+        );
+    }
+}
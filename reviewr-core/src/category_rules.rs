@@ -0,0 +1,233 @@
+//! `[[category_rules]]`: items carrying a given label or hashtag, or belonging to a given
+//! project, are moved into a category of their own, e.g. to tell bug fixes from feature work.
+//!
+//! ```toml
+//! [[category_rules]]
+//! category = "Bug Fixes"
+//! from = "Changes Merged"
+//! label = "^(bug|bugfix)$"
+//! ```
+//!
+//! Rules are tried in order and the first match wins. Platforms apply them while converting
+//! their results to activity items.
+
+use crate::models::DataPath;
+use crate::platform::{ActivityCategory, ActivityItem};
+use crate::unified_config::UnifiedConfigService;
+use log::warn;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::io;
+
+/// Item metadata holding comma separated labels: Gerrit hashtags, GitLab labels and JIRA
+/// components
+pub const LABEL_KEYS: [&str; 3] = ["hashtags", "labels", "components"];
+
+/// One `[[category_rules]]` entry; an item matches when it matches every condition that is set
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryRule {
+    /// Category matching items are moved to
+    pub category: String,
+    /// Platform id (`gitlab:work`) or platform type (`gitlab`); every platform when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+    /// Display name of the only category the rule moves items out of, e.g. `Changes Merged`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// Regex one of the item's hashtags, labels or components must match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Regex the item's project must match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+}
+
+impl CategoryRule {
+    /// The rule ready to match items; InvalidInput for an empty category or an invalid regex
+    fn compile(&self) -> io::Result<CompiledRule> {
+        let category = self.category.trim();
+        if category.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "category must not be empty",
+            ));
+        }
+        Ok(CompiledRule {
+            category: ActivityCategory::Other(category.to_string()),
+            platform: self.platform.clone(),
+            from: self.from.clone(),
+            label: compile_pattern("label", self.label.as_deref())?,
+            project: compile_pattern("project", self.project.as_deref())?,
+        })
+    }
+
+    /// Why the rule cannot be used, for `reviewr config validate`
+    pub fn check(&self) -> io::Result<()> {
+        self.compile().map(|_| ())
+    }
+}
+
+/// A `label` or `project` pattern as rules compile it
+pub fn compile_pattern(key: &str, pattern: Option<&str>) -> io::Result<Option<Regex>> {
+    pattern
+        .map(|pattern| {
+            Regex::new(pattern).map_err(|e| {
+                // Syntax errors come with the pattern and a caret on lines of their own
+                let message = e.to_string();
+                let reason = message.lines().last().unwrap_or_default();
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "Invalid {key} pattern '{pattern}': {}",
+                        reason.trim_start_matches("error: ")
+                    ),
+                )
+            })
+        })
+        .transpose()
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRule {
+    category: ActivityCategory,
+    platform: Option<String>,
+    from: Option<String>,
+    label: Option<Regex>,
+    project: Option<Regex>,
+}
+
+impl CompiledRule {
+    fn matches(&self, platform_id: &str, item: &ActivityItem) -> bool {
+        let platform = self.platform.as_deref().is_none_or(|platform| {
+            platform_id == platform
+                || platform_id
+                    .strip_prefix(platform)
+                    .is_some_and(|instance| instance.starts_with(':'))
+        });
+        let from = self.from.as_deref().is_none_or(|from| {
+            item.category
+                .display_name()
+                .eq_ignore_ascii_case(from.trim())
+        });
+        let label = self.label.as_ref().is_none_or(|label| {
+            LABEL_KEYS
+                .iter()
+                .filter_map(|key| item.metadata.get(*key))
+                .flat_map(|labels| labels.split(','))
+                .any(|value| label.is_match(value.trim()))
+        });
+        let project = self
+            .project
+            .as_ref()
+            .is_none_or(|project| project.is_match(&item.project));
+        platform && from && label && project
+    }
+}
+
+/// The configured rules, compiled
+#[derive(Debug, Clone, Default)]
+pub struct CategoryRules {
+    rules: Vec<CompiledRule>,
+}
+
+impl CategoryRules {
+    /// Rules that do not compile are skipped with a warning; `config validate` reports them
+    pub fn new(rules: &[CategoryRule]) -> Self {
+        let rules = rules
+            .iter()
+            .filter_map(|rule| {
+                rule.compile()
+                    .inspect_err(|e| warn!("Ignoring category rule '{}': {e}", rule.category))
+                    .ok()
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// The rules of `config.toml`, none when it is unreadable
+    pub fn load(data_path: &DataPath) -> Self {
+        Self::new(&UnifiedConfigService::load_category_rules(data_path))
+    }
+
+    /// Move `item` of the platform `platform_id` into the category of the first matching rule
+    pub fn apply(&self, platform_id: &str, item: &mut ActivityItem) {
+        if let Some(rule) = self
+            .rules
+            .iter()
+            .find(|rule| rule.matches(platform_id, item))
+        {
+            item.category = rule.category.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::ItemBuilder;
+
+    fn item(category: ActivityCategory, project: &str, hashtags: &str) -> ActivityItem {
+        ItemBuilder::new("1")
+            .with_title("Fix crash")
+            .with_category(category)
+            .with_project(project)
+            .with_metadata("hashtags", hashtags)
+            .build()
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let rules = CategoryRules::new(&[
+            CategoryRule {
+                category: "Bug Fixes".to_string(),
+                from: Some("changes merged".to_string()),
+                label: Some("^bug(fix)?$".to_string()),
+                ..CategoryRule::default()
+            },
+            CategoryRule {
+                category: "Infrastructure".to_string(),
+                platform: Some("gerrit".to_string()),
+                project: Some("^infra/".to_string()),
+                ..CategoryRule::default()
+            },
+        ]);
+
+        let mut merged = item(
+            ActivityCategory::ChangesMerged,
+            "infra/ci",
+            "release, bugfix",
+        );
+        rules.apply("gerrit:corp", &mut merged);
+        assert_eq!(merged.category.display_name(), "Bug Fixes");
+
+        // Only merged changes are bug fixes; the project rule takes the created one
+        let mut created = item(ActivityCategory::ChangesCreated, "infra/ci", "bugfix");
+        rules.apply("gerrit", &mut created);
+        assert_eq!(created.category.display_name(), "Infrastructure");
+
+        // `gerrit` covers gerrit:<instance> but not other platforms
+        let mut other = item(ActivityCategory::ChangesCreated, "infra/ci", "");
+        rules.apply("gerritlab", &mut other);
+        assert_eq!(other.category, ActivityCategory::ChangesCreated);
+        let mut feature = item(ActivityCategory::ChangesMerged, "app", "debug");
+        rules.apply("gerrit", &mut feature);
+        assert_eq!(feature.category, ActivityCategory::ChangesMerged);
+    }
+
+    #[test]
+    fn test_invalid_rules_are_skipped() {
+        let invalid = CategoryRule {
+            category: "Bug Fixes".to_string(),
+            label: Some("bug(".to_string()),
+            ..CategoryRule::default()
+        };
+        let error = invalid.check().unwrap_err();
+        assert!(error.to_string().contains("Invalid label pattern 'bug('"));
+        assert!(CategoryRule::default().check().is_err());
+
+        let rules = CategoryRules::new(&[invalid]);
+        let mut item = item(ActivityCategory::ChangesMerged, "app", "bug(");
+        rules.apply("gerrit", &mut item);
+        assert_eq!(item.category, ActivityCategory::ChangesMerged);
+    }
+}
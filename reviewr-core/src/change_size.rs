@@ -0,0 +1,298 @@
+//! Size buckets for changes and merge requests by lines touched (insertions + deletions), so a
+//! few large changes are not drowned out by many tiny ones in raw counts, and the code volume
+//! summed over them
+
+use crate::platform::{ActivityCategory, ActivityItem, DetailedActivities};
+use std::fmt;
+
+/// Item metadata holding the diffstat, set by the Gerrit and GitLab platforms
+pub const INSERTIONS_KEY: &str = "insertions";
+pub const DELETIONS_KEY: &str = "deletions";
+/// Item metadata holding the number of commits in a push, set by the GitLab platform
+pub const COMMIT_COUNT_KEY: &str = "commit_count";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChangeSize {
+    /// Fewer than 10 lines
+    Xs,
+    /// 10 to 99 lines
+    S,
+    /// 100 to 499 lines
+    M,
+    /// 500 lines or more
+    L,
+}
+
+impl ChangeSize {
+    pub const ALL: [ChangeSize; 4] = [ChangeSize::Xs, ChangeSize::S, ChangeSize::M, ChangeSize::L];
+
+    pub fn from_lines(lines: u32) -> Self {
+        match lines {
+            0..10 => ChangeSize::Xs,
+            10..100 => ChangeSize::S,
+            100..500 => ChangeSize::M,
+            _ => ChangeSize::L,
+        }
+    }
+
+    /// Bucket of an item with a diffstat in its metadata
+    pub fn of(item: &ActivityItem) -> Option<Self> {
+        lines_changed(item).map(Self::from_lines)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChangeSize::Xs => "XS",
+            ChangeSize::S => "S",
+            ChangeSize::M => "M",
+            ChangeSize::L => "L",
+        }
+    }
+}
+
+impl fmt::Display for ChangeSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Insertions plus deletions, when the platform reported them
+pub fn lines_changed(item: &ActivityItem) -> Option<u32> {
+    let count = |key| item.metadata.get(key)?.parse::<u32>().ok();
+    Some(count(INSERTIONS_KEY)?.saturating_add(count(DELETIONS_KEY)?))
+}
+
+/// Insertions and deletions in a unified diff body as GitLab returns it: hunks only, no
+/// `+++`/`---` file headers, so every `+`/`-` line counts
+pub fn diffstat(diff: &str) -> (u32, u32) {
+    diff.lines().fold((0, 0), |(insertions, deletions), line| {
+        if line.starts_with('+') {
+            (insertions + 1, deletions)
+        } else if line.starts_with('-') {
+            (insertions, deletions + 1)
+        } else {
+            (insertions, deletions)
+        }
+    })
+}
+
+/// Authored changes per size bucket
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SizeBreakdown {
+    /// Indexed like [`ChangeSize::ALL`]
+    pub counts: [usize; 4],
+    /// Authored changes without a diffstat
+    pub unknown: usize,
+}
+
+impl SizeBreakdown {
+    /// Categories whose items are the employee's own changes; reviews of others' changes would
+    /// say nothing about the size of their contributions
+    fn is_authored(category: &ActivityCategory) -> bool {
+        matches!(
+            category,
+            ActivityCategory::ChangesCreated | ActivityCategory::MergeRequestsCreated
+        )
+    }
+
+    pub fn from_activities<'a>(
+        activities: impl IntoIterator<Item = &'a DetailedActivities>,
+    ) -> Self {
+        let mut breakdown = Self::default();
+        for (_, items) in activities
+            .into_iter()
+            .flat_map(|activities| &activities.items_by_category)
+            .filter(|(category, _)| Self::is_authored(category))
+        {
+            for item in items {
+                match ChangeSize::of(item) {
+                    Some(size) => breakdown.counts[size as usize] += 1,
+                    None => breakdown.unknown += 1,
+                }
+            }
+        }
+        breakdown
+    }
+
+    pub fn count(&self, size: ChangeSize) -> usize {
+        self.counts[size as usize]
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum::<usize>() + self.unknown
+    }
+
+    /// e.g. `XS 3 · S 5 · M 2 · L 0`, with `· ? 1` for changes without a diffstat
+    pub fn summary(&self) -> String {
+        let mut parts: Vec<String> = ChangeSize::ALL
+            .iter()
+            .map(|size| format!("{size} {}", self.count(*size)))
+            .collect();
+        if self.unknown > 0 {
+            parts.push(format!("? {}", self.unknown));
+        }
+        parts.join(" · ")
+    }
+}
+
+/// Lines touched by authored changes and commits pushed, summed over everything reported
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodeVolume {
+    pub insertions: u64,
+    pub deletions: u64,
+    /// Authored changes with a diffstat
+    pub changes: usize,
+    pub commits: u64,
+}
+
+impl CodeVolume {
+    pub fn from_activities<'a>(
+        activities: impl IntoIterator<Item = &'a DetailedActivities>,
+    ) -> Self {
+        let mut volume = Self::default();
+        for (category, items) in activities
+            .into_iter()
+            .flat_map(|activities| &activities.items_by_category)
+        {
+            for item in items {
+                let count = |key| item.metadata.get(key)?.parse::<u64>().ok();
+                if *category == ActivityCategory::CommitsPushed {
+                    volume.commits += count(COMMIT_COUNT_KEY).unwrap_or_default();
+                } else if SizeBreakdown::is_authored(category)
+                    && let (Some(insertions), Some(deletions)) =
+                        (count(INSERTIONS_KEY), count(DELETIONS_KEY))
+                {
+                    volume.insertions += insertions;
+                    volume.deletions += deletions;
+                    volume.changes += 1;
+                }
+            }
+        }
+        volume
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes == 0 && self.commits == 0
+    }
+
+    /// e.g. `+420 -37 in 5 changes · 12 commits pushed`, leaving out what was not reported
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if self.changes > 0 {
+            parts.push(format!(
+                "+{} -{} in {} change{}",
+                self.insertions,
+                self.deletions,
+                self.changes,
+                if self.changes == 1 { "" } else { "s" }
+            ));
+        }
+        if self.commits > 0 {
+            parts.push(format!(
+                "{} commit{} pushed",
+                self.commits,
+                if self.commits == 1 { "" } else { "s" }
+            ));
+        }
+        parts.join(" · ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::ItemBuilder;
+
+    fn change(category: ActivityCategory, diffstat: Option<(u32, u32)>) -> ActivityItem {
+        let change = ItemBuilder::new("1").with_category(category);
+        match diffstat {
+            Some((insertions, deletions)) => change
+                .with_metadata(INSERTIONS_KEY, &insertions.to_string())
+                .with_metadata(DELETIONS_KEY, &deletions.to_string()),
+            None => change,
+        }
+        .build()
+    }
+
+    #[test]
+    fn test_buckets() {
+        assert_eq!(ChangeSize::from_lines(0), ChangeSize::Xs);
+        assert_eq!(ChangeSize::from_lines(9), ChangeSize::Xs);
+        assert_eq!(ChangeSize::from_lines(10), ChangeSize::S);
+        assert_eq!(ChangeSize::from_lines(499), ChangeSize::M);
+        assert_eq!(ChangeSize::from_lines(500), ChangeSize::L);
+        assert_eq!(
+            ChangeSize::of(&change(ActivityCategory::ChangesCreated, Some((80, 30)))),
+            Some(ChangeSize::M)
+        );
+        assert_eq!(
+            ChangeSize::of(&change(ActivityCategory::ChangesCreated, None)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_breakdown_counts_authored_changes_only() {
+        let mut activities = DetailedActivities::default();
+        activities.items_by_category.insert(
+            ActivityCategory::ChangesCreated,
+            vec![
+                change(ActivityCategory::ChangesCreated, Some((3, 1))),
+                change(ActivityCategory::ChangesCreated, Some((2, 0))),
+                change(ActivityCategory::ChangesCreated, Some((900, 20))),
+                change(ActivityCategory::ChangesCreated, None),
+            ],
+        );
+        activities.items_by_category.insert(
+            ActivityCategory::ReviewsGiven,
+            vec![change(ActivityCategory::ReviewsGiven, Some((40, 0)))],
+        );
+
+        let breakdown = SizeBreakdown::from_activities([&activities]);
+        assert_eq!(breakdown.counts, [2, 0, 0, 1]);
+        assert_eq!(breakdown.unknown, 1);
+        assert_eq!(breakdown.total(), 4);
+        assert_eq!(breakdown.summary(), "XS 2 · S 0 · M 0 · L 1 · ? 1");
+    }
+
+    #[test]
+    fn test_code_volume() {
+        let mut activities = DetailedActivities::default();
+        activities.items_by_category.insert(
+            ActivityCategory::MergeRequestsCreated,
+            vec![
+                change(ActivityCategory::MergeRequestsCreated, Some((300, 20))),
+                change(ActivityCategory::MergeRequestsCreated, Some((5, 1))),
+                change(ActivityCategory::MergeRequestsCreated, None),
+            ],
+        );
+        activities.items_by_category.insert(
+            ActivityCategory::MergeRequestsReviewed,
+            vec![change(
+                ActivityCategory::MergeRequestsReviewed,
+                Some((40, 0)),
+            )],
+        );
+        assert_eq!(
+            CodeVolume::from_activities([&activities]).summary(),
+            "+305 -21 in 2 changes"
+        );
+
+        let mut push = change(ActivityCategory::CommitsPushed, None);
+        push.metadata
+            .insert(COMMIT_COUNT_KEY.to_string(), "3".to_string());
+        activities
+            .items_by_category
+            .insert(ActivityCategory::CommitsPushed, vec![push.clone(), push]);
+        let volume = CodeVolume::from_activities([&activities]);
+        assert_eq!(volume.commits, 6);
+        assert_eq!(volume.summary(), "+305 -21 in 2 changes · 6 commits pushed");
+        assert!(CodeVolume::default().is_empty());
+    }
+
+    #[test]
+    fn test_diffstat() {
+        let diff = "@@ -1,3 +1,4 @@\n context\n-old\n+new\n+added\n--- removed SQL comment\n";
+        assert_eq!(diffstat(diff), (2, 2));
+    }
+}
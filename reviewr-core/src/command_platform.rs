@@ -0,0 +1,405 @@
+//! Platforms backed by an external executable, for review systems reviewr has no client for.
+//!
+//! The command configured under `[platforms.custom.<name>]` is run with these environment
+//! variables:
+//!
+//! - `REVIEWR_OPERATION`: `activities` or `test_connection`
+//! - `REVIEWR_USER`: the employee's username for `custom:<name>`, else their committer email
+//! - `REVIEWR_DAYS`: length of the review period
+//! - `REVIEWR_PLATFORM_ID`: `custom:<name>`
+//!
+//! For `activities` it prints a JSON array of items on stdout:
+//!
+//! ```json
+//! [{"id": "42", "title": "Fix login", "category": "ChangesMerged", "status": "merged",
+//!   "created": "2024-01-15T10:30:00Z", "updated": "2024-01-16T09:00:00Z",
+//!   "url": "https://review.example.com/42", "project": "auth", "metadata": {}}]
+//! ```
+//!
+//! `category` is a category name like `ChangesMerged` or `Changes Merged`; anything else
+//! becomes a category of its own. Only `id`, `title` and `category` are required. A non-zero
+//! exit status fails the operation with the command's stderr as the message.
+
+use crate::platform::{
+    ActivityCategory, ActivityItem, ActivityMetrics, ConnectionStatus, DetailedActivities,
+    ErrorContext, PlannedRequest, QueryPlan, ReviewPlatform, Severity,
+};
+use crate::unified_config::CustomPlatformConfig;
+use async_trait::async_trait;
+use log::info;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::io;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::process::Command;
+
+/// Icon of custom platforms that do not configure one
+const DEFAULT_ICON: &str = "🧩";
+
+/// Categories a command can name, by variant or display name
+const KNOWN_CATEGORIES: [ActivityCategory; 13] = [
+    ActivityCategory::ChangesCreated,
+    ActivityCategory::ChangesReviewed,
+    ActivityCategory::ChangesMerged,
+    ActivityCategory::ReviewsGiven,
+    ActivityCategory::ReviewsReceived,
+    ActivityCategory::IssuesCreated,
+    ActivityCategory::IssuesAssigned,
+    ActivityCategory::IssuesResolved,
+    ActivityCategory::IssuesCommented,
+    ActivityCategory::MergeRequestsCreated,
+    ActivityCategory::MergeRequestsReviewed,
+    ActivityCategory::MergeRequestsMerged,
+    ActivityCategory::CommitsPushed,
+];
+
+/// An item as printed by the command; optional fields default to empty
+#[derive(Debug, Deserialize)]
+struct CommandItem {
+    id: String,
+    title: String,
+    category: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    created: String,
+    #[serde(default)]
+    updated: String,
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    project: String,
+    #[serde(default)]
+    metadata: HashMap<String, String>,
+}
+
+pub struct GenericCommandPlatform {
+    config: CustomPlatformConfig,
+    platform_id: String, // e.g. "custom:codeflow"
+    name: String,
+}
+
+impl GenericCommandPlatform {
+    pub fn new(config: CustomPlatformConfig, instance: &str) -> Self {
+        let name = config
+            .display_name
+            .clone()
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or_else(|| instance.to_string());
+        Self {
+            config,
+            platform_id: format!("custom:{instance}"),
+            name,
+        }
+    }
+
+    /// Run the command for `operation` and return its stdout
+    async fn run(&self, operation: &str, user: &str, days: u32) -> io::Result<String> {
+        let mut command = Command::new(&self.config.command);
+        command
+            .args(&self.config.args)
+            .env("REVIEWR_OPERATION", operation)
+            .env("REVIEWR_USER", user)
+            .env("REVIEWR_DAYS", days.to_string())
+            .env("REVIEWR_PLATFORM_ID", &self.platform_id)
+            .stdin(Stdio::null())
+            .kill_on_drop(true);
+
+        let timeout = Duration::from_secs(self.config.timeout_secs.max(1));
+        let output = match tokio::time::timeout(timeout, command.output()).await {
+            Ok(Ok(output)) => output,
+            Ok(Err(e)) => {
+                self.log_failure(
+                    operation,
+                    user,
+                    Severity::Error,
+                    "spawn_error",
+                    &e.to_string(),
+                );
+                return Err(io::Error::new(
+                    e.kind(),
+                    format!("Cannot run {}: {e}", self.config.command),
+                ));
+            }
+            Err(_) => {
+                let message = format!("{} did not finish within {timeout:?}", self.config.command);
+                self.log_failure(operation, user, Severity::Warning, "timeout", &message);
+                return Err(io::Error::new(io::ErrorKind::TimedOut, message));
+            }
+        };
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            let message = format!(
+                "{} exited with {}: {stderr}",
+                self.config.command, output.status
+            );
+            self.log_failure(operation, user, Severity::Error, "command_error", &message);
+            return Err(io::Error::other(message));
+        }
+        String::from_utf8(output.stdout).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} printed invalid UTF-8: {e}", self.config.command),
+            )
+        })
+    }
+
+    fn log_failure(
+        &self,
+        operation: &str,
+        user: &str,
+        severity: Severity,
+        kind: &str,
+        message: &str,
+    ) {
+        let mut context = ErrorContext::new(&self.platform_id, operation)
+            .with_severity(severity)
+            .with_error(kind, message)
+            .with_metadata("command", &self.config.command);
+        if !user.is_empty() {
+            context = context.with_user(user);
+        }
+        context.log_error();
+    }
+
+    /// Group the items printed by the command by category
+    fn parse_items(&self, stdout: &str) -> io::Result<DetailedActivities> {
+        let items: Vec<CommandItem> = serde_json::from_str(stdout).map_err(|e| {
+            self.log_failure(
+                "activities",
+                "",
+                Severity::Error,
+                "json_parse_error",
+                &e.to_string(),
+            );
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} printed invalid activity JSON: {e}", self.config.command),
+            )
+        })?;
+
+        let mut activities = DetailedActivities::default();
+        for item in items {
+            let category = parse_category(&item.category);
+            activities
+                .items_by_category
+                .entry(category.clone())
+                .or_default()
+                .push(ActivityItem {
+                    id: item.id,
+                    title: item.title,
+                    status: item.status,
+                    created: item.created,
+                    updated: item.updated,
+                    url: item.url,
+                    platform: self.platform_id.clone(),
+                    category,
+                    project: item.project,
+                    metadata: item.metadata,
+                });
+        }
+        Ok(activities)
+    }
+}
+
+/// `ChangesMerged` or `changes merged`; unknown names become [`ActivityCategory::Other`]
+fn parse_category(name: &str) -> ActivityCategory {
+    let name = name.trim();
+    let squashed: String = name.chars().filter(|c| !c.is_whitespace()).collect();
+    KNOWN_CATEGORIES
+        .iter()
+        .find(|category| {
+            let display = category.display_name();
+            display.eq_ignore_ascii_case(name)
+                || display.replace(' ', "").eq_ignore_ascii_case(&squashed)
+        })
+        .cloned()
+        .unwrap_or_else(|| ActivityCategory::Other(name.to_string()))
+}
+
+#[async_trait]
+impl ReviewPlatform for GenericCommandPlatform {
+    async fn get_activity_metrics(&self, user: &str, days: u32) -> io::Result<ActivityMetrics> {
+        let activities = self.get_detailed_activities(user, days).await?;
+        let items_by_category: HashMap<ActivityCategory, u32> = activities
+            .items_by_category
+            .iter()
+            .map(|(category, items)| (category.clone(), items.len() as u32))
+            .collect();
+        Ok(ActivityMetrics {
+            total_items: items_by_category.values().sum(),
+            items_by_category,
+            platform_specific: HashMap::new(),
+        })
+    }
+
+    async fn get_detailed_activities(
+        &self,
+        user: &str,
+        days: u32,
+    ) -> io::Result<DetailedActivities> {
+        info!(
+            "Running {} for {user} over {days} days ({})",
+            self.config.command, self.platform_id
+        );
+        let stdout = self.run("activities", user, days).await?;
+        self.parse_items(&stdout)
+    }
+
+    async fn search_items(&self, _query: &str, _user: &str) -> io::Result<Vec<ActivityItem>> {
+        Ok(Vec::new())
+    }
+
+    fn get_platform_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_platform_icon(&self) -> &str {
+        self.config.icon.as_deref().unwrap_or(DEFAULT_ICON)
+    }
+
+    fn get_platform_id(&self) -> &str {
+        &self.platform_id
+    }
+
+    fn is_configured(&self) -> bool {
+        !self.config.command.trim().is_empty()
+    }
+
+    async fn test_connection(&self) -> io::Result<ConnectionStatus> {
+        if !self.is_configured() {
+            return Ok(ConnectionStatus::NotConfigured);
+        }
+        Ok(match self.run("test_connection", "", 0).await {
+            Ok(_) => ConnectionStatus::Connected,
+            Err(e) if e.kind() == io::ErrorKind::TimedOut => {
+                ConnectionStatus::Warning(e.to_string())
+            }
+            Err(e) => ConnectionStatus::Error(e.to_string()),
+        })
+    }
+
+    fn get_item_url(&self, item: &ActivityItem) -> String {
+        item.url.clone()
+    }
+
+    fn explain_queries(&self, user: &str, days: u32) -> io::Result<Option<QueryPlan>> {
+        let command = std::iter::once(&self.config.command)
+            .chain(&self.config.args)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+        Ok(Some(QueryPlan {
+            auth: "left to the command".to_string(),
+            time_range: format!("REVIEWR_DAYS={days}, filtered by the command"),
+            requests: vec![PlannedRequest {
+                label: "Activities".to_string(),
+                query: None,
+                request: format!(
+                    "RUN {command} with REVIEWR_OPERATION=activities REVIEWR_USER={user} \
+                     REVIEWR_DAYS={days} REVIEWR_PLATFORM_ID={}",
+                    self.platform_id
+                ),
+            }],
+            notes: vec![format!(
+                "The command is stopped after {}s",
+                self.config.timeout_secs.max(1)
+            )],
+        }))
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    /// A platform running `script` with `sh -c`
+    fn platform(script: &str) -> GenericCommandPlatform {
+        GenericCommandPlatform::new(
+            CustomPlatformConfig {
+                command: "sh".to_string(),
+                args: vec!["-c".to_string(), script.to_string()],
+                display_name: Some("Codeflow".to_string()),
+                timeout_secs: 10,
+                ..Default::default()
+            },
+            "codeflow",
+        )
+    }
+
+    #[test]
+    fn test_parse_category() {
+        assert_eq!(
+            parse_category("ChangesMerged"),
+            ActivityCategory::ChangesMerged
+        );
+        assert_eq!(
+            parse_category(" reviews given "),
+            ActivityCategory::ReviewsGiven
+        );
+        assert_eq!(
+            parse_category("Design Docs"),
+            ActivityCategory::Other("Design Docs".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_items_from_command_output() {
+        let platform = platform(
+            r#"echo "[{\"id\": \"$REVIEWR_USER\", \"title\": \"Days $REVIEWR_DAYS\", \"category\": \"ChangesMerged\", \"status\": \"merged\"},
+                      {\"id\": \"7\", \"title\": \"RFC\", \"category\": \"Design Docs\", \"metadata\": {\"pages\": \"4\"}}]""#,
+        );
+        assert_eq!(platform.get_platform_id(), "custom:codeflow");
+        assert_eq!(platform.get_platform_name(), "Codeflow");
+        assert!(platform.is_configured());
+
+        let activities = platform
+            .get_detailed_activities("jane@example.com", 30)
+            .await
+            .unwrap();
+        let merged = &activities.items_by_category[&ActivityCategory::ChangesMerged];
+        assert_eq!(merged[0].id, "jane@example.com");
+        assert_eq!(merged[0].title, "Days 30");
+        assert_eq!(merged[0].platform, "custom:codeflow");
+        let docs = &activities.items_by_category[&ActivityCategory::Other("Design Docs".into())];
+        assert_eq!(docs[0].metadata["pages"], "4");
+        assert_eq!(docs[0].status, "");
+
+        let metrics = platform.get_activity_metrics("jane", 30).await.unwrap();
+        assert_eq!(metrics.total_items, 2);
+    }
+
+    #[tokio::test]
+    async fn test_command_failures() {
+        let failing = platform(
+            "[ \"$REVIEWR_OPERATION\" = test_connection ] && exit 0; echo 'token expired' >&2; exit 3",
+        );
+        assert!(failing.test_connection().await.unwrap().is_ok());
+        let error = failing
+            .get_detailed_activities("jane", 30)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("token expired"));
+
+        let garbage = platform("echo not json");
+        let error = garbage
+            .get_detailed_activities("jane", 30)
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let missing = GenericCommandPlatform::new(
+            CustomPlatformConfig {
+                command: "/nonexistent/reviewr-platform".to_string(),
+                ..Default::default()
+            },
+            "missing",
+        );
+        assert!(matches!(
+            missing.test_connection().await.unwrap(),
+            ConnectionStatus::Error(_)
+        ));
+    }
+}
@@ -0,0 +1,485 @@
+//! `reviewr config validate`: unknown keys, missing platform fields, invalid URLs and, with
+//! `enforce_allowed_domains`, servers outside `allowed_domains` in `config.toml`, each with its
+//! line and column
+
+use crate::category_rules::compile_pattern;
+use crate::domains::check_server_url;
+use crate::models::DataPath;
+use crate::settings::SETTINGS;
+use crate::unified_config::{UnifiedConfig, validate_platform_url};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::ops::Range;
+use toml_edit::{ImDocument, Item, TableLike};
+
+const TOP_LEVEL_KEYS: [&str; 8] = [
+    "platforms",
+    "global_settings",
+    "ui_preferences",
+    "performance",
+    "scoring",
+    "category_rules",
+    "integrations",
+    "version",
+];
+const PLATFORM_KEYS: [&str; 4] = ["gerrit", "jira", "gitlab", "custom"];
+const SCORING_KEYS: [&str; 2] = ["weights", "normalization"];
+const INTEGRATION_KEYS: [&str; 1] = ["ldap"];
+const CATEGORY_RULE_KEYS: [&str; 5] = ["category", "platform", "from", "label", "project"];
+
+/// Keys of one platform instance table
+struct InstanceSchema {
+    required: &'static [&'static str],
+    optional: &'static [&'static str],
+    /// Key holding the server URL, checked like `config edit` does
+    url: Option<&'static str>,
+}
+
+const GERRIT: InstanceSchema = InstanceSchema {
+    required: &["gerrit_url", "username", "http_password"],
+    optional: &[
+        "accept_invalid_certs",
+        "max_concurrent_requests",
+        "request_timeout_secs",
+    ],
+    url: Some("gerrit_url"),
+};
+const JIRA: InstanceSchema = InstanceSchema {
+    required: &["jira_url", "username", "api_token"],
+    optional: &[
+        "project_filter",
+        "custom_fields",
+        "fields",
+        "lazy_details",
+        "accept_invalid_certs",
+        "max_concurrent_requests",
+        "request_timeout_secs",
+    ],
+    url: Some("jira_url"),
+};
+const GITLAB: InstanceSchema = InstanceSchema {
+    required: &["name", "url", "token"],
+    optional: &[
+        "accept_invalid_certs",
+        "max_concurrent_requests",
+        "request_timeout_secs",
+    ],
+    url: Some("url"),
+};
+const CUSTOM: InstanceSchema = InstanceSchema {
+    required: &["command"],
+    optional: &["args", "display_name", "icon", "timeout_secs"],
+    url: None,
+};
+
+const LDAP: InstanceSchema = InstanceSchema {
+    required: &["url", "base_dn"],
+    optional: &[
+        "filter",
+        "bind_dn",
+        "bind_password",
+        "name_attribute",
+        "title_attribute",
+        "email_attribute",
+        "team_attribute",
+    ],
+    // ldap:// and ldaps:// URLs, which the platform URL check would reject
+    url: None,
+};
+
+/// One problem in `config.toml`; line and column start at 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProblem {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// Problems of the data directory's `config.toml`; NotFound when there is none
+pub fn check_config_file(data_path: &DataPath) -> io::Result<Vec<ConfigProblem>> {
+    Ok(check_config(&fs::read_to_string(data_path.config_path())?))
+}
+
+/// Every problem found in `content`, in file order. Value types are checked by loading the
+/// configuration, which only happens once the structure is valid.
+pub fn check_config(content: &str) -> Vec<ConfigProblem> {
+    let mut checker = Checker {
+        content,
+        problems: Vec::new(),
+        enforced_domains: None,
+    };
+    match ImDocument::parse(content) {
+        Ok(document) => {
+            checker.enforced_domains = enforced_domains(document.as_table());
+            checker.check_root(document.as_table())
+        }
+        Err(e) => checker.report(e.span(), e.message().trim().to_string()),
+    }
+    if checker.problems.is_empty()
+        && let Err(e) = toml::from_str::<UnifiedConfig>(content)
+    {
+        checker.report(e.span(), e.message().trim().to_string());
+    }
+    checker.problems.sort_by_key(|p| (p.line, p.column));
+    checker.problems
+}
+
+/// `allowed_domains` when `enforce_allowed_domains` is on
+fn enforced_domains(root: &dyn TableLike) -> Option<Vec<String>> {
+    let settings = root.get("global_settings")?.as_table_like()?;
+    if !settings.get("enforce_allowed_domains")?.as_bool()? {
+        return None;
+    }
+    let domains = settings
+        .get("allowed_domains")
+        .and_then(Item::as_array)
+        .map(|domains| {
+            domains
+                .iter()
+                .filter_map(|domain| domain.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(domains)
+}
+
+struct Checker<'a> {
+    content: &'a str,
+    problems: Vec<ConfigProblem>,
+    /// Domains every server URL must be on
+    enforced_domains: Option<Vec<String>>,
+}
+
+impl Checker<'_> {
+    fn report(&mut self, span: Option<Range<usize>>, message: String) {
+        let offset = span.map_or(0, |span| span.start).min(self.content.len());
+        let before = &self.content[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        self.problems.push(ConfigProblem {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message,
+        });
+    }
+
+    /// The table under `key`, or None after reporting that it is not one
+    fn table<'t>(
+        &mut self,
+        parent: &'t dyn TableLike,
+        key: &str,
+        path: &str,
+    ) -> Option<&'t dyn TableLike> {
+        let item = parent.get(key)?;
+        let table = item.as_table_like();
+        if table.is_none() {
+            self.report(item.span(), format!("[{path}] must be a table"));
+        }
+        table
+    }
+
+    fn unknown_keys(&mut self, table: &dyn TableLike, path: &str, known: &[&str]) {
+        for (key, _) in table.iter() {
+            if !known.contains(&key) {
+                let span = table.key(key).and_then(|key| key.span());
+                self.report(span, format!("unknown key '{key}' in [{path}]"));
+            }
+        }
+    }
+
+    fn check_root(&mut self, root: &dyn TableLike) {
+        self.unknown_keys(root, "config.toml", &TOP_LEVEL_KEYS);
+        for section in ["global_settings", "performance", "ui_preferences"] {
+            if let Some(table) = self.table(root, section, section) {
+                let known: Vec<&str> = SETTINGS
+                    .iter()
+                    .filter(|setting| setting.section == section)
+                    .map(|setting| setting.key)
+                    .collect();
+                self.unknown_keys(table, section, &known);
+            }
+        }
+        if let Some(scoring) = self.table(root, "scoring", "scoring") {
+            self.unknown_keys(scoring, "scoring", &SCORING_KEYS);
+            self.table(scoring, "weights", "scoring.weights");
+        }
+        if let Some(platforms) = self.table(root, "platforms", "platforms") {
+            self.check_platforms(platforms);
+        }
+        if let Some(rules) = root.get("category_rules") {
+            self.check_category_rules(rules);
+        }
+        if let Some(integrations) = self.table(root, "integrations", "integrations") {
+            self.unknown_keys(integrations, "integrations", &INTEGRATION_KEYS);
+            if let Some(ldap) = self.table(integrations, "ldap", "integrations.ldap") {
+                self.check_instance(ldap, integrations.get("ldap"), "integrations.ldap", &LDAP);
+            }
+        }
+    }
+
+    fn check_category_rules(&mut self, rules: &Item) {
+        // `[[category_rules]]` tables, or an inline array of inline tables
+        let tables: Vec<(&dyn TableLike, Option<Range<usize>>)> = match rules {
+            Item::ArrayOfTables(tables) => tables
+                .iter()
+                .map(|table| (table as &dyn TableLike, table.span()))
+                .collect(),
+            _ => match rules.as_array() {
+                Some(array) if array.iter().all(|value| value.is_inline_table()) => array
+                    .iter()
+                    .filter_map(|value| {
+                        let table = value.as_inline_table()?;
+                        Some((table as &dyn TableLike, value.span()))
+                    })
+                    .collect(),
+                _ => {
+                    self.report(
+                        rules.span(),
+                        "category_rules must be a list of [[category_rules]] tables".to_string(),
+                    );
+                    return;
+                }
+            },
+        };
+        for (index, (rule, span)) in tables.into_iter().enumerate() {
+            let path = format!("category_rules.{}", index + 1);
+            self.unknown_keys(rule, &path, &CATEGORY_RULE_KEYS);
+            let at = span.or_else(|| rule.iter().find_map(|(_, item)| item.span()));
+            match rule.get("category") {
+                Some(item) if item.as_str().is_some_and(|name| !name.trim().is_empty()) => {}
+                Some(item) => self.report(
+                    item.span(),
+                    format!("category in [{path}] must be a non-empty string"),
+                ),
+                None => self.report(at, format!("missing required key 'category' in [{path}]")),
+            }
+            for key in ["platform", "from", "label", "project"] {
+                let Some(item) = rule.get(key) else {
+                    continue;
+                };
+                let Some(value) = item.as_str() else {
+                    self.report(item.span(), format!("{key} in [{path}] must be a string"));
+                    continue;
+                };
+                if matches!(key, "label" | "project")
+                    && let Err(e) = compile_pattern(key, Some(value))
+                {
+                    self.report(item.span(), format!("{key} in [{path}]: {e}"));
+                }
+            }
+        }
+    }
+
+    fn check_platforms(&mut self, platforms: &dyn TableLike) {
+        self.unknown_keys(platforms, "platforms", &PLATFORM_KEYS);
+        for (kind, schema) in [("gerrit", &GERRIT), ("jira", &JIRA)] {
+            let path = format!("platforms.{kind}");
+            let Some(table) = self.table(platforms, kind, &path) else {
+                continue;
+            };
+            // A single server has its settings inline, instance tables only hold tables
+            if table.iter().any(|(_, item)| !item.is_table_like()) {
+                self.check_instance(table, platforms.get(kind), &path, schema);
+            } else {
+                self.check_instances(table, &path, schema);
+            }
+        }
+        for (kind, schema) in [("gitlab", &GITLAB), ("custom", &CUSTOM)] {
+            let path = format!("platforms.{kind}");
+            if let Some(table) = self.table(platforms, kind, &path) {
+                self.check_instances(table, &path, schema);
+            }
+        }
+    }
+
+    fn check_instances(&mut self, table: &dyn TableLike, path: &str, schema: &InstanceSchema) {
+        for (name, item) in table.iter() {
+            let path = format!("{path}.{name}");
+            if let Some(instance) = self.table(table, name, &path) {
+                self.check_instance(instance, Some(item), &path, schema);
+            }
+        }
+    }
+
+    fn check_instance(
+        &mut self,
+        instance: &dyn TableLike,
+        item: Option<&Item>,
+        path: &str,
+        schema: &InstanceSchema,
+    ) {
+        let known: Vec<&str> = schema
+            .required
+            .iter()
+            .chain(schema.optional)
+            .copied()
+            .collect();
+        self.unknown_keys(instance, path, &known);
+        // Tables declared only through dotted headers have no span of their own
+        let at = item
+            .and_then(Item::span)
+            .or_else(|| instance.iter().find_map(|(_, item)| item.span()));
+        for key in schema.required {
+            if !instance.contains_key(key) {
+                self.report(
+                    at.clone(),
+                    format!("missing required key '{key}' in [{path}]"),
+                );
+            }
+        }
+        if let Some(key) = schema.url
+            && let Some(item) = instance.get(key)
+        {
+            match item.as_str() {
+                Some(url) => {
+                    let allowed = self.enforced_domains.as_deref();
+                    if let Err(e) = validate_platform_url(url).and_then(|()| {
+                        allowed.map_or(Ok(()), |allowed| check_server_url(url, allowed))
+                    }) {
+                        self.report(item.span(), format!("{key} in [{path}]: {e}"));
+                    }
+                }
+                None => self.report(item.span(), format!("{key} in [{path}] must be a string")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID: &str = r#"version = 1
+
+[platforms.gerrit]
+gerrit_url = "https://gerrit.example.com"
+username = "me"
+http_password = ""
+
+[platforms.jira.cloud]
+jira_url = "https://example.atlassian.net"
+username = "me@example.com"
+api_token = ""
+lazy_details = true
+
+[platforms.gitlab.work]
+name = "work"
+url = "https://gitlab.example.com"
+token = ""
+
+[ui_preferences]
+default_time_period_days = 30
+
+[[category_rules]]
+category = "Bug Fixes"
+label = "^bug"
+"#;
+
+    fn messages(content: &str) -> Vec<String> {
+        check_config(content)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_config() {
+        assert_eq!(messages(VALID), Vec::<String>::new());
+        // Every key `config set` can write is known
+        let defaults = toml::to_string(&UnifiedConfig::default()).unwrap();
+        assert_eq!(messages(&defaults), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_unknown_keys_missing_fields_and_urls() {
+        let content = VALID
+            .replace("username = \"me\"\n", "user_name = \"me\"\n")
+            .replace("https://example.atlassian.net", "example.atlassian.net")
+            .replace("default_time_period_days", "default_period_days")
+            .replace("version = 1", "version = 1\ntheme = \"dark\"");
+        assert_eq!(
+            messages(&content),
+            vec![
+                "2:1: unknown key 'theme' in [config.toml]",
+                "4:1: missing required key 'username' in [platforms.gerrit]",
+                "6:1: unknown key 'user_name' in [platforms.gerrit]",
+                "10:12: jira_url in [platforms.jira.cloud]: Invalid URL 'example.atlassian.net': \
+                 relative URL without a base",
+                "21:1: unknown key 'default_period_days' in [ui_preferences]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enforced_allowed_domains() {
+        let content = VALID.replace(
+            "version = 1\n",
+            "version = 1\n\n[global_settings]\nallowed_domains = [\"example.com\", \"atlassian.net\"]\nenforce_allowed_domains = true\n",
+        );
+        assert_eq!(messages(&content), Vec::<String>::new());
+
+        let content = content.replace("https://example.atlassian.net", "https://jira.other.org");
+        assert_eq!(
+            messages(&content),
+            vec![
+                "13:12: jira_url in [platforms.jira.cloud]: host 'jira.other.org' is not in \
+                 allowed_domains (example.com, atlassian.net)"
+            ]
+        );
+        // Without enforcement the domains only filter clipboard evidence
+        let content = content.replace("enforce_allowed_domains = true", "");
+        assert_eq!(messages(&content), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_ldap_integration() {
+        let content = VALID.to_string()
+            + "\n[integrations.ldap]\nurl = \"ldaps://ldap.example.com\"\n\
+                       base_dn = \"dc=example,dc=com\"\nname_attribute = \"displayName\"\n";
+        assert_eq!(messages(&content), Vec::<String>::new());
+
+        let content = content.replace("base_dn", "base") + "\n[integrations.github]\n";
+        assert_eq!(
+            messages(&content),
+            vec![
+                "26:1: missing required key 'base_dn' in [integrations.ldap]",
+                "28:1: unknown key 'base' in [integrations.ldap]",
+                "31:15: unknown key 'github' in [integrations]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_category_rules() {
+        let content = VALID.replace("label = \"^bug\"", "label = \"bug(\"\nlabels = \"x\"")
+            + "\n[[category_rules]]\nproject = \"^infra/\"\n";
+        let messages = messages(&content);
+        assert_eq!(
+            messages,
+            vec![
+                "24:9: label in [category_rules.1]: Invalid label pattern 'bug(': unclosed group",
+                "25:1: unknown key 'labels' in [category_rules.1]",
+                "27:1: missing required key 'category' in [category_rules.2]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_syntax_and_type_errors() {
+        let problems = check_config("[ui_preferences\n");
+        assert_eq!((problems.len(), problems[0].line), (1, 1));
+
+        // Types are checked once the structure is valid
+        let content = VALID.replace("= 30", "= \"thirty\"");
+        let problems = check_config(&content);
+        assert_eq!(problems.len(), 1);
+        assert_eq!((problems[0].line, problems[0].column), (20, 28));
+        assert!(problems[0].message.contains("invalid type"), "{problems:?}");
+    }
+}
@@ -0,0 +1,240 @@
+//! Named review cycles (e.g. `2025-H1`) with the date range they cover, kept in `cycles.toml`.
+//! At most one cycle is active; notes written while it runs are tagged with its name.
+
+use crate::atomic_write::write_with_backup;
+use crate::models::DataPath;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// One review cycle; it is active until it gets an end date
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewCycle {
+    pub name: String,
+    pub start: NaiveDate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<NaiveDate>,
+}
+
+impl ReviewCycle {
+    pub fn is_active(&self) -> bool {
+        self.end.is_none()
+    }
+
+    /// Whether `date` falls between the start and the end (inclusive) of the cycle
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        date >= self.start && self.end.is_none_or(|end| date <= end)
+    }
+
+    /// Days from the start of the cycle up to and including `today`, the period to fetch
+    /// platform activity for
+    pub fn days_until(&self, today: NaiveDate) -> u32 {
+        ((today - self.start).num_days() + 1).max(1) as u32
+    }
+
+    /// `2025-H1 (2025-01-01 to 2025-06-30)`, with `today` for an active cycle
+    pub fn label(&self) -> String {
+        let end = self
+            .end
+            .map(|end| end.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| "today".to_string());
+        format!("{} ({} to {end})", self.name, self.start.format("%Y-%m-%d"))
+    }
+}
+
+/// On-disk layout of `cycles.toml`
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CyclesFile {
+    #[serde(default, rename = "cycle")]
+    cycles: Vec<ReviewCycle>,
+}
+
+pub struct CycleService;
+
+impl CycleService {
+    fn cycles_path(data_path: &DataPath) -> PathBuf {
+        data_path.root.join("cycles.toml")
+    }
+
+    /// Every cycle in the order they were started; a missing file means none
+    pub fn list(data_path: &DataPath) -> io::Result<Vec<ReviewCycle>> {
+        let path = Self::cycles_path(data_path);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let file: CyclesFile = toml::from_str(&content).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid cycles file {}: {e}", path.display()),
+            )
+        })?;
+        Ok(file.cycles)
+    }
+
+    /// The cycle that has been started but not closed yet
+    pub fn active(data_path: &DataPath) -> io::Result<Option<ReviewCycle>> {
+        Ok(Self::list(data_path)?
+            .into_iter()
+            .find(ReviewCycle::is_active))
+    }
+
+    pub fn get(data_path: &DataPath, name: &str) -> io::Result<ReviewCycle> {
+        Self::list(data_path)?
+            .into_iter()
+            .find(|cycle| cycle.name == name)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("No review cycle named '{name}'"),
+                )
+            })
+    }
+
+    /// Start a new cycle on `today`. Only one cycle can be active and names are unique.
+    pub fn start(data_path: &DataPath, name: &str, today: NaiveDate) -> io::Result<ReviewCycle> {
+        validate_cycle_name(name)?;
+        let mut cycles = Self::list(data_path)?;
+        if let Some(active) = cycles.iter().find(|cycle| cycle.is_active()) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "Review cycle '{}' is still active; close it first",
+                    active.name
+                ),
+            ));
+        }
+        if cycles.iter().any(|cycle| cycle.name == name) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("A review cycle named '{name}' already exists"),
+            ));
+        }
+        if let Some(last_end) = cycles.iter().filter_map(|cycle| cycle.end).max()
+            && today <= last_end
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("A new cycle must start after the last one ended on {last_end}"),
+            ));
+        }
+        let cycle = ReviewCycle {
+            name: name.to_string(),
+            start: today,
+            end: None,
+        };
+        cycles.push(cycle.clone());
+        Self::save(data_path, cycles)?;
+        Ok(cycle)
+    }
+
+    /// End the active cycle on `today`
+    pub fn close(data_path: &DataPath, today: NaiveDate) -> io::Result<ReviewCycle> {
+        let mut cycles = Self::list(data_path)?;
+        let cycle = cycles
+            .iter_mut()
+            .find(|cycle| cycle.is_active())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No review cycle is active"))?;
+        cycle.end = Some(today.max(cycle.start));
+        let closed = cycle.clone();
+        Self::save(data_path, cycles)?;
+        Ok(closed)
+    }
+
+    fn save(data_path: &DataPath, cycles: Vec<ReviewCycle>) -> io::Result<()> {
+        fs::create_dir_all(&data_path.root)?;
+        let content = toml::to_string(&CyclesFile { cycles }).map_err(io::Error::other)?;
+        write_with_backup(&Self::cycles_path(data_path), content)
+    }
+}
+
+/// Cycle names end up in notes headings as `[name]`, so brackets and line breaks are out
+fn validate_cycle_name(name: &str) -> io::Result<()> {
+    if name.trim().is_empty() || name.trim() != name {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Cycle name must not be empty or start or end with whitespace",
+        ));
+    }
+    if name
+        .chars()
+        .any(|c| matches!(c, '[' | ']') || c.is_control())
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Cycle name must not contain brackets or control characters",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_start_close_and_active() {
+        let temp_dir = TempDir::new().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        assert_eq!(CycleService::active(&data_path).unwrap(), None);
+
+        let cycle = CycleService::start(&data_path, "2025-H1", date("2025-01-02")).unwrap();
+        assert!(cycle.is_active());
+        assert_eq!(CycleService::active(&data_path).unwrap(), Some(cycle));
+        let err = CycleService::start(&data_path, "2025-H2", date("2025-02-01")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+        let closed = CycleService::close(&data_path, date("2025-06-30")).unwrap();
+        assert_eq!(closed.end, Some(date("2025-06-30")));
+        assert_eq!(CycleService::active(&data_path).unwrap(), None);
+        assert_eq!(
+            CycleService::close(&data_path, date("2025-07-01"))
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::NotFound
+        );
+
+        // Names are unique and cycles do not overlap
+        assert!(CycleService::start(&data_path, "2025-H1", date("2025-07-01")).is_err());
+        assert!(CycleService::start(&data_path, "2025-H2", date("2025-06-30")).is_err());
+        CycleService::start(&data_path, "2025-H2", date("2025-07-01")).unwrap();
+        assert_eq!(CycleService::list(&data_path).unwrap().len(), 2);
+        assert_eq!(
+            CycleService::get(&data_path, "2025-H1").unwrap().end,
+            Some(date("2025-06-30"))
+        );
+    }
+
+    #[test]
+    fn test_cycle_range() {
+        let cycle = ReviewCycle {
+            name: "2025-H1".to_string(),
+            start: date("2025-01-01"),
+            end: Some(date("2025-06-30")),
+        };
+        assert!(cycle.contains(date("2025-01-01")));
+        assert!(cycle.contains(date("2025-06-30")));
+        assert!(!cycle.contains(date("2024-12-31")));
+        assert!(!cycle.contains(date("2025-07-01")));
+        assert_eq!(cycle.days_until(date("2025-01-01")), 1);
+        assert_eq!(cycle.days_until(date("2025-01-31")), 31);
+        assert_eq!(cycle.label(), "2025-H1 (2025-01-01 to 2025-06-30)");
+    }
+
+    #[test]
+    fn test_cycle_name_validation() {
+        assert!(validate_cycle_name("2025-H1").is_ok());
+        assert!(validate_cycle_name("Spring 2025").is_ok());
+        assert!(validate_cycle_name("").is_err());
+        assert!(validate_cycle_name(" 2025").is_err());
+        assert!(validate_cycle_name("[2025]").is_err());
+    }
+}
@@ -0,0 +1,81 @@
+//! `global_settings.allowed_domains`: the hosts clipboard evidence may link to and, with
+//! `enforce_allowed_domains`, the only hosts platform servers may be configured on.
+
+use std::io;
+
+/// Whether `host` is one of `allowed_domains` or a subdomain of one; an empty list allows any
+/// host
+pub fn is_domain_allowed(host: &str, allowed_domains: &[String]) -> bool {
+    allowed_domains.is_empty()
+        || allowed_domains
+            .iter()
+            .any(|d| host == d || host.ends_with(&format!(".{d}")))
+}
+
+/// Check that the host of a platform server URL is on `allowed_domains`. Unlike
+/// [`is_domain_allowed`], an empty list allows nothing. PermissionDenied otherwise.
+pub fn check_server_url(url: &str, allowed_domains: &[String]) -> io::Result<()> {
+    let denied = |reason: String| io::Error::new(io::ErrorKind::PermissionDenied, reason);
+    let host = url::Url::parse(url.trim())
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .ok_or_else(|| denied(format!("'{url}' has no host to check")))?;
+    if allowed_domains.is_empty() {
+        return Err(denied(format!(
+            "host '{host}' is not allowed: allowed_domains is empty"
+        )));
+    }
+    if !is_domain_allowed(&host, allowed_domains) {
+        return Err(denied(format!(
+            "host '{host}' is not in allowed_domains ({})",
+            allowed_domains.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_matching() {
+        let allowed_domains = vec!["company.example.com".to_string()];
+
+        // Exact match should work
+        assert!(is_domain_allowed("company.example.com", &allowed_domains));
+
+        // Subdomain should work
+        assert!(is_domain_allowed(
+            "review.company.example.com",
+            &allowed_domains
+        ));
+
+        // Different domain should not work
+        assert!(!is_domain_allowed("other.com", &allowed_domains));
+
+        // Partial match should not work
+        assert!(!is_domain_allowed("example.com", &allowed_domains));
+
+        // Empty allowed domains should allow everything
+        assert!(is_domain_allowed("any.domain.com", &[]));
+    }
+
+    #[test]
+    fn test_check_server_url() {
+        let allowed_domains = vec!["company.example.com".to_string()];
+        assert!(check_server_url("https://gerrit.company.example.com/", &allowed_domains).is_ok());
+
+        let error = check_server_url("https://gerrit.other.com", &allowed_domains).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+        assert_eq!(
+            error.to_string(),
+            "host 'gerrit.other.com' is not in allowed_domains (company.example.com)"
+        );
+
+        // Enforcement without any allowed domain rejects every server
+        let error = check_server_url("https://gerrit.company.example.com", &[]).unwrap_err();
+        assert!(error.to_string().contains("allowed_domains is empty"));
+        assert!(check_server_url("not a url", &allowed_domains).is_err());
+    }
+}
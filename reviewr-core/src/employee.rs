@@ -0,0 +1,1312 @@
+use crate::atomic_write::{backup_path, restore_backup, with_suffix, write_with_backup};
+use crate::cache::ActivityCache;
+use crate::journal::JournalService;
+use crate::models::{DataPath, Employee, EmployeeProfile, validate_employee_name};
+use crate::recent::RecentService;
+use crate::report::ReportService;
+use fs4::FileExt;
+use log::{info, warn};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// What a rename does when notes or goals already exist under the new name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameConflict {
+    /// Refuse the rename and leave every file untouched
+    Abort,
+    /// Append the old notes and goals to the existing files
+    Merge,
+}
+
+/// Where `rename_employee` put an employee's files
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenameSummary {
+    /// New paths of the employee file, notes, goals and reports that were moved
+    pub moved: Vec<PathBuf>,
+    /// Existing notes and goals the old ones were appended to
+    pub merged: Vec<PathBuf>,
+    /// Cached activity entries now stored under the new name
+    pub cache_entries: usize,
+}
+
+pub struct EmployeeService;
+
+impl EmployeeService {
+    pub fn add_employee(data_path: &DataPath, employee_name: &str) -> io::Result<()> {
+        validate_employee_name(employee_name)?;
+
+        println!("Adding new employee: {employee_name}");
+        print!("Title: ");
+        io::stdout().flush()?;
+        let mut title = String::new();
+        io::stdin().read_line(&mut title)?;
+
+        print!("Committer Email (optional): ");
+        io::stdout().flush()?;
+        let mut email = String::new();
+        io::stdin().read_line(&mut email)?;
+        let email = email.trim();
+        let email = if email.is_empty() {
+            None
+        } else {
+            Some(email.to_string())
+        };
+
+        Self::add_employee_with_data(
+            data_path,
+            employee_name,
+            title.trim(),
+            email,
+            EmployeeProfile::default(),
+        )
+    }
+
+    pub fn add_employee_with_data(
+        data_path: &DataPath,
+        employee_name: &str,
+        title: &str,
+        committer_email: Option<String>,
+        profile: EmployeeProfile,
+    ) -> io::Result<()> {
+        validate_employee_name(employee_name)?;
+
+        if title.trim().is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Title cannot be empty",
+            ));
+        }
+        profile.validate()?;
+
+        let employee = Employee {
+            name: employee_name.to_string(),
+            title: title.to_string(),
+            committer_email,
+            profile,
+            ..Default::default()
+        };
+
+        let toml = toml::to_string(&employee).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to serialize employee data: {e}"),
+            )
+        })?;
+
+        let path = data_path
+            .employees_dir
+            .join(format!("{employee_name}.toml"));
+
+        write_with_backup(&path, toml)?;
+
+        info!("Employee '{}' added to {}", employee_name, path.display());
+        println!("Employee '{employee_name}' added.");
+        Ok(())
+    }
+
+    pub fn employee_exists(data_path: &DataPath, employee_name: &str) -> bool {
+        let employee_file = data_path
+            .employees_dir
+            .join(format!("{employee_name}.toml"));
+        employee_file.exists()
+    }
+
+    pub fn list_employees(data_path: &DataPath) -> io::Result<Vec<String>> {
+        let mut employees = Vec::new();
+
+        if !data_path.employees_dir.exists() {
+            return Ok(employees);
+        }
+
+        for entry in fs::read_dir(&data_path.employees_dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension().and_then(|s| s.to_str()) == Some("toml")
+                && let Some(name) = path.file_stem().and_then(|s| s.to_str())
+            {
+                employees.push(name.to_string());
+            }
+        }
+
+        employees.sort();
+        Ok(employees)
+    }
+
+    pub fn get_employee(data_path: &DataPath, employee_name: &str) -> io::Result<Employee> {
+        validate_employee_name(employee_name)?;
+
+        let employee_file = data_path
+            .employees_dir
+            .join(format!("{employee_name}.toml"));
+
+        // Use file locking for read operations too
+        let file = fs::File::open(&employee_file)?;
+        FileExt::lock_shared(&file)?;
+        let content = fs::read_to_string(&employee_file)?;
+        FileExt::unlock(&file)?;
+
+        let employee: Employee = toml::from_str(&content).map_err(|e| {
+            warn!(
+                "Failed to parse employee file {}: {}",
+                employee_file.display(),
+                e
+            );
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid employee file format: {e}"),
+            )
+        })?;
+        Ok(employee)
+    }
+
+    /// The cached `(employee, platform_id, activities)` entries that may still be used: those
+    /// of existing employees whose data policy allows the platform
+    pub fn allowed_cache_entries<T>(
+        data_path: &DataPath,
+        entries: Vec<(String, String, T)>,
+    ) -> Vec<(String, String, T)> {
+        let mut employees: HashMap<String, Option<Employee>> = HashMap::new();
+        entries
+            .into_iter()
+            .filter(|(name, platform_id, _)| {
+                employees
+                    .entry(name.clone())
+                    .or_insert_with(|| Self::get_employee(data_path, name).ok())
+                    .as_ref()
+                    .is_some_and(|employee| employee.allows_platform(platform_id))
+            })
+            .collect()
+    }
+
+    pub fn update_employee(
+        data_path: &DataPath,
+        old_name: &str,
+        new_name: &str,
+        title: &str,
+        committer_email: Option<String>,
+        profile: EmployeeProfile,
+        on_conflict: RenameConflict,
+    ) -> io::Result<()> {
+        validate_employee_name(old_name)?;
+        validate_employee_name(new_name)?;
+
+        if title.trim().is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Title cannot be empty",
+            ));
+        }
+        profile.validate()?;
+
+        // Renaming first takes notes, goals, reports and cached activity along
+        if old_name != new_name {
+            Self::rename_employee(data_path, old_name, new_name, on_conflict)?;
+        }
+
+        // Usernames and the data policy are only edited in the TOML file, so carry them over
+        let existing = Self::get_employee(data_path, new_name).unwrap_or_default();
+
+        // Create updated employee
+        let employee = Employee {
+            name: new_name.to_string(),
+            title: title.to_string(),
+            committer_email,
+            profile,
+            ..existing
+        };
+
+        let toml = toml::to_string(&employee).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to serialize employee data: {e}"),
+            )
+        })?;
+
+        write_with_backup(&Self::employee_path(data_path, new_name), toml)?;
+
+        info!("Employee '{new_name}' updated (was '{old_name}')");
+        Ok(())
+    }
+
+    /// Rename an employee together with their notes, goals, report packets, cached activity,
+    /// journal and recent entries. Every conflict is checked before anything moves, and moved
+    /// files are moved back when a later one fails. Renames that only change the case work on
+    /// case-insensitive filesystems too.
+    pub fn rename_employee(
+        data_path: &DataPath,
+        old_name: &str,
+        new_name: &str,
+        on_conflict: RenameConflict,
+    ) -> io::Result<RenameSummary> {
+        validate_employee_name(old_name)?;
+        validate_employee_name(new_name)?;
+        if old_name == new_name {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Employee is already called '{new_name}'"),
+            ));
+        }
+
+        let old_path = Self::employee_path(data_path, old_name);
+        let new_path = Self::employee_path(data_path, new_name);
+        let employee = Self::get_employee(data_path, old_name)?;
+
+        // Refuse before writing anything so a failed rename never splits an employee's history
+        if is_taken(&old_path, &new_path) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("Employee '{new_name}' already exists"),
+            ));
+        }
+        let conflicts = Self::rename_conflicts(data_path, old_name, new_name);
+        // Ciphertexts cannot be appended to each other
+        if let Some(encrypted) = conflicts
+            .iter()
+            .find(|path| path.extension().is_some_and(|extension| extension == "age"))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "Encrypted notes {} already exist and cannot be merged",
+                    encrypted.display()
+                ),
+            ));
+        }
+        if !conflicts.is_empty() && on_conflict == RenameConflict::Abort {
+            let files: Vec<String> = conflicts.iter().map(|p| p.display().to_string()).collect();
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("Files for '{new_name}' already exist: {}", files.join(", ")),
+            ));
+        }
+        let reports: Vec<(PathBuf, PathBuf)> =
+            ReportService::employee_reports(data_path, old_name)?
+                .into_iter()
+                .map(|(path, date)| {
+                    let target = ReportService::default_output_path(data_path, new_name, &date);
+                    (path, target)
+                })
+                .collect();
+        // Two packets of the same day cannot be merged either
+        if let Some((_, target)) = reports
+            .iter()
+            .find(|(source, target)| is_taken(source, target))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("Report {} already exists", target.display()),
+            ));
+        }
+
+        // Plain moves can be undone, so they go first and merges only once they all succeeded
+        let mut moves = vec![(old_path.clone(), new_path.clone())];
+        let mut merges = Vec::new();
+        for (source, target) in Self::history_files(data_path, old_name, new_name) {
+            if !source.exists() {
+                continue;
+            }
+            if is_taken(&source, &target) {
+                merges.push((source, target));
+            } else {
+                moves.push((source, target));
+            }
+        }
+        moves.extend(reports);
+
+        let mut summary = RenameSummary::default();
+        let mut done: Vec<&(PathBuf, PathBuf)> = Vec::new();
+        for planned in &moves {
+            if let Err(e) = rename_path(&planned.0, &planned.1) {
+                undo_moves(&done);
+                return Err(e);
+            }
+            info!("Moved {} to {}", planned.0.display(), planned.1.display());
+            done.push(planned);
+        }
+
+        let employee = Employee {
+            name: new_name.to_string(),
+            ..employee
+        };
+        let written = toml::to_string(&employee)
+            .map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Failed to serialize employee data: {e}"),
+                )
+            })
+            .and_then(|toml| {
+                // The old backup would be found under the new name on case-insensitive filesystems
+                let old_backup = backup_path(&old_path);
+                if old_backup.exists() {
+                    fs::remove_file(&old_backup)?;
+                }
+                write_with_backup(&new_path, toml)
+            });
+        if let Err(e) = written {
+            undo_moves(&done);
+            return Err(e);
+        }
+        summary.moved = moves.into_iter().map(|(_, target)| target).collect();
+
+        for (source, target) in merges {
+            merge_file(&source, &target)?;
+            summary.merged.push(target);
+        }
+
+        // Derived data: a failure here leaves a stale entry behind, not a split history
+        summary.cache_entries = ActivityCache::new(data_path, Duration::ZERO)
+            .rename_employee(old_name, new_name)
+            .unwrap_or_else(|e| {
+                warn!("Failed to rename cached activity of '{old_name}': {e}");
+                0
+            });
+        if let Err(e) = JournalService::rename(data_path, old_name, new_name) {
+            warn!("Failed to rename '{old_name}' in the journal: {e}");
+        }
+        RecentService::rename(data_path, old_name, new_name)?;
+
+        info!("Employee '{old_name}' renamed to '{new_name}'");
+        Ok(summary)
+    }
+
+    /// Encrypt the employee's notes to `recipient` from now on
+    pub fn set_notes_recipient(
+        data_path: &DataPath,
+        employee_name: &str,
+        recipient: &str,
+    ) -> io::Result<()> {
+        let employee = Employee {
+            notes_recipient: Some(recipient.to_string()),
+            ..Self::get_employee(data_path, employee_name)?
+        };
+        let toml = toml::to_string(&employee).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to serialize employee data: {e}"),
+            )
+        })?;
+        Self::write_employee_toml(data_path, employee_name, &toml)
+    }
+
+    /// Notes and goals files of `new_name` that a rename from `old_name` would collide with
+    pub fn rename_conflicts(data_path: &DataPath, old_name: &str, new_name: &str) -> Vec<PathBuf> {
+        Self::history_files(data_path, old_name, new_name)
+            .into_iter()
+            .filter(|(source, target)| source.exists() && is_taken(source, target))
+            .map(|(_, target)| target)
+            .collect()
+    }
+
+    /// `(old, new)` paths of the notes and goals files that follow an employee's name
+    fn history_files(
+        data_path: &DataPath,
+        old_name: &str,
+        new_name: &str,
+    ) -> Vec<(PathBuf, PathBuf)> {
+        [
+            (&data_path.notes_dir, "md"),
+            (&data_path.notes_dir, "md.age"),
+            (&data_path.goals_dir, "toml"),
+        ]
+        .into_iter()
+        .map(|(dir, extension)| {
+            (
+                dir.join(format!("{old_name}.{extension}")),
+                dir.join(format!("{new_name}.{extension}")),
+            )
+        })
+        .collect()
+    }
+
+    pub fn employee_path(data_path: &DataPath, employee_name: &str) -> PathBuf {
+        data_path
+            .employees_dir
+            .join(format!("{employee_name}.toml"))
+    }
+
+    /// Parse hand-edited employee TOML. Errors name the offending line, and the `name`
+    /// field must match the file it is saved to.
+    pub fn validate_employee_toml(content: &str, expected_name: &str) -> io::Result<Employee> {
+        let employee: Employee = toml::from_str(content).map_err(|e| {
+            let message = match e.span() {
+                Some(span) => {
+                    let line = content[..span.start.min(content.len())]
+                        .lines()
+                        .count()
+                        .max(1);
+                    format!("line {line}: {}", e.message())
+                }
+                None => e.message().to_string(),
+            };
+            io::Error::new(io::ErrorKind::InvalidData, message)
+        })?;
+
+        validate_employee_name(&employee.name)?;
+        if employee.name != expected_name {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "name '{}' does not match the file '{expected_name}.toml'; use the edit form to rename",
+                    employee.name
+                ),
+            ));
+        }
+        if employee.title.trim().is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Title cannot be empty",
+            ));
+        }
+        employee.profile.validate()?;
+        employee.validate_out_of_office()?;
+        Ok(employee)
+    }
+
+    /// Replace an employee file with validated, hand-edited TOML (kept verbatim, comments included)
+    pub fn write_employee_toml(
+        data_path: &DataPath,
+        employee_name: &str,
+        content: &str,
+    ) -> io::Result<()> {
+        Self::validate_employee_toml(content, employee_name)?;
+        let path = Self::employee_path(data_path, employee_name);
+        write_with_backup(&path, content)?;
+        info!("Employee '{employee_name}' rewritten from raw TOML");
+        Ok(())
+    }
+
+    /// Replace an unreadable employee file with its `.bak`, if the backup itself is valid
+    pub fn restore_backup(data_path: &DataPath, employee_name: &str) -> io::Result<()> {
+        let path = Self::employee_path(data_path, employee_name);
+        let backup = fs::read_to_string(backup_path(&path))?;
+        Self::validate_employee_toml(&backup, employee_name).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("The backup of '{employee_name}' is not valid either: {e}"),
+            )
+        })?;
+        restore_backup(&path)?;
+        info!("Restored {} from its backup", path.display());
+        Ok(())
+    }
+
+    /// Files that belong to an employee: `(source, path relative to the data root)`
+    pub(crate) fn employee_files(
+        data_path: &DataPath,
+        employee_name: &str,
+    ) -> Vec<(PathBuf, PathBuf)> {
+        [
+            (&data_path.employees_dir, "employees", "toml"),
+            (&data_path.employees_dir, "employees", "toml.bak"),
+            (&data_path.notes_dir, "notes", "md"),
+            (&data_path.notes_dir, "notes", "md.age"),
+            (&data_path.goals_dir, "goals", "toml"),
+        ]
+        .into_iter()
+        .map(|(dir, subdir, extension)| {
+            let file_name = format!("{employee_name}.{extension}");
+            (dir.join(&file_name), PathBuf::from(subdir).join(file_name))
+        })
+        .filter(|(path, _)| path.exists())
+        .collect()
+    }
+
+    /// `employee_files` plus the employee's report packets, which go wherever the employee goes
+    fn owned_files(
+        data_path: &DataPath,
+        employee_name: &str,
+    ) -> io::Result<Vec<(PathBuf, PathBuf)>> {
+        let mut files = Self::employee_files(data_path, employee_name);
+        for (path, _) in ReportService::employee_reports(data_path, employee_name)? {
+            if let Some(file_name) = path.file_name() {
+                let relative = PathBuf::from("reports").join(file_name);
+                files.push((path, relative));
+            }
+        }
+        Ok(files)
+    }
+
+    /// Permanently delete an employee together with their notes, goals, report packets and
+    /// journal entries
+    pub fn remove_employee(data_path: &DataPath, employee_name: &str) -> io::Result<()> {
+        validate_employee_name(employee_name)?;
+        if !Self::employee_exists(data_path, employee_name) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Employee '{employee_name}' not found"),
+            ));
+        }
+
+        for (path, _) in Self::owned_files(data_path, employee_name)? {
+            fs::remove_file(&path)?;
+            info!("Removed {}", path.display());
+        }
+        if let Err(e) = JournalService::forget(data_path, employee_name) {
+            warn!("Failed to remove '{employee_name}' from the journal: {e}");
+        }
+        Ok(())
+    }
+
+    /// Move an employee, their notes, goals and report packets under `archive/`, and drop them
+    /// from the journal
+    pub fn archive_employee(data_path: &DataPath, employee_name: &str) -> io::Result<()> {
+        validate_employee_name(employee_name)?;
+        if !Self::employee_exists(data_path, employee_name) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Employee '{employee_name}' not found"),
+            ));
+        }
+
+        let files = Self::owned_files(data_path, employee_name)?;
+        // Refuse before moving anything so an employee is never split across both trees
+        if let Some((_, relative)) = files
+            .iter()
+            .find(|(_, relative)| data_path.archive_dir.join(relative).exists())
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "An archived '{}' already exists",
+                    data_path.archive_dir.join(relative).display()
+                ),
+            ));
+        }
+
+        for (path, relative) in files {
+            let target = data_path.archive_dir.join(relative);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::rename(&path, &target)?;
+            info!("Archived {} to {}", path.display(), target.display());
+        }
+        if let Err(e) = JournalService::forget(data_path, employee_name) {
+            warn!("Failed to remove '{employee_name}' from the journal: {e}");
+        }
+        Ok(())
+    }
+
+    /// Names of archived employees, sorted
+    pub fn list_archived_employees(data_path: &DataPath) -> io::Result<Vec<String>> {
+        let archived = DataPath {
+            employees_dir: data_path.archive_dir.join("employees"),
+            ..data_path.clone()
+        };
+        Self::list_employees(&archived)
+    }
+}
+
+/// Whether `target` is a file other than `source`. On case-insensitive filesystems a target
+/// differing only in case exists as the source itself; the directory listing tells them apart.
+fn is_taken(source: &Path, target: &Path) -> bool {
+    if !target.exists() {
+        return false;
+    }
+    let (Some(source_name), Some(target_name)) = (source.file_name(), target.file_name()) else {
+        return true;
+    };
+    let case_only = source_name != target_name
+        && source_name.to_string_lossy().to_lowercase()
+            == target_name.to_string_lossy().to_lowercase();
+    if !case_only || !source.exists() {
+        return true;
+    }
+    let Some(dir) = target.parent() else {
+        return true;
+    };
+    fs::read_dir(dir).map_or(true, |entries| {
+        entries
+            .flatten()
+            .any(|entry| entry.file_name() == target_name)
+    })
+}
+
+/// Rename through a temporary name, which case-insensitive filesystems need to change only the
+/// case of a name
+fn rename_path(source: &Path, target: &Path) -> io::Result<()> {
+    let staging = with_suffix(source, ".rename.tmp");
+    fs::rename(source, &staging)?;
+    fs::rename(&staging, target).inspect_err(|_| {
+        let _ = fs::rename(&staging, source);
+    })
+}
+
+/// Move already renamed files back, latest first
+fn undo_moves(done: &[&(PathBuf, PathBuf)]) {
+    for (source, target) in done.iter().rev() {
+        if let Err(e) = rename_path(target, source) {
+            warn!(
+                "Failed to move {} back to {}: {e}",
+                target.display(),
+                source.display()
+            );
+        }
+    }
+}
+
+/// Append `source` to the existing `target` and remove it. Notes are Markdown sections and
+/// goals `[[goal]]` tables, so appending keeps both valid.
+fn merge_file(source: &Path, target: &Path) -> io::Result<()> {
+    let mut merged = fs::read_to_string(target)?;
+    if !merged.is_empty() && !merged.ends_with("\n\n") {
+        merged.push_str(if merged.ends_with('\n') { "\n" } else { "\n\n" });
+    }
+    merged.push_str(&fs::read_to_string(source)?);
+    // Write next to the target and rename over it so a crash leaves either version
+    let staging = target.with_extension("merge.tmp");
+    fs::write(&staging, merged)?;
+    fs::rename(&staging, target)?;
+    fs::remove_file(source)?;
+    info!("Merged {} into {}", source.display(), target.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_add_employee_with_data() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+
+        EmployeeService::add_employee_with_data(
+            &data_path,
+            "John Doe",
+            "Engineer",
+            None,
+            Default::default(),
+        )
+        .unwrap();
+
+        let employee = EmployeeService::get_employee(&data_path, "John Doe").unwrap();
+        assert_eq!(employee.name, "John Doe");
+        assert_eq!(employee.title, "Engineer");
+    }
+
+    #[test]
+    fn test_update_employee() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+
+        // Create initial employee
+        EmployeeService::add_employee_with_data(
+            &data_path,
+            "John Doe",
+            "Engineer",
+            None,
+            Default::default(),
+        )
+        .unwrap();
+
+        // Update employee
+        EmployeeService::update_employee(
+            &data_path,
+            "John Doe",
+            "John Smith",
+            "Senior Engineer",
+            None,
+            Default::default(),
+            RenameConflict::Abort,
+        )
+        .unwrap();
+
+        // Verify old file is gone and new one exists
+        assert!(!EmployeeService::employee_exists(&data_path, "John Doe"));
+        assert!(EmployeeService::employee_exists(&data_path, "John Smith"));
+
+        let employee = EmployeeService::get_employee(&data_path, "John Smith").unwrap();
+        assert_eq!(employee.name, "John Smith");
+        assert_eq!(employee.title, "Senior Engineer");
+    }
+
+    #[test]
+    fn test_rename_moves_notes_and_goals() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        fs::create_dir_all(&data_path.goals_dir).unwrap();
+        EmployeeService::add_employee_with_data(
+            &data_path,
+            "Jane Doe",
+            "Engineer",
+            None,
+            Default::default(),
+        )
+        .unwrap();
+        fs::write(
+            data_path.notes_dir.join("Jane Doe.md"),
+            "## 2024-01-10\nOld\n",
+        )
+        .unwrap();
+        fs::write(
+            data_path.goals_dir.join("Jane Doe.toml"),
+            "[[goal]]\ntitle = \"Ship it\"\n",
+        )
+        .unwrap();
+
+        EmployeeService::update_employee(
+            &data_path,
+            "Jane Doe",
+            "Jane Smith",
+            "Engineer",
+            None,
+            Default::default(),
+            RenameConflict::Abort,
+        )
+        .unwrap();
+
+        assert!(!data_path.notes_dir.join("Jane Doe.md").exists());
+        assert!(!data_path.goals_dir.join("Jane Doe.toml").exists());
+        let notes = fs::read_to_string(data_path.notes_dir.join("Jane Smith.md")).unwrap();
+        assert!(notes.contains("Old"));
+        let goals = crate::goals::GoalsService::load_goals(&data_path, "Jane Smith").unwrap();
+        assert_eq!(goals[0].title, "Ship it");
+    }
+
+    #[test]
+    fn test_rename_conflict_aborts_or_merges() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        EmployeeService::add_employee_with_data(
+            &data_path,
+            "Jane Doe",
+            "Engineer",
+            None,
+            Default::default(),
+        )
+        .unwrap();
+        fs::write(
+            data_path.notes_dir.join("Jane Doe.md"),
+            "## 2024-01-10\nOld\n",
+        )
+        .unwrap();
+        fs::write(
+            data_path.notes_dir.join("Jane Smith.md"),
+            "## 2023-05-01\nStale\n",
+        )
+        .unwrap();
+        let rename = |on_conflict| {
+            EmployeeService::update_employee(
+                &data_path,
+                "Jane Doe",
+                "Jane Smith",
+                "Engineer",
+                None,
+                Default::default(),
+                on_conflict,
+            )
+        };
+
+        assert_eq!(
+            EmployeeService::rename_conflicts(&data_path, "Jane Doe", "Jane Smith"),
+            vec![data_path.notes_dir.join("Jane Smith.md")]
+        );
+        let err = rename(RenameConflict::Abort).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        // Nothing moved
+        assert!(EmployeeService::employee_exists(&data_path, "Jane Doe"));
+        assert!(!EmployeeService::employee_exists(&data_path, "Jane Smith"));
+
+        rename(RenameConflict::Merge).unwrap();
+        let notes = fs::read_to_string(data_path.notes_dir.join("Jane Smith.md")).unwrap();
+        assert_eq!(notes, "## 2023-05-01\nStale\n\n## 2024-01-10\nOld\n");
+        assert!(!data_path.notes_dir.join("Jane Doe.md").exists());
+    }
+
+    #[test]
+    fn test_rename_employee_takes_reports_cache_and_journal() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        EmployeeService::add_employee_with_data(
+            &data_path,
+            "Jane Doe",
+            "Engineer",
+            None,
+            Default::default(),
+        )
+        .unwrap();
+        fs::write(data_path.notes_dir.join("Jane Doe.md"), "## 2024-01-10\n").unwrap();
+        let reports = data_path.root.join("reports");
+        fs::create_dir_all(&reports).unwrap();
+        fs::write(reports.join("Jane Doe-2024-01-31.md"), "# Jane Doe\n").unwrap();
+        // Someone else's packet
+        fs::write(reports.join("Jane Doe-Smith-2024-01-31.md"), "").unwrap();
+        let cache = ActivityCache::new(&data_path, Duration::from_secs(3600));
+        cache
+            .store("Jane Doe", "jira", 30, &Default::default())
+            .unwrap();
+        JournalService::mark_done(&data_path, "2024-W03", "Jane Doe").unwrap();
+        RecentService::record(&data_path, "Jane Doe").unwrap();
+
+        let summary = EmployeeService::rename_employee(
+            &data_path,
+            "Jane Doe",
+            "Jane Smith",
+            RenameConflict::Abort,
+        )
+        .unwrap();
+
+        assert_eq!(
+            summary.moved,
+            vec![
+                data_path.employees_dir.join("Jane Smith.toml"),
+                data_path.notes_dir.join("Jane Smith.md"),
+                reports.join("Jane Smith-2024-01-31.md"),
+            ]
+        );
+        assert_eq!(summary.cache_entries, 1);
+        assert_eq!(
+            EmployeeService::get_employee(&data_path, "Jane Smith")
+                .unwrap()
+                .name,
+            "Jane Smith"
+        );
+        assert!(!EmployeeService::employee_exists(&data_path, "Jane Doe"));
+        assert!(reports.join("Jane Doe-Smith-2024-01-31.md").exists());
+        assert!(cache.load("Jane Doe", "jira", 30).is_none());
+        assert!(cache.load("Jane Smith", "jira", 30).is_some());
+        assert_eq!(
+            JournalService::completed(&data_path, "2024-W03"),
+            vec!["Jane Smith"]
+        );
+        assert_eq!(RecentService::load(&data_path), vec!["Jane Smith"]);
+    }
+
+    #[test]
+    fn test_rename_employee_case_only() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        for name in ["jane doe", "sam lee"] {
+            EmployeeService::add_employee_with_data(
+                &data_path,
+                name,
+                "Engineer",
+                None,
+                Default::default(),
+            )
+            .unwrap();
+        }
+        fs::write(data_path.notes_dir.join("jane doe.md"), "## 2024-01-10\n").unwrap();
+
+        EmployeeService::rename_employee(&data_path, "jane doe", "Jane Doe", RenameConflict::Abort)
+            .unwrap();
+        assert_eq!(
+            EmployeeService::get_employee(&data_path, "Jane Doe")
+                .unwrap()
+                .name,
+            "Jane Doe"
+        );
+        let notes = fs::read_to_string(data_path.notes_dir.join("Jane Doe.md")).unwrap();
+        assert_eq!(notes, "## 2024-01-10\n");
+
+        // On a case-sensitive filesystem these can be two employees
+        if !data_path.employees_dir.join("SAM LEE.toml").exists() {
+            EmployeeService::add_employee_with_data(
+                &data_path,
+                "Sam Lee",
+                "Engineer",
+                None,
+                Default::default(),
+            )
+            .unwrap();
+            let error = EmployeeService::rename_employee(
+                &data_path,
+                "sam lee",
+                "Sam Lee",
+                RenameConflict::Merge,
+            )
+            .unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        }
+    }
+
+    #[test]
+    fn test_update_employee_same_name() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+
+        // Create initial employee
+        EmployeeService::add_employee_with_data(
+            &data_path,
+            "John Doe",
+            "Engineer",
+            None,
+            Default::default(),
+        )
+        .unwrap();
+
+        // Update title only
+        EmployeeService::update_employee(
+            &data_path,
+            "John Doe",
+            "John Doe",
+            "Senior Engineer",
+            None,
+            Default::default(),
+            RenameConflict::Abort,
+        )
+        .unwrap();
+
+        let employee = EmployeeService::get_employee(&data_path, "John Doe").unwrap();
+        assert_eq!(employee.name, "John Doe");
+        assert_eq!(employee.title, "Senior Engineer");
+    }
+
+    #[test]
+    fn test_update_employee_keeps_usernames() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        fs::write(
+            data_path.employees_dir.join("Sam Lee.toml"),
+            "name = \"Sam Lee\"\ntitle = \"Contractor\"\n\n[usernames]\njira = \"slee\"\n",
+        )
+        .unwrap();
+
+        EmployeeService::update_employee(
+            &data_path,
+            "Sam Lee",
+            "Sam Lee",
+            "Engineer",
+            None,
+            Default::default(),
+            RenameConflict::Abort,
+        )
+        .unwrap();
+
+        let employee = EmployeeService::get_employee(&data_path, "Sam Lee").unwrap();
+        assert_eq!(employee.identity_for("jira"), Some("slee"));
+        // No username and no email for other platforms
+        assert_eq!(employee.identity_for("gerrit"), None);
+    }
+
+    #[test]
+    fn test_encrypted_notes_follow_rename_but_never_merge() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        EmployeeService::add_employee_with_data(
+            &data_path,
+            "Jane Doe",
+            "Engineer",
+            None,
+            EmployeeProfile::default(),
+        )
+        .unwrap();
+        EmployeeService::set_notes_recipient(&data_path, "Jane Doe", "age1abc").unwrap();
+        assert_eq!(
+            EmployeeService::get_employee(&data_path, "Jane Doe")
+                .unwrap()
+                .notes_recipient
+                .as_deref(),
+            Some("age1abc")
+        );
+        fs::write(data_path.notes_dir.join("Jane Doe.md.age"), [0u8, 159, 146]).unwrap();
+        // Notes left behind by a former employee of that name
+        fs::write(data_path.notes_dir.join("Sam Lee.md.age"), [1u8, 2, 3]).unwrap();
+
+        let rename = |new_name: &str| {
+            EmployeeService::update_employee(
+                &data_path,
+                "Jane Doe",
+                new_name,
+                "Engineer",
+                None,
+                EmployeeProfile::default(),
+                RenameConflict::Merge,
+            )
+        };
+        let error = rename("Sam Lee").unwrap_err();
+        assert!(error.to_string().contains("cannot be merged"));
+        assert!(data_path.notes_dir.join("Jane Doe.md.age").exists());
+
+        rename("Jane Smith").unwrap();
+        assert_eq!(
+            fs::read(data_path.notes_dir.join("Jane Smith.md.age")).unwrap(),
+            vec![0u8, 159, 146]
+        );
+    }
+
+    #[test]
+    fn test_restore_employee_backup() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        EmployeeService::add_employee_with_data(
+            &data_path,
+            "Jane Doe",
+            "Engineer",
+            None,
+            EmployeeProfile::default(),
+        )
+        .unwrap();
+        let path = EmployeeService::employee_path(&data_path, "Jane Doe");
+        // Nothing to go back to before the second save
+        assert!(EmployeeService::restore_backup(&data_path, "Jane Doe").is_err());
+
+        EmployeeService::set_notes_recipient(&data_path, "Jane Doe", "age1abc").unwrap();
+        fs::write(&path, "name = \"Jane Doe\"\ntitle = ").unwrap();
+        assert!(EmployeeService::get_employee(&data_path, "Jane Doe").is_err());
+
+        EmployeeService::restore_backup(&data_path, "Jane Doe").unwrap();
+        let employee = EmployeeService::get_employee(&data_path, "Jane Doe").unwrap();
+        assert_eq!(employee.title, "Engineer");
+        assert_eq!(employee.notes_recipient, None);
+        // The list never shows backups as employees
+        assert_eq!(
+            EmployeeService::list_employees(&data_path).unwrap(),
+            vec!["Jane Doe"]
+        );
+    }
+
+    #[test]
+    fn test_data_policy_blocks_platforms() {
+        let employee = Employee {
+            name: "Jane Doe".to_string(),
+            title: "Engineer".to_string(),
+            committer_email: Some("jane@example.com".to_string()),
+            exclude_platforms: vec!["gitlab".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(employee.identity_for("gerrit"), Some("jane@example.com"));
+        assert_eq!(employee.identity_for("gitlab:company"), None);
+        assert!(employee.allows_platform("gitlabx"));
+
+        let notes_only: Employee =
+            toml::from_str("name = \"Sam\"\ntitle = \"Engineer\"\nnotes_only = true\n").unwrap();
+        assert!(!notes_only.allows_platform("jira"));
+    }
+
+    #[test]
+    fn test_allowed_cache_entries_follow_data_policy() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        fs::write(
+            data_path.employees_dir.join("Jane Doe.toml"),
+            "name = \"Jane Doe\"\ntitle = \"Engineer\"\nexclude_platforms = [\"jira\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            data_path.employees_dir.join("Sam.toml"),
+            "name = \"Sam\"\ntitle = \"Engineer\"\nnotes_only = true\n",
+        )
+        .unwrap();
+
+        let entry =
+            |employee: &str, platform_id: &str| (employee.to_string(), platform_id.to_string(), ());
+        let allowed = EmployeeService::allowed_cache_entries(
+            &data_path,
+            vec![
+                entry("Jane Doe", "gerrit"),
+                entry("Jane Doe", "jira:work"),
+                entry("Sam", "gerrit"),
+                entry("Removed Person", "gerrit"),
+            ],
+        );
+        assert_eq!(allowed, vec![entry("Jane Doe", "gerrit")]);
+    }
+
+    #[test]
+    fn test_validate_employee_toml() {
+        let valid = "name = \"Jane Doe\"\ntitle = \"Engineer\"\n";
+        assert_eq!(
+            EmployeeService::validate_employee_toml(valid, "Jane Doe")
+                .unwrap()
+                .title,
+            "Engineer"
+        );
+
+        let err = EmployeeService::validate_employee_toml(
+            "name = \"Jane Doe\"\ntitle = \"Engineer\"\nnotes_only = maybe\n",
+            "Jane Doe",
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("line 3:"), "{err}");
+
+        let err = EmployeeService::validate_employee_toml(valid, "John Doe").unwrap_err();
+        assert!(err.to_string().contains("does not match"));
+
+        let err = EmployeeService::validate_employee_toml("name = \"Jane Doe\"\n", "Jane Doe")
+            .unwrap_err();
+        assert!(err.to_string().contains("title"), "{err}");
+    }
+
+    #[test]
+    fn test_out_of_office() {
+        let toml = r#"
+            name = "Jane Doe"
+            title = "Engineer"
+
+            [[out_of_office]]
+            start = "2025-07-01"
+            end = "2025-07-14"
+            reason = "Vacation"
+
+            [[out_of_office]]
+            start = "2025-07-10"
+            end = "2025-07-20"
+        "#;
+        let employee = EmployeeService::validate_employee_toml(toml, "Jane Doe").unwrap();
+        let today = NaiveDate::from_ymd_opt(2025, 7, 31).unwrap();
+        // July 1 to 20, the overlap counted once
+        assert_eq!(employee.days_out_of_office(today, 31), 20);
+        assert_eq!(employee.days_out_of_office(today, 11), 0);
+        assert_eq!(employee.days_out_of_office(today, 15), 4);
+
+        let backwards = toml.replace("end = \"2025-07-20\"", "end = \"2025-07-09\"");
+        let err = EmployeeService::validate_employee_toml(&backwards, "Jane Doe").unwrap_err();
+        assert!(err.to_string().contains("ends before it starts"), "{err}");
+    }
+
+    #[test]
+    fn test_archive_and_remove_employee() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        for name in ["John Doe", "Jane Smith"] {
+            EmployeeService::add_employee_with_data(
+                &data_path,
+                name,
+                "Engineer",
+                None,
+                Default::default(),
+            )
+            .unwrap();
+            fs::write(data_path.notes_dir.join(format!("{name}.md")), "# Notes\n").unwrap();
+        }
+
+        EmployeeService::archive_employee(&data_path, "John Doe").unwrap();
+        assert!(!EmployeeService::employee_exists(&data_path, "John Doe"));
+        assert!(data_path.archive_dir.join("notes/John Doe.md").exists());
+        assert_eq!(
+            EmployeeService::list_archived_employees(&data_path).unwrap(),
+            vec!["John Doe"]
+        );
+
+        EmployeeService::remove_employee(&data_path, "Jane Smith").unwrap();
+        assert!(
+            EmployeeService::list_employees(&data_path)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(!data_path.notes_dir.join("Jane Smith.md").exists());
+
+        let err = EmployeeService::remove_employee(&data_path, "Jane Smith").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_remove_and_archive_take_reports_and_journal() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        let reports = data_path.root.join("reports");
+        fs::create_dir_all(&reports).unwrap();
+        for name in ["John Doe", "Jane Doe"] {
+            EmployeeService::add_employee_with_data(
+                &data_path,
+                name,
+                "Engineer",
+                None,
+                Default::default(),
+            )
+            .unwrap();
+            fs::write(reports.join(format!("{name}-2024-01-31.md")), "# Packet\n").unwrap();
+            JournalService::mark_done(&data_path, "2024-W03", name).unwrap();
+        }
+        // Someone else's packet
+        fs::write(reports.join("Jane Doe-Smith-2024-01-31.md"), "").unwrap();
+
+        EmployeeService::archive_employee(&data_path, "John Doe").unwrap();
+        assert!(!reports.join("John Doe-2024-01-31.md").exists());
+        assert!(
+            data_path
+                .archive_dir
+                .join("reports/John Doe-2024-01-31.md")
+                .exists()
+        );
+        assert_eq!(
+            JournalService::completed(&data_path, "2024-W03"),
+            vec!["Jane Doe"]
+        );
+
+        EmployeeService::remove_employee(&data_path, "Jane Doe").unwrap();
+        assert!(!reports.join("Jane Doe-2024-01-31.md").exists());
+        assert!(reports.join("Jane Doe-Smith-2024-01-31.md").exists());
+        assert!(JournalService::completed(&data_path, "2024-W03").is_empty());
+    }
+
+    #[test]
+    fn test_profile_fields_round_trip() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        // Files written before the profile fields existed still load
+        fs::write(
+            data_path.employees_dir.join("Sam Lee.toml"),
+            "name = \"Sam Lee\"\ntitle = \"Engineer\"\n\n[usernames]\njira = \"slee\"\n",
+        )
+        .unwrap();
+        let employee = EmployeeService::get_employee(&data_path, "Sam Lee").unwrap();
+        assert_eq!(employee.profile, EmployeeProfile::default());
+        assert_eq!(employee.profile.summary(), None);
+
+        let profile = EmployeeProfile {
+            team: Some("Platform".to_string()),
+            manager: Some("Ann Lee".to_string()),
+            start_date: Some("2022-03-01".to_string()),
+            location: None,
+            tags: vec!["backend".to_string(), "oncall".to_string()],
+        };
+        EmployeeService::update_employee(
+            &data_path,
+            "Sam Lee",
+            "Sam Lee",
+            "Engineer",
+            None,
+            profile.clone(),
+            RenameConflict::Abort,
+        )
+        .unwrap();
+
+        let employee = EmployeeService::get_employee(&data_path, "Sam Lee").unwrap();
+        assert_eq!(employee.profile, profile);
+        assert_eq!(employee.identity_for("jira"), Some("slee"));
+        assert_eq!(
+            employee.profile.summary().unwrap(),
+            "Platform · manager Ann Lee · since 2022-03-01 · #backend #oncall"
+        );
+    }
+
+    #[test]
+    fn test_invalid_start_date_rejected() {
+        let err = EmployeeService::validate_employee_toml(
+            "name = \"Sam Lee\"\ntitle = \"Engineer\"\nstart_date = \"March 2022\"\n",
+            "Sam Lee",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("YYYY-MM-DD"));
+    }
+}
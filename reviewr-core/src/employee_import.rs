@@ -0,0 +1,751 @@
+//! `reviewr import`: employee files created in bulk from a CSV export of the org chart, or from
+//! an LDAP / Active Directory search configured under `[integrations.ldap]`.
+//!
+//! ```toml
+//! [integrations.ldap]
+//! url = "ldaps://ldap.example.com"
+//! base_dn = "ou=people,dc=example,dc=com"
+//! filter = "(&(objectClass=person)(departmentNumber=42))"
+//! ```
+//!
+//! The directory is searched with OpenLDAP's `ldapsearch`, which has to be on the PATH. Both
+//! sources are planned first, so a dry run shows exactly what an import would write.
+
+use crate::bulk_edit::{EditableField, FieldUpdate};
+use crate::employee::EmployeeService;
+use crate::models::{DataPath, Employee, EmployeeProfile, validate_employee_name};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+
+/// One person to import
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportRecord {
+    /// Where the record came from, for messages: `line 3` or the entry's DN
+    pub origin: String,
+    pub name: String,
+    pub title: String,
+    pub email: Option<String>,
+    pub team: Option<String>,
+}
+
+/// What to do with a record for an employee that already exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnConflict {
+    /// Leave the existing employee as it is
+    #[default]
+    Skip,
+    /// Set the existing employee's title, email and team from the record
+    Update,
+    /// Import nothing
+    Abort,
+}
+
+impl OnConflict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OnConflict::Skip => "skip",
+            OnConflict::Update => "update",
+            OnConflict::Abort => "abort",
+        }
+    }
+}
+
+impl fmt::Display for OnConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OnConflict {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "skip" => Ok(OnConflict::Skip),
+            "update" => Ok(OnConflict::Update),
+            "abort" => Ok(OnConflict::Abort),
+            other => Err(format!(
+                "unknown conflict mode '{other}' (expected skip, update or abort)"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportAction {
+    Create,
+    /// The existing employee file gets these changes
+    Update(Vec<FieldUpdate>),
+    /// Nothing is written for the record, for this reason
+    Skip(String),
+}
+
+/// A record as an import would handle it
+#[derive(Debug, Clone)]
+pub struct PlannedImport {
+    pub record: ImportRecord,
+    pub action: ImportAction,
+    /// The employee file to write; unused when skipped
+    pub employee: Employee,
+}
+
+impl PlannedImport {
+    fn skip(record: ImportRecord, reason: String) -> Self {
+        Self {
+            record,
+            action: ImportAction::Skip(reason),
+            employee: Employee::default(),
+        }
+    }
+}
+
+/// Columns `parse_csv` reads; any other column is ignored
+const CSV_COLUMNS: [&str; 4] = ["name", "title", "email", "team"];
+
+/// Records of a CSV file with a header row naming the `name`, `title`, `email` and `team`
+/// columns in any order (`email` and `team` may be left out). Fields may be quoted, with `""`
+/// for a quote inside one.
+pub fn parse_csv(content: &str) -> io::Result<Vec<ImportRecord>> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+    let mut rows = csv_rows(content.trim_start_matches('\u{feff}'))?.into_iter();
+    let (_, header) = rows
+        .next()
+        .ok_or_else(|| invalid("the CSV file is empty".to_string()))?;
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|field| field.trim().eq_ignore_ascii_case(name))
+    };
+    let columns: Vec<Option<usize>> = CSV_COLUMNS.iter().map(|name| column(name)).collect();
+    let (Some(name), Some(title)) = (columns[0], columns[1]) else {
+        return Err(invalid(format!(
+            "the header must name the name and title columns (found: {})",
+            header.join(", ")
+        )));
+    };
+    let (email, team) = (columns[2], columns[3]);
+
+    let records = rows
+        .map(|(line, fields)| {
+            let field = |index: Option<usize>| {
+                index
+                    .and_then(|index| fields.get(index))
+                    .map(|value| value.trim().to_string())
+                    .unwrap_or_default()
+            };
+            let optional = |index: Option<usize>| Some(field(index)).filter(|v| !v.is_empty());
+            ImportRecord {
+                origin: format!("line {line}"),
+                name: field(Some(name)),
+                title: field(Some(title)),
+                email: optional(email),
+                team: optional(team),
+            }
+        })
+        .collect();
+    Ok(records)
+}
+
+/// Non-empty rows with the line each starts on; quoted fields may span lines
+fn csv_rows(content: &str) -> io::Result<Vec<(usize, Vec<String>)>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let (mut line, mut row_line) = (1, 1);
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                in_quotes = true;
+            }
+            ',' if !in_quotes => row.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes && chars.peek() == Some(&'\n') => {}
+            '\n' if !in_quotes => {
+                row.push(std::mem::take(&mut field));
+                if row.iter().any(|field| !field.trim().is_empty()) {
+                    rows.push((row_line, std::mem::take(&mut row)));
+                }
+                row.clear();
+                line += 1;
+                row_line = line;
+            }
+            c => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+        }
+    }
+    if in_quotes {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("line {row_line}: quoted field is never closed"),
+        ));
+    }
+    row.push(field);
+    if row.iter().any(|field| !field.trim().is_empty()) {
+        rows.push((row_line, row));
+    }
+    Ok(rows)
+}
+
+/// `[integrations.ldap]`: the directory `reviewr import ldap` searches
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LdapConfig {
+    /// Server URL, e.g. `ldaps://ldap.example.com`
+    pub url: String,
+    /// Where the search starts, e.g. `ou=people,dc=example,dc=com`
+    pub base_dn: String,
+    #[serde(default = "default_filter")]
+    pub filter: String,
+    /// DN to bind as; anonymous when empty
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub bind_dn: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub bind_password: String,
+    #[serde(default = "default_name_attribute")]
+    pub name_attribute: String,
+    #[serde(default = "default_title_attribute")]
+    pub title_attribute: String,
+    #[serde(default = "default_email_attribute")]
+    pub email_attribute: String,
+    #[serde(default = "default_team_attribute")]
+    pub team_attribute: String,
+}
+
+fn default_filter() -> String {
+    "(objectClass=person)".to_string()
+}
+
+fn default_name_attribute() -> String {
+    "cn".to_string()
+}
+
+fn default_title_attribute() -> String {
+    "title".to_string()
+}
+
+fn default_email_attribute() -> String {
+    "mail".to_string()
+}
+
+fn default_team_attribute() -> String {
+    "department".to_string()
+}
+
+impl LdapConfig {
+    /// `ldapsearch` arguments; the bind password is read from stdin, never from the command line
+    pub fn search_args(&self) -> Vec<String> {
+        let mut args: Vec<String> = [
+            "-LLL",
+            "-x",
+            "-o",
+            "ldif-wrap=no",
+            "-E",
+            "pr=500/noprompt",
+            "-H",
+            &self.url,
+            "-b",
+            &self.base_dn,
+        ]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+        if !self.bind_dn.is_empty() {
+            args.extend(["-D", &self.bind_dn, "-y", "/dev/stdin"].map(str::to_string));
+        }
+        args.push(self.filter.clone());
+        args.extend(
+            [
+                &self.name_attribute,
+                &self.title_attribute,
+                &self.email_attribute,
+                &self.team_attribute,
+            ]
+            .map(|attribute| attribute.clone()),
+        );
+        args
+    }
+
+    /// Search the directory with `ldapsearch`
+    pub fn search(&self) -> io::Result<Vec<ImportRecord>> {
+        let mut child = Command::new("ldapsearch")
+            .args(self.search_args())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                if e.kind() == io::ErrorKind::NotFound {
+                    io::Error::new(
+                        io::ErrorKind::NotFound,
+                        "ldapsearch was not found; install the OpenLDAP client tools (ldap-utils)",
+                    )
+                } else {
+                    e
+                }
+            })?;
+        if let Some(mut stdin) = child.stdin.take()
+            && !self.bind_dn.is_empty()
+        {
+            stdin.write_all(self.bind_password.as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(io::Error::other(format!(
+                "ldapsearch on {} failed ({}): {}",
+                self.url,
+                output.status,
+                stderr.trim()
+            )));
+        }
+        let entries = parse_ldif(&String::from_utf8_lossy(&output.stdout));
+        info!(
+            "ldapsearch on {} returned {} entries",
+            self.url,
+            entries.len()
+        );
+        Ok(self.records(&entries))
+    }
+
+    /// Records from search results, using the configured attributes
+    pub fn records(&self, entries: &[LdifEntry]) -> Vec<ImportRecord> {
+        entries
+            .iter()
+            .map(|entry| ImportRecord {
+                origin: entry.dn.clone(),
+                name: entry.first(&self.name_attribute).unwrap_or_default(),
+                title: entry.first(&self.title_attribute).unwrap_or_default(),
+                email: entry.first(&self.email_attribute),
+                team: entry.first(&self.team_attribute),
+            })
+            .collect()
+    }
+}
+
+/// One entry of `ldapsearch` output
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LdifEntry {
+    pub dn: String,
+    /// Values by lowercase attribute name
+    pub attributes: HashMap<String, Vec<String>>,
+}
+
+impl LdifEntry {
+    /// First non-empty value of an attribute; names are case-insensitive
+    pub fn first(&self, attribute: &str) -> Option<String> {
+        self.attributes
+            .get(&attribute.to_ascii_lowercase())?
+            .iter()
+            .map(|value| value.trim())
+            .find(|value| !value.is_empty())
+            .map(str::to_string)
+    }
+}
+
+/// Entries of LDIF text: folded lines are joined and `attr:: <base64>` values decoded
+pub fn parse_ldif(content: &str) -> Vec<LdifEntry> {
+    use base64::Engine;
+
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        match (line.strip_prefix(' '), lines.last_mut()) {
+            (Some(continued), Some(last)) if !last.is_empty() => last.push_str(continued),
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    let mut entries = Vec::new();
+    let mut entry: Option<LdifEntry> = None;
+    for line in lines.iter().map(|line| line.trim_end()) {
+        if line.is_empty() {
+            entries.extend(entry.take());
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+        let Some((attribute, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = match value.strip_prefix(':') {
+            Some(encoded) => base64::engine::general_purpose::STANDARD
+                .decode(encoded.trim())
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+                .unwrap_or_default(),
+            None => value.trim().to_string(),
+        };
+        let attribute = attribute.trim().to_ascii_lowercase();
+        if attribute == "dn" {
+            entries.extend(entry.take());
+            entry = Some(LdifEntry {
+                dn: value,
+                ..LdifEntry::default()
+            });
+        } else if let Some(entry) = entry.as_mut() {
+            entry.attributes.entry(attribute).or_default().push(value);
+        }
+    }
+    entries.extend(entry);
+    entries
+}
+
+pub struct EmployeeImportService;
+
+impl EmployeeImportService {
+    /// What importing `records` would do, without writing anything. Records without a valid
+    /// name or title, and repeats of a name or email, are skipped. An existing employee is
+    /// matched by name (ignoring case) or committer email; with [`OnConflict::Abort`] any match
+    /// is an AlreadyExists error.
+    pub fn plan(
+        data_path: &DataPath,
+        records: Vec<ImportRecord>,
+        on_conflict: OnConflict,
+    ) -> io::Result<Vec<PlannedImport>> {
+        let mut existing = Vec::new();
+        for file_name in EmployeeService::list_employees(data_path)? {
+            existing.push(EmployeeService::get_employee(data_path, &file_name)?);
+        }
+        let archived: Vec<String> = EmployeeService::list_archived_employees(data_path)?
+            .into_iter()
+            .map(|name| name.to_lowercase())
+            .collect();
+
+        let mut seen: HashMap<String, String> = HashMap::new();
+        let mut conflicts = Vec::new();
+        let mut plan = Vec::new();
+        for record in records {
+            if let Err(e) = validate_employee_name(&record.name) {
+                plan.push(PlannedImport::skip(record, e.to_string()));
+                continue;
+            }
+            if record.title.is_empty() {
+                plan.push(PlannedImport::skip(record, "no title".to_string()));
+                continue;
+            }
+            let keys = std::iter::once(format!("name:{}", record.name.to_lowercase())).chain(
+                record
+                    .email
+                    .iter()
+                    .map(|e| format!("email:{}", e.to_lowercase())),
+            );
+            if let Some(first) = keys.clone().find_map(|key| seen.get(&key).cloned()) {
+                plan.push(PlannedImport::skip(
+                    record,
+                    format!("same person as {first}"),
+                ));
+                continue;
+            }
+            for key in keys {
+                seen.insert(key, record.origin.clone());
+            }
+
+            if archived.contains(&record.name.to_lowercase()) {
+                conflicts.push(format!("{} (archived)", record.name));
+                plan.push(PlannedImport::skip(
+                    record,
+                    "an archived employee has this name".to_string(),
+                ));
+                continue;
+            }
+            let matched = existing.iter().find(|employee| {
+                employee.name.eq_ignore_ascii_case(&record.name)
+                    || record.email.as_ref().is_some_and(|email| {
+                        employee
+                            .committer_email
+                            .as_ref()
+                            .is_some_and(|existing| existing.eq_ignore_ascii_case(email))
+                    })
+            });
+            let Some(employee) = matched else {
+                let employee = Employee {
+                    name: record.name.clone(),
+                    title: record.title.clone(),
+                    committer_email: record.email.clone(),
+                    profile: EmployeeProfile {
+                        team: record.team.clone(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                };
+                plan.push(PlannedImport {
+                    record,
+                    action: ImportAction::Create,
+                    employee,
+                });
+                continue;
+            };
+
+            conflicts.push(if employee.name.eq_ignore_ascii_case(&record.name) {
+                employee.name.clone()
+            } else {
+                format!("{} (as {})", record.name, employee.name)
+            });
+            if on_conflict != OnConflict::Update {
+                let reason = format!("'{}' already exists", employee.name);
+                plan.push(PlannedImport::skip(record, reason));
+                continue;
+            }
+            let mut updated = employee.clone();
+            let changes = [
+                (EditableField::Title, Some(&record.title)),
+                (EditableField::CommitterEmail, record.email.as_ref()),
+                (EditableField::Team, record.team.as_ref()),
+            ];
+            let mut updates = Vec::new();
+            // Empty fields keep the existing value
+            for (field, value) in changes {
+                let Some(value) = value else {
+                    continue;
+                };
+                let old = field.get(&updated);
+                if field == EditableField::CommitterEmail && old.eq_ignore_ascii_case(value) {
+                    continue;
+                }
+                field.set(&mut updated, value);
+                let new = field.get(&updated);
+                if old != new {
+                    updates.push(FieldUpdate { field, old, new });
+                }
+            }
+            if updates.is_empty() {
+                let reason = format!("'{}' is already up to date", employee.name);
+                plan.push(PlannedImport::skip(record, reason));
+                continue;
+            }
+            plan.push(PlannedImport {
+                record,
+                action: ImportAction::Update(updates),
+                employee: updated,
+            });
+        }
+
+        if on_conflict == OnConflict::Abort && !conflicts.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "nothing was imported; these employees already exist: {}",
+                    conflicts.join(", ")
+                ),
+            ));
+        }
+        Ok(plan)
+    }
+
+    /// Write every created and updated employee, keeping a `.bak` of each previous file
+    pub fn apply(data_path: &DataPath, plan: &[PlannedImport]) -> io::Result<()> {
+        for planned in plan {
+            if matches!(planned.action, ImportAction::Skip(_)) {
+                continue;
+            }
+            let toml = toml::to_string(&planned.employee).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Failed to serialize employee data: {e}"),
+                )
+            })?;
+            EmployeeService::write_employee_toml(data_path, &planned.employee.name, &toml)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_csv() {
+        let content = "\u{feff}Email,Name,Title,Location\r\n\
+            ann@example.com,Ann Lee,Engineer,Berlin\r\n\
+            \r\n\
+            ,\"Doe, Jane\",\"Staff \"\"Platform\"\"\nEngineer\",\n\
+            bob@example.com,Bob\n";
+        let records = parse_csv(content).unwrap();
+        assert_eq!(
+            records,
+            vec![
+                ImportRecord {
+                    origin: "line 2".to_string(),
+                    name: "Ann Lee".to_string(),
+                    title: "Engineer".to_string(),
+                    email: Some("ann@example.com".to_string()),
+                    team: None,
+                },
+                ImportRecord {
+                    origin: "line 4".to_string(),
+                    name: "Doe, Jane".to_string(),
+                    title: "Staff \"Platform\"\nEngineer".to_string(),
+                    email: None,
+                    team: None,
+                },
+                ImportRecord {
+                    origin: "line 6".to_string(),
+                    name: "Bob".to_string(),
+                    email: Some("bob@example.com".to_string()),
+                    ..Default::default()
+                },
+            ]
+        );
+
+        let error = parse_csv("name,email\nAnn,ann@example.com\n").unwrap_err();
+        assert!(error.to_string().contains("name and title columns"));
+        let error = parse_csv("name,title\n\"Ann,Engineer\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: quoted field is never closed");
+        assert!(parse_csv("").is_err());
+    }
+
+    #[test]
+    fn test_parse_ldif() {
+        let ldif = "# extended LDIF\n\
+            dn: uid=ann,ou=people,dc=example,dc=com\n\
+            cn: Ann Lee\n\
+            title: Senior\n  Engineer\n\
+            mail: ann@example.com\n\
+            \n\
+            dn: uid=jo,ou=people,dc=example,dc=com\n\
+            cn:: SsO2cmcgTcO8bGxlcg==\n\
+            Department: Platform\n";
+        let entries = parse_ldif(ldif);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[0].first("title").as_deref(),
+            Some("Senior Engineer")
+        );
+
+        let config: LdapConfig =
+            toml::from_str("url = \"ldap://ldap.example.com\"\nbase_dn = \"dc=example,dc=com\"")
+                .unwrap();
+        let records = config.records(&entries);
+        assert_eq!(records[1].origin, "uid=jo,ou=people,dc=example,dc=com");
+        assert_eq!(records[1].name, "Jörg Müller");
+        assert_eq!(records[1].team.as_deref(), Some("Platform"));
+        assert_eq!(records[1].title, "");
+        assert_eq!(records[1].email, None);
+    }
+
+    #[test]
+    fn test_search_args_keep_the_password_off_the_command_line() {
+        let mut config: LdapConfig =
+            toml::from_str("url = \"ldaps://ad.example.com\"\nbase_dn = \"dc=example,dc=com\"")
+                .unwrap();
+        let args = config.search_args();
+        assert!(!args.contains(&"-D".to_string()));
+        assert_eq!(
+            args[args.len() - 5..],
+            ["(objectClass=person)", "cn", "title", "mail", "department"]
+        );
+
+        config.bind_dn = "cn=reader,dc=example,dc=com".to_string();
+        config.bind_password = "hunter2".to_string();
+        let args = config.search_args();
+        assert!(args.windows(2).any(|pair| pair == ["-y", "/dev/stdin"]));
+        assert!(!args.iter().any(|arg| arg.contains("hunter2")));
+    }
+
+    #[test]
+    fn test_plan_and_apply() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        fs::write(
+            data_path.employees_dir.join("Ann Lee.toml"),
+            "name = \"Ann Lee\"\ntitle = \"Engineer\"\nteam = \"Core\"\nlocation = \"Berlin\"\n",
+        )
+        .unwrap();
+        fs::write(
+            data_path.employees_dir.join("Bob.toml"),
+            "name = \"Bob\"\ntitle = \"Engineer\"\ncommitter_email = \"bob@example.com\"\n",
+        )
+        .unwrap();
+
+        let records = parse_csv(
+            "name,title,email,team\n\
+             ann lee,Senior Engineer,,Core\n\
+             Robert,Engineer,BOB@example.com,\n\
+             Carol,Designer,carol@example.com,Web\n\
+             Carol,Designer,,\n\
+             Dan,,dan@example.com,\n\
+             Eve/Admin,Engineer,,\n",
+        )
+        .unwrap();
+
+        let plan =
+            EmployeeImportService::plan(&data_path, records.clone(), OnConflict::Skip).unwrap();
+        let actions: Vec<(&str, &ImportAction)> = plan
+            .iter()
+            .map(|planned| (planned.record.name.as_str(), &planned.action))
+            .collect();
+        assert_eq!(
+            actions[..4],
+            [
+                (
+                    "ann lee",
+                    &ImportAction::Skip("'Ann Lee' already exists".to_string())
+                ),
+                (
+                    "Robert",
+                    &ImportAction::Skip("'Bob' already exists".to_string())
+                ),
+                ("Carol", &ImportAction::Create),
+                (
+                    "Carol",
+                    &ImportAction::Skip("same person as line 4".to_string())
+                ),
+            ]
+        );
+        assert_eq!(actions[4].1, &ImportAction::Skip("no title".to_string()));
+        assert!(matches!(actions[5].1, ImportAction::Skip(reason) if reason.contains("invalid")));
+
+        let error = EmployeeImportService::plan(&data_path, records.clone(), OnConflict::Abort)
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert!(error.to_string().contains("Ann Lee, Robert (as Bob)"));
+
+        let plan = EmployeeImportService::plan(&data_path, records, OnConflict::Update).unwrap();
+        assert_eq!(
+            plan[0].action,
+            ImportAction::Update(vec![FieldUpdate {
+                field: EditableField::Title,
+                old: "Engineer".to_string(),
+                new: "Senior Engineer".to_string(),
+            }])
+        );
+        assert_eq!(
+            plan[1].action,
+            ImportAction::Skip("'Bob' is already up to date".to_string())
+        );
+        EmployeeImportService::apply(&data_path, &plan).unwrap();
+
+        let ann = EmployeeService::get_employee(&data_path, "Ann Lee").unwrap();
+        assert_eq!(ann.title, "Senior Engineer");
+        assert_eq!(ann.profile.location.as_deref(), Some("Berlin"));
+        let carol = EmployeeService::get_employee(&data_path, "Carol").unwrap();
+        assert_eq!(carol.committer_email.as_deref(), Some("carol@example.com"));
+        assert_eq!(carol.profile.team.as_deref(), Some("Web"));
+        assert_eq!(
+            EmployeeService::list_employees(&data_path).unwrap(),
+            vec!["Ann Lee", "Bob", "Carol"]
+        );
+    }
+}
@@ -0,0 +1,152 @@
+//! Last known employee list with titles, emails and last notes dates, so the selector can show it before the employees
+//! directory has been read, which is slow on network filesystems
+
+use crate::employee::EmployeeService;
+use crate::models::DataPath;
+use crate::notes::NotesService;
+use chrono::NaiveDate;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedEmployee {
+    /// File name without `.toml`, as used on the command line
+    pub name: String,
+    /// Empty when the employee file could not be read
+    #[serde(default)]
+    pub title: String,
+    /// Committer email; empty when not set
+    #[serde(default)]
+    pub email: String,
+    /// Date of the newest dated notes entry; None without plain-text notes entries
+    #[serde(default)]
+    pub last_note: Option<NaiveDate>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct IndexFile {
+    employees: Vec<IndexedEmployee>,
+}
+
+pub struct EmployeeIndexService;
+
+impl EmployeeIndexService {
+    fn index_path(data_path: &DataPath) -> PathBuf {
+        data_path.root.join("employee_index.json")
+    }
+
+    /// The employees as of the last scan, sorted by name; None before the first scan or when the
+    /// index is unreadable
+    pub fn load(data_path: &DataPath) -> Option<Vec<IndexedEmployee>> {
+        let path = Self::index_path(data_path);
+        let content = fs::read_to_string(&path).ok()?;
+        match serde_json::from_str::<IndexFile>(&content) {
+            Ok(index) => Some(index.employees),
+            Err(e) => {
+                warn!("Ignoring unreadable {}: {e}", path.display());
+                None
+            }
+        }
+    }
+
+    /// Read every employee file and return them sorted by name
+    pub fn scan(data_path: &DataPath) -> io::Result<Vec<IndexedEmployee>> {
+        EmployeeService::list_employees(data_path).map(|names| {
+            names
+                .into_iter()
+                .map(|name| {
+                    let (title, email) = match EmployeeService::get_employee(data_path, &name) {
+                        Ok(employee) => {
+                            (employee.title, employee.committer_email.unwrap_or_default())
+                        }
+                        Err(e) => {
+                            warn!("Failed to read employee {name}: {e}");
+                            (String::new(), String::new())
+                        }
+                    };
+                    let last_note = NotesService::last_entry_date(data_path, &name);
+                    IndexedEmployee {
+                        name,
+                        title,
+                        email,
+                        last_note,
+                    }
+                })
+                .collect()
+        })
+    }
+
+    /// Scan the employees directory and store the result when it differs from the index
+    pub fn rebuild(data_path: &DataPath) -> io::Result<Vec<IndexedEmployee>> {
+        let employees = Self::scan(data_path)?;
+        if Self::load(data_path).as_ref() != Some(&employees) {
+            Self::save(data_path, &employees)?;
+        }
+        Ok(employees)
+    }
+
+    fn save(data_path: &DataPath, employees: &[IndexedEmployee]) -> io::Result<()> {
+        fs::create_dir_all(&data_path.root)?;
+        let content = serde_json::to_string_pretty(&IndexFile {
+            employees: employees.to_vec(),
+        })
+        .map_err(io::Error::other)?;
+        fs::write(Self::index_path(data_path), content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_rebuild_and_load() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        assert_eq!(EmployeeIndexService::load(&data_path), None);
+
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        fs::write(
+            data_path.employees_dir.join("john-doe.toml"),
+            "name = \"John Doe\"\ntitle = \"Engineer\"\ncommitter_email = \"john@example.com\"",
+        )
+        .unwrap();
+        fs::write(data_path.employees_dir.join("broken.toml"), "not toml").unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        fs::write(
+            data_path.notes_dir.join("john-doe.md"),
+            "# Notes\n\n## 2024-03-04 1:1\nRelease plan\n",
+        )
+        .unwrap();
+
+        let employees = EmployeeIndexService::rebuild(&data_path).unwrap();
+        assert_eq!(
+            employees,
+            vec![
+                IndexedEmployee {
+                    name: "broken".to_string(),
+                    title: String::new(),
+                    email: String::new(),
+                    last_note: None,
+                },
+                IndexedEmployee {
+                    name: "john-doe".to_string(),
+                    title: "Engineer".to_string(),
+                    email: "john@example.com".to_string(),
+                    last_note: NaiveDate::from_ymd_opt(2024, 3, 4),
+                },
+            ]
+        );
+        assert_eq!(EmployeeIndexService::load(&data_path), Some(employees));
+
+        // The index outlives the files until the next rebuild
+        fs::remove_file(data_path.employees_dir.join("broken.toml")).unwrap();
+        assert_eq!(EmployeeIndexService::load(&data_path).unwrap().len(), 2);
+        EmployeeIndexService::rebuild(&data_path).unwrap();
+        assert_eq!(EmployeeIndexService::load(&data_path).unwrap().len(), 1);
+    }
+}
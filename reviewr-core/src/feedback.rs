@@ -0,0 +1,345 @@
+//! Peer feedback requests: who an employee worked with in a period, derived from the people
+//! recorded on activity items, and the request text to send them
+
+use crate::models::Employee;
+use crate::platform::{ActivityCategory, ActivityItem, DetailedActivities};
+use crate::timestamps::parse_timestamp;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+
+/// Item metadata naming the other people involved: change owners, reviewers, issue authors,
+/// assignees and whoever merged
+const PEOPLE_KEYS: &[&str] = &["owner_name", "reviewers", "author", "assignee", "merged_by"];
+
+/// Someone who worked with the employee in the period
+#[derive(Debug, Clone)]
+pub struct Collaborator {
+    pub name: String,
+    /// Items shared with the employee, most recently updated first
+    pub items: Vec<ActivityItem>,
+}
+
+pub struct FeedbackService;
+
+impl FeedbackService {
+    /// People named in an item's metadata
+    pub fn people(item: &ActivityItem) -> Vec<String> {
+        let mut people: Vec<String> = PEOPLE_KEYS
+            .iter()
+            .filter_map(|key| item.metadata.get(*key))
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .collect();
+        people.sort();
+        people.dedup();
+        people
+    }
+
+    /// Whether a name from item metadata refers to `employee`: their name, email or one of their
+    /// platform usernames
+    fn is_employee(employee: &Employee, person: &str) -> bool {
+        std::iter::once(employee.name.as_str())
+            .chain(employee.committer_email.as_deref())
+            .chain(employee.usernames.values().map(String::as_str))
+            .any(|identity| identity.eq_ignore_ascii_case(person))
+    }
+
+    /// Collaborators of `employee`, most shared items first. `own` is the employee's activity;
+    /// `others` is other tracked employees' activity, where items naming `employee` make that
+    /// employee a collaborator too.
+    pub fn collaborators<'a>(
+        employee: &Employee,
+        own: impl IntoIterator<Item = &'a DetailedActivities>,
+        others: impl IntoIterator<Item = (&'a str, &'a DetailedActivities)>,
+    ) -> Vec<Collaborator> {
+        // Keyed by lowercase name so "Ann" and "ann" on different platforms are one person
+        let mut shared: HashMap<String, (String, Vec<&ActivityItem>)> = HashMap::new();
+        let mut add = |name: &str, item: &'a ActivityItem| {
+            let (_, items) = shared
+                .entry(name.to_lowercase())
+                .or_insert_with(|| (name.to_string(), Vec::new()));
+            if !items
+                .iter()
+                .any(|known| known.platform == item.platform && known.id == item.id)
+            {
+                items.push(item);
+            }
+        };
+
+        for item in own.into_iter().flat_map(all_items) {
+            for person in Self::people(item) {
+                if !Self::is_employee(employee, &person) {
+                    add(&person, item);
+                }
+            }
+        }
+        for (other, activities) in others {
+            if Self::is_employee(employee, other) {
+                continue;
+            }
+            for item in all_items(activities) {
+                if Self::people(item)
+                    .iter()
+                    .any(|person| Self::is_employee(employee, person))
+                {
+                    add(other, item);
+                }
+            }
+        }
+
+        let mut collaborators: Vec<Collaborator> = shared
+            .into_values()
+            .map(|(name, items)| Collaborator {
+                name,
+                items: newest_first(items),
+            })
+            .collect();
+        collaborators.sort_by(|a, b| {
+            b.items
+                .len()
+                .cmp(&a.items.len())
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        collaborators
+    }
+
+    /// Up to `max_items` items covering as many categories as possible: the newest item of
+    /// each category in turn, largest categories first
+    pub fn representative_items<'a>(
+        activities: impl IntoIterator<Item = &'a DetailedActivities>,
+        max_items: usize,
+    ) -> Vec<ActivityItem> {
+        let mut by_category: BTreeMap<&str, (&ActivityCategory, Vec<&ActivityItem>)> =
+            BTreeMap::new();
+        for (category, items) in activities
+            .into_iter()
+            .flat_map(|activities| &activities.items_by_category)
+        {
+            by_category
+                .entry(category.display_name())
+                .or_insert_with(|| (category, Vec::new()))
+                .1
+                .extend(items);
+        }
+        let mut queues: Vec<Vec<ActivityItem>> = by_category
+            .into_values()
+            .map(|(_, items)| newest_first(items))
+            .collect();
+        queues.sort_by_key(|items| std::cmp::Reverse(items.len()));
+        for queue in &mut queues {
+            queue.reverse();
+        }
+
+        let mut picked = Vec::new();
+        while picked.len() < max_items && queues.iter().any(|queue| !queue.is_empty()) {
+            for queue in &mut queues {
+                if picked.len() == max_items {
+                    break;
+                }
+                if let Some(item) = queue.pop() {
+                    picked.push(item);
+                }
+            }
+        }
+        picked
+    }
+
+    /// Markdown/plain-text request asking `recipient` (or an unnamed colleague) for feedback on
+    /// `employee`, listing `items` as reminders of the shared work
+    pub fn request_text(
+        employee: &Employee,
+        recipient: Option<&str>,
+        items: &[ActivityItem],
+        period_days: u32,
+    ) -> String {
+        let name = &employee.name;
+        let mut out = String::new();
+        let _ = writeln!(out, "Subject: Feedback for {name}\n");
+        let _ = writeln!(out, "Hi {},\n", recipient.unwrap_or("there"));
+        let _ = writeln!(
+            out,
+            "I'm collecting feedback for {name}'s review covering the last {period_days} days.\n"
+        );
+        if !items.is_empty() {
+            let _ = writeln!(out, "Some of the work you were involved in:\n");
+            for item in items {
+                let link = if item.url.is_empty() {
+                    item.title.clone()
+                } else {
+                    format!("[{}]({})", item.title, item.url)
+                };
+                let _ = writeln!(
+                    out,
+                    "- {link} ({}, {})",
+                    item.category.display_name(),
+                    item.platform
+                );
+            }
+            let _ = writeln!(out);
+        }
+        let _ = writeln!(out, "Could you share a few sentences on:\n");
+        let _ = writeln!(out, "- What went well when working with {name}?");
+        let _ = writeln!(out, "- What could {name} do more of, or differently?");
+        let _ = writeln!(out, "- Anything else that should be part of the review?\n");
+        let _ = writeln!(out, "Thank you!");
+        out
+    }
+}
+
+fn all_items(activities: &DetailedActivities) -> impl Iterator<Item = &ActivityItem> {
+    activities.items_by_category.values().flatten()
+}
+
+fn newest_first(mut items: Vec<&ActivityItem>) -> Vec<ActivityItem> {
+    items.sort_by_key(|item| std::cmp::Reverse(parse_timestamp(&item.updated)));
+    items.into_iter().cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::ItemBuilder;
+
+    fn item(
+        id: &str,
+        category: ActivityCategory,
+        updated: &str,
+        people: &[(&str, &str)],
+    ) -> ActivityItem {
+        people
+            .iter()
+            .fold(
+                ItemBuilder::new(id)
+                    .with_created(updated)
+                    .with_updated(updated)
+                    .with_url(&format!("https://review.example.com/{id}"))
+                    .with_category(category),
+                |item, (key, value)| item.with_metadata(key, value),
+            )
+            .build()
+    }
+
+    fn activities(items: Vec<ActivityItem>) -> DetailedActivities {
+        let mut activities = DetailedActivities::default();
+        for item in items {
+            activities
+                .items_by_category
+                .entry(item.category.clone())
+                .or_default()
+                .push(item);
+        }
+        activities
+    }
+
+    fn jane() -> Employee {
+        Employee {
+            name: "Jane Doe".to_string(),
+            usernames: [("gerrit".to_string(), "jdoe".to_string())].into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_collaborators_from_own_and_others_activity() {
+        let own = activities(vec![
+            item(
+                "1",
+                ActivityCategory::ChangesMerged,
+                "2024-01-10T10:00:00Z",
+                &[("reviewers", "Ann, Bob, jdoe")],
+            ),
+            item(
+                "2",
+                ActivityCategory::ChangesMerged,
+                "2024-01-12T10:00:00Z",
+                &[("reviewers", "ann")],
+            ),
+            item(
+                "3",
+                ActivityCategory::ReviewsGiven,
+                "2024-01-11T10:00:00Z",
+                &[("owner_name", "Carl")],
+            ),
+        ]);
+        // Bob is tracked too and Jane reviewed one of his changes
+        let bobs = activities(vec![item(
+            "4",
+            ActivityCategory::ChangesMerged,
+            "2024-01-13T10:00:00Z",
+            &[("reviewers", "Jane Doe")],
+        )]);
+
+        let collaborators =
+            FeedbackService::collaborators(&jane(), [&own], [("Bob", &bobs), ("Jane Doe", &own)]);
+        let summary: Vec<(&str, Vec<&str>)> = collaborators
+            .iter()
+            .map(|c| {
+                (
+                    c.name.as_str(),
+                    c.items.iter().map(|i| i.id.as_str()).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Ann", vec!["2", "1"]),
+                ("Bob", vec!["4", "1"]),
+                ("Carl", vec!["3"])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_representative_items_cover_categories() {
+        let own = activities(vec![
+            item(
+                "1",
+                ActivityCategory::ChangesMerged,
+                "2024-01-10T10:00:00Z",
+                &[],
+            ),
+            item(
+                "2",
+                ActivityCategory::ChangesMerged,
+                "2024-01-12T10:00:00Z",
+                &[],
+            ),
+            item(
+                "3",
+                ActivityCategory::ChangesMerged,
+                "2024-01-09T10:00:00Z",
+                &[],
+            ),
+            item(
+                "4",
+                ActivityCategory::ReviewsGiven,
+                "2024-01-01T10:00:00Z",
+                &[],
+            ),
+        ]);
+        let picked = FeedbackService::representative_items([&own], 3);
+        let ids: Vec<&str> = picked.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["2", "4", "1"]);
+    }
+
+    #[test]
+    fn test_request_text() {
+        let items = [item(
+            "7",
+            ActivityCategory::ChangesMerged,
+            "2024-01-10T10:00:00Z",
+            &[],
+        )];
+        let text = FeedbackService::request_text(&jane(), Some("Ann"), &items, 90);
+        assert!(text.starts_with("Subject: Feedback for Jane Doe\n\nHi Ann,\n"));
+        assert!(text.contains("covering the last 90 days"));
+        assert!(text.contains("- [Item 7](https://review.example.com/7) (Changes Merged, gerrit)"));
+
+        let text = FeedbackService::request_text(&jane(), None, &[], 30);
+        assert!(text.contains("Hi there,"));
+        assert!(!text.contains("Some of the work"));
+    }
+}
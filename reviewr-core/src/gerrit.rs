@@ -0,0 +1,2007 @@
+use crate::accounts::{AccountCache, AccountCandidate};
+use crate::category_rules::CategoryRules;
+use crate::change_size::{DELETIONS_KEY, INSERTIONS_KEY};
+use crate::http::{HttpClientSettings, PlatformClient, RetryPolicy, api_error};
+use crate::http_cache::ResponseCache;
+use crate::models::DataPath;
+use crate::platform::{
+    ActivityCategory, ActivityItem, ActivityMetrics as PlatformActivityMetrics, ConnectionStatus,
+    DETAILS_PENDING_KEY, DetailedActivities, ErrorContext, PlannedRequest, PlatformCapabilities,
+    QueryPlan, ReviewPlatform, Severity,
+};
+use crate::redact::{mask_secret, redact_url};
+use crate::timestamps::TimestampSource;
+use crate::unified_config::{
+    DEFAULT_INSTANCE, PerformanceConfig, RequestLimits, UnifiedConfigService, instance_platform_id,
+};
+use async_trait::async_trait;
+use base64::Engine;
+use log::{info, warn};
+use reqwest::StatusCode;
+use reqwest::header::WWW_AUTHENTICATE;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+
+/// Change options of the activity searches; servers before 3.5 are asked again with labels only
+const CHANGE_OPTIONS: &str = "&o=LABELS&o=SUBMIT_REQUIREMENTS";
+/// Change options of the reviews received search, which needs every vote and comment
+const FEEDBACK_OPTIONS: &str = "&o=DETAILED_LABELS&o=MESSAGES";
+/// Pages of `page_size` changes read per search before the rest is given up on
+pub const MAX_CHANGE_PAGES: usize = 20;
+
+/// Item metadata of a change whose details were loaded: the non-zero votes per label, e.g.
+/// `Code-Review: +2 Ann, -1 Bob; Verified: +1 CI`
+pub const VOTES_KEY: &str = "votes";
+/// Item metadata of a change whose details were loaded: its reviewers, e.g. `Ann, Bob (CC: Eve)`
+pub const REVIEWER_LIST_KEY: &str = "reviewer_list";
+/// Item metadata of a change whose details were loaded: its review messages, oldest first, one
+/// `date author: text` line each
+pub const REVIEW_MESSAGES_KEY: &str = "review_messages";
+
+/// Review messages longer than this are cut in the details
+const MESSAGE_PREVIEW_CHARS: usize = 200;
+
+/// Changes owned by `user` updated in the last `days` days
+fn owned_changes_query(user: &str, days: u32) -> String {
+    format!("owner:{user} -age:{days}d")
+}
+
+fn merged_changes_query(user: &str, days: u32) -> String {
+    format!("owner:{user} status:merged -age:{days}d")
+}
+
+fn reviewed_changes_query(user: &str, days: u32) -> String {
+    format!("reviewer:{user} -age:{days}d")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GerritConfig {
+    pub gerrit_url: String,
+    pub username: String,
+    pub http_password: String,
+    /// Skip TLS certificate verification, e.g. for a test server with a self-signed certificate
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub accept_invalid_certs: bool,
+    #[serde(flatten)]
+    pub limits: RequestLimits,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ActivityMetrics {
+    pub commits_merged: u32,
+    pub changes_created: u32,
+    pub reviews_given: u32,
+    pub reviews_received: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeInfo {
+    pub id: String,
+    pub change_id: String,
+    pub subject: String,
+    pub status: String,
+    pub created: String,
+    pub updated: String,
+    pub project: String,
+    #[serde(rename = "_number")]
+    pub number: u32,
+    pub owner: Owner,
+    /// Votes summary per label, requested with `o=LABELS`
+    #[serde(default)]
+    pub labels: HashMap<String, LabelInfo>,
+    /// Requested with `o=SUBMIT_REQUIREMENTS` (Gerrit 3.5+)
+    #[serde(default)]
+    pub submit_requirements: Vec<SubmitRequirement>,
+    /// Only reported when the server computes mergeability
+    #[serde(default)]
+    pub mergeable: Option<bool>,
+    /// Review messages, requested with `o=MESSAGES`
+    #[serde(default)]
+    pub messages: Vec<ChangeMessage>,
+    /// Lines added and removed by the current patch set
+    #[serde(default)]
+    pub insertions: Option<u32>,
+    #[serde(default)]
+    pub deletions: Option<u32>,
+    #[serde(default)]
+    pub hashtags: Vec<String>,
+    /// Accounts by reviewer state (`REVIEWER`, `CC`), reported by `/detail`
+    #[serde(default)]
+    pub reviewers: HashMap<String, Vec<Owner>>,
+    /// Set on the last change of a page when the server has more matches
+    #[serde(
+        rename = "_more_changes",
+        default,
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub more_changes: bool,
+}
+
+/// Summary of a label; each field holds the account of the deciding vote when set
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LabelInfo {
+    #[serde(default)]
+    pub approved: Option<serde_json::Value>,
+    #[serde(default)]
+    pub rejected: Option<serde_json::Value>,
+    /// Every reviewer's vote, requested with `o=DETAILED_LABELS`
+    #[serde(default)]
+    pub all: Vec<ApprovalInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApprovalInfo {
+    #[serde(flatten)]
+    pub account: Owner,
+    /// 0 or absent when the reviewer has not voted on the label
+    #[serde(default)]
+    pub value: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeMessage {
+    /// Absent for messages posted by Gerrit itself
+    #[serde(default)]
+    pub author: Option<Owner>,
+    /// e.g. `autogenerated:gerrit:newPatchSet` for messages not written by a person
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub date: Option<String>,
+}
+
+/// Votes and comments other people left on a change
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReviewFeedback {
+    /// Reviewer names, sorted and without duplicates
+    pub reviewers: Vec<String>,
+    pub votes: usize,
+    pub comments: usize,
+}
+
+impl ReviewFeedback {
+    pub fn is_empty(&self) -> bool {
+        self.votes == 0 && self.comments == 0
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitRequirement {
+    pub name: String,
+    /// SATISFIED, UNSATISFIED, OVERRIDDEN, NOT_APPLICABLE, ERROR or FORCED
+    pub status: String,
+}
+
+/// CI result of a change, read from its `Verified` label
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CiVerdict {
+    Passing,
+    Failing,
+    Pending,
+}
+
+impl CiVerdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CiVerdict::Passing => "passing",
+            CiVerdict::Failing => "failing",
+            CiVerdict::Pending => "pending",
+        }
+    }
+}
+
+impl ChangeInfo {
+    /// `None` when the change has no `Verified` label, e.g. in projects without CI
+    pub fn ci_verdict(&self) -> Option<CiVerdict> {
+        let verified = self.labels.get("Verified")?;
+        Some(if verified.rejected.is_some() {
+            CiVerdict::Failing
+        } else if verified.approved.is_some() {
+            CiVerdict::Passing
+        } else {
+            CiVerdict::Pending
+        })
+    }
+
+    /// e.g. "Code-Review: SATISFIED, Verified: UNSATISFIED"
+    pub fn submit_requirements_summary(&self) -> Option<String> {
+        let summary: Vec<String> = self
+            .submit_requirements
+            .iter()
+            .filter(|requirement| requirement.status != "NOT_APPLICABLE")
+            .map(|requirement| format!("{}: {}", requirement.name, requirement.status))
+            .collect();
+        (!summary.is_empty()).then(|| summary.join(", "))
+    }
+
+    fn is_owner(&self, account: &Owner) -> bool {
+        match (self.owner.account_id, account.account_id) {
+            (Some(owner), Some(other)) => owner == other,
+            _ => {
+                (account.email.is_some() && account.email == self.owner.email)
+                    || (account.name.is_some() && account.name == self.owner.name)
+            }
+        }
+    }
+
+    /// Non-zero votes and human comments by anyone but the owner, from `o=DETAILED_LABELS`
+    /// and `o=MESSAGES`. `Verified` votes come from CI, so they are not counted as review.
+    pub fn review_feedback(&self) -> ReviewFeedback {
+        let mut reviewers = Vec::new();
+        let votes: Vec<&Owner> = self
+            .labels
+            .iter()
+            .filter(|(label, _)| label.as_str() != "Verified")
+            .flat_map(|(_, label)| &label.all)
+            .filter(|approval| approval.value.is_some_and(|value| value != 0))
+            .map(|approval| &approval.account)
+            .filter(|account| !self.is_owner(account))
+            .collect();
+        let comments: Vec<&Owner> = self
+            .messages
+            .iter()
+            .filter(|message| {
+                !message
+                    .tag
+                    .as_deref()
+                    .is_some_and(|tag| tag.starts_with("autogenerated:"))
+            })
+            .filter_map(|message| message.author.as_ref())
+            .filter(|account| !self.is_owner(account))
+            .collect();
+        for account in votes.iter().chain(&comments) {
+            reviewers.push(account.display_name());
+        }
+        reviewers.sort();
+        reviewers.dedup();
+        ReviewFeedback {
+            reviewers,
+            votes: votes.len(),
+            comments: comments.len(),
+        }
+    }
+
+    /// Every non-zero vote per label, from `o=DETAILED_LABELS` or `/detail`
+    pub fn votes_summary(&self) -> Option<String> {
+        let mut labels: Vec<(&String, &LabelInfo)> = self.labels.iter().collect();
+        labels.sort_by_key(|(name, _)| *name);
+        let summary: Vec<String> = labels
+            .into_iter()
+            .filter_map(|(name, label)| {
+                let votes: Vec<String> = label
+                    .all
+                    .iter()
+                    .filter_map(|approval| {
+                        let value = approval.value.filter(|value| *value != 0)?;
+                        Some(format!("{value:+} {}", approval.account.display_name()))
+                    })
+                    .collect();
+                (!votes.is_empty()).then(|| format!("{name}: {}", votes.join(", ")))
+            })
+            .collect();
+        (!summary.is_empty()).then(|| summary.join("; "))
+    }
+
+    /// Reviewers and CCs from `/detail`, e.g. `Ann, Bob (CC: Eve)`
+    pub fn reviewers_summary(&self) -> Option<String> {
+        let names = |state: &str| -> Vec<String> {
+            let mut names: Vec<String> = self
+                .reviewers
+                .get(state)
+                .into_iter()
+                .flatten()
+                .map(Owner::display_name)
+                .collect();
+            names.sort();
+            names
+        };
+        let (reviewers, cc) = (names("REVIEWER"), names("CC"));
+        match (reviewers.is_empty(), cc.is_empty()) {
+            (true, true) => None,
+            (false, true) => Some(reviewers.join(", ")),
+            (true, false) => Some(format!("CC: {}", cc.join(", "))),
+            (false, false) => Some(format!("{} (CC: {})", reviewers.join(", "), cc.join(", "))),
+        }
+    }
+
+    /// The review messages, oldest first, as `2024-01-15 10:30 Ann: text` on one line each
+    pub fn review_message_lines(&self) -> Vec<String> {
+        self.messages
+            .iter()
+            .map(|message| {
+                let date = message.date.as_deref().unwrap_or_default();
+                let author = message
+                    .author
+                    .as_ref()
+                    .map_or_else(|| "Gerrit".to_string(), Owner::display_name);
+                let text = message
+                    .message
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let text = match text.char_indices().nth(MESSAGE_PREVIEW_CHARS) {
+                    Some((end, _)) => format!("{}…", &text[..end]),
+                    None => text,
+                };
+                format!("{} {author}: {text}", date.get(..16).unwrap_or(date))
+                    .trim_start()
+                    .to_string()
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Owner {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    #[serde(
+        rename = "_account_id",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub account_id: Option<u64>,
+}
+
+impl Owner {
+    /// Name, else email, else the numeric account id
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .or_else(|| self.email.clone())
+            .unwrap_or_else(|| match self.account_id {
+                Some(id) => format!("account {id}"),
+                None => "unknown".to_string(),
+            })
+    }
+}
+
+/// An entry of `/a/accounts/?o=DETAILS`
+#[derive(Debug, Deserialize)]
+struct AccountInfo {
+    #[serde(rename = "_account_id")]
+    account_id: u64,
+    email: Option<String>,
+    #[serde(default)]
+    secondary_emails: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DetailedActivityMetrics {
+    pub commits_merged: Vec<ChangeInfo>,
+    pub changes_created: Vec<ChangeInfo>,
+    pub reviews_given: Vec<ChangeInfo>,
+    pub reviews_received: Vec<ChangeInfo>,
+}
+
+pub struct GerritClient {
+    client: PlatformClient,
+    /// Platform id errors are logged under, e.g. `gerrit:<instance>`
+    platform_id: String,
+    base_url: String,
+    auth_header: String,
+    response_cache: Option<ResponseCache>,
+    retry: RetryPolicy,
+    limits: RequestLimits,
+    page_size: u32,
+}
+
+impl GerritClient {
+    pub fn new(config: &GerritConfig, http: &HttpClientSettings) -> io::Result<Self> {
+        let limits = config.limits.clone();
+        let client = http.platform_client(
+            config.accept_invalid_certs,
+            limits.max_concurrent_requests(&PerformanceConfig::default()),
+            limits.request_timeout(),
+        )?;
+
+        let credentials = format!("{}:{}", config.username, config.http_password);
+        let auth_header = format!(
+            "Basic {}",
+            base64::engine::general_purpose::STANDARD.encode(credentials)
+        );
+
+        let base_url = config.gerrit_url.trim_end_matches('/').to_string();
+
+        info!("Created Gerrit client for {base_url}");
+
+        Ok(Self {
+            client,
+            platform_id: "gerrit".to_string(),
+            base_url,
+            auth_header,
+            response_cache: None,
+            retry: RetryPolicy::default(),
+            limits,
+            page_size: PerformanceConfig::default().page_size,
+        })
+    }
+
+    /// Apply the `[performance]` page size, and its request concurrency unless the instance
+    /// sets its own
+    pub fn with_performance(mut self, performance: &PerformanceConfig) -> Self {
+        self.client = self
+            .client
+            .with_max_concurrent_requests(self.limits.max_concurrent_requests(performance));
+        self.page_size = performance.page_size.max(1);
+        self
+    }
+
+    /// Log errors under the platform id of a named instance
+    pub fn with_platform_id(mut self, platform_id: &str) -> Self {
+        self.platform_id = platform_id.to_string();
+        self
+    }
+
+    /// Send conditional requests, reusing stored results the server reports as unchanged
+    pub fn with_response_cache(mut self, cache: ResponseCache) -> Self {
+        self.response_cache = Some(cache);
+        self
+    }
+
+    /// Retry transient failures according to `retry`
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub async fn get_activity_metrics(
+        &self,
+        email: &str,
+        days: u32,
+    ) -> io::Result<ActivityMetrics> {
+        info!("Fetching activity metrics for {email} (last {days} days)");
+
+        // Queries run concurrently, bounded by the client's request permits
+        let (changes_created, commits_merged, reviews_given, reviews_received) = tokio::try_join!(
+            self.get_changes_created(email, days),
+            self.get_changes_merged(email, days),
+            self.get_reviews_given(email, days),
+            self.get_reviews_received(email, days),
+        )?;
+        let metrics = ActivityMetrics {
+            changes_created,
+            commits_merged,
+            reviews_given,
+            reviews_received,
+        };
+
+        info!("Activity metrics for {email}: {metrics:?}");
+        Ok(metrics)
+    }
+
+    async fn get_changes_created(&self, email: &str, days: u32) -> io::Result<u32> {
+        self.query_changes(&owned_changes_query(email, days)).await
+    }
+
+    async fn get_changes_merged(&self, email: &str, days: u32) -> io::Result<u32> {
+        self.query_changes(&merged_changes_query(email, days)).await
+    }
+
+    async fn get_reviews_given(&self, email: &str, days: u32) -> io::Result<u32> {
+        self.query_changes(&reviewed_changes_query(email, days))
+            .await
+    }
+
+    async fn get_reviews_received(&self, email: &str, days: u32) -> io::Result<u32> {
+        let reviewed = self.get_detailed_reviews_received(email, days).await?;
+        Ok(reviewed.len() as u32)
+    }
+
+    async fn query_changes(&self, query: &str) -> io::Result<u32> {
+        info!("Querying Gerrit: {query}");
+        let changes = self.query_all_changes("query_changes", query, "").await?;
+        Ok(changes.len() as u32)
+    }
+
+    /// Check the credentials against `/a/accounts/self`, telling a wrong HTTP password apart
+    /// from unsupported authentication schemes and proxies or SSO pages in front of Gerrit
+    pub async fn check_account(&self) -> ConnectionStatus {
+        let url = format!("{}/a/accounts/self", self.base_url);
+        let request = match self
+            .client
+            .get(&url)
+            .header("Authorization", &self.auth_header)
+            .build()
+        {
+            Ok(request) => request,
+            Err(e) => return ConnectionStatus::Error(format!("Invalid Gerrit URL: {e}")),
+        };
+
+        let response = match self.client.execute(request, &self.retry).await {
+            Ok(response) => response,
+            Err(e) => {
+                // A timeout is often transient; logged as a warning it still shows up in history
+                let severity = if e.is_timeout() {
+                    Severity::Warning
+                } else {
+                    Severity::Error
+                };
+                ErrorContext::new(&self.platform_id, "test_connection")
+                    .with_severity(severity)
+                    .with_error("network_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .log_error();
+                return if e.is_timeout() {
+                    ConnectionStatus::Warning("Connection timeout".to_string())
+                } else {
+                    ConnectionStatus::Error(format!("Cannot reach {}: {e}", self.base_url))
+                };
+            }
+        };
+
+        let status = response.status();
+        let final_url = response.url().to_string();
+        let www_authenticate = response
+            .headers()
+            .get(WWW_AUTHENTICATE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = response.text().await.unwrap_or_default();
+
+        let result =
+            classify_account_response(status, www_authenticate.as_deref(), &final_url, &body);
+        if !result.is_ok() {
+            ErrorContext::new(&self.platform_id, "test_connection")
+                .with_error("api_error", &format!("HTTP {status}"))
+                .with_request_details(&final_url, Some(status.as_u16()), Some(&body))
+                .log_error();
+        }
+        result
+    }
+
+    /// GET a JSON endpoint of the accounts API; `None` when the server answers 404
+    async fn get_account_json<T: DeserializeOwned>(&self, url: &str) -> io::Result<Option<T>> {
+        let request = self
+            .client
+            .get(url)
+            .header("Authorization", &self.auth_header)
+            .build()
+            .map_err(|e| io::Error::other(format!("Invalid Gerrit URL: {e}")))?;
+        let response = self
+            .client
+            .execute(request, &self.retry)
+            .await
+            .map_err(|e| io::Error::other(format!("Gerrit API request failed: {e}")))?;
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            ErrorContext::new(&self.platform_id, "find_accounts")
+                .with_severity(Severity::Warning)
+                .with_error("api_error", &format!("HTTP {status}"))
+                .with_request_details(url, Some(status.as_u16()), Some(&text))
+                .log_error();
+            return Err(api_error("Gerrit", status, &text));
+        }
+        let json_text = text.strip_prefix(")]}'").unwrap_or(&text);
+        serde_json::from_str(json_text)
+            .map(Some)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}")))
+    }
+
+    /// Accounts with `email`, named by their numeric account id. The id stays the same when
+    /// the account's emails change, so queries by id also find changes made under old emails.
+    /// Searching `/a/accounts/?q=email:` can need extra permissions; when it finds nothing,
+    /// the account is looked up directly as `/a/accounts/<email>/detail`.
+    pub async fn find_accounts(&self, email: &str) -> io::Result<Vec<AccountCandidate>> {
+        let search_url = self.account_search_url(email);
+        let accounts = match self.get_account_json::<Vec<AccountInfo>>(&search_url).await {
+            Ok(Some(accounts)) if !accounts.is_empty() => accounts,
+            search => {
+                let detail_url = format!(
+                    "{}/a/accounts/{}/detail",
+                    self.base_url,
+                    urlencoding::encode(email)
+                );
+                match self.get_account_json::<AccountInfo>(&detail_url).await? {
+                    Some(account) => vec![account],
+                    None => {
+                        search?;
+                        Vec::new()
+                    }
+                }
+            }
+        };
+        Ok(accounts
+            .into_iter()
+            .map(|account| AccountCandidate {
+                username: account.account_id.to_string(),
+                emails: account
+                    .email
+                    .into_iter()
+                    .chain(account.secondary_emails)
+                    .collect(),
+            })
+            .collect())
+    }
+
+    /// The account to query for `user`: an email is resolved once to its account id through
+    /// `find_accounts`, usernames and ids are used as they are
+    pub async fn resolve_user(&self, accounts: &AccountCache, user: &str) -> String {
+        accounts
+            .resolve(&self.platform_id, user, || self.find_accounts(user))
+            .await
+    }
+
+    pub async fn get_detailed_activity_metrics(
+        &self,
+        email: &str,
+        days: u32,
+    ) -> io::Result<DetailedActivityMetrics> {
+        let [
+            commits_merged,
+            changes_created,
+            reviews_given,
+            reviews_received,
+        ] = self.get_detailed_activity_results(email, days).await;
+
+        Ok(DetailedActivityMetrics {
+            commits_merged: commits_merged.1?,
+            changes_created: changes_created.1?,
+            reviews_given: reviews_given.1?,
+            reviews_received: reviews_received.1?,
+        })
+    }
+
+    /// The changes of each activity category, or why they could not be fetched, so one failed
+    /// query doesn't hide the categories that loaded
+    pub async fn get_detailed_activity_results(
+        &self,
+        email: &str,
+        days: u32,
+    ) -> [(ActivityCategory, io::Result<Vec<ChangeInfo>>); 4] {
+        info!("Fetching detailed activity metrics for {email} (last {days} days)");
+
+        let (commits_merged, changes_created, reviews_given, reviews_received) = tokio::join!(
+            self.get_detailed_changes_merged(email, days),
+            self.get_detailed_changes_created(email, days),
+            self.get_detailed_reviews_given(email, days),
+            self.get_detailed_reviews_received(email, days),
+        );
+        [
+            (ActivityCategory::ChangesMerged, commits_merged),
+            (ActivityCategory::ChangesCreated, changes_created),
+            (ActivityCategory::ReviewsGiven, reviews_given),
+            (ActivityCategory::ReviewsReceived, reviews_received),
+        ]
+    }
+
+    async fn get_detailed_changes_created(
+        &self,
+        email: &str,
+        days: u32,
+    ) -> io::Result<Vec<ChangeInfo>> {
+        self.query_detailed_changes(&owned_changes_query(email, days))
+            .await
+    }
+
+    async fn get_detailed_changes_merged(
+        &self,
+        email: &str,
+        days: u32,
+    ) -> io::Result<Vec<ChangeInfo>> {
+        self.query_detailed_changes(&merged_changes_query(email, days))
+            .await
+    }
+
+    async fn get_detailed_reviews_given(
+        &self,
+        email: &str,
+        days: u32,
+    ) -> io::Result<Vec<ChangeInfo>> {
+        self.query_detailed_changes(&reviewed_changes_query(email, days))
+            .await
+    }
+
+    /// The employee's changes on which someone else voted or commented
+    async fn get_detailed_reviews_received(
+        &self,
+        email: &str,
+        days: u32,
+    ) -> io::Result<Vec<ChangeInfo>> {
+        let changes = self
+            .query_detailed_changes_with(&owned_changes_query(email, days), FEEDBACK_OPTIONS)
+            .await?;
+        Ok(changes
+            .into_iter()
+            .filter(|change| !change.review_feedback().is_empty())
+            .collect())
+    }
+
+    async fn query_detailed_changes(&self, query: &str) -> io::Result<Vec<ChangeInfo>> {
+        match self
+            .query_detailed_changes_with(query, CHANGE_OPTIONS)
+            .await
+        {
+            // Servers before 3.5 reject the unknown option; labels alone still give the CI verdict
+            Err(e) if e.to_string().contains("SUBMIT_REQUIREMENTS") => {
+                self.query_detailed_changes_with(query, "&o=LABELS").await
+            }
+            result => result,
+        }
+    }
+
+    async fn query_detailed_changes_with(
+        &self,
+        query: &str,
+        options: &str,
+    ) -> io::Result<Vec<ChangeInfo>> {
+        info!("Querying Gerrit for detailed changes: {query}");
+        self.query_all_changes("query_detailed_changes", query, options)
+            .await
+    }
+
+    /// Every change matching `query`, requesting the next page from where the last one ended
+    /// while the server reports `_more_changes`. Servers may return fewer changes per page than
+    /// asked for. After [`MAX_CHANGE_PAGES`] pages the rest is dropped with a warning.
+    async fn query_all_changes(
+        &self,
+        operation: &str,
+        query: &str,
+        options: &str,
+    ) -> io::Result<Vec<ChangeInfo>> {
+        let mut changes = Vec::new();
+        for _ in 0..MAX_CHANGE_PAGES {
+            let page = self
+                .query_changes_page(operation, query, options, changes.len())
+                .await?;
+            let more = page.last().is_some_and(|change| change.more_changes);
+            changes.extend(page);
+            if !more {
+                return Ok(changes);
+            }
+        }
+
+        warn!(
+            "Gerrit search '{query}' has more than {} changes; the rest is not counted",
+            changes.len()
+        );
+        ErrorContext::new(&self.platform_id, operation)
+            .with_severity(Severity::Warning)
+            .with_error(
+                "truncated",
+                &format!(
+                    "Stopped after {MAX_CHANGE_PAGES} pages ({} changes)",
+                    changes.len()
+                ),
+            )
+            .with_request_details(&self.changes_url(query, options, changes.len()), None, None)
+            .with_metadata("query", query)
+            .log_error();
+        Ok(changes)
+    }
+
+    /// The page of changes matching `query` that starts after the first `start` matches
+    async fn query_changes_page(
+        &self,
+        operation: &str,
+        query: &str,
+        options: &str,
+        start: usize,
+    ) -> io::Result<Vec<ChangeInfo>> {
+        let url = self.changes_url(query, options, start);
+
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", &self.auth_header);
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| {
+                ErrorContext::new(&self.platform_id, operation)
+                    .with_error("network_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .with_metadata("query", query)
+                    .log_error();
+                io::Error::other(format!("Gerrit API request failed: {e}"))
+            })?;
+
+        if !response.status.is_success() {
+            let status = response.status;
+            let error_text = response.body;
+            ErrorContext::new(&self.platform_id, operation)
+                .with_error("api_error", &format!("HTTP {status}"))
+                .with_request_details(&url, Some(status.as_u16()), Some(&error_text))
+                .with_metadata("query", query)
+                .log_error();
+            return Err(api_error("Gerrit", status, &error_text));
+        }
+
+        let text = response.body;
+
+        // Gerrit API responses start with ")]}'" to prevent JSON hijacking
+        let json_text = text.strip_prefix(")]}'").unwrap_or(&text);
+
+        let changes: Vec<ChangeInfo> = serde_json::from_str(json_text).map_err(|e| {
+            ErrorContext::new(&self.platform_id, operation)
+                .with_error("json_parse_error", &e.to_string())
+                .with_request_details(&url, None, Some(json_text))
+                .with_metadata("query", query)
+                .log_error();
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+        })?;
+
+        Ok(changes)
+    }
+
+    /// A change with every vote, its reviewers and its review messages, from
+    /// `/a/changes/{change}/detail`
+    pub async fn get_change_detail(&self, change: &str) -> io::Result<ChangeInfo> {
+        let url = format!(
+            "{}/a/changes/{}/detail",
+            self.base_url,
+            urlencoding::encode(change)
+        );
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", &self.auth_header);
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| {
+                ErrorContext::new(&self.platform_id, "get_change_detail")
+                    .with_error("network_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .with_metadata("change", change)
+                    .log_error();
+                io::Error::other(format!("Gerrit API request failed: {e}"))
+            })?;
+        if !response.status.is_success() {
+            ErrorContext::new(&self.platform_id, "get_change_detail")
+                .with_error("api_error", &format!("HTTP {}", response.status))
+                .with_request_details(&url, Some(response.status.as_u16()), Some(&response.body))
+                .with_metadata("change", change)
+                .log_error();
+            return Err(api_error("Gerrit", response.status, &response.body));
+        }
+        let text = response.body;
+        let json_text = text.strip_prefix(")]}'").unwrap_or(&text);
+        serde_json::from_str(json_text)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}")))
+    }
+
+    /// One page of changes matching `query`, with the change `options`, skipping the first
+    /// `start` matches
+    fn changes_url(&self, query: &str, options: &str, start: usize) -> String {
+        let mut url = format!(
+            "{}/a/changes/?q={}&n={}{options}",
+            self.base_url,
+            urlencoding::encode(query),
+            self.page_size
+        );
+        if start > 0 {
+            url.push_str(&format!("&start={start}"));
+        }
+        url
+    }
+
+    /// The searches of `get_detailed_activity_metrics` for the account `user`
+    pub fn planned_requests(&self, user: &str, days: u32) -> Vec<PlannedRequest> {
+        [
+            (
+                ActivityCategory::ChangesCreated,
+                owned_changes_query(user, days),
+                CHANGE_OPTIONS,
+            ),
+            (
+                ActivityCategory::ChangesMerged,
+                merged_changes_query(user, days),
+                CHANGE_OPTIONS,
+            ),
+            (
+                ActivityCategory::ReviewsGiven,
+                reviewed_changes_query(user, days),
+                CHANGE_OPTIONS,
+            ),
+            (
+                ActivityCategory::ReviewsReceived,
+                owned_changes_query(user, days),
+                FEEDBACK_OPTIONS,
+            ),
+        ]
+        .into_iter()
+        .map(|(category, query, options)| PlannedRequest {
+            label: category.display_name().to_string(),
+            request: format!("GET {}", redact_url(&self.changes_url(&query, options, 0))),
+            query: Some(query),
+        })
+        .collect()
+    }
+
+    /// The account search `find_accounts` starts with
+    fn account_search_url(&self, email: &str) -> String {
+        format!(
+            "{}/a/accounts/?q={}&o=DETAILS",
+            self.base_url,
+            urlencoding::encode(&format!("email:{email}"))
+        )
+    }
+
+    pub fn get_change_url(&self, project: &str, change_number: u32) -> String {
+        format!("{}/c/{}/+/{}", self.base_url, project, change_number)
+    }
+}
+
+pub struct GerritService;
+
+impl GerritService {
+    pub fn load_gerrit_config(
+        data_path: &DataPath,
+        instance: &str,
+    ) -> io::Result<Option<GerritConfig>> {
+        UnifiedConfigService::load_gerrit_config(data_path, instance)
+    }
+
+    pub async fn get_employee_metrics(
+        data_path: &DataPath,
+        instance: &str,
+        employee_email: &str,
+        days: u32,
+    ) -> io::Result<ActivityMetrics> {
+        let config = Self::load_gerrit_config(data_path, instance)?
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+format!("Gerrit configuration '{instance}' not found. Please configure Gerrit in the [platforms.gerrit] section of config.toml")
+                )
+            })?;
+
+        let performance = UnifiedConfigService::load_performance_config(data_path);
+        let http = UnifiedConfigService::load_http_settings(data_path);
+        let client = GerritClient::new(&config, &http)?
+            .with_platform_id(&instance_platform_id("gerrit", instance))
+            .with_response_cache(ResponseCache::new(data_path))
+            .with_retry(UnifiedConfigService::load_retry_policy(data_path))
+            .with_performance(&performance);
+        let user = client
+            .resolve_user(&AccountCache::new(data_path), employee_email)
+            .await;
+        client.get_activity_metrics(&user, days).await
+    }
+
+    /// The changes of each activity category of an employee, or why they could not be fetched,
+    /// with the instance's base URL
+    pub async fn get_detailed_employee_results(
+        data_path: &DataPath,
+        instance: &str,
+        employee_email: &str,
+        days: u32,
+    ) -> io::Result<(Vec<(ActivityCategory, io::Result<Vec<ChangeInfo>>)>, String)> {
+        let config = Self::load_gerrit_config(data_path, instance)?
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+format!("Gerrit configuration '{instance}' not found. Please configure Gerrit in the [platforms.gerrit] section of config.toml")
+                )
+            })?;
+
+        let performance = UnifiedConfigService::load_performance_config(data_path);
+        let http = UnifiedConfigService::load_http_settings(data_path);
+        let client = GerritClient::new(&config, &http)?
+            .with_platform_id(&instance_platform_id("gerrit", instance))
+            .with_response_cache(ResponseCache::new(data_path))
+            .with_retry(UnifiedConfigService::load_retry_policy(data_path))
+            .with_performance(&performance);
+        let user = client
+            .resolve_user(&AccountCache::new(data_path), employee_email)
+            .await;
+        let results = client.get_detailed_activity_results(&user, days).await;
+        let base_url = config.gerrit_url.trim_end_matches('/').to_string();
+
+        Ok((results.into(), base_url))
+    }
+
+    /// The change numbered `change` with its votes, reviewers and review messages
+    pub async fn get_change_details(
+        data_path: &DataPath,
+        instance: &str,
+        change: &str,
+    ) -> io::Result<ChangeInfo> {
+        let config = Self::load_gerrit_config(data_path, instance)?
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+format!("Gerrit configuration '{instance}' not found. Please configure Gerrit in the [platforms.gerrit] section of config.toml")
+                )
+            })?;
+
+        let performance = UnifiedConfigService::load_performance_config(data_path);
+        let http = UnifiedConfigService::load_http_settings(data_path);
+        let client = GerritClient::new(&config, &http)?
+            .with_platform_id(&instance_platform_id("gerrit", instance))
+            .with_response_cache(ResponseCache::new(data_path))
+            .with_retry(UnifiedConfigService::load_retry_policy(data_path))
+            .with_performance(&performance);
+        client.get_change_detail(change).await
+    }
+}
+
+/// Map the answer to `GET /a/accounts/self` to a connection status
+fn classify_account_response(
+    status: StatusCode,
+    www_authenticate: Option<&str>,
+    final_url: &str,
+    body: &str,
+) -> ConnectionStatus {
+    // Redirects are followed, so a login page elsewhere shows up as the final URL
+    let redirected = !final_url
+        .split('?')
+        .next()
+        .unwrap_or(final_url)
+        .ends_with("/a/accounts/self");
+    if redirected {
+        return ConnectionStatus::Error(format!(
+            "Redirected to {final_url}; a proxy or SSO login is intercepting API requests"
+        ));
+    }
+
+    match status {
+        StatusCode::OK => {
+            let json = body.trim_start().strip_prefix(")]}'").unwrap_or(body);
+            match serde_json::from_str::<serde_json::Value>(json) {
+                Ok(account) if account.get("_account_id").is_some() => {
+                    ConnectionStatus::Connected
+                }
+                _ => ConnectionStatus::Error(
+                    "The server did not answer like Gerrit; a proxy or SSO page may be in front of it"
+                        .to_string(),
+                ),
+            }
+        }
+        StatusCode::UNAUTHORIZED => {
+            let scheme = www_authenticate
+                .and_then(|header| header.split_whitespace().next())
+                .unwrap_or("Basic");
+            if scheme.eq_ignore_ascii_case("Basic") {
+                ConnectionStatus::Error(
+                    "Authentication failed: wrong username or HTTP password (use the HTTP password from Gerrit's Settings → HTTP Credentials, not your login password)"
+                        .to_string(),
+                )
+            } else {
+                ConnectionStatus::Error(format!(
+                    "Authentication failed: the server asks for {scheme} authentication instead of an HTTP password; a proxy or SSO may be in front of Gerrit"
+                ))
+            }
+        }
+        StatusCode::FORBIDDEN => ConnectionStatus::Error(
+            "Access denied: the account may not use the REST API".to_string(),
+        ),
+        StatusCode::NOT_FOUND => ConnectionStatus::Error(
+            "No Gerrit REST API at this URL; check gerrit_url (include any path prefix such as /r)"
+                .to_string(),
+        ),
+        status => ConnectionStatus::Error(format!("Gerrit returned HTTP {status}")),
+    }
+}
+
+/// Platform wrapper for Gerrit that implements the ReviewPlatform trait
+pub struct GerritPlatform {
+    data_path: DataPath,
+    /// Key of the `[platforms.gerrit.<instance>]` table
+    instance: String,
+    platform_id: String,
+    name: String,
+    /// `[performance] page_size`, read once for `get_capabilities`
+    page_size: u32,
+}
+
+impl GerritPlatform {
+    /// The default instance, configured with the single `[platforms.gerrit]` table
+    pub fn new(data_path: DataPath) -> Self {
+        Self::for_instance(data_path, DEFAULT_INSTANCE)
+    }
+
+    pub fn for_instance(data_path: DataPath, instance: &str) -> Self {
+        let name = if instance == DEFAULT_INSTANCE {
+            "Gerrit".to_string()
+        } else {
+            format!("Gerrit ({instance})")
+        };
+        Self {
+            instance: instance.to_string(),
+            platform_id: instance_platform_id("gerrit", instance),
+            name,
+            page_size: UnifiedConfigService::load_performance_config(&data_path)
+                .page_size
+                .max(1),
+            data_path,
+        }
+    }
+
+    /// Convert Gerrit's ActivityMetrics to platform ActivityMetrics
+    fn convert_metrics(&self, gerrit_metrics: &ActivityMetrics) -> PlatformActivityMetrics {
+        let mut metrics = PlatformActivityMetrics {
+            total_items: gerrit_metrics.commits_merged
+                + gerrit_metrics.changes_created
+                + gerrit_metrics.reviews_given
+                + gerrit_metrics.reviews_received,
+            ..Default::default()
+        };
+
+        metrics.items_by_category.insert(
+            ActivityCategory::ChangesMerged,
+            gerrit_metrics.commits_merged,
+        );
+        metrics.items_by_category.insert(
+            ActivityCategory::ChangesCreated,
+            gerrit_metrics.changes_created,
+        );
+        metrics
+            .items_by_category
+            .insert(ActivityCategory::ReviewsGiven, gerrit_metrics.reviews_given);
+        metrics.items_by_category.insert(
+            ActivityCategory::ReviewsReceived,
+            gerrit_metrics.reviews_received,
+        );
+
+        metrics
+            .platform_specific
+            .insert("commits_merged".to_string(), gerrit_metrics.commits_merged);
+        metrics.platform_specific.insert(
+            "changes_created".to_string(),
+            gerrit_metrics.changes_created,
+        );
+        metrics
+            .platform_specific
+            .insert("reviews_given".to_string(), gerrit_metrics.reviews_given);
+        metrics.platform_specific.insert(
+            "reviews_received".to_string(),
+            gerrit_metrics.reviews_received,
+        );
+
+        metrics
+    }
+
+    /// Convert Gerrit ChangeInfo to platform ActivityItem, in the category of the first
+    /// matching category rule if any
+    fn convert_change_to_item(
+        &self,
+        change: &ChangeInfo,
+        category: ActivityCategory,
+        base_url: &str,
+        rules: &CategoryRules,
+    ) -> ActivityItem {
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert("change_id".to_string(), change.change_id.clone());
+        metadata.insert("project".to_string(), change.project.clone());
+        metadata.insert("status".to_string(), change.status.clone());
+
+        if let Some(owner_name) = &change.owner.name {
+            metadata.insert("owner_name".to_string(), owner_name.clone());
+        }
+        if let Some(owner_email) = &change.owner.email {
+            metadata.insert("owner_email".to_string(), owner_email.clone());
+        }
+        if let Some(verdict) = change.ci_verdict() {
+            metadata.insert("ci_status".to_string(), verdict.as_str().to_string());
+        }
+        if let Some(requirements) = change.submit_requirements_summary() {
+            metadata.insert("submit_requirements".to_string(), requirements);
+        }
+        if !change.hashtags.is_empty() {
+            metadata.insert("hashtags".to_string(), change.hashtags.join(", "));
+        }
+        if let Some(mergeable) = change.mergeable {
+            metadata.insert("mergeable".to_string(), mergeable.to_string());
+        }
+        if let (Some(insertions), Some(deletions)) = (change.insertions, change.deletions) {
+            metadata.insert(INSERTIONS_KEY.to_string(), insertions.to_string());
+            metadata.insert(DELETIONS_KEY.to_string(), deletions.to_string());
+        }
+        let feedback = change.review_feedback();
+        if !feedback.is_empty() {
+            metadata.insert("reviewers".to_string(), feedback.reviewers.join(", "));
+            metadata.insert("review_votes".to_string(), feedback.votes.to_string());
+            metadata.insert("review_comments".to_string(), feedback.comments.to_string());
+        }
+        // Every vote, the reviewers and the messages are loaded for one change at a time
+        metadata.insert(DETAILS_PENDING_KEY.to_string(), "true".to_string());
+
+        let mut item = ActivityItem {
+            id: change.number.to_string(),
+            title: change.subject.clone(),
+            status: change.status.clone(),
+            created: change.created.clone(),
+            updated: change.updated.clone(),
+            url: format!("{}/c/{}/+/{}", base_url, change.project, change.number),
+            platform: self.platform_id.clone(),
+            category,
+            project: change.project.clone(),
+            metadata,
+        };
+        TimestampSource::Gerrit.flag_unparsed_dates(&mut item);
+        rules.apply(&self.platform_id, &mut item);
+        item
+    }
+
+    /// `item` with the votes, reviewers and review messages of its detailed `change`
+    fn with_review_details(item: &ActivityItem, change: &ChangeInfo) -> ActivityItem {
+        let mut item = item.clone();
+        item.metadata.remove(DETAILS_PENDING_KEY);
+        if let Some(votes) = change.votes_summary() {
+            item.metadata.insert(VOTES_KEY.to_string(), votes);
+        }
+        if let Some(reviewers) = change.reviewers_summary() {
+            item.metadata
+                .insert(REVIEWER_LIST_KEY.to_string(), reviewers);
+        }
+        let messages = change.review_message_lines();
+        if !messages.is_empty() {
+            item.metadata
+                .insert(REVIEW_MESSAGES_KEY.to_string(), messages.join("\n"));
+        }
+        item
+    }
+}
+
+#[async_trait]
+impl ReviewPlatform for GerritPlatform {
+    async fn get_activity_metrics(
+        &self,
+        user: &str,
+        days: u32,
+    ) -> std::io::Result<PlatformActivityMetrics> {
+        let gerrit_metrics =
+            GerritService::get_employee_metrics(&self.data_path, &self.instance, user, days)
+                .await?;
+        Ok(self.convert_metrics(&gerrit_metrics))
+    }
+
+    async fn get_detailed_activities(
+        &self,
+        user: &str,
+        days: u32,
+    ) -> std::io::Result<DetailedActivities> {
+        let (results, base_url) = GerritService::get_detailed_employee_results(
+            &self.data_path,
+            &self.instance,
+            user,
+            days,
+        )
+        .await?;
+
+        let mut activities = DetailedActivities::default();
+        let rules = CategoryRules::load(&self.data_path);
+
+        // Convert each category of changes to activity items
+        for (category, result) in results {
+            let result = result.map(|changes| {
+                changes
+                    .iter()
+                    .map(|change| {
+                        self.convert_change_to_item(change, category.clone(), &base_url, &rules)
+                    })
+                    .collect()
+            });
+            if let Err(e) = &result {
+                warn!(
+                    "Failed to load {} from {}: {e}",
+                    category.display_name(),
+                    self.name
+                );
+            }
+            activities.insert_result(category, result);
+        }
+        activities.regroup();
+
+        Ok(activities)
+    }
+
+    async fn search_items(&self, query: &str, user: &str) -> std::io::Result<Vec<ActivityItem>> {
+        // Basic search by filtering the activities of the configured default period
+        let days = UnifiedConfigService::load_default_period_days(&self.data_path);
+        let activities = self.get_detailed_activities(user, days).await?;
+
+        let mut results = Vec::new();
+        for items in activities.items_by_category.values() {
+            for item in items {
+                if item.title.to_lowercase().contains(&query.to_lowercase())
+                    || item.project.to_lowercase().contains(&query.to_lowercase())
+                {
+                    results.push(item.clone());
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn get_platform_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_platform_icon(&self) -> &str {
+        "🔧"
+    }
+
+    fn get_platform_id(&self) -> &str {
+        &self.platform_id
+    }
+
+    fn is_configured(&self) -> bool {
+        GerritService::load_gerrit_config(&self.data_path, &self.instance)
+            .map(|config| config.is_some())
+            .unwrap_or(false)
+    }
+
+    fn get_capabilities(&self) -> PlatformCapabilities {
+        // Detailed queries follow `_more_changes` for up to MAX_CHANGE_PAGES pages; `d` loads
+        // the review messages of a change
+        PlatformCapabilities {
+            supports_comments: true,
+            max_items: Some(self.page_size as usize * MAX_CHANGE_PAGES),
+        }
+    }
+
+    async fn test_connection(&self) -> std::io::Result<ConnectionStatus> {
+        match GerritService::load_gerrit_config(&self.data_path, &self.instance)? {
+            Some(config) if config.http_password.is_empty() => Ok(ConnectionStatus::Error(
+                "No HTTP password configured; generate one under Settings → HTTP Credentials in Gerrit"
+                    .to_string(),
+            )),
+            Some(config) => {
+                let http = UnifiedConfigService::load_http_settings(&self.data_path);
+                match GerritClient::new(&config, &http).map(|c| c.with_platform_id(&self.platform_id)) {
+                    Ok(client) => Ok(client.check_account().await),
+                    Err(e) => Ok(ConnectionStatus::Error(format!(
+                        "Client creation failed: {e}"
+                    ))),
+                }
+            }
+            None => Ok(ConnectionStatus::NotConfigured),
+        }
+    }
+
+    async fn get_item_details(&self, item: &ActivityItem) -> std::io::Result<ActivityItem> {
+        if !item.has_pending_details() {
+            return Ok(item.clone());
+        }
+        let change =
+            GerritService::get_change_details(&self.data_path, &self.instance, &item.id).await?;
+        Ok(Self::with_review_details(item, &change))
+    }
+
+    fn get_item_url(&self, item: &ActivityItem) -> String {
+        item.url.clone()
+    }
+
+    fn explain_queries(&self, user: &str, days: u32) -> std::io::Result<Option<QueryPlan>> {
+        let Some(config) = GerritService::load_gerrit_config(&self.data_path, &self.instance)?
+        else {
+            return Ok(None);
+        };
+        let performance = UnifiedConfigService::load_performance_config(&self.data_path);
+        let http = UnifiedConfigService::load_http_settings(&self.data_path);
+        let client = GerritClient::new(&config, &http)?
+            .with_platform_id(&self.platform_id)
+            .with_performance(&performance);
+
+        let mut notes = Vec::new();
+        let account = match AccountCache::new(&self.data_path).get(&self.platform_id, user) {
+            Some(account) if user.contains('@') => {
+                notes.push(format!(
+                    "{user} is account {account}, cached in accounts.json"
+                ));
+                account
+            }
+            None if user.contains('@') => {
+                notes.push(format!(
+                    "{user} is first resolved to an account id with GET {}; \
+                     the queries below use the email when that finds no unique account",
+                    redact_url(&client.account_search_url(user))
+                ));
+                user.to_string()
+            }
+            _ => user.to_string(),
+        };
+        notes.push(
+            "Servers before 3.5 reject o=SUBMIT_REQUIREMENTS and are asked again with o=LABELS"
+                .to_string(),
+        );
+        notes.push(format!(
+            "{} keeps only changes with votes or comments by someone else",
+            ActivityCategory::ReviewsReceived.display_name()
+        ));
+
+        Ok(Some(QueryPlan {
+            auth: format!(
+                "Basic, user {}, HTTP password {}",
+                config.username,
+                mask_secret(&config.http_password)
+            ),
+            time_range: format!(
+                "changes updated in the last {days} days (-age:{days}d), {} per page, at most \
+                 {MAX_CHANGE_PAGES} pages per query",
+                performance.page_size.max(1)
+            ),
+            requests: client.planned_requests(&account, days),
+            notes,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param, query_param_contains};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_classify_account_response() {
+        let url = "https://gerrit.example.com/a/accounts/self";
+        let ok = ")]}'\n{\"_account_id\": 1000, \"username\": \"jane\"}";
+        assert_eq!(
+            classify_account_response(StatusCode::OK, None, url, ok),
+            ConnectionStatus::Connected
+        );
+
+        let error_text = |status: ConnectionStatus| match status {
+            ConnectionStatus::Error(message) => message,
+            other => panic!("expected an error, got {other:?}"),
+        };
+        assert!(
+            error_text(classify_account_response(
+                StatusCode::UNAUTHORIZED,
+                Some("Basic realm=\"Gerrit Code Review\""),
+                url,
+                "Unauthorized",
+            ))
+            .contains("wrong username or HTTP password")
+        );
+        assert!(
+            error_text(classify_account_response(
+                StatusCode::UNAUTHORIZED,
+                Some("Negotiate"),
+                url,
+                "",
+            ))
+            .contains("asks for Negotiate authentication")
+        );
+        assert!(
+            error_text(classify_account_response(
+                StatusCode::OK,
+                None,
+                "https://sso.example.com/login?next=gerrit",
+                "<html>",
+            ))
+            .contains("Redirected to https://sso.example.com/login")
+        );
+        assert!(
+            error_text(classify_account_response(
+                StatusCode::OK,
+                None,
+                url,
+                "<html>Sign in</html>"
+            ))
+            .contains("did not answer like Gerrit")
+        );
+        assert!(
+            error_text(classify_account_response(
+                StatusCode::NOT_FOUND,
+                None,
+                url,
+                ""
+            ))
+            .contains("check gerrit_url")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_account_wrong_password() {
+        let server = MockServer::start().await;
+        let config = GerritConfig {
+            gerrit_url: server.uri(),
+            username: "bot".to_string(),
+            http_password: "wrong".to_string(),
+            accept_invalid_certs: false,
+            limits: RequestLimits::default(),
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/a/accounts/self"))
+            .respond_with(
+                ResponseTemplate::new(401)
+                    .insert_header("WWW-Authenticate", "Basic realm=\"Gerrit Code Review\""),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let status = GerritClient::new(&config, &HttpClientSettings::default())
+            .unwrap()
+            .check_account()
+            .await;
+        assert!(
+            matches!(status, ConnectionStatus::Error(message) if message.contains("HTTP password"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_detailed_metrics_query_requested_period() {
+        let server = MockServer::start().await;
+        let config = GerritConfig {
+            gerrit_url: server.uri(),
+            username: "bot".to_string(),
+            http_password: "secret".to_string(),
+            accept_invalid_certs: false,
+            limits: RequestLimits::default(),
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/a/changes/"))
+            .and(query_param_contains("q", "-age:180d"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(")]}'\n[]"))
+            .expect(4)
+            .mount(&server)
+            .await;
+
+        let client = GerritClient::new(&config, &HttpClientSettings::default()).unwrap();
+        let metrics = client
+            .get_detailed_activity_metrics("jane@example.com", 180)
+            .await
+            .unwrap();
+        assert!(metrics.changes_created.is_empty());
+    }
+
+    #[test]
+    fn test_explain_queries_masks_password() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let mut config = crate::unified_config::UnifiedConfig::default();
+        config.platforms.gerrit.insert(
+            DEFAULT_INSTANCE.to_string(),
+            GerritConfig {
+                gerrit_url: "https://gerrit.example.com/".to_string(),
+                username: "bot".to_string(),
+                http_password: "hunter2".to_string(),
+                accept_invalid_certs: false,
+                limits: RequestLimits::default(),
+            },
+        );
+        UnifiedConfigService::save_config(&config, &data_path).unwrap();
+        AccountCache::new(&data_path)
+            .insert("gerrit", "jane@example.com", "1000")
+            .unwrap();
+
+        let plan = GerritPlatform::new(data_path)
+            .explain_queries("jane@example.com", 30)
+            .unwrap()
+            .unwrap();
+        assert_eq!(plan.auth, "Basic, user bot, HTTP password [REDACTED]");
+        assert!(!format!("{plan:?}").contains("hunter2"));
+        assert!(plan.time_range.contains("-age:30d"));
+        let queries: Vec<_> = plan.requests.iter().map(|r| r.query.as_deref()).collect();
+        assert_eq!(
+            queries,
+            [
+                Some("owner:1000 -age:30d"),
+                Some("owner:1000 status:merged -age:30d"),
+                Some("reviewer:1000 -age:30d"),
+                Some("owner:1000 -age:30d"),
+            ]
+        );
+        assert!(plan.requests[3].request.starts_with(
+            "GET https://gerrit.example.com/a/changes/?q=owner%3A1000+-age%3A30d&n=100&o=DETAILED_LABELS"
+        ));
+        assert!(plan.notes[0].contains("is account 1000"));
+    }
+
+    #[test]
+    fn test_ci_verdict_and_submit_requirements() {
+        let json = r#"[{"id":"p~1","change_id":"I1","subject":"Fix","status":"NEW",
+            "created":"2024-01-10 00:00:00","updated":"2024-01-11 00:00:00","project":"core",
+            "_number":1,"owner":{"name":"Jane"},"mergeable":true,
+            "labels":{"Verified":{"rejected":{"_account_id":7}},"Code-Review":{}},
+            "submit_requirements":[{"name":"Code-Review","status":"UNSATISFIED"},
+                {"name":"Verified","status":"UNSATISFIED"},
+                {"name":"No-Wip","status":"NOT_APPLICABLE"}]}]"#;
+        let change = &serde_json::from_str::<Vec<ChangeInfo>>(json).unwrap()[0];
+        assert_eq!(change.ci_verdict(), Some(CiVerdict::Failing));
+        assert_eq!(
+            change.submit_requirements_summary().as_deref(),
+            Some("Code-Review: UNSATISFIED, Verified: UNSATISFIED")
+        );
+
+        let platform = GerritPlatform::new(DataPath::new(Some(std::env::temp_dir())).unwrap());
+        let item = platform.convert_change_to_item(
+            change,
+            ActivityCategory::ChangesCreated,
+            "https://gerrit.example.com",
+            &CategoryRules::default(),
+        );
+        assert_eq!(item.metadata["ci_status"], "failing");
+        assert_eq!(item.metadata["mergeable"], "true");
+        assert!(item.is_failing_ci());
+    }
+
+    #[tokio::test]
+    async fn test_detailed_query_falls_back_without_submit_requirements() {
+        let server = MockServer::start().await;
+        let config = GerritConfig {
+            gerrit_url: server.uri(),
+            username: "bot".to_string(),
+            http_password: "secret".to_string(),
+            accept_invalid_certs: false,
+            limits: RequestLimits::default(),
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/a/changes/"))
+            .and(query_param("o", "SUBMIT_REQUIREMENTS"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .set_body_string("\"SUBMIT_REQUIREMENTS\" is not a valid value for \"-o\""),
+            )
+            .expect(3)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/a/changes/"))
+            .and(query_param("o", "LABELS"))
+            .and(|request: &wiremock::Request| {
+                !request.url.as_str().contains("SUBMIT_REQUIREMENTS")
+            })
+            .respond_with(ResponseTemplate::new(200).set_body_string(")]}'\n[]"))
+            .expect(3)
+            .mount(&server)
+            .await;
+        // Reviews received are read from detailed labels and messages instead
+        Mock::given(method("GET"))
+            .and(path("/a/changes/"))
+            .and(query_param("o", "DETAILED_LABELS"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(")]}'\n[]"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = GerritClient::new(&config, &HttpClientSettings::default()).unwrap();
+        let metrics = client
+            .get_detailed_activity_metrics("jane@example.com", 30)
+            .await
+            .unwrap();
+        assert!(metrics.reviews_given.is_empty());
+    }
+
+    /// A change numbered `number` as `/a/changes/` lists it
+    fn change_json(number: u32, more_changes: bool) -> String {
+        let more = if more_changes {
+            r#","_more_changes":true"#
+        } else {
+            ""
+        };
+        format!(
+            r#"{{"id":"p~{number}","change_id":"I{number}","subject":"Change {number}",
+              "status":"NEW","created":"2024-01-10 00:00:00","updated":"2024-01-11 00:00:00",
+              "project":"core","_number":{number},"owner":{{"_account_id":1}}{more}}}"#
+        )
+    }
+
+    #[tokio::test]
+    async fn test_changes_are_read_page_by_page() {
+        let server = MockServer::start().await;
+        let config = GerritConfig {
+            gerrit_url: server.uri(),
+            username: "bot".to_string(),
+            http_password: "secret".to_string(),
+            accept_invalid_certs: false,
+            limits: RequestLimits::default(),
+        };
+        // The server caps pages at two changes however many are asked for
+        let page = |numbers: &[u32], more: bool| {
+            let changes: Vec<String> = numbers
+                .iter()
+                .enumerate()
+                .map(|(i, number)| change_json(*number, more && i == numbers.len() - 1))
+                .collect();
+            ResponseTemplate::new(200).set_body_string(format!(")]}}'\n[{}]", changes.join(",")))
+        };
+        Mock::given(method("GET"))
+            .and(path("/a/changes/"))
+            .and(query_param("start", "2"))
+            .respond_with(page(&[3, 4], true))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/a/changes/"))
+            .and(query_param("start", "4"))
+            .respond_with(page(&[5], false))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/a/changes/"))
+            .and(|request: &wiremock::Request| !request.url.as_str().contains("start="))
+            .respond_with(page(&[1, 2], true))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = GerritClient::new(&config, &HttpClientSettings::default()).unwrap();
+        let changes = client
+            .query_detailed_changes("owner:1 -age:90d")
+            .await
+            .unwrap();
+        let numbers: Vec<u32> = changes.iter().map(|change| change.number).collect();
+        assert_eq!(numbers, [1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_pagination_stops_at_page_cap() {
+        let server = MockServer::start().await;
+        let config = GerritConfig {
+            gerrit_url: server.uri(),
+            username: "bot".to_string(),
+            http_password: "secret".to_string(),
+            accept_invalid_certs: false,
+            limits: RequestLimits::default(),
+        };
+        // A server that always claims more changes
+        Mock::given(method("GET"))
+            .and(path("/a/changes/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(format!(")]}}'\n[{}]", change_json(1, true))),
+            )
+            .expect(MAX_CHANGE_PAGES as u64)
+            .mount(&server)
+            .await;
+
+        let client = GerritClient::new(&config, &HttpClientSettings::default()).unwrap();
+        let count = client.query_changes("owner:1 -age:90d").await.unwrap();
+        assert_eq!(count as usize, MAX_CHANGE_PAGES);
+    }
+
+    #[tokio::test]
+    async fn test_reviews_received_need_votes_or_comments_from_others() {
+        let server = MockServer::start().await;
+        let config = GerritConfig {
+            gerrit_url: server.uri(),
+            username: "bot".to_string(),
+            http_password: "secret".to_string(),
+            accept_invalid_certs: false,
+            limits: RequestLimits::default(),
+        };
+        let body = r#")]}'
+[{"id":"p~1","change_id":"I1","subject":"Reviewed","status":"MERGED",
+  "created":"2024-01-10 00:00:00","updated":"2024-01-11 00:00:00","project":"core",
+  "_number":1,"owner":{"_account_id":1,"name":"Jane"},"insertions":42,"deletions":7,
+  "labels":{"Code-Review":{"all":[{"_account_id":1,"name":"Jane","value":0},
+      {"_account_id":2,"name":"Bob","value":2},{"_account_id":3,"name":"Ann","value":0}]},
+    "Verified":{"all":[{"_account_id":9,"name":"CI","value":1}]}},
+  "messages":[{"author":{"_account_id":3,"name":"Ann"},"message":"Nit"},
+    {"author":{"_account_id":1,"name":"Jane"},"message":"Done"},
+    {"author":{"_account_id":9,"name":"CI"},"tag":"autogenerated:ci","message":"Build ok"}]},
+ {"id":"p~2","change_id":"I2","subject":"Only CI","status":"NEW",
+  "created":"2024-01-10 00:00:00","updated":"2024-01-11 00:00:00","project":"core",
+  "_number":2,"owner":{"_account_id":1,"name":"Jane"},
+  "labels":{"Verified":{"all":[{"_account_id":9,"name":"CI","value":-1}]}},
+  "messages":[{"author":{"_account_id":1,"name":"Jane"},"tag":"autogenerated:gerrit:newPatchSet"}]}]"#;
+
+        Mock::given(method("GET"))
+            .and(path("/a/changes/"))
+            .and(query_param("o", "DETAILED_LABELS"))
+            .and(query_param("o", "MESSAGES"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/a/changes/"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(")]}'\n[]"))
+            .mount(&server)
+            .await;
+
+        let client = GerritClient::new(&config, &HttpClientSettings::default()).unwrap();
+        let metrics = client
+            .get_detailed_activity_metrics("jane@example.com", 30)
+            .await
+            .unwrap();
+        assert_eq!(metrics.reviews_received.len(), 1);
+        let change = &metrics.reviews_received[0];
+        assert_eq!(
+            change.review_feedback(),
+            ReviewFeedback {
+                reviewers: vec!["Ann".to_string(), "Bob".to_string()],
+                votes: 1,
+                comments: 1,
+            }
+        );
+
+        let counts = client
+            .get_activity_metrics("jane@example.com", 30)
+            .await
+            .unwrap();
+        assert_eq!(counts.reviews_received, 1);
+        assert_eq!(counts.changes_created, 0);
+
+        let platform = GerritPlatform::new(DataPath::new(Some(std::env::temp_dir())).unwrap());
+        let item = platform.convert_change_to_item(
+            change,
+            ActivityCategory::ReviewsReceived,
+            "https://gerrit.example.com",
+            &CategoryRules::default(),
+        );
+        assert_eq!(item.metadata["reviewers"], "Ann, Bob");
+        assert_eq!(item.metadata["review_votes"], "1");
+        assert_eq!(item.metadata["review_comments"], "1");
+        assert_eq!(item.metadata["insertions"], "42");
+        assert_eq!(item.metadata["deletions"], "7");
+    }
+
+    #[tokio::test]
+    async fn test_item_details_load_votes_reviewers_and_messages() {
+        let server = MockServer::start().await;
+        let body = r#")]}'
+{"id":"core~42","change_id":"I42","subject":"Fix crash","status":"NEW",
+ "created":"2024-01-10 00:00:00.000000000","updated":"2024-01-11 00:00:00.000000000",
+ "project":"core","_number":42,"owner":{"_account_id":1,"name":"Jane"},
+ "labels":{"Verified":{"all":[{"_account_id":9,"name":"CI","value":1}]},
+   "Code-Review":{"all":[{"_account_id":2,"name":"Bob","value":-1},
+     {"_account_id":3,"name":"Ann","value":2},{"_account_id":4,"name":"Eve","value":0}]}},
+ "reviewers":{"REVIEWER":[{"_account_id":3,"name":"Ann"},{"_account_id":2,"name":"Bob"}],
+   "CC":[{"_account_id":4,"name":"Eve"}]},
+ "messages":[{"author":{"_account_id":1,"name":"Jane"},"date":"2024-01-10 08:00:00.000000000",
+     "tag":"autogenerated:gerrit:newPatchSet","message":"Uploaded patch set 1."},
+   {"author":{"_account_id":3,"name":"Ann"},"date":"2024-01-11 09:30:00.000000000",
+     "message":"Patch Set 1: Code-Review+2\n\nLooks good"}]}"#;
+        Mock::given(method("GET"))
+            .and(path("/a/changes/42/detail"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        std::fs::write(
+            data_path.config_path(),
+            format!(
+                "[platforms.gerrit]\ngerrit_url = \"{}\"\nusername = \"bot\"\nhttp_password = \"pw\"\n\n[ui_preferences]\n",
+                server.uri()
+            ),
+        )
+        .unwrap();
+        let platform = GerritPlatform::new(data_path);
+        let change: ChangeInfo = serde_json::from_str(body.strip_prefix(")]}'").unwrap()).unwrap();
+        let item = platform.convert_change_to_item(
+            &change,
+            ActivityCategory::ChangesCreated,
+            &server.uri(),
+            &CategoryRules::default(),
+        );
+        assert!(item.has_pending_details());
+
+        let detailed = platform.get_item_details(&item).await.unwrap();
+        assert!(!detailed.has_pending_details());
+        assert_eq!(
+            detailed.metadata[VOTES_KEY],
+            "Code-Review: -1 Bob, +2 Ann; Verified: +1 CI"
+        );
+        assert_eq!(detailed.metadata[REVIEWER_LIST_KEY], "Ann, Bob (CC: Eve)");
+        assert_eq!(
+            detailed.metadata[REVIEW_MESSAGES_KEY],
+            "2024-01-10 08:00 Jane: Uploaded patch set 1.\n\
+             2024-01-11 09:30 Ann: Patch Set 1: Code-Review+2 Looks good"
+        );
+        // Loaded items are returned as they are
+        let again = platform.get_item_details(&detailed).await.unwrap();
+        assert_eq!(again.metadata, detailed.metadata);
+    }
+
+    #[tokio::test]
+    async fn test_failed_query_is_reported_for_its_category() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/a/changes/"))
+            .and(query_param("q", "reviewer:1000 -age:30d"))
+            .respond_with(ResponseTemplate::new(403).set_body_string("Forbidden"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/a/changes/"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(")]}'\n[]"))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        std::fs::write(
+            data_path.config_path(),
+            format!(
+                "[platforms.gerrit]\ngerrit_url = \"{}\"\nusername = \"bot\"\nhttp_password = \"pw\"\n\n[ui_preferences]\n",
+                server.uri()
+            ),
+        )
+        .unwrap();
+        AccountCache::new(&data_path)
+            .insert("gerrit", "jane@example.com", "1000")
+            .unwrap();
+
+        let activities = GerritPlatform::new(data_path)
+            .get_detailed_activities("jane@example.com", 30)
+            .await
+            .unwrap();
+        assert!(activities.is_partial());
+        assert!(
+            activities.failed_categories[&ActivityCategory::ReviewsGiven].contains("403"),
+            "{activities:?}"
+        );
+        assert_eq!(activities.failed_categories.len(), 1);
+        assert!(
+            activities
+                .items_by_category
+                .contains_key(&ActivityCategory::ChangesCreated)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_accounts_by_email() {
+        let server = MockServer::start().await;
+        let config = GerritConfig {
+            gerrit_url: server.uri(),
+            username: "bot".to_string(),
+            http_password: "secret".to_string(),
+            accept_invalid_certs: false,
+            limits: RequestLimits::default(),
+        };
+        Mock::given(method("GET"))
+            .and(path("/a/accounts/"))
+            .and(query_param("q", "email:jane@example.com"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                ")]}'\n[{\"_account_id\": 1000, \"username\": \"jane\", \"email\": \"jane@example.com\"}]",
+            ))
+            .mount(&server)
+            .await;
+        // Searching needs permissions the bot lacks on this server; the direct lookup works
+        Mock::given(method("GET"))
+            .and(path("/a/accounts/"))
+            .and(query_param("q", "email:sam@example.com"))
+            .respond_with(ResponseTemplate::new(403).set_body_string("forbidden"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/a/accounts/sam%40example.com/detail"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                ")]}'\n{\"_account_id\": 1001, \"email\": \"sam@new.example.com\"}",
+            ))
+            .mount(&server)
+            .await;
+
+        let client = GerritClient::new(&config, &HttpClientSettings::default()).unwrap();
+        // Accounts are queried by their id, which survives email changes
+        let accounts = client.find_accounts("jane@example.com").await.unwrap();
+        assert_eq!(accounts[0].username, "1000");
+        assert_eq!(accounts[0].emails, vec!["jane@example.com".to_string()]);
+        let accounts = client.find_accounts("sam@example.com").await.unwrap();
+        assert_eq!(accounts[0].username, "1001");
+
+        // Unknown to the server
+        assert!(
+            client
+                .find_accounts("nobody@example.com")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let accounts = AccountCache::new(&data_path);
+        assert_eq!(
+            client.resolve_user(&accounts, "sam@example.com").await,
+            "1001"
+        );
+        assert_eq!(client.resolve_user(&accounts, "jane").await, "jane");
+    }
+}
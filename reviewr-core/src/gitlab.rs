@@ -0,0 +1,1403 @@
+use crate::accounts::{AccountCache, AccountCandidate};
+use crate::category_rules::CategoryRules;
+use crate::change_size::{COMMIT_COUNT_KEY, DELETIONS_KEY, INSERTIONS_KEY, diffstat};
+use crate::http::{PlatformClient, RetryPolicy, api_error};
+use crate::http_cache::ResponseCache;
+use crate::models::DataPath;
+use crate::platform::{
+    ActivityCategory, ActivityItem, ActivityMetrics as PlatformActivityMetrics, COMMENTS_KEY,
+    ConnectionStatus, DetailedActivities, ErrorContext, PlannedRequest, PlatformCapabilities,
+    QueryPlan, ReviewPlatform, Severity,
+};
+use crate::redact::{mask_secret, redact_url};
+use crate::timestamps::TimestampSource;
+use crate::unified_config::{GitLabConfig, UnifiedConfigService};
+use async_trait::async_trait;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+
+/// GitLab platform implementation
+pub struct GitLabPlatform {
+    config: GitLabConfig,
+    platform_id: String, // e.g., "gitlab:company", "gitlab:public"
+    client: PlatformClient,
+    response_cache: Option<ResponseCache>,
+    accounts: AccountCache,
+    retry: RetryPolicy,
+    page_size: u32,
+    category_rules: CategoryRules,
+}
+
+impl GitLabPlatform {
+    /// InvalidInput when the configured `http_proxy` or `ca_bundle_path` cannot be used
+    pub fn new(
+        config: GitLabConfig,
+        instance_id: String,
+        data_path: &DataPath,
+    ) -> io::Result<Self> {
+        let performance = UnifiedConfigService::load_performance_config(data_path);
+        let client = UnifiedConfigService::load_http_settings(data_path).platform_client(
+            config.accept_invalid_certs,
+            config.limits.max_concurrent_requests(&performance),
+            config.limits.request_timeout(),
+        )?;
+        let platform_id = format!("gitlab:{instance_id}");
+        Ok(Self {
+            config,
+            platform_id,
+            client,
+            response_cache: Some(ResponseCache::new(data_path)),
+            accounts: AccountCache::new(data_path),
+            retry: UnifiedConfigService::load_retry_policy(data_path),
+            page_size: performance.page_size.max(1),
+            category_rules: CategoryRules::load(data_path),
+        })
+    }
+}
+
+#[async_trait]
+impl ReviewPlatform for GitLabPlatform {
+    async fn get_activity_metrics(
+        &self,
+        user: &str,
+        days: u32,
+    ) -> io::Result<PlatformActivityMetrics> {
+        info!(
+            "Getting GitLab activity metrics for user: {user} over {days} days from {}",
+            self.config.name
+        );
+
+        // TODO: Implement actual metrics fetching
+        Ok(PlatformActivityMetrics {
+            total_items: 0,
+            items_by_category: HashMap::new(),
+            platform_specific: HashMap::new(),
+        })
+    }
+
+    async fn get_detailed_activities(
+        &self,
+        user: &str,
+        days: u32,
+    ) -> io::Result<DetailedActivities> {
+        info!(
+            "Getting detailed GitLab activities for user: {user} over {days} days from {}",
+            self.config.name
+        );
+
+        let mut activities = DetailedActivities::default();
+        let username = self
+            .accounts
+            .resolve(&self.platform_id, user, || self.search_users(user))
+            .await;
+        let user = username.as_str();
+
+        let (since_str, after) = period_start(days);
+
+        // Fetches run concurrently, bounded by the platform's request permits
+        let (authored_mrs, review_mrs, merged_mrs, assigned_issues, created_issues, pushes) = tokio::join!(
+            self.fetch_merge_requests_authored_by(user, &since_str),
+            self.fetch_merge_requests_for_review(user, &since_str),
+            self.fetch_merge_requests_merged_by(user, &since_str),
+            self.fetch_issues_assigned_to(user, &since_str),
+            self.fetch_issues_created_by(user, &since_str),
+            self.fetch_push_events(user, &after),
+        );
+        for (category, result) in [
+            (ActivityCategory::MergeRequestsCreated, authored_mrs),
+            (ActivityCategory::MergeRequestsReviewed, review_mrs),
+            (ActivityCategory::MergeRequestsMerged, merged_mrs),
+            (ActivityCategory::IssuesAssigned, assigned_issues),
+            (ActivityCategory::IssuesCreated, created_issues),
+            (ActivityCategory::CommitsPushed, pushes),
+        ] {
+            let result = result.map(|mut items| {
+                // Conversion leaves merge requests and issues in a placeholder category
+                for item in &mut items {
+                    item.category = category.clone();
+                    self.category_rules.apply(&self.platform_id, item);
+                }
+                items
+            });
+            if let Err(e) = &result {
+                warn!(
+                    "Failed to load {} from {}: {e}",
+                    category.display_name(),
+                    self.config.name
+                );
+            }
+            activities.insert_result(category, result);
+        }
+
+        activities.regroup();
+        Ok(activities)
+    }
+
+    async fn search_items(&self, query: &str, user: &str) -> io::Result<Vec<ActivityItem>> {
+        info!(
+            "Searching GitLab items with query: '{query}' for user: {user} on {}",
+            self.config.name
+        );
+
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("Search is not supported on {}", self.config.name),
+        ))
+    }
+
+    fn get_platform_name(&self) -> &str {
+        &self.config.name
+    }
+
+    fn get_platform_icon(&self) -> &str {
+        "🦊" // GitLab fox emoji
+    }
+
+    fn get_platform_id(&self) -> &str {
+        &self.platform_id
+    }
+
+    fn is_configured(&self) -> bool {
+        self.config.is_configured()
+    }
+
+    fn get_capabilities(&self) -> PlatformCapabilities {
+        // Merge requests carry their comment counts
+        PlatformCapabilities {
+            supports_comments: true,
+            max_items: Some(self.page_size as usize), // per_page of every list request
+        }
+    }
+
+    fn get_item_url(&self, item: &ActivityItem) -> String {
+        // For now, just return the item URL if it exists
+        // TODO: Implement GitLab-specific URL generation if needed
+        item.url.clone()
+    }
+
+    async fn test_connection(&self) -> io::Result<ConnectionStatus> {
+        info!("Testing GitLab connection to: {}", self.config.name);
+
+        let url = format!("{}/projects", self.config.api_base_url());
+
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.config.token))
+            .header("User-Agent", "reviewr/1.0")
+            .query(&[("simple", "true"), ("per_page", "1")]) // Just get 1 project to test
+            .build()
+            .map_err(|e| io::Error::other(format!("Invalid GitLab request: {e}")))?;
+        let response = self
+            .client
+            .execute(request, &self.retry)
+            .await
+            .map_err(|e| {
+                let severity = if e.is_timeout() {
+                    Severity::Warning
+                } else {
+                    Severity::Error
+                };
+                ErrorContext::new(&self.platform_id, "test_connection")
+                    .with_severity(severity)
+                    .with_error("network_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .log_error();
+                io::Error::other(format!("GitLab API request failed: {e}"))
+            })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+
+            ErrorContext::new(&self.platform_id, "test_connection")
+                .with_error("api_error", &format!("HTTP {status}"))
+                .with_request_details(&url, Some(status.as_u16()), Some(&error_text))
+                .log_error();
+
+            return Ok(ConnectionStatus::Error(
+                api_error("GitLab", status, &error_text).to_string(),
+            ));
+        }
+
+        // Try to parse as JSON to ensure we get valid response
+        let projects: Result<Vec<serde_json::Value>, _> = response.json().await;
+
+        match projects {
+            Ok(_) => {
+                info!("GitLab connection successful to {}", self.config.name);
+                Ok(ConnectionStatus::Connected)
+            }
+            Err(e) => {
+                ErrorContext::new(&self.platform_id, "test_connection")
+                    .with_error("json_parse_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .log_error();
+
+                Ok(ConnectionStatus::Error(format!(
+                    "Invalid response from GitLab API: {e}"
+                )))
+            }
+        }
+    }
+
+    fn explain_queries(&self, user: &str, days: u32) -> io::Result<Option<QueryPlan>> {
+        let request_url = |url: &str, params: &[(&str, String)]| {
+            let url = url::Url::parse_with_params(url, params)
+                .map_or_else(|_| url.to_string(), String::from);
+            format!("GET {}", redact_url(&url))
+        };
+
+        let mut notes = Vec::new();
+        let username = match self.accounts.get(&self.platform_id, user) {
+            Some(username) if user.contains('@') => {
+                notes.push(format!("{user} is {username}, cached in accounts.json"));
+                username
+            }
+            None if user.contains('@') => {
+                let search_url = format!("{}/users", self.config.api_base_url());
+                notes.push(format!(
+                    "{user} is first resolved to a username with {}; \
+                     the queries below use the part before @ when that finds no unique user",
+                    request_url(&search_url, &[("search", user.to_string())])
+                ));
+                user.to_string()
+            }
+            _ => user.to_string(),
+        };
+        let username = query_username(&username);
+
+        let (since, after) = period_start(days);
+        let requests = [
+            ActivityCategory::MergeRequestsCreated,
+            ActivityCategory::MergeRequestsReviewed,
+            ActivityCategory::MergeRequestsMerged,
+            ActivityCategory::IssuesAssigned,
+            ActivityCategory::IssuesCreated,
+            ActivityCategory::CommitsPushed,
+        ]
+        .into_iter()
+        .map(|category| {
+            let start = if category == ActivityCategory::CommitsPushed {
+                &after
+            } else {
+                &since
+            };
+            let (url, params) = self.activity_request(&category, username, start);
+            PlannedRequest {
+                label: category.display_name().to_string(),
+                query: None,
+                request: request_url(&url, &params),
+            }
+        })
+        .collect();
+
+        let base = self.config.api_base_url();
+        notes.push(format!(
+            "{} keeps only merge requests merged by {username}",
+            ActivityCategory::MergeRequestsMerged.display_name()
+        ));
+        notes.push(format!(
+            "Each created merge request is sized with GET {base}/projects/<id>/merge_requests/<iid>/diffs"
+        ));
+        notes.push(format!(
+            "Comments on each reviewed merge request are counted with GET {base}/projects/<id>/merge_requests/<iid>/notes"
+        ));
+        notes.push(format!(
+            "Each project pushed to is named with GET {base}/projects/<id>"
+        ));
+
+        Ok(Some(QueryPlan {
+            auth: format!("Bearer token {}", mask_secret(&self.config.token)),
+            time_range: format!(
+                "created after {since} (merged: updated after it, pushes: after {after}), \
+                 at most {} per query",
+                self.page_size
+            ),
+            requests,
+            notes,
+        }))
+    }
+}
+
+/// GitLab queries by username; an unresolved email is queried by its local part
+fn query_username(user: &str) -> &str {
+    user.split('@').next().unwrap_or(user)
+}
+
+/// Start of a `days` long period as the `*_after` timestamp of listings, and as the date the
+/// events API takes, which excludes it. The timestamp is the start of that day, so the request
+/// URLs, and the response cache entries keyed on them, stay the same all day.
+fn period_start(days: u32) -> (String, String) {
+    let since = chrono::Utc::now() - chrono::Duration::days(days as i64);
+    let after = since - chrono::Duration::days(1);
+    (
+        since.format("%Y-%m-%dT00:00:00.000Z").to_string(),
+        after.format("%Y-%m-%d").to_string(),
+    )
+}
+
+impl GitLabPlatform {
+    /// URL and query parameters of the request fetching `category` for `username`. `since`
+    /// is the `period_start` timestamp, or its date for `CommitsPushed`.
+    fn activity_request(
+        &self,
+        category: &ActivityCategory,
+        username: &str,
+        since: &str,
+    ) -> (String, Vec<(&'static str, String)>) {
+        let base = self.config.api_base_url();
+        let (url, mut params) = match category {
+            ActivityCategory::CommitsPushed => {
+                let params = vec![
+                    ("action", "pushed".to_string()),
+                    ("after", since.to_string()),
+                ];
+                (format!("{base}/users/{username}/events"), params)
+            }
+            ActivityCategory::MergeRequestsMerged => (
+                format!("{base}/merge_requests"),
+                // There is no merged_by filter; merged requests are filtered after fetching
+                vec![
+                    ("state", "merged".to_string()),
+                    ("updated_after", since.to_string()),
+                    ("order_by", "updated_at".to_string()),
+                    ("sort", "desc".to_string()),
+                ],
+            ),
+            _ => {
+                let (path, filter) = match category {
+                    ActivityCategory::MergeRequestsReviewed => {
+                        ("merge_requests", "reviewer_username")
+                    }
+                    ActivityCategory::IssuesAssigned => ("issues", "assignee_username"),
+                    ActivityCategory::IssuesCreated => ("issues", "author_username"),
+                    _ => ("merge_requests", "author_username"),
+                };
+                (
+                    format!("{base}/{path}"),
+                    vec![
+                        (filter, username.to_string()),
+                        ("created_after", since.to_string()),
+                        // Open, closed and merged alike
+                        ("state", "all".to_string()),
+                        ("order_by", "created_at".to_string()),
+                        ("sort", "desc".to_string()),
+                    ],
+                )
+            }
+        };
+        params.push(("per_page", self.page_size.to_string()));
+        if *category != ActivityCategory::CommitsPushed {
+            // Include project information
+            params.push(("with_projects_enabled", "true".to_string()));
+        }
+        (url, params)
+    }
+
+    /// Users matching `email` from `/users?search=`; GitLab matches emails exactly there, and
+    /// only shows them to admins or when the user made theirs public
+    async fn search_users(&self, email: &str) -> io::Result<Vec<AccountCandidate>> {
+        let url = format!("{}/users", self.config.api_base_url());
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.config.token))
+            .header("User-Agent", "reviewr/1.0")
+            .query(&[("search", email)])
+            .build()
+            .map_err(|e| io::Error::other(format!("Invalid GitLab request: {e}")))?;
+        let response = self
+            .client
+            .execute(request, &self.retry)
+            .await
+            .map_err(|e| io::Error::other(format!("GitLab API request failed: {e}")))?;
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            ErrorContext::new(&self.platform_id, "search_users")
+                .with_severity(Severity::Warning)
+                .with_error("api_error", &format!("HTTP {status}"))
+                .with_request_details(&url, Some(status.as_u16()), Some(&body))
+                .log_error();
+            return Err(api_error("GitLab", status, &body));
+        }
+        let users: Vec<GitLabUser> = serde_json::from_str(&body).map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+        })?;
+        Ok(users
+            .into_iter()
+            .map(|user| AccountCandidate {
+                username: user.username,
+                emails: user.email.into_iter().chain(user.public_email).collect(),
+            })
+            .collect())
+    }
+
+    /// Fetch merge requests authored by the user
+    async fn fetch_merge_requests_authored_by(
+        &self,
+        user: &str,
+        since: &str,
+    ) -> io::Result<Vec<ActivityItem>> {
+        let username = query_username(user);
+
+        let (url, params) =
+            self.activity_request(&ActivityCategory::MergeRequestsCreated, username, since);
+
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.config.token))
+            .header("User-Agent", "reviewr/1.0")
+            .query(&params);
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| {
+                ErrorContext::new(&self.platform_id, "fetch_authored_mrs")
+                    .with_error("network_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .with_metadata("user", user)
+                    .log_error();
+                io::Error::other(format!("GitLab API request failed: {e}"))
+            })?;
+
+        if !response.status.is_success() {
+            let status = response.status;
+            let error_text = response.body;
+
+            ErrorContext::new(&self.platform_id, "fetch_authored_mrs")
+                .with_error("api_error", &format!("HTTP {status}"))
+                .with_request_details(&url, Some(status.as_u16()), Some(&error_text))
+                .with_metadata("user", user)
+                .log_error();
+
+            return Err(api_error("GitLab", status, &error_text));
+        }
+
+        let mrs: Vec<GitLabMergeRequest> = serde_json::from_str(&response.body).map_err(|e| {
+            ErrorContext::new(&self.platform_id, "fetch_authored_mrs")
+                .with_error("json_parse_error", &e.to_string())
+                .with_request_details(&url, None, None)
+                .with_metadata("user", user)
+                .log_error();
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+        })?;
+
+        let diffstats = futures::future::join_all(
+            mrs.iter()
+                .map(|mr| self.fetch_merge_request_diffstat(mr.project_id, mr.iid)),
+        )
+        .await;
+        let mut items: Vec<ActivityItem> = mrs
+            .into_iter()
+            .map(|mr| self.merge_request_to_activity_item(mr))
+            .collect();
+        for (item, result) in items.iter_mut().zip(diffstats) {
+            match result {
+                Ok((insertions, deletions)) => {
+                    item.metadata
+                        .insert(INSERTIONS_KEY.to_string(), insertions.to_string());
+                    item.metadata
+                        .insert(DELETIONS_KEY.to_string(), deletions.to_string());
+                }
+                Err(e) => warn!("No diffstat for {} {}: {e}", self.platform_id, item.id),
+            }
+        }
+        Ok(items)
+    }
+
+    /// Lines added and removed by a merge request, counted over its diffs. Only the first
+    /// `page_size` files are fetched, so very large merge requests are undercounted, though rarely
+    /// by enough to change their size bucket.
+    async fn fetch_merge_request_diffstat(
+        &self,
+        project_id: u64,
+        iid: u64,
+    ) -> io::Result<(u32, u32)> {
+        let url = format!(
+            "{}/projects/{project_id}/merge_requests/{iid}/diffs",
+            self.config.api_base_url()
+        );
+        let per_page = self.page_size.to_string();
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.config.token))
+            .header("User-Agent", "reviewr/1.0")
+            .query(&[("per_page", per_page.as_str())]);
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| io::Error::other(format!("GitLab API request failed: {e}")))?;
+        if !response.status.is_success() {
+            ErrorContext::new(&self.platform_id, "fetch_mr_diffs")
+                .with_error("api_error", &format!("HTTP {}", response.status))
+                .with_request_details(&url, Some(response.status.as_u16()), Some(&response.body))
+                .log_error();
+            return Err(api_error("GitLab", response.status, &response.body));
+        }
+
+        let diffs: Vec<GitLabDiff> = serde_json::from_str(&response.body).map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+        })?;
+        Ok(diffs
+            .iter()
+            .map(|file| diffstat(&file.diff))
+            .fold((0, 0), |(insertions, deletions), (added, removed)| {
+                (insertions + added, deletions + removed)
+            }))
+    }
+
+    /// Pushes by the user after the date `after` (`YYYY-MM-DD`), one item per push with its
+    /// commit count, from the user's events
+    async fn fetch_push_events(&self, user: &str, after: &str) -> io::Result<Vec<ActivityItem>> {
+        let username = query_username(user);
+
+        let (url, params) =
+            self.activity_request(&ActivityCategory::CommitsPushed, username, after);
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.config.token))
+            .header("User-Agent", "reviewr/1.0")
+            .query(&params);
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| {
+                ErrorContext::new(&self.platform_id, "fetch_push_events")
+                    .with_error("network_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .with_metadata("user", user)
+                    .log_error();
+                io::Error::other(format!("GitLab API request failed: {e}"))
+            })?;
+
+        if !response.status.is_success() {
+            ErrorContext::new(&self.platform_id, "fetch_push_events")
+                .with_error("api_error", &format!("HTTP {}", response.status))
+                .with_request_details(&url, Some(response.status.as_u16()), Some(&response.body))
+                .with_metadata("user", user)
+                .log_error();
+            return Err(api_error("GitLab", response.status, &response.body));
+        }
+
+        let events: Vec<GitLabEvent> = serde_json::from_str(&response.body).map_err(|e| {
+            ErrorContext::new(&self.platform_id, "fetch_push_events")
+                .with_error("json_parse_error", &e.to_string())
+                .with_request_details(&url, None, None)
+                .with_metadata("user", user)
+                .log_error();
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+        })?;
+
+        // Events only carry the project id; look each project up once for its name and URL
+        let mut project_ids: Vec<u64> = events.iter().map(|event| event.project_id).collect();
+        project_ids.sort_unstable();
+        project_ids.dedup();
+        let projects: HashMap<u64, GitLabProject> =
+            futures::future::join_all(project_ids.iter().map(|&id| self.fetch_project(id)))
+                .await
+                .into_iter()
+                .zip(&project_ids)
+                .filter_map(|(result, &id)| match result {
+                    Ok(project) => Some((id, project)),
+                    Err(e) => {
+                        warn!("No details for {} project {id}: {e}", self.platform_id);
+                        None
+                    }
+                })
+                .collect();
+
+        Ok(events
+            .into_iter()
+            .filter_map(|event| {
+                let project = projects.get(&event.project_id);
+                self.push_event_to_activity_item(event, project)
+            })
+            .collect())
+    }
+
+    async fn fetch_project(&self, project_id: u64) -> io::Result<GitLabProject> {
+        let url = format!("{}/projects/{project_id}", self.config.api_base_url());
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.config.token))
+            .header("User-Agent", "reviewr/1.0");
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| io::Error::other(format!("GitLab API request failed: {e}")))?;
+        if !response.status.is_success() {
+            return Err(api_error("GitLab", response.status, &response.body));
+        }
+        serde_json::from_str(&response.body)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}")))
+    }
+
+    /// Fetch merge requests where user is assigned as reviewer
+    async fn fetch_merge_requests_for_review(
+        &self,
+        user: &str,
+        since: &str,
+    ) -> io::Result<Vec<ActivityItem>> {
+        let username = query_username(user);
+
+        let (url, params) =
+            self.activity_request(&ActivityCategory::MergeRequestsReviewed, username, since);
+
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.config.token))
+            .header("User-Agent", "reviewr/1.0")
+            .query(&params);
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| {
+                ErrorContext::new(&self.platform_id, "fetch_review_mrs")
+                    .with_error("network_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .with_metadata("user", user)
+                    .log_error();
+                io::Error::other(format!("GitLab API request failed: {e}"))
+            })?;
+
+        if !response.status.is_success() {
+            let status = response.status;
+            let error_text = response.body;
+
+            ErrorContext::new(&self.platform_id, "fetch_review_mrs")
+                .with_error("api_error", &format!("HTTP {status}"))
+                .with_request_details(&url, Some(status.as_u16()), Some(&error_text))
+                .with_metadata("user", user)
+                .log_error();
+
+            return Err(api_error("GitLab", status, &error_text));
+        }
+
+        let mrs: Vec<GitLabMergeRequest> = serde_json::from_str(&response.body).map_err(|e| {
+            ErrorContext::new(&self.platform_id, "fetch_review_mrs")
+                .with_error("json_parse_error", &e.to_string())
+                .with_request_details(&url, None, None)
+                .with_metadata("user", user)
+                .log_error();
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+        })?;
+
+        let comment_counts = futures::future::join_all(
+            mrs.iter()
+                .map(|mr| self.count_review_comments(mr.project_id, mr.iid, username)),
+        )
+        .await;
+        let mut items: Vec<ActivityItem> = mrs
+            .into_iter()
+            .map(|mr| self.merge_request_to_activity_item(mr))
+            .collect();
+        for (item, result) in items.iter_mut().zip(comment_counts) {
+            match result {
+                Ok(count) => {
+                    item.metadata
+                        .insert(COMMENTS_KEY.to_string(), count.to_string());
+                }
+                Err(e) => warn!(
+                    "No review comments for {} {}: {e}",
+                    self.platform_id, item.id
+                ),
+            }
+        }
+        Ok(items)
+    }
+
+    /// Notes `username` wrote on a merge request, leaving out system notes such as approvals
+    /// and pushes. Only the first `page_size` notes are read.
+    async fn count_review_comments(
+        &self,
+        project_id: u64,
+        iid: u64,
+        username: &str,
+    ) -> io::Result<usize> {
+        let url = format!(
+            "{}/projects/{project_id}/merge_requests/{iid}/notes",
+            self.config.api_base_url()
+        );
+        let per_page = self.page_size.to_string();
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.config.token))
+            .header("User-Agent", "reviewr/1.0")
+            .query(&[("per_page", per_page.as_str())]);
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| io::Error::other(format!("GitLab API request failed: {e}")))?;
+        if !response.status.is_success() {
+            ErrorContext::new(&self.platform_id, "fetch_mr_notes")
+                .with_error("api_error", &format!("HTTP {}", response.status))
+                .with_request_details(&url, Some(response.status.as_u16()), Some(&response.body))
+                .log_error();
+            return Err(api_error("GitLab", response.status, &response.body));
+        }
+
+        let notes: Vec<GitLabNote> = serde_json::from_str(&response.body).map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+        })?;
+        Ok(notes
+            .iter()
+            .filter(|note| !note.system && note.author.username.eq_ignore_ascii_case(username))
+            .count())
+    }
+
+    /// Fetch merge requests that were merged by the user
+    async fn fetch_merge_requests_merged_by(
+        &self,
+        user: &str,
+        since: &str,
+    ) -> io::Result<Vec<ActivityItem>> {
+        let username = query_username(user);
+
+        // Note: GitLab API doesn't have a direct filter for "merged_by_username"
+        // We need to fetch merged MRs and filter client-side
+        let (url, params) =
+            self.activity_request(&ActivityCategory::MergeRequestsMerged, username, since);
+
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.config.token))
+            .header("User-Agent", "reviewr/1.0")
+            .query(&params);
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| {
+                ErrorContext::new(&self.platform_id, "fetch_merged_mrs")
+                    .with_error("network_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .with_metadata("user", user)
+                    .log_error();
+                io::Error::other(format!("GitLab API request failed: {e}"))
+            })?;
+
+        if !response.status.is_success() {
+            let status = response.status;
+            let error_text = response.body;
+
+            ErrorContext::new(&self.platform_id, "fetch_merged_mrs")
+                .with_error("api_error", &format!("HTTP {status}"))
+                .with_request_details(&url, Some(status.as_u16()), Some(&error_text))
+                .with_metadata("user", user)
+                .log_error();
+
+            return Err(api_error("GitLab", status, &error_text));
+        }
+
+        let mrs: Vec<GitLabMergeRequest> = serde_json::from_str(&response.body).map_err(|e| {
+            ErrorContext::new(&self.platform_id, "fetch_merged_mrs")
+                .with_error("json_parse_error", &e.to_string())
+                .with_request_details(&url, None, None)
+                .with_metadata("user", user)
+                .log_error();
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+        })?;
+
+        // Filter to only MRs merged by this user
+        let filtered_mrs: Vec<GitLabMergeRequest> = mrs
+            .into_iter()
+            .filter(|mr| {
+                mr.merged_by
+                    .as_ref()
+                    .map(|merged_by| merged_by.username == username)
+                    .unwrap_or(false)
+            })
+            .collect();
+
+        Ok(filtered_mrs
+            .into_iter()
+            .map(|mr| self.merge_request_to_activity_item(mr))
+            .collect())
+    }
+
+    /// Fetch issues assigned to the user
+    async fn fetch_issues_assigned_to(
+        &self,
+        user: &str,
+        since: &str,
+    ) -> io::Result<Vec<ActivityItem>> {
+        let username = query_username(user);
+
+        let (url, params) =
+            self.activity_request(&ActivityCategory::IssuesAssigned, username, since);
+
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.config.token))
+            .header("User-Agent", "reviewr/1.0")
+            .query(&params);
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| {
+                ErrorContext::new(&self.platform_id, "fetch_assigned_issues")
+                    .with_error("network_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .with_metadata("user", user)
+                    .log_error();
+                io::Error::other(format!("GitLab API request failed: {e}"))
+            })?;
+
+        if !response.status.is_success() {
+            let status = response.status;
+            let error_text = response.body;
+
+            ErrorContext::new(&self.platform_id, "fetch_assigned_issues")
+                .with_error("api_error", &format!("HTTP {status}"))
+                .with_request_details(&url, Some(status.as_u16()), Some(&error_text))
+                .with_metadata("user", user)
+                .log_error();
+
+            return Err(api_error("GitLab", status, &error_text));
+        }
+
+        let issues: Vec<GitLabIssue> = serde_json::from_str(&response.body).map_err(|e| {
+            ErrorContext::new(&self.platform_id, "fetch_assigned_issues")
+                .with_error("json_parse_error", &e.to_string())
+                .with_request_details(&url, None, None)
+                .with_metadata("user", user)
+                .log_error();
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+        })?;
+
+        Ok(issues
+            .into_iter()
+            .map(|issue| self.issue_to_activity_item(issue))
+            .collect())
+    }
+
+    /// Fetch issues created by the user
+    async fn fetch_issues_created_by(
+        &self,
+        user: &str,
+        since: &str,
+    ) -> io::Result<Vec<ActivityItem>> {
+        let username = query_username(user);
+
+        let (url, params) =
+            self.activity_request(&ActivityCategory::IssuesCreated, username, since);
+
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.config.token))
+            .header("User-Agent", "reviewr/1.0")
+            .query(&params);
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| {
+                ErrorContext::new(&self.platform_id, "fetch_created_issues")
+                    .with_error("network_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .with_metadata("user", user)
+                    .log_error();
+                io::Error::other(format!("GitLab API request failed: {e}"))
+            })?;
+
+        if !response.status.is_success() {
+            let status = response.status;
+            let error_text = response.body;
+
+            ErrorContext::new(&self.platform_id, "fetch_created_issues")
+                .with_error("api_error", &format!("HTTP {status}"))
+                .with_request_details(&url, Some(status.as_u16()), Some(&error_text))
+                .with_metadata("user", user)
+                .log_error();
+
+            return Err(api_error("GitLab", status, &error_text));
+        }
+
+        let issues: Vec<GitLabIssue> = serde_json::from_str(&response.body).map_err(|e| {
+            ErrorContext::new(&self.platform_id, "fetch_created_issues")
+                .with_error("json_parse_error", &e.to_string())
+                .with_request_details(&url, None, None)
+                .with_metadata("user", user)
+                .log_error();
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+        })?;
+
+        Ok(issues
+            .into_iter()
+            .map(|issue| self.issue_to_activity_item(issue))
+            .collect())
+    }
+
+    /// Convert GitLab merge request to ActivityItem
+    fn merge_request_to_activity_item(&self, mr: GitLabMergeRequest) -> ActivityItem {
+        let status = match mr.state.as_str() {
+            "opened" => "Open",
+            "merged" => "Merged",
+            "closed" => "Closed",
+            _ => &mr.state,
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert("author".to_string(), mr.author.name);
+        metadata.insert("item_type".to_string(), "Merge Request".to_string());
+        metadata.insert("target_branch".to_string(), mr.target_branch);
+        metadata.insert("source_branch".to_string(), mr.source_branch);
+        if !mr.labels.is_empty() {
+            metadata.insert("labels".to_string(), mr.labels.join(", "));
+        }
+
+        if let Some(assignee) = mr.assignees.first() {
+            metadata.insert("assignee".to_string(), assignee.name.clone());
+        }
+
+        if let Some(merged_by) = mr.merged_by {
+            metadata.insert("merged_by".to_string(), merged_by.name);
+        }
+
+        let mut item = ActivityItem {
+            id: format!("mr-{}", mr.iid),
+            title: mr.title,
+            url: mr.web_url,
+            status: status.to_string(),
+            created: mr.created_at,
+            updated: mr.updated_at,
+            platform: self.config.name.clone(),
+            category: ActivityCategory::MergeRequestsCreated, // Will be overridden based on context
+            project: mr
+                .project
+                .map(|p| format!("{} ({})", p.name, p.path_with_namespace))
+                .unwrap_or_else(|| format!("Project ID: {}", mr.project_id)),
+            metadata,
+        };
+        TimestampSource::GitLab.flag_unparsed_dates(&mut item);
+        item
+    }
+
+    /// Convert a push event to an ActivityItem; events without push data are skipped
+    fn push_event_to_activity_item(
+        &self,
+        event: GitLabEvent,
+        project: Option<&GitLabProject>,
+    ) -> Option<ActivityItem> {
+        let push = event.push_data?;
+        let ref_name = push.ref_name.unwrap_or_default();
+        let title = match (&push.commit_title, push.commit_count) {
+            (Some(commit_title), 1) => commit_title.clone(),
+            (Some(commit_title), count) => format!("{commit_title} (+{} more)", count - 1),
+            (None, _) => format!("{} {} {ref_name}", push.action, push.ref_type),
+        };
+
+        let mut metadata = HashMap::new();
+        if let Some(author) = event.author_username {
+            metadata.insert("author".to_string(), author);
+        }
+        metadata.insert("item_type".to_string(), "Push".to_string());
+        metadata.insert(COMMIT_COUNT_KEY.to_string(), push.commit_count.to_string());
+        metadata.insert("ref".to_string(), ref_name);
+        if let Some(commit) = &push.commit_to {
+            metadata.insert("commit".to_string(), commit.clone());
+        }
+
+        let url = match (project, &push.commit_to) {
+            (Some(project), Some(commit)) => format!("{}/-/commit/{commit}", project.web_url),
+            (Some(project), None) => project.web_url.clone(),
+            (None, _) => String::new(),
+        };
+        let mut item = ActivityItem {
+            id: format!("push-{}", event.id),
+            title,
+            url,
+            status: "Pushed".to_string(),
+            created: event.created_at.clone(),
+            updated: event.created_at,
+            platform: self.config.name.clone(),
+            category: ActivityCategory::CommitsPushed,
+            project: project
+                .map(|p| format!("{} ({})", p.name, p.path_with_namespace))
+                .unwrap_or_else(|| format!("Project ID: {}", event.project_id)),
+            metadata,
+        };
+        TimestampSource::GitLab.flag_unparsed_dates(&mut item);
+        Some(item)
+    }
+
+    /// Convert GitLab issue to ActivityItem
+    fn issue_to_activity_item(&self, issue: GitLabIssue) -> ActivityItem {
+        let status = match issue.state.as_str() {
+            "opened" => "Open",
+            "closed" => "Closed",
+            _ => &issue.state,
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert("author".to_string(), issue.author.name);
+        metadata.insert("item_type".to_string(), "Issue".to_string());
+        if !issue.labels.is_empty() {
+            metadata.insert("labels".to_string(), issue.labels.join(", "));
+        }
+
+        if let Some(assignee) = issue.assignees.first() {
+            metadata.insert("assignee".to_string(), assignee.name.clone());
+        }
+
+        let mut item = ActivityItem {
+            id: format!("issue-{}", issue.iid),
+            title: issue.title,
+            url: issue.web_url,
+            status: status.to_string(),
+            created: issue.created_at,
+            updated: issue.updated_at,
+            platform: self.config.name.clone(),
+            category: ActivityCategory::IssuesCreated, // Will be overridden based on context
+            project: issue
+                .project
+                .map(|p| format!("{} ({})", p.name, p.path_with_namespace))
+                .unwrap_or_else(|| format!("Project ID: {}", issue.project_id)),
+            metadata,
+        };
+        TimestampSource::GitLab.flag_unparsed_dates(&mut item);
+        item
+    }
+}
+
+// GitLab API response structures
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitLabProject {
+    pub id: u64,
+    pub name: String,
+    pub path_with_namespace: String,
+    pub web_url: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitLabMergeRequest {
+    pub id: u64,
+    pub iid: u64,
+    pub title: String,
+    pub description: Option<String>,
+    pub state: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub merged_at: Option<String>,
+    pub closed_at: Option<String>,
+    pub target_branch: String,
+    pub source_branch: String,
+    pub author: GitLabUser,
+    pub assignees: Vec<GitLabUser>,
+    pub reviewers: Vec<GitLabUser>,
+    pub merged_by: Option<GitLabUser>,
+    pub web_url: String,
+    pub project_id: u64,
+    pub project: Option<GitLabProject>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+/// One changed file of a merge request, from `merge_requests/:iid/diffs`
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitLabDiff {
+    #[serde(default)]
+    pub diff: String,
+}
+
+/// A comment on a merge request, from `merge_requests/:iid/notes`
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitLabNote {
+    pub id: u64,
+    pub author: GitLabUser,
+    /// Generated by GitLab for approvals, pushes and other events
+    #[serde(default)]
+    pub system: bool,
+}
+
+/// An entry of `users/:username/events`; only pushes are requested
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitLabEvent {
+    pub id: u64,
+    pub project_id: u64,
+    pub created_at: String,
+    #[serde(default)]
+    pub author_username: Option<String>,
+    #[serde(default)]
+    pub push_data: Option<GitLabPushData>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitLabPushData {
+    pub commit_count: u64,
+    /// `pushed`, `created` or `removed`
+    pub action: String,
+    /// `branch` or `tag`
+    pub ref_type: String,
+    #[serde(rename = "ref")]
+    pub ref_name: Option<String>,
+    pub commit_title: Option<String>,
+    /// Head commit after the push; none when a ref was removed
+    pub commit_to: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitLabIssue {
+    pub id: u64,
+    pub iid: u64,
+    pub title: String,
+    pub description: Option<String>,
+    pub state: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub closed_at: Option<String>,
+    pub author: GitLabUser,
+    pub assignees: Vec<GitLabUser>,
+    pub web_url: String,
+    pub project_id: u64,
+    pub project: Option<GitLabProject>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitLabUser {
+    pub id: u64,
+    pub username: String,
+    pub name: String,
+    pub email: Option<String>,
+    #[serde(default)]
+    pub public_email: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unified_config::RequestLimits;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn platform(server: &MockServer, data_path: &DataPath) -> GitLabPlatform {
+        let config = GitLabConfig {
+            name: "GitLab".to_string(),
+            url: server.uri(),
+            token: "token".to_string(),
+            accept_invalid_certs: false,
+            limits: RequestLimits::default(),
+        };
+        GitLabPlatform::new(config, "work".to_string(), data_path).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_explain_queries_match_fetches() {
+        let server = MockServer::start().await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+
+        let plan = platform(&server, &data_path)
+            .explain_queries("jane@example.com", 30)
+            .unwrap()
+            .unwrap();
+        assert_eq!(plan.auth, "Bearer token [REDACTED]");
+        assert!(plan.notes[0].contains("/api/v4/users?search=jane%40example.com"));
+        let created = format!(
+            "GET {}/api/v4/merge_requests?author_username=jane&created_after=",
+            server.uri()
+        );
+        assert!(plan.requests[0].request.starts_with(&created), "{plan:?}");
+        let pushes = format!(
+            "GET {}/api/v4/users/jane/events?action=pushed&after=",
+            server.uri()
+        );
+        assert!(plan.requests[5].request.starts_with(&pushes), "{plan:?}");
+        assert!(!plan.requests[5].request.contains("with_projects_enabled"));
+        // Day precision keeps the URLs, and their cached validators, stable between runs
+        assert!(
+            plan.requests[0].request.contains("T00%3A00%3A00.000Z"),
+            "{plan:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_push_events_and_diffstats() {
+        let server = MockServer::start().await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let platform = platform(&server, &data_path);
+
+        Mock::given(method("GET"))
+            .and(path("/api/v4/users/sam/events"))
+            .and(query_param("action", "pushed"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[
+                    {"id": 1, "project_id": 7, "created_at": "2025-03-01T10:00:00.000Z",
+                     "author_username": "sam",
+                     "push_data": {"commit_count": 3, "action": "pushed", "ref_type": "branch",
+                                   "ref": "main", "commit_title": "Fix parser",
+                                   "commit_to": "abc123"}},
+                    {"id": 2, "project_id": 7, "created_at": "2025-03-02T10:00:00.000Z",
+                     "push_data": {"commit_count": 0, "action": "removed", "ref_type": "branch",
+                                   "ref": "old", "commit_title": null, "commit_to": null}}
+                ]"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v4/projects/7"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{"id": 7, "name": "core", "path_with_namespace": "team/core",
+                    "web_url": "https://gitlab.example.com/team/core"}"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v4/merge_requests"))
+            .and(query_param("author_username", "sam"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"id": 100, "iid": 4, "title": "Add parser", "description": null,
+                     "state": "merged", "created_at": "2025-03-01T09:00:00.000Z",
+                     "updated_at": "2025-03-02T09:00:00.000Z", "merged_at": null,
+                     "closed_at": null, "target_branch": "main", "source_branch": "parser",
+                     "author": {"id": 1, "username": "sam", "name": "Sam", "email": null},
+                     "assignees": [], "reviewers": [], "merged_by": null,
+                     "web_url": "https://gitlab.example.com/team/core/-/merge_requests/4",
+                     "project_id": 7, "project": null}]"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v4/projects/7/merge_requests/4/diffs"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"[{"diff": "@@ -1,2 +1,3 @@\n-old\n+new\n+more\n"}]"#),
+            )
+            .mount(&server)
+            .await;
+        // Everything else comes back empty
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+            .mount(&server)
+            .await;
+
+        let activities = platform.get_detailed_activities("sam", 30).await.unwrap();
+        let pushes = &activities.items_by_category[&ActivityCategory::CommitsPushed];
+        assert_eq!(pushes.len(), 2);
+        assert_eq!(pushes[0].title, "Fix parser (+2 more)");
+        assert_eq!(pushes[0].project, "core (team/core)");
+        assert_eq!(
+            pushes[0].url,
+            "https://gitlab.example.com/team/core/-/commit/abc123"
+        );
+        assert_eq!(pushes[0].metadata[COMMIT_COUNT_KEY], "3");
+        assert_eq!(pushes[1].title, "removed branch old");
+
+        let authored = &activities.items_by_category[&ActivityCategory::MergeRequestsCreated];
+        assert_eq!(authored[0].metadata[INSERTIONS_KEY], "2");
+        assert_eq!(authored[0].metadata[DELETIONS_KEY], "1");
+    }
+
+    #[tokio::test]
+    async fn test_failed_fetch_is_reported_for_its_category() {
+        let server = MockServer::start().await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let platform = platform(&server, &data_path);
+
+        Mock::given(method("GET"))
+            .and(path("/api/v4/issues"))
+            .and(query_param("assignee_username", "sam"))
+            .respond_with(ResponseTemplate::new(403).set_body_string("403 Forbidden"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+            .mount(&server)
+            .await;
+
+        let activities = platform.get_detailed_activities("sam", 30).await.unwrap();
+        assert!(activities.is_partial());
+        assert!(
+            activities.failed_categories[&ActivityCategory::IssuesAssigned].contains("403"),
+            "{activities:?}"
+        );
+        // A 403 is not mistaken for "no issues"; the other categories still load
+        assert!(
+            !activities
+                .items_by_category
+                .contains_key(&ActivityCategory::IssuesAssigned)
+        );
+        assert!(
+            activities
+                .items_by_category
+                .contains_key(&ActivityCategory::IssuesCreated)
+        );
+        assert_eq!(activities.failed_categories.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_review_comments_of_reviewed_merge_requests() {
+        let server = MockServer::start().await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let platform = platform(&server, &data_path);
+
+        Mock::given(method("GET"))
+            .and(path("/api/v4/merge_requests"))
+            .and(query_param("reviewer_username", "sam"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"id": 200, "iid": 9, "title": "Refactor", "description": null,
+                     "state": "opened", "created_at": "2025-03-01T09:00:00.000Z",
+                     "updated_at": "2025-03-02T09:00:00.000Z", "merged_at": null,
+                     "closed_at": null, "target_branch": "main", "source_branch": "refactor",
+                     "author": {"id": 2, "username": "kim", "name": "Kim", "email": null},
+                     "assignees": [], "reviewers": [], "merged_by": null,
+                     "web_url": "https://gitlab.example.com/team/core/-/merge_requests/9",
+                     "project_id": 7, "project": null}]"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v4/projects/7/merge_requests/9/notes"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"id": 1, "system": false,
+                     "author": {"id": 1, "username": "sam", "name": "Sam", "email": null}},
+                    {"id": 2, "system": true,
+                     "author": {"id": 1, "username": "sam", "name": "Sam", "email": null}},
+                    {"id": 3, "system": false,
+                     "author": {"id": 2, "username": "kim", "name": "Kim", "email": null}},
+                    {"id": 4, "system": false,
+                     "author": {"id": 1, "username": "Sam", "name": "Sam", "email": null}}]"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+            .mount(&server)
+            .await;
+
+        let activities = platform.get_detailed_activities("sam", 30).await.unwrap();
+        let reviewed = &activities.items_by_category[&ActivityCategory::MergeRequestsReviewed];
+        assert_eq!(reviewed[0].metadata[COMMENTS_KEY], "2");
+        assert_eq!(
+            reviewed[0].category,
+            ActivityCategory::MergeRequestsReviewed
+        );
+    }
+}
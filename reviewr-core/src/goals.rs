@@ -0,0 +1,457 @@
+use crate::atomic_write::write_with_backup;
+use crate::models::DataPath;
+use crate::platform::{ActivityCategory, ActivityItem};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Where a goal stands, as judged by the manager
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GoalStatus {
+    #[default]
+    OnTrack,
+    AtRisk,
+    Done,
+}
+
+impl GoalStatus {
+    pub const ALL: [GoalStatus; 3] = [GoalStatus::OnTrack, GoalStatus::AtRisk, GoalStatus::Done];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalStatus::OnTrack => "on-track",
+            GoalStatus::AtRisk => "at-risk",
+            GoalStatus::Done => "done",
+        }
+    }
+
+    /// The status after this one, wrapping around, for cycling through them with one key
+    pub fn next(&self) -> Self {
+        match self {
+            GoalStatus::OnTrack => GoalStatus::AtRisk,
+            GoalStatus::AtRisk => GoalStatus::Done,
+            GoalStatus::Done => GoalStatus::OnTrack,
+        }
+    }
+}
+
+impl fmt::Display for GoalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A status a goal was given on a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GoalUpdate {
+    pub date: NaiveDate,
+    pub status: GoalStatus,
+}
+
+/// A review goal linked to the projects and labels whose items count towards it
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Goal {
+    pub title: String,
+    #[serde(default)]
+    pub status: GoalStatus,
+    /// Day the goal should be reached by
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub projects: Vec<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Number of completed items that marks the goal as done
+    #[serde(default)]
+    pub target_items: Option<u32>,
+    /// Links to design docs, demos or tickets that show progress
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub evidence: Vec<String>,
+    /// Every status change with its day, oldest first; kept by `reviewr goals`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<GoalUpdate>,
+}
+
+impl Goal {
+    /// Change the status, recording the change on `today`; a second change on the same day
+    /// replaces the first
+    pub fn set_status(&mut self, status: GoalStatus, today: NaiveDate) {
+        if self.status == status && !self.history.is_empty() {
+            return;
+        }
+        self.status = status;
+        match self.history.last_mut() {
+            Some(last) if last.date == today => last.status = status,
+            _ => self.history.push(GoalUpdate {
+                date: today,
+                status,
+            }),
+        }
+    }
+
+    /// The status at the end of `date`; None when the goal had no recorded status yet
+    pub fn status_on(&self, date: NaiveDate) -> Option<GoalStatus> {
+        self.history
+            .iter()
+            .rev()
+            .find(|update| update.date <= date)
+            .map(|update| update.status)
+    }
+
+    /// InvalidInput for an empty title or an evidence link that is not an http(s) URL
+    pub fn validate(&self) -> io::Result<()> {
+        if self.title.trim().is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Goal title cannot be empty",
+            ));
+        }
+        for link in &self.evidence {
+            let valid =
+                url::Url::parse(link).is_ok_and(|url| matches!(url.scheme(), "http" | "https"));
+            if !valid {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("Evidence '{link}' is not an http(s) URL"),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// On-disk layout of `goals/{employee}.toml`
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct GoalsFile {
+    #[serde(default, rename = "goal", skip_serializing_if = "Vec::is_empty")]
+    goals: Vec<Goal>,
+}
+
+/// Activity matched against a single goal for the review period
+#[derive(Debug, Clone)]
+pub struct GoalOutcome {
+    pub goal: Goal,
+    pub completed: Vec<ActivityItem>,
+    pub open_items: usize,
+    pub progress_percent: u8,
+}
+
+pub struct GoalsService;
+
+impl GoalsService {
+    fn goals_path(data_path: &DataPath, employee_name: &str) -> PathBuf {
+        data_path.goals_dir.join(format!("{employee_name}.toml"))
+    }
+
+    /// Load an employee's goals; a missing goals file means no goals
+    pub fn load_goals(data_path: &DataPath, employee_name: &str) -> io::Result<Vec<Goal>> {
+        let goals_path = Self::goals_path(data_path, employee_name);
+        if !goals_path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = fs::read_to_string(&goals_path)?;
+        let file: GoalsFile = toml::from_str(&content).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid goals file {}: {e}", goals_path.display()),
+            )
+        })?;
+        Ok(file.goals)
+    }
+
+    /// Replace an employee's goals file, keeping the previous version as `.bak`. Comments in a
+    /// hand-written file are not kept.
+    pub fn save_goals(data_path: &DataPath, employee_name: &str, goals: &[Goal]) -> io::Result<()> {
+        for goal in goals {
+            goal.validate()?;
+        }
+        let file = GoalsFile {
+            goals: goals.to_vec(),
+        };
+        let content = toml::to_string(&file).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to serialize goals: {e}"),
+            )
+        })?;
+        fs::create_dir_all(&data_path.goals_dir)?;
+        write_with_backup(&Self::goals_path(data_path, employee_name), content)
+    }
+
+    /// Match activity items to each goal and estimate progress. A goal marked done is at 100%;
+    /// otherwise without a `target_items`, progress is the share of completed items among all
+    /// items linked to the goal. Only the employee's own work is linked: changes and merge
+    /// requests they created and issues assigned to or resolved by them, not what they reviewed.
+    pub fn goal_outcomes<'a>(
+        goals: &[Goal],
+        items: impl IntoIterator<Item = &'a ActivityItem>,
+    ) -> Vec<GoalOutcome> {
+        // The same change can appear in several categories (e.g. created and merged)
+        let mut seen = HashSet::new();
+        let items: Vec<&ActivityItem> = items
+            .into_iter()
+            .filter(|item| Self::is_own_work(item))
+            .filter(|item| seen.insert((item.platform.as_str(), item.id.as_str())))
+            .collect();
+
+        goals
+            .iter()
+            .map(|goal| {
+                let linked: Vec<&ActivityItem> = items
+                    .iter()
+                    .copied()
+                    .filter(|item| Self::matches_goal(goal, item))
+                    .collect();
+                let completed: Vec<ActivityItem> = linked
+                    .iter()
+                    .filter(|item| Self::is_completed(item))
+                    .map(|item| (*item).clone())
+                    .collect();
+                let open_items = linked.len() - completed.len();
+
+                let progress = match goal.target_items {
+                    _ if goal.status == GoalStatus::Done => 100,
+                    Some(0) => 100,
+                    Some(target) => completed.len() * 100 / target as usize,
+                    None if linked.is_empty() => 0,
+                    None => completed.len() * 100 / linked.len(),
+                };
+
+                GoalOutcome {
+                    goal: goal.clone(),
+                    completed,
+                    open_items,
+                    progress_percent: progress.min(100) as u8,
+                }
+            })
+            .collect()
+    }
+
+    fn matches_goal(goal: &Goal, item: &ActivityItem) -> bool {
+        // GitLab items carry "name (namespace/path)" as their project
+        let item_project = item.project.to_lowercase();
+        let project_match = goal.projects.iter().any(|project| {
+            let project = project.to_lowercase();
+            item_project == project
+                || item_project.starts_with(&format!("{project} ("))
+                || item_project.ends_with(&format!("({project})"))
+        });
+
+        let item_labels: Vec<&str> = ["labels", "components"]
+            .iter()
+            .filter_map(|key| item.metadata.get(*key))
+            .flat_map(|value| value.split(',').map(str::trim))
+            .collect();
+        let label_match = goal.labels.iter().any(|label| {
+            item_labels
+                .iter()
+                .any(|item_label| item_label.eq_ignore_ascii_case(label))
+        });
+
+        project_match || label_match
+    }
+
+    fn is_own_work(item: &ActivityItem) -> bool {
+        matches!(
+            item.category,
+            ActivityCategory::ChangesCreated
+                | ActivityCategory::ChangesMerged
+                | ActivityCategory::MergeRequestsCreated
+                | ActivityCategory::MergeRequestsMerged
+                | ActivityCategory::IssuesAssigned
+                | ActivityCategory::IssuesResolved
+        )
+    }
+
+    fn is_completed(item: &ActivityItem) -> bool {
+        matches!(
+            item.category,
+            ActivityCategory::ChangesMerged
+                | ActivityCategory::IssuesResolved
+                | ActivityCategory::MergeRequestsMerged
+        ) || ["merged", "done", "resolved", "closed"]
+            .iter()
+            .any(|status| item.status.eq_ignore_ascii_case(status))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::ItemBuilder;
+    use tempfile::tempdir;
+
+    fn item(id: &str, project: &str, status: &str, category: ActivityCategory) -> ActivityItem {
+        ItemBuilder::new(id)
+            .with_project(project)
+            .with_status(status)
+            .with_category(category)
+            .build()
+    }
+
+    #[test]
+    fn test_load_goals_missing_file() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        assert!(
+            GoalsService::load_goals(&data_path, "John Doe")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_load_goals_from_toml() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.goals_dir).unwrap();
+        fs::write(
+            data_path.goals_dir.join("John Doe.toml"),
+            "[[goal]]\ntitle = \"Ship auth rewrite\"\nprojects = [\"auth-service\"]\ntarget_items = 4\n",
+        )
+        .unwrap();
+
+        let goals = GoalsService::load_goals(&data_path, "John Doe").unwrap();
+        assert_eq!(goals.len(), 1);
+        assert_eq!(goals[0].projects, vec!["auth-service"]);
+        assert_eq!(goals[0].target_items, Some(4));
+    }
+
+    #[test]
+    fn test_goal_outcomes_progress() {
+        let mut labelled = item("3", "web", "opened", ActivityCategory::MergeRequestsCreated);
+        labelled
+            .metadata
+            .insert("labels".to_string(), "security, frontend".to_string());
+        let items = vec![
+            item(
+                "1",
+                "auth-service",
+                "MERGED",
+                ActivityCategory::ChangesMerged,
+            ),
+            // Same change listed again under another category must not be double counted
+            item(
+                "1",
+                "auth-service",
+                "MERGED",
+                ActivityCategory::ChangesCreated,
+            ),
+            item("2", "auth-service", "NEW", ActivityCategory::ChangesCreated),
+            labelled,
+        ];
+        let goals = vec![
+            Goal {
+                title: "Auth".to_string(),
+                projects: vec!["auth-service".to_string()],
+                ..Default::default()
+            },
+            Goal {
+                title: "Security".to_string(),
+                labels: vec!["Security".to_string()],
+                target_items: Some(2),
+                ..Default::default()
+            },
+        ];
+
+        let outcomes = GoalsService::goal_outcomes(&goals, &items);
+        assert_eq!(outcomes[0].completed.len(), 1);
+        assert_eq!(outcomes[0].open_items, 1);
+        assert_eq!(outcomes[0].progress_percent, 50);
+        assert!(outcomes[1].completed.is_empty());
+        assert_eq!(outcomes[1].open_items, 1);
+        assert_eq!(outcomes[1].progress_percent, 0);
+    }
+
+    #[test]
+    fn test_reviewed_items_do_not_complete_goals() {
+        let items = vec![
+            item(
+                "1",
+                "auth-service",
+                "MERGED",
+                ActivityCategory::ChangesReviewed,
+            ),
+            item(
+                "2",
+                "auth-service",
+                "merged",
+                ActivityCategory::MergeRequestsReviewed,
+            ),
+            item(
+                "3",
+                "auth-service",
+                "MERGED",
+                ActivityCategory::ReviewsGiven,
+            ),
+            item("4", "auth-service", "NEW", ActivityCategory::ChangesCreated),
+        ];
+        let goals = vec![Goal {
+            title: "Auth".to_string(),
+            projects: vec!["auth-service".to_string()],
+            target_items: Some(1),
+            ..Default::default()
+        }];
+
+        let outcomes = GoalsService::goal_outcomes(&goals, &items);
+        assert!(outcomes[0].completed.is_empty());
+        assert_eq!(outcomes[0].open_items, 1);
+        assert_eq!(outcomes[0].progress_percent, 0);
+    }
+
+    #[test]
+    fn test_status_history() {
+        let day = |d| NaiveDate::from_ymd_opt(2025, 3, d).unwrap();
+        let mut goal = Goal {
+            title: "Ship auth rewrite".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(goal.status_on(day(1)), None);
+
+        goal.set_status(GoalStatus::OnTrack, day(1));
+        goal.set_status(GoalStatus::AtRisk, day(10));
+        // A second change on the same day replaces the first; unchanged ones are not recorded
+        goal.set_status(GoalStatus::Done, day(20));
+        goal.set_status(GoalStatus::OnTrack, day(20));
+        goal.set_status(GoalStatus::OnTrack, day(25));
+        assert_eq!(goal.history.len(), 3);
+        assert_eq!(goal.status_on(day(9)), Some(GoalStatus::OnTrack));
+        assert_eq!(goal.status_on(day(15)), Some(GoalStatus::AtRisk));
+        assert_eq!(goal.status_on(day(31)), Some(GoalStatus::OnTrack));
+        assert_eq!(GoalStatus::Done.next(), GoalStatus::OnTrack);
+    }
+
+    #[test]
+    fn test_save_and_load_goals() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let mut goal = Goal {
+            title: "Mentor a new hire".to_string(),
+            target_date: NaiveDate::from_ymd_opt(2025, 6, 30),
+            evidence: vec!["https://docs.example.com/onboarding".to_string()],
+            ..Default::default()
+        };
+        goal.set_status(
+            GoalStatus::AtRisk,
+            NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+        );
+        GoalsService::save_goals(&data_path, "John Doe", std::slice::from_ref(&goal)).unwrap();
+
+        let content = fs::read_to_string(data_path.goals_dir.join("John Doe.toml")).unwrap();
+        assert!(content.contains("status = \"at-risk\""));
+        assert!(content.contains("target_date = \"2025-06-30\""));
+        assert_eq!(
+            GoalsService::load_goals(&data_path, "John Doe").unwrap(),
+            vec![goal.clone()]
+        );
+
+        goal.evidence.push("docs/onboarding.md".to_string());
+        let error = GoalsService::save_goals(&data_path, "John Doe", &[goal]).unwrap_err();
+        assert!(error.to_string().contains("not an http(s) URL"));
+    }
+}
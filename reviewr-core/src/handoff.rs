@@ -0,0 +1,460 @@
+//! Handoff folders: everything reviewr keeps about one employee, for passing a report on to
+//! another manager. The folder mirrors the data directory (`employees/`, `notes/`, `goals/`,
+//! `annotations.json`) plus `snapshots/` with the cached activity and a `handoff.toml` manifest.
+
+use crate::annotations::{Annotation, AnnotationStore};
+use crate::cache::ActivityCache;
+use crate::employee::EmployeeService;
+use crate::models::{DataPath, validate_employee_name};
+use crate::note_encryption::{NoteEncryptionService, NotesCipher};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+const MANIFEST: &str = "handoff.toml";
+const ANNOTATIONS: &str = "annotations.json";
+const SNAPSHOTS_PREFIX: &str = "snapshots/";
+
+/// Contents of `handoff.toml`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HandoffManifest {
+    pub employee: String,
+    /// RFC 3339
+    pub exported_at: String,
+    /// Every file is encrypted to an age recipient and stored with an extra `.age`
+    #[serde(default)]
+    pub encrypted: bool,
+    /// Paths relative to the folder, without the `.age` of encrypted files
+    pub files: Vec<String>,
+}
+
+pub struct HandoffService;
+
+impl HandoffService {
+    /// Write the employee's record, notes, goals, activity snapshots and the annotations of
+    /// those activities to the new folder `output`, encrypted to `recipient` if given
+    pub fn export(
+        data_path: &DataPath,
+        employee_name: &str,
+        output: &Path,
+        recipient: Option<&str>,
+        cipher: &dyn NotesCipher,
+    ) -> io::Result<HandoffManifest> {
+        validate_employee_name(employee_name)?;
+        if !EmployeeService::employee_exists(data_path, employee_name) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Employee '{employee_name}' not found"),
+            ));
+        }
+        if let Some(recipient) = recipient {
+            NoteEncryptionService::validate_recipient(recipient)?;
+        }
+        if output.exists() && fs::read_dir(output)?.next().is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists and is not empty", output.display()),
+            ));
+        }
+
+        let mut entries: Vec<(String, Vec<u8>)> = Vec::new();
+        for (path, relative) in EmployeeService::employee_files(data_path, employee_name) {
+            if path.extension().is_some_and(|ext| ext == "bak") {
+                continue;
+            }
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            entries.push((relative, fs::read(&path)?));
+        }
+
+        let snapshots =
+            ActivityCache::new(data_path, Duration::ZERO).employee_snapshots(employee_name)?;
+        let items: Vec<(&str, &str)> = snapshots
+            .iter()
+            .flat_map(|snapshot| {
+                snapshot
+                    .activities
+                    .items_by_category
+                    .values()
+                    .flatten()
+                    .map(|item| (snapshot.platform_id.as_str(), item.id.as_str()))
+            })
+            .collect();
+        let annotations = AnnotationStore::load(data_path).subset(items);
+        if !annotations.is_empty() {
+            let json = serde_json::to_string_pretty(&annotations).map_err(io::Error::other)?;
+            entries.push((ANNOTATIONS.to_string(), json.into_bytes()));
+        }
+        for snapshot in &snapshots {
+            entries.push((
+                format!(
+                    "{SNAPSHOTS_PREFIX}{}-{}d.json",
+                    snapshot_stem(&snapshot.platform_id),
+                    snapshot.days
+                ),
+                snapshot.json.clone().into_bytes(),
+            ));
+        }
+
+        let manifest = HandoffManifest {
+            employee: employee_name.to_string(),
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            encrypted: recipient.is_some(),
+            files: entries.iter().map(|(name, _)| name.clone()).collect(),
+        };
+        for (name, content) in entries {
+            let (name, content) = match recipient {
+                Some(recipient) => (
+                    format!("{name}.age"),
+                    cipher.encrypt(recipient.trim(), &content)?,
+                ),
+                None => (name, content),
+            };
+            let path = output.join(&name);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, content)?;
+        }
+        let manifest_toml = toml::to_string_pretty(&manifest).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to serialize handoff manifest: {e}"),
+            )
+        })?;
+        fs::create_dir_all(output)?;
+        fs::write(output.join(MANIFEST), manifest_toml)?;
+        info!(
+            "Exported {} files for '{employee_name}' to {}",
+            manifest.files.len(),
+            output.display()
+        );
+        Ok(manifest)
+    }
+
+    /// Add the employee in a handoff folder to this data directory. Encrypted folders need the
+    /// `identity` of their recipient. Annotations are merged; everything else must be new.
+    pub fn import(
+        data_path: &DataPath,
+        folder: &Path,
+        identity: Option<&Path>,
+        cipher: &dyn NotesCipher,
+    ) -> io::Result<HandoffManifest> {
+        let manifest: HandoffManifest = toml::from_str(&fs::read_to_string(folder.join(MANIFEST))?)
+            .map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Invalid {MANIFEST}: {e}"),
+                )
+            })?;
+        let employee = manifest.employee.as_str();
+        validate_employee_name(employee)?;
+        if EmployeeService::employee_exists(data_path, employee) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("Employee '{employee}' already exists"),
+            ));
+        }
+        if manifest.encrypted && identity.is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The handoff is encrypted; pass --identity or set age_identity",
+            ));
+        }
+
+        // Read and check every file before writing anything
+        let mut entries: Vec<(String, Vec<u8>)> = Vec::new();
+        for name in &manifest.files {
+            validate_entry_name(employee, name)?;
+            let content = match identity.filter(|_| manifest.encrypted) {
+                Some(identity) => {
+                    cipher.decrypt(identity, &fs::read(folder.join(format!("{name}.age")))?)?
+                }
+                None => fs::read(folder.join(name))?,
+            };
+            entries.push((name.clone(), content));
+        }
+        if !entries
+            .iter()
+            .any(|(name, _)| name.starts_with("employees/"))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("The handoff contains no employee record for '{employee}'"),
+            ));
+        }
+        for (name, content) in &entries {
+            let path = data_path.root.join(name);
+            if (name.starts_with("notes/") || name.starts_with("goals/")) && path.exists() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!("{} already exists", path.display()),
+                ));
+            }
+            if name.starts_with("employees/") {
+                let content = String::from_utf8(content.clone()).map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "The employee record is not UTF-8",
+                    )
+                })?;
+                EmployeeService::validate_employee_toml(&content, employee)?;
+            }
+        }
+
+        let cache = ActivityCache::new(data_path, Duration::ZERO);
+        for (name, content) in entries {
+            if name == ANNOTATIONS {
+                let annotations: BTreeMap<String, Annotation> = serde_json::from_slice(&content)
+                    .map_err(|e| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("Invalid {ANNOTATIONS}: {e}"),
+                        )
+                    })?;
+                AnnotationStore::load(data_path).merge(annotations)?;
+            } else if name.starts_with(SNAPSHOTS_PREFIX) {
+                let json = String::from_utf8_lossy(&content);
+                let owner = cache.import_snapshot(&json)?;
+                if owner != employee {
+                    log::warn!("Imported a snapshot of '{owner}' from the handoff of '{employee}'");
+                }
+            } else {
+                let path = data_path.root.join(&name);
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, content)?;
+            }
+        }
+        info!(
+            "Imported {} files for '{employee}' from {}",
+            manifest.files.len(),
+            folder.display()
+        );
+        Ok(manifest)
+    }
+}
+
+/// Platform ids like `custom:wiki` as a file name
+fn snapshot_stem(platform_id: &str) -> String {
+    platform_id
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Only the files an export writes are accepted, so a handoff cannot write elsewhere
+fn validate_entry_name(employee: &str, name: &str) -> io::Result<()> {
+    let valid = [
+        format!("employees/{employee}.toml"),
+        format!("notes/{employee}.md"),
+        format!("notes/{employee}.md.age"),
+        format!("goals/{employee}.toml"),
+        ANNOTATIONS.to_string(),
+    ]
+    .contains(&name.to_string())
+        || name
+            .strip_prefix(SNAPSHOTS_PREFIX)
+            .and_then(|file| file.strip_suffix(".json"))
+            .is_some_and(|stem| {
+                !stem.is_empty()
+                    && stem
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+            });
+    if valid {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Unexpected file '{name}' in handoff"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::{ActivityCategory, DetailedActivities};
+    use crate::test_support::ItemBuilder;
+    use tempfile::tempdir;
+
+    /// Reverses the bytes instead of calling `age`
+    struct ReverseCipher;
+
+    impl NotesCipher for ReverseCipher {
+        fn encrypt(&self, _recipient: &str, plaintext: &[u8]) -> io::Result<Vec<u8>> {
+            Ok(plaintext.iter().rev().copied().collect())
+        }
+
+        fn decrypt(&self, _identity: &Path, ciphertext: &[u8]) -> io::Result<Vec<u8>> {
+            Ok(ciphertext.iter().rev().copied().collect())
+        }
+    }
+
+    fn data_path(dir: &Path) -> DataPath {
+        let data_path = DataPath::new(Some(dir.to_path_buf())).unwrap();
+        for dir in [
+            &data_path.employees_dir,
+            &data_path.notes_dir,
+            &data_path.goals_dir,
+        ] {
+            fs::create_dir_all(dir).unwrap();
+        }
+        data_path
+    }
+
+    fn setup_employee(data_path: &DataPath) {
+        fs::write(
+            data_path.employees_dir.join("Jane Doe.toml"),
+            "name = \"Jane Doe\"\ntitle = \"Engineer\"\n",
+        )
+        .unwrap();
+        fs::write(
+            data_path.notes_dir.join("Jane Doe.md"),
+            "# Notes\nGreat quarter\n",
+        )
+        .unwrap();
+        fs::write(
+            data_path.goals_dir.join("Jane Doe.toml"),
+            "[[goals]]\ntitle = \"Ship SSO\"\n",
+        )
+        .unwrap();
+
+        let mut activities = DetailedActivities::default();
+        activities.items_by_category.insert(
+            ActivityCategory::ChangesMerged,
+            vec![
+                ItemBuilder::new("12345")
+                    .with_title("Add SSO")
+                    .with_created("2024-01-10T00:00:00Z")
+                    .with_updated("2024-01-11T00:00:00Z")
+                    .with_category(ActivityCategory::ChangesMerged)
+                    .with_project("auth")
+                    .build(),
+            ],
+        );
+        ActivityCache::new(data_path, Duration::from_secs(3600))
+            .store("Jane Doe", "gerrit", 30, &activities)
+            .unwrap();
+        let mut annotations = AnnotationStore::load(data_path);
+        annotations
+            .set("gerrit", "12345", "great design doc")
+            .unwrap();
+        annotations
+            .set("gerrit", "99999", "someone else's")
+            .unwrap();
+    }
+
+    #[test]
+    fn test_export_and_import() {
+        let source = tempdir().unwrap();
+        let source_path = data_path(source.path());
+        setup_employee(&source_path);
+        let folder = source.path().join("handoff");
+
+        let manifest =
+            HandoffService::export(&source_path, "Jane Doe", &folder, None, &ReverseCipher)
+                .unwrap();
+        assert_eq!(
+            manifest.files,
+            vec![
+                "employees/Jane Doe.toml",
+                "notes/Jane Doe.md",
+                "goals/Jane Doe.toml",
+                "annotations.json",
+                "snapshots/gerrit-30d.json",
+            ]
+        );
+        let exported = fs::read_to_string(folder.join("annotations.json")).unwrap();
+        assert!(exported.contains("great design doc"));
+        assert!(!exported.contains("someone else's"));
+        // The folder must be new or empty
+        assert!(
+            HandoffService::export(&source_path, "Jane Doe", &folder, None, &ReverseCipher)
+                .is_err()
+        );
+
+        let target = tempdir().unwrap();
+        let target_path = data_path(target.path());
+        HandoffService::import(&target_path, &folder, None, &ReverseCipher).unwrap();
+        assert!(EmployeeService::employee_exists(&target_path, "Jane Doe"));
+        assert_eq!(
+            fs::read_to_string(target_path.notes_dir.join("Jane Doe.md")).unwrap(),
+            "# Notes\nGreat quarter\n"
+        );
+        assert!(target_path.goals_dir.join("Jane Doe.toml").exists());
+        let annotations = AnnotationStore::load(&target_path);
+        assert_eq!(
+            annotations.get("gerrit", "12345").unwrap().text,
+            "great design doc"
+        );
+        assert!(
+            ActivityCache::new(&target_path, Duration::from_secs(3600))
+                .load_any_age("Jane Doe", "gerrit", 30)
+                .is_some()
+        );
+
+        // Importing the same person twice is refused
+        assert!(HandoffService::import(&target_path, &folder, None, &ReverseCipher).is_err());
+    }
+
+    #[test]
+    fn test_encrypted_handoff() {
+        let source = tempdir().unwrap();
+        let source_path = data_path(source.path());
+        setup_employee(&source_path);
+        let folder = source.path().join("handoff");
+
+        assert!(
+            HandoffService::export(
+                &source_path,
+                "Jane Doe",
+                &folder,
+                Some("not-a-key"),
+                &ReverseCipher
+            )
+            .is_err()
+        );
+        let manifest = HandoffService::export(
+            &source_path,
+            "Jane Doe",
+            &folder,
+            Some("age1recipient"),
+            &ReverseCipher,
+        )
+        .unwrap();
+        assert!(manifest.encrypted);
+        assert!(folder.join("notes/Jane Doe.md.age").exists());
+        assert!(!folder.join("notes/Jane Doe.md").exists());
+
+        let target = tempdir().unwrap();
+        let target_path = data_path(target.path());
+        assert!(HandoffService::import(&target_path, &folder, None, &ReverseCipher).is_err());
+        let identity = target.path().join("key.txt");
+        HandoffService::import(&target_path, &folder, Some(&identity), &ReverseCipher).unwrap();
+        assert_eq!(
+            fs::read_to_string(target_path.notes_dir.join("Jane Doe.md")).unwrap(),
+            "# Notes\nGreat quarter\n"
+        );
+    }
+
+    #[test]
+    fn test_validate_entry_name() {
+        assert!(validate_entry_name("Jane Doe", "notes/Jane Doe.md").is_ok());
+        assert!(validate_entry_name("Jane Doe", "snapshots/custom_wiki-30d.json").is_ok());
+        assert!(validate_entry_name("Jane Doe", "notes/John Doe.md").is_err());
+        assert!(validate_entry_name("Jane Doe", "config.toml").is_err());
+        assert!(validate_entry_name("Jane Doe", "snapshots/../config.json").is_err());
+    }
+}
@@ -0,0 +1,575 @@
+//! HTTP clients of the platforms, built with the configured proxy and CA certificates and
+//! limited to a number of requests in flight, and retries with exponential backoff for their
+//! idempotent requests
+
+use crate::http_cache::{CachedResponse, ResponseCache, send_conditional};
+use log::warn;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{
+    Certificate, Client, ClientBuilder, IntoUrl, Method, NoProxy, Proxy, Request, RequestBuilder,
+    Response, StatusCode,
+};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+/// `global_settings.http_proxy` and `ca_bundle_path`, applied to every platform client
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HttpClientSettings {
+    /// Proxy URL for all requests; None leaves it to `HTTPS_PROXY`/`HTTP_PROXY`
+    pub proxy: Option<String>,
+    /// PEM file of CA certificates trusted besides the system ones
+    pub ca_bundle: Option<PathBuf>,
+}
+
+impl HttpClientSettings {
+    /// A client builder with the proxy and CA certificates, for a platform instance that may
+    /// `accept_invalid_certs`. InvalidInput for an unusable proxy URL or CA bundle.
+    pub fn client_builder(&self, accept_invalid_certs: bool) -> io::Result<ClientBuilder> {
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidInput, message);
+        let mut builder = Client::builder();
+        if let Some(proxy) = &self.proxy {
+            let proxy = Proxy::all(proxy)
+                .map_err(|e| invalid(format!("Invalid http_proxy '{proxy}': {e}")))?
+                .no_proxy(NoProxy::from_env());
+            builder = builder.proxy(proxy);
+        }
+        if let Some(path) = &self.ca_bundle {
+            let pem = fs::read(path).map_err(|e| {
+                invalid(format!(
+                    "Cannot read ca_bundle_path '{}': {e}",
+                    path.display()
+                ))
+            })?;
+            let certificates = Certificate::from_pem_bundle(&pem)
+                .ok()
+                .filter(|certificates| !certificates.is_empty())
+                .ok_or_else(|| {
+                    invalid(format!(
+                        "ca_bundle_path '{}' holds no PEM certificates",
+                        path.display()
+                    ))
+                })?;
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+        if accept_invalid_certs {
+            warn!("TLS certificate verification is disabled (accept_invalid_certs)");
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+        Ok(builder)
+    }
+
+    /// A [`PlatformClient`] with the proxy and CA certificates that gives up on requests after
+    /// `timeout` and sends at most `max_concurrent_requests` at once
+    pub fn platform_client(
+        &self,
+        accept_invalid_certs: bool,
+        max_concurrent_requests: usize,
+        timeout: Duration,
+    ) -> io::Result<PlatformClient> {
+        let client = self.build_client(accept_invalid_certs, Some(timeout))?;
+        Ok(PlatformClient::new(client, max_concurrent_requests))
+    }
+
+    /// A client with the proxy and CA certificates that gives up on requests after `timeout`
+    pub fn build_client(
+        &self,
+        accept_invalid_certs: bool,
+        timeout: Option<Duration>,
+    ) -> io::Result<Client> {
+        let mut builder = self.client_builder(accept_invalid_certs)?;
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
+        builder
+            .build()
+            .map_err(|e| io::Error::other(format!("Failed to create HTTP client: {e}")))
+    }
+}
+
+/// A platform instance's HTTP client. Every request it sends waits for one of
+/// `max_concurrent_requests` permits, shared by all clones, so one platform never has more
+/// requests in flight than allowed.
+#[derive(Debug, Clone)]
+pub struct PlatformClient {
+    client: Client,
+    permits: Arc<Semaphore>,
+    max_concurrent_requests: usize,
+}
+
+impl PlatformClient {
+    pub fn new(client: Client, max_concurrent_requests: usize) -> Self {
+        let max_concurrent_requests = max_concurrent_requests.max(1);
+        Self {
+            client,
+            permits: Arc::new(Semaphore::new(max_concurrent_requests)),
+            max_concurrent_requests,
+        }
+    }
+
+    /// The same client with a new limit, e.g. from `[performance]` applied after creation
+    pub fn with_max_concurrent_requests(self, max_concurrent_requests: usize) -> Self {
+        Self::new(self.client, max_concurrent_requests)
+    }
+
+    /// Requests that may be in flight at once
+    pub fn max_concurrent_requests(&self) -> usize {
+        self.max_concurrent_requests
+    }
+
+    /// A GET request, to be sent with [`Self::execute`] or [`Self::send_conditional`]
+    pub fn get(&self, url: impl IntoUrl) -> RequestBuilder {
+        self.client.get(url)
+    }
+
+    /// [`execute_with_retry`] once a permit is free; the permit is held through the retries
+    pub async fn execute(
+        &self,
+        request: Request,
+        retry: &RetryPolicy,
+    ) -> reqwest::Result<Response> {
+        // The semaphore is never closed, so acquiring only ever waits
+        let _permit = self.permits.acquire().await.ok();
+        execute_with_retry(&self.client, request, retry).await
+    }
+
+    /// [`send_conditional`] once a permit is free
+    pub async fn send_conditional(
+        &self,
+        request: RequestBuilder,
+        cache: Option<&ResponseCache>,
+        retry: &RetryPolicy,
+    ) -> reqwest::Result<CachedResponse> {
+        let _permit = self.permits.acquire().await.ok();
+        send_conditional(&self.client, request, cache, retry).await
+    }
+}
+
+/// Attempts made for a request when `global_settings.http_max_attempts` is not set
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// How often and how patiently a failed request is repeated
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first one; 1 disables retries
+    pub max_attempts: u32,
+    /// Wait before the first retry, doubled for every further one
+    pub base_delay: Duration,
+    /// Upper bound for any single wait, including a server's `Retry-After`
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ATTEMPTS)
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+
+    /// Wait before retry number `retry` (1-based), preferring the server's `Retry-After`
+    fn delay(&self, retry: u32, retry_after: Option<Duration>) -> Duration {
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)));
+        retry_after.unwrap_or(backoff).min(self.max_delay)
+    }
+}
+
+/// Rate limiting and the server errors that are usually transient
+fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Reset values above this are Unix timestamps (GitLab, GitHub) rather than delay seconds
+const EPOCH_THRESHOLD: u64 = 1_000_000_000;
+
+/// Wait the server asked for: `Retry-After` as delay seconds or an HTTP date, else the reset of
+/// `RateLimit-Reset`, `X-RateLimit-Reset` or the combined `RateLimit: ...;reset=N` header
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let header = |name: &str| headers.get(name)?.to_str().ok().map(str::trim);
+
+    if let Some(value) = header(RETRY_AFTER.as_str()) {
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(Duration::from_secs(seconds));
+        }
+        if let Ok(date) = chrono::DateTime::parse_from_rfc2822(value) {
+            return (date.with_timezone(&chrono::Utc) - chrono::Utc::now())
+                .to_std()
+                .ok();
+        }
+    }
+
+    let reset = header("ratelimit-reset")
+        .or_else(|| header("x-ratelimit-reset"))
+        .and_then(|value| value.parse::<u64>().ok())
+        .or_else(|| {
+            header("ratelimit")?
+                .split([',', ';'])
+                .find_map(|part| part.trim().strip_prefix("reset=")?.parse().ok())
+        })?;
+    if reset > EPOCH_THRESHOLD {
+        let now = chrono::Utc::now().timestamp().max(0) as u64;
+        Some(Duration::from_secs(reset.saturating_sub(now)))
+    } else {
+        Some(Duration::from_secs(reset))
+    }
+}
+
+/// Error for an unsuccessful platform response. A 429 that outlasted the retries gets a hint
+/// instead of the server's body.
+pub fn api_error(platform: &str, status: StatusCode, body: &str) -> io::Error {
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return io::Error::other(format!(
+            "{platform} rate limit still exceeded after backing off; try again in a few \
+             minutes or lower max_concurrent_requests for the platform"
+        ));
+    }
+    io::Error::other(format!("{platform} API returned {status}: {body}"))
+}
+
+/// Execute `request`, repeating GET and HEAD requests that hit a connection error, a timeout,
+/// `429 Too Many Requests` or a 5xx gateway/server error. The last response or error is
+/// returned once the attempts are used up.
+pub async fn execute_with_retry(
+    client: &Client,
+    request: Request,
+    policy: &RetryPolicy,
+) -> reqwest::Result<Response> {
+    let idempotent = matches!(*request.method(), Method::GET | Method::HEAD);
+    if !idempotent || policy.max_attempts <= 1 {
+        return client.execute(request).await;
+    }
+
+    let mut attempt = 1;
+    loop {
+        let Some(current) = request.try_clone() else {
+            return client.execute(request).await;
+        };
+        let last_attempt = attempt >= policy.max_attempts;
+
+        let delay = match client.execute(current).await {
+            Ok(response) if !last_attempt && is_retryable_status(response.status()) => {
+                let delay = policy.delay(attempt, retry_after(response.headers()));
+                if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                    warn!(
+                        "Rate limited by {}, backing off for {delay:?} (attempt {attempt}/{})",
+                        request.url().host_str().unwrap_or_default(),
+                        policy.max_attempts
+                    );
+                } else {
+                    warn!(
+                        "{} returned HTTP {}, retrying in {delay:?} (attempt {attempt}/{})",
+                        request.url().path(),
+                        response.status(),
+                        policy.max_attempts
+                    );
+                }
+                delay
+            }
+            Err(e) if !last_attempt && (e.is_connect() || e.is_timeout()) => {
+                let delay = policy.delay(attempt, None);
+                warn!(
+                    "{} failed ({e}), retrying in {delay:?} (attempt {attempt}/{})",
+                    request.url().path(),
+                    policy.max_attempts
+                );
+                delay
+            }
+            result => return result,
+        };
+
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            base_delay: Duration::from_millis(1),
+            ..RetryPolicy::new(max_attempts)
+        }
+    }
+
+    #[test]
+    fn test_backoff_and_retry_after() {
+        let policy = RetryPolicy::new(5);
+        assert_eq!(policy.delay(1, None), Duration::from_millis(500));
+        assert_eq!(policy.delay(3, None), Duration::from_secs(2));
+        assert_eq!(policy.delay(20, None), Duration::from_secs(30));
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(7))),
+            Duration::from_secs(7)
+        );
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(600))),
+            Duration::from_secs(30)
+        );
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("12"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(12)));
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        // A date in the past means "now"
+        assert_eq!(retry_after(&headers), None);
+        assert_eq!(RetryPolicy::new(0).max_attempts, 1);
+    }
+
+    #[test]
+    fn test_rate_limit_reset_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("RateLimit-Reset", HeaderValue::from_static("20"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(20)));
+
+        // GitLab sends the reset as a Unix timestamp
+        let reset = (chrono::Utc::now().timestamp() + 45).to_string();
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Reset", HeaderValue::from_str(&reset).unwrap());
+        let delay = retry_after(&headers).unwrap();
+        assert!(delay > Duration::from_secs(40) && delay <= Duration::from_secs(45));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "RateLimit",
+            HeaderValue::from_static("limit=100, remaining=0, reset=12"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(12)));
+
+        // Retry-After wins over the reset
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("3"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(3)));
+        assert_eq!(retry_after(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_api_error_messages() {
+        let error = api_error("GitLab", StatusCode::TOO_MANY_REQUESTS, "Retry later");
+        assert!(
+            error
+                .to_string()
+                .contains("GitLab rate limit still exceeded")
+        );
+        let error = api_error("JIRA", StatusCode::BAD_REQUEST, "bad JQL");
+        assert_eq!(
+            error.to_string(),
+            "JIRA API returned 400 Bad Request: bad JQL"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retries_rate_limited_get() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::new();
+        let request = client
+            .get(format!("{}/search", server.uri()))
+            .build()
+            .unwrap();
+        let response = execute_with_retry(&client, request, &fast_policy(3))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.text().await.unwrap(), "ok");
+    }
+
+    #[tokio::test]
+    async fn test_waits_for_rate_limit_reset() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(429).insert_header("RateLimit-Reset", "1"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::new();
+        let request = client.get(server.uri()).build().unwrap();
+        let started = std::time::Instant::now();
+        let response = execute_with_retry(&client, request, &fast_policy(2))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        // The server's reset replaces the 1 ms backoff
+        assert!(started.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::new();
+        let request = client.get(server.uri()).build().unwrap();
+        let response = execute_with_retry(&client, request, &fast_policy(2))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        // Non-idempotent requests are sent once
+        let request = client.post(server.uri()).build().unwrap();
+        let response = execute_with_retry(&client, request, &fast_policy(2))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_platform_client_limits_and_timeout() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/slow"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(300)))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/stuck"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(3)))
+            .mount(&server)
+            .await;
+
+        let settings = HttpClientSettings::default();
+        let client = settings
+            .platform_client(false, 1, Duration::from_secs(1))
+            .unwrap();
+        let retry = fast_policy(1);
+        let send = |path: &str| {
+            let request = client
+                .get(format!("{}{path}", server.uri()))
+                .build()
+                .unwrap();
+            client.execute(request, &retry)
+        };
+
+        // One request in flight at a time
+        let started = std::time::Instant::now();
+        let (a, b, c) = tokio::join!(send("/slow"), send("/slow"), send("/slow"));
+        assert!(a.is_ok() && b.is_ok() && c.is_ok());
+        assert!(started.elapsed() >= Duration::from_millis(900));
+
+        let error = send("/stuck").await.unwrap_err();
+        assert!(error.is_timeout(), "{error}");
+
+        let client = client.with_max_concurrent_requests(0);
+        assert_eq!(client.max_concurrent_requests(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_requests_go_through_the_proxy() {
+        let proxy = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ping"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("pong"))
+            .expect(1)
+            .mount(&proxy)
+            .await;
+
+        let settings = HttpClientSettings {
+            proxy: Some(proxy.uri()),
+            ca_bundle: None,
+        };
+        let client = settings.build_client(false, None).unwrap();
+        let response = client
+            .get("http://reviewr.invalid/ping")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.text().await.unwrap(), "pong");
+    }
+
+    #[test]
+    fn test_unusable_proxy_and_ca_bundle() {
+        let settings = HttpClientSettings {
+            proxy: Some("http://[::1".to_string()),
+            ca_bundle: None,
+        };
+        let error = settings.build_client(false, None).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(error.to_string().contains("Invalid http_proxy"), "{error}");
+
+        let dir = tempfile::tempdir().unwrap();
+        let missing = HttpClientSettings {
+            proxy: None,
+            ca_bundle: Some(dir.path().join("missing.pem")),
+        };
+        let error = missing.build_client(false, None).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(
+            error.to_string().contains("Cannot read ca_bundle_path"),
+            "{error}"
+        );
+
+        let not_pem = dir.path().join("notes.txt");
+        fs::write(&not_pem, "not a certificate").unwrap();
+        let settings = HttpClientSettings {
+            proxy: None,
+            ca_bundle: Some(not_pem),
+        };
+        let error = settings.build_client(true, None).unwrap_err();
+        assert!(
+            error.to_string().contains("holds no PEM certificates"),
+            "{error}"
+        );
+
+        assert!(
+            HttpClientSettings::default()
+                .build_client(true, None)
+                .is_ok()
+        );
+    }
+}
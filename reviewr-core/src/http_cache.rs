@@ -0,0 +1,317 @@
+//! Conditional request support (ETag / Last-Modified) for platform HTTP clients
+
+use crate::http::{RetryPolicy, execute_with_retry};
+use crate::models::DataPath;
+use log::{info, warn};
+use reqwest::header::{
+    ETAG, HeaderMap, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+/// Entries neither stored nor revalidated for this long are removed, e.g. those of URLs that
+/// are no longer requested
+const STALE_AFTER: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+/// Cached validators and body for a single request URL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: String,
+}
+
+/// Response of a conditional GET, with the body taken from the cache on `304 Not Modified`
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    pub status: StatusCode,
+    pub body: String,
+    /// True when the server answered 304 and `body` came from the cache
+    pub not_modified: bool,
+}
+
+/// On-disk store of response validators, one JSON file per request URL
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: PathBuf,
+}
+
+impl ResponseCache {
+    pub fn new(data_path: &DataPath) -> Self {
+        Self {
+            dir: data_path.root.join("cache").join("http"),
+        }
+    }
+
+    fn entry_path(&self, url: &str) -> PathBuf {
+        // The digest only names the file; the stored URL is compared on load. A name that is
+        // stable across Rust releases keeps upgrades from orphaning entries.
+        let digest = Sha256::digest(url);
+        self.dir.join(format!("{digest:x}.json"))
+    }
+
+    fn load(&self, url: &str) -> Option<CacheEntry> {
+        let content = fs::read_to_string(self.entry_path(url)).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+        (entry.url == url).then_some(entry)
+    }
+
+    fn store(&self, entry: &CacheEntry) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.entry_path(&entry.url);
+        let added = !path.exists();
+        let json = serde_json::to_string(entry).map_err(io::Error::other)?;
+        fs::write(path, json)?;
+        // Only new entries grow the cache
+        if added {
+            self.prune(SystemTime::now())?;
+        }
+        Ok(())
+    }
+
+    /// Mark the entry of `url` as used, so pruning keeps it
+    fn touch(&self, url: &str) -> io::Result<()> {
+        fs::File::options()
+            .write(true)
+            .open(self.entry_path(url))?
+            .set_modified(SystemTime::now())
+    }
+
+    /// Remove the entries last stored or revalidated more than `STALE_AFTER` before `now`.
+    /// Returns how many were removed.
+    fn prune(&self, now: SystemTime) -> io::Result<usize> {
+        let mut removed = 0;
+        for entry in fs::read_dir(&self.dir)?.flatten() {
+            let path = entry.path();
+            let stale = entry
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .is_ok_and(|modified| {
+                    now.duration_since(modified)
+                        .is_ok_and(|age| age > STALE_AFTER)
+                });
+            if stale && path.extension().is_some_and(|ext| ext == "json") {
+                fs::remove_file(&path)?;
+                removed += 1;
+            }
+        }
+        if removed > 0 {
+            info!("Removed {removed} stale HTTP cache entries");
+        }
+        Ok(removed)
+    }
+}
+
+fn header_string(headers: &HeaderMap, name: reqwest::header::HeaderName) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string())
+}
+
+/// Send a GET, adding `If-None-Match`/`If-Modified-Since` from a previous response when
+/// available. Successful responses carrying validators are stored for the next call. Transient
+/// failures are retried according to `retry`.
+pub async fn send_conditional(
+    client: &Client,
+    request: RequestBuilder,
+    cache: Option<&ResponseCache>,
+    retry: &RetryPolicy,
+) -> reqwest::Result<CachedResponse> {
+    let mut request = request.build()?;
+    let url = request.url().to_string();
+    let entry = cache.and_then(|cache| cache.load(&url));
+
+    if let Some(entry) = &entry {
+        if let Some(value) = entry
+            .etag
+            .as_deref()
+            .and_then(|etag| HeaderValue::from_str(etag).ok())
+        {
+            request.headers_mut().insert(IF_NONE_MATCH, value);
+        }
+        if let Some(value) = entry
+            .last_modified
+            .as_deref()
+            .and_then(|date| HeaderValue::from_str(date).ok())
+        {
+            request.headers_mut().insert(IF_MODIFIED_SINCE, value);
+        }
+    }
+
+    let response = execute_with_retry(client, request, retry).await?;
+    let status = response.status();
+
+    if status == StatusCode::NOT_MODIFIED
+        && let Some(entry) = entry
+    {
+        info!("Not modified, using cached response for {url}");
+        if let Some(cache) = cache
+            && let Err(e) = cache.touch(&url)
+        {
+            warn!("Failed to refresh the HTTP cache entry of {url}: {e}");
+        }
+        return Ok(CachedResponse {
+            status: StatusCode::OK,
+            body: entry.body,
+            not_modified: true,
+        });
+    }
+
+    let etag = header_string(response.headers(), ETAG);
+    let last_modified = header_string(response.headers(), LAST_MODIFIED);
+    let body = response.text().await?;
+
+    if status.is_success()
+        && (etag.is_some() || last_modified.is_some())
+        && let Some(cache) = cache
+    {
+        let entry = CacheEntry {
+            url,
+            etag,
+            last_modified,
+            body: body.clone(),
+        };
+        if let Err(e) = cache.store(&entry) {
+            warn!("Failed to store response validators: {e}");
+        }
+    }
+
+    Ok(CachedResponse {
+        status,
+        body,
+        not_modified: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_etag_revalidation_uses_cached_body() {
+        let server = MockServer::start().await;
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = ResponseCache::new(&data_path);
+        let client = Client::new();
+        let url = format!("{}/changes/", server.uri());
+
+        // Revalidation request gets a 304; mounted first so it wins when the header matches
+        Mock::given(method("GET"))
+            .and(path("/changes/"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/changes/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_string("[1, 2, 3]"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let first = send_conditional(
+            &client,
+            client.get(&url),
+            Some(&cache),
+            &RetryPolicy::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(first.status, StatusCode::OK);
+        assert!(!first.not_modified);
+
+        let second = send_conditional(
+            &client,
+            client.get(&url),
+            Some(&cache),
+            &RetryPolicy::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(second.status, StatusCode::OK);
+        assert!(second.not_modified);
+        assert_eq!(second.body, "[1, 2, 3]");
+    }
+
+    #[test]
+    fn test_stale_entries_are_pruned() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = ResponseCache::new(&data_path);
+        let entry = |url: &str| CacheEntry {
+            url: url.to_string(),
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None,
+            body: "[]".to_string(),
+        };
+        cache.store(&entry("https://example.com/old")).unwrap();
+        cache.store(&entry("https://example.com/used")).unwrap();
+
+        let later = SystemTime::now() + STALE_AFTER + Duration::from_secs(60);
+        assert_eq!(cache.prune(SystemTime::now()).unwrap(), 0);
+        fs::File::options()
+            .write(true)
+            .open(cache.entry_path("https://example.com/used"))
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert_eq!(cache.prune(later).unwrap(), 1);
+        assert!(cache.load("https://example.com/old").is_none());
+        assert!(cache.load("https://example.com/used").is_some());
+    }
+
+    #[test]
+    fn test_entry_names_are_stable() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = ResponseCache::new(&data_path);
+
+        let path = cache.entry_path("https://example.com/a/changes/?q=owner:jane");
+        assert_eq!(
+            path.file_name().unwrap().to_str().unwrap(),
+            "c065381532243408bc85f292df9196e7ac6f104427115dc804c5cd2b547f0700.json"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_responses_without_validators_are_not_cached() {
+        let server = MockServer::start().await;
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = ResponseCache::new(&data_path);
+        let client = Client::new();
+        let url = format!("{}/search", server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+            .mount(&server)
+            .await;
+
+        send_conditional(
+            &client,
+            client.get(&url),
+            Some(&cache),
+            &RetryPolicy::default(),
+        )
+        .await
+        .unwrap();
+        assert!(cache.load(&url).is_none());
+    }
+}
@@ -0,0 +1,1194 @@
+use crate::accounts::{AccountCache, AccountCandidate};
+use crate::category_rules::CategoryRules;
+use crate::http::{HttpClientSettings, PlatformClient, RetryPolicy, api_error};
+use crate::http_cache::ResponseCache;
+use crate::models::DataPath;
+use crate::platform::{
+    ActivityCategory, ActivityItem, ActivityMetrics, ConnectionStatus, DETAILS_PENDING_KEY,
+    DetailedActivities, ErrorContext, PlannedRequest, PlatformCapabilities, QueryPlan,
+    ReviewPlatform, Severity,
+};
+use crate::redact::{mask_secret, redact_url};
+use crate::timestamps::TimestampSource;
+use crate::unified_config::{
+    DEFAULT_INSTANCE, JiraConfig, PerformanceConfig, RequestLimits, UnifiedConfigService,
+    instance_platform_id,
+};
+use async_trait::async_trait;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+
+/// Issues reported by `user` in the last `days` days
+fn created_issues_jql(user: &str, days: u32) -> String {
+    format!("reporter = \"{user}\" AND created >= -{days}d ORDER BY created DESC")
+}
+
+fn resolved_issues_jql(user: &str, days: u32) -> String {
+    format!("assignee = \"{user}\" AND resolved >= -{days}d ORDER BY resolved DESC")
+}
+
+/// Every open issue assigned to `user`, whatever the period
+fn assigned_issues_jql(user: &str) -> String {
+    format!("assignee = \"{user}\" AND resolution = Unresolved ORDER BY updated DESC")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueInfo {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+    pub assignee: Option<String>,
+    pub created: String,
+    pub updated: String,
+    pub resolved: Option<String>,
+    pub project: String,
+    pub issue_type: String,
+    pub priority: Option<String>,
+    pub components: Vec<String>,
+    /// Fetched with the essential fields only (`lazy_details`)
+    #[serde(default)]
+    pub details_pending: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct JiraActivityMetrics {
+    pub tickets_created: u32,
+    pub tickets_resolved: u32,
+    pub tickets_assigned: u32,
+    pub comments_added: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct DetailedJiraMetrics {
+    pub tickets_created: Vec<IssueInfo>,
+    pub tickets_resolved: Vec<IssueInfo>,
+    pub tickets_assigned: Vec<IssueInfo>,
+    pub tickets_commented: Vec<IssueInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraSearchResponse {
+    issues: Vec<JiraIssue>,
+    total: i32,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraIssue {
+    key: String,
+    fields: JiraFields,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraFields {
+    summary: String,
+    status: JiraStatus,
+    assignee: Option<JiraUser>,
+    #[allow(dead_code)]
+    reporter: Option<JiraUser>,
+    created: String,
+    updated: String,
+    resolutiondate: Option<String>,
+    project: JiraProject,
+    issuetype: Option<JiraIssueType>,
+    priority: Option<JiraPriority>,
+    components: Option<Vec<JiraComponent>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraStatus {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraUser {
+    /// Username that JQL user clauses match on
+    name: Option<String>,
+    #[serde(rename = "displayName")]
+    display_name: Option<String>,
+    #[serde(rename = "emailAddress")]
+    email_address: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraProject {
+    key: String,
+    #[allow(dead_code)]
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraIssueType {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraPriority {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraComponent {
+    name: String,
+}
+
+pub struct JiraClient {
+    client: PlatformClient,
+    /// Platform id errors are logged under, e.g. `jira:<instance>`
+    platform_id: String,
+    base_url: String,
+    auth_header: String,
+    response_cache: Option<ResponseCache>,
+    retry: RetryPolicy,
+    limits: RequestLimits,
+    page_size: u32,
+    /// `fields` of issue searches and of single issues, from the configured field selection
+    search_fields: String,
+    detail_fields: String,
+    lazy_details: bool,
+}
+
+impl JiraClient {
+    pub fn new(config: &JiraConfig, http: &HttpClientSettings) -> io::Result<Self> {
+        let limits = config.limits.clone();
+        let client = http.platform_client(
+            config.accept_invalid_certs,
+            limits.max_concurrent_requests(&PerformanceConfig::default()),
+            limits.request_timeout(),
+        )?;
+
+        // Use Bearer authentication for Personal Access Tokens (PAT) in JIRA Data Center
+        let auth_header = format!("Bearer {}", config.api_token);
+
+        let base_url = config.jira_url.trim_end_matches('/').to_string();
+
+        info!("Created JIRA client for {base_url}");
+
+        Ok(Self {
+            client,
+            platform_id: "jira".to_string(),
+            base_url,
+            auth_header,
+            response_cache: None,
+            retry: RetryPolicy::default(),
+            limits,
+            page_size: PerformanceConfig::default().page_size,
+            search_fields: config.search_fields(),
+            detail_fields: config.detail_fields(),
+            lazy_details: config.lazy_details,
+        })
+    }
+
+    /// Apply the `[performance]` page size, and its request concurrency unless the instance
+    /// sets its own
+    pub fn with_performance(mut self, performance: &PerformanceConfig) -> Self {
+        self.client = self
+            .client
+            .with_max_concurrent_requests(self.limits.max_concurrent_requests(performance));
+        self.page_size = performance.page_size.max(1);
+        self
+    }
+
+    /// Log errors under the platform id of a named instance
+    pub fn with_platform_id(mut self, platform_id: &str) -> Self {
+        self.platform_id = platform_id.to_string();
+        self
+    }
+
+    /// Send conditional requests, reusing stored results the server reports as unchanged
+    pub fn with_response_cache(mut self, cache: ResponseCache) -> Self {
+        self.response_cache = Some(cache);
+        self
+    }
+
+    /// Retry transient failures according to `retry`
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub async fn get_activity_metrics(
+        &self,
+        user_email: &str,
+        days: u32,
+    ) -> io::Result<JiraActivityMetrics> {
+        info!("Fetching JIRA activity metrics for {user_email} (last {days} days)");
+
+        let mut metrics = JiraActivityMetrics::default();
+
+        // Get tickets created by user
+        let created_count = self.count_tickets_created(user_email, days).await?;
+        metrics.tickets_created = created_count;
+
+        // Get tickets resolved by user
+        let resolved_count = self.count_tickets_resolved(user_email, days).await?;
+        metrics.tickets_resolved = resolved_count;
+
+        // Get tickets currently assigned to user
+        let assigned_count = self.count_tickets_assigned(user_email).await?;
+        metrics.tickets_assigned = assigned_count;
+
+        // Comments are harder to track efficiently, leaving at 0 for now
+        metrics.comments_added = 0;
+
+        info!("JIRA activity metrics for {user_email}: {metrics:?}");
+        Ok(metrics)
+    }
+
+    async fn count_tickets_created(&self, user_email: &str, days: u32) -> io::Result<u32> {
+        let jql = format!("reporter = \"{user_email}\" AND created >= -{days}d");
+        self.search_issues_count(&jql).await
+    }
+
+    async fn count_tickets_resolved(&self, user_email: &str, days: u32) -> io::Result<u32> {
+        let jql = format!("assignee = \"{user_email}\" AND resolved >= -{days}d");
+        self.search_issues_count(&jql).await
+    }
+
+    async fn count_tickets_assigned(&self, user_email: &str) -> io::Result<u32> {
+        let jql = format!("assignee = \"{user_email}\" AND resolution = Unresolved");
+        self.search_issues_count(&jql).await
+    }
+
+    async fn search_issues_count(&self, jql: &str) -> io::Result<u32> {
+        let url = format!(
+            "{}/rest/api/2/search?jql={}&maxResults=0",
+            self.base_url,
+            urlencoding::encode(jql)
+        );
+
+        info!("JIRA JQL query: {jql}");
+
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", &self.auth_header)
+            .header("Accept", "application/json");
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| {
+                ErrorContext::new(&self.platform_id, "search_issues_count")
+                    .with_error("network_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .with_metadata("jql_query", jql)
+                    .log_error();
+                io::Error::other(format!("JIRA API request failed: {e}"))
+            })?;
+
+        if !response.status.is_success() {
+            let status = response.status;
+            let error_text = response.body;
+            ErrorContext::new(&self.platform_id, "search_issues_count")
+                .with_error("api_error", &format!("HTTP {status}"))
+                .with_request_details(&url, Some(status.as_u16()), Some(&error_text))
+                .with_metadata("jql_query", jql)
+                .log_error();
+            return Err(api_error("JIRA", status, &error_text));
+        }
+
+        let search_response: JiraSearchResponse =
+            serde_json::from_str(&response.body).map_err(|e| {
+                ErrorContext::new(&self.platform_id, "search_issues_count")
+                    .with_error("json_parse_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .with_metadata("jql_query", jql)
+                    .log_error();
+                io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+            })?;
+
+        Ok(search_response.total as u32)
+    }
+
+    pub async fn get_detailed_activity_metrics(
+        &self,
+        user_email: &str,
+        days: u32,
+    ) -> io::Result<DetailedJiraMetrics> {
+        let [
+            tickets_created,
+            tickets_resolved,
+            tickets_assigned,
+            tickets_commented,
+        ] = self.get_detailed_activity_results(user_email, days).await;
+
+        Ok(DetailedJiraMetrics {
+            tickets_created: tickets_created.1?,
+            tickets_resolved: tickets_resolved.1?,
+            tickets_assigned: tickets_assigned.1?,
+            tickets_commented: tickets_commented.1?,
+        })
+    }
+
+    /// The issues of each activity category, or why they could not be fetched, so one failed
+    /// search doesn't hide the categories that loaded
+    pub async fn get_detailed_activity_results(
+        &self,
+        user_email: &str,
+        days: u32,
+    ) -> [(ActivityCategory, io::Result<Vec<IssueInfo>>); 4] {
+        info!("Fetching detailed JIRA activity metrics for {user_email} (last {days} days)");
+
+        let (tickets_created, tickets_resolved, tickets_assigned) = tokio::join!(
+            self.get_detailed_tickets_created(user_email, days),
+            self.get_detailed_tickets_resolved(user_email, days),
+            self.get_detailed_tickets_assigned(user_email),
+        );
+        let tickets_commented = Ok(Vec::new()); // Complex to implement efficiently
+
+        [
+            (ActivityCategory::IssuesCreated, tickets_created),
+            (ActivityCategory::IssuesResolved, tickets_resolved),
+            (ActivityCategory::IssuesAssigned, tickets_assigned),
+            (ActivityCategory::IssuesCommented, tickets_commented),
+        ]
+    }
+
+    async fn get_detailed_tickets_created(
+        &self,
+        user_email: &str,
+        days: u32,
+    ) -> io::Result<Vec<IssueInfo>> {
+        self.search_detailed_issues(&created_issues_jql(user_email, days))
+            .await
+    }
+
+    async fn get_detailed_tickets_resolved(
+        &self,
+        user_email: &str,
+        days: u32,
+    ) -> io::Result<Vec<IssueInfo>> {
+        self.search_detailed_issues(&resolved_issues_jql(user_email, days))
+            .await
+    }
+
+    async fn get_detailed_tickets_assigned(&self, user_email: &str) -> io::Result<Vec<IssueInfo>> {
+        self.search_detailed_issues(&assigned_issues_jql(user_email))
+            .await
+    }
+
+    /// One page of issues matching `jql`, with the configured search fields
+    fn search_url(&self, jql: &str) -> String {
+        format!(
+            "{}/rest/api/2/search?jql={}&maxResults={}&fields={}",
+            self.base_url,
+            urlencoding::encode(jql),
+            self.page_size,
+            self.search_fields
+        )
+    }
+
+    /// The searches of `get_detailed_activity_metrics` for the username `user`
+    pub fn planned_requests(&self, user: &str, days: u32) -> Vec<PlannedRequest> {
+        [
+            (
+                ActivityCategory::IssuesCreated,
+                created_issues_jql(user, days),
+            ),
+            (
+                ActivityCategory::IssuesResolved,
+                resolved_issues_jql(user, days),
+            ),
+            (ActivityCategory::IssuesAssigned, assigned_issues_jql(user)),
+        ]
+        .into_iter()
+        .map(|(category, jql)| PlannedRequest {
+            label: category.display_name().to_string(),
+            request: format!("GET {}", redact_url(&self.search_url(&jql))),
+            query: Some(jql),
+        })
+        .collect()
+    }
+
+    async fn search_detailed_issues(&self, jql: &str) -> io::Result<Vec<IssueInfo>> {
+        let url = self.search_url(jql);
+
+        info!("JIRA detailed query: {jql}");
+
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", &self.auth_header)
+            .header("Accept", "application/json");
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| {
+                ErrorContext::new(&self.platform_id, "search_detailed_issues")
+                    .with_error("network_error", &e.to_string())
+                    .with_request_details(&url, None, None)
+                    .with_metadata("jql_query", jql)
+                    .log_error();
+                io::Error::other(format!("JIRA API request failed: {e}"))
+            })?;
+
+        if !response.status.is_success() {
+            let status = response.status;
+            let error_text = response.body;
+            ErrorContext::new(&self.platform_id, "search_detailed_issues")
+                .with_error("api_error", &format!("HTTP {status}"))
+                .with_request_details(&url, Some(status.as_u16()), Some(&error_text))
+                .with_metadata("jql_query", jql)
+                .log_error();
+            return Err(api_error("JIRA", status, &error_text));
+        }
+
+        let response_text = response.body;
+
+        // Log first 500 chars of response for debugging
+        let preview = if response_text.len() > 500 {
+            format!("{}...", &response_text[..500])
+        } else {
+            response_text.clone()
+        };
+
+        log::info!("JIRA response preview: {preview}");
+
+        let search_response: JiraSearchResponse =
+            serde_json::from_str(&response_text).map_err(|e| {
+                ErrorContext::new(&self.platform_id, "search_detailed_issues")
+                    .with_error("json_parse_error", &e.to_string())
+                    .with_request_details(&url, None, Some(&response_text))
+                    .with_metadata("jql_query", jql)
+                    .log_error();
+                io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+            })?;
+
+        Ok(search_response
+            .issues
+            .into_iter()
+            .map(|issue| Self::issue_info(issue, self.lazy_details))
+            .collect())
+    }
+
+    fn issue_info(issue: JiraIssue, details_pending: bool) -> IssueInfo {
+        let components = issue
+            .fields
+            .components
+            .unwrap_or_default()
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+
+        IssueInfo {
+            key: issue.key,
+            summary: issue.fields.summary,
+            status: issue.fields.status.name,
+            assignee: issue.fields.assignee.and_then(|a| a.display_name),
+            created: issue.fields.created,
+            updated: issue.fields.updated,
+            resolved: issue.fields.resolutiondate,
+            project: issue.fields.project.key,
+            issue_type: issue.fields.issuetype.map(|t| t.name).unwrap_or_default(),
+            priority: issue.fields.priority.map(|p| p.name),
+            components,
+            details_pending,
+        }
+    }
+
+    fn issue_url(&self, issue_key: &str) -> String {
+        format!(
+            "{}/rest/api/2/issue/{}?fields={}",
+            self.base_url,
+            urlencoding::encode(issue_key),
+            self.detail_fields
+        )
+    }
+
+    /// One issue with every configured field, for issues searched with `lazy_details`
+    pub async fn get_issue(&self, issue_key: &str) -> io::Result<IssueInfo> {
+        let url = self.issue_url(issue_key);
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", &self.auth_header)
+            .header("Accept", "application/json");
+        let response = self
+            .client
+            .send_conditional(request, self.response_cache.as_ref(), &self.retry)
+            .await
+            .map_err(|e| io::Error::other(format!("JIRA API request failed: {e}")))?;
+        if !response.status.is_success() {
+            ErrorContext::new(&self.platform_id, "get_issue")
+                .with_error("api_error", &format!("HTTP {}", response.status))
+                .with_request_details(&url, Some(response.status.as_u16()), Some(&response.body))
+                .with_metadata("issue", issue_key)
+                .log_error();
+            return Err(api_error("JIRA", response.status, &response.body));
+        }
+        let issue: JiraIssue = serde_json::from_str(&response.body).map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+        })?;
+        Ok(Self::issue_info(issue, false))
+    }
+
+    /// Users matching `email` from `/rest/api/2/user/search`
+    pub async fn find_users(&self, email: &str) -> io::Result<Vec<AccountCandidate>> {
+        let url = self.user_search_url(email);
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", &self.auth_header)
+            .header("Accept", "application/json")
+            .build()
+            .map_err(|e| io::Error::other(format!("Invalid JIRA URL: {e}")))?;
+        let response = self
+            .client
+            .execute(request, &self.retry)
+            .await
+            .map_err(|e| io::Error::other(format!("JIRA API request failed: {e}")))?;
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            ErrorContext::new(&self.platform_id, "find_users")
+                .with_severity(Severity::Warning)
+                .with_error("api_error", &format!("HTTP {status}"))
+                .with_request_details(&url, Some(status.as_u16()), Some(&text))
+                .log_error();
+            return Err(api_error("JIRA", status, &text));
+        }
+        let users: Vec<JiraUser> = response.json().await.map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid JSON: {e}"))
+        })?;
+        Ok(users
+            .into_iter()
+            .filter_map(|user| {
+                Some(AccountCandidate {
+                    username: user.name?,
+                    emails: user.email_address.into_iter().collect(),
+                })
+            })
+            .collect())
+    }
+
+    fn user_search_url(&self, email: &str) -> String {
+        format!(
+            "{}/rest/api/2/user/search?username={}",
+            self.base_url,
+            urlencoding::encode(email)
+        )
+    }
+
+    /// The username to query for `user`, resolving an email once through `find_users`
+    pub async fn resolve_user(&self, accounts: &AccountCache, user: &str) -> String {
+        accounts
+            .resolve(&self.platform_id, user, || self.find_users(user))
+            .await
+    }
+
+    pub fn get_issue_url(&self, issue_key: &str) -> String {
+        format!("{}/browse/{}", self.base_url, issue_key)
+    }
+
+    pub async fn test_connection(&self) -> io::Result<()> {
+        let url = format!("{}/rest/api/2/myself", self.base_url);
+
+        let request = self
+            .client
+            .get(&url)
+            .header("Authorization", &self.auth_header)
+            .header("Accept", "application/json")
+            .build()
+            .map_err(|e| io::Error::other(format!("Connection test failed: {e}")))?;
+        let response = self
+            .client
+            .execute(request, &self.retry)
+            .await
+            .map_err(|e| io::Error::other(format!("Connection test failed: {e}")))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(io::Error::other(format!(
+                "JIRA connection test failed with status: {}",
+                response.status()
+            )))
+        }
+    }
+}
+
+pub struct JiraService;
+
+impl JiraService {
+    pub fn load_jira_config(
+        data_path: &DataPath,
+        instance: &str,
+    ) -> io::Result<Option<JiraConfig>> {
+        UnifiedConfigService::load_jira_config(data_path, instance)
+    }
+
+    /// A client for `instance` with the data directory's cache, retry and performance
+    /// settings, and the instance's base URL
+    fn connect(data_path: &DataPath, instance: &str) -> io::Result<(JiraClient, String)> {
+        let config = Self::load_jira_config(data_path, instance)?
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+format!("JIRA configuration '{instance}' not found. Please configure JIRA in the [platforms.jira] section of config.toml")
+                )
+            })?;
+
+        let performance = UnifiedConfigService::load_performance_config(data_path);
+        let http = UnifiedConfigService::load_http_settings(data_path);
+        let client = JiraClient::new(&config, &http)?
+            .with_platform_id(&instance_platform_id("jira", instance))
+            .with_response_cache(ResponseCache::new(data_path))
+            .with_retry(UnifiedConfigService::load_retry_policy(data_path))
+            .with_performance(&performance);
+        let base_url = config.jira_url.trim_end_matches('/').to_string();
+        Ok((client, base_url))
+    }
+
+    pub async fn get_employee_metrics(
+        data_path: &DataPath,
+        instance: &str,
+        employee_email: &str,
+        days: u32,
+    ) -> io::Result<JiraActivityMetrics> {
+        let (client, _) = Self::connect(data_path, instance)?;
+        let user = client
+            .resolve_user(&AccountCache::new(data_path), employee_email)
+            .await;
+        client.get_activity_metrics(&user, days).await
+    }
+
+    /// The issues of each activity category of an employee, or why they could not be fetched,
+    /// with the instance's base URL
+    pub async fn get_detailed_employee_results(
+        data_path: &DataPath,
+        instance: &str,
+        employee_email: &str,
+        days: u32,
+    ) -> io::Result<(Vec<(ActivityCategory, io::Result<Vec<IssueInfo>>)>, String)> {
+        let (client, base_url) = Self::connect(data_path, instance)?;
+        let user = client
+            .resolve_user(&AccountCache::new(data_path), employee_email)
+            .await;
+        let results = client.get_detailed_activity_results(&user, days).await;
+
+        Ok((results.into(), base_url))
+    }
+
+    /// Every configured field of one issue, with the instance's base URL
+    pub async fn get_issue_details(
+        data_path: &DataPath,
+        instance: &str,
+        issue_key: &str,
+    ) -> io::Result<(IssueInfo, String)> {
+        let (client, base_url) = Self::connect(data_path, instance)?;
+        Ok((client.get_issue(issue_key).await?, base_url))
+    }
+}
+
+/// Platform wrapper for JIRA that implements the ReviewPlatform trait
+pub struct JiraPlatform {
+    data_path: DataPath,
+    /// Key of the `[platforms.jira.<instance>]` table
+    instance: String,
+    platform_id: String,
+    name: String,
+    /// `[performance] page_size`, read once for `get_capabilities`
+    page_size: u32,
+}
+
+impl JiraPlatform {
+    /// The default instance, configured with the single `[platforms.jira]` table
+    pub fn new(data_path: DataPath) -> Self {
+        Self::for_instance(data_path, DEFAULT_INSTANCE)
+    }
+
+    pub fn for_instance(data_path: DataPath, instance: &str) -> Self {
+        let name = if instance == DEFAULT_INSTANCE {
+            "JIRA".to_string()
+        } else {
+            format!("JIRA ({instance})")
+        };
+        Self {
+            instance: instance.to_string(),
+            platform_id: instance_platform_id("jira", instance),
+            name,
+            page_size: UnifiedConfigService::load_performance_config(&data_path)
+                .page_size
+                .max(1),
+            data_path,
+        }
+    }
+
+    /// Convert JIRA's ActivityMetrics to platform ActivityMetrics
+    fn convert_metrics(&self, jira_metrics: &JiraActivityMetrics) -> ActivityMetrics {
+        let mut metrics = ActivityMetrics {
+            total_items: jira_metrics.tickets_created
+                + jira_metrics.tickets_resolved
+                + jira_metrics.tickets_assigned
+                + jira_metrics.comments_added,
+            ..Default::default()
+        };
+
+        metrics.items_by_category.insert(
+            ActivityCategory::IssuesCreated,
+            jira_metrics.tickets_created,
+        );
+        metrics.items_by_category.insert(
+            ActivityCategory::IssuesResolved,
+            jira_metrics.tickets_resolved,
+        );
+        metrics.items_by_category.insert(
+            ActivityCategory::IssuesAssigned,
+            jira_metrics.tickets_assigned,
+        );
+        metrics.items_by_category.insert(
+            ActivityCategory::IssuesCommented,
+            jira_metrics.comments_added,
+        );
+
+        metrics
+            .platform_specific
+            .insert("tickets_created".to_string(), jira_metrics.tickets_created);
+        metrics.platform_specific.insert(
+            "tickets_resolved".to_string(),
+            jira_metrics.tickets_resolved,
+        );
+        metrics.platform_specific.insert(
+            "tickets_assigned".to_string(),
+            jira_metrics.tickets_assigned,
+        );
+        metrics
+            .platform_specific
+            .insert("comments_added".to_string(), jira_metrics.comments_added);
+
+        metrics
+    }
+
+    /// Convert JIRA IssueInfo to platform ActivityItem, in the category of the first matching
+    /// category rule if any
+    fn convert_issue_to_item(
+        &self,
+        issue: &IssueInfo,
+        category: ActivityCategory,
+        base_url: &str,
+        rules: &CategoryRules,
+    ) -> ActivityItem {
+        let mut metadata = HashMap::new();
+        if !issue.issue_type.is_empty() {
+            metadata.insert("issue_type".to_string(), issue.issue_type.clone());
+        }
+        metadata.insert("project".to_string(), issue.project.clone());
+        metadata.insert("status".to_string(), issue.status.clone());
+
+        if let Some(assignee) = &issue.assignee {
+            metadata.insert("assignee".to_string(), assignee.clone());
+        }
+        if let Some(priority) = &issue.priority {
+            metadata.insert("priority".to_string(), priority.clone());
+        }
+        if !issue.components.is_empty() {
+            metadata.insert("components".to_string(), issue.components.join(", "));
+        }
+        if issue.details_pending {
+            metadata.insert(DETAILS_PENDING_KEY.to_string(), "true".to_string());
+        }
+
+        let mut item = ActivityItem {
+            id: issue.key.clone(),
+            title: issue.summary.clone(),
+            status: issue.status.clone(),
+            created: issue.created.clone(),
+            updated: issue.updated.clone(),
+            url: format!("{}/browse/{}", base_url, issue.key),
+            platform: self.platform_id.clone(),
+            category,
+            project: issue.project.clone(),
+            metadata,
+        };
+        TimestampSource::Jira.flag_unparsed_dates(&mut item);
+        rules.apply(&self.platform_id, &mut item);
+        item
+    }
+}
+
+#[async_trait]
+impl ReviewPlatform for JiraPlatform {
+    async fn get_activity_metrics(&self, user: &str, days: u32) -> io::Result<ActivityMetrics> {
+        let jira_metrics =
+            JiraService::get_employee_metrics(&self.data_path, &self.instance, user, days).await?;
+        Ok(self.convert_metrics(&jira_metrics))
+    }
+
+    async fn get_detailed_activities(
+        &self,
+        user: &str,
+        days: u32,
+    ) -> io::Result<DetailedActivities> {
+        let (results, base_url) =
+            JiraService::get_detailed_employee_results(&self.data_path, &self.instance, user, days)
+                .await?;
+
+        let mut activities = DetailedActivities::default();
+        let rules = CategoryRules::load(&self.data_path);
+
+        // Convert each category of issues to activity items
+        for (category, result) in results {
+            let result = result.map(|issues| {
+                issues
+                    .iter()
+                    .map(|issue| {
+                        self.convert_issue_to_item(issue, category.clone(), &base_url, &rules)
+                    })
+                    .collect()
+            });
+            if let Err(e) = &result {
+                warn!(
+                    "Failed to load {} from {}: {e}",
+                    category.display_name(),
+                    self.name
+                );
+            }
+            activities.insert_result(category, result);
+        }
+        activities.regroup();
+
+        Ok(activities)
+    }
+
+    async fn search_items(&self, query: &str, user: &str) -> io::Result<Vec<ActivityItem>> {
+        // Basic search by filtering the activities of the configured default period
+        let days = UnifiedConfigService::load_default_period_days(&self.data_path);
+        let activities = self.get_detailed_activities(user, days).await?;
+
+        let mut results = Vec::new();
+        for items in activities.items_by_category.values() {
+            for item in items {
+                if item.title.to_lowercase().contains(&query.to_lowercase())
+                    || item.project.to_lowercase().contains(&query.to_lowercase())
+                    || item.id.to_lowercase().contains(&query.to_lowercase())
+                {
+                    results.push(item.clone());
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn get_platform_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_platform_icon(&self) -> &str {
+        "🎫"
+    }
+
+    fn get_platform_id(&self) -> &str {
+        &self.platform_id
+    }
+
+    fn is_configured(&self) -> bool {
+        JiraService::load_jira_config(&self.data_path, &self.instance)
+            .map(|config| config.is_some())
+            .unwrap_or(false)
+    }
+
+    fn get_capabilities(&self) -> PlatformCapabilities {
+        // Detailed searches request one page of issues for the requested period
+        PlatformCapabilities {
+            supports_comments: true,
+            max_items: Some(self.page_size as usize),
+        }
+    }
+
+    async fn test_connection(&self) -> io::Result<ConnectionStatus> {
+        match JiraService::load_jira_config(&self.data_path, &self.instance)? {
+            Some(config) => match JiraClient::new(
+                &config,
+                &UnifiedConfigService::load_http_settings(&self.data_path),
+            )
+            .map(|c| c.with_platform_id(&self.platform_id))
+            {
+                Ok(client) => match client.test_connection().await {
+                    Ok(_) => Ok(ConnectionStatus::Connected),
+                    Err(e) => {
+                        if e.to_string().contains("authentication") || e.to_string().contains("401")
+                        {
+                            Ok(ConnectionStatus::Error("Authentication failed".to_string()))
+                        } else if e.to_string().contains("timeout") {
+                            ErrorContext::new(&self.platform_id, "test_connection")
+                                .with_severity(Severity::Warning)
+                                .with_error("network_error", &e.to_string())
+                                .log_error();
+                            Ok(ConnectionStatus::Warning("Connection timeout".to_string()))
+                        } else {
+                            Ok(ConnectionStatus::Error(format!("Connection failed: {e}")))
+                        }
+                    }
+                },
+                Err(e) => Ok(ConnectionStatus::Error(format!(
+                    "Client creation failed: {e}"
+                ))),
+            },
+            None => Ok(ConnectionStatus::NotConfigured),
+        }
+    }
+
+    async fn get_item_details(&self, item: &ActivityItem) -> io::Result<ActivityItem> {
+        if !item.has_pending_details() {
+            return Ok(item.clone());
+        }
+        let (issue, base_url) =
+            JiraService::get_issue_details(&self.data_path, &self.instance, &item.id).await?;
+        // The item stays in the category it is listed under
+        let rules = CategoryRules::default();
+        Ok(self.convert_issue_to_item(&issue, item.category.clone(), &base_url, &rules))
+    }
+
+    fn get_item_url(&self, item: &ActivityItem) -> String {
+        item.url.clone()
+    }
+
+    fn explain_queries(&self, user: &str, days: u32) -> io::Result<Option<QueryPlan>> {
+        let Some(config) = JiraService::load_jira_config(&self.data_path, &self.instance)? else {
+            return Ok(None);
+        };
+        let performance = UnifiedConfigService::load_performance_config(&self.data_path);
+        let http = UnifiedConfigService::load_http_settings(&self.data_path);
+        let client = JiraClient::new(&config, &http)?
+            .with_platform_id(&self.platform_id)
+            .with_performance(&performance);
+
+        let mut notes = Vec::new();
+        let username = match AccountCache::new(&self.data_path).get(&self.platform_id, user) {
+            Some(username) if user.contains('@') => {
+                notes.push(format!("{user} is {username}, cached in accounts.json"));
+                username
+            }
+            None if user.contains('@') => {
+                notes.push(format!(
+                    "{user} is first resolved to a username with GET {}; \
+                     the queries below use the email when that finds no unique user",
+                    redact_url(&client.user_search_url(user))
+                ));
+                user.to_string()
+            }
+            _ => user.to_string(),
+        };
+        if config.lazy_details {
+            notes.push(format!(
+                "lazy_details: each issue opened in the browser is fetched on its own, \
+                 e.g. GET {}",
+                redact_url(&client.issue_url("KEY-1"))
+            ));
+        }
+
+        Ok(Some(QueryPlan {
+            auth: format!("Bearer token {}", mask_secret(&config.api_token)),
+            time_range: format!(
+                "issues created or resolved in the last {days} days (-{days}d); \
+                 assigned issues are all unresolved ones, at most {} per query",
+                performance.page_size.max(1)
+            ),
+            requests: client.planned_requests(&username, days),
+            notes,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param, query_param_contains};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_detailed_metrics_query_requested_period() {
+        let server = MockServer::start().await;
+        let config = JiraConfig {
+            jira_url: server.uri(),
+            username: "bot".to_string(),
+            api_token: "token".to_string(),
+            project_filter: Vec::new(),
+            custom_fields: HashMap::new(),
+            fields: Vec::new(),
+            lazy_details: false,
+            accept_invalid_certs: false,
+            limits: RequestLimits::default(),
+        };
+
+        // Created and resolved searches use the requested window; assigned has none
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/search"))
+            .and(query_param_contains("jql", ">= -90d"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(r#"{"issues": [], "total": 0}"#),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/search"))
+            .and(query_param_contains("jql", "resolution = Unresolved"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(r#"{"issues": [], "total": 0}"#),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = JiraClient::new(&config, &HttpClientSettings::default()).unwrap();
+        let metrics = client
+            .get_detailed_activity_metrics("jane@example.com", 90)
+            .await
+            .unwrap();
+        assert!(metrics.tickets_created.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_users_resolves_username() {
+        let server = MockServer::start().await;
+        let config = JiraConfig {
+            jira_url: server.uri(),
+            username: "bot".to_string(),
+            api_token: "token".to_string(),
+            project_filter: Vec::new(),
+            custom_fields: HashMap::new(),
+            fields: Vec::new(),
+            lazy_details: false,
+            accept_invalid_certs: false,
+            limits: RequestLimits::default(),
+        };
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/user/search"))
+            .and(query_param_contains("username", "jane@example.com"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"name": "jdoe", "displayName": "Jane Doe", "emailAddress": "jane@example.com"},
+                    {"name": "jdoe2", "displayName": "Jane Doe", "emailAddress": "jane@other.com"}]"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let accounts = AccountCache::new(&data_path);
+        let client = JiraClient::new(&config, &HttpClientSettings::default()).unwrap();
+        assert_eq!(
+            client.resolve_user(&accounts, "jane@example.com").await,
+            "jdoe"
+        );
+        // The second resolution is answered from accounts.json
+        assert_eq!(
+            client.resolve_user(&accounts, "jane@example.com").await,
+            "jdoe"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_search_is_reported_for_its_category() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/search"))
+            .and(query_param("jql", assigned_issues_jql("jdoe")))
+            .respond_with(ResponseTemplate::new(403).set_body_string("Forbidden"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/search"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(r#"{"total": 0, "issues": []}"#),
+            )
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        std::fs::write(
+            data_path.config_path(),
+            format!(
+                "[platforms.jira]\njira_url = \"{}\"\nusername = \"bot\"\napi_token = \"t\"\n\n[ui_preferences]\n",
+                server.uri()
+            ),
+        )
+        .unwrap();
+        AccountCache::new(&data_path)
+            .insert("jira", "jane@example.com", "jdoe")
+            .unwrap();
+
+        let activities = JiraPlatform::new(data_path)
+            .get_detailed_activities("jane@example.com", 30)
+            .await
+            .unwrap();
+        assert!(
+            activities.failed_categories[&ActivityCategory::IssuesAssigned].contains("403"),
+            "{activities:?}"
+        );
+        assert_eq!(activities.failed_categories.len(), 1);
+        assert!(
+            activities
+                .items_by_category
+                .contains_key(&ActivityCategory::IssuesResolved)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lazy_details_search_then_expand() {
+        let server = MockServer::start().await;
+        let config = JiraConfig {
+            jira_url: server.uri(),
+            username: "bot".to_string(),
+            api_token: "token".to_string(),
+            project_filter: Vec::new(),
+            custom_fields: HashMap::new(),
+            fields: vec!["priority".to_string(), "customfield_10002".to_string()],
+            lazy_details: true,
+            accept_invalid_certs: false,
+            limits: RequestLimits::default(),
+        };
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/search"))
+            .and(query_param(
+                "fields",
+                "summary,status,created,updated,project",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{"total": 1, "issues": [{"key": "CORE-1", "fields": {
+                    "summary": "Fix login", "status": {"name": "Open"},
+                    "created": "2025-03-01T10:00:00.000+0000",
+                    "updated": "2025-03-02T10:00:00.000+0000",
+                    "project": {"key": "CORE", "name": "Core"}}}]}"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/issue/CORE-1"))
+            .and(query_param(
+                "fields",
+                "summary,status,created,updated,project,priority,customfield_10002",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{"key": "CORE-1", "fields": {
+                    "summary": "Fix login", "status": {"name": "Open"},
+                    "created": "2025-03-01T10:00:00.000+0000",
+                    "updated": "2025-03-02T10:00:00.000+0000",
+                    "project": {"key": "CORE", "name": "Core"},
+                    "priority": {"name": "High"}}}"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = JiraClient::new(&config, &HttpClientSettings::default()).unwrap();
+        let issues = client
+            .get_detailed_activity_metrics("jdoe", 30)
+            .await
+            .unwrap()
+            .tickets_created;
+        assert_eq!(issues[0].summary, "Fix login");
+        assert!(issues[0].details_pending);
+        assert_eq!(issues[0].issue_type, "");
+
+        let issue = client.get_issue("CORE-1").await.unwrap();
+        assert!(!issue.details_pending);
+        assert_eq!(issue.priority.as_deref(), Some("High"));
+    }
+}
@@ -0,0 +1,241 @@
+//! Weekly journaling pass: which employees' notes were written in which ISO week, and the
+//! activity highlights shown before each one
+
+use crate::models::DataPath;
+use crate::platform::{ActivityCategory, ActivityItem, DetailedActivities};
+use crate::timestamps::parse_timestamp;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// How many weeks of progress are kept in `journal.json`
+pub const KEPT_WEEKS: usize = 12;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct JournalFile {
+    /// ISO week (`2024-W03`) -> employees journaled that week
+    weeks: BTreeMap<String, Vec<String>>,
+}
+
+/// What an employee did recently, for a quick look before writing their notes
+#[derive(Debug, Clone, Default)]
+pub struct JournalHighlights {
+    /// Items per category, largest first
+    pub counts: Vec<(ActivityCategory, usize)>,
+    /// Most recently updated items first
+    pub latest: Vec<ActivityItem>,
+}
+
+impl JournalHighlights {
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+}
+
+pub struct JournalService;
+
+impl JournalService {
+    fn journal_path(data_path: &DataPath) -> PathBuf {
+        data_path.root.join("journal.json")
+    }
+
+    /// ISO week of `date`, e.g. `2024-W03`
+    pub fn week_key(date: NaiveDate) -> String {
+        let week = date.iso_week();
+        format!("{}-W{:02}", week.year(), week.week())
+    }
+
+    fn load(data_path: &DataPath) -> JournalFile {
+        let path = Self::journal_path(data_path);
+        let Ok(content) = fs::read_to_string(&path) else {
+            return JournalFile::default();
+        };
+        serde_json::from_str(&content).unwrap_or_else(|e| {
+            warn!("Ignoring unreadable {}: {e}", path.display());
+            JournalFile::default()
+        })
+    }
+
+    /// Employees journaled in `week`
+    pub fn completed(data_path: &DataPath, week: &str) -> Vec<String> {
+        Self::load(data_path).weeks.remove(week).unwrap_or_default()
+    }
+
+    /// Record that `employee`'s notes were written in `week`; only the last [`KEPT_WEEKS`]
+    /// weeks are kept
+    pub fn mark_done(data_path: &DataPath, week: &str, employee: &str) -> io::Result<()> {
+        let mut journal = Self::load(data_path);
+        let done = journal.weeks.entry(week.to_string()).or_default();
+        if done.iter().any(|name| name == employee) {
+            return Ok(());
+        }
+        done.push(employee.to_string());
+        while journal.weeks.len() > KEPT_WEEKS {
+            journal.weeks.pop_first();
+        }
+
+        let content = serde_json::to_string_pretty(&journal).map_err(io::Error::other)?;
+        fs::write(Self::journal_path(data_path), content)
+    }
+
+    /// Drop a removed or archived employee from every journaled week
+    pub fn forget(data_path: &DataPath, employee: &str) -> io::Result<()> {
+        let mut journal = Self::load(data_path);
+        let mut forgotten = false;
+        for done in journal.weeks.values_mut() {
+            let before = done.len();
+            done.retain(|name| name != employee);
+            forgotten |= done.len() != before;
+        }
+        if !forgotten {
+            return Ok(());
+        }
+
+        let content = serde_json::to_string_pretty(&journal).map_err(io::Error::other)?;
+        fs::write(Self::journal_path(data_path), content)
+    }
+
+    /// Carry a renamed employee's journaled weeks over to the new name
+    pub fn rename(data_path: &DataPath, old_name: &str, new_name: &str) -> io::Result<()> {
+        let mut journal = Self::load(data_path);
+        let mut renamed = false;
+        for done in journal.weeks.values_mut() {
+            if done.iter().any(|name| name == new_name) {
+                let before = done.len();
+                done.retain(|name| name != old_name);
+                renamed |= done.len() != before;
+            } else if let Some(name) = done.iter_mut().find(|name| *name == old_name) {
+                *name = new_name.to_string();
+                renamed = true;
+            }
+        }
+        if !renamed {
+            return Ok(());
+        }
+
+        let content = serde_json::to_string_pretty(&journal).map_err(io::Error::other)?;
+        fs::write(Self::journal_path(data_path), content)
+    }
+
+    /// Items updated since `since` across `activities`, keeping `max_items` of the latest
+    pub fn highlights<'a>(
+        activities: impl IntoIterator<Item = &'a DetailedActivities>,
+        since: DateTime<Utc>,
+        max_items: usize,
+    ) -> JournalHighlights {
+        let mut counts: HashMap<ActivityCategory, usize> = HashMap::new();
+        let mut recent: Vec<(DateTime<Utc>, &ActivityItem)> = Vec::new();
+        for (category, items) in activities
+            .into_iter()
+            .flat_map(|activities| &activities.items_by_category)
+        {
+            for item in items {
+                let Some(updated) = parse_timestamp(&item.updated) else {
+                    continue;
+                };
+                if updated >= since {
+                    *counts.entry(category.clone()).or_default() += 1;
+                    recent.push((updated, item));
+                }
+            }
+        }
+
+        let mut counts: Vec<(ActivityCategory, usize)> = counts.into_iter().collect();
+        counts.sort_by(|(a, a_count), (b, b_count)| {
+            b_count
+                .cmp(a_count)
+                .then_with(|| a.display_name().cmp(b.display_name()))
+        });
+        recent.sort_by_key(|(updated, _)| std::cmp::Reverse(*updated));
+        JournalHighlights {
+            counts,
+            latest: recent
+                .into_iter()
+                .take(max_items)
+                .map(|(_, item)| item.clone())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::ItemBuilder;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_week_progress() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 1, 18).unwrap();
+        let week = JournalService::week_key(date);
+        assert_eq!(week, "2024-W03");
+        assert_eq!(
+            JournalService::week_key(NaiveDate::from_ymd_opt(2024, 12, 30).unwrap()),
+            "2025-W01"
+        );
+
+        assert!(JournalService::completed(&data_path, &week).is_empty());
+        JournalService::mark_done(&data_path, &week, "Alice").unwrap();
+        JournalService::mark_done(&data_path, &week, "Alice").unwrap();
+        JournalService::mark_done(&data_path, "2024-W04", "Bob").unwrap();
+        assert_eq!(JournalService::completed(&data_path, &week), vec!["Alice"]);
+
+        for week in 5..20 {
+            JournalService::mark_done(&data_path, &format!("2024-W{week:02}"), "Bob").unwrap();
+        }
+        assert!(JournalService::completed(&data_path, "2024-W03").is_empty());
+        assert_eq!(
+            JournalService::completed(&data_path, "2024-W19"),
+            vec!["Bob"]
+        );
+    }
+
+    #[test]
+    fn test_highlights_of_recent_items() {
+        let item = |id: &str, category: ActivityCategory, updated: &str| {
+            ItemBuilder::new(id)
+                .with_category(category)
+                .with_created(updated)
+                .with_updated(updated)
+                .build()
+        };
+        let mut activities = DetailedActivities::default();
+        activities.items_by_category.insert(
+            ActivityCategory::ReviewsGiven,
+            vec![
+                item("1", ActivityCategory::ReviewsGiven, "2024-01-17T10:00:00Z"),
+                item("2", ActivityCategory::ReviewsGiven, "2024-01-15T10:00:00Z"),
+                item("3", ActivityCategory::ReviewsGiven, "2024-01-01T10:00:00Z"),
+            ],
+        );
+        activities.items_by_category.insert(
+            ActivityCategory::ChangesMerged,
+            vec![item(
+                "4",
+                ActivityCategory::ChangesMerged,
+                "2024-01-18 09:00:00",
+            )],
+        );
+
+        let since = DateTime::parse_from_rfc3339("2024-01-11T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let highlights = JournalService::highlights([&activities], since, 2);
+        assert_eq!(
+            highlights.counts,
+            vec![
+                (ActivityCategory::ReviewsGiven, 2),
+                (ActivityCategory::ChangesMerged, 1)
+            ]
+        );
+        let latest: Vec<&str> = highlights.latest.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(latest, vec!["4", "1"]);
+        assert!(JournalService::highlights([], since, 2).is_empty());
+    }
+}
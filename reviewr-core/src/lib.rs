@@ -0,0 +1,116 @@
+//! Core library behind the `reviewr` CLI.
+//!
+//! This crate holds everything that does not depend on a terminal: the data
+//! layout on disk, employee and notes services, the unified configuration and
+//! the review platform integrations (Gerrit, JIRA, GitLab). Other tools can use
+//! it to fetch review activity programmatically:
+//!
+//! ```no_run
+//! use reviewr_core::models::DataPath;
+//! use reviewr_core::platform::{PlatformRegistry, ReviewPlatform};
+//! use reviewr_core::gerrit::GerritPlatform;
+//!
+//! # async fn run() -> std::io::Result<()> {
+//! let data_path = DataPath::new(None)?;
+//! let mut registry = PlatformRegistry::new();
+//! registry.register_platform(Box::new(GerritPlatform::new(data_path.clone())));
+//!
+//! for platform in registry.get_configured_platforms() {
+//!     let activities = platform
+//!         .get_detailed_activities("jane@example.com", 30)
+//!         .await?;
+//!     println!("{}: {} categories", platform.get_platform_name(), activities.items_by_category.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+/// Platform usernames resolved from employee emails through each platform's user API
+pub mod accounts;
+/// Free-text annotations on activity items, kept across sessions
+pub mod annotations;
+/// Pseudonyms and internal-link removal for reports shared outside the company network
+pub mod anonymize;
+/// Crash-safe file replacement with a `.bak` copy of the previous version
+pub mod atomic_write;
+/// One field change applied to every employee matching a team, manager or tag
+pub mod bulk_edit;
+/// Configuration bundles for moving reviewr's settings to another machine
+pub mod bundle;
+/// On-disk cache of fetched platform activity with a time-to-live
+pub mod cache;
+/// Config rules moving items with matching labels or projects into categories of their own
+pub mod category_rules;
+/// XS/S/M/L size buckets of authored changes from their diffstat
+pub mod change_size;
+/// Custom platforms backed by an external executable printing JSON
+pub mod command_platform;
+/// Schema check of `config.toml` reporting each problem with its line and column
+pub mod config_check;
+/// Named review cycles that scope notes and review packets to a date range
+pub mod cycle;
+/// Allowed domains for clipboard evidence and, when enforced, platform servers
+pub mod domains;
+/// Employee records stored as `employees/{name}.toml`
+pub mod employee;
+/// Employee files created in bulk from a CSV file or an LDAP directory
+pub mod employee_import;
+/// Cached employee names and titles for an instant selector start
+pub mod employee_index;
+/// Peer feedback requests with suggested collaborators to ask
+pub mod feedback;
+/// Progress events emitted while fetching platform data
+pub mod fetch_progress;
+/// Gerrit client and `ReviewPlatform` implementation
+pub mod gerrit;
+/// GitLab `ReviewPlatform` implementation (one per configured instance)
+pub mod gitlab;
+/// Employee goals and their progress estimated from activity data
+pub mod goals;
+/// Portable folders with everything kept about one employee, for a new manager
+pub mod handoff;
+/// Retries with exponential backoff for transient platform HTTP failures
+pub mod http;
+/// Conditional GET support (ETag / Last-Modified) shared by the platform clients
+pub mod http_cache;
+/// JIRA client and `ReviewPlatform` implementation
+pub mod jira;
+/// Weekly journaling progress and activity highlights
+pub mod journal;
+/// Shared data types: `Employee`, `DataPath` and input validation
+pub mod models;
+/// Notes encrypted to a per-employee age recipient key
+pub mod note_encryption;
+/// Per-employee Markdown notes
+pub mod notes;
+/// Organisation-wide distributions of cached activity metrics
+pub mod org_stats;
+/// Category counts of the review period against the equally long period before it
+pub mod period_comparison;
+/// The `ReviewPlatform` trait, activity types, registry and error reporting
+pub mod platform;
+/// Recently opened employees for the selector's default ordering
+pub mod recent;
+/// Redaction of secrets and personal data in logged request details
+pub mod redact;
+/// Markdown review packet export
+pub mod report;
+/// Configurable activity index: a weighted sum over activity categories
+pub mod scoring;
+/// Offline full-text index of cached activity items and notes entries
+pub mod search_index;
+/// Platform credentials in the OS keyring with `config.toml` fallback
+pub mod secrets;
+/// Typed settings resolved from CLI flags, environment, `config.toml` and defaults
+pub mod settings;
+/// The platform instance entered in `reviewr init`, tested live before it is saved
+pub mod setup;
+/// Per-person activity counts for comparing a team
+pub mod team;
+/// Activity builders and sample data for tests, behind the `test-support` feature
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+/// Platform timestamp parsing and time zone aware display
+pub mod timestamps;
+/// The unified `config.toml` format and its loader
+pub mod unified_config;
@@ -0,0 +1,282 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Employee {
+    pub name: String,
+    pub title: String,
+    pub committer_email: Option<String>,
+    /// Optional team, manager, start date, location and tags; absent in older files
+    #[serde(flatten)]
+    pub profile: EmployeeProfile,
+    /// Platform usernames keyed by platform id (`gerrit`, `jira`, `gitlab:<instance>`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub usernames: BTreeMap<String, String>,
+    /// Platforms whose activity must not be fetched; `gitlab` covers every GitLab instance
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_platforms: Vec<String>,
+    /// No activity is aggregated at all; only notes and goals are used
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub notes_only: bool,
+    /// age public key the notes are encrypted to, as `notes/<name>.md.age`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes_recipient: Option<String>,
+    /// Leave and other time away, left out of activity rates
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub out_of_office: Vec<Absence>,
+}
+
+/// A stretch of time out of office, first and last day included
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Absence {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl Absence {
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        date >= self.start && date <= self.end
+    }
+}
+
+/// Days from `from` to `to` (inclusive) that fall in any of `absences`; overlapping ranges
+/// count once
+pub fn days_absent(absences: &[Absence], from: NaiveDate, to: NaiveDate) -> u32 {
+    from.iter_days()
+        .take_while(|date| *date <= to)
+        .filter(|date| absences.iter().any(|absence| absence.contains(*date)))
+        .count() as u32
+}
+
+/// Structured, optional details about an employee
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmployeeProfile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manager: Option<String>,
+    /// First day in the role, `YYYY-MM-DD`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+impl EmployeeProfile {
+    pub fn validate(&self) -> io::Result<()> {
+        if let Some(start_date) = &self.start_date
+            && NaiveDate::parse_from_str(start_date, "%Y-%m-%d").is_err()
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Start date '{start_date}' must be formatted as YYYY-MM-DD"),
+            ));
+        }
+        if self.tags.iter().any(|tag| tag.trim().is_empty()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Tags cannot be empty",
+            ));
+        }
+        Ok(())
+    }
+
+    /// One-line description such as `Platform · manager Ann Lee · since 2022-03-01 · #backend`,
+    /// or `None` when no field is set
+    pub fn summary(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if let Some(team) = &self.team {
+            parts.push(team.clone());
+        }
+        if let Some(manager) = &self.manager {
+            parts.push(format!("manager {manager}"));
+        }
+        if let Some(start_date) = &self.start_date {
+            parts.push(format!("since {start_date}"));
+        }
+        if let Some(location) = &self.location {
+            parts.push(location.clone());
+        }
+        if !self.tags.is_empty() {
+            let tags: Vec<String> = self.tags.iter().map(|tag| format!("#{tag}")).collect();
+            parts.push(tags.join(" "));
+        }
+        (!parts.is_empty()).then(|| parts.join(" · "))
+    }
+}
+
+impl Employee {
+    /// Out-of-office ranges must not end before they start
+    pub fn validate_out_of_office(&self) -> io::Result<()> {
+        match self
+            .out_of_office
+            .iter()
+            .find(|absence| absence.end < absence.start)
+        {
+            Some(absence) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Out-of-office range {} to {} ends before it starts",
+                    absence.start, absence.end
+                ),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Days out of office among the `days` days up to and including `today`
+    pub fn days_out_of_office(&self, today: NaiveDate, days: u32) -> u32 {
+        let from = today - chrono::Duration::days(days.saturating_sub(1) as i64);
+        days_absent(&self.out_of_office, from, today)
+    }
+
+    /// Whether this employee's activity may be fetched from a platform
+    pub fn allows_platform(&self, platform_id: &str) -> bool {
+        !self.notes_only
+            && !self.exclude_platforms.iter().any(|excluded| {
+                let excluded = excluded.trim();
+                platform_id == excluded
+                    || platform_id
+                        .strip_prefix(excluded)
+                        .is_some_and(|rest| rest.starts_with(':'))
+            })
+    }
+
+    /// Identifier to query a platform with: its configured username, else the committer email.
+    /// `None` when the platform is excluded by the employee's data policy.
+    pub fn identity_for(&self, platform_id: &str) -> Option<&str> {
+        if !self.allows_platform(platform_id) {
+            return None;
+        }
+        self.usernames
+            .get(platform_id)
+            .map(|username| username.trim())
+            .filter(|username| !username.is_empty())
+            .or_else(|| {
+                self.committer_email
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|email| !email.is_empty())
+            })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DataPath {
+    pub root: PathBuf,
+    pub employees_dir: PathBuf,
+    pub notes_dir: PathBuf,
+    pub goals_dir: PathBuf,
+    /// Former team members, with the same `employees/`, `notes/` and `goals/` layout
+    pub archive_dir: PathBuf,
+}
+
+impl DataPath {
+    pub fn new(data_path: Option<PathBuf>) -> io::Result<Self> {
+        let root = match data_path {
+            Some(path) => path,
+            None => dirs::home_dir()
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::NotFound,
+                        "Home directory not found. Please specify --data-path.",
+                    )
+                })?
+                .join(".reviewr"),
+        };
+        let employees_dir = root.join("employees");
+        let notes_dir = root.join("notes");
+        let goals_dir = root.join("goals");
+        let archive_dir = root.join("archive");
+
+        Ok(Self {
+            root,
+            employees_dir,
+            notes_dir,
+            goals_dir,
+            archive_dir,
+        })
+    }
+
+    pub fn config_path(&self) -> PathBuf {
+        self.root.join("config.toml")
+    }
+
+    /// Note templates, `templates/<name>.md`
+    pub fn templates_dir(&self) -> PathBuf {
+        self.root.join("templates")
+    }
+}
+
+pub fn validate_employee_name(name: &str) -> io::Result<()> {
+    if name.trim().is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Employee name cannot be empty",
+        ));
+    }
+
+    let invalid_chars = ['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+    if name.chars().any(|c| invalid_chars.contains(&c)) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Employee name contains invalid characters (/, \\, :, *, ?, \", <, >, |)",
+        ));
+    }
+
+    if name.len() > 255 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Employee name too long (max 255 characters)",
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_domain(domain: &str) -> io::Result<()> {
+    if domain.trim().is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Domain cannot be empty",
+        ));
+    }
+
+    // Basic domain validation - check for valid characters and structure
+    if !domain
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-')
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Domain contains invalid characters",
+        ));
+    }
+
+    if domain.starts_with('.')
+        || domain.ends_with('.')
+        || domain.starts_with('-')
+        || domain.ends_with('-')
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Domain format is invalid",
+        ));
+    }
+
+    if domain.contains("..") || domain.contains("--") {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Domain format is invalid",
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,347 @@
+//! Notes encrypted to an employee's age recipient key (`notes/<name>.md.age`), through the
+//! `age` command line tool
+
+use crate::models::DataPath;
+use log::info;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Encryption of whole notes files to a recipient, and decryption with an identity file
+pub trait NotesCipher {
+    fn encrypt(&self, recipient: &str, plaintext: &[u8]) -> io::Result<Vec<u8>>;
+    fn decrypt(&self, identity: &Path, ciphertext: &[u8]) -> io::Result<Vec<u8>>;
+}
+
+/// The `age` binary (<https://age-encryption.org>)
+pub struct AgeCli;
+
+impl AgeCli {
+    fn run(args: &[&str], input: &[u8]) -> io::Result<Vec<u8>> {
+        let mut child = Command::new("age")
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| match e.kind() {
+                io::ErrorKind::NotFound => io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "The `age` command is not installed; see https://age-encryption.org",
+                ),
+                _ => e,
+            })?;
+        // Feed stdin from a thread so a large output cannot block the write
+        let mut stdin = child.stdin.take().expect("stdin is piped");
+        let input = input.to_vec();
+        let writer = std::thread::spawn(move || stdin.write_all(&input));
+        let output = child.wait_with_output()?;
+        writer
+            .join()
+            .map_err(|_| io::Error::other("Writing to age failed"))??;
+        if !output.status.success() {
+            return Err(io::Error::other(format!(
+                "age failed ({}): {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(output.stdout)
+    }
+}
+
+impl NotesCipher for AgeCli {
+    fn encrypt(&self, recipient: &str, plaintext: &[u8]) -> io::Result<Vec<u8>> {
+        Self::run(&["--encrypt", "--recipient", recipient], plaintext)
+    }
+
+    fn decrypt(&self, identity: &Path, ciphertext: &[u8]) -> io::Result<Vec<u8>> {
+        let identity = identity.to_string_lossy();
+        Self::run(&["--decrypt", "--identity", &identity], ciphertext)
+    }
+}
+
+/// Decrypted notes in a private temporary directory, removed again when dropped
+pub struct NotesEditCopy {
+    pub path: PathBuf,
+    dir: PathBuf,
+    /// Left on disk because the edits could not be encrypted
+    kept: bool,
+}
+
+impl Drop for NotesEditCopy {
+    fn drop(&mut self) {
+        if !self.kept {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+}
+
+pub struct NoteEncryptionService;
+
+impl NoteEncryptionService {
+    pub fn encrypted_path(data_path: &DataPath, employee_name: &str) -> PathBuf {
+        data_path.notes_dir.join(format!("{employee_name}.md.age"))
+    }
+
+    /// age X25519 (`age1...`) or SSH (`ssh-ed25519 ...`, `ssh-rsa ...`) public key
+    pub fn validate_recipient(recipient: &str) -> io::Result<()> {
+        let recipient = recipient.trim();
+        let valid = (recipient.starts_with("age1")
+            && recipient.len() > 4
+            && !recipient.contains(char::is_whitespace))
+            || recipient.starts_with("ssh-ed25519 ")
+            || recipient.starts_with("ssh-rsa ");
+        if valid {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Invalid age recipient '{recipient}' (expected an age1... or ssh-ed25519 public key)"
+                ),
+            ))
+        }
+    }
+
+    /// Decrypted notes, or `None` when the employee has no encrypted notes
+    pub fn read(
+        data_path: &DataPath,
+        employee_name: &str,
+        identity: Option<&Path>,
+        cipher: &dyn NotesCipher,
+    ) -> io::Result<Option<String>> {
+        let path = Self::encrypted_path(data_path, employee_name);
+        let ciphertext = match fs::read(&path) {
+            Ok(ciphertext) => ciphertext,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let identity = identity.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "Notes for '{employee_name}' are encrypted; set the identity file with `reviewr config set age_identity <path>`"
+                ),
+            )
+        })?;
+        let plaintext = cipher.decrypt(identity, &ciphertext)?;
+        String::from_utf8(plaintext)
+            .map(Some)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Write `content` encrypted to `recipient`, replacing the encrypted notes in one step and
+    /// removing any plaintext `<name>.md` left from before encryption was enabled
+    pub fn write(
+        data_path: &DataPath,
+        employee_name: &str,
+        recipient: &str,
+        content: &str,
+        cipher: &dyn NotesCipher,
+    ) -> io::Result<()> {
+        let ciphertext = cipher.encrypt(recipient, content.as_bytes())?;
+        let path = Self::encrypted_path(data_path, employee_name);
+        let staging = path.with_extension("age.tmp");
+        fs::write(&staging, ciphertext)?;
+        fs::rename(&staging, &path)?;
+
+        let plaintext = data_path.notes_dir.join(format!("{employee_name}.md"));
+        if plaintext.exists() {
+            fs::remove_file(&plaintext)?;
+            info!("Replaced {} with {}", plaintext.display(), path.display());
+        }
+        Ok(())
+    }
+
+    /// Copy of the notes to edit: the decrypted `<name>.md.age`, else the plaintext notes about
+    /// to be encrypted, else no file at all
+    pub fn checkout(
+        data_path: &DataPath,
+        employee_name: &str,
+        identity: Option<&Path>,
+        cipher: &dyn NotesCipher,
+    ) -> io::Result<NotesEditCopy> {
+        let content = match Self::read(data_path, employee_name, identity, cipher)? {
+            Some(content) => Some(content),
+            None => {
+                let plaintext = data_path.notes_dir.join(format!("{employee_name}.md"));
+                match fs::read_to_string(&plaintext) {
+                    Ok(content) => Some(content),
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+                    Err(e) => return Err(e),
+                }
+            }
+        };
+
+        let dir = private_temp_dir()?;
+        let copy = NotesEditCopy {
+            path: dir.join(format!("{employee_name}.md")),
+            dir,
+            kept: false,
+        };
+        if let Some(content) = content {
+            fs::write(&copy.path, content)?;
+        }
+        Ok(copy)
+    }
+
+    /// Encrypt an edited copy back into the notes directory. If that fails the copy is kept,
+    /// so the edits are not lost.
+    pub fn checkin(
+        data_path: &DataPath,
+        employee_name: &str,
+        recipient: &str,
+        mut copy: NotesEditCopy,
+        cipher: &dyn NotesCipher,
+    ) -> io::Result<()> {
+        let content = match fs::read_to_string(&copy.path) {
+            Ok(content) => content,
+            // Nothing was written, e.g. the editor quit without saving new notes
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        Self::write(data_path, employee_name, recipient, &content, cipher).map_err(|e| {
+            copy.kept = true;
+            io::Error::new(
+                e.kind(),
+                format!(
+                    "{e}; the unencrypted edits are kept in {}",
+                    copy.path.display()
+                ),
+            )
+        })
+    }
+}
+
+/// A fresh directory only the current user can read
+fn private_temp_dir() -> io::Result<PathBuf> {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.subsec_nanos())
+        .unwrap_or_default();
+    let dir = std::env::temp_dir().join(format!("reviewr-{}-{nanos}", std::process::id()));
+    let mut builder = fs::DirBuilder::new();
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder.create(&dir)?;
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    /// Reversible stand-in for age: the recipient followed by the reversed bytes
+    struct ReverseCipher;
+
+    impl NotesCipher for ReverseCipher {
+        fn encrypt(&self, recipient: &str, plaintext: &[u8]) -> io::Result<Vec<u8>> {
+            let mut out = format!("{recipient}\n").into_bytes();
+            out.extend(plaintext.iter().rev());
+            Ok(out)
+        }
+
+        fn decrypt(&self, _identity: &Path, ciphertext: &[u8]) -> io::Result<Vec<u8>> {
+            let start = ciphertext.iter().position(|b| *b == b'\n').unwrap() + 1;
+            Ok(ciphertext[start..].iter().rev().copied().collect())
+        }
+    }
+
+    #[test]
+    fn test_validate_recipient() {
+        assert!(NoteEncryptionService::validate_recipient("age1qyqszqgpqyqszqgp").is_ok());
+        assert!(NoteEncryptionService::validate_recipient("ssh-ed25519 AAAAC3Nza me@host").is_ok());
+        assert!(NoteEncryptionService::validate_recipient("age1").is_err());
+        assert!(NoteEncryptionService::validate_recipient("hunter2").is_err());
+    }
+
+    #[test]
+    fn test_edit_round_trip_replaces_plaintext() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        let plaintext = data_path.notes_dir.join("Jane Doe.md");
+        fs::write(&plaintext, "# Notes for Jane Doe\n\nPIP started\n").unwrap();
+        let identity = temp_dir.path().join("keys.txt");
+
+        // Plaintext notes are picked up and encrypted on the first check-in
+        let copy =
+            NoteEncryptionService::checkout(&data_path, "Jane Doe", None, &ReverseCipher).unwrap();
+        let mut content = fs::read_to_string(&copy.path).unwrap();
+        content.push_str("\n## 2024-01-15\n\nCheck-in\n");
+        fs::write(&copy.path, &content).unwrap();
+        let dir = copy.dir.clone();
+        NoteEncryptionService::checkin(&data_path, "Jane Doe", "age1abc", copy, &ReverseCipher)
+            .unwrap();
+        assert!(!dir.exists());
+        assert!(!plaintext.exists());
+
+        let encrypted = NoteEncryptionService::encrypted_path(&data_path, "Jane Doe");
+        assert!(
+            !fs::read_to_string(&encrypted)
+                .unwrap()
+                .contains("PIP started")
+        );
+        let read =
+            NoteEncryptionService::read(&data_path, "Jane Doe", Some(&identity), &ReverseCipher)
+                .unwrap();
+        assert_eq!(read.as_deref(), Some(content.as_str()));
+
+        // Encrypted notes need an identity to be read
+        let error =
+            NoteEncryptionService::read(&data_path, "Jane Doe", None, &ReverseCipher).unwrap_err();
+        assert!(error.to_string().contains("age_identity"));
+        assert!(
+            NoteEncryptionService::read(&data_path, "John Smith", None, &ReverseCipher)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    struct FailingCipher;
+
+    impl NotesCipher for FailingCipher {
+        fn encrypt(&self, _recipient: &str, _plaintext: &[u8]) -> io::Result<Vec<u8>> {
+            Err(io::Error::other("age failed"))
+        }
+
+        fn decrypt(&self, _identity: &Path, _ciphertext: &[u8]) -> io::Result<Vec<u8>> {
+            Err(io::Error::other("age failed"))
+        }
+    }
+
+    #[test]
+    fn test_failed_checkin_keeps_edits() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+
+        let copy =
+            NoteEncryptionService::checkout(&data_path, "Jane Doe", None, &FailingCipher).unwrap();
+        fs::write(&copy.path, "# Notes\n").unwrap();
+        let path = copy.path.clone();
+        let error =
+            NoteEncryptionService::checkin(&data_path, "Jane Doe", "age1abc", copy, &FailingCipher)
+                .unwrap_err();
+        assert!(error.to_string().contains("kept in"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "# Notes\n");
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_checkin_without_edit_writes_nothing() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+
+        let copy =
+            NoteEncryptionService::checkout(&data_path, "Jane Doe", None, &ReverseCipher).unwrap();
+        assert!(!copy.path.exists());
+        NoteEncryptionService::checkin(&data_path, "Jane Doe", "age1abc", copy, &ReverseCipher)
+            .unwrap();
+        assert!(!NoteEncryptionService::encrypted_path(&data_path, "Jane Doe").exists());
+    }
+}
@@ -0,0 +1,1175 @@
+use crate::cycle::CycleService;
+use crate::domains::is_domain_allowed;
+use crate::employee::EmployeeService;
+use crate::http::HttpClientSettings;
+use crate::models::{DataPath, Employee};
+use crate::note_encryption::{AgeCli, NoteEncryptionService};
+use crate::platform::ActivityItem;
+use crate::timestamps::TimestampFormat;
+use crate::unified_config::UnifiedConfigService;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use log::{info, warn};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+/// Statistics for a single `## ` section of a notes file
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoteSectionStats {
+    pub heading: String,
+    pub word_count: usize,
+    pub evidence_links: usize,
+    pub tags: Vec<String>,
+}
+
+/// Aggregated statistics for an employee's notes file
+#[derive(Debug, Clone, Default)]
+pub struct NotesStats {
+    pub sections: Vec<NoteSectionStats>,
+    pub total_words: usize,
+    pub total_evidence_links: usize,
+    pub tag_counts: BTreeMap<String, usize>,
+}
+
+/// A line in another employee's notes that mentions someone as `@Employee Name`
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoteMention {
+    /// Employee whose notes file contains the mention
+    pub source: String,
+    /// The `## ` section the line belongs to, if any
+    pub section: Option<String>,
+    pub line: String,
+}
+
+/// A dated `## YYYY-MM-DD [HH:MM] [title]` section of a notes file
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoteEntry {
+    pub date: NaiveDate,
+    pub time: Option<NaiveTime>,
+    /// Heading text after the date and time, e.g. `1:1` for a template section
+    pub title: String,
+    pub body: String,
+}
+
+impl NoteEntry {
+    /// The review cycle the entry was tagged with, from a trailing `[<cycle>]` in the title
+    pub fn cycle(&self) -> Option<&str> {
+        let tag = self.title.strip_suffix(']')?;
+        let (_, name) = tag.rsplit_once('[')?;
+        Some(name)
+    }
+
+    /// The heading text without the leading `## `
+    pub fn heading(&self) -> String {
+        let mut heading = self.date.format("%Y-%m-%d").to_string();
+        if let Some(time) = self.time {
+            heading.push_str(&time.format(" %H:%M").to_string());
+        }
+        if !self.title.is_empty() {
+            heading.push(' ');
+            heading.push_str(&self.title);
+        }
+        heading
+    }
+}
+
+/// Templates that ship with reviewr. They are written to `templates/<name>.md` on first use,
+/// after which the file on disk is used and can be edited freely.
+const BUILTIN_TEMPLATES: &[(&str, &str)] = &[
+    (
+        "one-on-one",
+        "## {{date}} 1:1\n\n### Check-in\n\n### Progress since last time\n\n### Blockers\n\n### Action items\n\n",
+    ),
+    (
+        "quarterly",
+        "## {{date}} Quarterly review\n\n### Highlights\n\n### Goal progress\n\n### Growth areas\n\n### Focus for next quarter\n\n",
+    ),
+    (
+        "perf-review",
+        "## {{date}} Performance review\n\n{{name}}, {{title}} (manager: {{manager}})\n\n### Impact\n\n### Strengths\n\n### Areas for improvement\n\n### Evidence\n\n### Summary\n\n",
+    ),
+];
+
+/// How long fetching the title of one clipboard evidence URL may take
+pub const EVIDENCE_TITLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct NotesService;
+
+impl NotesService {
+    /// Open the employee's notes in `$EDITOR` after appending the `evidence` lines, e.g. from
+    /// [`Self::evidence_lines`]
+    pub fn open_notes(
+        data_path: &DataPath,
+        employee_name: &str,
+        evidence: &[String],
+        template: Option<&str>,
+    ) -> io::Result<()> {
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        let section = match template {
+            Some(template) => {
+                let employee = EmployeeService::get_employee(data_path, employee_name)?;
+                let content = Self::load_template(data_path, template)?;
+                Some(Self::render_template(&content, &employee, &today))
+            }
+            None => None,
+        };
+
+        let recipient = EmployeeService::get_employee(data_path, employee_name)
+            .ok()
+            .and_then(|employee| employee.notes_recipient);
+        let Some(recipient) = recipient else {
+            let note_path = data_path.notes_dir.join(format!("{employee_name}.md"));
+            return Self::edit_notes_file(
+                data_path,
+                &note_path,
+                employee_name,
+                evidence,
+                section.as_deref(),
+            );
+        };
+
+        // Encrypted notes are edited as a private decrypted copy and encrypted again afterwards
+        let identity = UnifiedConfigService::load_age_identity(data_path);
+        let copy = NoteEncryptionService::checkout(
+            data_path,
+            employee_name,
+            identity.as_deref(),
+            &AgeCli,
+        )?;
+        Self::edit_notes_file(
+            data_path,
+            &copy.path,
+            employee_name,
+            evidence,
+            section.as_deref(),
+        )?;
+        NoteEncryptionService::checkin(data_path, employee_name, &recipient, copy, &AgeCli)
+    }
+
+    /// Create or extend the notes file at `note_path`, then open it in `$EDITOR`
+    fn edit_notes_file(
+        data_path: &DataPath,
+        note_path: &Path,
+        employee_name: &str,
+        evidence: &[String],
+        section: Option<&str>,
+    ) -> io::Result<()> {
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        if !note_path.exists() {
+            info!("Creating new notes file for employee: {employee_name}");
+            let mut file = fs::File::create(note_path)?;
+            match section {
+                Some(section) => write!(file, "# Notes for {employee_name}\n\n{section}")?,
+                None => writeln!(
+                    file,
+                    "# Notes for {employee_name}\n\n## {}\n\n",
+                    Self::dated_heading(data_path, &today)?
+                )?,
+            }
+        } else if let Some(section) = section {
+            info!("Appending template to notes");
+            let mut file = fs::OpenOptions::new().append(true).open(note_path)?;
+            write!(file, "\n{section}")?;
+        }
+
+        if !evidence.is_empty() {
+            let mut file = fs::OpenOptions::new().append(true).open(note_path)?;
+            for line in evidence {
+                writeln!(file, "{line}")?;
+            }
+        }
+
+        let editor = env::var("EDITOR").unwrap_or_else(|_| "vim".to_string());
+        info!(
+            "Opening notes file {} with editor: {}",
+            note_path.display(),
+            editor
+        );
+        Command::new(editor).arg(note_path).status()?;
+
+        Ok(())
+    }
+
+    /// `date`, followed by `[<cycle>]` while a review cycle is active
+    fn dated_heading(data_path: &DataPath, date: &str) -> io::Result<String> {
+        Ok(match CycleService::active(data_path)? {
+            Some(cycle) => format!("{date} [{}]", cycle.name),
+            None => date.to_string(),
+        })
+    }
+
+    /// Start a `## <date>` section, tagged with the active review cycle, at the end of existing
+    /// notes unless they already have one.
+    /// A missing notes file is left to [`Self::open_notes`], which creates it with the section.
+    pub fn ensure_dated_section(
+        data_path: &DataPath,
+        employee_name: &str,
+        date: &str,
+    ) -> io::Result<()> {
+        let note_path = data_path.notes_dir.join(format!("{employee_name}.md"));
+        let content = match fs::read_to_string(&note_path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        let heading = format!("## {}", Self::dated_heading(data_path, date)?);
+        if content.lines().any(|line| line.trim() == heading) {
+            return Ok(());
+        }
+        let separator = if content.ends_with('\n') { "" } else { "\n" };
+        let mut file = fs::OpenOptions::new().append(true).open(&note_path)?;
+        write!(file, "{separator}\n{heading}\n\n")
+    }
+
+    /// Add a `## <date> <time> [<cycle>]` entry with `text` to the end of an employee's notes
+    /// without opening an editor. Encrypted notes are decrypted, extended and encrypted again.
+    pub fn append_entry(
+        data_path: &DataPath,
+        employee_name: &str,
+        text: &str,
+        now: NaiveDateTime,
+    ) -> io::Result<NoteEntry> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Note text is empty",
+            ));
+        }
+        if text.lines().any(|line| line.starts_with("## ")) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Note text must not contain '## ' headings; they would start a new entry",
+            ));
+        }
+        let entry = NoteEntry {
+            date: now.date(),
+            time: now
+                .time()
+                .with_second(0)
+                .and_then(|time| time.with_nanosecond(0)),
+            title: CycleService::active(data_path)?
+                .map(|cycle| format!("[{}]", cycle.name))
+                .unwrap_or_default(),
+            body: text.to_string(),
+        };
+
+        let existing = Self::read_notes(data_path, employee_name)?;
+        let mut addition = match &existing {
+            None => format!("# Notes for {employee_name}\n"),
+            Some(content) if content.ends_with('\n') => String::new(),
+            Some(_) => "\n".to_string(),
+        };
+        addition.push_str(&format!("\n## {}\n\n{}\n", entry.heading(), entry.body));
+        Self::write_addition(data_path, employee_name, existing, &addition)?;
+        info!("Appended note entry for {employee_name}");
+        Ok(entry)
+    }
+
+    /// Add an evidence `line` about `url` to an employee's notes: under the last entry when it is
+    /// from `now`'s day, so evidence collected in one sitting stays together, and as a new entry
+    /// otherwise. Returns false, writing nothing, when the notes already mention `url`.
+    pub fn append_evidence(
+        data_path: &DataPath,
+        employee_name: &str,
+        line: &str,
+        url: &str,
+        now: NaiveDateTime,
+    ) -> io::Result<bool> {
+        let existing = Self::read_notes(data_path, employee_name)?;
+        if !url.is_empty() && existing.as_deref().is_some_and(|notes| notes.contains(url)) {
+            return Ok(false);
+        }
+        let last_entry = existing
+            .as_deref()
+            .and_then(|notes| Self::parse_entries(notes).pop());
+        match (existing, last_entry) {
+            (Some(notes), Some(entry)) if entry.date == now.date() => {
+                let separator = if notes.ends_with('\n') { "" } else { "\n" };
+                let addition = format!("{separator}{line}\n");
+                Self::write_addition(data_path, employee_name, Some(notes), &addition)?;
+                info!("Appended evidence to today's notes entry for {employee_name}");
+            }
+            _ => {
+                Self::append_entry(data_path, employee_name, line, now)?;
+            }
+        }
+        Ok(true)
+    }
+
+    /// `- Evidence: [title](url) — project (status, updated <date>)` for an activity item, in the
+    /// style of the review packet's item lists
+    pub fn item_evidence_line(item: &ActivityItem, timestamps: &TimestampFormat) -> String {
+        let timestamps = TimestampFormat {
+            relative: false,
+            ..*timestamps
+        };
+        let title = if item.url.is_empty() {
+            item.title.clone()
+        } else {
+            format!("[{}]({})", escape_link_text(&item.title), item.url)
+        };
+        format!(
+            "- Evidence: {title} — {} ({}, updated {})",
+            item.project,
+            item.status,
+            timestamps.format(&item.updated)
+        )
+    }
+
+    /// Write `addition` after the `existing` notes, encrypting the whole file again when the
+    /// employee's notes are encrypted
+    fn write_addition(
+        data_path: &DataPath,
+        employee_name: &str,
+        existing: Option<String>,
+        addition: &str,
+    ) -> io::Result<()> {
+        let recipient = EmployeeService::get_employee(data_path, employee_name)
+            .ok()
+            .and_then(|employee| employee.notes_recipient);
+        match recipient {
+            Some(recipient) => {
+                let content = existing.unwrap_or_default() + addition;
+                NoteEncryptionService::write(
+                    data_path,
+                    employee_name,
+                    &recipient,
+                    &content,
+                    &AgeCli,
+                )
+            }
+            None => {
+                let note_path = data_path.notes_dir.join(format!("{employee_name}.md"));
+                let mut file = fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(note_path)?;
+                file.write_all(addition.as_bytes())
+            }
+        }
+    }
+
+    /// The dated entries of an employee's notes in file order; empty when there are no notes
+    pub fn list_entries(data_path: &DataPath, employee_name: &str) -> io::Result<Vec<NoteEntry>> {
+        Ok(Self::read_notes(data_path, employee_name)?
+            .map(|content| Self::parse_entries(&content))
+            .unwrap_or_default())
+    }
+
+    /// Split notes into their dated `## ` sections. Sections whose heading does not start with a
+    /// `YYYY-MM-DD` date, and text before the first section, are not entries.
+    pub fn parse_entries(content: &str) -> Vec<NoteEntry> {
+        let mut entries = Vec::new();
+        let mut current: Option<(NoteEntry, Vec<&str>)> = None;
+        let finish = |current: Option<(NoteEntry, Vec<&str>)>, entries: &mut Vec<NoteEntry>| {
+            if let Some((mut entry, lines)) = current {
+                entry.body = lines.join("\n").trim().to_string();
+                entries.push(entry);
+            }
+        };
+
+        for line in content.lines() {
+            let Some(heading) = line.strip_prefix("## ") else {
+                if let Some((_, lines)) = current.as_mut() {
+                    lines.push(line);
+                }
+                continue;
+            };
+            finish(current.take(), &mut entries);
+            current = Self::parse_entry_heading(heading).map(|entry| (entry, Vec::new()));
+        }
+        finish(current, &mut entries);
+        entries
+    }
+
+    fn parse_entry_heading(heading: &str) -> Option<NoteEntry> {
+        let mut rest = heading.trim();
+        let (date, after) = rest.split_once(' ').unwrap_or((rest, ""));
+        let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+        rest = after.trim_start();
+        let (time, after) = rest.split_once(' ').unwrap_or((rest, ""));
+        // Exactly `HH:MM`, so a `1:1` title is not taken for a time
+        let time = (time.len() == 5)
+            .then(|| NaiveTime::parse_from_str(time, "%H:%M").ok())
+            .flatten();
+        if time.is_some() {
+            rest = after.trim_start();
+        }
+        Some(NoteEntry {
+            date,
+            time,
+            title: rest.to_string(),
+            body: String::new(),
+        })
+    }
+
+    /// Names of the available templates: the built-in ones plus any `templates/*.md`
+    pub fn list_templates(data_path: &DataPath) -> io::Result<Vec<String>> {
+        let mut names: Vec<String> = BUILTIN_TEMPLATES
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect();
+        let templates_dir = data_path.templates_dir();
+        if templates_dir.exists() {
+            for entry in fs::read_dir(&templates_dir)? {
+                let path = entry?.path();
+                if path.extension().is_some_and(|ext| ext == "md")
+                    && let Some(name) = path.file_stem().and_then(|stem| stem.to_str())
+                {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names.sort();
+        names.dedup();
+        Ok(names)
+    }
+
+    /// Read `templates/<name>.md`, writing the built-in version first if there is one
+    pub fn load_template(data_path: &DataPath, name: &str) -> io::Result<String> {
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Invalid template name '{name}'"),
+            ));
+        }
+
+        let path = data_path.templates_dir().join(format!("{name}.md"));
+        if path.exists() {
+            return fs::read_to_string(&path);
+        }
+
+        let Some((_, content)) = BUILTIN_TEMPLATES
+            .iter()
+            .find(|(builtin, _)| *builtin == name)
+        else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "Unknown template '{name}'. Available: {}",
+                    Self::list_templates(data_path)?.join(", ")
+                ),
+            ));
+        };
+        fs::create_dir_all(data_path.templates_dir())?;
+        fs::write(&path, content)?;
+        info!("Wrote built-in template to {}", path.display());
+        Ok(content.to_string())
+    }
+
+    /// Fill in `{{name}}`, `{{date}}`, `{{title}}` and `{{manager}}`
+    pub fn render_template(template: &str, employee: &Employee, date: &str) -> String {
+        template
+            .replace("{{name}}", &employee.name)
+            .replace("{{date}}", date)
+            .replace("{{title}}", &employee.title)
+            .replace(
+                "{{manager}}",
+                employee.profile.manager.as_deref().unwrap_or("–"),
+            )
+    }
+
+    /// Compute per-section word counts, evidence links and tags for an employee's notes
+    pub fn notes_stats(data_path: &DataPath, employee_name: &str) -> io::Result<NotesStats> {
+        let content = Self::read_notes(data_path, employee_name)?.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No notes found for '{employee_name}'"),
+            )
+        })?;
+        Ok(Self::compute_stats(&content))
+    }
+
+    /// An employee's notes, decrypting `<name>.md.age` with the configured age identity
+    pub fn read_notes(data_path: &DataPath, employee_name: &str) -> io::Result<Option<String>> {
+        let identity = UnifiedConfigService::load_age_identity(data_path);
+        if let Some(content) =
+            NoteEncryptionService::read(data_path, employee_name, identity.as_deref(), &AgeCli)?
+        {
+            return Ok(Some(content));
+        }
+        let note_path = data_path.notes_dir.join(format!("{employee_name}.md"));
+        match fs::read_to_string(&note_path) {
+            Ok(content) => Ok(Some(content)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The plain-text notes of an employee, without decrypting `<name>.md.age`; for quick
+    /// listings that must not start the age CLI for every employee
+    fn read_plain_notes(data_path: &DataPath, employee_name: &str) -> Option<String> {
+        fs::read_to_string(data_path.notes_dir.join(format!("{employee_name}.md"))).ok()
+    }
+
+    /// Date of the newest dated entry of an employee's plain-text notes
+    pub fn last_entry_date(data_path: &DataPath, employee_name: &str) -> Option<NaiveDate> {
+        let content = Self::read_plain_notes(data_path, employee_name)?;
+        Self::parse_entries(&content)
+            .into_iter()
+            .map(|entry| entry.date)
+            .max()
+    }
+
+    /// The first `max_lines` lines of an employee's plain-text notes, without leading blank
+    /// lines; None when there are none
+    pub fn preview(
+        data_path: &DataPath,
+        employee_name: &str,
+        max_lines: usize,
+    ) -> Option<Vec<String>> {
+        let content = Self::read_plain_notes(data_path, employee_name)?;
+        Some(
+            content
+                .lines()
+                .skip_while(|line| line.trim().is_empty())
+                .take(max_lines)
+                .map(str::to_string)
+                .collect(),
+        )
+    }
+
+    /// Parse notes content into statistics. Sections start at `## ` headings; text before the
+    /// first section (e.g. the `# Notes for ...` title) is not counted.
+    pub fn compute_stats(content: &str) -> NotesStats {
+        let mut stats = NotesStats::default();
+        let mut current: Option<NoteSectionStats> = None;
+
+        for line in content.lines() {
+            if let Some(heading) = line.strip_prefix("## ") {
+                if let Some(section) = current.take() {
+                    stats.sections.push(section);
+                }
+                current = Some(NoteSectionStats {
+                    heading: heading.trim().to_string(),
+                    word_count: 0,
+                    evidence_links: 0,
+                    tags: Vec::new(),
+                });
+                continue;
+            }
+
+            let Some(section) = current.as_mut() else {
+                continue;
+            };
+            if line.starts_with('#') {
+                continue;
+            }
+
+            for word in line.split_whitespace() {
+                if word.starts_with("http://") || word.starts_with("https://") {
+                    section.evidence_links += 1;
+                } else if let Some(tag) = Self::parse_tag(word) {
+                    if !section.tags.contains(&tag) {
+                        section.tags.push(tag);
+                    }
+                } else if word.chars().any(|c| c.is_alphanumeric()) {
+                    section.word_count += 1;
+                }
+            }
+        }
+        if let Some(section) = current.take() {
+            stats.sections.push(section);
+        }
+
+        for section in &stats.sections {
+            stats.total_words += section.word_count;
+            stats.total_evidence_links += section.evidence_links;
+            for tag in &section.tags {
+                *stats.tag_counts.entry(tag.clone()).or_insert(0) += 1;
+            }
+        }
+
+        stats
+    }
+
+    /// Find lines in other employees' notes that mention `employee_name` as `@Employee Name`
+    pub fn find_mentions(
+        data_path: &DataPath,
+        employee_name: &str,
+    ) -> io::Result<Vec<NoteMention>> {
+        if !data_path.notes_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut sources: Vec<(String, PathBuf)> = fs::read_dir(&data_path.notes_dir)?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+            .filter_map(|path| {
+                let source = path.file_stem()?.to_str()?.to_string();
+                Some((source, path))
+            })
+            .filter(|(source, _)| source != employee_name)
+            .collect();
+        sources.sort();
+
+        let mut mentions = Vec::new();
+        for (source, path) in sources {
+            let content = fs::read_to_string(&path)?;
+            for (section, line) in Self::mention_lines(&content, employee_name) {
+                mentions.push(NoteMention {
+                    source: source.clone(),
+                    section,
+                    line,
+                });
+            }
+        }
+        Ok(mentions)
+    }
+
+    /// Lines of `content` mentioning `@name` (case-insensitive, ending at a word boundary),
+    /// paired with the `## ` section they appear in
+    pub fn mention_lines(content: &str, name: &str) -> Vec<(Option<String>, String)> {
+        let mention = format!("@{}", name.to_lowercase());
+        let mut section = None;
+        let mut lines = Vec::new();
+
+        for line in content.lines() {
+            if let Some(heading) = line.strip_prefix("## ") {
+                section = Some(heading.trim().to_string());
+                continue;
+            }
+            let lower = line.to_lowercase();
+            let mentioned = lower.match_indices(&mention).any(|(idx, _)| {
+                !lower[idx + mention.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_alphanumeric())
+            });
+            if mentioned {
+                lines.push((section.clone(), line.trim().to_string()));
+            }
+        }
+        lines
+    }
+
+    /// A tag is a `#word` token, e.g. `#mentoring` or `#on-call`
+    fn parse_tag(word: &str) -> Option<String> {
+        let tag = word
+            .strip_prefix('#')?
+            .trim_end_matches(|c: char| !c.is_alphanumeric());
+        if !tag.is_empty()
+            && tag
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            Some(tag.to_lowercase())
+        } else {
+            None
+        }
+    }
+
+    /// Clipboard text, when there is a clipboard and it holds text
+    pub fn read_clipboard() -> Option<String> {
+        let mut clipboard = arboard::Clipboard::new().ok()?;
+        clipboard.get_text().ok()
+    }
+
+    /// The distinct http(s) URLs in `text` on `allowed_domains`, in order. URLs may be
+    /// separated by whitespace or wrapped in `<>`, quotes or brackets.
+    pub fn evidence_urls(text: &str, allowed_domains: &[String]) -> Vec<url::Url> {
+        let mut urls: Vec<url::Url> = Vec::new();
+        for word in text.split_whitespace() {
+            let word = word
+                .trim_start_matches(['<', '(', '[', '"', '\''])
+                .trim_end_matches(['>', ')', ']', '"', '\'', ',', '.', ';']);
+            let Ok(url) = url::Url::parse(word) else {
+                continue;
+            };
+            if !matches!(url.scheme(), "http" | "https") || urls.contains(&url) {
+                continue;
+            }
+            let Some(host) = url.host_str() else {
+                continue;
+            };
+            if !is_domain_allowed(host, allowed_domains) {
+                warn!("Clipboard URL domain '{host}' not in allowed domains: {allowed_domains:?}");
+                continue;
+            }
+            urls.push(url);
+        }
+        urls
+    }
+
+    /// `- Evidence: [Title](url)` bullets for the URLs in `text`, each titled with its page's
+    /// `<title>`. Pages are fetched concurrently, at most [`EVIDENCE_TITLE_TIMEOUT`] each, and
+    /// never redirected off `allowed_domains`, through the proxy and CA certificates of
+    /// `http`; a URL whose title cannot be read is listed bare.
+    pub async fn evidence_lines(
+        text: &str,
+        allowed_domains: &[String],
+        http: &HttpClientSettings,
+    ) -> Vec<String> {
+        let urls = Self::evidence_urls(text, allowed_domains);
+        if urls.is_empty() {
+            info!("Clipboard holds no evidence URLs, skipping evidence insertion");
+            return Vec::new();
+        }
+        let allowed = allowed_domains.to_vec();
+        let client = http
+            .client_builder(false)
+            .and_then(|builder| {
+                builder
+                    .timeout(EVIDENCE_TITLE_TIMEOUT)
+                    .redirect(reqwest::redirect::Policy::custom(move |attempt| {
+                        let allowed_target = attempt
+                            .url()
+                            .host_str()
+                            .is_some_and(|host| is_domain_allowed(host, &allowed));
+                        if allowed_target && attempt.previous().len() < 5 {
+                            attempt.follow()
+                        } else {
+                            attempt.stop()
+                        }
+                    }))
+                    .build()
+                    .map_err(io::Error::other)
+            })
+            .map_err(|e| warn!("Cannot fetch evidence titles: {e}"))
+            .ok();
+        let titles = futures::future::join_all(urls.iter().map(|url| async {
+            match &client {
+                Some(client) => Self::page_title(client, url).await,
+                None => None,
+            }
+        }))
+        .await;
+        urls.iter()
+            .zip(titles)
+            .map(|(url, title)| {
+                info!("Adding evidence URL from clipboard: {url}");
+                match title {
+                    Some(title) => format!("- Evidence: [{}]({url})", escape_link_text(&title)),
+                    None => format!("- Evidence: {url}"),
+                }
+            })
+            .collect()
+    }
+
+    /// The `<title>` of the HTML page at `url`, whitespace collapsed and entities decoded
+    async fn page_title(client: &reqwest::Client, url: &url::Url) -> Option<String> {
+        let response = client
+            .get(url.clone())
+            .send()
+            .await
+            .inspect_err(|e| warn!("Failed to fetch title of {url}: {e}"))
+            .ok()?;
+        if !response.status().is_success() {
+            warn!("Failed to fetch title of {url}: HTTP {}", response.status());
+            return None;
+        }
+        let body = response.text().await.ok()?;
+        let title = Regex::new(r"(?is)<title[^>]*>(.*?)</title>")
+            .ok()?
+            .captures(&body)?
+            .get(1)?
+            .as_str()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let title = decode_entities(&title);
+        (!title.is_empty()).then_some(title)
+    }
+}
+
+/// Page titles go into `[...]`, so brackets must not end the link text early
+fn escape_link_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+}
+
+/// The HTML entities page titles commonly contain
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evidence_urls() {
+        let text = "See <https://gerrit.company.example.com/c/1>, and\n\
+                    (https://jira.company.example.com/browse/PROJ-2). Also \
+                    https://gerrit.company.example.com/c/1 ftp://company.example.com/x \
+                    https://other.com/page not-a-url";
+        let urls: Vec<String> =
+            NotesService::evidence_urls(text, &["company.example.com".to_string()])
+                .into_iter()
+                .map(String::from)
+                .collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://gerrit.company.example.com/c/1",
+                "https://jira.company.example.com/browse/PROJ-2",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_evidence_lines_with_page_titles() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/design"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "<html><head><TITLE>\n  Design [v2] &amp; rollout\n</TITLE></head></html>",
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/gone"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        // Redirects leaving the allowed domains are not followed
+        Mock::given(method("GET"))
+            .and(path("/moved"))
+            .respond_with(
+                ResponseTemplate::new(302).insert_header("Location", "http://elsewhere.test/"),
+            )
+            .mount(&server)
+            .await;
+
+        let base = server.uri();
+        let text = format!("{base}/design {base}/gone {base}/moved https://other.com/");
+        let lines = NotesService::evidence_lines(
+            &text,
+            &["127.0.0.1".to_string()],
+            &HttpClientSettings::default(),
+        )
+        .await;
+        assert_eq!(
+            lines,
+            vec![
+                format!("- Evidence: [Design \\[v2\\] & rollout]({base}/design)"),
+                format!("- Evidence: {base}/gone"),
+                format!("- Evidence: {base}/moved"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compute_stats_per_section() {
+        let content = "# Notes for Jane\n\n\
+## 2024-01-15\n\
+Led the design review #design #mentoring\n\
+- Evidence: https://gerrit.example.com/c/project/+/1\n\n\
+## 2024-01-22\n\
+Short sync, nothing notable. #design\n";
+
+        let stats = NotesService::compute_stats(content);
+        assert_eq!(stats.sections.len(), 2);
+
+        let first = &stats.sections[0];
+        assert_eq!(first.heading, "2024-01-15");
+        assert_eq!(first.evidence_links, 1);
+        assert_eq!(first.tags, vec!["design", "mentoring"]);
+        // "Led the design review" + "- Evidence:" label
+        assert_eq!(first.word_count, 5);
+
+        let second = &stats.sections[1];
+        assert_eq!(second.word_count, 4);
+        assert_eq!(second.evidence_links, 0);
+
+        assert_eq!(stats.total_words, 9);
+        assert_eq!(stats.total_evidence_links, 1);
+        assert_eq!(stats.tag_counts.get("design"), Some(&2));
+        assert_eq!(stats.tag_counts.get("mentoring"), Some(&1));
+    }
+
+    #[test]
+    fn test_mention_lines() {
+        let content = "# Notes for John\n\n\
+## 2024-01-15\n\
+Paired with @Jane Doe on the outage\n\
+Asked @jane doe, @Janet Smith and others\n\
+## 2024-01-22\n\
+@Jane Doex is someone else\n\
+Thanks to @Jane Doe.\n";
+
+        let lines = NotesService::mention_lines(content, "Jane Doe");
+        assert_eq!(
+            lines,
+            vec![
+                (
+                    Some("2024-01-15".to_string()),
+                    "Paired with @Jane Doe on the outage".to_string()
+                ),
+                (
+                    Some("2024-01-15".to_string()),
+                    "Asked @jane doe, @Janet Smith and others".to_string()
+                ),
+                (
+                    Some("2024-01-22".to_string()),
+                    "Thanks to @Jane Doe.".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_mentions_skips_own_notes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        fs::write(
+            data_path.notes_dir.join("Jane Doe.md"),
+            "## 2024-01-01\nNote to self about @Jane Doe\n",
+        )
+        .unwrap();
+        fs::write(
+            data_path.notes_dir.join("John Smith.md"),
+            "## 2024-01-15\nGreat handover from @Jane Doe\n",
+        )
+        .unwrap();
+
+        let mentions = NotesService::find_mentions(&data_path, "Jane Doe").unwrap();
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].source, "John Smith");
+        assert_eq!(mentions[0].section.as_deref(), Some("2024-01-15"));
+    }
+
+    #[test]
+    fn test_templates() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+
+        let template = NotesService::load_template(&data_path, "perf-review").unwrap();
+        assert!(data_path.templates_dir().join("perf-review.md").exists());
+
+        let employee = Employee {
+            name: "Jane Doe".to_string(),
+            title: "Engineer".to_string(),
+            committer_email: None,
+            profile: crate::models::EmployeeProfile {
+                manager: Some("Sam Lee".to_string()),
+                ..Default::default()
+            },
+            usernames: Default::default(),
+            exclude_platforms: Vec::new(),
+            notes_only: false,
+            notes_recipient: None,
+            out_of_office: Vec::new(),
+        };
+        let rendered = NotesService::render_template(&template, &employee, "2024-03-01");
+        assert!(rendered.starts_with("## 2024-03-01 Performance review"));
+        assert!(rendered.contains("Jane Doe, Engineer (manager: Sam Lee)"));
+
+        // Edited and custom templates on disk take precedence
+        fs::write(
+            data_path.templates_dir().join("perf-review.md"),
+            "## {{date}} Custom\n",
+        )
+        .unwrap();
+        fs::write(
+            data_path.templates_dir().join("skip-level.md"),
+            "## Skip-level\n",
+        )
+        .unwrap();
+        assert_eq!(
+            NotesService::load_template(&data_path, "perf-review").unwrap(),
+            "## {{date}} Custom\n"
+        );
+        assert_eq!(
+            NotesService::list_templates(&data_path).unwrap(),
+            vec!["one-on-one", "perf-review", "quarterly", "skip-level"]
+        );
+
+        let err = NotesService::load_template(&data_path, "unknown").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(NotesService::load_template(&data_path, "../config").is_err());
+    }
+
+    #[test]
+    fn test_parse_entries() {
+        let content = "# Notes for Jane\n\nintro\n\n## Goals\nship it\n\n## 2024-01-15\nFirst\n\n\
+                       ## 2024-02-01 1:1\n### Check-in\nfine\n\n## 2024-02-03 09:15\nQuick note\n";
+        let entries = NotesService::parse_entries(content);
+        let headings: Vec<String> = entries.iter().map(NoteEntry::heading).collect();
+        assert_eq!(
+            headings,
+            vec!["2024-01-15", "2024-02-01 1:1", "2024-02-03 09:15"]
+        );
+        assert_eq!(entries[0].body, "First");
+        assert_eq!(entries[1].title, "1:1");
+        assert_eq!(entries[1].body, "### Check-in\nfine");
+        assert_eq!(
+            entries[2].time,
+            Some(NaiveTime::from_hms_opt(9, 15, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_last_entry_date_and_preview() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        assert_eq!(NotesService::last_entry_date(&data_path, "Jane Doe"), None);
+        assert_eq!(NotesService::preview(&data_path, "Jane Doe", 3), None);
+
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        fs::write(
+            data_path.notes_dir.join("Jane Doe.md"),
+            "\n# Notes for Jane Doe\n\n## 2024-03-11\nRelease\n\n## 2024-02-01 1:1\nPlans\n",
+        )
+        .unwrap();
+        assert_eq!(
+            NotesService::last_entry_date(&data_path, "Jane Doe"),
+            NaiveDate::from_ymd_opt(2024, 3, 11)
+        );
+        assert_eq!(
+            NotesService::preview(&data_path, "Jane Doe", 3).unwrap(),
+            vec!["# Notes for Jane Doe", "", "## 2024-03-11"]
+        );
+    }
+
+    #[test]
+    fn test_append_entry() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        let now = NaiveDate::from_ymd_opt(2024, 3, 4)
+            .unwrap()
+            .and_hms_opt(16, 45, 12)
+            .unwrap();
+
+        NotesService::append_entry(&data_path, "Jane Doe", "Led the incident review", now).unwrap();
+        NotesService::append_entry(&data_path, "Jane Doe", "  Follow up on #oncall  ", now)
+            .unwrap();
+        let content = fs::read_to_string(data_path.notes_dir.join("Jane Doe.md")).unwrap();
+        assert_eq!(
+            content,
+            "# Notes for Jane Doe\n\n## 2024-03-04 16:45\n\nLed the incident review\n\n\
+             ## 2024-03-04 16:45\n\nFollow up on #oncall\n"
+        );
+        let entries = NotesService::list_entries(&data_path, "Jane Doe").unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].body, "Follow up on #oncall");
+
+        assert!(NotesService::append_entry(&data_path, "Jane Doe", "  ", now).is_err());
+        assert!(
+            NotesService::append_entry(&data_path, "Jane Doe", "a\n## 2024-01-01\nb", now).is_err()
+        );
+        assert!(
+            NotesService::list_entries(&data_path, "Nobody")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_append_item_evidence() {
+        use crate::platform::ActivityCategory;
+        use crate::test_support::ItemBuilder;
+        use crate::timestamps::DisplayZone;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        let item = ItemBuilder::new("12345")
+            .with_title("Fix [auth] login")
+            .with_created("2024-01-15T10:30:00Z")
+            .with_updated("2024-01-15T16:45:00Z")
+            .with_url("https://gerrit.example.com/c/auth/+/12345")
+            .with_category(ActivityCategory::ChangesMerged)
+            .with_project("auth")
+            .build();
+        let line =
+            NotesService::item_evidence_line(&item, &TimestampFormat::new(DisplayZone::Utc, true));
+        assert_eq!(
+            line,
+            "- Evidence: [Fix \\[auth\\] login](https://gerrit.example.com/c/auth/+/12345) — auth (MERGED, updated 2024-01-15 16:45 UTC)"
+        );
+
+        let day = NaiveDate::from_ymd_opt(2024, 3, 4).unwrap();
+        let now = day.and_hms_opt(9, 5, 0).unwrap();
+        assert!(
+            NotesService::append_evidence(&data_path, "Jane Doe", &line, &item.url, now).unwrap()
+        );
+        // The same item is not added twice
+        assert!(
+            !NotesService::append_evidence(&data_path, "Jane Doe", &line, &item.url, now).unwrap()
+        );
+        // Later the same day, evidence joins the entry
+        let later = day.and_hms_opt(11, 0, 0).unwrap();
+        NotesService::append_evidence(
+            &data_path,
+            "Jane Doe",
+            "- Evidence: Design review",
+            "",
+            later,
+        )
+        .unwrap();
+        let content = fs::read_to_string(data_path.notes_dir.join("Jane Doe.md")).unwrap();
+        assert_eq!(
+            content,
+            format!(
+                "# Notes for Jane Doe\n\n## 2024-03-04 09:05\n\n{line}\n- Evidence: Design review\n"
+            )
+        );
+
+        // On another day it starts a new entry
+        let next_day = NaiveDate::from_ymd_opt(2024, 3, 5)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        NotesService::append_evidence(&data_path, "Jane Doe", "- Evidence: Demo", "", next_day)
+            .unwrap();
+        let entries = NotesService::list_entries(&data_path, "Jane Doe").unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].body, "- Evidence: Demo");
+    }
+
+    #[test]
+    fn test_entries_tagged_with_active_cycle() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        let day = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        CycleService::start(&data_path, "2025-H1", day).unwrap();
+
+        let entry = NotesService::append_entry(
+            &data_path,
+            "Jane Doe",
+            "Shipped search",
+            day.and_hms_opt(9, 30, 0).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(entry.heading(), "2025-01-06 09:30 [2025-H1]");
+        NotesService::ensure_dated_section(&data_path, "Jane Doe", "2025-01-07").unwrap();
+
+        let entries = NotesService::list_entries(&data_path, "Jane Doe").unwrap();
+        let cycles: Vec<_> = entries.iter().map(NoteEntry::cycle).collect();
+        assert_eq!(cycles, vec![Some("2025-H1"), Some("2025-H1")]);
+        assert_eq!(entries[1].heading(), "2025-01-07 [2025-H1]");
+        assert_eq!(entries[1].time, None);
+    }
+
+    #[test]
+    fn test_compute_stats_empty_notes() {
+        let stats = NotesService::compute_stats("# Notes for Jane\n\n");
+        assert!(stats.sections.is_empty());
+        assert_eq!(stats.total_words, 0);
+    }
+}
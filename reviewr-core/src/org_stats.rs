@@ -0,0 +1,218 @@
+use crate::platform::{ActivityCategory, DetailedActivities};
+use std::collections::{BTreeMap, HashSet};
+
+/// Per-employee numbers compared across the organisation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OrgMetric {
+    ChangesCreated,
+    ChangesMerged,
+    ReviewsGiven,
+    IssuesResolved,
+}
+
+impl OrgMetric {
+    pub const ALL: [OrgMetric; 4] = [
+        OrgMetric::ChangesCreated,
+        OrgMetric::ChangesMerged,
+        OrgMetric::ReviewsGiven,
+        OrgMetric::IssuesResolved,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            OrgMetric::ChangesCreated => "Changes created",
+            OrgMetric::ChangesMerged => "Changes merged",
+            OrgMetric::ReviewsGiven => "Reviews given",
+            OrgMetric::IssuesResolved => "Issues resolved",
+        }
+    }
+
+    /// Gerrit, GitLab and JIRA categories that count towards this metric
+    fn categories(&self) -> &'static [ActivityCategory] {
+        match self {
+            OrgMetric::ChangesCreated => &[
+                ActivityCategory::ChangesCreated,
+                ActivityCategory::MergeRequestsCreated,
+            ],
+            OrgMetric::ChangesMerged => &[
+                ActivityCategory::ChangesMerged,
+                ActivityCategory::MergeRequestsMerged,
+            ],
+            OrgMetric::ReviewsGiven => &[
+                ActivityCategory::ReviewsGiven,
+                ActivityCategory::MergeRequestsReviewed,
+            ],
+            OrgMetric::IssuesResolved => &[ActivityCategory::IssuesResolved],
+        }
+    }
+}
+
+/// Percentile bands of one metric across employees
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Distribution {
+    pub min: f64,
+    pub p25: f64,
+    pub median: f64,
+    pub p75: f64,
+    pub p90: f64,
+    pub max: f64,
+}
+
+impl Distribution {
+    /// Linear-interpolated percentiles; `None` for an empty sample
+    pub fn from_values(values: &[usize]) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        let mut sorted: Vec<f64> = values.iter().map(|v| *v as f64).collect();
+        sorted.sort_by(f64::total_cmp);
+        let percentile = |p: f64| {
+            let rank = p * (sorted.len() - 1) as f64;
+            let lower = rank.floor() as usize;
+            let upper = rank.ceil() as usize;
+            sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+        };
+        Some(Self {
+            min: sorted[0],
+            p25: percentile(0.25),
+            median: percentile(0.5),
+            p75: percentile(0.75),
+            p90: percentile(0.9),
+            max: sorted[sorted.len() - 1],
+        })
+    }
+
+    /// Band an individual value falls into, e.g. "p50-p75"
+    pub fn band(&self, value: usize) -> &'static str {
+        let value = value as f64;
+        if value < self.p25 {
+            "<p25"
+        } else if value < self.median {
+            "p25-p50"
+        } else if value < self.p75 {
+            "p50-p75"
+        } else if value < self.p90 {
+            "p75-p90"
+        } else {
+            ">=p90"
+        }
+    }
+}
+
+/// Organisation-wide view over the cached activity of every employee
+#[derive(Debug, Clone, Default)]
+pub struct OrgStats {
+    /// Metric counts per employee
+    pub employees: BTreeMap<String, BTreeMap<OrgMetric, usize>>,
+    pub distributions: BTreeMap<OrgMetric, Distribution>,
+    /// Share of employees (0.0-1.0) with at least one review given
+    pub review_participation: f64,
+}
+
+impl OrgStats {
+    /// Aggregate `(employee, activities)` pairs; one employee may appear once per platform
+    pub fn from_activities<'a>(
+        activities: impl IntoIterator<Item = (&'a str, &'a DetailedActivities)>,
+    ) -> Self {
+        let mut employees: BTreeMap<String, BTreeMap<OrgMetric, usize>> = BTreeMap::new();
+        let mut seen = HashSet::new();
+        for (employee, activities) in activities {
+            let counts = employees
+                .entry(employee.to_string())
+                .or_insert_with(|| OrgMetric::ALL.iter().map(|metric| (*metric, 0)).collect());
+            for metric in OrgMetric::ALL {
+                for category in metric.categories() {
+                    let items = activities
+                        .items_by_category
+                        .get(category)
+                        .into_iter()
+                        .flatten();
+                    // Count a change once per metric even if cached for several periods/platform ids
+                    let new_items = items
+                        .filter(|item| {
+                            seen.insert((employee, metric, item.platform.clone(), item.id.clone()))
+                        })
+                        .count();
+                    *counts.entry(metric).or_default() += new_items;
+                }
+            }
+        }
+
+        let distributions = OrgMetric::ALL
+            .iter()
+            .filter_map(|metric| {
+                let values: Vec<usize> = employees.values().map(|counts| counts[metric]).collect();
+                Distribution::from_values(&values).map(|distribution| (*metric, distribution))
+            })
+            .collect();
+        let reviewers = employees
+            .values()
+            .filter(|counts| counts[&OrgMetric::ReviewsGiven] > 0)
+            .count();
+        let review_participation = if employees.is_empty() {
+            0.0
+        } else {
+            reviewers as f64 / employees.len() as f64
+        };
+
+        Self {
+            employees,
+            distributions,
+            review_participation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::ItemBuilder;
+
+    fn activities(merged: usize, reviews: usize) -> DetailedActivities {
+        let item = |id: usize, category: ActivityCategory| {
+            ItemBuilder::new(&id.to_string())
+                .with_category(category)
+                .build()
+        };
+        let mut activities = DetailedActivities::default();
+        activities.items_by_category.insert(
+            ActivityCategory::ChangesMerged,
+            (0..merged)
+                .map(|id| item(id, ActivityCategory::ChangesMerged))
+                .collect(),
+        );
+        activities.items_by_category.insert(
+            ActivityCategory::ReviewsGiven,
+            (0..reviews)
+                .map(|id| item(100 + id, ActivityCategory::ReviewsGiven))
+                .collect(),
+        );
+        activities
+    }
+
+    #[test]
+    fn test_percentiles() {
+        let distribution = Distribution::from_values(&[1, 2, 3, 4, 10]).unwrap();
+        assert_eq!(distribution.min, 1.0);
+        assert_eq!(distribution.median, 3.0);
+        assert_eq!(distribution.p25, 2.0);
+        assert_eq!(distribution.p75, 4.0);
+        assert_eq!(distribution.max, 10.0);
+        assert_eq!(distribution.band(10), ">=p90");
+        assert_eq!(distribution.band(1), "<p25");
+        assert!(Distribution::from_values(&[]).is_none());
+    }
+
+    #[test]
+    fn test_org_stats_from_activities() {
+        let alice = activities(4, 2);
+        let bob = activities(2, 0);
+        let carol = activities(6, 1);
+        let stats =
+            OrgStats::from_activities([("Alice", &alice), ("Bob", &bob), ("Carol", &carol)]);
+
+        assert_eq!(stats.employees["Alice"][&OrgMetric::ChangesMerged], 4);
+        assert_eq!(stats.distributions[&OrgMetric::ChangesMerged].median, 4.0);
+        assert!((stats.review_participation - 2.0 / 3.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,222 @@
+//! Category counts of the review period against the equally long period before it
+
+use crate::platform::{ActivityCategory, ActivityMetrics, DetailedActivities, ReviewPlatform};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Category counts of the period before the review period, by combined category
+pub type PeriodCounts = HashMap<ActivityCategory, usize>;
+
+/// What the platform counted in the period before the review period: the count over twice the
+/// period minus the count over the period. Categories counted as zero both times are left out,
+/// since that is how platforms report categories they cannot count.
+pub fn previous_period(current: &ActivityMetrics, doubled: &ActivityMetrics) -> PeriodCounts {
+    let mut counts = PeriodCounts::new();
+    for (category, total) in &doubled.items_by_category {
+        let recent = current
+            .items_by_category
+            .get(category)
+            .copied()
+            .unwrap_or(0);
+        if *total == 0 && recent == 0 {
+            continue;
+        }
+        *counts.entry(category.combined()).or_default() += total.saturating_sub(recent) as usize;
+    }
+    counts
+}
+
+/// [`previous_period`] of a platform; None when it cannot count, which only hides the change
+pub async fn previous_period_counts(
+    platform: &dyn ReviewPlatform,
+    user: &str,
+    days: u32,
+) -> Option<PeriodCounts> {
+    let counted = tokio::try_join!(
+        platform.get_activity_metrics(user, days),
+        platform.get_activity_metrics(user, days.saturating_mul(2)),
+    );
+    match counted {
+        Ok((current, doubled)) => {
+            Some(previous_period(&current, &doubled)).filter(|counts| !counts.is_empty())
+        }
+        Err(e) => {
+            log::debug!(
+                "No previous period counts from {}: {e}",
+                platform.get_platform_id()
+            );
+            None
+        }
+    }
+}
+
+/// Items of one combined category in the review period and in the period before it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CategoryComparison {
+    pub category: ActivityCategory,
+    pub current: usize,
+    /// None when a platform listing the category could not count the earlier period
+    pub previous: Option<usize>,
+}
+
+impl CategoryComparison {
+    pub fn delta(&self) -> Option<i64> {
+        self.previous
+            .map(|previous| self.current as i64 - previous as i64)
+    }
+
+    /// "↑4", "↓2", "=" or "–" when there is nothing to compare against
+    pub fn change(&self) -> String {
+        match self.delta() {
+            Some(delta) if delta > 0 => format!("↑{delta}"),
+            Some(delta) if delta < 0 => format!("↓{}", delta.unsigned_abs()),
+            Some(_) => "=".to_string(),
+            None => "–".to_string(),
+        }
+    }
+
+    /// "Changes Created: 12, ↑4 vs prior 30 days"
+    pub fn describe(&self, days: u32) -> String {
+        let label = self.category.display_name();
+        match self.previous {
+            Some(_) => format!(
+                "{label}: {}, {} vs prior {days} days",
+                self.current,
+                self.change()
+            ),
+            None => format!(
+                "{label}: {}, no count for the prior {days} days",
+                self.current
+            ),
+        }
+    }
+}
+
+/// Items per combined category across `platforms`, each with the counts its platform reported
+/// for the previous period, ordered by category name. The previous count of a category is
+/// known when every platform listing it could count it.
+pub fn compare_periods<'a>(
+    platforms: impl IntoIterator<Item = (&'a DetailedActivities, Option<&'a PeriodCounts>)>,
+) -> Vec<CategoryComparison> {
+    let platforms: Vec<_> = platforms.into_iter().collect();
+    let mut current: HashMap<ActivityCategory, usize> = HashMap::new();
+    for (activities, _) in &platforms {
+        for (category, items) in &activities.items_by_category {
+            *current.entry(category.combined()).or_default() += items.len();
+        }
+    }
+    let mut rows: Vec<CategoryComparison> = current
+        .into_iter()
+        .map(|(category, count)| {
+            let mut previous = Some(0);
+            for (activities, counts) in &platforms {
+                let listed = activities
+                    .items_by_category
+                    .keys()
+                    .any(|listed| listed.combined() == category);
+                match counts.and_then(|counts| counts.get(&category)) {
+                    Some(earlier) => previous = previous.map(|sum| sum + earlier),
+                    None if listed => previous = None,
+                    None => {}
+                }
+            }
+            CategoryComparison {
+                category,
+                current: count,
+                previous,
+            }
+        })
+        .collect();
+    rows.sort_by(|a, b| a.category.display_name().cmp(b.category.display_name()));
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::ItemBuilder;
+
+    fn activities(categories: &[(ActivityCategory, usize)]) -> DetailedActivities {
+        DetailedActivities {
+            items_by_category: categories
+                .iter()
+                .map(|(category, count)| {
+                    let item = ItemBuilder::new("1")
+                        .with_status("NEW")
+                        .with_category(category.clone())
+                        .build();
+                    (category.clone(), vec![item; *count])
+                })
+                .collect(),
+            failed_categories: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_previous_period_leaves_out_uncounted_categories() {
+        let current = ActivityMetrics {
+            items_by_category: HashMap::from([
+                (ActivityCategory::MergeRequestsCreated, 2),
+                (ActivityCategory::IssuesCommented, 0),
+            ]),
+            ..Default::default()
+        };
+        let doubled = ActivityMetrics {
+            items_by_category: HashMap::from([
+                (ActivityCategory::MergeRequestsCreated, 5),
+                (ActivityCategory::IssuesCommented, 0),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            previous_period(&current, &doubled),
+            HashMap::from([(ActivityCategory::ChangesCreated, 3)])
+        );
+    }
+
+    #[test]
+    fn test_compare_periods_across_platforms() {
+        let gerrit = activities(&[(ActivityCategory::ChangesCreated, 5)]);
+        let gitlab = activities(&[
+            (ActivityCategory::MergeRequestsCreated, 7),
+            (ActivityCategory::IssuesAssigned, 2),
+        ]);
+        let gerrit_before = PeriodCounts::from([(ActivityCategory::ChangesCreated, 3)]);
+        let gitlab_before = PeriodCounts::from([(ActivityCategory::ChangesCreated, 5)]);
+
+        let rows = compare_periods([
+            (&gerrit, Some(&gerrit_before)),
+            (&gitlab, Some(&gitlab_before)),
+        ]);
+        assert_eq!(
+            rows,
+            vec![
+                CategoryComparison {
+                    category: ActivityCategory::ChangesCreated,
+                    current: 12,
+                    previous: Some(8),
+                },
+                // GitLab could not count its issues before
+                CategoryComparison {
+                    category: ActivityCategory::IssuesAssigned,
+                    current: 2,
+                    previous: None,
+                },
+            ]
+        );
+        assert_eq!(
+            rows[0].describe(30),
+            "Changes Created: 12, ↑4 vs prior 30 days"
+        );
+        assert_eq!(
+            rows[1].describe(30),
+            "Issues Assigned: 2, no count for the prior 30 days"
+        );
+
+        let fewer = CategoryComparison {
+            previous: Some(14),
+            ..rows[0].clone()
+        };
+        assert_eq!(fewer.change(), "↓2");
+    }
+}
@@ -1,6 +1,34 @@
+use crate::models::DataPath;
+use crate::redact::{redact_body, redact_url, verbose_error_logs};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+/// `error.log` of the active data directory, once the CLI has set it
+static ERROR_LOG_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Write `log_error` entries to `error.log` in `data_path` (the `--data-path` directory)
+pub fn set_error_log_dir(data_path: &DataPath) {
+    let path = data_path.root.join("error.log");
+    *ERROR_LOG_PATH.write().unwrap_or_else(|e| e.into_inner()) = Some(path);
+}
+
+/// The error log in use: the one set with [`set_error_log_dir`], else `~/.reviewr/error.log`
+pub fn error_log_path() -> PathBuf {
+    if let Some(path) = ERROR_LOG_PATH
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+    {
+        return path;
+    }
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".reviewr")
+        .join("error.log")
+}
 
 /// Core trait that all review platforms must implement
 #[async_trait::async_trait]
@@ -25,10 +53,60 @@ pub trait ReviewPlatform: Send + Sync {
 
     /// Configuration and connection status
     fn is_configured(&self) -> bool;
+
+    /// Features this platform implements; defaults to none so callers hide unsupported actions
+    fn get_capabilities(&self) -> PlatformCapabilities {
+        PlatformCapabilities::default()
+    }
     async fn test_connection(&self) -> io::Result<ConnectionStatus>;
 
+    /// The item with everything `get_detailed_activities` left out, for items with
+    /// [`DETAILS_PENDING_KEY`]; platforms that fetch all fields up front return it as it is
+    async fn get_item_details(&self, item: &ActivityItem) -> io::Result<ActivityItem> {
+        Ok(item.clone())
+    }
+
     /// URL generation for items
     fn get_item_url(&self, item: &ActivityItem) -> String;
+
+    /// The requests `get_detailed_activities` would make for `user`, built without contacting
+    /// the platform; None when the platform cannot describe them
+    fn explain_queries(&self, _user: &str, _days: u32) -> io::Result<Option<QueryPlan>> {
+        Ok(None)
+    }
+}
+
+/// Features a platform supports, so the CLI/TUI can degrade instead of showing empty results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlatformCapabilities {
+    /// Review messages or comments are reported, e.g. loaded by `get_item_details` or counted
+    /// per item; the review browser only offers `d` on such platforms
+    pub supports_comments: bool,
+    /// Per-query item limit; a category at this size is likely truncated
+    pub max_items: Option<usize>,
+}
+
+/// What fetching a user's activity would request, for `reviewr review --explain`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryPlan {
+    /// How requests authenticate, with secrets masked
+    pub auth: String,
+    /// The period the queries cover, as the platform filters it
+    pub time_range: String,
+    pub requests: Vec<PlannedRequest>,
+    /// Account lookups, follow-up requests per item and filtering done after fetching
+    pub notes: Vec<String>,
+}
+
+/// One request of a [`QueryPlan`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedRequest {
+    /// Category the request fetches
+    pub label: String,
+    /// Search expression sent in the request, e.g. a Gerrit query or JQL
+    pub query: Option<String>,
+    /// Method and URL with secrets masked, e.g. `GET https://...`
+    pub request: String,
 }
 
 /// Basic activity metrics summary
@@ -39,12 +117,63 @@ pub struct ActivityMetrics {
     pub platform_specific: HashMap<String, u32>,
 }
 
+/// The items of one category as fetched, or why they could not be
+pub type CategoryResult = io::Result<Vec<ActivityItem>>;
+
 /// Detailed activities with full item lists
 #[derive(Debug, Clone, Default)]
 pub struct DetailedActivities {
     pub items_by_category: HashMap<ActivityCategory, Vec<ActivityItem>>,
+    /// Categories whose fetch failed, with the error, so they aren't mistaken for empty ones
+    pub failed_categories: HashMap<ActivityCategory, String>,
+}
+
+impl DetailedActivities {
+    /// Add the items of a fetched category, or record its error
+    pub fn insert_result(&mut self, category: ActivityCategory, result: CategoryResult) {
+        match result {
+            Ok(items) => {
+                self.failed_categories.remove(&category);
+                self.items_by_category.insert(category, items);
+            }
+            Err(e) => {
+                self.failed_categories.insert(category, e.to_string());
+            }
+        }
+    }
+
+    /// Whether some category could not be loaded, leaving the activities incomplete
+    pub fn is_partial(&self) -> bool {
+        !self.failed_categories.is_empty()
+    }
+
+    /// List items whose category changed after they were grouped, e.g. by category rules,
+    /// under their new category; the categories they left stay, even when empty
+    pub fn regroup(&mut self) {
+        let mut moved = Vec::new();
+        for (category, items) in &mut self.items_by_category {
+            let (kept, left): (Vec<_>, Vec<_>) = std::mem::take(items)
+                .into_iter()
+                .partition(|item| &item.category == category);
+            *items = kept;
+            moved.extend(left);
+        }
+        for item in moved {
+            self.items_by_category
+                .entry(item.category.clone())
+                .or_default()
+                .push(item);
+        }
+    }
 }
 
+/// Item metadata counting the review comments the user left on someone else's change
+pub const COMMENTS_KEY: &str = "comments";
+
+/// Item metadata marking an item fetched with its essential fields only; the platform's
+/// `get_item_details` loads the rest
+pub const DETAILS_PENDING_KEY: &str = "details_pending";
+
 /// Individual activity item (change, ticket, MR, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActivityItem {
@@ -60,6 +189,28 @@ pub struct ActivityItem {
     pub metadata: HashMap<String, String>,
 }
 
+impl ActivityItem {
+    /// An open change whose CI verdict (`ci_status` metadata) is failing
+    pub fn is_failing_ci(&self) -> bool {
+        self.metadata
+            .get("ci_status")
+            .is_some_and(|status| status == "failing")
+            && matches!(self.status.as_str(), "NEW" | "OPEN" | "opened")
+    }
+
+    pub fn has_pending_details(&self) -> bool {
+        self.metadata.contains_key(DETAILS_PENDING_KEY)
+    }
+
+    /// Whether the item reached a final state on its platform: merged, abandoned, closed, done
+    /// or resolved
+    pub fn is_closed(&self) -> bool {
+        ["merged", "abandoned", "closed", "done", "resolved"]
+            .iter()
+            .any(|status| self.status.eq_ignore_ascii_case(status))
+    }
+}
+
 /// Categories of activities across platforms
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ActivityCategory {
@@ -120,6 +271,19 @@ impl ActivityCategory {
             _ => 'o',
         }
     }
+
+    /// The category this one is merged into when activity of all platforms is listed together:
+    /// GitLab merge requests count as changes and every kind of review given as reviews given
+    pub fn combined(&self) -> ActivityCategory {
+        match self {
+            ActivityCategory::MergeRequestsCreated => ActivityCategory::ChangesCreated,
+            ActivityCategory::MergeRequestsMerged => ActivityCategory::ChangesMerged,
+            ActivityCategory::ChangesReviewed | ActivityCategory::MergeRequestsReviewed => {
+                ActivityCategory::ReviewsGiven
+            }
+            other => other.clone(),
+        }
+    }
 }
 
 /// Connection status for platform health checks
@@ -156,9 +320,46 @@ pub enum PlatformError {
     DataParseError(String),
 }
 
+/// How serious a logged platform event is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The operation failed
+    #[default]
+    Error,
+    /// The operation degraded without failing, e.g. a connection check timed out
+    Warning,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "error" => Ok(Severity::Error),
+            "warning" | "warn" => Ok(Severity::Warning),
+            other => Err(format!(
+                "unknown severity '{other}' (expected error or warning)"
+            )),
+        }
+    }
+}
+
 /// Structured error context for detailed error reporting
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorContext {
+    /// Entries written before severities existed are errors
+    #[serde(default)]
+    pub severity: Severity,
     pub platform_id: String,
     pub operation: String,
     pub user: Option<String>,
@@ -174,6 +375,7 @@ pub struct ErrorContext {
 impl ErrorContext {
     pub fn new(platform_id: &str, operation: &str) -> Self {
         Self {
+            severity: Severity::Error,
             platform_id: platform_id.to_string(),
             operation: operation.to_string(),
             user: None,
@@ -187,6 +389,11 @@ impl ErrorContext {
         }
     }
 
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
     pub fn with_user(mut self, user: &str) -> Self {
         self.user = Some(user.to_string());
         self
@@ -198,15 +405,32 @@ impl ErrorContext {
         self
     }
 
+    /// Record the failed request. Secrets and email addresses are redacted and the body is
+    /// truncated unless `verbose_error_logs` is enabled.
     pub fn with_request_details(
+        self,
+        url: &str,
+        status_code: Option<u16>,
+        response_body: Option<&str>,
+    ) -> Self {
+        self.with_request_details_verbosity(url, status_code, response_body, verbose_error_logs())
+    }
+
+    fn with_request_details_verbosity(
         mut self,
         url: &str,
         status_code: Option<u16>,
         response_body: Option<&str>,
+        verbose: bool,
     ) -> Self {
-        self.request_url = Some(url.to_string());
+        if verbose {
+            self.request_url = Some(url.to_string());
+            self.response_body = response_body.map(|s| s.to_string());
+        } else {
+            self.request_url = Some(redact_url(url));
+            self.response_body = response_body.map(redact_body);
+        }
         self.status_code = status_code;
-        self.response_body = response_body.map(|s| s.to_string());
         self
     }
 
@@ -215,10 +439,17 @@ impl ErrorContext {
         self
     }
 
+    /// Log the event at its severity's level and append it to error.log
     pub fn log_error(&self) {
-        log::error!(
+        let level = match self.severity {
+            Severity::Error => log::Level::Error,
+            Severity::Warning => log::Level::Warn,
+        };
+        log::log!(
             target: "platform_errors",
-            "Platform error: {} | Operation: {} | Type: {} | Message: {} | User: {:?} | URL: {:?} | Status: {:?} | Context: {:?}",
+            level,
+            "Platform {}: {} | Operation: {} | Type: {} | Message: {} | User: {:?} | URL: {:?} | Status: {:?} | Context: {:?}",
+            self.severity.as_str(),
             self.platform_id,
             self.operation,
             self.error_type,
@@ -239,15 +470,10 @@ impl ErrorContext {
         use std::fs::OpenOptions;
         use std::io::Write;
 
-        // Get the data directory (where config.toml lives)
-        let data_dir = dirs::home_dir()
-            .unwrap_or_else(|| std::path::PathBuf::from("."))
-            .join(".reviewr");
-
-        // Ensure directory exists
-        std::fs::create_dir_all(&data_dir)?;
-
-        let error_log_path = data_dir.join("error.log");
+        let error_log_path = error_log_path();
+        if let Some(data_dir) = error_log_path.parent() {
+            std::fs::create_dir_all(data_dir)?;
+        }
 
         let mut file = OpenOptions::new()
             .create(true)
@@ -266,20 +492,17 @@ impl ErrorContext {
 pub struct ErrorLogReader;
 
 impl ErrorLogReader {
-    /// Read recent errors from the error.log file
+    /// Read recent entries from the error log at `error_log_path`, optionally only those of
+    /// one platform or severity
     pub fn read_recent_errors(
+        error_log_path: &Path,
         limit: usize,
         platform_filter: Option<&str>,
+        severity_filter: Option<Severity>,
     ) -> Result<Vec<ErrorContext>, Box<dyn std::error::Error + Send + Sync>> {
         use std::fs::File;
         use std::io::{BufRead, BufReader};
 
-        let data_dir = dirs::home_dir()
-            .unwrap_or_else(|| std::path::PathBuf::from("."))
-            .join(".reviewr");
-
-        let error_log_path = data_dir.join("error.log");
-
         if !error_log_path.exists() {
             return Ok(Vec::new());
         }
@@ -297,6 +520,9 @@ impl ErrorLogReader {
                 {
                     continue;
                 }
+                if severity_filter.is_some_and(|severity| error.severity != severity) {
+                    continue;
+                }
                 errors.push(error);
             }
         }
@@ -308,18 +534,13 @@ impl ErrorLogReader {
         Ok(errors)
     }
 
-    /// Get error statistics by reading the log file
-    pub fn get_error_stats()
-    -> Result<HashMap<String, ErrorStats>, Box<dyn std::error::Error + Send + Sync>> {
+    /// Get error statistics by reading the log file at `error_log_path`
+    pub fn get_error_stats(
+        error_log_path: &Path,
+    ) -> Result<HashMap<String, ErrorStats>, Box<dyn std::error::Error + Send + Sync>> {
         use std::fs::File;
         use std::io::{BufRead, BufReader};
 
-        let data_dir = dirs::home_dir()
-            .unwrap_or_else(|| std::path::PathBuf::from("."))
-            .join(".reviewr");
-
-        let error_log_path = data_dir.join("error.log");
-
         if !error_log_path.exists() {
             return Ok(HashMap::new());
         }
@@ -335,6 +556,11 @@ impl ErrorLogReader {
                 let entry = stats
                     .entry(error.platform_id.clone())
                     .or_insert_with(ErrorStats::new);
+                if error.severity == Severity::Warning {
+                    entry.total_warnings += 1;
+                    entry.last_warning_time = Some(error.timestamp.clone());
+                    continue;
+                }
                 entry.total_errors += 1;
 
                 let count = entry
@@ -357,6 +583,10 @@ pub struct ErrorStats {
     pub total_errors: usize,
     pub error_types: HashMap<String, usize>,
     pub last_error_time: Option<String>,
+    #[serde(default)]
+    pub total_warnings: usize,
+    #[serde(default)]
+    pub last_warning_time: Option<String>,
 }
 
 impl ErrorStats {
@@ -365,6 +595,8 @@ impl ErrorStats {
             total_errors: 0,
             error_types: HashMap::new(),
             last_error_time: None,
+            total_warnings: 0,
+            last_warning_time: None,
         }
     }
 }
@@ -391,7 +623,7 @@ impl From<PlatformError> for io::Error {
 
 /// Platform registry for managing multiple review platforms
 pub struct PlatformRegistry {
-    platforms: HashMap<String, Box<dyn ReviewPlatform>>,
+    platforms: HashMap<String, Arc<dyn ReviewPlatform>>,
 }
 
 impl PlatformRegistry {
@@ -403,13 +635,18 @@ impl PlatformRegistry {
 
     pub fn register_platform(&mut self, platform: Box<dyn ReviewPlatform>) {
         let id = platform.get_platform_id().to_string();
-        self.platforms.insert(id, platform);
+        self.platforms.insert(id, Arc::from(platform));
     }
 
     pub fn get_platform(&self, id: &str) -> Option<&dyn ReviewPlatform> {
         self.platforms.get(id).map(|p| p.as_ref())
     }
 
+    /// A platform that can be moved into a spawned task, e.g. to abort its requests
+    pub fn get_shared_platform(&self, id: &str) -> Option<Arc<dyn ReviewPlatform>> {
+        self.platforms.get(id).cloned()
+    }
+
     pub fn get_configured_platforms(&self) -> Vec<&dyn ReviewPlatform> {
         self.platforms
             .values()
@@ -495,6 +732,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_request_details_are_redacted() {
+        let url = "https://gitlab.example.com/api/v4/users?private_token=secret";
+        let body = r#"{"message":"401 Unauthorized","token":"secret"}"#;
+
+        let error = ErrorContext::new("gitlab", "get_user").with_request_details_verbosity(
+            url,
+            Some(401),
+            Some(body),
+            false,
+        );
+        assert!(!error.request_url.unwrap().contains("secret"));
+        assert!(!error.response_body.unwrap().contains("secret"));
+
+        let error = ErrorContext::new("gitlab", "get_user").with_request_details_verbosity(
+            url,
+            Some(401),
+            Some(body),
+            true,
+        );
+        assert_eq!(error.request_url.as_deref(), Some(url));
+        assert_eq!(error.response_body.as_deref(), Some(body));
+    }
+
     #[test]
     fn test_error_context_serialization() {
         let error = ErrorContext::new("jira", "search_issues")
@@ -512,6 +773,54 @@ mod tests {
         assert_eq!(error.error_message, deserialized.error_message);
     }
 
+    #[test]
+    fn test_severity() {
+        let warning = ErrorContext::new("gerrit", "test_connection")
+            .with_severity(Severity::Warning)
+            .with_error("timeout", "Connection timeout");
+        let json = serde_json::to_string(&warning).expect("Should serialize to JSON");
+        assert!(json.contains("\"severity\":\"warning\""));
+
+        // Lines logged before severities existed
+        let legacy: ErrorContext = serde_json::from_str(
+            r#"{"platform_id":"jira","operation":"search_issues","user":null,
+                "timestamp":"2024-01-15T10:30:00Z","error_type":"api_error",
+                "error_message":"HTTP 500","request_url":null,"status_code":500,
+                "response_body":null,"metadata":{}}"#,
+        )
+        .expect("Should deserialize legacy entry");
+        assert_eq!(legacy.severity, Severity::Error);
+
+        assert_eq!("Warning".parse::<Severity>(), Ok(Severity::Warning));
+        assert_eq!("error".parse::<Severity>(), Ok(Severity::Error));
+        assert!("info".parse::<Severity>().is_err());
+    }
+
+    #[test]
+    fn test_insert_result_records_failed_categories() {
+        let mut activities = DetailedActivities::default();
+        activities.insert_result(
+            ActivityCategory::IssuesCreated,
+            Err(io::Error::other("HTTP 403 Forbidden")),
+        );
+        activities.insert_result(ActivityCategory::ChangesMerged, Ok(Vec::new()));
+        assert!(activities.is_partial());
+        assert_eq!(
+            activities.failed_categories[&ActivityCategory::IssuesCreated],
+            "HTTP 403 Forbidden"
+        );
+        assert!(
+            !activities
+                .items_by_category
+                .contains_key(&ActivityCategory::IssuesCreated)
+        );
+
+        // A later successful fetch replaces the failure
+        activities.insert_result(ActivityCategory::IssuesCreated, Ok(Vec::new()));
+        assert!(!activities.is_partial());
+        assert_eq!(activities.items_by_category.len(), 2);
+    }
+
     #[test]
     fn test_error_stats_creation() {
         let stats = ErrorStats::new();
@@ -524,43 +833,21 @@ mod tests {
     #[test]
     fn test_error_log_reader_empty_file() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
-        let original_home = std::env::var("HOME").ok();
-        unsafe {
-            std::env::set_var("HOME", temp_dir.path());
-        }
+        let error_log_path = temp_dir.path().join("error.log");
 
-        let errors =
-            ErrorLogReader::read_recent_errors(10, None).expect("Should read empty errors");
+        let errors = ErrorLogReader::read_recent_errors(&error_log_path, 10, None, None)
+            .expect("Should read empty errors");
         assert!(errors.is_empty());
 
-        let stats = ErrorLogReader::get_error_stats().expect("Should get empty stats");
+        let stats =
+            ErrorLogReader::get_error_stats(&error_log_path).expect("Should get empty stats");
         assert!(stats.is_empty());
-
-        // Restore original HOME if it existed
-        if let Some(home) = original_home {
-            unsafe {
-                std::env::set_var("HOME", home);
-            }
-        } else {
-            unsafe {
-                std::env::remove_var("HOME");
-            }
-        }
     }
 
     #[test]
     fn test_error_log_reader_with_data() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
-        let original_home = std::env::var("HOME").ok();
-        unsafe {
-            std::env::set_var("HOME", temp_dir.path());
-        }
-
-        // Create .reviewr directory and error.log
-        let reviewr_dir = temp_dir.path().join(".reviewr");
-        fs::create_dir_all(&reviewr_dir).expect("Should create reviewr dir");
-
-        let error_log_path = reviewr_dir.join("error.log");
+        let error_log_path = temp_dir.path().join("error.log");
 
         // Write test error data
         let error1 = ErrorContext::new("gerrit", "query_changes")
@@ -578,7 +865,8 @@ mod tests {
             .expect("Should write errors to file");
 
         // Test reading errors
-        let errors = ErrorLogReader::read_recent_errors(10, None).expect("Should read errors");
+        let errors = ErrorLogReader::read_recent_errors(&error_log_path, 10, None, None)
+            .expect("Should read errors");
         assert_eq!(errors.len(), 2);
 
         // Should be in reverse order (most recent first)
@@ -586,13 +874,14 @@ mod tests {
         assert_eq!(errors[1].platform_id, "gerrit");
 
         // Test filtering by platform
-        let gerrit_errors = ErrorLogReader::read_recent_errors(10, Some("gerrit"))
-            .expect("Should read gerrit errors");
+        let gerrit_errors =
+            ErrorLogReader::read_recent_errors(&error_log_path, 10, Some("gerrit"), None)
+                .expect("Should read gerrit errors");
         assert_eq!(gerrit_errors.len(), 1);
         assert_eq!(gerrit_errors[0].platform_id, "gerrit");
 
         // Test statistics
-        let stats = ErrorLogReader::get_error_stats().expect("Should get stats");
+        let stats = ErrorLogReader::get_error_stats(&error_log_path).expect("Should get stats");
         assert_eq!(stats.len(), 2);
 
         let gerrit_stats = stats.get("gerrit").expect("Should have gerrit stats");
@@ -602,16 +891,5 @@ mod tests {
         let jira_stats = stats.get("jira").expect("Should have jira stats");
         assert_eq!(jira_stats.total_errors, 1);
         assert_eq!(jira_stats.error_types.get("api_error"), Some(&1));
-
-        // Restore original HOME if it existed
-        if let Some(home) = original_home {
-            unsafe {
-                std::env::set_var("HOME", home);
-            }
-        } else {
-            unsafe {
-                std::env::remove_var("HOME");
-            }
-        }
     }
 }
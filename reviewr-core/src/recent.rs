@@ -0,0 +1,115 @@
+//! Employees whose reviews, notes or reports were opened recently, most recent first
+
+use crate::models::DataPath;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// How many employees are remembered
+pub const MAX_RECENT_EMPLOYEES: usize = 10;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RecentFile {
+    employees: Vec<String>,
+}
+
+pub struct RecentService;
+
+impl RecentService {
+    fn recent_path(data_path: &DataPath) -> PathBuf {
+        data_path.root.join("recent.json")
+    }
+
+    /// Recently opened employees, most recent first; a missing or unreadable file yields none
+    pub fn load(data_path: &DataPath) -> Vec<String> {
+        let path = Self::recent_path(data_path);
+        let Ok(content) = fs::read_to_string(&path) else {
+            return Vec::new();
+        };
+        match serde_json::from_str::<RecentFile>(&content) {
+            Ok(recent) => recent.employees,
+            Err(e) => {
+                warn!("Ignoring unreadable {}: {e}", path.display());
+                Vec::new()
+            }
+        }
+    }
+
+    /// Move `employee` to the front of the recent list
+    pub fn record(data_path: &DataPath, employee: &str) -> io::Result<()> {
+        let mut employees = Self::load(data_path);
+        employees.retain(|name| name != employee);
+        employees.insert(0, employee.to_string());
+        employees.truncate(MAX_RECENT_EMPLOYEES);
+        Self::save(data_path, employees)
+    }
+
+    /// Drop a removed or archived employee from the recent list
+    pub fn forget(data_path: &DataPath, employee: &str) -> io::Result<()> {
+        let mut employees = Self::load(data_path);
+        let before = employees.len();
+        employees.retain(|name| name != employee);
+        if employees.len() == before {
+            return Ok(());
+        }
+        Self::save(data_path, employees)
+    }
+
+    /// Keep a renamed employee at their position in the recent list
+    pub fn rename(data_path: &DataPath, old_name: &str, new_name: &str) -> io::Result<()> {
+        let mut employees = Self::load(data_path);
+        let Some(position) = employees.iter().position(|name| name == old_name) else {
+            return Ok(());
+        };
+        employees[position] = new_name.to_string();
+        Self::save(data_path, employees)
+    }
+
+    fn save(data_path: &DataPath, employees: Vec<String>) -> io::Result<()> {
+        fs::create_dir_all(&data_path.root)?;
+        let content =
+            serde_json::to_string_pretty(&RecentFile { employees }).map_err(io::Error::other)?;
+        fs::write(Self::recent_path(data_path), content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_record_moves_to_front_and_caps() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        assert!(RecentService::load(&data_path).is_empty());
+
+        RecentService::record(&data_path, "Alice").unwrap();
+        RecentService::record(&data_path, "Bob").unwrap();
+        RecentService::record(&data_path, "Alice").unwrap();
+        assert_eq!(RecentService::load(&data_path), vec!["Alice", "Bob"]);
+
+        for i in 0..MAX_RECENT_EMPLOYEES {
+            RecentService::record(&data_path, &format!("Employee {i}")).unwrap();
+        }
+        let recent = RecentService::load(&data_path);
+        assert_eq!(recent.len(), MAX_RECENT_EMPLOYEES);
+        assert_eq!(recent[0], format!("Employee {}", MAX_RECENT_EMPLOYEES - 1));
+    }
+
+    #[test]
+    fn test_forget() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+
+        RecentService::record(&data_path, "Alice").unwrap();
+        RecentService::record(&data_path, "Bob").unwrap();
+        RecentService::forget(&data_path, "Alice").unwrap();
+        assert_eq!(RecentService::load(&data_path), vec!["Bob"]);
+
+        RecentService::rename(&data_path, "Bob", "Robert").unwrap();
+        assert_eq!(RecentService::load(&data_path), vec!["Robert"]);
+    }
+}
@@ -0,0 +1,996 @@
+use crate::anonymize::Anonymizer;
+use crate::change_size::SizeBreakdown;
+use crate::cycle::ReviewCycle;
+use crate::feedback::FeedbackService;
+use crate::goals::GoalOutcome;
+use crate::models::{DataPath, Employee};
+use crate::notes::{NoteEntry, NotesService};
+use crate::period_comparison::{CategoryComparison, PeriodCounts, compare_periods};
+use crate::platform::{ActivityItem, DetailedActivities};
+use crate::timestamps::{TimestampFormat, parse_timestamp};
+use chrono::{Duration, NaiveDate};
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Activity fetched from one platform, or the error that prevented it
+#[derive(Debug, Clone)]
+pub struct PlatformSection {
+    pub name: String,
+    pub activities: Result<DetailedActivities, String>,
+    /// Counts of the equally long period before, when fetched for a comparison and the
+    /// platform could count it
+    pub previous_period: Option<PeriodCounts>,
+}
+
+/// Everything that goes into an exported review packet
+#[derive(Debug, Clone)]
+pub struct ReviewPacket {
+    pub employee: Employee,
+    pub period_days: u32,
+    /// The review cycle the packet covers instead of the last `period_days` days
+    pub cycle: Option<ReviewCycle>,
+    pub generated_on: String,
+    pub platforms: Vec<PlatformSection>,
+    pub goals: Vec<GoalOutcome>,
+    pub notes: Option<String>,
+    /// Add authored changes per size bucket to the summary
+    pub size_buckets: bool,
+    /// Compare the item counts with the period before, from each section's `previous_period`
+    pub compare: bool,
+    pub timestamps: TimestampFormat,
+}
+
+impl ReviewPacket {
+    /// Render the packet as a single Markdown document
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let employee = &self.employee;
+        let email = employee.committer_email.as_deref().unwrap_or("no email");
+
+        let _ = writeln!(out, "# Review Packet: {}\n", employee.name);
+        let _ = writeln!(
+            out,
+            "_{} · {email} · {} · generated {}_\n",
+            employee.title,
+            self.period_label(),
+            self.generated_on
+        );
+
+        self.write_summary(&mut out);
+        self.write_platforms(&mut out);
+        self.write_goals(&mut out);
+        self.write_notes(&mut out);
+
+        out
+    }
+
+    /// Drop activity last updated after the end of a closed cycle. Platforms are queried for
+    /// everything updated since the cycle started, which includes the time after it ended.
+    pub fn limit_to_cycle(&mut self) {
+        let Some(end) = self.cycle.as_ref().and_then(|cycle| cycle.end) else {
+            return;
+        };
+        for platform in &mut self.platforms {
+            let Ok(activities) = &mut platform.activities else {
+                continue;
+            };
+            for items in activities.items_by_category.values_mut() {
+                items.retain(|item| {
+                    parse_timestamp(&item.updated).is_none_or(|updated| updated.date_naive() <= end)
+                });
+            }
+            activities
+                .items_by_category
+                .retain(|_, items| !items.is_empty());
+        }
+    }
+
+    fn period_label(&self) -> String {
+        match &self.cycle {
+            Some(cycle) => format!("cycle {}", cycle.label()),
+            None => format!("last {} days", self.period_days),
+        }
+    }
+
+    /// Make the packet safe to share outside the company: the employee and everyone named on
+    /// their items become pseudonyms, the email is dropped and internal links are removed
+    pub fn redact(&mut self, anonymizer: &Anonymizer) {
+        let mut anonymizer = anonymizer.clone();
+        anonymizer.add_employee(&self.employee);
+        let items = self
+            .platforms
+            .iter()
+            .filter_map(|platform| platform.activities.as_ref().ok())
+            .flat_map(|activities| activities.items_by_category.values().flatten())
+            .chain(self.goals.iter().flat_map(|outcome| &outcome.completed));
+        for person in items.flat_map(FeedbackService::people) {
+            anonymizer.add_person(&person, []);
+        }
+
+        let redact_item = |item: &mut ActivityItem| {
+            item.title = anonymizer.text(&item.title);
+            item.url = anonymizer.url(&item.url);
+        };
+        self.employee.name = anonymizer.pseudonym(&self.employee.name);
+        self.employee.committer_email = None;
+        for platform in &mut self.platforms {
+            match &mut platform.activities {
+                Ok(activities) => {
+                    activities
+                        .items_by_category
+                        .values_mut()
+                        .flatten()
+                        .for_each(redact_item);
+                    for e in activities.failed_categories.values_mut() {
+                        *e = anonymizer.text(e);
+                    }
+                }
+                Err(e) => *e = anonymizer.text(e),
+            }
+        }
+        for outcome in &mut self.goals {
+            outcome.goal.title = anonymizer.text(&outcome.goal.title);
+            outcome.goal.evidence = outcome
+                .goal
+                .evidence
+                .iter()
+                .map(|link| anonymizer.url(link))
+                .filter(|link| !link.is_empty())
+                .collect();
+            outcome.completed.iter_mut().for_each(redact_item);
+        }
+        if let Some(notes) = &mut self.notes {
+            *notes = anonymizer.text(notes);
+        }
+    }
+
+    /// Compact plain-text summary for a self-review request email: item counts and the
+    /// `top_items` most recently updated items per category. Goals and notes stay out.
+    pub fn to_email_text(&self, top_items: usize) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "{}\n", self.email_heading());
+        if let Some(message) = self.email_without_activity() {
+            let _ = writeln!(out, "{message}");
+            return out;
+        }
+        for platform in &self.platforms {
+            let activities = match &platform.activities {
+                Ok(activities) => activities,
+                Err(e) => {
+                    let _ = writeln!(out, "{}: failed to load ({e})\n", platform.name);
+                    continue;
+                }
+            };
+            let _ = writeln!(out, "{}", platform.name);
+            if activities.items_by_category.is_empty() && !activities.is_partial() {
+                let _ = writeln!(out, "  No activity in this period.");
+            }
+            for (category, e) in failed_categories(activities) {
+                let _ = writeln!(out, "  {category}: failed to load ({e})");
+            }
+            for (category, items) in email_categories(activities) {
+                let _ = writeln!(out, "  {category}: {}", items.len());
+                for item in most_recent(items, top_items) {
+                    let link = if item.url.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" {}", item.url)
+                    };
+                    let _ = writeln!(
+                        out,
+                        "    - {} ({}, {}){link}",
+                        item.title, item.project, item.status
+                    );
+                }
+                if items.len() > top_items {
+                    let _ = writeln!(out, "    … and {} more", items.len() - top_items);
+                }
+            }
+            out.push('\n');
+        }
+        let _ = writeln!(out, "Total: {} items", self.email_total());
+        let comparison = self.comparison();
+        if !comparison.is_empty() {
+            let _ = writeln!(out, "\nCompared to the prior {} days:", self.period_days);
+            for row in comparison {
+                let _ = writeln!(out, "  {}", row.describe(self.period_days));
+            }
+        }
+        out
+    }
+
+    /// The same summary as [`Self::to_email_text`] as an HTML fragment for rich-text mail
+    pub fn to_email_html(&self, top_items: usize) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "<p>{}</p>", escape_html(&self.email_heading()));
+        if let Some(message) = self.email_without_activity() {
+            let _ = writeln!(out, "<p>{}</p>", escape_html(message));
+            return out;
+        }
+        for platform in &self.platforms {
+            let name = escape_html(&platform.name);
+            let activities = match &platform.activities {
+                Ok(activities) => activities,
+                Err(e) => {
+                    let _ = writeln!(
+                        out,
+                        "<p><b>{name}</b>: failed to load ({})</p>",
+                        escape_html(e)
+                    );
+                    continue;
+                }
+            };
+            let _ = writeln!(out, "<p><b>{name}</b></p>");
+            if activities.items_by_category.is_empty() && !activities.is_partial() {
+                let _ = writeln!(out, "<p>No activity in this period.</p>");
+                continue;
+            }
+            let _ = writeln!(out, "<ul>");
+            for (category, e) in failed_categories(activities) {
+                let _ = writeln!(
+                    out,
+                    "<li>{}: failed to load ({})</li>",
+                    escape_html(category),
+                    escape_html(e)
+                );
+            }
+            for (category, items) in email_categories(activities) {
+                let _ = writeln!(out, "<li>{}: {}", escape_html(category), items.len());
+                let _ = writeln!(out, "<ul>");
+                for item in most_recent(items, top_items) {
+                    let title = if item.url.is_empty() {
+                        escape_html(&item.title)
+                    } else {
+                        format!(
+                            "<a href=\"{}\">{}</a>",
+                            escape_html(&item.url),
+                            escape_html(&item.title)
+                        )
+                    };
+                    let _ = writeln!(
+                        out,
+                        "<li>{title} ({}, {})</li>",
+                        escape_html(&item.project),
+                        escape_html(&item.status)
+                    );
+                }
+                if items.len() > top_items {
+                    let _ = writeln!(out, "<li>… and {} more</li>", items.len() - top_items);
+                }
+                let _ = writeln!(out, "</ul></li>");
+            }
+            let _ = writeln!(out, "</ul>");
+        }
+        let _ = writeln!(out, "<p>Total: {} items</p>", self.email_total());
+        let comparison = self.comparison();
+        if !comparison.is_empty() {
+            let _ = writeln!(
+                out,
+                "<p>Compared to the prior {} days:</p>\n<ul>",
+                self.period_days
+            );
+            for row in comparison {
+                let _ = writeln!(
+                    out,
+                    "<li>{}</li>",
+                    escape_html(&row.describe(self.period_days))
+                );
+            }
+            let _ = writeln!(out, "</ul>");
+        }
+        out
+    }
+
+    fn email_heading(&self) -> String {
+        format!(
+            "Activity summary for {} ({}), last {} days up to {}",
+            self.employee.name, self.employee.title, self.period_days, self.generated_on
+        )
+    }
+
+    fn email_without_activity(&self) -> Option<&'static str> {
+        if self.employee.notes_only {
+            Some("Activity aggregation is disabled for this employee (data policy).")
+        } else if self.platforms.is_empty() {
+            Some("No review platforms were queried.")
+        } else {
+            None
+        }
+    }
+
+    fn email_total(&self) -> usize {
+        self.platforms
+            .iter()
+            .filter_map(|platform| platform.activities.as_ref().ok())
+            .flat_map(|activities| activities.items_by_category.values())
+            .map(Vec::len)
+            .sum()
+    }
+
+    /// Items per category against the period before, empty unless the packet compares
+    fn comparison(&self) -> Vec<CategoryComparison> {
+        if !self.compare {
+            return Vec::new();
+        }
+        compare_periods(self.platforms.iter().filter_map(|platform| {
+            let activities = platform.activities.as_ref().ok()?;
+            Some((activities, platform.previous_period.as_ref()))
+        }))
+    }
+
+    fn write_summary(&self, out: &mut String) {
+        let _ = writeln!(out, "## Summary\n");
+        if self.employee.notes_only {
+            let _ = writeln!(
+                out,
+                "Activity aggregation is disabled for this employee (data policy).\n"
+            );
+            return;
+        }
+        if !self.employee.exclude_platforms.is_empty() {
+            let _ = writeln!(
+                out,
+                "_Excluded by data policy: {}_\n",
+                self.employee.exclude_platforms.join(", ")
+            );
+        }
+        if self.platforms.is_empty() {
+            let _ = writeln!(out, "No review platforms were queried.\n");
+            return;
+        }
+
+        let _ = writeln!(out, "| Platform | Category | Items |");
+        let _ = writeln!(out, "|---|---|---|");
+        let mut total = 0;
+        for platform in &self.platforms {
+            match &platform.activities {
+                Ok(activities) => {
+                    for (category, items) in sorted_categories(activities) {
+                        total += items;
+                        let _ = writeln!(out, "| {} | {category} | {items} |", platform.name);
+                    }
+                    for (category, _) in failed_categories(activities) {
+                        let _ =
+                            writeln!(out, "| {} | {category} | _failed to load_ |", platform.name);
+                    }
+                }
+                Err(_) => {
+                    let _ = writeln!(out, "| {} | _failed to load_ | - |", platform.name);
+                }
+            }
+        }
+        let _ = writeln!(out, "| **Total** | | **{total}** |\n");
+
+        if self.size_buckets {
+            let breakdown = SizeBreakdown::from_activities(
+                self.platforms
+                    .iter()
+                    .filter_map(|platform| platform.activities.as_ref().ok()),
+            );
+            let _ = writeln!(
+                out,
+                "Authored changes by size: {} _(XS < 10 lines, S < 100, M < 500, L ≥ 500)_\n",
+                breakdown.summary()
+            );
+        }
+
+        let comparison = self.comparison();
+        if !comparison.is_empty() {
+            let _ = writeln!(
+                out,
+                "### Compared to the previous {} days\n",
+                self.period_days
+            );
+            let _ = writeln!(out, "| Category | Before | Now | Change |");
+            let _ = writeln!(out, "|---|---|---|---|");
+            for row in &comparison {
+                let before = row
+                    .previous
+                    .map_or("–".to_string(), |previous| previous.to_string());
+                let _ = writeln!(
+                    out,
+                    "| {} | {before} | {} | {} |",
+                    row.category.display_name(),
+                    row.current,
+                    row.change()
+                );
+            }
+            out.push('\n');
+        }
+    }
+
+    fn write_platforms(&self, out: &mut String) {
+        for platform in &self.platforms {
+            let _ = writeln!(out, "## {}\n", platform.name);
+            let activities = match &platform.activities {
+                Ok(activities) => activities,
+                Err(e) => {
+                    let _ = writeln!(out, "_Failed to load: {e}_\n");
+                    continue;
+                }
+            };
+            for (category, e) in failed_categories(activities) {
+                let _ = writeln!(out, "_{category} failed to load: {e}_\n");
+            }
+            if activities.items_by_category.is_empty() {
+                if !activities.is_partial() {
+                    let _ = writeln!(out, "No activity in this period.\n");
+                }
+                continue;
+            }
+
+            let mut categories: Vec<_> = activities.items_by_category.iter().collect();
+            categories.sort_by_key(|(category, _)| category.display_name().to_string());
+            for (category, items) in categories {
+                let _ = writeln!(out, "### {} ({})\n", category.display_name(), items.len());
+                for item in items {
+                    let _ = writeln!(
+                        out,
+                        "- {} {} — {} ({}, updated {})",
+                        item_link(item),
+                        item.title,
+                        item.project,
+                        item.status,
+                        self.timestamps.format(&item.updated)
+                    );
+                }
+                out.push('\n');
+            }
+        }
+    }
+
+    /// Last day before the packet's period: the day before the cycle started, or `period_days`
+    /// before the packet was generated
+    fn day_before_period(&self) -> Option<NaiveDate> {
+        match &self.cycle {
+            Some(cycle) => cycle.start.pred_opt(),
+            None => NaiveDate::parse_from_str(&self.generated_on, "%Y-%m-%d")
+                .ok()
+                .map(|generated_on| generated_on - Duration::days(i64::from(self.period_days))),
+        }
+    }
+
+    fn write_goals(&self, out: &mut String) {
+        if self.goals.is_empty() {
+            return;
+        }
+        let _ = writeln!(out, "## Goals\n");
+        let before = self.day_before_period();
+        for outcome in &self.goals {
+            let goal = &outcome.goal;
+            let target = goal
+                .target_date
+                .map(|date| format!(", due {date}"))
+                .unwrap_or_default();
+            let _ = writeln!(
+                out,
+                "- **{}** — {}{target} · {}% ({} completed, {} open)",
+                goal.title,
+                goal.status,
+                outcome.progress_percent,
+                outcome.completed.len(),
+                outcome.open_items
+            );
+            // Only goals kept with `reviewr goals` have a status history
+            if let (Some(before), false) = (before, goal.history.is_empty()) {
+                let since = before + Duration::days(1);
+                let changes = goal
+                    .history
+                    .iter()
+                    .filter(|update| update.date > before)
+                    .map(|update| format!("{} {}", update.date, update.status))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let progress = match goal.status_on(before) {
+                    None => format!("Added since {since}"),
+                    Some(previous) if changes.is_empty() => {
+                        format!("Unchanged since {since} ({previous})")
+                    }
+                    Some(previous) => format!("Since {since}: {previous} → {}", goal.status),
+                };
+                let changes = if changes.is_empty() {
+                    String::new()
+                } else {
+                    format!(" ({changes})")
+                };
+                let _ = writeln!(out, "  - {progress}{changes}");
+            }
+            for link in &goal.evidence {
+                let _ = writeln!(out, "  - Evidence: <{link}>");
+            }
+            for item in &outcome.completed {
+                let _ = writeln!(out, "  - {} {}", item_link(item), item.title);
+            }
+        }
+        out.push('\n');
+    }
+
+    fn write_notes(&self, out: &mut String) {
+        let _ = writeln!(out, "## Notes\n");
+        let Some(notes) = &self.notes else {
+            let _ = writeln!(out, "No notes recorded.");
+            return;
+        };
+        let entries = NotesService::parse_entries(notes);
+        if let Some(cycle) = &self.cycle {
+            self.write_cycle_notes(out, cycle, &entries);
+            return;
+        }
+        if let (false, Ok(generated_on)) = (
+            entries.is_empty(),
+            NaiveDate::parse_from_str(&self.generated_on, "%Y-%m-%d"),
+        ) {
+            let since = generated_on - Duration::days(i64::from(self.period_days));
+            let in_period = entries.iter().filter(|entry| entry.date > since).count();
+            let _ = writeln!(
+                out,
+                "_{in_period} of {} dated entries fall in the last {} days._\n",
+                entries.len(),
+                self.period_days
+            );
+        }
+        // Drop the file's own title and nest its sections under this heading
+        let lines = notes
+            .lines()
+            .filter(|line| !line.starts_with("# "))
+            .skip_while(|line| line.trim().is_empty());
+        for line in lines {
+            if line.starts_with('#') {
+                let _ = writeln!(out, "#{line}");
+            } else {
+                let _ = writeln!(out, "{line}");
+            }
+        }
+    }
+
+    /// Only the dated entries written during the cycle or tagged with it; undated sections
+    /// are left out
+    fn write_cycle_notes(&self, out: &mut String, cycle: &ReviewCycle, entries: &[NoteEntry]) {
+        let in_cycle: Vec<&NoteEntry> = entries
+            .iter()
+            .filter(|entry| {
+                entry.cycle() == Some(cycle.name.as_str()) || cycle.contains(entry.date)
+            })
+            .collect();
+        let _ = writeln!(
+            out,
+            "_{} of {} dated entries belong to cycle {}._\n",
+            in_cycle.len(),
+            entries.len(),
+            cycle.name
+        );
+        for entry in in_cycle {
+            let _ = writeln!(out, "### {}\n", entry.heading());
+            if !entry.body.is_empty() {
+                let _ = writeln!(out, "{}\n", entry.body);
+            }
+        }
+    }
+}
+
+/// `[id](url)`, or the bare id when the URL was redacted
+fn item_link(item: &ActivityItem) -> String {
+    if item.url.is_empty() {
+        item.id.clone()
+    } else {
+        format!("[{}]({})", item.id, item.url)
+    }
+}
+
+/// Categories by name with their items
+fn email_categories(activities: &DetailedActivities) -> Vec<(&str, &[ActivityItem])> {
+    let mut categories: Vec<(&str, &[ActivityItem])> = activities
+        .items_by_category
+        .iter()
+        .map(|(category, items)| (category.display_name(), items.as_slice()))
+        .collect();
+    categories.sort_by_key(|(category, _)| *category);
+    categories
+}
+
+/// The `limit` most recently updated items; unreadable dates come last
+fn most_recent(items: &[ActivityItem], limit: usize) -> Vec<&ActivityItem> {
+    let mut items: Vec<&ActivityItem> = items.iter().collect();
+    items.sort_by_key(|item| std::cmp::Reverse(parse_timestamp(&item.updated)));
+    items.truncate(limit);
+    items
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Categories that could not be loaded with their errors, by display name
+fn failed_categories(activities: &DetailedActivities) -> Vec<(&str, &str)> {
+    let mut categories: Vec<(&str, &str)> = activities
+        .failed_categories
+        .iter()
+        .map(|(category, e)| (category.display_name(), e.as_str()))
+        .collect();
+    categories.sort();
+    categories
+}
+
+fn sorted_categories(activities: &DetailedActivities) -> Vec<(&str, usize)> {
+    let mut categories: Vec<(&str, usize)> = activities
+        .items_by_category
+        .iter()
+        .map(|(category, items)| (category.display_name(), items.len()))
+        .collect();
+    categories.sort();
+    categories
+}
+
+pub struct ReportService;
+
+impl ReportService {
+    /// Default packet location: `reports/{employee}-{date}.md` under the data directory
+    pub fn default_output_path(data_path: &DataPath, employee_name: &str, date: &str) -> PathBuf {
+        data_path
+            .root
+            .join("reports")
+            .join(format!("{employee_name}-{date}.md"))
+    }
+
+    /// Packets of an employee at their default location, with the date each was generated on
+    pub fn employee_reports(
+        data_path: &DataPath,
+        employee_name: &str,
+    ) -> io::Result<Vec<(PathBuf, String)>> {
+        let entries = match fs::read_dir(data_path.root.join("reports")) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let prefix = format!("{employee_name}-");
+        let mut reports: Vec<(PathBuf, String)> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let file_name = entry.file_name().into_string().ok()?;
+                // Reports of `Jane Doe-Smith` are not reports of `Jane Doe`
+                let date = file_name.strip_prefix(&prefix)?.strip_suffix(".md")?;
+                NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+                Some((entry.path(), date.to_string()))
+            })
+            .collect();
+        reports.sort();
+        Ok(reports)
+    }
+
+    pub fn write_report(path: &Path, packet: &ReviewPacket) -> io::Result<()> {
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, packet.to_markdown())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goals::{Goal, GoalStatus, GoalsService};
+    use crate::platform::ActivityCategory;
+    use crate::test_support::ItemBuilder;
+    use crate::timestamps::DisplayZone;
+
+    fn packet() -> ReviewPacket {
+        let mut activities = DetailedActivities::default();
+        activities.items_by_category.insert(
+            ActivityCategory::ChangesMerged,
+            vec![
+                ItemBuilder::new("12345")
+                    .with_title("Fix login bug")
+                    .with_created("2024-01-15T10:30:00Z")
+                    .with_updated("2024-01-15T16:45:00Z")
+                    .with_url("https://gerrit.example.com/c/auth/+/12345")
+                    .with_category(ActivityCategory::ChangesMerged)
+                    .with_project("auth")
+                    .build(),
+            ],
+        );
+
+        ReviewPacket {
+            employee: Employee {
+                name: "Jane Doe".to_string(),
+                title: "Engineer".to_string(),
+                committer_email: Some("jane@example.com".to_string()),
+                ..Default::default()
+            },
+            period_days: 30,
+            cycle: None,
+            generated_on: "2024-02-01".to_string(),
+            platforms: vec![
+                PlatformSection {
+                    name: "Gerrit".to_string(),
+                    activities: Ok(activities),
+                    previous_period: Some(PeriodCounts::from([(
+                        ActivityCategory::ChangesMerged,
+                        3,
+                    )])),
+                },
+                PlatformSection {
+                    name: "JIRA".to_string(),
+                    activities: Err("connection refused".to_string()),
+                    previous_period: None,
+                },
+            ],
+            goals: Vec::new(),
+            notes: Some("# Notes for Jane Doe\n\n## 2024-01-15\nGreat review\n".to_string()),
+            size_buckets: false,
+            compare: false,
+            timestamps: TimestampFormat::new(DisplayZone::Utc, false),
+        }
+    }
+
+    #[test]
+    fn test_markdown_sections() {
+        let markdown = packet().to_markdown();
+
+        assert!(markdown.starts_with("# Review Packet: Jane Doe\n"));
+        assert!(markdown.contains("| Gerrit | Changes Merged | 1 |"));
+        assert!(markdown.contains("| JIRA | _failed to load_ | - |"));
+        assert!(markdown.contains("| **Total** | | **1** |"));
+        assert!(markdown.contains("### Changes Merged (1)"));
+        assert!(markdown.contains(
+            "- [12345](https://gerrit.example.com/c/auth/+/12345) Fix login bug — auth (MERGED, updated 2024-01-15 16:45 UTC)"
+        ));
+        assert!(markdown.contains("_Failed to load: connection refused_"));
+        // Notes sections are nested one level below the packet's "## Notes"
+        assert!(markdown.contains(
+            "## Notes\n\n_1 of 1 dated entries fall in the last 30 days._\n\n### 2024-01-15\nGreat review"
+        ));
+        assert!(!markdown.contains("# Notes for Jane Doe"));
+    }
+
+    #[test]
+    fn test_failed_categories_are_not_shown_as_zero() {
+        let mut packet = packet();
+        let Ok(activities) = &mut packet.platforms[0].activities else {
+            unreachable!()
+        };
+        activities.insert_result(
+            ActivityCategory::ReviewsGiven,
+            Err(io::Error::other("HTTP 403")),
+        );
+
+        let markdown = packet.to_markdown();
+        assert!(markdown.contains("| Gerrit | Reviews Given | _failed to load_ |"));
+        assert!(markdown.contains("_Reviews Given failed to load: HTTP 403_"));
+        assert!(markdown.contains("| **Total** | | **1** |"));
+
+        let text = packet.to_email_text(1);
+        assert!(text.contains("Gerrit\n  Reviews Given: failed to load (HTTP 403)\n"));
+        let html = packet.to_email_html(1);
+        assert!(html.contains("<li>Reviews Given: failed to load (HTTP 403)</li>"));
+
+        // A platform whose only category failed has no "no activity" line
+        let Ok(activities) = &mut packet.platforms[0].activities else {
+            unreachable!()
+        };
+        activities.items_by_category.clear();
+        let markdown = packet.to_markdown();
+        assert!(!markdown.contains("No activity in this period."));
+    }
+
+    #[test]
+    fn test_cycle_packet() {
+        let mut packet = packet();
+        packet.cycle = Some(ReviewCycle {
+            name: "2024-Q1".to_string(),
+            start: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            end: Some(NaiveDate::from_ymd_opt(2024, 1, 10).unwrap()),
+        });
+        packet.notes = Some(
+            "# Notes for Jane Doe\n\n## Goals\nship it\n\n## 2023-12-20\nOld\n\n\
+             ## 2024-01-05\nKickoff\n\n## 2024-01-12 [2024-Q1]\nLate write-up\n\n\
+             ## 2024-01-20\nNext cycle\n"
+                .to_string(),
+        );
+        packet.limit_to_cycle();
+        let markdown = packet.to_markdown();
+
+        assert!(
+            markdown
+                .contains("Engineer · jane@example.com · cycle 2024-Q1 (2024-01-01 to 2024-01-10)")
+        );
+        // The merged change was last updated after the cycle ended
+        assert!(!markdown.contains("Fix login bug"));
+        assert!(markdown.contains(
+            "_2 of 4 dated entries belong to cycle 2024-Q1._\n\n### 2024-01-05\n\nKickoff\n\n\
+             ### 2024-01-12 [2024-Q1]\n\nLate write-up\n"
+        ));
+        assert!(!markdown.contains("ship it"));
+        assert!(!markdown.contains("Next cycle"));
+    }
+
+    #[test]
+    fn test_goals_progress_since_period_start() {
+        let mut packet = packet();
+        let day = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let mut tracked = Goal {
+            title: "Ship auth rewrite".to_string(),
+            target_date: Some(day(3, 31)),
+            evidence: vec!["https://docs.example.com/auth".to_string()],
+            ..Default::default()
+        };
+        tracked.set_status(GoalStatus::OnTrack, day(1, 1));
+        tracked.set_status(GoalStatus::AtRisk, day(1, 10));
+        tracked.set_status(GoalStatus::OnTrack, day(1, 20));
+        let mut added = Goal {
+            title: "Mentor Sam".to_string(),
+            ..Default::default()
+        };
+        added.set_status(GoalStatus::Done, day(1, 25));
+        let legacy = Goal {
+            title: "Write docs".to_string(),
+            ..Default::default()
+        };
+        packet.goals = GoalsService::goal_outcomes(&[tracked, added, legacy], []);
+        let markdown = packet.to_markdown();
+
+        // The period is the 30 days after 2024-01-02
+        assert!(markdown.contains(
+            "- **Ship auth rewrite** — on-track, due 2024-03-31 · 0% (0 completed, 0 open)\n  \
+             - Since 2024-01-03: on-track → on-track (2024-01-10 at-risk, 2024-01-20 on-track)\n  \
+             - Evidence: <https://docs.example.com/auth>\n"
+        ));
+        assert!(markdown.contains(
+            "- **Mentor Sam** — done · 100% (0 completed, 0 open)\n  \
+             - Added since 2024-01-03 (2024-01-25 done)\n"
+        ));
+        assert!(markdown.contains("- **Write docs** — on-track · 0% (0 completed, 0 open)\n\n"));
+
+        packet.cycle = Some(ReviewCycle {
+            name: "2024-Q1".to_string(),
+            start: day(1, 15),
+            end: None,
+        });
+        let markdown = packet.to_markdown();
+        assert!(
+            markdown.contains("  - Since 2024-01-15: at-risk → on-track (2024-01-20 on-track)\n")
+        );
+    }
+
+    #[test]
+    fn test_redacted_packet() {
+        let mut packet = packet();
+        let Ok(activities) = &mut packet.platforms[0].activities else {
+            unreachable!()
+        };
+        let item = &mut activities
+            .items_by_category
+            .get_mut(&ActivityCategory::ChangesMerged)
+            .unwrap()[0];
+        item.title = "Fix login bug reported by Ann Lee".to_string();
+        item.metadata
+            .insert("reviewers".to_string(), "Ann Lee".to_string());
+        packet.notes = Some(
+            "# Notes for Jane Doe\n\n## 2024-01-15\nJane Doe paired with ann lee on \
+             https://gerrit.example.com/c/auth/+/12345, mail jane@example.com\n"
+                .to_string(),
+        );
+
+        let anonymizer = Anonymizer::new("salt", vec!["gerrit.example.com".to_string()]);
+        let jane = anonymizer.pseudonym("Jane Doe");
+        let ann = anonymizer.pseudonym("Ann Lee");
+        packet.redact(&anonymizer);
+        let markdown = packet.to_markdown();
+
+        assert!(markdown.starts_with(&format!("# Review Packet: {jane}\n")));
+        assert!(markdown.contains("Engineer · no email · last 30 days"));
+        assert!(markdown.contains(&format!("- 12345 Fix login bug reported by {ann} — auth")));
+        assert!(markdown.contains(&format!(
+            "{jane} paired with {ann} on [internal link], mail {jane}"
+        )));
+        assert!(!markdown.contains("Jane Doe"));
+        assert!(!markdown.contains("gerrit.example.com"));
+    }
+
+    #[test]
+    fn test_markdown_size_buckets() {
+        let mut packet = packet();
+        assert!(!packet.to_markdown().contains("Authored changes by size"));
+
+        packet.size_buckets = true;
+        let Ok(activities) = &mut packet.platforms[0].activities else {
+            unreachable!()
+        };
+        let mut change = activities.items_by_category[&ActivityCategory::ChangesMerged][0].clone();
+        change.category = ActivityCategory::ChangesCreated;
+        change
+            .metadata
+            .insert("insertions".to_string(), "120".to_string());
+        change
+            .metadata
+            .insert("deletions".to_string(), "5".to_string());
+        activities
+            .items_by_category
+            .insert(ActivityCategory::ChangesCreated, vec![change]);
+
+        let markdown = packet.to_markdown();
+        assert!(markdown.contains("Authored changes by size: XS 0 · S 0 · M 1 · L 0 _(XS < 10"));
+    }
+
+    #[test]
+    fn test_compare_with_previous_period() {
+        let mut packet = packet();
+        assert!(!packet.to_markdown().contains("Compared to"));
+        assert!(!packet.to_email_text(3).contains("Compared to"));
+
+        packet.compare = true;
+        let markdown = packet.to_markdown();
+        assert!(markdown.contains(
+            "### Compared to the previous 30 days\n\n| Category | Before | Now | Change |\n|---|---|---|---|\n| Changes Merged | 3 | 1 | ↓2 |\n"
+        ));
+        let text = packet.to_email_text(3);
+        assert!(text.ends_with(
+            "Total: 1 items\n\nCompared to the prior 30 days:\n  Changes Merged: 1, ↓2 vs prior 30 days\n"
+        ));
+        let html = packet.to_email_html(3);
+        assert!(html.contains("<li>Changes Merged: 1, ↓2 vs prior 30 days</li>"));
+    }
+
+    #[test]
+    fn test_email_summary() {
+        let mut packet = packet();
+        let Ok(activities) = &mut packet.platforms[0].activities else {
+            unreachable!()
+        };
+        let merged = activities
+            .items_by_category
+            .get_mut(&ActivityCategory::ChangesMerged)
+            .unwrap();
+        let mut newer = merged[0].clone();
+        newer.id = "12346".to_string();
+        newer.title = "Add <oauth> & SSO".to_string();
+        newer.updated = "2024-01-20T09:00:00Z".to_string();
+        newer.url = String::new();
+        merged.push(newer);
+
+        let text = packet.to_email_text(1);
+        assert!(text.starts_with(
+            "Activity summary for Jane Doe (Engineer), last 30 days up to 2024-02-01\n\n"
+        ));
+        assert!(text.contains(
+            "Gerrit\n  Changes Merged: 2\n    - Add <oauth> & SSO (auth, MERGED)\n    … and 1 more\n"
+        ));
+        assert!(text.contains("JIRA: failed to load (connection refused)"));
+        assert!(text.ends_with("Total: 2 items\n"));
+        // Notes are for the manager only
+        assert!(!text.contains("Great review"));
+
+        let html = packet.to_email_html(2);
+        assert!(html.contains("<li>Add &lt;oauth&gt; &amp; SSO (auth, MERGED)</li>"));
+        assert!(html.contains(
+            "<li><a href=\"https://gerrit.example.com/c/auth/+/12345\">Fix login bug</a> (auth, MERGED)</li>"
+        ));
+        assert!(html.contains("<p><b>JIRA</b>: failed to load (connection refused)</p>"));
+        assert!(!html.contains("more"));
+
+        packet.employee.notes_only = true;
+        assert!(
+            packet
+                .to_email_text(3)
+                .contains("Activity aggregation is disabled for this employee")
+        );
+    }
+
+    #[test]
+    fn test_markdown_respects_notes_only() {
+        let mut packet = packet();
+        packet.employee.notes_only = true;
+        packet.platforms.clear();
+        let markdown = packet.to_markdown();
+
+        assert!(markdown.contains("Activity aggregation is disabled for this employee"));
+        assert!(!markdown.contains("| Platform | Category | Items |"));
+        assert!(markdown.contains("### 2024-01-15\nGreat review"));
+    }
+}
@@ -0,0 +1,183 @@
+//! The optional `[scoring]` section: a weighted sum over activity categories, the "activity
+//! index", giving one sortable number per employee. It is a heuristic for ordering a team at a
+//! glance, not a measure of performance.
+
+use crate::platform::ActivityCategory;
+use crate::team::{TeamComparison, TeamMember};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// How category counts are scaled before they are weighted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Normalization {
+    /// Counts as they are
+    #[default]
+    None,
+    /// Counts per 30 days present, so indexes over different periods and around leave compare
+    PerMonth,
+    /// Each count as a percentage of the highest count anyone compared has in that category
+    TeamMax,
+}
+
+/// Weights per category; the index is off while no weight is set
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoringConfig {
+    /// Category display name (e.g. `Reviews Given`, any case) -> weight; others count 0
+    #[serde(default)]
+    pub weights: BTreeMap<String, f64>,
+    #[serde(default)]
+    pub normalization: Normalization,
+}
+
+impl ScoringConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.weights.is_empty()
+    }
+
+    /// Used to leave the section out of `config.toml` when nothing is configured
+    pub fn is_default(&self) -> bool {
+        self == &Self::default()
+    }
+
+    pub fn weight(&self, category: &ActivityCategory) -> f64 {
+        self.weights
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(category.display_name()))
+            .map(|(_, weight)| *weight)
+            .unwrap_or_default()
+    }
+
+    /// The activity index of one member over a period of `days`, less the days they were out of
+    /// office. `team_max` holds the highest count per category among everyone compared and is
+    /// only used by [`Normalization::TeamMax`].
+    pub fn index(
+        &self,
+        member: &TeamMember,
+        team_max: &HashMap<ActivityCategory, usize>,
+        days: u32,
+    ) -> f64 {
+        let days_present = days.saturating_sub(member.days_absent).max(1);
+        member
+            .counts
+            .iter()
+            .map(|(category, &count)| {
+                let value = match self.normalization {
+                    Normalization::None => count as f64,
+                    Normalization::PerMonth => count as f64 * 30.0 / days_present as f64,
+                    Normalization::TeamMax => match team_max.get(category) {
+                        Some(&max) if max > 0 => count as f64 * 100.0 / max as f64,
+                        _ => 0.0,
+                    },
+                };
+                value * self.weight(category)
+            })
+            .sum()
+    }
+
+    /// Set the activity index of every member of `team`; nothing changes when scoring is off
+    pub fn apply(&self, team: &mut TeamComparison, days: u32) {
+        if !self.is_enabled() {
+            return;
+        }
+        let mut team_max: HashMap<ActivityCategory, usize> = HashMap::new();
+        for member in &team.members {
+            for (category, &count) in &member.counts {
+                let max = team_max.entry(category.clone()).or_default();
+                *max = (*max).max(count);
+            }
+        }
+        for member in &mut team.members {
+            member.activity_index = Some(self.index(member, &team_max, days));
+        }
+    }
+}
+
+/// An index rounded for display, e.g. `42.5`
+pub fn format_index(index: f64) -> String {
+    format!("{index:.1}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(name: &str, counts: &[(ActivityCategory, usize)]) -> TeamMember {
+        TeamMember {
+            name: name.to_string(),
+            counts: counts.iter().cloned().collect(),
+            ..TeamMember::default()
+        }
+    }
+
+    fn indexes(config: &ScoringConfig, days: u32) -> Vec<Option<f64>> {
+        let mut team = team();
+        config.apply(&mut team, days);
+        team.members
+            .iter()
+            .map(|member| member.activity_index)
+            .collect()
+    }
+
+    fn team() -> TeamComparison {
+        TeamComparison {
+            members: vec![
+                member(
+                    "Alice",
+                    &[
+                        (ActivityCategory::ChangesMerged, 4),
+                        (ActivityCategory::ReviewsGiven, 10),
+                    ],
+                ),
+                member(
+                    "Bob",
+                    &[
+                        (ActivityCategory::ChangesMerged, 8),
+                        (ActivityCategory::IssuesResolved, 3),
+                    ],
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_weighted_index() {
+        let config: ScoringConfig = toml::from_str(
+            r#"
+            [weights]
+            "changes merged" = 2.0
+            "Reviews Given" = 0.5
+            "#,
+        )
+        .unwrap();
+        assert!(config.is_enabled());
+        // Issues Resolved has no weight
+        assert_eq!(indexes(&config, 30), vec![Some(13.0), Some(16.0)]);
+
+        let per_month = ScoringConfig {
+            normalization: Normalization::PerMonth,
+            ..config.clone()
+        };
+        assert_eq!(indexes(&per_month, 60)[0], Some(6.5));
+        // Days out of office do not count towards the period
+        let mut absent = team();
+        absent.set_days_absent("Alice", 30);
+        per_month.apply(&mut absent, 60);
+        assert_eq!(absent.members[0].activity_index, Some(13.0));
+
+        let team_max = ScoringConfig {
+            normalization: Normalization::TeamMax,
+            ..config
+        };
+        assert_eq!(indexes(&team_max, 30), vec![Some(150.0), Some(200.0)]);
+        assert_eq!(format_index(150.0), "150.0");
+    }
+
+    #[test]
+    fn test_disabled_without_weights() {
+        let config = ScoringConfig::default();
+        assert!(!config.is_enabled());
+        assert!(config.is_default());
+        assert_eq!(indexes(&config, 30), vec![None, None]);
+    }
+}
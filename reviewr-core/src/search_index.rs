@@ -0,0 +1,556 @@
+//! Offline full-text search over cached activity items and notes entries of every employee.
+//!
+//! An inverted index from lowercase words to the documents containing them is kept in
+//! `cache/search_index.json`. It is rebuilt whenever a cache entry or notes file changed since it
+//! was built, so searching never queries a platform. Encrypted notes are not indexed: their text
+//! must not end up in a plain file.
+
+use crate::cache::ActivityCache;
+use crate::employee::EmployeeService;
+use crate::models::DataPath;
+use crate::notes::NotesService;
+use crate::platform::{ActivityItem, DetailedActivities};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, UNIX_EPOCH};
+
+/// Bump when the stored layout changes, so older index files are rebuilt
+const INDEX_VERSION: u32 = 2;
+
+/// A word in the title counts this many times as one in the body
+const TITLE_WEIGHT: u32 = 3;
+
+/// Snippets of notes are cut to this many characters
+const SNIPPET_LENGTH: usize = 120;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DocumentKind {
+    Item,
+    Note,
+}
+
+/// One searchable activity item or dated notes entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Document {
+    pub kind: DocumentKind,
+    pub employee: String,
+    /// Platform id of an item, `notes` for a notes entry
+    pub source: String,
+    /// Item id, or the heading of a notes entry
+    pub id: String,
+    pub title: String,
+    /// Categories, project and status of an item; empty for notes
+    pub detail: String,
+    /// Empty for notes
+    pub url: String,
+    /// `YYYY-MM-DD` the item was last updated or the entry was written
+    pub date: String,
+    /// Body of a notes entry; label and metadata text of an item
+    pub body: String,
+}
+
+/// A document matching every word of a query
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub score: u32,
+    #[serde(flatten)]
+    pub document: Document,
+    /// The first line of a notes entry mentioning a query word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+/// Size and modification time of a file the index was built from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct SourceFile {
+    path: PathBuf,
+    len: u64,
+    modified_nanos: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    version: u32,
+    sources: Vec<SourceFile>,
+    documents: Vec<Document>,
+    /// Word → (document index, weight) sorted by document index
+    postings: BTreeMap<String, Vec<(u32, u32)>>,
+}
+
+/// Lowercase words of `text`, split at anything that is not a letter or digit
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+impl SearchIndex {
+    /// Index `documents`; `sources` tell later whether it is out of date
+    fn new(documents: Vec<Document>, sources: Vec<SourceFile>) -> Self {
+        let mut postings: BTreeMap<String, Vec<(u32, u32)>> = BTreeMap::new();
+        for (index, document) in documents.iter().enumerate() {
+            let mut weights: HashMap<String, u32> = HashMap::new();
+            for word in tokenize(&document.title).chain(tokenize(&document.id)) {
+                *weights.entry(word).or_default() += TITLE_WEIGHT;
+            }
+            let body = [&document.detail, &document.body];
+            for word in body.iter().flat_map(|text| tokenize(text)) {
+                *weights.entry(word).or_default() += 1;
+            }
+            for (word, weight) in weights {
+                postings
+                    .entry(word)
+                    .or_default()
+                    .push((index as u32, weight));
+            }
+        }
+        Self {
+            version: INDEX_VERSION,
+            sources,
+            documents,
+            postings,
+        }
+    }
+
+    pub fn documents(&self) -> &[Document] {
+        &self.documents
+    }
+
+    /// Documents containing every word of `query`, best first. A query word also matches longer
+    /// words it starts with, scoring half as much as an exact match. `employee` (case-insensitive)
+    /// restricts the hits to one employee.
+    pub fn search(&self, query: &str, employee: Option<&str>, limit: usize) -> Vec<SearchHit> {
+        let words: Vec<String> = tokenize(query).collect();
+        if words.is_empty() {
+            return Vec::new();
+        }
+
+        let mut scores: Option<HashMap<u32, u32>> = None;
+        for word in &words {
+            let mut matches: HashMap<u32, u32> = HashMap::new();
+            for (term, postings) in self
+                .postings
+                .range(word.clone()..)
+                .take_while(|(term, _)| term.starts_with(word.as_str()))
+            {
+                let factor = if term == word { 2 } else { 1 };
+                for &(document, weight) in postings {
+                    *matches.entry(document).or_default() += weight * factor;
+                }
+            }
+            scores = Some(match scores {
+                None => matches,
+                Some(scores) => scores
+                    .into_iter()
+                    .filter_map(|(document, score)| {
+                        matches
+                            .get(&document)
+                            .map(|extra| (document, score + extra))
+                    })
+                    .collect(),
+            });
+        }
+
+        let mut hits: Vec<SearchHit> = scores
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(index, score)| (&self.documents[index as usize], score))
+            .filter(|(document, _)| {
+                employee.is_none_or(|employee| document.employee.eq_ignore_ascii_case(employee))
+            })
+            .map(|(document, score)| SearchHit {
+                score,
+                snippet: (document.kind == DocumentKind::Note)
+                    .then(|| snippet(&document.body, &words))
+                    .flatten(),
+                document: document.clone(),
+            })
+            .collect();
+        hits.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| b.document.date.cmp(&a.document.date))
+                .then_with(|| a.document.employee.cmp(&b.document.employee))
+                .then_with(|| a.document.title.cmp(&b.document.title))
+        });
+        hits.truncate(limit);
+        hits
+    }
+}
+
+/// The first line of `body` containing one of `words`, shortened to [`SNIPPET_LENGTH`]
+fn snippet(body: &str, words: &[String]) -> Option<String> {
+    let line = body.lines().map(str::trim).find(|line| {
+        let line = line.to_lowercase();
+        words.iter().any(|word| line.contains(word.as_str()))
+    })?;
+    if line.chars().count() <= SNIPPET_LENGTH {
+        return Some(line.to_string());
+    }
+    let short: String = line.chars().take(SNIPPET_LENGTH - 1).collect();
+    Some(format!("{short}…"))
+}
+
+/// One document per item and employee; an item listed in several categories or cached for
+/// several periods is indexed once, from its most recently updated copy
+fn item_documents(entries: Vec<(String, String, DetailedActivities)>) -> Vec<Document> {
+    let mut items: BTreeMap<(String, String, String), (ActivityItem, Vec<String>)> =
+        BTreeMap::new();
+    for (employee, platform_id, activities) in entries {
+        for item in activities.items_by_category.into_values().flatten() {
+            let key = (employee.clone(), platform_id.clone(), item.id.clone());
+            let category = item.category.display_name().to_string();
+            match items.get_mut(&key) {
+                Some((stored, categories)) => {
+                    if !categories.contains(&category) {
+                        categories.push(category);
+                    }
+                    if item.updated > stored.updated {
+                        *stored = item;
+                    }
+                }
+                None => {
+                    items.insert(key, (item, vec![category]));
+                }
+            }
+        }
+    }
+
+    items
+        .into_iter()
+        .map(|((employee, source, _), (item, mut categories))| {
+            categories.sort();
+            let mut detail = categories.join(", ");
+            for part in [&item.project, &item.status] {
+                if !part.is_empty() {
+                    detail.push_str(" · ");
+                    detail.push_str(part);
+                }
+            }
+            let mut metadata: Vec<&String> = item
+                .metadata
+                .values()
+                .filter(|value| !value.starts_with("http"))
+                .collect();
+            metadata.sort();
+            Document {
+                kind: DocumentKind::Item,
+                employee,
+                source,
+                id: item.id.clone(),
+                title: item.title.clone(),
+                detail,
+                url: item.url.clone(),
+                date: item.updated.chars().take(10).collect(),
+                body: metadata
+                    .into_iter()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            }
+        })
+        .collect()
+}
+
+/// Plain `notes/*.md` files by employee name
+fn notes_files(data_path: &DataPath) -> io::Result<Vec<(String, PathBuf)>> {
+    let entries = match fs::read_dir(&data_path.notes_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut files: Vec<(String, PathBuf)> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .filter_map(|path| Some((path.file_stem()?.to_str()?.to_string(), path)))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+fn source_file(path: &Path) -> Option<SourceFile> {
+    let metadata = fs::metadata(path).ok()?;
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .unwrap_or(Duration::ZERO);
+    Some(SourceFile {
+        path: path.to_path_buf(),
+        len: metadata.len(),
+        modified_nanos: modified.as_nanos() as u64,
+    })
+}
+
+pub struct SearchIndexService;
+
+impl SearchIndexService {
+    fn index_path(data_path: &DataPath) -> PathBuf {
+        data_path.root.join("cache").join("search_index.json")
+    }
+
+    /// The files an index is built from: every activity cache entry and plain notes file
+    fn sources(data_path: &DataPath) -> io::Result<Vec<SourceFile>> {
+        let cache = ActivityCache::new(data_path, Duration::ZERO);
+        let mut paths = cache.entry_files()?;
+        paths.extend(notes_files(data_path)?.into_iter().map(|(_, path)| path));
+        // A changed data policy changes which cached items may be indexed
+        paths.extend(
+            EmployeeService::list_employees(data_path)?
+                .iter()
+                .map(|employee| EmployeeService::employee_path(data_path, employee)),
+        );
+        Ok(paths.iter().filter_map(|path| source_file(path)).collect())
+    }
+
+    /// The stored index, rebuilt first when activity was fetched or notes were edited since
+    pub fn load_or_build(data_path: &DataPath) -> io::Result<SearchIndex> {
+        let sources = Self::sources(data_path)?;
+        let path = Self::index_path(data_path);
+        if let Ok(content) = fs::read_to_string(&path) {
+            match serde_json::from_str::<SearchIndex>(&content) {
+                Ok(index) if index.version == INDEX_VERSION && index.sources == sources => {
+                    return Ok(index);
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Rebuilding unreadable {}: {e}", path.display()),
+            }
+        }
+        Self::build(data_path, sources)
+    }
+
+    /// Index everything again and store the result, whether or not anything changed
+    pub fn rebuild(data_path: &DataPath) -> io::Result<SearchIndex> {
+        let sources = Self::sources(data_path)?;
+        Self::build(data_path, sources)
+    }
+
+    fn build(data_path: &DataPath, sources: Vec<SourceFile>) -> io::Result<SearchIndex> {
+        let cache = ActivityCache::new(data_path, Duration::ZERO);
+        let mut documents = item_documents(EmployeeService::allowed_cache_entries(
+            data_path,
+            cache.all_entries()?,
+        ));
+        for (employee, path) in notes_files(data_path)? {
+            let content = match fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(e) => {
+                    warn!("Not indexing {}: {e}", path.display());
+                    continue;
+                }
+            };
+            documents.extend(
+                NotesService::parse_entries(&content)
+                    .into_iter()
+                    .map(|entry| Document {
+                        kind: DocumentKind::Note,
+                        employee: employee.clone(),
+                        source: "notes".to_string(),
+                        id: entry.heading(),
+                        title: entry.title.clone(),
+                        detail: String::new(),
+                        url: String::new(),
+                        date: entry.date.format("%Y-%m-%d").to_string(),
+                        body: entry.body,
+                    }),
+            );
+        }
+
+        let index = SearchIndex::new(documents, sources);
+        let path = Self::index_path(data_path);
+        let stored =
+            fs::create_dir_all(path.parent().expect("index path has a parent")).and_then(|()| {
+                let json = serde_json::to_string(&index).map_err(io::Error::other)?;
+                fs::write(&path, json)
+            });
+        match stored {
+            Ok(()) => info!("Indexed {} documents for search", index.documents.len()),
+            Err(e) => warn!("Failed to store {}: {e}", path.display()),
+        }
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::ActivityCategory;
+    use crate::test_support::ItemBuilder;
+    use tempfile::tempdir;
+
+    fn item(id: &str, title: &str, category: ActivityCategory, updated: &str) -> ActivityItem {
+        ItemBuilder::new(id)
+            .with_title(title)
+            .with_category(category)
+            .with_updated(updated)
+            .with_url(&format!("https://gerrit.example.com/c/{id}"))
+            .with_project("infra/ci")
+            .with_metadata("hashtags", "flaky-tests")
+            .build()
+    }
+
+    fn activities(items: Vec<ActivityItem>) -> DetailedActivities {
+        let mut activities = DetailedActivities::default();
+        for item in items {
+            activities
+                .items_by_category
+                .entry(item.category.clone())
+                .or_default()
+                .push(item);
+        }
+        activities
+    }
+
+    fn employee(data_path: &DataPath, name: &str, policy: &str) {
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        fs::write(
+            EmployeeService::employee_path(data_path, name),
+            format!("name = \"{name}\"\ntitle = \"Engineer\"\n{policy}"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_items_and_notes_are_found_offline() {
+        let dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(dir.path().to_path_buf())).unwrap();
+        employee(&data_path, "Jane Doe", "");
+        let cache = ActivityCache::new(&data_path, Duration::from_secs(3600));
+        let fix = "Fix flaky login test";
+        cache
+            .store(
+                "Jane Doe",
+                "gerrit",
+                30,
+                &activities(vec![
+                    item("101", fix, ActivityCategory::ChangesCreated, "2024-03-01"),
+                    item("101", fix, ActivityCategory::ChangesMerged, "2024-03-02"),
+                    item(
+                        "102",
+                        "Bump CI image",
+                        ActivityCategory::ChangesMerged,
+                        "2024-03-05",
+                    ),
+                ]),
+            )
+            .unwrap();
+        cache
+            .store(
+                "Jane Doe",
+                "gerrit",
+                90,
+                &activities(vec![item(
+                    "101",
+                    fix,
+                    ActivityCategory::ChangesMerged,
+                    "2024-02-20",
+                )]),
+            )
+            .unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        fs::write(
+            data_path.notes_dir.join("Sam Lee.md"),
+            "# Notes\n\n## 2024-03-04 1:1\n\nPaired with Jane on the login rewrite.\nGood progress.\n",
+        )
+        .unwrap();
+
+        let index = SearchIndexService::load_or_build(&data_path).unwrap();
+        // Change 101 is indexed once although it is cached in two categories and two periods
+        assert_eq!(index.documents().len(), 3);
+
+        let hits = index.search("LOGIN", None, 10);
+        assert_eq!(hits.len(), 2);
+        // The title match ranks before the notes body match
+        assert_eq!(hits[0].document.id, "101");
+        assert_eq!(hits[0].document.date, "2024-03-02");
+        assert_eq!(
+            hits[0].document.detail,
+            "Changes Created, Changes Merged · infra/ci · MERGED"
+        );
+        assert_eq!(hits[1].document.kind, DocumentKind::Note);
+        assert_eq!(hits[1].document.employee, "Sam Lee");
+        assert_eq!(
+            hits[1].snippet.as_deref(),
+            Some("Paired with Jane on the login rewrite.")
+        );
+
+        // Every word must match; a word also matches longer words starting with it
+        assert_eq!(index.search("flak login", None, 10).len(), 1);
+        assert_eq!(index.search("flaky", None, 10).len(), 2);
+        assert!(index.search("login deploy", None, 10).is_empty());
+        assert!(index.search("  ", None, 10).is_empty());
+        assert_eq!(index.search("login", Some("sam lee"), 10).len(), 1);
+        assert_eq!(index.search("login", None, 1).len(), 1);
+    }
+
+    #[test]
+    fn test_data_policy_keeps_items_out_of_the_index() {
+        let dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(dir.path().to_path_buf())).unwrap();
+        employee(&data_path, "Jane Doe", "exclude_platforms = [\"jira\"]\n");
+        employee(&data_path, "Sam Lee", "notes_only = true\n");
+        let cache = ActivityCache::new(&data_path, Duration::from_secs(3600));
+        let secret = |id: &str| {
+            activities(vec![item(
+                id,
+                "Secret migration",
+                ActivityCategory::ChangesMerged,
+                "2024-03-01",
+            )])
+        };
+        cache.store("Jane Doe", "gerrit", 30, &secret("1")).unwrap();
+        cache.store("Jane Doe", "jira", 30, &secret("2")).unwrap();
+        cache.store("Sam Lee", "gerrit", 30, &secret("3")).unwrap();
+        // Cached before the employee was removed
+        cache
+            .store("Former Colleague", "gerrit", 30, &secret("4"))
+            .unwrap();
+
+        let index = SearchIndexService::load_or_build(&data_path).unwrap();
+        let hits = index.search("secret", None, 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].document.id, "1");
+
+        // Changing the policy rebuilds the index
+        employee(&data_path, "Jane Doe", "notes_only = true\n");
+        let index = SearchIndexService::load_or_build(&data_path).unwrap();
+        assert!(index.search("secret", None, 10).is_empty());
+    }
+
+    #[test]
+    fn test_index_is_rebuilt_when_sources_change() {
+        let dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        let notes = data_path.notes_dir.join("Jane Doe.md");
+        fs::write(&notes, "## 2024-03-04\n\nDiscussed the roadmap.\n").unwrap();
+        // Encrypted notes stay out of the index
+        fs::write(data_path.notes_dir.join("Sam Lee.md.age"), [1u8, 2, 3]).unwrap();
+
+        let index = SearchIndexService::load_or_build(&data_path).unwrap();
+        assert_eq!(index.search("roadmap", None, 10).len(), 1);
+        assert!(SearchIndexService::index_path(&data_path).exists());
+
+        fs::write(
+            &notes,
+            "## 2024-03-04\n\nDiscussed the roadmap.\n\n## 2024-03-11\n\nRoadmap approved.\n",
+        )
+        .unwrap();
+        let index = SearchIndexService::load_or_build(&data_path).unwrap();
+        let hits = index.search("roadmap", None, 10);
+        assert_eq!(hits.len(), 2);
+        // Equal scores: the most recent entry first
+        assert_eq!(hits[0].document.date, "2024-03-11");
+        assert_eq!(hits[0].snippet.as_deref(), Some("Roadmap approved."));
+
+        fs::remove_file(&notes).unwrap();
+        let index = SearchIndexService::load_or_build(&data_path).unwrap();
+        assert!(index.documents().is_empty());
+    }
+}
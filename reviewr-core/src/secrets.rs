@@ -0,0 +1,410 @@
+//! Platform credentials in the OS keyring, with the plaintext `config.toml` values as fallback
+
+use crate::employee_import::LdapConfig;
+use crate::models::DataPath;
+use crate::unified_config::{
+    DEFAULT_INSTANCE, PlatformConfigs, UnifiedConfigService, instance_platform_id,
+};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+
+/// Keyring service name every reviewr credential is stored under
+pub const KEYRING_SERVICE: &str = "reviewr";
+
+/// Keyring account of the `[integrations.ldap]` bind password
+pub const LDAP_ACCOUNT: &str = "ldap";
+
+/// Where platform tokens and passwords are read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecretBackendKind {
+    /// Plaintext values in `config.toml`
+    #[default]
+    Config,
+    /// The OS keyring, falling back to `config.toml` for platforms without a stored secret
+    Keyring,
+}
+
+impl SecretBackendKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecretBackendKind::Config => "config",
+            SecretBackendKind::Keyring => "keyring",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "config" => Some(SecretBackendKind::Config),
+            "keyring" => Some(SecretBackendKind::Keyring),
+            _ => None,
+        }
+    }
+}
+
+/// Storage for secrets keyed by account, the platform id (`gerrit`, `jira:<instance>`,
+/// `gitlab:<instance>`)
+pub trait SecretBackend: Send + Sync {
+    fn get(&self, account: &str) -> io::Result<Option<String>>;
+    fn set(&self, account: &str, secret: &str) -> io::Result<()>;
+}
+
+/// Lookups are cached for the process so the keyring is asked once per account
+static KEYRING_LOOKUPS: Mutex<Option<HashMap<String, Option<String>>>> = Mutex::new(None);
+
+/// The OS keyring through its command line tools: `secret-tool` (libsecret) on Linux and
+/// `security` (Keychain) on macOS
+pub struct KeyringBackend;
+
+impl KeyringBackend {
+    fn lookup(account: &str) -> io::Result<Option<String>> {
+        let output = if cfg!(target_os = "macos") {
+            Command::new("security")
+                .args(["find-generic-password", "-s", KEYRING_SERVICE])
+                .args(["-a", account, "-w"])
+                .stderr(Stdio::null())
+                .output()?
+        } else if cfg!(unix) {
+            Command::new("secret-tool")
+                .args(["lookup", "service", KEYRING_SERVICE, "account", account])
+                .stderr(Stdio::null())
+                .output()?
+        } else {
+            return Err(unsupported());
+        };
+        if !output.status.success() {
+            return Ok(None);
+        }
+        let secret = String::from_utf8_lossy(&output.stdout)
+            .trim_end_matches(['\r', '\n'])
+            .to_string();
+        Ok((!secret.is_empty()).then_some(secret))
+    }
+}
+
+/// A keyring tool run storing a secret. The secret goes to stdin, never onto the command line
+/// where other local users could read it from the process list.
+#[derive(Debug)]
+struct StoreCommand {
+    program: &'static str,
+    args: Vec<String>,
+    stdin: String,
+}
+
+impl StoreCommand {
+    /// `security -i` reads the `add-generic-password` command from stdin instead of argv
+    fn security(account: &str, secret: &str) -> Self {
+        let quote =
+            |value: &str| format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""));
+        Self {
+            program: "security",
+            args: vec!["-i".to_string()],
+            stdin: format!(
+                "add-generic-password -U -s {} -a {} -w {}\n",
+                quote(KEYRING_SERVICE),
+                quote(account),
+                quote(secret)
+            ),
+        }
+    }
+
+    /// `secret-tool store` reads the secret from stdin
+    fn secret_tool(account: &str, secret: &str) -> Self {
+        Self {
+            program: "secret-tool",
+            args: [
+                "store",
+                "--label",
+                &format!("reviewr {account}"),
+                "service",
+                KEYRING_SERVICE,
+                "account",
+                account,
+            ]
+            .map(str::to_string)
+            .to_vec(),
+            stdin: secret.to_string(),
+        }
+    }
+
+    fn run(&self) -> io::Result<std::process::ExitStatus> {
+        let mut child = Command::new(self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(self.stdin.as_bytes())?;
+        }
+        child.wait()
+    }
+}
+
+fn unsupported() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        "No OS keyring tool available on this platform; keep the secret in config.toml",
+    )
+}
+
+impl SecretBackend for KeyringBackend {
+    fn get(&self, account: &str) -> io::Result<Option<String>> {
+        let mut cache = KEYRING_LOOKUPS.lock().unwrap_or_else(|e| e.into_inner());
+        let cache = cache.get_or_insert_with(HashMap::new);
+        if let Some(secret) = cache.get(account) {
+            return Ok(secret.clone());
+        }
+        let secret = Self::lookup(account)?;
+        cache.insert(account.to_string(), secret.clone());
+        Ok(secret)
+    }
+
+    fn set(&self, account: &str, secret: &str) -> io::Result<()> {
+        let command = if cfg!(target_os = "macos") {
+            StoreCommand::security(account, secret)
+        } else if cfg!(unix) {
+            StoreCommand::secret_tool(account, secret)
+        } else {
+            return Err(unsupported());
+        };
+        let status = command.run()?;
+        if !status.success() {
+            return Err(io::Error::other(format!(
+                "Storing the secret for '{account}' in the OS keyring failed ({status})"
+            )));
+        }
+        let mut cache = KEYRING_LOOKUPS.lock().unwrap_or_else(|e| e.into_inner());
+        cache
+            .get_or_insert_with(HashMap::new)
+            .insert(account.to_string(), Some(secret.to_string()));
+        Ok(())
+    }
+}
+
+pub struct SecretService;
+
+impl SecretService {
+    /// Replace the secrets in `platforms` with the backend's values where one is stored.
+    /// Platforms without a stored secret keep their `config.toml` value.
+    pub fn resolve_platform_secrets(platforms: &mut PlatformConfigs, backend: &dyn SecretBackend) {
+        let resolve = |account: &str, value: &mut String| match backend.get(account) {
+            Ok(Some(secret)) => *value = secret,
+            Ok(None) => {}
+            Err(e) => warn!("Could not read the '{account}' secret from the keyring: {e}"),
+        };
+        for (instance, gerrit) in &mut platforms.gerrit {
+            resolve(
+                &instance_platform_id("gerrit", instance),
+                &mut gerrit.http_password,
+            );
+        }
+        for (instance, jira) in &mut platforms.jira {
+            resolve(&instance_platform_id("jira", instance), &mut jira.api_token);
+        }
+        for (instance, gitlab) in &mut platforms.gitlab {
+            resolve(&format!("gitlab:{instance}"), &mut gitlab.token);
+        }
+    }
+
+    /// Replace the LDAP bind password with the backend's value (account `ldap`) if one is stored
+    pub fn resolve_ldap_secret(ldap: &mut LdapConfig, backend: &dyn SecretBackend) {
+        match backend.get(LDAP_ACCOUNT) {
+            Ok(Some(secret)) => ldap.bind_password = secret,
+            Ok(None) => {}
+            Err(e) => warn!("Could not read the '{LDAP_ACCOUNT}' secret from the keyring: {e}"),
+        }
+    }
+
+    /// Blank every platform token and password, e.g. before sharing a configuration
+    pub fn strip_platform_secrets(platforms: &mut PlatformConfigs) {
+        for gerrit in platforms.gerrit.values_mut() {
+            gerrit.http_password.clear();
+        }
+        for jira in platforms.jira.values_mut() {
+            jira.api_token.clear();
+        }
+        for gitlab in platforms.gitlab.values_mut() {
+            gitlab.token.clear();
+        }
+    }
+
+    /// Store a platform secret in the backend, switch the config to the keyring and remove the
+    /// plaintext copy from `config.toml`
+    pub fn store_platform_secret(
+        data_path: &DataPath,
+        account: &str,
+        secret: &str,
+        backend: &dyn SecretBackend,
+    ) -> io::Result<()> {
+        if secret.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Secret cannot be empty",
+            ));
+        }
+        let mut config = UnifiedConfigService::load_config(data_path)?;
+        let ldap = &mut config.integrations.ldap;
+        let platforms = &mut config.platforms;
+        let (platform, instance) = account
+            .split_once(':')
+            .unwrap_or((account, DEFAULT_INSTANCE));
+        let plaintext = match platform {
+            "gerrit" => platforms
+                .gerrit
+                .get_mut(instance)
+                .map(|c| &mut c.http_password),
+            "jira" => platforms.jira.get_mut(instance).map(|c| &mut c.api_token),
+            "gitlab" if instance != DEFAULT_INSTANCE => {
+                platforms.gitlab.get_mut(instance).map(|c| &mut c.token)
+            }
+            LDAP_ACCOUNT if instance == DEFAULT_INSTANCE => {
+                ldap.as_mut().map(|c| &mut c.bind_password)
+            }
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "Unknown platform '{account}' (expected gerrit[:<instance>], jira[:<instance>], gitlab:<instance> or ldap)"
+                    ),
+                ));
+            }
+        };
+        let Some(plaintext) = plaintext else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("'{account}' is not configured in config.toml"),
+            ));
+        };
+
+        backend.set(account, secret)?;
+        plaintext.clear();
+        config.global_settings.secret_backend = SecretBackendKind::Keyring;
+        UnifiedConfigService::save_config(&config, data_path)?;
+        info!("Stored the '{account}' secret in the keyring");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gerrit::GerritConfig;
+    use crate::unified_config::{JiraConfig, RequestLimits, UnifiedConfig};
+    use tempfile::tempdir;
+
+    #[derive(Default)]
+    struct MemoryBackend {
+        secrets: Mutex<HashMap<String, String>>,
+    }
+
+    impl SecretBackend for MemoryBackend {
+        fn get(&self, account: &str) -> io::Result<Option<String>> {
+            Ok(self.secrets.lock().unwrap().get(account).cloned())
+        }
+
+        fn set(&self, account: &str, secret: &str) -> io::Result<()> {
+            self.secrets
+                .lock()
+                .unwrap()
+                .insert(account.to_string(), secret.to_string());
+            Ok(())
+        }
+    }
+
+    fn config() -> UnifiedConfig {
+        let mut config = UnifiedConfig::default();
+        config.platforms.gerrit.insert(
+            DEFAULT_INSTANCE.to_string(),
+            GerritConfig {
+                gerrit_url: "https://gerrit.example.com".to_string(),
+                username: "bot".to_string(),
+                http_password: "plain-gerrit".to_string(),
+                accept_invalid_certs: false,
+                limits: RequestLimits::default(),
+            },
+        );
+        config.platforms.jira.insert(
+            DEFAULT_INSTANCE.to_string(),
+            JiraConfig {
+                jira_url: "https://jira.example.com".to_string(),
+                username: "bot".to_string(),
+                api_token: "plain-jira".to_string(),
+                project_filter: Vec::new(),
+                custom_fields: HashMap::new(),
+                fields: Vec::new(),
+                lazy_details: false,
+                accept_invalid_certs: false,
+                limits: RequestLimits::default(),
+            },
+        );
+        config
+    }
+
+    #[test]
+    fn test_store_moves_secret_out_of_config() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        UnifiedConfigService::save_config(&config(), &data_path).unwrap();
+        let backend = MemoryBackend::default();
+
+        SecretService::store_platform_secret(&data_path, "jira", "kept-secret", &backend).unwrap();
+
+        let saved = UnifiedConfigService::load_config(&data_path).unwrap();
+        assert_eq!(
+            saved.global_settings.secret_backend,
+            SecretBackendKind::Keyring
+        );
+        assert!(saved.platforms.jira[DEFAULT_INSTANCE].api_token.is_empty());
+        let raw = std::fs::read_to_string(data_path.config_path()).unwrap();
+        assert!(!raw.contains("kept-secret"));
+
+        // The keyring wins; platforms without a stored secret keep the config value
+        let mut platforms = saved.platforms;
+        SecretService::resolve_platform_secrets(&mut platforms, &backend);
+        assert_eq!(platforms.jira[DEFAULT_INSTANCE].api_token, "kept-secret");
+        assert_eq!(
+            platforms.gerrit[DEFAULT_INSTANCE].http_password,
+            "plain-gerrit"
+        );
+    }
+
+    #[test]
+    fn test_keyring_commands_keep_the_secret_off_argv() {
+        let secret = "s3cr\"et with spaces";
+        for command in [
+            StoreCommand::security("jira_api_token", secret),
+            StoreCommand::secret_tool("jira_api_token", secret),
+        ] {
+            assert!(
+                command.args.iter().all(|arg| !arg.contains("s3cr")),
+                "{command:?}"
+            );
+            assert!(command.stdin.contains("s3cr"));
+        }
+        assert_eq!(
+            StoreCommand::security("jira_api_token", secret).stdin,
+            "add-generic-password -U -s \"reviewr\" -a \"jira_api_token\" -w \"s3cr\\\"et with spaces\"\n"
+        );
+    }
+
+    #[test]
+    fn test_store_rejects_unknown_or_unconfigured_platforms() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        UnifiedConfigService::save_config(&config(), &data_path).unwrap();
+        let backend = MemoryBackend::default();
+
+        let err =
+            SecretService::store_platform_secret(&data_path, "github", "x", &backend).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let err = SecretService::store_platform_secret(&data_path, "gitlab:work", "x", &backend)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(backend.secrets.lock().unwrap().is_empty());
+    }
+}
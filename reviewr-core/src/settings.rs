@@ -0,0 +1,639 @@
+//! Typed access to every option, resolved as command line flag > `REVIEWR_<KEY>` environment
+//! variable > `config.toml` > built-in default
+
+use crate::models::{DataPath, validate_domain};
+use crate::timestamps::DisplayZone;
+use crate::unified_config::{UnifiedConfig, UnifiedConfigService};
+use log::info;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+
+/// How `config set` reads a setting's value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingKind {
+    /// `true` or `false`
+    Bool,
+    /// A whole number above zero
+    Positive,
+    /// Whole minutes; 0 turns the feature off
+    Minutes,
+    /// Comma-separated values
+    List,
+    /// Comma-separated domains, each validated
+    Domains,
+    /// Free text; empty unsets the setting
+    Text,
+    /// A command line with shell quoting; empty unsets the setting
+    Command,
+    /// `local`, `UTC` or an offset such as `+02:00`
+    Timezone,
+    /// One of these names, ignoring case
+    Choice(&'static [&'static str]),
+}
+
+/// An option: its key, which is also what `config get/set` take, its `config.toml` section and
+/// how `config set` parses it
+#[derive(Debug, Clone, Copy)]
+pub struct Setting {
+    pub key: &'static str,
+    pub section: &'static str,
+    pub kind: SettingKind,
+}
+
+const fn setting(key: &'static str, section: &'static str, kind: SettingKind) -> Setting {
+    Setting { key, section, kind }
+}
+
+/// Every option
+pub const SETTINGS: &[Setting] = &[
+    setting("allowed_domains", "global_settings", SettingKind::Domains),
+    setting(
+        "enforce_allowed_domains",
+        "global_settings",
+        SettingKind::Bool,
+    ),
+    setting("clipboard_evidence", "global_settings", SettingKind::Bool),
+    setting("verbose_error_logs", "global_settings", SettingKind::Bool),
+    setting("redact_exports", "global_settings", SettingKind::Bool),
+    setting(
+        "http_max_attempts",
+        "global_settings",
+        SettingKind::Positive,
+    ),
+    setting(
+        "secret_backend",
+        "global_settings",
+        SettingKind::Choice(&["config", "keyring"]),
+    ),
+    setting("age_identity", "global_settings", SettingKind::Text),
+    setting("http_proxy", "global_settings", SettingKind::Text),
+    setting("ca_bundle_path", "global_settings", SettingKind::Text),
+    setting(
+        "max_concurrent_platforms",
+        "performance",
+        SettingKind::Positive,
+    ),
+    setting(
+        "max_concurrent_requests",
+        "performance",
+        SettingKind::Positive,
+    ),
+    setting("page_size", "performance", SettingKind::Positive),
+    setting("cache_ttl_minutes", "performance", SettingKind::Minutes),
+    setting(
+        "default_time_period_days",
+        "ui_preferences",
+        SettingKind::Positive,
+    ),
+    setting("timezone", "ui_preferences", SettingKind::Timezone),
+    setting("relative_timestamps", "ui_preferences", SettingKind::Bool),
+    setting("size_buckets", "ui_preferences", SettingKind::Bool),
+    setting("browser_command", "ui_preferences", SettingKind::Command),
+    setting(
+        "auto_refresh_minutes",
+        "ui_preferences",
+        SettingKind::Minutes,
+    ),
+    setting("show_platform_icons", "ui_preferences", SettingKind::Bool),
+    setting(
+        "preferred_platform_order",
+        "ui_preferences",
+        SettingKind::List,
+    ),
+    setting(
+        "theme",
+        "ui_preferences",
+        SettingKind::Choice(&["Default", "Dark", "Light", "HighContrast"]),
+    ),
+];
+
+/// Environment variable overriding a setting, e.g. `REVIEWR_PAGE_SIZE`
+pub fn env_var(key: &str) -> String {
+    format!("REVIEWR_{}", key.to_ascii_uppercase())
+}
+
+/// Where a setting's value came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingSource {
+    Flag,
+    Env(String),
+    ConfigFile,
+    Default,
+}
+
+impl fmt::Display for SettingSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettingSource::Flag => write!(f, "command line"),
+            SettingSource::Env(var) => write!(f, "{var}"),
+            SettingSource::ConfigFile => write!(f, "config.toml"),
+            SettingSource::Default => write!(f, "default"),
+        }
+    }
+}
+
+impl Setting {
+    /// The option called `key`
+    pub fn find(key: &str) -> io::Result<&'static Setting> {
+        SETTINGS
+            .iter()
+            .find(|setting| setting.key == key)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("Unknown setting '{key}'"),
+                )
+            })
+    }
+
+    /// Read `config set` input; None unsets an optional setting
+    pub fn parse(&self, raw: &str) -> io::Result<Option<toml::Value>> {
+        let value = raw.trim();
+        let invalid = |expected: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Invalid value for {}: '{raw}' (expected {expected})",
+                    self.key
+                ),
+            )
+        };
+        let list = || {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+        };
+        Ok(Some(match self.kind {
+            SettingKind::Bool => {
+                toml::Value::Boolean(value.parse().map_err(|_| invalid("true or false"))?)
+            }
+            SettingKind::Positive => toml::Value::Integer(
+                value
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| invalid("a positive number"))?
+                    .into(),
+            ),
+            SettingKind::Minutes => toml::Value::Integer(
+                value
+                    .parse::<u32>()
+                    .map_err(|_| invalid("minutes, 0 turns it off"))?
+                    .into(),
+            ),
+            SettingKind::List => toml::Value::Array(list().map(toml::Value::String).collect()),
+            SettingKind::Domains => {
+                let domains: Vec<String> = value
+                    .split(',')
+                    .map(|domain| domain.trim().to_string())
+                    .collect();
+                for domain in &domains {
+                    validate_domain(domain)?;
+                }
+                toml::Value::Array(domains.into_iter().map(toml::Value::String).collect())
+            }
+            SettingKind::Text if value.is_empty() => return Ok(None),
+            SettingKind::Text => toml::Value::String(value.to_string()),
+            SettingKind::Command if value.is_empty() => return Ok(None),
+            SettingKind::Command => {
+                // Reject unbalanced quotes now rather than when the command first runs
+                shlex::split(value)
+                    .filter(|args| !args.is_empty())
+                    .ok_or_else(|| invalid("a command line with balanced quotes"))?;
+                toml::Value::String(value.to_string())
+            }
+            SettingKind::Timezone => {
+                if DisplayZone::from_setting(Some(value)) == DisplayZone::Local
+                    && !value.eq_ignore_ascii_case("local")
+                {
+                    return Err(invalid("local, UTC or an offset like +02:00"));
+                }
+                toml::Value::String(value.to_string())
+            }
+            SettingKind::Choice(names) => {
+                let name = names
+                    .iter()
+                    .find(|name| name.eq_ignore_ascii_case(value))
+                    .ok_or_else(|| invalid(&names.join(", ")))?;
+                toml::Value::String(name.to_string())
+            }
+        }))
+    }
+
+    /// Validate `raw` and write it to `config.toml`, keeping every other setting. Returns the
+    /// value as `config get` shows it, or None when the setting was unset.
+    pub fn write(&self, data_path: &DataPath, raw: &str) -> io::Result<Option<String>> {
+        let value = self.parse(raw)?;
+        let shown = value.as_ref().map(format_value);
+        let mut table = to_table(&UnifiedConfigService::load_config(data_path)?)?;
+        let section = table
+            .entry(self.section.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        if let Some(section) = section.as_table_mut() {
+            match value {
+                Some(value) => section.insert(self.key.to_string(), value),
+                None => section.remove(self.key),
+            };
+        }
+        let config: UnifiedConfig = toml::Value::Table(table).try_into().map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Invalid value for {}: '{raw}' ({e})", self.key),
+            )
+        })?;
+        UnifiedConfigService::save_config(&config, data_path)?;
+        info!("Updated {} configuration", self.key);
+        Ok(shown)
+    }
+
+    /// What people should know after `config set` wrote `config.toml`
+    pub fn warnings(&self, data_path: &DataPath) -> io::Result<Vec<String>> {
+        let config = UnifiedConfigService::load_config(data_path)?;
+        let mut warnings = Vec::new();
+        match self.key {
+            "enforce_allowed_domains" if config.global_settings.enforce_allowed_domains => {
+                if let Err(e) = UnifiedConfigService::check_platform_domains(data_path) {
+                    warnings.push(e.to_string());
+                }
+            }
+            "verbose_error_logs" if config.global_settings.verbose_error_logs => {
+                warnings.push(
+                    "error.log will contain full URLs and response bodies, which may include tokens or personal data."
+                        .to_string(),
+                );
+            }
+            _ => {}
+        }
+        Ok(warnings)
+    }
+}
+
+/// A value formatted for people: strings unquoted, lists as `["a", "b"]`
+fn format_value(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        toml::Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(format_value).collect();
+            format!("{items:?}")
+        }
+        other => other.to_string(),
+    }
+}
+
+fn section_of(key: &str) -> io::Result<&'static str> {
+    Setting::find(key).map(|setting| setting.section)
+}
+
+fn to_table(config: &UnifiedConfig) -> io::Result<toml::Table> {
+    toml::Table::try_from(config).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+pub struct Settings {
+    defaults: toml::Table,
+    /// `config.toml` as written; None when there is no file
+    file: Option<toml::Table>,
+    /// `REVIEWR_*` variables by setting key
+    env: HashMap<String, String>,
+    flags: HashMap<String, toml::Value>,
+}
+
+impl Settings {
+    /// Read `config.toml` and the `REVIEWR_*` environment
+    pub fn load(data_path: &DataPath) -> io::Result<Self> {
+        let file = match fs::read_to_string(data_path.config_path()) {
+            Ok(content) => Some(toml::from_str(&content).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Invalid unified config format: {e}"),
+                )
+            })?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e),
+        };
+        Self::from_parts(file, std::env::vars())
+    }
+
+    fn from_parts(
+        file: Option<toml::Table>,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> io::Result<Self> {
+        let vars: HashMap<String, String> = vars.into_iter().collect();
+        let env = SETTINGS
+            .iter()
+            .filter_map(|setting| {
+                vars.get(&env_var(setting.key))
+                    .map(|value| (setting.key.to_string(), value.clone()))
+            })
+            .collect();
+        Ok(Self {
+            defaults: to_table(&UnifiedConfig::default())?,
+            file,
+            env,
+            flags: HashMap::new(),
+        })
+    }
+
+    /// Override a setting from a command line flag, when the flag was given
+    pub fn with_flag<T: Serialize>(mut self, key: &str, value: Option<T>) -> io::Result<Self> {
+        section_of(key)?;
+        if let Some(value) = value {
+            let value = toml::Value::try_from(value)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+            self.flags.insert(key.to_string(), value);
+        }
+        Ok(self)
+    }
+
+    fn lookup<'a>(table: &'a toml::Table, section: &str, key: &str) -> Option<&'a toml::Value> {
+        table.get(section)?.as_table()?.get(key)
+    }
+
+    /// Environment values are typed after the default: numbers, booleans, comma-separated lists,
+    /// else plain strings
+    fn parse_env(&self, key: &str, section: &str, raw: &str) -> io::Result<toml::Value> {
+        let invalid = |expected: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{}={raw}: expected {expected}", env_var(key)),
+            )
+        };
+        Ok(match Self::lookup(&self.defaults, section, key) {
+            Some(toml::Value::Integer(_)) => {
+                toml::Value::Integer(raw.trim().parse().map_err(|_| invalid("a whole number"))?)
+            }
+            Some(toml::Value::Boolean(_)) => {
+                toml::Value::Boolean(raw.trim().parse().map_err(|_| invalid("true or false"))?)
+            }
+            Some(toml::Value::Array(_)) => toml::Value::Array(
+                raw.split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| toml::Value::String(item.to_string()))
+                    .collect(),
+            ),
+            _ => toml::Value::String(raw.to_string()),
+        })
+    }
+
+    /// The winning value of a setting and where it came from; None for an unset optional one
+    pub fn resolve(&self, key: &str) -> io::Result<Option<(toml::Value, SettingSource)>> {
+        let section = section_of(key)?;
+        if let Some(value) = self.flags.get(key) {
+            return Ok(Some((value.clone(), SettingSource::Flag)));
+        }
+        if let Some(raw) = self.env.get(key) {
+            let value = self.parse_env(key, section, raw)?;
+            return Ok(Some((value, SettingSource::Env(env_var(key)))));
+        }
+        if let Some(value) = self
+            .file
+            .as_ref()
+            .and_then(|file| Self::lookup(file, section, key))
+        {
+            return Ok(Some((value.clone(), SettingSource::ConfigFile)));
+        }
+        Ok(Self::lookup(&self.defaults, section, key)
+            .map(|value| (value.clone(), SettingSource::Default)))
+    }
+
+    /// [`Self::resolve`] formatted for people: strings unquoted, lists as `["a", "b"]`
+    pub fn display(&self, key: &str) -> io::Result<Option<(String, SettingSource)>> {
+        Ok(self
+            .resolve(key)?
+            .map(|(value, source)| (format_value(&value), source)))
+    }
+
+    /// A setting as `T`, or None when an optional setting is unset
+    pub fn get_opt<T: DeserializeOwned>(&self, key: &str) -> io::Result<Option<T>> {
+        let Some((value, source)) = self.resolve(key)? else {
+            return Ok(None);
+        };
+        value.try_into().map(Some).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid value for {key} from {source}: {e}"),
+            )
+        })
+    }
+
+    /// A setting as `T`; unset optional settings are an error
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> io::Result<T> {
+        self.get_opt(key)?.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Setting '{key}' is not set"),
+            )
+        })
+    }
+
+    /// The whole configuration with environment and flag overrides applied. Read-only: saving it
+    /// would write the overrides into `config.toml`.
+    pub fn config(&self) -> io::Result<UnifiedConfig> {
+        let mut table = match &self.file {
+            Some(file) => file.clone(),
+            None => self.defaults.clone(),
+        };
+        for setting in SETTINGS {
+            let key = setting.key;
+            if !self.flags.contains_key(key) && !self.env.contains_key(key) {
+                continue;
+            }
+            if let Some((value, _)) = self.resolve(key)? {
+                let section = table
+                    .entry(setting.section.to_string())
+                    .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+                if let Some(section) = section.as_table_mut() {
+                    section.insert(key.to_string(), value);
+                }
+            }
+        }
+        toml::Value::Table(table).try_into().map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid unified config format: {e}"),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(file: &str, vars: &[(&str, &str)]) -> Settings {
+        let vars = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()));
+        Settings::from_parts(Some(toml::from_str(file).unwrap()), vars).unwrap()
+    }
+
+    #[test]
+    fn test_precedence() {
+        let file = "[performance]\npage_size = 25\ncache_ttl_minutes = 5\n[ui_preferences]\n";
+        let settings = settings(file, &[("REVIEWR_CACHE_TTL_MINUTES", "0")])
+            .with_flag("cache_ttl_minutes", Some(30u64))
+            .unwrap()
+            .with_flag::<u32>("default_time_period_days", None)
+            .unwrap();
+
+        assert_eq!(settings.get::<u32>("page_size").unwrap(), 25);
+        assert_eq!(settings.get::<u64>("cache_ttl_minutes").unwrap(), 30);
+        assert_eq!(
+            settings.get::<usize>("max_concurrent_platforms").unwrap(),
+            4
+        );
+        assert_eq!(
+            settings
+                .resolve("default_time_period_days")
+                .unwrap()
+                .unwrap()
+                .1,
+            SettingSource::Default
+        );
+        assert_eq!(settings.get_opt::<String>("timezone").unwrap(), None);
+        assert!(settings.get::<String>("timezone").is_err());
+        assert!(settings.get::<u32>("no_such_setting").is_err());
+
+        let without_flag = super::tests::settings(file, &[("REVIEWR_CACHE_TTL_MINUTES", "0")]);
+        assert_eq!(
+            without_flag.resolve("cache_ttl_minutes").unwrap().unwrap(),
+            (
+                toml::Value::Integer(0),
+                SettingSource::Env("REVIEWR_CACHE_TTL_MINUTES".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn test_env_values_are_typed_and_applied_to_config() {
+        let settings = settings(
+            "[platforms]\n[ui_preferences]\n",
+            &[
+                ("REVIEWR_ALLOWED_DOMAINS", "example.com, corp.example.com"),
+                ("REVIEWR_RELATIVE_TIMESTAMPS", "true"),
+                ("REVIEWR_TIMEZONE", "UTC"),
+                ("REVIEWR_SECRET_BACKEND", "keyring"),
+            ],
+        );
+        let config = settings.config().unwrap();
+        assert_eq!(
+            config.global_settings.allowed_domains,
+            vec!["example.com", "corp.example.com"]
+        );
+        assert!(config.ui_preferences.relative_timestamps);
+        assert_eq!(config.ui_preferences.timezone.as_deref(), Some("UTC"));
+        assert_eq!(
+            config.global_settings.secret_backend,
+            crate::secrets::SecretBackendKind::Keyring
+        );
+
+        let invalid = super::tests::settings("", &[("REVIEWR_PAGE_SIZE", "lots")]);
+        let error = invalid.get::<u32>("page_size").unwrap_err();
+        assert!(error.to_string().contains("REVIEWR_PAGE_SIZE=lots"));
+        assert!(invalid.config().is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let settings = settings(
+            "[global_settings]\nallowed_domains = [\"example.com\"]\n[platforms]\n[ui_preferences]\n",
+            &[("REVIEWR_THEME", "Dark")],
+        );
+        let display = |key| settings.display(key).unwrap().map(|(value, _)| value);
+        assert_eq!(
+            display("allowed_domains").as_deref(),
+            Some("[\"example.com\"]")
+        );
+        assert_eq!(display("theme").as_deref(), Some("Dark"));
+        assert_eq!(display("page_size").as_deref(), Some("100"));
+        assert_eq!(display("age_identity"), None);
+        assert!(matches!(
+            settings.config().unwrap().ui_preferences.theme,
+            crate::unified_config::UiTheme::Dark
+        ));
+    }
+
+    #[test]
+    fn test_parse_config_set_input() {
+        let parse = |key: &str, raw: &str| Setting::find(key).unwrap().parse(raw);
+
+        assert_eq!(
+            parse("redact_exports", " true ").unwrap(),
+            Some(toml::Value::Boolean(true))
+        );
+        assert!(parse("redact_exports", "yes").is_err());
+        assert!(parse("page_size", "0").is_err());
+        assert_eq!(
+            parse("cache_ttl_minutes", "0").unwrap(),
+            Some(toml::Value::Integer(0))
+        );
+        assert!(parse("allowed_domains", "example.com,bad domain").is_err());
+        assert_eq!(
+            parse("preferred_platform_order", "jira, gerrit").unwrap(),
+            Some(toml::Value::Array(vec![
+                toml::Value::String("jira".to_string()),
+                toml::Value::String("gerrit".to_string()),
+            ]))
+        );
+        assert_eq!(parse("age_identity", " ").unwrap(), None);
+        assert!(parse("browser_command", "chrome \"--profile").is_err());
+        assert!(parse("timezone", "Mars/Olympus").is_err());
+        assert_eq!(
+            parse("theme", "highcontrast").unwrap(),
+            Some(toml::Value::String("HighContrast".to_string()))
+        );
+        let error = parse("secret_backend", "vault").unwrap_err();
+        assert!(error.to_string().contains("expected config, keyring"));
+        assert!(Setting::find("no_such_setting").is_err());
+    }
+
+    #[test]
+    fn test_write_keeps_other_settings() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let write = |key: &str, raw: &str| Setting::find(key).unwrap().write(&data_path, raw);
+
+        assert_eq!(write("page_size", "25").unwrap().as_deref(), Some("25"));
+        assert_eq!(
+            write("browser_command", "firefox -P work {url}")
+                .unwrap()
+                .as_deref(),
+            Some("firefox -P work {url}")
+        );
+        assert_eq!(
+            write("secret_backend", "Keyring").unwrap().as_deref(),
+            Some("keyring")
+        );
+        assert!(write("page_size", "-1").is_err());
+
+        let config = UnifiedConfigService::load_config(&data_path).unwrap();
+        assert_eq!(config.performance.page_size, 25);
+        assert_eq!(
+            config.global_settings.secret_backend,
+            crate::secrets::SecretBackendKind::Keyring
+        );
+        assert_eq!(
+            config.ui_preferences.browser_command.as_deref(),
+            Some("firefox -P work {url}")
+        );
+
+        assert_eq!(write("browser_command", "").unwrap(), None);
+        let config = UnifiedConfigService::load_config(&data_path).unwrap();
+        assert_eq!(config.ui_preferences.browser_command, None);
+        assert_eq!(config.performance.page_size, 25);
+    }
+
+    #[test]
+    fn test_every_setting_has_a_known_section() {
+        let defaults = to_table(&UnifiedConfig::default()).unwrap();
+        for setting in SETTINGS {
+            assert!(defaults.contains_key(setting.section), "{}", setting.key);
+        }
+    }
+}
@@ -0,0 +1,323 @@
+//! The platform instance entered in `reviewr init`: added to the unified config and tested
+//! live with the entered credentials before anything is written
+
+use crate::gerrit::{GerritClient, GerritConfig};
+use crate::gitlab::GitLabPlatform;
+use crate::jira::JiraClient;
+use crate::models::DataPath;
+use crate::platform::{ConnectionStatus, ReviewPlatform};
+use crate::unified_config::{
+    DEFAULT_INSTANCE, GitLabConfig, JiraConfig, RequestLimits, UnifiedConfig, UnifiedConfigService,
+    instance_platform_id,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetupPlatform {
+    Gerrit,
+    GitLab,
+    Jira,
+}
+
+impl SetupPlatform {
+    /// In the order the wizard lists them
+    pub const ALL: [SetupPlatform; 3] = [
+        SetupPlatform::Gerrit,
+        SetupPlatform::GitLab,
+        SetupPlatform::Jira,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            SetupPlatform::Gerrit => "Gerrit",
+            SetupPlatform::GitLab => "GitLab",
+            SetupPlatform::Jira => "JIRA",
+        }
+    }
+
+    /// A number from the wizard's list or a platform name, in any case
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        Self::ALL
+            .into_iter()
+            .enumerate()
+            .find_map(|(index, platform)| {
+                (input == (index + 1).to_string()
+                    || input.eq_ignore_ascii_case(platform.display_name()))
+                .then_some(platform)
+            })
+    }
+
+    /// What the user field holds: the login, or for GitLab the name shown for the instance
+    pub fn user_label(&self) -> &'static str {
+        match self {
+            SetupPlatform::Gerrit | SetupPlatform::Jira => "Username",
+            SetupPlatform::GitLab => "Display name",
+        }
+    }
+
+    pub fn secret_label(&self) -> &'static str {
+        match self {
+            SetupPlatform::Gerrit => "HTTP password",
+            SetupPlatform::GitLab => "Access token",
+            SetupPlatform::Jira => "API token",
+        }
+    }
+
+    /// Instance name offered for a server: the default instance, except for GitLab whose
+    /// instances are always named, where the first label of the host is offered
+    pub fn default_instance(&self, url: &str) -> String {
+        match self {
+            SetupPlatform::GitLab => url::Url::parse(url.trim())
+                .ok()
+                .and_then(|url| url.host_str()?.split('.').next().map(str::to_string))
+                .unwrap_or_else(|| "gitlab".to_string()),
+            SetupPlatform::Gerrit | SetupPlatform::Jira => DEFAULT_INSTANCE.to_string(),
+        }
+    }
+
+    /// Where the secret is created, shown before it is asked for
+    pub fn secret_hint(&self) -> &'static str {
+        match self {
+            SetupPlatform::Gerrit => "Generate it under Settings → HTTP Credentials in Gerrit",
+            SetupPlatform::GitLab => {
+                "Create a personal access token with the read_api scope in your GitLab profile"
+            }
+            SetupPlatform::Jira => "Create a personal access token in your JIRA profile",
+        }
+    }
+}
+
+/// One platform instance as entered in the wizard
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlatformSetup {
+    pub platform: SetupPlatform,
+    pub instance: String,
+    pub url: String,
+    /// Username, or the display name of a GitLab instance
+    pub user: String,
+    pub secret: String,
+}
+
+impl PlatformSetup {
+    /// Keyring account of the instance, as used by `config set-secret`
+    pub fn account(&self) -> String {
+        match self.platform {
+            SetupPlatform::Gerrit => instance_platform_id("gerrit", &self.instance),
+            SetupPlatform::GitLab => format!("gitlab:{}", self.instance),
+            SetupPlatform::Jira => instance_platform_id("jira", &self.instance),
+        }
+    }
+
+    /// Add the instance to `config`, keeping the other settings of an instance of the same
+    /// name. With `store_secret` false the secret is left out, for the keyring to hold it.
+    pub fn apply(&self, config: &mut UnifiedConfig, store_secret: bool) {
+        let secret = if store_secret {
+            self.secret.clone()
+        } else {
+            String::new()
+        };
+        let platforms = &mut config.platforms;
+        match self.platform {
+            SetupPlatform::Gerrit => {
+                let gerrit = platforms
+                    .gerrit
+                    .entry(self.instance.clone())
+                    .or_insert_with(|| GerritConfig {
+                        gerrit_url: String::new(),
+                        username: String::new(),
+                        http_password: String::new(),
+                        accept_invalid_certs: false,
+                        limits: RequestLimits::default(),
+                    });
+                gerrit.gerrit_url = self.url.clone();
+                gerrit.username = self.user.clone();
+                gerrit.http_password = secret;
+            }
+            SetupPlatform::GitLab => {
+                let gitlab = platforms
+                    .gitlab
+                    .entry(self.instance.clone())
+                    .or_insert_with(|| GitLabConfig {
+                        name: String::new(),
+                        url: String::new(),
+                        token: String::new(),
+                        accept_invalid_certs: false,
+                        limits: RequestLimits::default(),
+                    });
+                gitlab.name = self.user.clone();
+                gitlab.url = self.url.clone();
+                gitlab.token = secret;
+            }
+            SetupPlatform::Jira => {
+                let jira = platforms
+                    .jira
+                    .entry(self.instance.clone())
+                    .or_insert_with(|| JiraConfig {
+                        jira_url: String::new(),
+                        username: String::new(),
+                        api_token: String::new(),
+                        project_filter: Vec::new(),
+                        custom_fields: HashMap::new(),
+                        fields: Vec::new(),
+                        lazy_details: false,
+                        accept_invalid_certs: false,
+                        limits: RequestLimits::default(),
+                    });
+                jira.jira_url = self.url.clone();
+                jira.username = self.user.clone();
+                jira.api_token = secret;
+            }
+        }
+    }
+
+    /// Connect with the entered values through the proxy, CA certificates and retry policy of
+    /// `data_path`, without saving them first
+    pub async fn test_connection(&self, data_path: &DataPath) -> ConnectionStatus {
+        let mut config = UnifiedConfig::default();
+        self.apply(&mut config, true);
+        let http = UnifiedConfigService::load_http_settings(data_path);
+        let retry = UnifiedConfigService::load_retry_policy(data_path);
+        let platforms = config.platforms;
+        let failed = |e: std::io::Error| ConnectionStatus::Error(e.to_string());
+        match self.platform {
+            SetupPlatform::Gerrit => {
+                match GerritClient::new(&platforms.gerrit[&self.instance], &http) {
+                    Ok(client) => client.with_retry(retry).check_account().await,
+                    Err(e) => failed(e),
+                }
+            }
+            SetupPlatform::GitLab => {
+                let config = platforms.gitlab[&self.instance].clone();
+                match GitLabPlatform::new(config, self.instance.clone(), data_path) {
+                    Ok(platform) => platform.test_connection().await.unwrap_or_else(failed),
+                    Err(e) => failed(e),
+                }
+            }
+            SetupPlatform::Jira => match JiraClient::new(&platforms.jira[&self.instance], &http) {
+                Ok(client) => match client.with_retry(retry).test_connection().await {
+                    Ok(()) => ConnectionStatus::Connected,
+                    Err(e) => failed(e),
+                },
+                Err(e) => failed(e),
+            },
+        }
+    }
+
+    /// The domain offered as the first allowed domain: the server's host without its first
+    /// label when that leaves at least two, so `review.corp.example.com` offers
+    /// `corp.example.com`
+    pub fn suggested_domain(&self) -> Option<String> {
+        let url = url::Url::parse(self.url.trim()).ok()?;
+        let host = url.domain()?;
+        let labels: Vec<&str> = host.split('.').collect();
+        if labels.len() > 2 {
+            Some(labels[1..].join("."))
+        } else {
+            Some(host.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config_check::check_config;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn setup(platform: SetupPlatform, url: &str) -> PlatformSetup {
+        PlatformSetup {
+            platform,
+            instance: "corp".to_string(),
+            url: url.to_string(),
+            user: "jdoe".to_string(),
+            secret: "s3cret".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_platform_choice() {
+        assert_eq!(SetupPlatform::parse("2"), Some(SetupPlatform::GitLab));
+        assert_eq!(SetupPlatform::parse(" jira "), Some(SetupPlatform::Jira));
+        assert_eq!(SetupPlatform::parse("4"), None);
+        assert_eq!(SetupPlatform::parse(""), None);
+        assert_eq!(
+            SetupPlatform::GitLab.default_instance("https://gitlab.corp.example.com"),
+            "gitlab"
+        );
+        assert_eq!(
+            SetupPlatform::Gerrit.default_instance("https://review.corp.example.com"),
+            DEFAULT_INSTANCE
+        );
+    }
+
+    #[test]
+    fn test_apply_writes_a_valid_config() {
+        let mut config = UnifiedConfig::default();
+        config.platforms.jira.insert(
+            "corp".to_string(),
+            JiraConfig {
+                jira_url: "https://old.example.com".to_string(),
+                username: "old".to_string(),
+                api_token: "old".to_string(),
+                project_filter: vec!["CORE".to_string()],
+                custom_fields: HashMap::new(),
+                fields: Vec::new(),
+                lazy_details: false,
+                accept_invalid_certs: false,
+                limits: RequestLimits::default(),
+            },
+        );
+        let jira = setup(SetupPlatform::Jira, "https://jira.corp.example.com");
+        jira.apply(&mut config, true);
+        setup(SetupPlatform::Gerrit, "https://review.corp.example.com").apply(&mut config, false);
+
+        // Settings of an existing instance are kept
+        assert_eq!(config.platforms.jira["corp"].project_filter, ["CORE"]);
+        assert_eq!(config.platforms.jira["corp"].api_token, "s3cret");
+        assert!(config.platforms.gerrit["corp"].http_password.is_empty());
+
+        let content = toml::to_string_pretty(&config).unwrap();
+        assert!(check_config(&content).is_empty(), "{content}");
+        assert_eq!(jira.suggested_domain().as_deref(), Some("corp.example.com"));
+        assert_eq!(
+            setup(SetupPlatform::GitLab, "https://gitlab.com").suggested_domain(),
+            Some("gitlab.com".to_string())
+        );
+        assert_eq!(
+            setup(SetupPlatform::Gerrit, "https://review.test").account(),
+            "gerrit:corp"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_connection_with_entered_credentials() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v4/projects"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/myself"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let gitlab = setup(SetupPlatform::GitLab, &server.uri());
+        assert_eq!(
+            gitlab.test_connection(&data_path).await,
+            ConnectionStatus::Connected
+        );
+        let jira = setup(SetupPlatform::Jira, &server.uri());
+        assert!(matches!(
+            jira.test_connection(&data_path).await,
+            ConnectionStatus::Error(message) if message.contains("401")
+        ));
+        // Nothing was written
+        assert!(!data_path.config_path().exists());
+    }
+}
@@ -0,0 +1,306 @@
+//! Items per category and person for comparing a whole team side by side
+
+use crate::anonymize::Anonymizer;
+use crate::platform::{ActivityCategory, DetailedActivities};
+use crate::scoring::format_index;
+use std::collections::{BTreeMap, HashMap};
+
+/// One person's row of the comparison
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TeamMember {
+    pub name: String,
+    pub counts: HashMap<ActivityCategory, usize>,
+    /// Platforms whose fetch failed, so the counts are incomplete
+    pub failed_platforms: Vec<String>,
+    /// Categories some platform could not load, whose counts are incomplete
+    pub failed_categories: Vec<ActivityCategory>,
+    /// Heuristic from the `[scoring]` weights, when configured
+    pub activity_index: Option<f64>,
+    /// Days of the period spent out of office, excluded from per-month rates
+    pub days_absent: u32,
+}
+
+impl TeamMember {
+    pub fn count(&self, category: &ActivityCategory) -> usize {
+        self.counts.get(category).copied().unwrap_or_default()
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// The count of a category for display: "failed" when it could not be loaded at all, the
+    /// count marked "(partial)" when only some platform failed to load it
+    pub fn count_label(&self, category: &ActivityCategory) -> String {
+        let count = self.count(category);
+        match self.failed_categories.contains(category) {
+            false => count.to_string(),
+            true if count == 0 => "failed".to_string(),
+            true => format!("{count} (partial)"),
+        }
+    }
+}
+
+/// Per-person category counts, with every category seen for anyone as a column
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TeamComparison {
+    pub members: Vec<TeamMember>,
+}
+
+impl TeamComparison {
+    /// Start with an empty row for every name, so people without activity still show up
+    pub fn new<S: Into<String>>(names: impl IntoIterator<Item = S>) -> Self {
+        let members: BTreeMap<String, TeamMember> = names
+            .into_iter()
+            .map(|name| {
+                let name = name.into();
+                let member = TeamMember {
+                    name: name.clone(),
+                    ..TeamMember::default()
+                };
+                (name, member)
+            })
+            .collect();
+        Self {
+            members: members.into_values().collect(),
+        }
+    }
+
+    fn member_mut(&mut self, name: &str) -> &mut TeamMember {
+        let index = match self
+            .members
+            .binary_search_by(|member| member.name.as_str().cmp(name))
+        {
+            Ok(index) => index,
+            Err(index) => {
+                self.members.insert(
+                    index,
+                    TeamMember {
+                        name: name.to_string(),
+                        ..TeamMember::default()
+                    },
+                );
+                index
+            }
+        };
+        &mut self.members[index]
+    }
+
+    /// Add one platform's activities for a person, marking the categories it failed to load
+    pub fn add(&mut self, name: &str, activities: &DetailedActivities) {
+        self.add_counts(
+            name,
+            activities
+                .items_by_category
+                .iter()
+                .map(|(category, items)| (category, items.len())),
+        );
+        let member = self.member_mut(name);
+        for category in activities.failed_categories.keys() {
+            if !member.failed_categories.contains(category) {
+                member.failed_categories.push(category.clone());
+            }
+        }
+    }
+
+    /// Add item counts per category for a person
+    pub fn add_counts<'a>(
+        &mut self,
+        name: &str,
+        counts: impl IntoIterator<Item = (&'a ActivityCategory, usize)>,
+    ) {
+        let member = self.member_mut(name);
+        for (category, count) in counts {
+            *member.counts.entry(category.clone()).or_default() += count;
+        }
+    }
+
+    pub fn set_days_absent(&mut self, name: &str, days: u32) {
+        self.member_mut(name).days_absent = days;
+    }
+
+    pub fn add_failure(&mut self, name: &str, platform: &str) {
+        self.member_mut(name)
+            .failed_platforms
+            .push(platform.to_string());
+    }
+
+    /// Every category with at least one item for anyone or that failed to load for someone, by
+    /// display name
+    pub fn categories(&self) -> Vec<ActivityCategory> {
+        let mut categories: Vec<ActivityCategory> = Vec::new();
+        for member in &self.members {
+            let listed = member
+                .counts
+                .iter()
+                .filter(|(_, count)| **count > 0)
+                .map(|(category, _)| category)
+                .chain(&member.failed_categories);
+            for category in listed {
+                if !categories.contains(category) {
+                    categories.push(category.clone());
+                }
+            }
+        }
+        categories.sort_by(|a, b| a.display_name().cmp(b.display_name()));
+        categories
+    }
+
+    /// Replace every name with its pseudonym, re-sorted so the order gives no names away
+    pub fn redact(&mut self, anonymizer: &Anonymizer) {
+        for member in &mut self.members {
+            member.name = anonymizer.pseudonym(&member.name);
+        }
+        self.members.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    /// Whether `[scoring]` gave the members an activity index
+    pub fn has_activity_index(&self) -> bool {
+        self.members
+            .iter()
+            .any(|member| member.activity_index.is_some())
+    }
+
+    /// `Employee,<category>...,Total` with one line per person, plus the activity index when
+    /// scoring is configured and the platforms that failed to load when any did. Categories
+    /// that failed to load read "failed" rather than 0.
+    pub fn to_csv(&self) -> String {
+        let categories = self.categories();
+        let with_index = self.has_activity_index();
+        let with_failures = self
+            .members
+            .iter()
+            .any(|member| !member.failed_platforms.is_empty());
+        let mut header = vec![csv_field("Employee")];
+        header.extend(categories.iter().map(|c| csv_field(c.display_name())));
+        header.push(csv_field("Total"));
+        if with_index {
+            header.push(csv_field("Activity index (heuristic)"));
+        }
+        if with_failures {
+            header.push(csv_field("Failed to load"));
+        }
+
+        let mut csv = header.join(",");
+        csv.push('\n');
+        for member in &self.members {
+            let mut row = vec![csv_field(&member.name)];
+            row.extend(
+                categories
+                    .iter()
+                    .map(|category| csv_field(&member.count_label(category))),
+            );
+            row.push(member.total().to_string());
+            if with_index {
+                row.push(member.activity_index.map(format_index).unwrap_or_default());
+            }
+            if with_failures {
+                row.push(csv_field(&member.failed_platforms.join("; ")));
+            }
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+/// Quote a field containing a separator, quote or line break (RFC 4180)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::ScoringConfig;
+    use crate::test_support::ItemBuilder;
+
+    fn activities(category: ActivityCategory, count: usize) -> DetailedActivities {
+        let items = (0..count)
+            .map(|id| {
+                ItemBuilder::new(&id.to_string())
+                    .with_category(category.clone())
+                    .build()
+            })
+            .collect();
+        let mut activities = DetailedActivities::default();
+        activities.items_by_category.insert(category, items);
+        activities
+    }
+
+    #[test]
+    fn test_team_comparison() {
+        let mut team = TeamComparison::new(["Carol", "Alice"]);
+        team.add("Alice", &activities(ActivityCategory::ReviewsGiven, 3));
+        team.add("Alice", &activities(ActivityCategory::ChangesMerged, 2));
+        team.add("Bob, Jr.", &activities(ActivityCategory::ReviewsGiven, 1));
+        team.add_failure("Bob, Jr.", "JIRA");
+
+        let names: Vec<&str> = team.members.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["Alice", "Bob, Jr.", "Carol"]);
+        assert_eq!(team.members[0].total(), 5);
+        assert_eq!(team.members[1].failed_platforms, vec!["JIRA"]);
+        assert_eq!(
+            team.categories(),
+            vec![
+                ActivityCategory::ChangesMerged,
+                ActivityCategory::ReviewsGiven
+            ]
+        );
+        assert_eq!(
+            team.to_csv(),
+            "Employee,Changes Merged,Reviews Given,Total,Failed to load\n\
+             Alice,2,3,5,\n\
+             \"Bob, Jr.\",0,1,1,JIRA\n\
+             Carol,0,0,0,\n"
+        );
+
+        let anonymizer = Anonymizer::new("salt", Vec::new());
+        team.redact(&anonymizer);
+        let alice = team
+            .members
+            .iter()
+            .find(|member| member.name == anonymizer.pseudonym("Alice"))
+            .unwrap();
+        assert_eq!(alice.total(), 5);
+        assert!(team.members.is_sorted_by(|a, b| a.name <= b.name));
+        assert!(!team.to_csv().contains("Alice"));
+
+        // The activity index survives redaction and becomes the last column
+        let scoring = ScoringConfig {
+            weights: [("Reviews Given".to_string(), 2.0)].into(),
+            ..ScoringConfig::default()
+        };
+        scoring.apply(&mut team, 30);
+        let csv = team.to_csv();
+        assert!(csv.starts_with(
+            "Employee,Changes Merged,Reviews Given,Total,Activity index (heuristic),Failed to load\n"
+        ));
+        assert!(csv.contains(",2,3,5,6.0,\n"));
+    }
+
+    #[test]
+    fn test_failed_loads_are_not_counted_as_zero() {
+        let mut partial = activities(ActivityCategory::ChangesMerged, 2);
+        partial.insert_result(
+            ActivityCategory::ReviewsGiven,
+            Err(std::io::Error::other("HTTP 403")),
+        );
+        let mut team = TeamComparison::new(["Alice", "Bob"]);
+        team.add("Alice", &partial);
+        team.add("Bob", &activities(ActivityCategory::ReviewsGiven, 1));
+        team.add("Bob", &partial);
+        team.add_failure("Bob", "JIRA");
+
+        assert_eq!(
+            team.to_csv(),
+            "Employee,Changes Merged,Reviews Given,Total,Failed to load\n\
+             Alice,2,failed,2,\n\
+             Bob,2,1 (partial),3,JIRA\n"
+        );
+    }
+}
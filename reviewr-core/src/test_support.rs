@@ -1,9 +1,92 @@
-use crate::core::platform::{ActivityCategory, ActivityItem, ActivityMetrics, DetailedActivities};
+//! Activity builders and sample data shared by the unit tests of both crates.
+//!
+//! Enabled for reviewr-core's own tests and, through the `test-support` feature, for the
+//! `reviewr` crate's tests.
+
+use crate::platform::{ActivityCategory, ActivityItem, ActivityMetrics, DetailedActivities};
 use std::collections::HashMap;
 
+/// An activity item for tests: "Item <id>", a merged Gerrit change in `core` without dates, URL
+/// or metadata until set
+pub struct ItemBuilder {
+    item: ActivityItem,
+}
+
+impl ItemBuilder {
+    pub fn new(id: &str) -> Self {
+        Self {
+            item: ActivityItem {
+                id: id.to_string(),
+                title: format!("Item {id}"),
+                status: "MERGED".to_string(),
+                created: String::new(),
+                updated: String::new(),
+                url: String::new(),
+                platform: "gerrit".to_string(),
+                category: ActivityCategory::ChangesCreated,
+                project: "core".to_string(),
+                metadata: HashMap::new(),
+            },
+        }
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.item.title = title.to_string();
+        self
+    }
+
+    pub fn with_status(mut self, status: &str) -> Self {
+        self.item.status = status.to_string();
+        self
+    }
+
+    pub fn with_created(mut self, created: &str) -> Self {
+        self.item.created = created.to_string();
+        self
+    }
+
+    pub fn with_updated(mut self, updated: &str) -> Self {
+        self.item.updated = updated.to_string();
+        self
+    }
+
+    pub fn with_url(mut self, url: &str) -> Self {
+        self.item.url = url.to_string();
+        self
+    }
+
+    pub fn with_platform(mut self, platform: &str) -> Self {
+        self.item.platform = platform.to_string();
+        self
+    }
+
+    pub fn with_category(mut self, category: ActivityCategory) -> Self {
+        self.item.category = category;
+        self
+    }
+
+    pub fn with_project(mut self, project: &str) -> Self {
+        self.item.project = project.to_string();
+        self
+    }
+
+    pub fn with_metadata(mut self, key: &str, value: &str) -> Self {
+        self.item
+            .metadata
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn build(self) -> ActivityItem {
+        self.item
+    }
+}
+
 pub fn create_sample_activity_metrics() -> ActivityMetrics {
-    let mut metrics = ActivityMetrics::default();
-    metrics.total_items = 15;
+    let mut metrics = ActivityMetrics {
+        total_items: 15,
+        ..Default::default()
+    };
 
     // Add sample category data
     metrics
@@ -37,8 +120,10 @@ pub fn create_sample_activity_metrics() -> ActivityMetrics {
 }
 
 pub fn create_sample_jira_metrics() -> ActivityMetrics {
-    let mut metrics = ActivityMetrics::default();
-    metrics.total_items = 12;
+    let mut metrics = ActivityMetrics {
+        total_items: 12,
+        ..Default::default()
+    };
 
     metrics
         .items_by_category
@@ -73,7 +158,7 @@ pub fn create_sample_detailed_activities() -> DetailedActivities {
     let mut activities = DetailedActivities::default();
 
     // Sample Gerrit changes
-    let gerrit_changes = vec![
+    let gerrit_changes = [
         ActivityItem {
             id: "12345".to_string(),
             title: "Fix critical bug in authentication module".to_string(),
@@ -125,7 +210,7 @@ pub fn create_sample_detailed_activities() -> DetailedActivities {
 pub fn create_sample_jira_activities() -> DetailedActivities {
     let mut activities = DetailedActivities::default();
 
-    let jira_issues = vec![
+    let jira_issues = [
         ActivityItem {
             id: "PROJ-123".to_string(),
             title: "Implement OAuth2 integration for third-party services".to_string(),
@@ -0,0 +1,326 @@
+//! Parsing of the platforms' timestamp formats and their display in a chosen time zone
+
+use crate::platform::ActivityItem;
+use crate::unified_config::UiPreferences;
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, Utc};
+use log::warn;
+
+/// Item metadata naming the timestamps (`created`, `updated`) that no parser understood; they
+/// are shown as received and left out of date-based views
+pub const UNPARSED_DATES_KEY: &str = "unparsed_dates";
+
+/// Local date-time layouts seen in platform responses, all read as UTC: Gerrit's, ISO without
+/// an offset, without seconds, and JIRA's display format from custom fields
+const NAIVE_FORMATS: [&str; 5] = [
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M",
+    "%d/%b/%y %I:%M %p",
+];
+
+/// Time zone timestamps are shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayZone {
+    #[default]
+    Local,
+    Utc,
+    Fixed(FixedOffset),
+}
+
+impl DisplayZone {
+    /// Parse `ui_preferences.timezone`: "local", "UTC" or a fixed offset such as "+02:00".
+    /// Unknown values fall back to the local zone.
+    pub fn from_setting(setting: Option<&str>) -> Self {
+        let Some(setting) = setting.map(str::trim).filter(|s| !s.is_empty()) else {
+            return DisplayZone::Local;
+        };
+        if setting.eq_ignore_ascii_case("local") {
+            DisplayZone::Local
+        } else if setting.eq_ignore_ascii_case("utc") || setting == "Z" {
+            DisplayZone::Utc
+        } else if let Ok(offset) = setting.parse::<FixedOffset>() {
+            DisplayZone::Fixed(offset)
+        } else {
+            warn!("Unknown timezone '{setting}', showing local time");
+            DisplayZone::Local
+        }
+    }
+}
+
+/// How item timestamps are rendered in lists, detail panes and reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TimestampFormat {
+    pub zone: DisplayZone,
+    /// Show "3 days ago" instead of the date
+    pub relative: bool,
+}
+
+impl TimestampFormat {
+    pub fn new(zone: DisplayZone, relative: bool) -> Self {
+        Self { zone, relative }
+    }
+
+    pub fn from_preferences(preferences: &UiPreferences) -> Self {
+        Self::new(
+            DisplayZone::from_setting(preferences.timezone.as_deref()),
+            preferences.relative_timestamps,
+        )
+    }
+
+    /// Format a raw platform timestamp; unparseable input is returned unchanged
+    pub fn format(&self, raw: &str) -> String {
+        self.format_at(raw, Utc::now())
+    }
+
+    pub fn format_at(&self, raw: &str, now: DateTime<Utc>) -> String {
+        let Some(timestamp) = parse_timestamp(raw) else {
+            return raw.to_string();
+        };
+        if self.relative {
+            return relative_time(timestamp, now);
+        }
+        match self.zone {
+            DisplayZone::Local => timestamp
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            DisplayZone::Utc => timestamp.format("%Y-%m-%d %H:%M UTC").to_string(),
+            DisplayZone::Fixed(offset) => timestamp
+                .with_timezone(&offset)
+                .format("%Y-%m-%d %H:%M %:z")
+                .to_string(),
+        }
+    }
+}
+
+/// Platform whose own timestamp format is tried before all the others
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampSource {
+    /// `2024-01-15 10:30:00.000000000`, always UTC
+    Gerrit,
+    /// `2024-01-15T10:30:00.000+0000`
+    Jira,
+    /// RFC 3339
+    GitLab,
+}
+
+impl TimestampSource {
+    pub fn parse(self, raw: &str) -> Option<DateTime<Utc>> {
+        let raw = raw.trim();
+        let native = match self {
+            TimestampSource::Gerrit => parse_naive(raw, "%Y-%m-%d %H:%M:%S%.f"),
+            TimestampSource::Jira => parse_with_offset(raw),
+            TimestampSource::GitLab => parse_rfc3339(raw),
+        };
+        native.or_else(|| parse_timestamp(raw))
+    }
+
+    /// Record which of an item's timestamps cannot be parsed in [`UNPARSED_DATES_KEY`]
+    pub fn flag_unparsed_dates(self, item: &mut ActivityItem) {
+        let unparsed: Vec<&str> = [("created", &item.created), ("updated", &item.updated)]
+            .into_iter()
+            .filter(|(_, raw)| !raw.trim().is_empty() && self.parse(raw).is_none())
+            .map(|(field, _)| field)
+            .collect();
+        if unparsed.is_empty() {
+            return;
+        }
+        warn!(
+            "{} {}: unparseable {} timestamp(s): created '{}', updated '{}'",
+            item.platform,
+            item.id,
+            unparsed.join(" and "),
+            item.created,
+            item.updated
+        );
+        item.metadata
+            .insert(UNPARSED_DATES_KEY.to_string(), unparsed.join(", "));
+    }
+}
+
+fn parse_rfc3339(raw: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw)
+        .ok()
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+}
+
+fn parse_with_offset(raw: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M:%S%.f%z")
+        .ok()
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+}
+
+fn parse_naive(raw: &str, format: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(raw, format)
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+/// Seconds or, from 12 digits on, milliseconds since the epoch
+fn parse_epoch(raw: &str) -> Option<DateTime<Utc>> {
+    if raw.is_empty() || !raw.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let value: i64 = raw.parse().ok()?;
+    if raw.len() >= 12 {
+        DateTime::from_timestamp_millis(value)
+    } else {
+        DateTime::from_timestamp(value, 0)
+    }
+}
+
+/// Parse any timestamp format used by the platforms: RFC 3339 (GitLab),
+/// `2024-01-15T10:30:00.000+0000` (JIRA) and `2024-01-15 10:30:00.000000000` in UTC (Gerrit),
+/// plus lenient fallbacks: a comma as decimal separator, missing seconds or offset, bare dates,
+/// RFC 2822 and epoch seconds or milliseconds. Returns None instead of guessing.
+pub fn parse_timestamp(raw: &str) -> Option<DateTime<Utc>> {
+    let raw = raw.trim();
+    // Locales writing "10:30:00,123" for fractional seconds
+    let dotted = raw.replace(',', ".");
+    [raw, dotted.as_str()]
+        .into_iter()
+        .find_map(|raw| {
+            parse_rfc3339(raw)
+                .or_else(|| parse_with_offset(raw))
+                .or_else(|| {
+                    NAIVE_FORMATS
+                        .iter()
+                        .find_map(|format| parse_naive(raw, format))
+                })
+        })
+        .or_else(|| {
+            NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|naive| naive.and_utc())
+        })
+        .or_else(|| {
+            DateTime::parse_from_rfc2822(raw)
+                .ok()
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+        })
+        .or_else(|| parse_epoch(raw))
+}
+
+/// Coarse "N units ago" description of `timestamp` relative to `now`
+pub fn relative_time(timestamp: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let seconds = (now - timestamp).num_seconds();
+    if seconds < 0 {
+        return "in the future".to_string();
+    }
+    let (count, unit) = match seconds {
+        0..60 => return "just now".to_string(),
+        60..3_600 => (seconds / 60, "minute"),
+        3_600..86_400 => (seconds / 3_600, "hour"),
+        86_400..2_592_000 => (seconds / 86_400, "day"),
+        2_592_000..31_536_000 => (seconds / 2_592_000, "month"),
+        _ => (seconds / 31_536_000, "year"),
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    format!("{count} {unit}{plural} ago")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::ItemBuilder;
+
+    #[test]
+    fn test_parse_platform_formats() {
+        let expected = "2024-01-15T10:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(parse_timestamp("2024-01-15T10:30:00Z"), Some(expected));
+        assert_eq!(
+            parse_timestamp("2024-01-15T12:30:00.000+0200"),
+            Some(expected)
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-15 10:30:00.000000000"),
+            Some(expected)
+        );
+        assert_eq!(parse_timestamp("yesterday"), None);
+    }
+
+    #[test]
+    fn test_parse_fallbacks() {
+        let expected = "2024-01-15T10:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        for raw in [
+            "2024-01-15 10:30:00,000",
+            "2024-01-15T12:30:00+02:00",
+            "2024-01-15T10:30:00",
+            "2024-01-15 10:30",
+            "15/Jan/24 10:30 AM",
+            "Mon, 15 Jan 2024 10:30:00 +0000",
+            "1705314600",
+            "1705314600000",
+        ] {
+            assert_eq!(parse_timestamp(raw), Some(expected), "{raw}");
+        }
+        assert_eq!(
+            parse_timestamp("2024-01-15"),
+            "2024-01-15T00:00:00Z".parse::<DateTime<Utc>>().ok()
+        );
+        for raw in [
+            "",
+            "2024-13-45 10:30:00",
+            "99999999999999999999",
+            "15.01.2024 25:61",
+        ] {
+            assert_eq!(parse_timestamp(raw), None, "{raw}");
+        }
+    }
+
+    #[test]
+    fn test_per_platform_parsers() {
+        let expected = "2024-01-15T10:30:00Z".parse::<DateTime<Utc>>().ok();
+        assert_eq!(
+            TimestampSource::Gerrit.parse("2024-01-15 10:30:00.000000000"),
+            expected
+        );
+        assert_eq!(
+            TimestampSource::Jira.parse("2024-01-15T11:30:00.000+0100"),
+            expected
+        );
+        assert_eq!(
+            TimestampSource::GitLab.parse("2024-01-15T10:30:00Z"),
+            expected
+        );
+        // Other platforms' formats are still understood
+        assert_eq!(
+            TimestampSource::GitLab.parse("2024-01-15 10:30:00.000000000"),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_flag_unparsed_dates() {
+        let mut item = ItemBuilder::new("PROJ-1")
+            .with_created("2024-01-15T10:30:00.000+0000")
+            .with_updated("gestern")
+            .with_platform("jira")
+            .build();
+        TimestampSource::Jira.flag_unparsed_dates(&mut item);
+        assert_eq!(item.metadata[UNPARSED_DATES_KEY], "updated");
+
+        item.updated = item.created.clone();
+        item.metadata.clear();
+        TimestampSource::Jira.flag_unparsed_dates(&mut item);
+        assert!(item.metadata.is_empty());
+    }
+
+    #[test]
+    fn test_format_zones_and_relative() {
+        let now = "2024-01-18T10:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        let raw = "2024-01-15 10:30:00.000000000";
+
+        let utc = TimestampFormat::new(DisplayZone::Utc, false);
+        assert_eq!(utc.format_at(raw, now), "2024-01-15 10:30 UTC");
+
+        let berlin = TimestampFormat::new(DisplayZone::from_setting(Some("+01:00")), false);
+        assert_eq!(berlin.format_at(raw, now), "2024-01-15 11:30 +01:00");
+
+        let relative = TimestampFormat::new(DisplayZone::Utc, true);
+        assert_eq!(relative.format_at(raw, now), "3 days ago");
+        assert_eq!(relative.format_at("not a date", now), "not a date");
+    }
+}
@@ -0,0 +1,948 @@
+use crate::atomic_write::{backup_path, restore_backup, write_with_backup};
+use crate::category_rules::CategoryRule;
+use crate::domains::check_server_url;
+use crate::employee_import::LdapConfig;
+use crate::gerrit::GerritConfig;
+use crate::http::{DEFAULT_MAX_ATTEMPTS, HttpClientSettings, RetryPolicy};
+use crate::models::DataPath;
+use crate::scoring::ScoringConfig;
+use crate::secrets::{KeyringBackend, SecretBackendKind, SecretService};
+use crate::settings::Settings;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Global settings that apply across all platforms
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlobalSettings {
+    #[serde(default)]
+    pub allowed_domains: Vec<String>,
+    /// Refuse to contact Gerrit, JIRA or GitLab servers outside `allowed_domains`
+    #[serde(default)]
+    pub enforce_allowed_domains: bool,
+    /// Read the clipboard for evidence URLs when opening notes
+    #[serde(default = "default_true")]
+    pub clipboard_evidence: bool,
+    /// Keep full request URLs and response bodies in `error.log` instead of redacting them
+    #[serde(default)]
+    pub verbose_error_logs: bool,
+    /// Pseudonymize people and drop internal links in `report`, `team-review` and `org-stats`
+    #[serde(default)]
+    pub redact_exports: bool,
+    /// Where platform tokens and passwords are read from
+    #[serde(default)]
+    pub secret_backend: SecretBackendKind,
+    /// Attempts per platform GET when it is rate limited or fails transiently; 1 disables retries
+    #[serde(default = "default_http_max_attempts")]
+    pub http_max_attempts: u32,
+    /// age identity file that decrypts employees' encrypted notes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age_identity: Option<String>,
+    /// Proxy every platform request goes through, e.g. `http://proxy.corp.example.com:3128`;
+    /// unset uses `HTTPS_PROXY`/`HTTP_PROXY`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_proxy: Option<String>,
+    /// PEM file with extra CA certificates to trust, e.g. a private company CA
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_bundle_path: Option<String>,
+}
+
+fn default_http_max_attempts() -> u32 {
+    DEFAULT_MAX_ATTEMPTS
+}
+
+impl Default for GlobalSettings {
+    fn default() -> Self {
+        Self {
+            allowed_domains: Vec::new(),
+            enforce_allowed_domains: false,
+            clipboard_evidence: true,
+            verbose_error_logs: false,
+            redact_exports: false,
+            secret_backend: SecretBackendKind::Config,
+            http_max_attempts: DEFAULT_MAX_ATTEMPTS,
+            age_identity: None,
+            http_proxy: None,
+            ca_bundle_path: None,
+        }
+    }
+}
+
+/// Concurrency and paging limits for platform fetches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceConfig {
+    /// Platforms fetched at the same time
+    #[serde(default = "default_max_concurrent_platforms")]
+    pub max_concurrent_platforms: usize,
+    /// HTTP requests in flight per platform
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize,
+    /// Items requested per query (Gerrit `n`, JIRA `maxResults`, GitLab `per_page`)
+    #[serde(default = "default_page_size")]
+    pub page_size: u32,
+    /// How long cached platform activity stays fresh; 0 disables the activity cache
+    #[serde(default = "default_cache_ttl_minutes")]
+    pub cache_ttl_minutes: u64,
+}
+
+impl Default for PerformanceConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_platforms: default_max_concurrent_platforms(),
+            max_concurrent_requests: default_max_concurrent_requests(),
+            page_size: default_page_size(),
+            cache_ttl_minutes: default_cache_ttl_minutes(),
+        }
+    }
+}
+
+fn default_max_concurrent_platforms() -> usize {
+    4
+}
+fn default_max_concurrent_requests() -> usize {
+    4
+}
+fn default_page_size() -> u32 {
+    100
+}
+fn default_cache_ttl_minutes() -> u64 {
+    60
+}
+
+/// Timeout of platform requests when an instance sets no `request_timeout_secs`
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Request limits of one platform instance, set next to its URL and credentials
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestLimits {
+    /// HTTP requests in flight to this instance; `performance.max_concurrent_requests` when
+    /// not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_requests: Option<usize>,
+    /// Seconds before a request to this instance is given up
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_timeout_secs: Option<u64>,
+}
+
+impl RequestLimits {
+    pub fn max_concurrent_requests(&self, performance: &PerformanceConfig) -> usize {
+        self.max_concurrent_requests
+            .unwrap_or(performance.max_concurrent_requests)
+            .max(1)
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_secs(
+            self.request_timeout_secs
+                .unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS)
+                .max(1),
+        )
+    }
+}
+
+/// `[integrations]`: systems reviewr reads employees from, as opposed to activity platforms
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrationsConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ldap: Option<LdapConfig>,
+}
+
+impl IntegrationsConfig {
+    pub fn is_empty(&self) -> bool {
+        self.ldap.is_none()
+    }
+}
+
+/// Unified configuration supporting multiple review platforms
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnifiedConfig {
+    pub platforms: PlatformConfigs,
+    #[serde(default)]
+    pub global_settings: GlobalSettings,
+    pub ui_preferences: UiPreferences,
+    #[serde(default)]
+    pub performance: PerformanceConfig,
+    #[serde(default, skip_serializing_if = "ScoringConfig::is_default")]
+    pub scoring: ScoringConfig,
+    /// `[[category_rules]]`, tried in order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub category_rules: Vec<CategoryRule>,
+    #[serde(default, skip_serializing_if = "IntegrationsConfig::is_empty")]
+    pub integrations: IntegrationsConfig,
+    #[serde(default)]
+    pub version: u32,
+}
+
+impl Default for UnifiedConfig {
+    fn default() -> Self {
+        Self {
+            platforms: PlatformConfigs::default(),
+            global_settings: GlobalSettings::default(),
+            ui_preferences: UiPreferences::default(),
+            performance: PerformanceConfig::default(),
+            scoring: ScoringConfig::default(),
+            category_rules: Vec::new(),
+            integrations: IntegrationsConfig::default(),
+            version: 1,
+        }
+    }
+}
+
+/// Instance name of a Gerrit or JIRA server configured with the single-table form
+/// (`[platforms.gerrit]`); it keeps the plain `gerrit`/`jira` platform id
+pub const DEFAULT_INSTANCE: &str = "default";
+
+/// Platform id of a Gerrit or JIRA instance: `gerrit` for the default one, else `gerrit:<instance>`
+pub fn instance_platform_id(platform: &str, instance: &str) -> String {
+    if instance == DEFAULT_INSTANCE {
+        platform.to_string()
+    } else {
+        format!("{platform}:{instance}")
+    }
+}
+
+/// Check that a platform server URL is an absolute `http(s)://` URL with a host
+pub fn validate_platform_url(value: &str) -> io::Result<()> {
+    let invalid = |reason: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Invalid URL '{value}': {reason}"),
+        )
+    };
+    let url = url::Url::parse(value.trim()).map_err(|e| invalid(&e.to_string()))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(invalid("use http:// or https://"));
+    }
+    if url.host_str().is_none_or(str::is_empty) {
+        return Err(invalid("missing host"));
+    }
+    Ok(())
+}
+
+/// Configuration for all supported platforms
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PlatformConfigs {
+    /// Gerrit servers by instance name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", with = "instances")]
+    pub gerrit: HashMap<String, GerritConfig>,
+    /// JIRA servers by instance name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", with = "instances")]
+    pub jira: HashMap<String, JiraConfig>,
+    #[serde(default)]
+    pub gitlab: HashMap<String, GitLabConfig>,
+    /// Platforms backed by an external executable, by name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub custom: HashMap<String, CustomPlatformConfig>,
+}
+
+/// `[platforms.gerrit.<instance>]` tables, or the legacy single `[platforms.gerrit]` table that
+/// is read (and written back) as the `default` instance
+mod instances {
+    use super::DEFAULT_INSTANCE;
+    use serde::de::{DeserializeOwned, Error};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::HashMap;
+
+    pub fn serialize<S, T>(instances: &HashMap<String, T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        match instances.get(DEFAULT_INSTANCE) {
+            Some(config) if instances.len() == 1 => config.serialize(serializer),
+            _ => instances.serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<HashMap<String, T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: DeserializeOwned,
+    {
+        let table = toml::Table::deserialize(deserializer)?;
+        // Instance tables only hold tables; a single server has its URL and credentials inline
+        let single = table.values().any(|value| !value.is_table());
+        if single {
+            let config = T::deserialize(toml::Value::Table(table)).map_err(D::Error::custom)?;
+            return Ok(HashMap::from([(DEFAULT_INSTANCE.to_string(), config)]));
+        }
+        table
+            .into_iter()
+            .map(|(instance, value)| {
+                T::deserialize(value)
+                    .map(|config| (instance.clone(), config))
+                    .map_err(|e| D::Error::custom(format!("instance '{instance}': {e}")))
+            })
+            .collect()
+    }
+}
+
+/// JIRA platform configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraConfig {
+    pub jira_url: String,
+    pub username: String,
+    pub api_token: String,
+    #[serde(default)]
+    pub project_filter: Vec<String>,
+    #[serde(default)]
+    pub custom_fields: HashMap<String, String>,
+    /// Issue fields requested besides key, summary, status, dates and project; empty means
+    /// `DEFAULT_JIRA_FIELDS`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+    /// Search for the essential fields only and load the others per issue on demand
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub lazy_details: bool,
+    /// Skip TLS certificate verification, e.g. for a test server with a self-signed certificate
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub accept_invalid_certs: bool,
+    #[serde(flatten)]
+    pub limits: RequestLimits,
+}
+
+/// Fields of every JIRA issue fetched unless `fields` says otherwise
+pub const DEFAULT_JIRA_FIELDS: [&str; 6] = [
+    "assignee",
+    "reporter",
+    "resolutiondate",
+    "issuetype",
+    "priority",
+    "components",
+];
+
+impl JiraConfig {
+    /// The `fields` query parameter for a full issue: the essentials plus the configured fields
+    pub fn detail_fields(&self) -> String {
+        let mut fields: Vec<&str> = JIRA_ESSENTIAL_FIELDS.to_vec();
+        let configured: Vec<&str> = if self.fields.is_empty() {
+            DEFAULT_JIRA_FIELDS.to_vec()
+        } else {
+            self.fields.iter().map(|field| field.trim()).collect()
+        };
+        for field in configured {
+            if !field.is_empty() && !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+        fields.join(",")
+    }
+
+    /// The `fields` query parameter of issue searches
+    pub fn search_fields(&self) -> String {
+        if self.lazy_details {
+            JIRA_ESSENTIAL_FIELDS.join(",")
+        } else {
+            self.detail_fields()
+        }
+    }
+}
+
+/// Fields every issue list needs; always requested
+const JIRA_ESSENTIAL_FIELDS: [&str; 5] = ["summary", "status", "created", "updated", "project"];
+
+/// GitLab platform configuration for a single instance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabConfig {
+    pub name: String,
+    pub url: String,
+    pub token: String,
+    /// Skip TLS certificate verification, e.g. for a test server with a self-signed certificate
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub accept_invalid_certs: bool,
+    #[serde(flatten)]
+    pub limits: RequestLimits,
+}
+
+impl GitLabConfig {
+    /// Check if this GitLab configuration is valid and complete
+    pub fn is_configured(&self) -> bool {
+        !self.url.is_empty() && !self.token.is_empty() && !self.name.is_empty()
+    }
+
+    /// Get the API base URL for this GitLab instance
+    pub fn api_base_url(&self) -> String {
+        format!("{}/api/v4", self.url.trim_end_matches('/'))
+    }
+}
+
+/// `[platforms.custom.<name>]`: an executable that prints the activity items of a user as JSON
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CustomPlatformConfig {
+    /// Program to run; looked up in `PATH` unless it is a path
+    pub command: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    /// Name shown in the CLI and TUI; defaults to the table name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// Seconds the command may run before it is killed
+    #[serde(default = "default_command_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_command_timeout_secs() -> u64 {
+    60
+}
+
+/// UI preferences and customization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UiPreferences {
+    #[serde(default = "default_time_period")]
+    pub default_time_period_days: u32,
+    #[serde(default = "default_true")]
+    pub show_platform_icons: bool,
+    #[serde(default)]
+    pub preferred_platform_order: Vec<String>,
+    #[serde(default)]
+    pub theme: UiTheme,
+    /// Zone for displayed timestamps: "local" (default), "UTC" or an offset like "+02:00"
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// Show item timestamps as "3 days ago" in the review browser
+    #[serde(default)]
+    pub relative_timestamps: bool,
+    /// Report authored changes per XS/S/M/L size bucket; GitLab then fetches each authored merge
+    /// request's diff to count its lines
+    #[serde(default)]
+    pub size_buckets: bool,
+    /// Command opening item URLs instead of the system browser, e.g.
+    /// `google-chrome --profile-directory="Profile 2" {url}`; without `{url}` the URL is appended
+    #[serde(default)]
+    pub browser_command: Option<String>,
+    /// Refetch every platform in the background this often while the review browser is open;
+    /// 0 turns it off
+    #[serde(default)]
+    pub auto_refresh_minutes: u64,
+}
+
+impl Default for UiPreferences {
+    fn default() -> Self {
+        Self {
+            default_time_period_days: 30,
+            show_platform_icons: true,
+            preferred_platform_order: vec!["gerrit".to_string(), "jira".to_string()],
+            theme: UiTheme::Default,
+            timezone: None,
+            relative_timestamps: false,
+            size_buckets: false,
+            browser_command: None,
+            auto_refresh_minutes: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub enum UiTheme {
+    #[default]
+    Default,
+    Dark,
+    Light,
+    HighContrast,
+}
+
+fn default_time_period() -> u32 {
+    30
+}
+/// `path` with a leading `~/` resolved against the home directory
+fn expand_home(path: &str) -> Option<PathBuf> {
+    match path.strip_prefix("~/") {
+        Some(relative) => dirs::home_dir().map(|home| home.join(relative)),
+        None => Some(PathBuf::from(path)),
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Service for managing unified configuration
+pub struct UnifiedConfigService;
+
+impl UnifiedConfigService {
+    /// Load unified configuration, migrating from legacy if needed
+    pub fn load_config(data_path: &DataPath) -> io::Result<UnifiedConfig> {
+        let unified_config_path = data_path.root.join("config.toml");
+
+        // Try to load unified config first
+        if unified_config_path.exists() {
+            let content = std::fs::read_to_string(&unified_config_path)?;
+            let config: UnifiedConfig = toml::from_str(&content).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Invalid unified config format: {e}"),
+                )
+            })?;
+            return Ok(config);
+        }
+
+        // If no unified config, create default
+        Self::create_default_config(data_path)
+    }
+
+    /// The configuration with `REVIEWR_*` environment overrides applied, for reading settings.
+    /// Use [`Self::load_config`] when the result is going to be saved.
+    pub fn load_effective_config(data_path: &DataPath) -> io::Result<UnifiedConfig> {
+        Settings::load(data_path)?.config()
+    }
+
+    /// Save unified configuration without ever leaving a truncated file behind; the previous
+    /// version is kept as `config.toml.bak`
+    pub fn save_config(config: &UnifiedConfig, data_path: &DataPath) -> io::Result<()> {
+        let config_path = data_path.root.join("config.toml");
+        let toml_content = toml::to_string_pretty(config).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to serialize config: {e}"),
+            )
+        })?;
+
+        write_with_backup(&config_path, toml_content)?;
+        log::info!("Saved unified configuration to {}", config_path.display());
+        Ok(())
+    }
+
+    /// Replace an unreadable `config.toml` with `config.toml.bak`, if the backup parses
+    pub fn restore_backup(data_path: &DataPath) -> io::Result<()> {
+        let config_path = data_path.config_path();
+        let backup = std::fs::read_to_string(backup_path(&config_path))?;
+        toml::from_str::<UnifiedConfig>(&backup).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("The configuration backup is not valid either: {e}"),
+            )
+        })?;
+        restore_backup(&config_path)?;
+        log::info!("Restored {} from its backup", config_path.display());
+        Ok(())
+    }
+
+    /// Create default configuration if none exists
+    fn create_default_config(_data_path: &DataPath) -> io::Result<UnifiedConfig> {
+        Ok(UnifiedConfig::default())
+    }
+
+    /// Platform configurations with their secrets taken from the keyring when it is the
+    /// configured backend. Never save these back: the keyring secrets would end up in the file.
+    pub fn load_platform_configs(data_path: &DataPath) -> io::Result<PlatformConfigs> {
+        let config = Self::load_effective_config(data_path)?;
+        let mut platforms = config.platforms;
+        if config.global_settings.secret_backend == SecretBackendKind::Keyring {
+            SecretService::resolve_platform_secrets(&mut platforms, &KeyringBackend);
+        }
+        Ok(platforms)
+    }
+
+    /// `[integrations.ldap]` with its bind password taken from the keyring when it is the
+    /// configured backend; NotFound when the section is missing
+    pub fn load_ldap_config(data_path: &DataPath) -> io::Result<LdapConfig> {
+        let config = Self::load_effective_config(data_path)?;
+        let mut ldap = config.integrations.ldap.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "No [integrations.ldap] section in {}",
+                    data_path.config_path().display()
+                ),
+            )
+        })?;
+        if config.global_settings.secret_backend == SecretBackendKind::Keyring {
+            SecretService::resolve_ldap_secret(&mut ldap, &KeyringBackend);
+        }
+        Ok(ldap)
+    }
+
+    /// With `enforce_allowed_domains`, check that every Gerrit, JIRA and GitLab server is on
+    /// `allowed_domains`; PermissionDenied naming each one that is not
+    pub fn check_platform_domains(data_path: &DataPath) -> io::Result<()> {
+        Self::check_config_domains(&Self::load_effective_config(data_path)?)
+    }
+
+    /// [`Self::check_platform_domains`] of a configuration that is not saved yet
+    pub fn check_config_domains(config: &UnifiedConfig) -> io::Result<()> {
+        let settings = &config.global_settings;
+        if !settings.enforce_allowed_domains {
+            return Ok(());
+        }
+        let platforms = &config.platforms;
+        let mut servers: Vec<(String, &str)> = Vec::new();
+        servers.extend(platforms.gerrit.iter().map(|(instance, gerrit)| {
+            (format!("Gerrit '{instance}'"), gerrit.gerrit_url.as_str())
+        }));
+        servers.extend(
+            platforms
+                .jira
+                .iter()
+                .map(|(instance, jira)| (format!("JIRA '{instance}'"), jira.jira_url.as_str())),
+        );
+        servers.extend(
+            platforms
+                .gitlab
+                .iter()
+                .filter(|(_, gitlab)| !gitlab.url.is_empty())
+                .map(|(instance, gitlab)| (format!("GitLab '{instance}'"), gitlab.url.as_str())),
+        );
+        servers.sort();
+
+        let rejected: Vec<String> = servers
+            .into_iter()
+            .filter_map(|(server, url)| {
+                check_server_url(url, &settings.allowed_domains)
+                    .err()
+                    .map(|e| format!("{server} ({url}): {e}"))
+            })
+            .collect();
+        if rejected.is_empty() {
+            return Ok(());
+        }
+        Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!(
+                "enforce_allowed_domains is on and no request was sent: {}. Fix the URL or add its domain with `reviewr config set allowed_domains`.",
+                rejected.join("; ")
+            ),
+        ))
+    }
+
+    /// Load the configuration of one Gerrit instance from unified config; fails while a
+    /// platform server is outside the enforced `allowed_domains`
+    pub fn load_gerrit_config(
+        data_path: &DataPath,
+        instance: &str,
+    ) -> io::Result<Option<GerritConfig>> {
+        Self::check_platform_domains(data_path)?;
+        let mut platforms = Self::load_platform_configs(data_path)?;
+        if let Some(gerrit_config) = platforms.gerrit.remove(instance) {
+            log::info!("Loaded Gerrit config '{instance}' from unified config");
+            Ok(Some(gerrit_config))
+        } else {
+            log::info!("Gerrit config '{instance}' not found in unified config");
+            Ok(None)
+        }
+    }
+
+    /// Load the `[performance]` section, falling back to defaults when the config is unreadable
+    pub fn load_performance_config(data_path: &DataPath) -> PerformanceConfig {
+        Self::load_effective_config(data_path)
+            .map(|config| config.performance)
+            .unwrap_or_default()
+    }
+
+    /// The `[scoring]` section, off when the config is unreadable
+    pub fn load_scoring_config(data_path: &DataPath) -> ScoringConfig {
+        Self::load_effective_config(data_path)
+            .map(|config| config.scoring)
+            .unwrap_or_default()
+    }
+
+    /// The `[[category_rules]]`, none when the config is unreadable
+    pub fn load_category_rules(data_path: &DataPath) -> Vec<CategoryRule> {
+        Self::load_effective_config(data_path)
+            .map(|config| config.category_rules)
+            .unwrap_or_default()
+    }
+
+    /// Retry policy from `global_settings.http_max_attempts`, falling back to the default when the
+    /// config is unreadable
+    pub fn load_retry_policy(data_path: &DataPath) -> RetryPolicy {
+        Self::load_effective_config(data_path)
+            .map(|config| RetryPolicy::new(config.global_settings.http_max_attempts))
+            .unwrap_or_default()
+    }
+
+    /// `ui_preferences.default_time_period_days`, falling back to the default when the config is
+    /// unreadable
+    pub fn load_default_period_days(data_path: &DataPath) -> u32 {
+        Self::load_effective_config(data_path)
+            .map(|config| config.ui_preferences.default_time_period_days)
+            .unwrap_or_else(|_| default_time_period())
+    }
+
+    /// `global_settings.age_identity` with a leading `~/` expanded, if configured
+    pub fn load_age_identity(data_path: &DataPath) -> Option<PathBuf> {
+        let identity = Self::load_effective_config(data_path)
+            .ok()?
+            .global_settings
+            .age_identity?;
+        expand_home(&identity)
+    }
+
+    /// `global_settings.http_proxy` and `ca_bundle_path` (with a leading `~/` expanded), none
+    /// when the config is unreadable
+    pub fn load_http_settings(data_path: &DataPath) -> HttpClientSettings {
+        let Ok(config) = Self::load_effective_config(data_path) else {
+            return HttpClientSettings::default();
+        };
+        let settings = config.global_settings;
+        HttpClientSettings {
+            proxy: settings.http_proxy.filter(|proxy| !proxy.trim().is_empty()),
+            ca_bundle: settings
+                .ca_bundle_path
+                .filter(|path| !path.trim().is_empty())
+                .and_then(|path| expand_home(&path)),
+        }
+    }
+
+    /// Load the configuration of one JIRA instance from unified config; fails while a platform
+    /// server is outside the enforced `allowed_domains`
+    pub fn load_jira_config(
+        data_path: &DataPath,
+        instance: &str,
+    ) -> io::Result<Option<JiraConfig>> {
+        Self::check_platform_domains(data_path)?;
+        let mut platforms = Self::load_platform_configs(data_path)?;
+        if let Some(jira_config) = platforms.jira.remove(instance) {
+            log::info!("Loaded JIRA config '{instance}' from unified config");
+            Ok(Some(jira_config))
+        } else {
+            log::info!("JIRA config '{instance}' not found in unified config");
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_http_max_attempts_default() {
+        assert_eq!(
+            GlobalSettings::default().http_max_attempts,
+            DEFAULT_MAX_ATTEMPTS
+        );
+        let settings: GlobalSettings = toml::from_str("http_max_attempts = 5").unwrap();
+        assert_eq!(settings.http_max_attempts, 5);
+        assert_eq!(
+            toml::from_str::<GlobalSettings>("")
+                .unwrap()
+                .http_max_attempts,
+            DEFAULT_MAX_ATTEMPTS
+        );
+    }
+
+    #[test]
+    fn test_clipboard_evidence_defaults_to_enabled() {
+        assert!(GlobalSettings::default().clipboard_evidence);
+
+        let settings: GlobalSettings = toml::from_str("allowed_domains = []").unwrap();
+        assert!(settings.clipboard_evidence);
+
+        let settings: GlobalSettings = toml::from_str("clipboard_evidence = false").unwrap();
+        assert!(!settings.clipboard_evidence);
+    }
+
+    #[test]
+    fn test_performance_section_is_optional() {
+        let config: UnifiedConfig =
+            toml::from_str("[platforms]\n[ui_preferences]\n[performance]\npage_size = 25\n")
+                .unwrap();
+        assert_eq!(config.performance.page_size, 25);
+        assert_eq!(config.performance.max_concurrent_platforms, 4);
+
+        let config: UnifiedConfig = toml::from_str("[platforms]\n[ui_preferences]\n").unwrap();
+        assert_eq!(config.performance.max_concurrent_requests, 4);
+    }
+
+    #[test]
+    fn test_single_and_multi_instance_platform_tables() {
+        let legacy: UnifiedConfig = toml::from_str(
+            "[platforms.gerrit]\ngerrit_url = \"https://gerrit.example.com\"\nusername = \"bot\"\nhttp_password = \"pw\"\n[ui_preferences]\n",
+        )
+        .unwrap();
+        assert_eq!(
+            legacy.platforms.gerrit[DEFAULT_INSTANCE].gerrit_url,
+            "https://gerrit.example.com"
+        );
+        // The single-table form is written back unchanged
+        let saved = toml::to_string(&legacy).unwrap();
+        assert!(saved.contains("[platforms.gerrit]\n"));
+
+        let multi: UnifiedConfig = toml::from_str(
+            "[platforms.jira.cloud]\njira_url = \"https://a.example.com\"\nusername = \"bot\"\napi_token = \"t\"\n\n[platforms.jira.datacenter]\njira_url = \"https://b.example.com\"\nusername = \"bot\"\napi_token = \"t\"\n[platforms.jira.datacenter.custom_fields]\nstory_points = \"customfield_10002\"\n[ui_preferences]\n",
+        )
+        .unwrap();
+        assert_eq!(multi.platforms.jira.len(), 2);
+        assert_eq!(
+            multi.platforms.jira["datacenter"].custom_fields["story_points"],
+            "customfield_10002"
+        );
+        let reloaded: UnifiedConfig = toml::from_str(&toml::to_string(&multi).unwrap()).unwrap();
+        assert_eq!(reloaded.platforms.jira.len(), 2);
+        assert!(reloaded.platforms.gerrit.is_empty());
+
+        assert_eq!(instance_platform_id("jira", DEFAULT_INSTANCE), "jira");
+        assert_eq!(instance_platform_id("jira", "cloud"), "jira:cloud");
+    }
+
+    #[test]
+    fn test_jira_field_selection() {
+        let mut config: JiraConfig = toml::from_str(
+            "jira_url = \"https://jira.example.com\"\nusername = \"bot\"\napi_token = \"t\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config.search_fields(),
+            "summary,status,created,updated,project,assignee,reporter,resolutiondate,issuetype,priority,components"
+        );
+        config.fields = vec!["priority".to_string(), " status ".to_string()];
+        assert_eq!(
+            config.detail_fields(),
+            "summary,status,created,updated,project,priority"
+        );
+        config.lazy_details = true;
+        assert_eq!(
+            config.search_fields(),
+            "summary,status,created,updated,project"
+        );
+        // Unset options stay out of config.toml
+        assert!(
+            !toml::to_string(&JiraConfig {
+                fields: Vec::new(),
+                lazy_details: false,
+                ..config
+            })
+            .unwrap()
+            .contains("lazy_details")
+        );
+    }
+
+    #[test]
+    fn test_validate_platform_url() {
+        assert!(validate_platform_url("https://gerrit.example.com").is_ok());
+        assert!(validate_platform_url("http://localhost:8080/jira/").is_ok());
+        for invalid in ["gerrit.example.com", "ftp://example.com", "https://", ""] {
+            let error = validate_platform_url(invalid).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_enforced_allowed_domains_refuse_platform_configs() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        std::fs::write(
+            data_path.config_path(),
+            "[global_settings]\nallowed_domains = [\"corp.example.com\"]\n\n\
+             [platforms.gerrit]\ngerrit_url = \"https://gerrit.corp.example.com\"\nusername = \"bot\"\nhttp_password = \"pw\"\n\n\
+             [platforms.gitlab.public]\nname = \"public\"\nurl = \"https://gitlab.com\"\ntoken = \"t\"\n\n[ui_preferences]\n",
+        )
+        .unwrap();
+        // Off by default: the domains only filter clipboard evidence
+        UnifiedConfigService::check_platform_domains(&data_path).unwrap();
+
+        let mut config = UnifiedConfigService::load_config(&data_path).unwrap();
+        config.global_settings.enforce_allowed_domains = true;
+        UnifiedConfigService::save_config(&config, &data_path).unwrap();
+        let error = UnifiedConfigService::check_platform_domains(&data_path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+        assert!(
+            error.to_string().contains(
+                "GitLab 'public' (https://gitlab.com): host 'gitlab.com' is not in allowed_domains"
+            ),
+            "{error}"
+        );
+        assert!(!error.to_string().contains("Gerrit"), "{error}");
+        // No client is built for any platform while one is misconfigured
+        assert!(UnifiedConfigService::load_gerrit_config(&data_path, DEFAULT_INSTANCE).is_err());
+
+        config.platforms.gitlab.clear();
+        UnifiedConfigService::save_config(&config, &data_path).unwrap();
+        UnifiedConfigService::check_platform_domains(&data_path).unwrap();
+        assert!(
+            UnifiedConfigService::load_gerrit_config(&data_path, DEFAULT_INSTANCE)
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_http_settings_and_accept_invalid_certs() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        assert_eq!(
+            UnifiedConfigService::load_http_settings(&data_path),
+            HttpClientSettings::default()
+        );
+
+        std::fs::write(
+            data_path.config_path(),
+            "[global_settings]\nhttp_proxy = \"http://proxy.corp.example.com:3128\"\nca_bundle_path = \"/etc/corp/ca.pem\"\n\n\
+             [platforms.gitlab.lab]\nname = \"lab\"\nurl = \"https://gitlab.test\"\ntoken = \"t\"\naccept_invalid_certs = true\n\n[ui_preferences]\n",
+        )
+        .unwrap();
+        assert_eq!(
+            UnifiedConfigService::load_http_settings(&data_path),
+            HttpClientSettings {
+                proxy: Some("http://proxy.corp.example.com:3128".to_string()),
+                ca_bundle: Some(PathBuf::from("/etc/corp/ca.pem")),
+            }
+        );
+        let mut config = UnifiedConfigService::load_config(&data_path).unwrap();
+        assert!(config.platforms.gitlab["lab"].accept_invalid_certs);
+
+        // Verification stays on, and unmentioned, unless asked for
+        config
+            .platforms
+            .gitlab
+            .get_mut("lab")
+            .unwrap()
+            .accept_invalid_certs = false;
+        let saved = toml::to_string(&config).unwrap();
+        assert!(!saved.contains("accept_invalid_certs"), "{saved}");
+    }
+
+    #[test]
+    fn test_per_platform_request_limits() {
+        let config: UnifiedConfig = toml::from_str(
+            "[performance]\nmax_concurrent_requests = 6\n\n\
+             [platforms.gerrit.review]\ngerrit_url = \"https://review.test\"\nusername = \"u\"\n\
+             http_password = \"p\"\nmax_concurrent_requests = 2\nrequest_timeout_secs = 90\n\n\
+             [platforms.gitlab.lab]\nname = \"lab\"\nurl = \"https://gitlab.test\"\ntoken = \"t\"\n\n[ui_preferences]\n",
+        )
+        .unwrap();
+        let gerrit = &config.platforms.gerrit["review"].limits;
+        assert_eq!(gerrit.max_concurrent_requests(&config.performance), 2);
+        assert_eq!(gerrit.request_timeout(), Duration::from_secs(90));
+
+        // Unset limits fall back to [performance] and the default timeout, and are not saved
+        let gitlab = &config.platforms.gitlab["lab"].limits;
+        assert_eq!(gitlab.max_concurrent_requests(&config.performance), 6);
+        assert_eq!(
+            gitlab.request_timeout(),
+            Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS)
+        );
+        let saved = toml::to_string(&config).unwrap();
+        assert_eq!(saved.matches("request_timeout_secs").count(), 1, "{saved}");
+    }
+
+    #[test]
+    fn test_save_config_replaces_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let mut config = UnifiedConfig::default();
+        config.global_settings.http_max_attempts = 7;
+        UnifiedConfigService::save_config(&config, &data_path).unwrap();
+        config.global_settings.http_max_attempts = 2;
+        UnifiedConfigService::save_config(&config, &data_path).unwrap();
+
+        let loaded = UnifiedConfigService::load_config(&data_path).unwrap();
+        assert_eq!(loaded.global_settings.http_max_attempts, 2);
+        assert!(
+            std::fs::read_dir(temp_dir.path())
+                .unwrap()
+                .all(|entry| !entry.unwrap().path().to_string_lossy().ends_with(".tmp"))
+        );
+
+        // A corrupted config can be brought back from the previous save
+        std::fs::write(data_path.config_path(), "[global_settings\n").unwrap();
+        assert!(UnifiedConfigService::load_config(&data_path).is_err());
+        UnifiedConfigService::restore_backup(&data_path).unwrap();
+        let restored = UnifiedConfigService::load_config(&data_path).unwrap();
+        assert_eq!(restored.global_settings.http_max_attempts, 7);
+    }
+}
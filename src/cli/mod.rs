@@ -1,17 +1,117 @@
 use crate::core::{
-    employee::EmployeeService,
+    annotations::AnnotationStore,
+    anonymize::Anonymizer,
+    atomic_write::backup_path,
+    bulk_edit::{BulkEditService, EmployeeFilter, FieldChange},
+    bundle::BundleService,
+    cache::ActivityCache,
+    command_platform::GenericCommandPlatform,
+    config_check::check_config_file,
+    cycle::CycleService,
+    employee::{EmployeeService, RenameConflict},
+    employee_import::{EmployeeImportService, ImportAction, ImportRecord, OnConflict, parse_csv},
+    feedback::FeedbackService,
     gerrit::GerritPlatform,
     gitlab::GitLabPlatform,
+    goals::GoalsService,
+    handoff::HandoffService,
     jira::JiraPlatform,
-    models::{DataPath, validate_domain},
+    journal::JournalService,
+    models::{DataPath, Employee, validate_domain},
+    note_encryption::{AgeCli, NoteEncryptionService},
     notes::NotesService,
-    platform::{ErrorLogReader, PlatformRegistry},
-    unified_config::UnifiedConfigService,
+    org_stats::OrgStats,
+    period_comparison::previous_period_counts,
+    platform::{
+        ConnectionStatus, DetailedActivities, ErrorLogReader, PlatformRegistry, ReviewPlatform,
+        Severity, error_log_path,
+    },
+    recent::RecentService,
+    report::{PlatformSection, ReportService, ReviewPacket},
+    scoring::format_index,
+    search_index::{DocumentKind, SearchIndexService},
+    secrets::{KeyringBackend, SecretBackendKind, SecretService},
+    settings::{SETTINGS, Setting, SettingSource, Settings},
+    setup::{PlatformSetup, SetupPlatform},
+    team::TeamComparison,
+    timestamps::TimestampFormat,
+    unified_config::{
+        DEFAULT_INSTANCE, UnifiedConfig, UnifiedConfigService, validate_platform_url,
+    },
 };
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use crossterm::{
+    event::{self, Event, KeyCode, KeyModifiers},
+    terminal::{disable_raw_mode, enable_raw_mode},
+};
+use futures::StreamExt;
 use log::{error, info};
-use std::io::{self, Write};
-use std::path::PathBuf;
+use nucleo::{Config, Matcher, Utf32Str};
+use std::collections::HashMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+/// `println!` through [`crate::terminal::styled`], so `--no-color` covers every line
+macro_rules! outln {
+    () => {
+        println!()
+    };
+    ($($arg:tt)*) => {
+        println!("{}", $crate::terminal::styled(&format!($($arg)*)))
+    };
+}
+
+/// `print!` through [`crate::terminal::styled`]
+macro_rules! out {
+    ($($arg:tt)*) => {
+        print!("{}", $crate::terminal::styled(&format!($($arg)*)))
+    };
+}
+
+/// `eprintln!` through [`crate::terminal::styled`]
+macro_rules! errln {
+    ($($arg:tt)*) => {
+        eprintln!("{}", $crate::terminal::styled(&format!($($arg)*)))
+    };
+}
+
+/// Output of the commands scripts read: `list`, `review` and `config get`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Text for people, with emoji and colors
+    #[default]
+    Text,
+    /// One JSON document on stdout and nothing else
+    Json,
+}
+
+/// Print the JSON document of `--output json`. It bypasses [`crate::terminal::styled`], which
+/// would change the values.
+fn print_json(value: &serde_json::Value) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(value).map_err(io::Error::other)?;
+    println!("{json}");
+    Ok(())
+}
+
+/// Machine-readable output of `team-review`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    Csv,
+}
+
+/// Layout of `summary`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SummaryFormat {
+    /// Plain text for pasting into an email
+    Email,
+    /// HTML fragment for rich-text email
+    EmailHtml,
+}
 
 #[derive(Parser)]
 #[command(name = "reviewr")]
@@ -23,32 +123,351 @@ pub struct Cli {
     /// Sets a custom data path
     #[arg(long, value_name = "FILE")]
     pub data_path: Option<PathBuf>,
+
+    /// Print plain text without ANSI styling or emoji (also on with NO_COLOR or TERM=dumb)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+}
+
+impl Cli {
+    /// Parse the command line. `notes <name>` for an employee named like a `notes` subcommand,
+    /// e.g. "stats", is not a valid use of that subcommand; it opens that employee's notes
+    /// instead when the employee exists.
+    pub fn parse_args() -> Self {
+        let args: Vec<OsString> = env::args_os().collect();
+        match Self::try_parse_from(&args) {
+            Ok(cli) => cli,
+            Err(e) => Self::notes_employee_fallback(&args).unwrap_or_else(|| e.exit()),
+        }
+    }
+
+    /// `notes <subcommand name> <flags>` reparsed as `notes <flags> -- <name>`, when an
+    /// employee of that name exists
+    fn notes_employee_fallback(args: &[OsString]) -> Option<Self> {
+        let notes = Self::subcommand_position(args)?;
+        if args[notes] != "notes" {
+            return None;
+        }
+        let name = args.get(notes + 1)?.to_str()?;
+        Self::command()
+            .find_subcommand("notes")?
+            .find_subcommand(name)?;
+        let mut reparsed: Vec<OsString> = args
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != notes + 1)
+            .map(|(_, arg)| arg.clone())
+            .collect();
+        reparsed.extend(["--".into(), name.into()]);
+        let cli = Self::try_parse_from(reparsed).ok()?;
+        let data_path = DataPath::new(cli.data_path.clone()).ok()?;
+        EmployeeService::employee_exists(&data_path, name).then_some(cli)
+    }
+
+    /// Index of the subcommand in `args`, skipping the top-level options and their values the
+    /// way clap does, so `--data-path notes` is not taken for the `notes` subcommand
+    fn subcommand_position(args: &[OsString]) -> Option<usize> {
+        let command = Self::command();
+        let mut position = 1;
+        while let Some(arg) = args.get(position) {
+            let arg = arg.to_str()?;
+            if arg == "--" {
+                return None;
+            }
+            let Some(option) = arg.strip_prefix('-') else {
+                return Some(position);
+            };
+            let (option, inline_value) = match option.split_once('=') {
+                Some((option, _)) => (option, true),
+                None => (option, false),
+            };
+            let takes_value = command.get_arguments().any(|argument| {
+                let matches = match option.strip_prefix('-') {
+                    Some(long) => argument.get_long() == Some(long),
+                    None => {
+                        option.chars().count() == 1 && argument.get_short() == option.chars().next()
+                    }
+                };
+                matches && argument.get_action().takes_values()
+            });
+            position += if takes_value && !inline_value { 2 } else { 1 };
+        }
+        None
+    }
 }
 
 #[derive(Subcommand)]
 pub enum Commands {
+    /// Set up reviewr step by step: the first platform with a live connection test, allowed
+    /// domains and an optional employee import
+    Init,
     /// Add a new employee
     Add {
         /// The name of the employee (optional - if not provided, opens TUI form)
         employee: Option<String>,
     },
     /// Open notes for an employee
+    #[command(args_conflicts_with_subcommands = true)]
     Notes {
+        #[command(subcommand)]
+        command: Option<NotesCommands>,
         /// The name of the employee (optional - if not provided, opens TUI selector)
         employee: Option<String>,
+        /// Do not read the clipboard for evidence URLs
+        #[arg(long)]
+        no_clipboard: bool,
+        /// Start a new section from a template: one-on-one, quarterly, perf-review or any
+        /// `templates/<name>.md` in the data directory
+        #[arg(long, value_name = "NAME")]
+        template: Option<String>,
+        /// Add a timestamped entry with this text instead of opening the editor
+        #[arg(long, value_name = "TEXT", requires = "employee", conflicts_with_all = ["template", "list"])]
+        append: Option<String>,
+        /// List the dated entries instead of opening the editor
+        #[arg(long, requires = "employee", conflicts_with = "template")]
+        list: bool,
+        /// Read the notes in a pager instead of the editor: jump between headings and open
+        /// evidence links (same as `notes show`)
+        #[arg(long, requires = "employee", conflicts_with_all = ["template", "append", "list"])]
+        view: bool,
+    },
+    /// Add and edit an employee's goals with their status, target date and evidence links
+    Goals {
+        /// The name of the employee (optional - if not provided, opens the selector)
+        employee: Option<String>,
+    },
+    /// Weekly journaling pass: recent highlights, then each employee's notes in turn
+    Journal {
+        /// Also include employees already journaled this week
+        #[arg(long)]
+        all: bool,
     },
     /// Edit an employee's information
     Edit {
         /// The name of the employee (optional - if not provided, opens TUI selector)
         employee: Option<String>,
+        /// Edit the employee TOML directly in $EDITOR instead of the form
+        #[arg(long)]
+        raw: bool,
+    },
+    /// Rename an employee together with their notes, goals, reports and cached activity
+    Rename {
+        /// The current name of the employee
+        old: String,
+        /// The new name
+        new: String,
+        /// Append to notes and goals that already exist under the new name without asking
+        #[arg(long)]
+        merge: bool,
     },
     /// List all employees
-    List,
+    List {
+        /// Also show archived employees
+        #[arg(long)]
+        all: bool,
+        /// Show team, manager, start date, location and tags
+        #[arg(short, long)]
+        verbose: bool,
+        /// json: every field of each employee
+        #[arg(long, value_enum, default_value_t)]
+        output: OutputFormat,
+    },
+    /// Permanently delete an employee with their notes and goals
+    Remove {
+        /// The name of the employee
+        employee: String,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Set fields of every employee in a team, under a manager or with a tag at once
+    BulkEdit {
+        /// Only employees in this team (case-insensitive)
+        #[arg(long)]
+        team: Option<String>,
+        /// Only employees with this manager (case-insensitive)
+        #[arg(long)]
+        manager: Option<String>,
+        /// Only employees with this tag (case-insensitive)
+        #[arg(long)]
+        tag: Option<String>,
+        /// Edit every employee; required when no filter is given
+        #[arg(long, conflicts_with_all = ["team", "manager", "tag"])]
+        all: bool,
+        /// field=value to set: title, committer_email, team, manager, start_date, location or
+        /// tags (comma-separated); an empty value clears optional fields
+        #[arg(long = "set", value_name = "FIELD=VALUE", required = true)]
+        changes: Vec<FieldChange>,
+        /// Show what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Create employees in bulk from a CSV file or the LDAP directory in [integrations.ldap]
+    Import {
+        #[command(subcommand)]
+        command: ImportCommands,
+    },
+    /// Move an employee with their notes and goals to the archive
+    Archive {
+        /// The name of the employee
+        employee: String,
+    },
+    /// Copy an employee's record, notes, goals, activity snapshots and annotations to a folder
+    /// for another manager, or import such a folder with --import
+    Handoff {
+        /// The name of the employee
+        #[arg(required_unless_present = "import", conflicts_with = "import")]
+        employee: Option<String>,
+        /// Folder to create; must not exist or be empty
+        #[arg(long, short, required_unless_present = "import")]
+        output: Option<PathBuf>,
+        /// Encrypt every file to this age recipient (age1... or ssh-ed25519 public key)
+        #[arg(long, conflicts_with = "import")]
+        recipient: Option<String>,
+        /// Add the employee from a handoff folder to this data directory
+        #[arg(long, value_name = "FOLDER", conflicts_with = "output")]
+        import: Option<PathBuf>,
+        /// age identity file for an encrypted handoff; defaults to the age_identity setting
+        #[arg(long, requires = "import")]
+        identity: Option<PathBuf>,
+    },
+    /// Print employee names one per line (for shell completion)
+    Completions,
     /// Generate review report for an employee
     Review {
         /// The name of the employee (optional - if not provided, opens TUI selector)
         employee: Option<String>,
+        /// Ignore cached platform activity and fetch everything again
+        #[arg(long)]
+        refresh: bool,
+        /// Review period in days (defaults to ui_preferences.default_time_period_days)
+        #[arg(long)]
+        days: Option<u32>,
+        /// Show the last fetched activity without contacting any platform
+        #[arg(long, conflicts_with = "refresh")]
+        offline: bool,
+        /// Print the queries each platform would run, with secrets masked, without running them
+        #[arg(long, conflicts_with_all = ["refresh", "offline"])]
+        explain: bool,
+        /// json: print the item counts per platform and category instead of opening the TUI
+        #[arg(long, value_enum, default_value_t, conflicts_with = "explain")]
+        output: OutputFormat,
+    },
+    /// Export a Markdown review packet with platform activity, goals and notes
+    Report {
+        /// The name of the employee
+        employee: String,
+        /// Output file (defaults to reports/<employee>-<date>.md in the data directory)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Replace people with pseudonyms and drop internal links (global_settings.redact_exports)
+        #[arg(long)]
+        redact: bool,
+        /// Cover this review cycle instead of the active one
+        #[arg(long)]
+        cycle: Option<String>,
+        /// Compare the item counts with the equally long period before
+        #[arg(long)]
+        compare: bool,
+    },
+    /// Start, close and inspect review cycles that scope notes and review packets
+    Cycle {
+        #[command(subcommand)]
+        command: CycleCommands,
+    },
+    /// Print a compact activity summary to send with a self-review request
+    Summary {
+        /// The name of the employee
+        employee: String,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = SummaryFormat::Email)]
+        format: SummaryFormat,
+        /// Period in days (defaults to ui_preferences.default_time_period_days)
+        #[arg(long)]
+        days: Option<u32>,
+        /// Most recently updated items listed per category
+        #[arg(long, default_value_t = 3)]
+        top: usize,
+        /// Write the summary to this file instead of printing it
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Compare the item counts with the equally long period before
+        #[arg(long)]
+        compare: bool,
+    },
+    /// Draft peer feedback requests, suggesting collaborators from cached activity
+    Feedback {
+        /// The name of the employee
+        employee: String,
+        /// Period in days (defaults to ui_preferences.default_time_period_days)
+        #[arg(long)]
+        days: Option<u32>,
+        /// How many collaborators to draft a request for
+        #[arg(long, default_value_t = 3)]
+        ask: usize,
+        /// Write the requests to this file instead of printing them
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Show org-wide distributions of cached activity to calibrate individual numbers
+    OrgStats {
+        /// Period in days (defaults to ui_preferences.default_time_period_days)
+        #[arg(long)]
+        days: Option<u32>,
+        /// Replace employee names with pseudonyms (global_settings.redact_exports)
+        #[arg(long)]
+        redact: bool,
+    },
+    /// Compare activity per category across employees, fetching every platform concurrently
+    TeamReview {
+        /// Only include employees whose team matches (case-insensitive)
+        #[arg(long)]
+        team: Option<String>,
+        /// Review period in days (defaults to ui_preferences.default_time_period_days)
+        #[arg(long)]
+        days: Option<u32>,
+        /// Ignore cached platform activity and fetch everything again
+        #[arg(long)]
+        refresh: bool,
+        /// Print the table in this format instead of opening the TUI
+        #[arg(long, value_enum)]
+        export: Option<ExportFormat>,
+        /// Write the export to a file instead of stdout
+        #[arg(short, long, requires = "export")]
+        output: Option<PathBuf>,
+        /// Replace employee names with pseudonyms (global_settings.redact_exports)
+        #[arg(long)]
+        redact: bool,
+    },
+    /// Overview of the whole team: activity per employee and platform, refreshed in the background
+    Dashboard {
+        /// Only include employees whose team matches (case-insensitive)
+        #[arg(long)]
+        team: Option<String>,
+        /// Period in days (defaults to ui_preferences.default_time_period_days)
+        #[arg(long)]
+        days: Option<u32>,
+    },
+    /// Search fetched items and notes of every employee offline; opens an interactive search
+    /// without a query
+    Search {
+        /// Words that must all appear; a word also matches longer words starting with it
+        query: Vec<String>,
+        /// Only search the items and notes of this employee
+        #[arg(short, long)]
+        employee: Option<String>,
+        /// Number of results to show
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
+        /// Index everything again even when nothing changed
+        #[arg(long)]
+        reindex: bool,
+        /// json: every field of each result
+        #[arg(long, value_enum, default_value_t)]
+        output: OutputFormat,
     },
+    /// Check configuration, data directory permissions, employee files and platform connectivity
+    Doctor,
     /// Manage configuration
     Config {
         #[command(subcommand)]
@@ -67,6 +486,9 @@ pub enum ConfigCommands {
     Get {
         /// The key to get
         key: String,
+        /// json: the value with where it came from; unknown keys fail
+        #[arg(long, value_enum, default_value_t)]
+        output: OutputFormat,
     },
     /// Set a configuration value
     Set {
@@ -75,10 +497,108 @@ pub enum ConfigCommands {
         /// The value to set
         value: String,
     },
+    /// Add, edit and remove Gerrit, JIRA and GitLab instances interactively
+    Edit,
+    /// Check config.toml for unknown keys, missing platform fields and invalid URLs; fails when
+    /// any is found
+    Validate,
+    /// Store a platform token or password in the OS keyring instead of config.toml
+    SetSecret {
+        /// gerrit, jira (optionally with :<instance>), gitlab:<instance> or ldap
+        platform: String,
+    },
+    /// Write config.toml and note templates to a tar bundle for another machine
+    Export {
+        /// Bundle file to create
+        #[arg(long, short)]
+        output: PathBuf,
+        /// Keep platform tokens and passwords (including keyring secrets) in the bundle
+        #[arg(long)]
+        include_secrets: bool,
+    },
+    /// Unpack a bundle created by `config export` into the data directory
+    Import {
+        /// Bundle file to read
+        bundle: PathBuf,
+        /// Replace existing files
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ImportCommands {
+    /// Read employees from a CSV file with name, title, email and team columns
+    Csv {
+        /// CSV file with a header row; email and team are optional
+        file: PathBuf,
+        /// Show what would be created without writing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// What to do when an employee with the same name or email exists: skip, update or
+        /// abort
+        #[arg(long, default_value = "skip")]
+        on_conflict: OnConflict,
+    },
+    /// Search the directory configured under [integrations.ldap] with ldapsearch
+    Ldap {
+        /// Show what would be created without writing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// What to do when an employee with the same name or email exists: skip, update or
+        /// abort
+        #[arg(long, default_value = "skip")]
+        on_conflict: OnConflict,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum NotesCommands {
+    /// Read an employee's notes, rendered in a pager with search, heading jumps and link opening
+    Show {
+        /// The name of the employee
+        employee: String,
+    },
+    /// Show word counts, evidence links and tags per notes section
+    Stats {
+        /// The name of the employee
+        employee: String,
+    },
+    /// List lines in other employees' notes that mention `@<employee>`
+    Mentions {
+        /// The name of the employee
+        employee: String,
+    },
+    /// Encrypt an employee's notes to an age public key; they are decrypted while editing
+    Encrypt {
+        /// The name of the employee
+        employee: String,
+        /// age recipient (`age1...` or an SSH public key)
+        recipient: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum CycleCommands {
+    /// Start a review cycle today; new notes entries are tagged with it until it is closed
+    Start {
+        /// Name of the cycle, e.g. 2025-H1
+        name: String,
+    },
+    /// End the active review cycle today
+    Close,
+    /// Show the active cycle with notes entries per employee, and the cycles before it
+    Status,
 }
 
 #[derive(Subcommand)]
 pub enum ErrorCommands {
+    /// Browse the error log interactively: filter entries, read response bodies, open URLs
+    Browse {
+        /// Platform to show first
+        #[arg(short, long)]
+        platform: Option<String>,
+    },
     /// Show recent errors
     List {
         /// Platform to filter by
@@ -87,6 +607,9 @@ pub enum ErrorCommands {
         /// Number of errors to show
         #[arg(short, long, default_value = "10")]
         limit: usize,
+        /// Only show entries of this severity: error or warning (e.g. connection timeouts)
+        #[arg(short, long)]
+        severity: Option<Severity>,
     },
     /// Show error statistics by platform
     Stats,
@@ -104,133 +627,2055 @@ pub enum ErrorCommands {
 }
 
 /// Initialize the platform registry with all available platforms
-fn create_platform_registry(data_path: &DataPath) -> PlatformRegistry {
+fn create_platform_registry(data_path: &DataPath) -> io::Result<PlatformRegistry> {
+    // Refuse misconfigured servers before any platform sends a request
+    UnifiedConfigService::check_platform_domains(data_path)?;
     let mut registry = PlatformRegistry::new();
+    let platforms = UnifiedConfigService::load_platform_configs(data_path).ok();
 
-    // Register Gerrit platform
-    let gerrit_platform = GerritPlatform::new(data_path.clone());
-    registry.register_platform(Box::new(gerrit_platform));
-
-    // Register JIRA platform
-    let jira_platform = JiraPlatform::new(data_path.clone());
-    registry.register_platform(Box::new(jira_platform));
+    // Register one Gerrit and JIRA platform per instance; without any, the default
+    // instance is still registered so it is reported as not configured
+    let instances = |configured: Vec<&String>| {
+        let mut instances: Vec<String> = configured.into_iter().cloned().collect();
+        if instances.is_empty() {
+            instances.push(DEFAULT_INSTANCE.to_string());
+        }
+        instances
+    };
+    let gerrit = platforms.as_ref().map(|p| p.gerrit.keys().collect());
+    for instance in instances(gerrit.unwrap_or_default()) {
+        let gerrit_platform = GerritPlatform::for_instance(data_path.clone(), &instance);
+        registry.register_platform(Box::new(gerrit_platform));
+    }
+    let jira = platforms.as_ref().map(|p| p.jira.keys().collect());
+    for instance in instances(jira.unwrap_or_default()) {
+        let jira_platform = JiraPlatform::for_instance(data_path.clone(), &instance);
+        registry.register_platform(Box::new(jira_platform));
+    }
 
     // Register GitLab platforms (multiple instances)
-    if let Ok(config) = UnifiedConfigService::load_config(data_path) {
-        for (instance_id, gitlab_config) in config.platforms.gitlab {
+    if let Some(platforms) = platforms {
+        for (instance_id, gitlab_config) in platforms.gitlab {
             if gitlab_config.is_configured() {
-                let gitlab_platform = GitLabPlatform::new(gitlab_config, instance_id, data_path);
+                let gitlab_platform = GitLabPlatform::new(gitlab_config, instance_id, data_path)?;
                 registry.register_platform(Box::new(gitlab_platform));
             }
         }
+        for (name, custom_config) in platforms.custom {
+            let custom_platform = GenericCommandPlatform::new(custom_config, &name);
+            registry.register_platform(Box::new(custom_platform));
+        }
+    }
+
+    Ok(registry)
+}
+
+/// Maximum number of "did you mean" suggestions for an unknown employee
+const MAX_EMPLOYEE_SUGGESTIONS: usize = 3;
+
+/// Existing employees whose names fuzzy-match `name`, best match first
+pub fn suggest_employees(data_path: &DataPath, name: &str) -> io::Result<Vec<String>> {
+    let mut matcher = Matcher::new(Config::DEFAULT);
+    let mut score = |haystack: &str, needle: &str| {
+        let mut haystack_buf = Vec::new();
+        let mut needle_buf = Vec::new();
+        matcher.fuzzy_match(
+            Utf32Str::new(haystack, &mut haystack_buf),
+            Utf32Str::new(needle, &mut needle_buf),
+        )
+    };
+
+    let mut matches: Vec<(String, u16)> = Vec::new();
+    for employee in EmployeeService::list_employees(data_path)? {
+        // Fall back to matching single words so a typo in one part of the name still hints
+        let best = score(&employee, name).or_else(|| {
+            name.split_whitespace()
+                .filter_map(|word| score(&employee, word))
+                .max()
+        });
+        if let Some(best) = best {
+            matches.push((employee, best));
+        }
+    }
+    matches.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    Ok(matches
+        .into_iter()
+        .take(MAX_EMPLOYEE_SUGGESTIONS)
+        .map(|(employee, _)| employee)
+        .collect())
+}
+
+/// Ask on stderr whether to go ahead; never when stdin is not a terminal
+fn confirm(prompt: &str) -> io::Result<bool> {
+    if !io::stdin().is_terminal() {
+        return Ok(false);
+    }
+    eprint!("{prompt} (y/n) ");
+    io::stderr().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().eq_ignore_ascii_case("y"))
+}
+
+/// Offer to restore `config.toml.bak` when `config.toml` cannot be parsed
+pub fn offer_config_restore(data_path: &DataPath, error: &io::Error) -> io::Result<()> {
+    let config_path = data_path.config_path();
+    let backup = backup_path(&config_path);
+    if !backup.exists() {
+        return Ok(());
+    }
+    errln!("⚠️  {} cannot be loaded: {error}", config_path.display());
+    if !confirm(&format!(
+        "Restore the last saved version from {}?",
+        backup.display()
+    ))? {
+        return Ok(());
+    }
+    UnifiedConfigService::restore_backup(data_path)?;
+    errln!(
+        "♻️  Restored {}; the damaged file was kept as config.toml.broken",
+        config_path.display()
+    );
+    Ok(())
+}
+
+/// Offer to restore `<name>.toml.bak` when an employee file cannot be parsed
+fn offer_employee_restore(data_path: &DataPath, name: &str, error: &io::Error) -> io::Result<()> {
+    let path = EmployeeService::employee_path(data_path, name);
+    if !backup_path(&path).exists() {
+        return Ok(());
+    }
+    errln!("⚠️  {} cannot be loaded: {error}", path.display());
+    if !confirm(&format!("Restore the last saved version of '{name}'?"))? {
+        return Ok(());
+    }
+    EmployeeService::restore_backup(data_path, name)?;
+    errln!(
+        "♻️  Restored {}; the damaged file was kept as {name}.toml.broken",
+        path.display()
+    );
+    Ok(())
+}
+
+/// Resolve an employee name given on the command line. Unknown names print the closest
+/// matches and, when interactive, let the user pick one (or add the name when `offer_add`).
+fn resolve_employee(
+    data_path: &DataPath,
+    name: &str,
+    offer_add: bool,
+) -> io::Result<Option<String>> {
+    if EmployeeService::employee_exists(data_path, name) {
+        if let Err(e) = EmployeeService::get_employee(data_path, name)
+            && e.kind() == io::ErrorKind::InvalidData
+        {
+            offer_employee_restore(data_path, name, &e)?;
+        }
+        return Ok(Some(name.to_string()));
+    }
+
+    outln!("Employee '{name}' not found.");
+    let suggestions = suggest_employees(data_path, name)?;
+    if suggestions.is_empty() {
+        if !offer_add {
+            return Ok(None);
+        }
+        out!("Would you like to add them? (y/n) ");
+        io::stdout().flush()?;
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        if input.trim().eq_ignore_ascii_case("y") {
+            EmployeeService::add_employee(data_path, name)?;
+            return Ok(Some(name.to_string()));
+        }
+        return Ok(None);
+    }
+
+    if let [only] = suggestions.as_slice() {
+        outln!("Did you mean '{only}'?");
+    } else {
+        outln!("Did you mean one of these?");
+    }
+    for (index, suggestion) in suggestions.iter().enumerate() {
+        outln!("  {}. {suggestion}", index + 1);
+    }
+    if !io::stdin().is_terminal() {
+        return Ok(None);
+    }
+
+    if offer_add {
+        out!("Pick a number, 'a' to add '{name}', or press Enter to cancel: ");
+    } else {
+        out!("Pick a number, or press Enter to cancel: ");
+    }
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let input = input.trim();
+    if offer_add && input.eq_ignore_ascii_case("a") {
+        EmployeeService::add_employee(data_path, name)?;
+        return Ok(Some(name.to_string()));
+    }
+    Ok(input
+        .parse::<usize>()
+        .ok()
+        .and_then(|choice| choice.checked_sub(1))
+        .and_then(|index| suggestions.get(index).cloned()))
+}
+
+/// Print employee names one per line, for shell completion scripts
+pub fn handle_completions_command(data_path: &DataPath) -> io::Result<()> {
+    for employee in EmployeeService::list_employees(data_path)? {
+        outln!("{employee}");
+    }
+    Ok(())
+}
+
+pub fn handle_list_command(
+    data_path: &DataPath,
+    include_archived: bool,
+    verbose: bool,
+    output: OutputFormat,
+) -> io::Result<()> {
+    let employees = EmployeeService::list_employees(data_path)?;
+    let archived = if include_archived {
+        EmployeeService::list_archived_employees(data_path)?
+    } else {
+        Vec::new()
+    };
+
+    if output == OutputFormat::Json {
+        let employees: Vec<serde_json::Value> = employees
+            .iter()
+            .map(
+                |employee_name| match EmployeeService::get_employee(data_path, employee_name) {
+                    Ok(employee) => serde_json::to_value(&employee).map_err(io::Error::other),
+                    Err(e) => Ok(serde_json::json!({
+                        "name": employee_name,
+                        "error": e.to_string(),
+                    })),
+                },
+            )
+            .collect::<io::Result<_>>()?;
+        return print_json(&serde_json::json!({
+            "employees": employees,
+            "archived": archived,
+        }));
+    }
+
+    if employees.is_empty() && archived.is_empty() {
+        outln!("No employees found.");
+        return Ok(());
+    }
+
+    outln!("Employees ({}):", employees.len());
+    outln!("{}", "=".repeat(20));
+
+    for employee_name in &employees {
+        match EmployeeService::get_employee(data_path, employee_name) {
+            Ok(employee) => {
+                outln!("• {} - {}", employee.name, employee.title);
+                if verbose && let Some(summary) = employee.profile.summary() {
+                    outln!("  {summary}");
+                }
+            }
+            Err(e) => {
+                outln!("• {employee_name} - (Error loading: {e})");
+            }
+        }
+    }
+
+    if !archived.is_empty() {
+        outln!();
+        outln!("Archived ({}):", archived.len());
+        outln!("{}", "=".repeat(20));
+        for employee_name in &archived {
+            outln!("• {employee_name}");
+        }
+    }
+
+    Ok(())
+}
+
+pub fn handle_bulk_edit_command(
+    data_path: &DataPath,
+    filter: EmployeeFilter,
+    all: bool,
+    changes: &[FieldChange],
+    dry_run: bool,
+) -> io::Result<()> {
+    if filter.is_empty() && !all {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Select employees with --team, --manager or --tag, or pass --all",
+        ));
+    }
+    let plan = BulkEditService::plan(data_path, &filter, changes)?;
+    if plan.is_empty() {
+        outln!("No matching employee needs a change.");
+        return Ok(());
+    }
+
+    for edit in &plan {
+        outln!("• {}", edit.updated.name);
+        for update in &edit.updates {
+            outln!("    {}: '{}' → '{}'", update.field, update.old, update.new);
+        }
+    }
+    if dry_run {
+        outln!("Dry run: {} employee(s) would be updated.", plan.len());
+        return Ok(());
+    }
+    BulkEditService::apply(data_path, &plan)?;
+    outln!("✅ Updated {} employee(s).", plan.len());
+    Ok(())
+}
+
+pub fn handle_import_command(data_path: &DataPath, command: &ImportCommands) -> io::Result<()> {
+    let (records, dry_run, on_conflict) = match command {
+        ImportCommands::Csv {
+            file,
+            dry_run,
+            on_conflict,
+        } => {
+            let content = fs::read_to_string(file).map_err(|e| {
+                io::Error::new(e.kind(), format!("Could not read {}: {e}", file.display()))
+            })?;
+            let records = parse_csv(&content)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", file.display())))?;
+            (records, *dry_run, *on_conflict)
+        }
+        ImportCommands::Ldap {
+            dry_run,
+            on_conflict,
+        } => {
+            let ldap = UnifiedConfigService::load_ldap_config(data_path)?;
+            outln!("🔎 Searching {} under {}...", ldap.url, ldap.base_dn);
+            (ldap.search()?, *dry_run, *on_conflict)
+        }
+    };
+    import_employees(data_path, records, dry_run, on_conflict)
+}
+
+/// Print what importing `records` does to each employee, then do it unless `dry_run`
+fn import_employees(
+    data_path: &DataPath,
+    records: Vec<ImportRecord>,
+    dry_run: bool,
+    on_conflict: OnConflict,
+) -> io::Result<()> {
+    if records.is_empty() {
+        outln!("No employees to import.");
+        return Ok(());
+    }
+
+    let plan = EmployeeImportService::plan(data_path, records, on_conflict)?;
+    let (mut created, mut updated, mut skipped) = (0, 0, 0);
+    for planned in &plan {
+        let record = &planned.record;
+        match &planned.action {
+            ImportAction::Create => {
+                created += 1;
+                let details: Vec<String> = [
+                    Some(record.title.clone()),
+                    record.email.clone(),
+                    record.team.as_ref().map(|team| format!("team {team}")),
+                ]
+                .into_iter()
+                .flatten()
+                .collect();
+                outln!("• {} (new): {}", record.name, details.join(", "));
+            }
+            ImportAction::Update(updates) => {
+                updated += 1;
+                outln!("• {} (update)", planned.employee.name);
+                for update in updates {
+                    outln!("    {}: '{}' → '{}'", update.field, update.old, update.new);
+                }
+            }
+            ImportAction::Skip(reason) => {
+                skipped += 1;
+                outln!(
+                    "⚠️  Skipping {} ({}): {reason}",
+                    record.origin,
+                    if record.name.is_empty() {
+                        "no name"
+                    } else {
+                        record.name.as_str()
+                    }
+                );
+            }
+        }
+    }
+    if dry_run {
+        outln!(
+            "Dry run: {created} employee(s) would be created, {updated} updated and {skipped} skipped."
+        );
+        return Ok(());
+    }
+    EmployeeImportService::apply(data_path, &plan)?;
+    outln!("✅ Created {created} employee(s), updated {updated} and skipped {skipped}.");
+    Ok(())
+}
+
+pub fn handle_remove_command(data_path: &DataPath, employee: &str, yes: bool) -> io::Result<()> {
+    let Some(employee) = resolve_employee(data_path, employee, false)? else {
+        return Ok(());
+    };
+
+    if !yes {
+        out!("Permanently delete '{employee}' including their notes, goals and reports? (y/n) ");
+        io::stdout().flush()?;
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        if !input.trim().eq_ignore_ascii_case("y") {
+            outln!("Removal cancelled.");
+            return Ok(());
+        }
+    }
+
+    EmployeeService::remove_employee(data_path, &employee)?;
+    ActivityCache::new(data_path, Duration::ZERO).invalidate_employee(&employee)?;
+    RecentService::forget(data_path, &employee)?;
+    outln!("Employee '{employee}' removed.");
+    Ok(())
+}
+
+pub fn handle_rename_command(
+    data_path: &DataPath,
+    old: &str,
+    new: &str,
+    merge: bool,
+) -> io::Result<()> {
+    let Some(old) = resolve_employee(data_path, old, false)? else {
+        return Ok(());
+    };
+
+    let mut on_conflict = if merge {
+        RenameConflict::Merge
+    } else {
+        RenameConflict::Abort
+    };
+    let conflicts = EmployeeService::rename_conflicts(data_path, &old, new);
+    if !merge && !conflicts.is_empty() {
+        errln!("Notes or goals for '{new}' already exist:");
+        for path in &conflicts {
+            errln!("  {}", path.display());
+        }
+        if confirm(&format!("Append the notes and goals of '{old}' to them?"))? {
+            on_conflict = RenameConflict::Merge;
+        } else if io::stdin().is_terminal() {
+            outln!("Rename cancelled.");
+            return Ok(());
+        }
+    }
+
+    let summary = EmployeeService::rename_employee(data_path, &old, new, on_conflict)?;
+    outln!("✅ Renamed '{old}' to '{new}'.");
+    for path in &summary.moved {
+        outln!("  moved  {}", path.display());
+    }
+    for path in &summary.merged {
+        outln!("  merged {}", path.display());
+    }
+    if summary.cache_entries > 0 {
+        outln!("  {} cached activity entries", summary.cache_entries);
+    }
+    Ok(())
+}
+
+pub fn handle_archive_command(data_path: &DataPath, employee: &str) -> io::Result<()> {
+    let Some(employee) = resolve_employee(data_path, employee, false)? else {
+        return Ok(());
+    };
+
+    EmployeeService::archive_employee(data_path, &employee)?;
+    RecentService::forget(data_path, &employee)?;
+    outln!(
+        "Employee '{employee}' archived to {}",
+        data_path.archive_dir.display()
+    );
+    Ok(())
+}
+
+pub fn handle_handoff_command(
+    data_path: &DataPath,
+    employee: &str,
+    output: &Path,
+    recipient: Option<&str>,
+) -> io::Result<()> {
+    let Some(employee) = resolve_employee(data_path, employee, false)? else {
+        return Ok(());
+    };
+
+    let manifest = HandoffService::export(data_path, &employee, output, recipient, &AgeCli)?;
+    outln!(
+        "📦 Handed off {employee} in {} ({} files):",
+        output.display(),
+        manifest.files.len()
+    );
+    for file in &manifest.files {
+        outln!("  {file}");
+    }
+    match recipient {
+        Some(recipient) => outln!("🔒 Encrypted to {}", recipient.trim()),
+        None => outln!("⚠️ The files are not encrypted; pass --recipient to encrypt them"),
+    }
+    outln!(
+        "The new manager adds them with `reviewr handoff --import {}`.",
+        output.display()
+    );
+    Ok(())
+}
+
+pub fn handle_handoff_import_command(
+    data_path: &DataPath,
+    folder: &Path,
+    identity: Option<PathBuf>,
+) -> io::Result<()> {
+    let identity = identity.or_else(|| UnifiedConfigService::load_age_identity(data_path));
+    let manifest = HandoffService::import(data_path, folder, identity.as_deref(), &AgeCli)?;
+    outln!(
+        "📥 Added {} ({} files, exported {})",
+        manifest.employee,
+        manifest.files.len(),
+        manifest.exported_at
+    );
+    Ok(())
+}
+
+pub async fn handle_review_command(
+    data_path: &DataPath,
+    employee: &Option<String>,
+    refresh: bool,
+    days: Option<u32>,
+    offline: bool,
+    explain: bool,
+    output: OutputFormat,
+) -> io::Result<()> {
+    let employee_name = match employee {
+        Some(name) => name.clone(),
+        None if output == OutputFormat::Json => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "--output json needs an employee name",
+            ));
+        }
+        None => {
+            // Use TUI selector to choose employee
+            use crate::tui::EmployeeSelector;
+            let mut selector = EmployeeSelector::new(data_path)?;
+            match selector.run()? {
+                Some(selected) => selected,
+                None => {
+                    outln!("No employee selected.");
+                    return Ok(());
+                }
+            }
+        }
+    };
+    let Some(employee_name) = resolve_employee(data_path, &employee_name, false)? else {
+        return Ok(());
+    };
+
+    // Get employee details
+    let employee = EmployeeService::get_employee(data_path, &employee_name)?;
+    if output == OutputFormat::Json {
+        return print_review_metrics(data_path, &employee, days, refresh, offline).await;
+    }
+    RecentService::record(data_path, &employee_name)?;
+    if employee.notes_only {
+        outln!(
+            "'{employee_name}' has opted out of activity aggregation (notes_only); no platform data is fetched."
+        );
+        outln!("Use 'reviewr notes {employee_name}' or 'reviewr report {employee_name}' instead.");
+        return Ok(());
+    }
+
+    // Create platform registry and get configured platforms
+    let registry = create_platform_registry(data_path)?;
+    let configured_platforms = registry.get_configured_platforms();
+    if !configured_platforms.is_empty()
+        && configured_platforms
+            .iter()
+            .all(|platform| !employee.allows_platform(platform.get_platform_id()))
+    {
+        outln!("Every configured platform is excluded by '{employee_name}''s data policy.");
+        return Ok(());
+    }
+
+    // Each platform is queried with its configured username, falling back to the email
+    if !configured_platforms.is_empty()
+        && !configured_platforms
+            .iter()
+            .any(|platform| employee.identity_for(platform.get_platform_id()).is_some())
+    {
+        outln!(
+            "Employee '{employee_name}' does not have a committer email or platform usernames configured."
+        );
+        outln!("Use 'reviewr edit {employee_name}' to add their committer email,");
+        outln!("or add a [usernames] table to employees/{employee_name}.toml.");
+        return Ok(());
+    }
+
+    if explain {
+        let days = Settings::load(data_path)?
+            .with_flag("default_time_period_days", days)?
+            .config()?
+            .ui_preferences
+            .default_time_period_days;
+        return print_query_plans(&employee, &configured_platforms, days);
+    }
+
+    let email = employee.committer_email.clone().unwrap_or_default();
+    let identity = if email.trim().is_empty() {
+        "platform usernames"
+    } else {
+        email.as_str()
+    };
+    outln!(
+        "Generating review report for {} ({identity})...",
+        employee.name
+    );
+    outln!("This may take a moment...\n");
+
+    if configured_platforms.is_empty() {
+        outln!("❌ No review platforms are configured.");
+        outln!("\nTo get started:");
+        outln!("• Configure platforms in the [platforms] section of config.toml");
+        outln!("• Run 'reviewr config' to check current configuration");
+        return Ok(());
+    }
+
+    // Launch multi-platform review browser
+    let mut browser = review_browser(data_path, &employee, &registry, days, refresh)?;
+    browser.set_offline(offline);
+
+    browser.run(&registry).await?;
+    print_goals_redline(data_path, &employee.name, browser.platform_activities())?;
+    Ok(())
+}
+
+/// `review --output json`: item counts per platform and category, loaded like the review
+/// browser loads them (cache, data policy and `--offline` included)
+async fn print_review_metrics(
+    data_path: &DataPath,
+    employee: &Employee,
+    days: Option<u32>,
+    refresh: bool,
+    offline: bool,
+) -> io::Result<()> {
+    let registry = create_platform_registry(data_path)?;
+    let mut browser = review_browser(data_path, employee, &registry, days, refresh)?;
+    browser.set_offline(offline);
+    if !employee.notes_only {
+        browser.load_data_async(&registry).await?;
+    }
+
+    let health = browser.platform_health();
+    let platforms: Vec<serde_json::Value> = browser
+        .platform_order()
+        .iter()
+        .filter(|_| !employee.notes_only)
+        .map(|platform_id| {
+            let (status, message) = match health.get(platform_id).map(|h| &h.connection) {
+                Some(ConnectionStatus::Connected) => ("ok", None),
+                Some(ConnectionStatus::Warning(message)) => ("skipped", Some(message.clone())),
+                Some(ConnectionStatus::Error(message)) => ("error", Some(message.clone())),
+                Some(ConnectionStatus::NotConfigured) | None => (
+                    "skipped",
+                    Some("No username or email configured".to_string()),
+                ),
+            };
+            let activities = browser.platform_activities().get(platform_id);
+            let categories: serde_json::Map<String, serde_json::Value> = activities
+                .into_iter()
+                .flat_map(|activities| &activities.items_by_category)
+                .map(|(category, items)| (category.display_name().to_string(), items.len().into()))
+                .collect();
+            serde_json::json!({
+                "id": platform_id,
+                "name": browser.platform_names().get(platform_id),
+                "status": status,
+                "message": message,
+                "fetched_at": health
+                    .get(platform_id)
+                    .and_then(|h| h.fetched_at)
+                    .map(|fetched_at| fetched_at.to_rfc3339()),
+                "total_items": activities.map(|activities| {
+                    activities.items_by_category.values().map(Vec::len).sum::<usize>()
+                }),
+                "items_by_category": categories,
+            })
+        })
+        .collect();
+    print_json(&serde_json::json!({
+        "employee": employee.name,
+        "days": browser.days(),
+        "notes_only": employee.notes_only,
+        "platforms": platforms,
+    }))
+}
+
+/// Print the requests each platform would make for the employee; nothing is sent
+fn print_query_plans(
+    employee: &Employee,
+    platforms: &[&dyn ReviewPlatform],
+    days: u32,
+) -> io::Result<()> {
+    if platforms.is_empty() {
+        outln!("❌ No review platforms are configured.");
+        return Ok(());
+    }
+    outln!(
+        "Queries for {} over the last {days} days (nothing is sent):",
+        employee.name
+    );
+    for platform in platforms {
+        let platform_id = platform.get_platform_id();
+        outln!(
+            "\n{} {} ({platform_id})",
+            platform.get_platform_icon(),
+            platform.get_platform_name()
+        );
+        if !employee.allows_platform(platform_id) {
+            outln!("  Skipped: excluded by the employee's data policy");
+            continue;
+        }
+        let Some(user) = employee.identity_for(platform_id) else {
+            outln!("  Skipped: no username or committer email for this platform");
+            continue;
+        };
+        let Some(plan) = platform.explain_queries(user, days)? else {
+            outln!("  This platform cannot list its queries");
+            continue;
+        };
+        outln!("  User:   {user}");
+        outln!("  Auth:   {}", plan.auth);
+        outln!("  Period: {}", plan.time_range);
+        for request in &plan.requests {
+            outln!("  {}:", request.label);
+            if let Some(query) = &request.query {
+                outln!("    {query}");
+            }
+            outln!("    {}", request.request);
+        }
+        for note in &plan.notes {
+            outln!("  Note: {note}");
+        }
+    }
+    Ok(())
+}
+
+/// The review browser of an employee, set up from their data policy and `config.toml`
+fn review_browser(
+    data_path: &DataPath,
+    employee: &Employee,
+    registry: &PlatformRegistry,
+    days: Option<u32>,
+    refresh: bool,
+) -> io::Result<crate::tui::MultiPlatformBrowser> {
+    let mut browser = crate::tui::MultiPlatformBrowser::for_employee(employee.clone(), registry);
+    browser.set_profile_summary(employee.profile.summary());
+    browser.set_out_of_office(employee.out_of_office.clone());
+    let config = Settings::load(data_path)?
+        .with_flag("default_time_period_days", days)?
+        .config()?;
+    browser.set_days(config.ui_preferences.default_time_period_days);
+    browser.set_timestamp_format(TimestampFormat::from_preferences(&config.ui_preferences));
+    browser.set_size_buckets(config.ui_preferences.size_buckets);
+    browser.set_browser_command(effective_browser_command(data_path)?);
+    browser.set_auto_refresh(config.ui_preferences.auto_refresh_minutes);
+    let performance = config.performance;
+    browser.set_max_concurrent_platforms(performance.max_concurrent_platforms);
+    let cache = ActivityCache::new(
+        data_path,
+        Duration::from_secs(performance.cache_ttl_minutes * 60),
+    );
+    if refresh {
+        cache.invalidate_employee(&employee.name)?;
+    }
+    browser.set_activity_cache(cache);
+    browser.set_annotation_store(AnnotationStore::load(data_path));
+    browser.set_notes_path(data_path.clone());
+    Ok(browser)
+}
+
+pub fn handle_search_command(
+    data_path: &DataPath,
+    query: &[String],
+    employee: &Option<String>,
+    limit: usize,
+    reindex: bool,
+    output: OutputFormat,
+) -> io::Result<()> {
+    let index = if reindex {
+        SearchIndexService::rebuild(data_path)?
+    } else {
+        SearchIndexService::load_or_build(data_path)?
+    };
+    let query = query.join(" ");
+
+    if query.trim().is_empty() {
+        if output == OutputFormat::Json || !io::stdout().is_terminal() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Give a query to search for",
+            ));
+        }
+        use crate::tui::SearchView;
+        let mut view = SearchView::new(index, employee.clone(), query);
+        view.set_browser_command(effective_browser_command(data_path)?);
+        return view.run();
+    }
+
+    let hits = index.search(&query, employee.as_deref(), limit);
+    if output == OutputFormat::Json {
+        return print_json(&serde_json::json!({
+            "query": query,
+            "results": hits,
+        }));
+    }
+    if hits.is_empty() {
+        outln!("No items or notes match '{query}'.");
+        return Ok(());
+    }
+
+    outln!("Results for '{query}' ({}):", hits.len());
+    outln!("{}", "=".repeat(20));
+    for hit in &hits {
+        let document = &hit.document;
+        match document.kind {
+            DocumentKind::Item => {
+                outln!(
+                    "• [{}] {}: {} {}",
+                    document.source,
+                    document.employee,
+                    document.id,
+                    document.title
+                );
+                outln!("  {} · {}", document.date, document.detail);
+                if !document.url.is_empty() {
+                    outln!("  {}", document.url);
+                }
+            }
+            DocumentKind::Note => {
+                outln!("• [notes] {}: {}", document.employee, document.id);
+                if let Some(snippet) = &hit.snippet {
+                    outln!("  {snippet}");
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn handle_goals_command(data_path: &DataPath, employee: &Option<String>) -> io::Result<()> {
+    let employee = match employee {
+        Some(name) => resolve_employee(data_path, name, false)?,
+        None => crate::tui::EmployeeSelector::new(data_path)?.run()?,
+    };
+    let Some(employee) = employee else {
+        outln!("No employee selected.");
+        return Ok(());
+    };
+    let mut editor = crate::tui::GoalsEditor::load(data_path, &employee)?;
+    if editor.run(data_path)? {
+        outln!(
+            "🎯 Saved the goals of '{employee}' to {}",
+            data_path
+                .goals_dir
+                .join(format!("{employee}.toml"))
+                .display()
+        );
+    } else {
+        outln!("No changes saved.");
+    }
+    Ok(())
+}
+
+/// Print each goal with the completed items linked to it and an estimated progress
+fn print_goals_redline(
+    data_path: &DataPath,
+    employee_name: &str,
+    activities: &HashMap<String, DetailedActivities>,
+) -> io::Result<()> {
+    let goals = GoalsService::load_goals(data_path, employee_name)?;
+    if goals.is_empty() {
+        return Ok(());
+    }
+
+    let items = activities
+        .values()
+        .flat_map(|activities| activities.items_by_category.values())
+        .flatten();
+    let outcomes = GoalsService::goal_outcomes(&goals, items);
+
+    outln!("\n🎯 Goals vs outcomes for {employee_name}:");
+    outln!("{}", "=".repeat(20));
+    for outcome in &outcomes {
+        let target = outcome
+            .goal
+            .target_date
+            .map(|date| format!(", due {date}"))
+            .unwrap_or_default();
+        outln!(
+            "• {} [{}{target}] - {}% ({} completed, {} open)",
+            outcome.goal.title,
+            outcome.goal.status,
+            outcome.progress_percent,
+            outcome.completed.len(),
+            outcome.open_items
+        );
+        if outcome.completed.is_empty() {
+            outln!("    (no completed items linked to this goal)");
+        }
+        for item in &outcome.completed {
+            outln!("    ✓ {} - {}", item.title, item.url);
+        }
+    }
+
+    Ok(())
+}
+
+/// `ui_preferences.browser_command` of the effective configuration, environment overrides
+/// included, for every view that opens links
+fn effective_browser_command(data_path: &DataPath) -> io::Result<Option<String>> {
+    Ok(Settings::load(data_path)?
+        .config()?
+        .ui_preferences
+        .browser_command)
+}
+
+/// Pseudonyms for `--redact` or `global_settings.redact_exports`; None when output is not redacted
+fn export_anonymizer(
+    data_path: &DataPath,
+    config: &UnifiedConfig,
+) -> io::Result<Option<Anonymizer>> {
+    if !config.global_settings.redact_exports {
+        return Ok(None);
+    }
+    Anonymizer::load(data_path, &config.platforms).map(Some)
+}
+
+pub async fn handle_report_command(
+    data_path: &DataPath,
+    employee_name: &str,
+    output: &Option<PathBuf>,
+    redact: bool,
+    cycle: Option<&str>,
+    compare: bool,
+) -> io::Result<()> {
+    let cycle = match cycle {
+        Some(name) => Some(CycleService::get(data_path, name)?),
+        None => CycleService::active(data_path)?,
+    };
+    let Some(employee_name) = resolve_employee(data_path, employee_name, false)? else {
+        return Ok(());
+    };
+    let employee_name = employee_name.as_str();
+    let employee = EmployeeService::get_employee(data_path, employee_name)?;
+    RecentService::record(data_path, employee_name)?;
+    let config = Settings::load(data_path)?
+        .with_flag("redact_exports", redact.then_some(true))?
+        .config()?;
+    let today = chrono::Local::now().date_naive();
+    let days = match &cycle {
+        Some(cycle) => cycle.days_until(today),
+        None => config.ui_preferences.default_time_period_days,
+    };
+    let anonymizer = export_anonymizer(data_path, &config)?;
+
+    let platforms = fetch_platform_sections(data_path, &employee, days, &config, compare).await?;
+    if platforms.is_empty() {
+        outln!("No platform can be queried for {employee_name}; exporting notes and goals only.");
+    }
+
+    let goals = GoalsService::load_goals(data_path, employee_name)?;
+    let items = platforms
+        .iter()
+        .filter_map(|platform| platform.activities.as_ref().ok())
+        .flat_map(|activities| activities.items_by_category.values())
+        .flatten();
+    let goals = GoalsService::goal_outcomes(&goals, items);
+
+    let notes = NotesService::read_notes(data_path, employee_name)?;
+
+    let today = today.format("%Y-%m-%d").to_string();
+    // A redacted packet's default file name must not give the name away either
+    let file_name = match &anonymizer {
+        Some(anonymizer) => anonymizer.pseudonym(employee_name),
+        None => employee_name.to_string(),
+    };
+    let output_path = output
+        .clone()
+        .unwrap_or_else(|| ReportService::default_output_path(data_path, &file_name, &today));
+    let mut packet = ReviewPacket {
+        employee,
+        period_days: days,
+        cycle,
+        generated_on: today,
+        platforms,
+        goals,
+        notes,
+        size_buckets: config.ui_preferences.size_buckets,
+        compare,
+        // A written report is read later, so relative times would go stale
+        timestamps: TimestampFormat {
+            relative: false,
+            ..TimestampFormat::from_preferences(&config.ui_preferences)
+        },
+    };
+    packet.limit_to_cycle();
+    if let Some(anonymizer) = &anonymizer {
+        packet.redact(anonymizer);
+    }
+    ReportService::write_report(&output_path, &packet)?;
+
+    outln!("📄 Review packet written to {}", output_path.display());
+    Ok(())
+}
+
+pub fn handle_cycle_command(data_path: &DataPath, command: &CycleCommands) -> io::Result<()> {
+    let today = chrono::Local::now().date_naive();
+    match command {
+        CycleCommands::Start { name } => {
+            let cycle = CycleService::start(data_path, name, today)?;
+            outln!(
+                "Started review cycle {} on {}. New notes entries are tagged [{}].",
+                cycle.name,
+                cycle.start,
+                cycle.name
+            );
+        }
+        CycleCommands::Close => {
+            let cycle = CycleService::close(data_path, today)?;
+            outln!("Closed review cycle {}.", cycle.label());
+            outln!(
+                "Run `reviewr report <employee> --cycle {}` for its packets.",
+                cycle.name
+            );
+        }
+        CycleCommands::Status => {
+            let cycles = CycleService::list(data_path)?;
+            match cycles.iter().find(|cycle| cycle.is_active()) {
+                Some(cycle) => {
+                    outln!(
+                        "Active cycle: {} (day {})",
+                        cycle.label(),
+                        cycle.days_until(today)
+                    );
+                    for employee in EmployeeService::list_employees(data_path)? {
+                        let entries = NotesService::list_entries(data_path, &employee)?
+                            .iter()
+                            .filter(|entry| {
+                                entry.cycle() == Some(cycle.name.as_str())
+                                    || cycle.contains(entry.date)
+                            })
+                            .count();
+                        outln!("  {employee}: {entries} notes entries");
+                    }
+                }
+                None => outln!(
+                    "No review cycle is active. Start one with `reviewr cycle start <name>`."
+                ),
+            }
+            let closed: Vec<_> = cycles.iter().filter(|cycle| !cycle.is_active()).collect();
+            if !closed.is_empty() {
+                outln!("Previous cycles:");
+                for cycle in closed.iter().rev() {
+                    outln!("  {}", cycle.label());
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Fresh activity of an employee from every platform they can be queried on, each with its
+/// configured username falling back to the email
+/// Activity of every platform the employee has an identity on, with the counts of the period
+/// before when `compare` is set
+async fn fetch_platform_sections(
+    data_path: &DataPath,
+    employee: &Employee,
+    days: u32,
+    config: &UnifiedConfig,
+    compare: bool,
+) -> io::Result<Vec<PlatformSection>> {
+    let registry = create_platform_registry(data_path)?;
+    let queried: Vec<_> = registry
+        .get_configured_platforms()
+        .into_iter()
+        .filter_map(|platform| {
+            let user = employee.identity_for(platform.get_platform_id())?;
+            Some((platform, user))
+        })
+        .collect();
+    if !queried.is_empty() {
+        errln!(
+            "Fetching activity for {} from {} platform(s)...",
+            employee.name,
+            queried.len()
+        );
+    }
+    let fetches = queried.iter().map(|(platform, user)| async move {
+        let previous = async {
+            if compare {
+                previous_period_counts(*platform, user, days).await
+            } else {
+                None
+            }
+        };
+        let (activities, previous_period) =
+            tokio::join!(platform.get_detailed_activities(user, days), previous);
+        PlatformSection {
+            name: platform.get_platform_name().to_string(),
+            activities: activities.map_err(|e| e.to_string()),
+            previous_period,
+        }
+    });
+    Ok(futures::stream::iter(fetches)
+        .buffered(config.performance.max_concurrent_platforms.max(1))
+        .collect()
+        .await)
+}
+
+pub async fn handle_summary_command(
+    data_path: &DataPath,
+    employee_name: &str,
+    format: SummaryFormat,
+    days: Option<u32>,
+    top: usize,
+    output: &Option<PathBuf>,
+    compare: bool,
+) -> io::Result<()> {
+    let Some(employee_name) = resolve_employee(data_path, employee_name, false)? else {
+        return Ok(());
+    };
+    let employee = EmployeeService::get_employee(data_path, &employee_name)?;
+    let config = Settings::load(data_path)?
+        .with_flag("default_time_period_days", days)?
+        .config()?;
+    let days = config.ui_preferences.default_time_period_days;
+
+    let platforms = if employee.notes_only {
+        Vec::new()
+    } else {
+        fetch_platform_sections(data_path, &employee, days, &config, compare).await?
+    };
+    let packet = ReviewPacket {
+        employee,
+        period_days: days,
+        cycle: None,
+        generated_on: chrono::Local::now().format("%Y-%m-%d").to_string(),
+        platforms,
+        // The summary goes to the employee: no goals progress and no manager notes
+        goals: Vec::new(),
+        notes: None,
+        size_buckets: false,
+        compare,
+        timestamps: TimestampFormat::from_preferences(&config.ui_preferences),
+    };
+    let summary = match format {
+        SummaryFormat::Email => packet.to_email_text(top),
+        SummaryFormat::EmailHtml => packet.to_email_html(top),
+    };
+
+    match output {
+        Some(path) => {
+            fs::write(path, summary)?;
+            outln!("✉️  Summary written to {}", path.display());
+        }
+        None => out!("{summary}"),
+    }
+    Ok(())
+}
+
+/// Shared items listed in each feedback request
+const FEEDBACK_ITEMS: usize = 5;
+
+pub fn handle_feedback_command(
+    data_path: &DataPath,
+    employee_name: &str,
+    days: Option<u32>,
+    ask: usize,
+    output: &Option<PathBuf>,
+) -> io::Result<()> {
+    let Some(employee_name) = resolve_employee(data_path, employee_name, false)? else {
+        return Ok(());
+    };
+    let employee = EmployeeService::get_employee(data_path, &employee_name)?;
+    let days: u32 = Settings::load(data_path)?
+        .with_flag("default_time_period_days", days)?
+        .get("default_time_period_days")?;
+
+    // Cached activity only, like org-stats: every tracked employee's items feed the suggestions
+    let entries = if employee.notes_only {
+        outln!("Activity aggregation is disabled for {employee_name}; drafting a generic request.");
+        Vec::new()
+    } else {
+        ActivityCache::new(data_path, Duration::ZERO).entries_for_period(days)?
+    };
+    let own: Vec<&DetailedActivities> = entries
+        .iter()
+        .filter(|(name, platform, _)| *name == employee_name && employee.allows_platform(platform))
+        .map(|(_, _, activities)| activities)
+        .collect();
+    if own.is_empty() && !employee.notes_only {
+        outln!("No cached activity for {employee_name} in the last {days} days.");
+        outln!("Run 'reviewr review {employee_name} --days {days}' to fetch it.");
+    }
+    let others = entries
+        .iter()
+        .filter(|(name, platform, _)| *name != employee_name && employee.allows_platform(platform))
+        .map(|(name, _, activities)| (name.as_str(), activities));
+    let collaborators = FeedbackService::collaborators(&employee, own.iter().copied(), others);
+
+    let requests: Vec<String> = if collaborators.is_empty() {
+        let items = FeedbackService::representative_items(own, FEEDBACK_ITEMS);
+        vec![FeedbackService::request_text(&employee, None, &items, days)]
+    } else {
+        outln!("Suggested people to ask about {employee_name}:");
+        for collaborator in collaborators.iter().take(ask) {
+            outln!(
+                "  {} - {} shared item(s)",
+                collaborator.name,
+                collaborator.items.len()
+            );
+        }
+        collaborators
+            .iter()
+            .take(ask)
+            .map(|collaborator| {
+                let items = &collaborator.items[..collaborator.items.len().min(FEEDBACK_ITEMS)];
+                FeedbackService::request_text(&employee, Some(&collaborator.name), items, days)
+            })
+            .collect()
+    };
+    let text = requests.join("\n---\n\n");
+
+    match output {
+        Some(output) => {
+            fs::write(output, text)?;
+            outln!("📄 Feedback requests written to {}", output.display());
+        }
+        None => {
+            outln!();
+            out!("{text}");
+        }
+    }
+    Ok(())
+}
+
+pub fn handle_org_stats_command(
+    data_path: &DataPath,
+    days: Option<u32>,
+    redact: bool,
+) -> io::Result<()> {
+    let config = Settings::load(data_path)?
+        .with_flag("default_time_period_days", days)?
+        .with_flag("redact_exports", redact.then_some(true))?
+        .config()?;
+    let days = config.ui_preferences.default_time_period_days;
+    let anonymizer = export_anonymizer(data_path, &config)?;
+    let display_name = |name: &str| match &anonymizer {
+        Some(anonymizer) => anonymizer.pseudonym(name),
+        None => name.to_string(),
+    };
+    // Every cached fetch counts here, however old, so stats work without network access.
+    // Employees' data policies still apply to what was cached before they were set.
+    let entries = EmployeeService::allowed_cache_entries(
+        data_path,
+        ActivityCache::new(data_path, Duration::ZERO).entries_for_period(days)?,
+    );
+    let stats = OrgStats::from_activities(
+        entries
+            .iter()
+            .map(|(employee, _, activities)| (employee.as_str(), activities)),
+    );
+
+    if stats.employees.is_empty() {
+        outln!("No cached activity for the last {days} days.");
+        outln!("Run 'reviewr review <employee> --days {days}' to fetch and cache activity first.");
+        return Ok(());
+    }
+
+    outln!(
+        "Org statistics for the last {days} days ({} employees with cached activity):",
+        stats.employees.len()
+    );
+    outln!("{}", "=".repeat(20));
+    outln!(
+        "{:<16} {:>6} {:>6} {:>6} {:>6} {:>6} {:>6}",
+        "Metric",
+        "min",
+        "p25",
+        "median",
+        "p75",
+        "p90",
+        "max"
+    );
+    for (metric, distribution) in &stats.distributions {
+        outln!(
+            "{:<16} {:>6.1} {:>6.1} {:>6.1} {:>6.1} {:>6.1} {:>6.1}",
+            metric.label(),
+            distribution.min,
+            distribution.p25,
+            distribution.median,
+            distribution.p75,
+            distribution.p90,
+            distribution.max
+        );
+    }
+    outln!();
+    outln!(
+        "Review participation: {:.0}% of employees gave at least one review",
+        stats.review_participation * 100.0
+    );
+
+    outln!();
+    outln!("Per employee:");
+    // Sorted by the displayed name, so pseudonyms are not in the order of the real names
+    let mut rows: Vec<_> = stats
+        .employees
+        .iter()
+        .map(|(employee, counts)| (display_name(employee), counts))
+        .collect();
+    rows.sort_by(|a, b| a.0.cmp(&b.0));
+    for (employee, counts) in rows {
+        let bands: Vec<String> = stats
+            .distributions
+            .iter()
+            .map(|(metric, distribution)| {
+                let value = counts[metric];
+                format!("{} {value} ({})", metric.label(), distribution.band(value))
+            })
+            .collect();
+        outln!("• {employee}: {}", bands.join(", "));
+    }
+
+    // notes_only employees are never fetched, so they aren't missing anything
+    let mut missing: Vec<String> = EmployeeService::list_employees(data_path)?
+        .iter()
+        .filter(|employee| !stats.employees.contains_key(*employee))
+        .filter(|employee| {
+            EmployeeService::get_employee(data_path, employee)
+                .is_ok_and(|employee| !employee.notes_only)
+        })
+        .map(|employee| display_name(employee))
+        .collect();
+    missing.sort();
+    if !missing.is_empty() {
+        outln!();
+        outln!("No cached activity for: {}", missing.join(", "));
+        outln!("Run 'reviewr review <employee> --days {days}' to include them.");
+    }
+    Ok(())
+}
+
+/// Every employee, or those whose team matches `team` (case-insensitive)
+fn team_employees(data_path: &DataPath, team: &Option<String>) -> io::Result<Vec<Employee>> {
+    let mut employees = Vec::new();
+    for name in EmployeeService::list_employees(data_path)? {
+        let employee = EmployeeService::get_employee(data_path, &name)?;
+        let in_team = match team {
+            Some(team) => employee
+                .profile
+                .team
+                .as_deref()
+                .is_some_and(|t| t.trim().eq_ignore_ascii_case(team.trim())),
+            None => true,
+        };
+        if in_team {
+            employees.push(employee);
+        }
+    }
+    Ok(employees)
+}
+
+fn print_no_team_employees(team: &Option<String>) {
+    match team {
+        Some(team) => outln!("No employees found in team '{team}'."),
+        None => outln!("No employees found. Add one with 'reviewr add <name>'."),
+    }
+}
+
+pub async fn handle_team_review_command(
+    data_path: &DataPath,
+    team: &Option<String>,
+    days: Option<u32>,
+    refresh: bool,
+    export: Option<ExportFormat>,
+    output: &Option<PathBuf>,
+    redact: bool,
+) -> io::Result<()> {
+    let config = Settings::load(data_path)?
+        .with_flag("default_time_period_days", days)?
+        .with_flag("redact_exports", redact.then_some(true))?
+        .config()?;
+    let days = config.ui_preferences.default_time_period_days;
+    let anonymizer = export_anonymizer(data_path, &config)?;
+
+    let employees = team_employees(data_path, team)?;
+    if employees.is_empty() {
+        print_no_team_employees(team);
+        return Ok(());
+    }
+
+    let registry = create_platform_registry(data_path)?;
+    let platforms = registry.get_configured_platforms();
+    let performance = config.performance;
+    let cache = ActivityCache::new(
+        data_path,
+        Duration::from_secs(performance.cache_ttl_minutes * 60),
+    );
+    if refresh {
+        for employee in &employees {
+            cache.invalidate_employee(&employee.name)?;
+        }
+    }
+
+    // Progress goes to stderr so a CSV export on stdout stays clean
+    let opted_out: Vec<&str> = employees
+        .iter()
+        .filter(|employee| employee.notes_only)
+        .map(|employee| employee.name.as_str())
+        .collect();
+    if !opted_out.is_empty() {
+        errln!("Not aggregated (notes_only): {}", opted_out.join(", "));
+    }
+    if platforms.is_empty() {
+        errln!("❌ No review platforms are configured; every count is zero.");
+    }
+    let queries: Vec<_> = employees
+        .iter()
+        .flat_map(|employee| {
+            platforms.iter().filter_map(move |platform| {
+                let user = employee.identity_for(platform.get_platform_id())?;
+                Some((employee.name.as_str(), *platform, user))
+            })
+        })
+        .collect();
+    if !queries.is_empty() {
+        errln!(
+            "Fetching activity for {} employee(s) from {} platform(s)...",
+            employees.len(),
+            platforms.len()
+        );
+    }
+    let cache = &cache;
+    let fetches = queries
+        .into_iter()
+        .map(|(name, platform, user)| async move {
+            let platform_id = platform.get_platform_id();
+            if let Some((activities, _)) = cache.load(name, platform_id, days) {
+                return (name, platform, Ok(activities));
+            }
+            let result = platform.get_detailed_activities(user, days).await;
+            if let Ok(activities) = &result
+                && let Err(e) = cache.store(name, platform_id, days, activities)
+            {
+                error!("Failed to cache {platform_id} activity for {name}: {e}");
+            }
+            (name, platform, result)
+        });
+    let results: Vec<_> = futures::stream::iter(fetches)
+        .buffer_unordered(performance.max_concurrent_platforms.max(1))
+        .collect()
+        .await;
+
+    let mut comparison = TeamComparison::new(
+        employees
+            .iter()
+            .filter(|employee| !employee.notes_only)
+            .map(|employee| employee.name.clone()),
+    );
+    for (name, platform, result) in results {
+        match result {
+            Ok(activities) => {
+                let mut failed: Vec<&str> = activities
+                    .failed_categories
+                    .keys()
+                    .map(|category| category.display_name())
+                    .collect();
+                if !failed.is_empty() {
+                    failed.sort();
+                    errln!(
+                        "⚠️  {} could not load {} for {name}",
+                        platform.get_platform_name(),
+                        failed.join(", ")
+                    );
+                }
+                comparison.add(name, &activities);
+            }
+            Err(e) => {
+                errln!(
+                    "⚠️  {} fetch failed for {name}: {e}",
+                    platform.get_platform_name()
+                );
+                comparison.add_failure(name, platform.get_platform_name());
+            }
+        }
+    }
+
+    let today = chrono::Local::now().date_naive();
+    for employee in employees.iter().filter(|employee| !employee.notes_only) {
+        let absent = employee.days_out_of_office(today, days);
+        if absent > 0 {
+            comparison.set_days_absent(&employee.name, absent);
+        }
+    }
+    config.scoring.apply(&mut comparison, days);
+    if let Some(anonymizer) = &anonymizer {
+        comparison.redact(anonymizer);
+    }
+
+    match (export, output) {
+        (Some(ExportFormat::Csv), Some(output)) => {
+            fs::write(output, comparison.to_csv())?;
+            outln!("📄 Team review written to {}", output.display());
+        }
+        (Some(ExportFormat::Csv), None) => out!("{}", comparison.to_csv()),
+        (None, _) => {
+            let title = match team {
+                Some(team) => format!("Team {team} · last {days} days"),
+                None => format!("All employees · last {days} days"),
+            };
+            let mut view = crate::tui::TeamReviewView::new(title, comparison);
+            view.run()?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn handle_dashboard_command(
+    data_path: &DataPath,
+    team: &Option<String>,
+    days: Option<u32>,
+) -> io::Result<()> {
+    let config = Settings::load(data_path)?
+        .with_flag("default_time_period_days", days)?
+        .config()?;
+    let days = config.ui_preferences.default_time_period_days;
+    let employees: Vec<Employee> = team_employees(data_path, team)?
+        .into_iter()
+        .filter(|employee| !employee.notes_only)
+        .collect();
+    if employees.is_empty() {
+        print_no_team_employees(team);
+        return Ok(());
+    }
+    let registry = create_platform_registry(data_path)?;
+    if registry.get_configured_platforms().is_empty() {
+        outln!("❌ No review platforms are configured.");
+        return Ok(());
+    }
+
+    let performance = config.performance;
+    let cache = ActivityCache::new(
+        data_path,
+        Duration::from_secs(performance.cache_ttl_minutes * 60),
+    );
+    let title = match team {
+        Some(team) => format!("Team {team} · last {days} days"),
+        None => format!("All employees · last {days} days"),
+    };
+    let mut view = crate::tui::DashboardView::new(title, &employees, &registry, cache, days);
+    view.set_max_concurrent_platforms(performance.max_concurrent_platforms);
+
+    // The dashboard's background fetches keep running while a drill-down is open
+    while let Some(selection) = view.run().await? {
+        let Some(employee) = employees
+            .iter()
+            .find(|employee| employee.name == selection.employee)
+        else {
+            continue;
+        };
+        RecentService::record(data_path, &employee.name)?;
+        let mut browser = review_browser(data_path, employee, &registry, Some(days), false)?;
+        browser.open_platform(&selection.platform_id);
+        browser.run(&registry).await?;
+        view.reload_employee(&employee.name);
+    }
+    Ok(())
+}
+
+/// Outcome of one `reviewr doctor` check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckLevel {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Default)]
+struct DoctorReport {
+    passed: usize,
+    warnings: usize,
+    failures: usize,
+}
+
+impl DoctorReport {
+    fn record(&mut self, level: CheckLevel, message: &str, hint: Option<&str>) {
+        let icon = match level {
+            CheckLevel::Pass => {
+                self.passed += 1;
+                "✅"
+            }
+            CheckLevel::Warn => {
+                self.warnings += 1;
+                "⚠️"
+            }
+            CheckLevel::Fail => {
+                self.failures += 1;
+                "❌"
+            }
+        };
+        outln!("{icon} {message}");
+        if let Some(hint) = hint {
+            outln!("   → {hint}");
+        }
+    }
+}
+
+/// Whether a file can be created in `dir`, checked by writing and removing a probe file
+fn check_writable(dir: &std::path::Path) -> io::Result<()> {
+    let probe = dir.join(".reviewr-doctor");
+    fs::write(&probe, b"")?;
+    fs::remove_file(&probe)
+}
+
+pub async fn handle_doctor_command(data_path: &DataPath) -> io::Result<()> {
+    let mut report = DoctorReport::default();
+
+    outln!("Configuration");
+    let config_path = data_path.config_path();
+    if !config_path.exists() {
+        report.record(
+            CheckLevel::Warn,
+            &format!("No config file at {}", config_path.display()),
+            Some("Defaults are used; run 'reviewr config set <key> <value>' to create it"),
+        );
+    } else {
+        match UnifiedConfigService::load_config(data_path) {
+            Ok(_) => report.record(
+                CheckLevel::Pass,
+                &format!("{} is valid", config_path.display()),
+                None,
+            ),
+            Err(e) if backup_path(&config_path).exists() => {
+                offer_config_restore(data_path, &e)?;
+                match UnifiedConfigService::load_config(data_path) {
+                    Ok(_) => report.record(
+                        CheckLevel::Pass,
+                        &format!("{} restored from its backup", config_path.display()),
+                        None,
+                    ),
+                    Err(e) => report.record(
+                        CheckLevel::Fail,
+                        &format!("{} cannot be loaded: {e}", config_path.display()),
+                        Some("Run 'reviewr doctor' in a terminal to restore config.toml.bak"),
+                    ),
+                }
+            }
+            Err(e) => report.record(
+                CheckLevel::Fail,
+                &format!("{} cannot be loaded: {e}", config_path.display()),
+                Some("Fix the TOML syntax or move the file aside to start from defaults"),
+            ),
+        }
+    }
+
+    outln!();
+    outln!("Data directory");
+    for (label, dir) in [
+        ("Data directory", &data_path.root),
+        ("Employees directory", &data_path.employees_dir),
+        ("Notes directory", &data_path.notes_dir),
+    ] {
+        if !dir.is_dir() {
+            report.record(
+                CheckLevel::Fail,
+                &format!("{label} {} does not exist", dir.display()),
+                Some("Create it or pass a different --data-path"),
+            );
+            continue;
+        }
+        match check_writable(dir) {
+            Ok(()) => report.record(
+                CheckLevel::Pass,
+                &format!("{label} {} is writable", dir.display()),
+                None,
+            ),
+            Err(e) => report.record(
+                CheckLevel::Fail,
+                &format!("{label} {} is not writable: {e}", dir.display()),
+                Some("Fix the directory permissions for the current user"),
+            ),
+        }
+    }
+
+    outln!();
+    outln!("Employee files");
+    let employees = EmployeeService::list_employees(data_path)?;
+    if employees.is_empty() {
+        report.record(
+            CheckLevel::Warn,
+            "No employees yet",
+            Some("Add one with 'reviewr add <name>'"),
+        );
+    }
+    for employee in &employees {
+        let path = data_path.employees_dir.join(format!("{employee}.toml"));
+        let validate = || {
+            fs::read_to_string(&path)
+                .and_then(|content| EmployeeService::validate_employee_toml(&content, employee))
+        };
+        let mut result = validate();
+        if let Err(e) = &result
+            && backup_path(&path).exists()
+        {
+            offer_employee_restore(data_path, employee, e)?;
+            result = validate();
+        }
+        match result {
+            Ok(_) => report.record(CheckLevel::Pass, &format!("{employee}.toml"), None),
+            Err(e) => report.record(
+                CheckLevel::Fail,
+                &format!("{employee}.toml: {e}"),
+                Some(&format!(
+                    "Run 'reviewr edit \"{employee}\" --raw' to fix it"
+                )),
+            ),
+        }
+    }
+
+    if data_path.notes_dir.is_dir() {
+        let mut orphaned: Vec<String> = fs::read_dir(&data_path.notes_dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().and_then(|s| s.to_str()) == Some("md"))
+            .filter_map(|path| path.file_stem()?.to_str().map(str::to_string))
+            .filter(|name| !employees.contains(name))
+            .collect();
+        orphaned.sort();
+        if orphaned.is_empty() {
+            report.record(
+                CheckLevel::Pass,
+                "Every notes file belongs to an employee",
+                None,
+            );
+        } else {
+            report.record(
+                CheckLevel::Warn,
+                &format!("Notes without an employee file: {}", orphaned.join(", ")),
+                Some("Re-add the employee or move the notes into the archive"),
+            );
+        }
+    }
+
+    outln!();
+    outln!("Platform connectivity");
+    let mut connections: Vec<(String, _)> = match create_platform_registry(data_path) {
+        Ok(registry) => registry.test_all_connections().await.into_iter().collect(),
+        Err(e) => vec![(
+            "allowed domains".to_string(),
+            ConnectionStatus::Error(e.to_string()),
+        )],
+    };
+    connections.sort_by(|a, b| a.0.cmp(&b.0));
+    for (platform, status) in &connections {
+        match status {
+            ConnectionStatus::Connected => {
+                report.record(CheckLevel::Pass, &format!("{platform}: connected"), None)
+            }
+            ConnectionStatus::NotConfigured => report.record(
+                CheckLevel::Warn,
+                &format!("{platform}: not configured"),
+                Some("Add its section to config.toml to include its activity in reviews"),
+            ),
+            ConnectionStatus::Warning(message) => {
+                report.record(CheckLevel::Warn, &format!("{platform}: {message}"), None)
+            }
+            ConnectionStatus::Error(message) => report.record(
+                CheckLevel::Fail,
+                &format!("{platform}: {message}"),
+                Some("Check the URL and credentials; 'reviewr errors' shows the failed requests"),
+            ),
+        }
+    }
+
+    outln!();
+    outln!(
+        "{} passed, {} warnings, {} failed",
+        report.passed,
+        report.warnings,
+        report.failures
+    );
+    if report.failures > 0 {
+        return Err(io::Error::other(format!(
+            "{} doctor check(s) failed",
+            report.failures
+        )));
     }
+    Ok(())
+}
 
-    registry
+/// Ask for one line, taking `default` on an empty answer. Fails at the end of the input, so a
+/// wizard fed from a pipe never asks forever.
+fn prompt_line(question: &str, default: Option<&str>) -> io::Result<String> {
+    match default.filter(|default| !default.is_empty()) {
+        Some(default) => out!("{question} [{default}]: "),
+        None => out!("{question}: "),
+    }
+    io::stdout().flush()?;
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input)? == 0 {
+        outln!();
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "Cancelled: the input ended before every question was answered",
+        ));
+    }
+    let input = input.trim();
+    Ok(match (input.is_empty(), default) {
+        (true, Some(default)) => default.to_string(),
+        _ => input.to_string(),
+    })
 }
 
-pub fn handle_list_command(data_path: &DataPath) -> io::Result<()> {
-    let employees = EmployeeService::list_employees(data_path)?;
+/// Ask until the answer passes `check`, printing why each rejected one failed
+fn prompt_valid(
+    question: &str,
+    default: Option<&str>,
+    check: impl Fn(&str) -> Result<(), String>,
+) -> io::Result<String> {
+    loop {
+        let answer = prompt_line(question, default)?;
+        match check(&answer) {
+            Ok(()) => return Ok(answer),
+            Err(problem) => outln!("⚠️  {problem}"),
+        }
+    }
+}
 
-    if employees.is_empty() {
-        println!("No employees found.");
-        return Ok(());
+/// The platform questions of `reviewr init`, offering the answers of `previous` as defaults
+fn prompt_platform_setup(previous: Option<&PlatformSetup>) -> io::Result<PlatformSetup> {
+    for (index, platform) in SetupPlatform::ALL.iter().enumerate() {
+        outln!("  {}. {}", index + 1, platform.display_name());
     }
+    let choice = prompt_valid(
+        "Which platform do you review on?",
+        Some(previous.map_or("Gerrit", |setup| setup.platform.display_name())),
+        |answer| {
+            SetupPlatform::parse(answer)
+                .map(|_| ())
+                .ok_or_else(|| format!("Pick 1 to {} or a platform name", SetupPlatform::ALL.len()))
+        },
+    )?;
+    let platform = SetupPlatform::parse(&choice).unwrap_or(SetupPlatform::Gerrit);
+    let same = previous.filter(|setup| setup.platform == platform);
 
-    println!("Employees ({}):", employees.len());
-    println!("{}", "=".repeat(20));
+    let url = prompt_valid(
+        &format!("{} URL", platform.display_name()),
+        same.map(|setup| setup.url.as_str()),
+        |answer| validate_platform_url(answer).map_err(|e| e.to_string()),
+    )?;
+    let default_instance = match same {
+        Some(setup) => setup.instance.clone(),
+        None => platform.default_instance(&url),
+    };
+    let instance = prompt_valid("Instance name", Some(&default_instance), |answer| {
+        if answer.is_empty() || answer.contains(|c: char| c == ':' || c.is_whitespace()) {
+            Err("Instance name cannot be empty or contain ':' or spaces".to_string())
+        } else {
+            Ok(())
+        }
+    })?;
+    let user = prompt_valid(
+        platform.user_label(),
+        same.map(|setup| setup.user.as_str()),
+        |answer| {
+            if answer.is_empty() {
+                Err(format!("{} is required", platform.user_label()))
+            } else {
+                Ok(())
+            }
+        },
+    )?;
 
-    for employee_name in &employees {
-        match EmployeeService::get_employee(data_path, employee_name) {
-            Ok(employee) => {
-                println!("• {} - {}", employee.name, employee.title);
+    outln!("{}", platform.secret_hint());
+    let secret = loop {
+        let question = match same {
+            Some(_) => format!(
+                "{} (Enter keeps the one typed before): ",
+                platform.secret_label()
+            ),
+            None => format!("{}: ", platform.secret_label()),
+        };
+        let secret = read_secret(&question)?;
+        match (secret.is_empty(), same) {
+            (false, _) => break secret,
+            (true, Some(setup)) => break setup.secret.clone(),
+            (true, None) if io::stdin().is_terminal() => {
+                outln!("⚠️  {} is required", platform.secret_label())
             }
-            Err(e) => {
-                println!("• {employee_name} - (Error loading: {e})");
+            (true, None) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{} is required", platform.secret_label()),
+                ));
             }
         }
-    }
+    };
 
-    Ok(())
+    Ok(PlatformSetup {
+        platform,
+        instance,
+        url,
+        user,
+        secret,
+    })
 }
 
-pub async fn handle_review_command(
-    data_path: &DataPath,
-    employee: &Option<String>,
-) -> io::Result<()> {
-    let employee_name = match employee {
-        Some(name) => name.clone(),
-        None => {
-            // Use TUI selector to choose employee
-            use crate::tui::EmployeeSelector;
-            let mut selector = EmployeeSelector::new(data_path)?;
-            match selector.run()? {
-                Some(selected) => selected,
-                None => {
-                    println!("No employee selected.");
-                    return Ok(());
-                }
-            }
-        }
-    };
+pub async fn handle_init_command(data_path: &DataPath) -> io::Result<()> {
+    let config_path = data_path.config_path();
+    let existing = config_path.exists();
+    let mut config = UnifiedConfigService::load_config(data_path)?;
 
-    // Get employee details
-    let employee = EmployeeService::get_employee(data_path, &employee_name)?;
+    outln!("👋 Setting up reviewr in {}", data_path.root.display());
+    outln!(
+        "📁 Employees are kept in {} and notes in {}",
+        data_path.employees_dir.display(),
+        data_path.notes_dir.display()
+    );
+    if existing {
+        outln!(
+            "{} exists; what you enter is added to it.",
+            config_path.display()
+        );
+    }
 
-    // Check if employee has committer email
-    let email = match &employee.committer_email {
-        Some(email) if !email.trim().is_empty() => email,
-        _ => {
-            println!("Employee '{employee_name}' does not have a committer email configured.");
-            println!("Use 'reviewr edit {employee_name}' to add their committer email.");
-            return Ok(());
+    outln!();
+    outln!("Platform");
+    let mut previous = None;
+    let setup = loop {
+        let setup = prompt_platform_setup(previous.as_ref())?;
+        outln!("🔌 Connecting to {}...", setup.url);
+        let problem = match setup.test_connection(data_path).await {
+            ConnectionStatus::Connected => {
+                outln!(
+                    "✅ Connected to {} as {}",
+                    setup.platform.display_name(),
+                    setup.user
+                );
+                break Some(setup);
+            }
+            ConnectionStatus::Warning(message) | ConnectionStatus::Error(message) => message,
+            ConnectionStatus::NotConfigured => "not configured".to_string(),
+        };
+        outln!("❌ {problem}");
+        let choice = prompt_line(
+            "[r]e-enter the details, [k]eep them anyway or [s]kip the platform",
+            Some("r"),
+        )?;
+        match choice.to_lowercase().as_str() {
+            "k" | "keep" => break Some(setup),
+            "s" | "skip" => break None,
+            _ => previous = Some(setup),
         }
     };
-
-    println!(
-        "Generating review report for {} ({})...",
-        employee.name, email
-    );
-    println!("This may take a moment...\n");
-
-    // Create platform registry and get configured platforms
-    let registry = create_platform_registry(data_path);
-    let configured_platforms = registry.get_configured_platforms();
-
-    if configured_platforms.is_empty() {
-        println!("❌ No review platforms are configured.");
-        println!("\nTo get started:");
-        println!("• Configure platforms in the [platforms] section of config.toml");
-        println!("• Run 'reviewr config' to check current configuration");
-        return Ok(());
+    let uses_keyring = config.global_settings.secret_backend == SecretBackendKind::Keyring;
+    if let Some(setup) = &setup {
+        setup.apply(&mut config, !uses_keyring);
     }
 
-    // Launch multi-platform review browser
-    use crate::tui::MultiPlatformBrowser;
-    let mut browser = MultiPlatformBrowser::new(employee.name.clone(), email.clone(), &registry);
+    outln!();
+    outln!("Allowed domains");
+    outln!("Evidence links in the clipboard are only followed on these domains; none allows any.");
+    let mut suggested = config.global_settings.allowed_domains.clone();
+    if let Some(domain) = setup.as_ref().and_then(PlatformSetup::suggested_domain)
+        && !suggested.contains(&domain)
+    {
+        suggested.push(domain);
+    }
+    let answer = prompt_valid(
+        "Allowed domains, comma separated",
+        Some(&suggested.join(", ")),
+        |answer| {
+            answer
+                .split(',')
+                .map(str::trim)
+                .filter(|domain| !domain.is_empty())
+                .try_for_each(validate_domain)
+                .map_err(|e| e.to_string())
+        },
+    )?;
+    config.global_settings.allowed_domains = answer
+        .split(',')
+        .map(str::trim)
+        .filter(|domain| !domain.is_empty())
+        .map(str::to_string)
+        .collect();
+    if !config.global_settings.allowed_domains.is_empty() {
+        let enforce = prompt_line(
+            "Refuse to contact platform servers outside these domains? (y/n)",
+            Some("n"),
+        )?;
+        config.global_settings.enforce_allowed_domains = enforce.eq_ignore_ascii_case("y");
+        if let Err(e) = UnifiedConfigService::check_config_domains(&config) {
+            outln!("⚠️  {e}");
+            outln!("Left enforce_allowed_domains off.");
+            config.global_settings.enforce_allowed_domains = false;
+        }
+    }
 
-    // Load data from all configured platforms with background processing
-    println!(
-        "🔄 Starting data fetch from {} platform(s)...",
-        configured_platforms.len()
-    );
-    println!("   Loading will continue in background - press ESC to cancel");
+    UnifiedConfigService::save_config(&config, data_path)?;
+    if let Some(setup) = setup.as_ref().filter(|_| uses_keyring) {
+        SecretService::store_platform_secret(
+            data_path,
+            &setup.account(),
+            &setup.secret,
+            &KeyringBackend,
+        )?;
+        outln!(
+            "🔐 Stored the {} secret in the OS keyring.",
+            setup.account()
+        );
+    }
+    outln!("💾 Saved the configuration to {}", config_path.display());
 
-    match browser.load_data_async(&registry).await {
-        Ok(_) => {
-            println!("✅ Data loading completed, launching TUI...");
-            browser.run()?;
-            Ok(())
+    outln!();
+    outln!("Employees");
+    loop {
+        let answer = prompt_line(
+            "CSV file with name, title, email and team columns to import (Enter to skip)",
+            None,
+        )?;
+        if answer.is_empty() {
+            break;
         }
-        Err(e) => {
-            error!("Failed to load review data: {e}");
-            println!("❌ Failed to load review data: {e}");
-            println!("\nPossible issues:");
-            println!("• Check platform configurations");
-            println!("• Verify network connectivity to platform instances");
-            println!("• Ensure credentials are correct");
-            Err(e)
+        let file = PathBuf::from(&answer);
+        let records = fs::read_to_string(&file)
+            .map_err(|e| format!("Could not read {}: {e}", file.display()))
+            .and_then(|content| {
+                parse_csv(&content).map_err(|e| format!("{}: {e}", file.display()))
+            });
+        match records {
+            Ok(records) => {
+                import_employees(data_path, records, false, OnConflict::Skip)?;
+                break;
+            }
+            Err(problem) => outln!("⚠️  {problem}"),
         }
     }
+
+    outln!();
+    outln!("🎉 reviewr is set up. Next steps:");
+    outln!("  reviewr doctor          check the configuration and platform connections");
+    outln!("  reviewr add <name>      add an employee");
+    outln!("  reviewr review <name>   review an employee's activity");
+    Ok(())
 }
 
 pub fn handle_add_command(data_path: &DataPath, employee: &Option<String>) -> io::Result<()> {
@@ -246,7 +2691,7 @@ pub fn handle_add_command(data_path: &DataPath, employee: &Option<String>) -> io
                     Ok(())
                 }
                 None => {
-                    println!("Employee creation cancelled.");
+                    outln!("Employee creation cancelled.");
                     Ok(())
                 }
             }
@@ -254,29 +2699,329 @@ pub fn handle_add_command(data_path: &DataPath, employee: &Option<String>) -> io
     }
 }
 
-pub fn handle_notes_command(data_path: &DataPath, employee: &str) -> io::Result<()> {
-    if !EmployeeService::employee_exists(data_path, employee) {
-        println!("Employee '{employee}' not found.");
-        print!("Would you like to add them? (y/n) ");
+pub async fn handle_notes_command(
+    data_path: &DataPath,
+    employee: &str,
+    use_clipboard: bool,
+    template: Option<&str>,
+) -> io::Result<()> {
+    let Some(employee) = resolve_employee(data_path, employee, true)? else {
+        return Ok(());
+    };
+
+    RecentService::record(data_path, &employee)?;
+    let settings = UnifiedConfigService::load_effective_config(data_path)?.global_settings;
+    // The clipboard is not even read while evidence capture is off
+    let clipboard = (use_clipboard && settings.clipboard_evidence)
+        .then(NotesService::read_clipboard)
+        .flatten();
+    let evidence = match clipboard {
+        Some(text) => {
+            let http = UnifiedConfigService::load_http_settings(data_path);
+            NotesService::evidence_lines(&text, &settings.allowed_domains, &http).await
+        }
+        None => {
+            info!("Clipboard evidence capture disabled or clipboard empty, skipping evidence");
+            Vec::new()
+        }
+    };
+    NotesService::open_notes(data_path, &employee, &evidence, template)
+}
+
+pub fn handle_notes_append_command(
+    data_path: &DataPath,
+    employee: &str,
+    text: &str,
+) -> io::Result<()> {
+    let Some(employee) = resolve_employee(data_path, employee, false)? else {
+        return Ok(());
+    };
+
+    let entry = NotesService::append_entry(
+        data_path,
+        &employee,
+        text,
+        chrono::Local::now().naive_local(),
+    )?;
+    outln!("Added entry '{}' to {employee}'s notes.", entry.heading());
+    Ok(())
+}
+
+pub fn handle_notes_list_command(data_path: &DataPath, employee: &str) -> io::Result<()> {
+    let Some(employee) = resolve_employee(data_path, employee, false)? else {
+        return Ok(());
+    };
+
+    let entries = NotesService::list_entries(data_path, &employee)?;
+    if entries.is_empty() {
+        outln!("No dated entries in {employee}'s notes.");
+        return Ok(());
+    }
+    outln!("Notes entries for {employee} ({}):", entries.len());
+    for entry in &entries {
+        let words = entry.body.split_whitespace().count();
+        outln!("• {} - {words} words", entry.heading());
+    }
+    Ok(())
+}
+
+/// Items listed under each employee in `reviewr journal`
+const JOURNAL_HIGHLIGHT_ITEMS: usize = 5;
+
+pub fn handle_journal_command(data_path: &DataPath, all: bool) -> io::Result<()> {
+    let today = chrono::Local::now().date_naive();
+    let week = JournalService::week_key(today);
+    let employees = EmployeeService::list_employees(data_path)?;
+    if employees.is_empty() {
+        outln!("No employees found. Add one with 'reviewr add <name>'.");
+        return Ok(());
+    }
+    let done = JournalService::completed(data_path, &week);
+    let pending: Vec<&String> = employees
+        .iter()
+        .filter(|employee| all || !done.contains(employee))
+        .collect();
+    if pending.is_empty() {
+        outln!(
+            "All {} employees are journaled for {week}. Use --all to go through them again.",
+            employees.len()
+        );
+        return Ok(());
+    }
+
+    // Highlights come from cached activity only, so the pass works offline and starts instantly
+    let days = UnifiedConfigService::load_default_period_days(data_path);
+    let entries = ActivityCache::new(data_path, Duration::ZERO).entries_for_period(days)?;
+    let since = chrono::Utc::now() - chrono::Duration::days(7);
+    let today = today.format("%Y-%m-%d").to_string();
+    let highlights: Vec<_> = pending
+        .iter()
+        .map(|employee| {
+            let activities = entries
+                .iter()
+                .filter(|(name, _, _)| name == *employee)
+                .map(|(_, _, activities)| activities);
+            JournalService::highlights(activities, since, JOURNAL_HIGHLIGHT_ITEMS)
+        })
+        .collect();
+    // The index compares the week's activity of everyone still to journal
+    let mut scored = TeamComparison::new(pending.iter().map(|employee| employee.as_str()));
+    for (employee, highlights) in pending.iter().zip(&highlights) {
+        scored.add_counts(
+            employee,
+            highlights
+                .counts
+                .iter()
+                .map(|(category, count)| (category, *count)),
+        );
+    }
+    UnifiedConfigService::load_scoring_config(data_path).apply(&mut scored, 7);
+
+    outln!(
+        "📓 Weekly journal {week}: {} of {} employees to go",
+        pending.len(),
+        employees.len()
+    );
+    let mut journaled = 0;
+    for (index, (employee, highlights)) in pending.iter().zip(&highlights).enumerate() {
+        outln!();
+        outln!("[{}/{}] {employee}", index + 1, pending.len());
+        if highlights.is_empty() {
+            outln!("  No cached activity in the last 7 days.");
+            outln!("  Run 'reviewr review {employee}' to fetch it.");
+        } else {
+            let counts: Vec<String> = highlights
+                .counts
+                .iter()
+                .map(|(category, count)| format!("{count} {}", category.display_name()))
+                .collect();
+            outln!("  Last 7 days: {}", counts.join(", "));
+            if let Some(index) = scored
+                .members
+                .iter()
+                .find(|member| member.name == **employee)
+                .and_then(|member| member.activity_index)
+            {
+                outln!(
+                    "  Activity index: {} (heuristic from [scoring] weights)",
+                    format_index(index)
+                );
+            }
+            for item in &highlights.latest {
+                outln!("  • {} ({})", item.title, item.platform);
+            }
+        }
+
+        out!("Write notes? [Enter] open, s skip, q quit: ");
         io::stdout().flush()?;
         let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        if input.trim().eq_ignore_ascii_case("y") {
-            EmployeeService::add_employee(data_path, employee)?;
-        } else {
+        if io::stdin().read_line(&mut input)? == 0 {
+            break;
+        }
+        match input.trim().to_lowercase().as_str() {
+            "q" => break,
+            "s" => continue,
+            _ => {}
+        }
+
+        NotesService::ensure_dated_section(data_path, employee, &today)?;
+        RecentService::record(data_path, employee)?;
+        NotesService::open_notes(data_path, employee, &[], None)?;
+        JournalService::mark_done(data_path, &week, employee)?;
+        journaled += 1;
+    }
+
+    let done = JournalService::completed(data_path, &week);
+    let remaining = employees
+        .iter()
+        .filter(|employee| !done.contains(employee))
+        .count();
+    outln!();
+    outln!("Journaled {journaled} employee(s) this session; {remaining} left for {week}.");
+    Ok(())
+}
+
+/// Sections with fewer words than this are flagged as thin documentation
+const THIN_SECTION_WORDS: usize = 20;
+
+pub fn handle_notes_stats_command(data_path: &DataPath, employee: &str) -> io::Result<()> {
+    let Some(employee) = resolve_employee(data_path, employee, false)? else {
+        return Ok(());
+    };
+    let employee = employee.as_str();
+
+    let stats = match NotesService::notes_stats(data_path, employee) {
+        Ok(stats) => stats,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            outln!("No notes found for '{employee}'.");
             return Ok(());
         }
+        Err(e) => return Err(e),
+    };
+
+    outln!(
+        "Notes statistics for {employee} ({} sections):",
+        stats.sections.len()
+    );
+    outln!("{}", "=".repeat(20));
+
+    for section in &stats.sections {
+        let tags = if section.tags.is_empty() {
+            String::new()
+        } else {
+            let tags: Vec<String> = section.tags.iter().map(|t| format!("#{t}")).collect();
+            format!(" | tags: {}", tags.join(", "))
+        };
+        let thin = if section.word_count < THIN_SECTION_WORDS {
+            " ⚠️ thin"
+        } else {
+            ""
+        };
+        outln!(
+            "• {} - {} words, {} evidence links{tags}{thin}",
+            section.heading,
+            section.word_count,
+            section.evidence_links
+        );
+    }
+
+    outln!();
+    outln!(
+        "Total: {} words, {} evidence links",
+        stats.total_words,
+        stats.total_evidence_links
+    );
+    if !stats.tag_counts.is_empty() {
+        let tags: Vec<String> = stats
+            .tag_counts
+            .iter()
+            .map(|(tag, count)| format!("#{tag} ({count})"))
+            .collect();
+        outln!("Tags: {}", tags.join(", "));
+    }
+
+    Ok(())
+}
+
+pub fn handle_notes_encrypt_command(
+    data_path: &DataPath,
+    employee: &str,
+    recipient: &str,
+) -> io::Result<()> {
+    NoteEncryptionService::validate_recipient(recipient)?;
+    let Some(employee) = resolve_employee(data_path, employee, false)? else {
+        return Ok(());
+    };
+    // Read first: notes already encrypted to another key need the identity to re-encrypt
+    let notes = NotesService::read_notes(data_path, &employee)?;
+
+    EmployeeService::set_notes_recipient(data_path, &employee, recipient.trim())?;
+
+    if let Some(notes) = notes {
+        NoteEncryptionService::write(data_path, &employee, recipient.trim(), &notes, &AgeCli)?;
+    }
+    outln!(
+        "🔒 Notes for {employee} are encrypted to {} as {}",
+        recipient.trim(),
+        NoteEncryptionService::encrypted_path(data_path, &employee).display()
+    );
+    outln!("Set the identity that decrypts them with `reviewr config set age_identity <path>`.");
+    Ok(())
+}
+
+pub fn handle_notes_show_command(data_path: &DataPath, employee: &str) -> io::Result<()> {
+    let Some(employee) = resolve_employee(data_path, employee, false)? else {
+        return Ok(());
+    };
+    let Some(content) = NotesService::read_notes(data_path, &employee)? else {
+        outln!("No notes found for '{employee}'.");
+        return Ok(());
+    };
+
+    // Piped or redirected output gets the Markdown as is
+    if !io::stdout().is_terminal() {
+        out!("{content}");
+        return Ok(());
+    }
+    use crate::tui::NotesPager;
+    let mut pager = NotesPager::new(format!("📝 Notes for {employee}"), &content);
+    pager.set_employees(&EmployeeService::list_employees(data_path)?);
+    pager.set_browser_command(effective_browser_command(data_path)?);
+    pager.run()
+}
+
+pub fn handle_notes_mentions_command(data_path: &DataPath, employee: &str) -> io::Result<()> {
+    let mentions = NotesService::find_mentions(data_path, employee)?;
+    if mentions.is_empty() {
+        outln!("No notes mention @{employee}.");
+        return Ok(());
+    }
+
+    outln!("Mentions of @{employee} ({}):", mentions.len());
+    outln!("{}", "=".repeat(20));
+    for mention in &mentions {
+        match &mention.section {
+            Some(section) => outln!("• {} ({section}): {}", mention.source, mention.line),
+            None => outln!("• {}: {}", mention.source, mention.line),
+        }
     }
 
-    NotesService::open_notes(data_path, employee)
+    Ok(())
 }
 
-pub fn handle_edit_command(data_path: &DataPath, employee: &Option<String>) -> io::Result<()> {
+pub fn handle_edit_command(
+    data_path: &DataPath,
+    employee: &Option<String>,
+    raw: bool,
+) -> io::Result<()> {
     match employee {
         Some(name) => {
-            if !EmployeeService::employee_exists(data_path, name) {
-                println!("Employee '{name}' not found.");
+            let Some(name) = resolve_employee(data_path, name, false)? else {
                 return Ok(());
+            };
+            let name = name.as_str();
+            if raw {
+                return edit_employee_raw(data_path, name);
             }
 
             // Get existing employee data
@@ -288,15 +3033,16 @@ pub fn handle_edit_command(data_path: &DataPath, employee: &Option<String>) -> i
                 existing_employee.name.clone(),
                 existing_employee.title.clone(),
                 existing_employee.committer_email.clone(),
+                existing_employee.profile.clone(),
             );
 
             match form.run(data_path)? {
                 Some(_employee_data) => {
-                    println!("Employee '{name}' updated.");
+                    outln!("Employee '{name}' updated.");
                     Ok(())
                 }
                 None => {
-                    println!("Edit cancelled.");
+                    outln!("Edit cancelled.");
                     Ok(())
                 }
             }
@@ -306,162 +3052,361 @@ pub fn handle_edit_command(data_path: &DataPath, employee: &Option<String>) -> i
             use crate::tui::EmployeeSelector;
             let mut selector = EmployeeSelector::new(data_path)?;
             if let Some(selected_employee) = selector.run()? {
-                handle_edit_command(data_path, &Some(selected_employee))
+                handle_edit_command(data_path, &Some(selected_employee), raw)
             } else {
-                println!("No employee selected.");
+                outln!("No employee selected.");
                 Ok(())
             }
         }
     }
 }
 
+/// Edit the employee TOML in `$EDITOR` on a scratch copy, saving it only once it validates
+fn edit_employee_raw(data_path: &DataPath, name: &str) -> io::Result<()> {
+    let employee_path = EmployeeService::employee_path(data_path, name);
+    let original = fs::read_to_string(&employee_path)?;
+    let scratch_path = env::temp_dir().join(format!("reviewr-{name}.toml"));
+    fs::write(&scratch_path, &original)?;
+
+    let editor = env::var("EDITOR").unwrap_or_else(|_| "vim".to_string());
+    let result = loop {
+        info!("Opening {} with editor: {editor}", scratch_path.display());
+        Command::new(&editor).arg(&scratch_path).status()?;
+        let edited = fs::read_to_string(&scratch_path)?;
+        if edited == original {
+            outln!("No changes to '{name}'.");
+            break Ok(());
+        }
+
+        match EmployeeService::write_employee_toml(data_path, name, &edited) {
+            Ok(()) => {
+                outln!("Employee '{name}' updated.");
+                break Ok(());
+            }
+            Err(e) => {
+                outln!("❌ Invalid employee file: {e}");
+                out!("Re-open the editor to fix it? (y/n) ");
+                io::stdout().flush()?;
+                let mut input = String::new();
+                io::stdin().read_line(&mut input)?;
+                if !input.trim().eq_ignore_ascii_case("y") {
+                    outln!("Edit discarded; {} is unchanged.", employee_path.display());
+                    break Ok(());
+                }
+            }
+        }
+    };
+    let _ = fs::remove_file(&scratch_path);
+    result
+}
+
 pub fn handle_config_command(
     data_path: &DataPath,
     command: &Option<ConfigCommands>,
 ) -> io::Result<()> {
     match command {
-        Some(ConfigCommands::Get { key }) => {
-            let config = UnifiedConfigService::load_config(data_path)?;
-            match key.as_str() {
-                "allowed_domains" => {
-                    println!(
-                        "allowed_domains: {:?}",
-                        config.global_settings.allowed_domains
-                    );
-                    println!("Config file: {}", data_path.config_path().display());
-                }
-                _ => {
-                    println!("Unknown key: {key}");
+        Some(ConfigCommands::Get { key, output }) => {
+            if Setting::find(key).is_err() {
+                if *output == OutputFormat::Json {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("Unknown key: {key}"),
+                    ));
                 }
+                outln!("Unknown key: {key}");
+                return Ok(());
             }
+            if *output == OutputFormat::Json {
+                let resolved = Settings::load(data_path)?.resolve(key)?;
+                let (value, source) = match resolved {
+                    Some((value, source)) => (
+                        serde_json::to_value(&value).map_err(io::Error::other)?,
+                        Some(source.to_string()),
+                    ),
+                    None => (serde_json::Value::Null, None),
+                };
+                return print_json(&serde_json::json!({
+                    "key": key,
+                    "value": value,
+                    "source": source,
+                    "config_file": data_path.config_path(),
+                }));
+            }
+            print_setting(&Settings::load(data_path)?, key)?;
+            outln!("Config file: {}", data_path.config_path().display());
         }
         Some(ConfigCommands::Set { key, value }) => {
-            let mut config = UnifiedConfigService::load_config(data_path)?;
-            match key.as_str() {
-                "allowed_domains" => {
-                    let domains: Result<Vec<String>, io::Error> = value
-                        .split(',')
-                        .map(|s| {
-                            let domain = s.trim().to_string();
-                            validate_domain(&domain)?;
-                            Ok(domain)
-                        })
-                        .collect();
-
-                    match domains {
-                        Ok(valid_domains) => {
-                            config.global_settings.allowed_domains = valid_domains;
-                            UnifiedConfigService::save_config(&config, data_path)?;
-                            info!("Updated allowed_domains configuration");
-                            println!(
-                                "allowed_domains set to: {:?}",
-                                config.global_settings.allowed_domains
-                            );
-                            println!("Config file: {}", data_path.config_path().display());
-                        }
-                        Err(e) => {
-                            error!("Invalid domain in configuration: {e}");
-                            return Err(e);
-                        }
-                    }
-                }
-                _ => {
-                    println!("Unknown key: {key}");
+            let Ok(setting) = Setting::find(key) else {
+                outln!("Unknown key: {key}");
+                return Ok(());
+            };
+            match setting.write(data_path, value)? {
+                Some(shown) => outln!("{key} set to: {shown}"),
+                None => outln!("{key} set to: (not set)"),
+            }
+            for warning in setting.warnings(data_path)? {
+                outln!("⚠️  {warning}");
+            }
+            outln!("Config file: {}", data_path.config_path().display());
+        }
+        Some(ConfigCommands::Validate) => {
+            let path = data_path.config_path();
+            let problems = match check_config_file(data_path) {
+                Ok(problems) => problems,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                    outln!("No config file at {}; defaults are used.", path.display());
+                    return Ok(());
                 }
+                Err(e) => return Err(e),
+            };
+            if problems.is_empty() {
+                outln!("✅ {} is valid.", path.display());
+                return Ok(());
+            }
+            for problem in &problems {
+                outln!("{}:{problem}", path.display());
+            }
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} problem(s) in {}", problems.len(), path.display()),
+            ));
+        }
+        Some(ConfigCommands::Edit) => {
+            let mut editor = crate::tui::ConfigEditor::load(data_path)?;
+            if editor.run(data_path, &KeyringBackend)? {
+                outln!(
+                    "💾 Saved the configuration to {}",
+                    data_path.config_path().display()
+                );
+            } else {
+                outln!("No changes saved.");
+            }
+        }
+        Some(ConfigCommands::SetSecret { platform }) => {
+            let secret = read_secret(&format!("Secret for {platform}: "))?;
+            SecretService::store_platform_secret(data_path, platform, &secret, &KeyringBackend)?;
+            outln!("🔐 Stored the {platform} secret in the OS keyring.");
+            outln!(
+                "Removed it from {}; secret_backend is now keyring.",
+                data_path.config_path().display()
+            );
+        }
+        Some(ConfigCommands::Export {
+            output,
+            include_secrets,
+        }) => {
+            let files = BundleService::export(data_path, output, *include_secrets)?;
+            outln!("📦 Exported {} files to {}:", files.len(), output.display());
+            for file in &files {
+                outln!("  {file}");
+            }
+            if *include_secrets {
+                outln!(
+                    "⚠️  The bundle contains platform tokens and passwords in plaintext. Keep it private."
+                );
+            } else {
+                outln!(
+                    "Platform tokens and passwords were left out; set them again after importing."
+                );
+            }
+        }
+        Some(ConfigCommands::Import { bundle, force }) => {
+            let entries = BundleService::import(data_path, bundle, *force)?;
+            outln!(
+                "📥 Imported {} files into {}:",
+                entries.len(),
+                data_path.root.display()
+            );
+            for entry in &entries {
+                let note = if entry.replaced { " (replaced)" } else { "" };
+                outln!("  {}{note}", entry.name);
             }
+            outln!("Run `reviewr doctor` to check the imported configuration.");
         }
         None => {
             // Show all current configuration
-            let config = UnifiedConfigService::load_config(data_path)?;
-            println!("Current Configuration:");
-            println!("======================");
-            println!(
-                "allowed_domains: {:?}",
-                config.global_settings.allowed_domains
-            );
-            println!();
-            println!("Config file: {}", data_path.config_path().display());
+            let settings = Settings::load(data_path)?;
+            outln!("Current Configuration:");
+            outln!("======================");
+            for setting in SETTINGS {
+                print_setting(&settings, setting.key)?;
+            }
+            outln!();
+            outln!("Config file: {}", data_path.config_path().display());
+        }
+    }
+    Ok(())
+}
+
+/// Print `key: value`, naming the environment variable or flag that overrides `config.toml`
+fn print_setting(settings: &Settings, key: &str) -> io::Result<()> {
+    match settings.display(key)? {
+        Some((value, source @ (SettingSource::Env(_) | SettingSource::Flag))) => {
+            outln!("{key}: {value} (from {source})")
         }
+        Some((value, _)) => outln!("{key}: {value}"),
+        None if key == "timezone" => outln!("{key}: local"),
+        None => outln!("{key}: (not set)"),
     }
     Ok(())
 }
 
-pub fn handle_errors_command(command: &Option<ErrorCommands>) -> io::Result<()> {
+/// Read a secret without echoing it in a terminal, or as one line from piped stdin
+fn read_secret(prompt: &str) -> io::Result<String> {
+    if !io::stdin().is_terminal() {
+        let mut secret = String::new();
+        io::stdin().read_line(&mut secret)?;
+        return Ok(secret.trim_end_matches(['\r', '\n']).to_string());
+    }
+
+    out!("{prompt}");
+    io::stdout().flush()?;
+    enable_raw_mode()?;
+    let mut secret = String::new();
+    let result = loop {
+        match event::read() {
+            Ok(Event::Key(key)) => match key.code {
+                KeyCode::Enter => break Ok(()),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    break Err(io::Error::new(io::ErrorKind::Interrupted, "Cancelled"));
+                }
+                KeyCode::Char(c) => secret.push(c),
+                KeyCode::Backspace => {
+                    secret.pop();
+                }
+                KeyCode::Esc => break Err(io::Error::new(io::ErrorKind::Interrupted, "Cancelled")),
+                _ => {}
+            },
+            Ok(_) => {}
+            Err(e) => break Err(e),
+        }
+    };
+    disable_raw_mode()?;
+    outln!();
+    result.map(|()| secret)
+}
+
+/// Open the error log viewer with the entries of `platform` shown first
+fn browse_errors(data_path: &DataPath, platform: Option<String>) -> io::Result<()> {
+    use crate::tui::ErrorBrowser;
+
+    let errors = ErrorLogReader::read_recent_errors(&error_log_path(), usize::MAX, None, None)
+        .map_err(|e| io::Error::other(format!("Failed to read error log: {e}")))?;
+    if errors.is_empty() {
+        outln!("No errors found.");
+        return Ok(());
+    }
+    let mut browser = ErrorBrowser::new(errors);
+    browser.set_browser_command(effective_browser_command(data_path)?);
+    browser.set_platform_filter(platform);
+    browser.run()
+}
+
+pub fn handle_errors_command(
+    data_path: &DataPath,
+    command: &Option<ErrorCommands>,
+) -> io::Result<()> {
     match command {
-        Some(ErrorCommands::List { platform, limit }) => {
-            match ErrorLogReader::read_recent_errors(*limit, platform.as_deref()) {
-                Ok(errors) => {
-                    if errors.is_empty() {
-                        println!("No errors found.");
-                        return Ok(());
-                    }
+        Some(ErrorCommands::Browse { platform }) => browse_errors(data_path, platform.clone())?,
+        Some(ErrorCommands::List {
+            platform,
+            limit,
+            severity,
+        }) => match ErrorLogReader::read_recent_errors(
+            &error_log_path(),
+            *limit,
+            platform.as_deref(),
+            *severity,
+        ) {
+            Ok(errors) => {
+                if errors.is_empty() {
+                    outln!("No errors found.");
+                    return Ok(());
+                }
 
-                    println!("Recent errors (showing {} most recent):", errors.len());
-                    println!();
-
-                    for error in errors {
-                        println!(
-                            "🔴 {} | {} | {}",
-                            error.timestamp, error.platform_id, error.operation
-                        );
-                        println!(
-                            "   Type: {} | Message: {}",
-                            error.error_type, error.error_message
-                        );
-                        if let Some(user) = &error.user {
-                            println!("   User: {user}");
-                        }
-                        if let Some(url) = &error.request_url {
-                            println!("   URL: {url}");
-                        }
-                        if let Some(status) = error.status_code {
-                            println!("   Status: {status}");
-                        }
-                        if !error.metadata.is_empty() {
-                            println!("   Context: {:?}", error.metadata);
-                        }
-                        println!();
+                outln!("Recent errors (showing {} most recent):", errors.len());
+                outln!();
+
+                for error in errors {
+                    let icon = match error.severity {
+                        Severity::Error => "🔴",
+                        Severity::Warning => "🟡",
+                    };
+                    outln!(
+                        "{icon} {} | {} | {}",
+                        error.timestamp,
+                        error.platform_id,
+                        error.operation
+                    );
+                    outln!(
+                        "   Type: {} | Message: {}",
+                        error.error_type,
+                        error.error_message
+                    );
+                    if let Some(user) = &error.user {
+                        outln!("   User: {user}");
                     }
-                }
-                Err(e) => {
-                    error!("Failed to read error log: {e}");
-                    println!("❌ Failed to read error log: {e}");
+                    if let Some(url) = &error.request_url {
+                        outln!("   URL: {url}");
+                    }
+                    if let Some(status) = error.status_code {
+                        outln!("   Status: {status}");
+                    }
+                    if !error.metadata.is_empty() {
+                        outln!("   Context: {:?}", error.metadata);
+                    }
+                    outln!();
                 }
             }
-        }
-        Some(ErrorCommands::Stats) => match ErrorLogReader::get_error_stats() {
+            Err(e) => {
+                error!("Failed to read error log: {e}");
+                outln!("❌ Failed to read error log: {e}");
+            }
+        },
+        Some(ErrorCommands::Stats) => match ErrorLogReader::get_error_stats(&error_log_path()) {
             Ok(stats) => {
                 if stats.is_empty() {
-                    println!("No error statistics available.");
+                    outln!("No error statistics available.");
                     return Ok(());
                 }
 
-                println!("Error statistics by platform:");
-                println!();
+                outln!("Error statistics by platform:");
+                outln!();
 
                 for (platform, platform_stats) in stats {
-                    println!("📊 Platform: {platform}");
-                    println!("   Total errors: {}", platform_stats.total_errors);
+                    outln!("📊 Platform: {platform}");
+                    outln!("   Total errors: {}", platform_stats.total_errors);
                     if let Some(last_error) = &platform_stats.last_error_time {
-                        println!("   Last error: {last_error}");
+                        outln!("   Last error: {last_error}");
+                    }
+                    if platform_stats.total_warnings > 0 {
+                        outln!("   Warnings: {}", platform_stats.total_warnings);
+                    }
+                    if let Some(last_warning) = &platform_stats.last_warning_time {
+                        outln!("   Last warning: {last_warning}");
                     }
 
                     if !platform_stats.error_types.is_empty() {
-                        println!("   Error types:");
+                        outln!("   Error types:");
                         for (error_type, count) in platform_stats.error_types {
-                            println!("     {error_type}: {count}");
+                            outln!("     {error_type}: {count}");
                         }
                     }
-                    println!();
+                    outln!();
                 }
             }
             Err(e) => {
                 error!("Failed to read error statistics: {e}");
-                println!("❌ Failed to read error statistics: {e}");
+                outln!("❌ Failed to read error statistics: {e}");
             }
         },
         Some(ErrorCommands::Export { platform, output }) => {
-            match ErrorLogReader::read_recent_errors(1000, platform.as_deref()) {
+            match ErrorLogReader::read_recent_errors(
+                &error_log_path(),
+                1000,
+                platform.as_deref(),
+                None,
+            ) {
                 Ok(errors) => {
                     let json_output = serde_json::to_string_pretty(&errors).map_err(|e| {
                         io::Error::other(format!("Failed to serialize errors: {e}"))
@@ -470,45 +3415,44 @@ pub fn handle_errors_command(command: &Option<ErrorCommands>) -> io::Result<()>
                     match output {
                         Some(output_path) => {
                             std::fs::write(output_path, json_output)?;
-                            println!(
+                            outln!(
                                 "✅ Exported {} errors to {}",
                                 errors.len(),
                                 output_path.display()
                             );
                         }
                         None => {
-                            println!("{json_output}");
+                            outln!("{json_output}");
                         }
                     }
                 }
                 Err(e) => {
                     error!("Failed to export errors: {e}");
-                    println!("❌ Failed to export errors: {e}");
+                    outln!("❌ Failed to export errors: {e}");
                 }
             }
         }
         Some(ErrorCommands::Clear) => {
-            let data_dir = dirs::home_dir()
-                .unwrap_or_else(|| std::path::PathBuf::from("."))
-                .join(".reviewr");
-
-            let error_log_path = data_dir.join("error.log");
-
+            let error_log_path = error_log_path();
             if error_log_path.exists() {
                 std::fs::remove_file(error_log_path)?;
-                println!("✅ Error log cleared.");
+                outln!("✅ Error log cleared.");
             } else {
-                println!("No error log file found.");
+                outln!("No error log file found.");
             }
         }
+        None if io::stdin().is_terminal() && io::stdout().is_terminal() => {
+            browse_errors(data_path, None)?
+        }
         None => {
-            println!("Available error commands:");
-            println!("  list    - Show recent errors");
-            println!("  stats   - Show error statistics by platform");
-            println!("  export  - Export errors to JSON");
-            println!("  clear   - Clear all recorded errors");
-            println!();
-            println!("Use 'reviewr errors <command> --help' for more information.");
+            outln!("Available error commands:");
+            outln!("  browse  - Browse errors interactively (the default in a terminal)");
+            outln!("  list    - Show recent errors");
+            outln!("  stats   - Show error statistics by platform");
+            outln!("  export  - Export errors to JSON");
+            outln!("  clear   - Clear all recorded errors");
+            outln!();
+            outln!("Use 'reviewr errors <command> --help' for more information.");
         }
     }
 
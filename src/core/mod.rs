@@ -1,9 +0,0 @@
-pub mod employee;
-pub mod fetch_progress;
-pub mod gerrit;
-pub mod gitlab;
-pub mod jira;
-pub mod models;
-pub mod notes;
-pub mod platform;
-pub mod unified_config;
@@ -1,7 +1,4 @@
 pub mod cli;
-pub mod core;
+pub use reviewr_core as core;
 pub mod terminal;
 pub mod tui;
-
-#[cfg(test)]
-pub mod test_fixtures;
@@ -1,16 +1,30 @@
 pub mod cli;
-pub mod core;
+pub use reviewr_core as core;
 pub mod terminal;
 pub mod tui;
 
-use clap::Parser;
 use cli::{
-    Cli, Commands, handle_add_command, handle_config_command, handle_edit_command,
-    handle_errors_command, handle_list_command, handle_notes_command, handle_review_command,
+    Cli, Commands, ConfigCommands, NotesCommands, handle_add_command, handle_archive_command,
+    handle_bulk_edit_command, handle_completions_command, handle_config_command,
+    handle_cycle_command, handle_dashboard_command, handle_doctor_command, handle_edit_command,
+    handle_errors_command, handle_feedback_command, handle_goals_command, handle_handoff_command,
+    handle_handoff_import_command, handle_import_command, handle_init_command,
+    handle_journal_command, handle_list_command, handle_notes_append_command, handle_notes_command,
+    handle_notes_encrypt_command, handle_notes_list_command, handle_notes_mentions_command,
+    handle_notes_show_command, handle_notes_stats_command, handle_org_stats_command,
+    handle_remove_command, handle_rename_command, handle_report_command, handle_review_command,
+    handle_search_command, handle_summary_command, handle_team_review_command,
+    offer_config_restore,
 };
+use core::bulk_edit::EmployeeFilter;
 use core::models::DataPath;
+use core::platform::set_error_log_dir;
+use core::redact::set_verbose_error_logs;
+use core::settings::Settings;
+use core::unified_config::UnifiedConfigService;
 use std::fs;
 use std::io;
+use terminal::{plain_output_requested, set_plain_output};
 use tui::EmployeeSelector;
 
 #[tokio::main]
@@ -18,40 +32,234 @@ async fn main() -> io::Result<()> {
     // Initialize logging
     env_logger::init();
 
-    let cli = Cli::parse();
+    let cli = Cli::parse_args();
+    set_plain_output(plain_output_requested(cli.no_color, std::env::vars()));
     let data_path = DataPath::new(cli.data_path)?;
 
     fs::create_dir_all(&data_path.employees_dir)?;
     fs::create_dir_all(&data_path.notes_dir)?;
+    set_error_log_dir(&data_path);
+
+    match UnifiedConfigService::load_config(&data_path) {
+        Ok(_) => set_verbose_error_logs(Settings::load(&data_path)?.get("verbose_error_logs")?),
+        // Doctor and `config validate` report the broken file themselves
+        Err(e)
+            if e.kind() == io::ErrorKind::InvalidData
+                && !matches!(
+                    cli.command,
+                    Commands::Doctor
+                        | Commands::Config {
+                            command: Some(ConfigCommands::Validate)
+                        }
+                ) =>
+        {
+            offer_config_restore(&data_path, &e)?
+        }
+        Err(_) => {}
+    }
 
     match &cli.command {
+        Commands::Init => {
+            handle_init_command(&data_path).await?;
+        }
         Commands::Add { employee } => {
             handle_add_command(&data_path, employee)?;
         }
-        Commands::Notes { employee } => {
-            if let Some(employee_name) = employee {
-                handle_notes_command(&data_path, employee_name)?;
+        Commands::Notes {
+            command,
+            employee,
+            no_clipboard,
+            template,
+            append,
+            list,
+            view,
+        } => {
+            if let Some(NotesCommands::Show { employee }) = command {
+                handle_notes_show_command(&data_path, employee)?;
+            } else if let Some(NotesCommands::Stats { employee }) = command {
+                handle_notes_stats_command(&data_path, employee)?;
+            } else if let Some(NotesCommands::Mentions { employee }) = command {
+                handle_notes_mentions_command(&data_path, employee)?;
+            } else if let Some(NotesCommands::Encrypt {
+                employee,
+                recipient,
+            }) = command
+            {
+                handle_notes_encrypt_command(&data_path, employee, recipient)?;
+            } else if let (Some(employee_name), Some(text)) = (employee, append) {
+                handle_notes_append_command(&data_path, employee_name, text)?;
+            } else if let (Some(employee_name), true) = (employee, *list) {
+                handle_notes_list_command(&data_path, employee_name)?;
+            } else if let (Some(employee_name), true) = (employee, *view) {
+                handle_notes_show_command(&data_path, employee_name)?;
+            } else if let Some(employee_name) = employee {
+                handle_notes_command(
+                    &data_path,
+                    employee_name,
+                    !no_clipboard,
+                    template.as_deref(),
+                )
+                .await?;
             } else {
                 let mut selector = EmployeeSelector::new(&data_path)?;
                 if let Some(selected_employee) = selector.run()? {
-                    handle_notes_command(&data_path, &selected_employee)?;
+                    handle_notes_command(
+                        &data_path,
+                        &selected_employee,
+                        !no_clipboard,
+                        template.as_deref(),
+                    )
+                    .await?;
                 }
             }
         }
-        Commands::Edit { employee } => {
-            handle_edit_command(&data_path, employee)?;
+        Commands::Journal { all } => {
+            handle_journal_command(&data_path, *all)?;
+        }
+        Commands::Goals { employee } => {
+            handle_goals_command(&data_path, employee)?;
+        }
+        Commands::Edit { employee, raw } => {
+            handle_edit_command(&data_path, employee, *raw)?;
+        }
+        Commands::Rename { old, new, merge } => {
+            handle_rename_command(&data_path, old, new, *merge)?;
+        }
+        Commands::List {
+            all,
+            verbose,
+            output,
+        } => {
+            handle_list_command(&data_path, *all, *verbose, *output)?;
+        }
+        Commands::Remove { employee, yes } => {
+            handle_remove_command(&data_path, employee, *yes)?;
+        }
+        Commands::BulkEdit {
+            team,
+            manager,
+            tag,
+            all,
+            changes,
+            dry_run,
+        } => {
+            let filter = EmployeeFilter {
+                team: team.clone(),
+                manager: manager.clone(),
+                tag: tag.clone(),
+            };
+            handle_bulk_edit_command(&data_path, filter, *all, changes, *dry_run)?;
+        }
+        Commands::Import { command } => {
+            handle_import_command(&data_path, command)?;
+        }
+        Commands::Archive { employee } => {
+            handle_archive_command(&data_path, employee)?;
+        }
+        Commands::Handoff {
+            employee,
+            output,
+            recipient,
+            import,
+            identity,
+        } => match (import, employee, output) {
+            (Some(folder), _, _) => {
+                handle_handoff_import_command(&data_path, folder, identity.clone())?
+            }
+            (None, Some(employee), Some(output)) => {
+                handle_handoff_command(&data_path, employee, output, recipient.as_deref())?
+            }
+            _ => unreachable!("clap requires an employee and --output without --import"),
+        },
+        Commands::Completions => {
+            handle_completions_command(&data_path)?;
+        }
+        Commands::Review {
+            employee,
+            refresh,
+            days,
+            offline,
+            explain,
+            output,
+        } => {
+            handle_review_command(
+                &data_path, employee, *refresh, *days, *offline, *explain, *output,
+            )
+            .await?;
+        }
+        Commands::Report {
+            employee,
+            output,
+            redact,
+            cycle,
+            compare,
+        } => {
+            handle_report_command(
+                &data_path,
+                employee,
+                output,
+                *redact,
+                cycle.as_deref(),
+                *compare,
+            )
+            .await?;
+        }
+        Commands::Cycle { command } => {
+            handle_cycle_command(&data_path, command)?;
+        }
+        Commands::Summary {
+            employee,
+            format,
+            days,
+            top,
+            output,
+            compare,
+        } => {
+            handle_summary_command(&data_path, employee, *format, *days, *top, output, *compare)
+                .await?;
+        }
+        Commands::Feedback {
+            employee,
+            days,
+            ask,
+            output,
+        } => {
+            handle_feedback_command(&data_path, employee, *days, *ask, output)?;
+        }
+        Commands::OrgStats { days, redact } => {
+            handle_org_stats_command(&data_path, *days, *redact)?;
+        }
+        Commands::TeamReview {
+            team,
+            days,
+            refresh,
+            export,
+            output,
+            redact,
+        } => {
+            handle_team_review_command(&data_path, team, *days, *refresh, *export, output, *redact)
+                .await?;
+        }
+        Commands::Dashboard { team, days } => {
+            handle_dashboard_command(&data_path, team, *days).await?;
         }
-        Commands::List => {
-            handle_list_command(&data_path)?;
+        Commands::Search {
+            query,
+            employee,
+            limit,
+            reindex,
+            output,
+        } => {
+            handle_search_command(&data_path, query, employee, *limit, *reindex, *output)?;
         }
-        Commands::Review { employee } => {
-            handle_review_command(&data_path, employee).await?;
+        Commands::Doctor => {
+            handle_doctor_command(&data_path).await?;
         }
         Commands::Config { command } => {
             handle_config_command(&data_path, command)?;
         }
         Commands::Errors { command } => {
-            handle_errors_command(command)?;
+            handle_errors_command(&data_path, command)?;
         }
     }
 
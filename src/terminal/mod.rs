@@ -1,4 +1,102 @@
+//! Terminal input/output and the plain output mode (`--no-color`, `NO_COLOR`)
+
+use std::borrow::Cow;
 use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static PLAIN_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Strip ANSI styling and emoji from everything the CLI prints
+pub fn set_plain_output(enabled: bool) {
+    PLAIN_OUTPUT.store(enabled, Ordering::Relaxed);
+}
+
+pub fn plain_output() -> bool {
+    PLAIN_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// Plain output is on with `--no-color`, a non-empty `NO_COLOR` (see no-color.org) or `TERM=dumb`
+pub fn plain_output_requested(
+    flag: bool,
+    vars: impl IntoIterator<Item = (String, String)>,
+) -> bool {
+    flag || vars.into_iter().any(|(name, value)| {
+        (name == "NO_COLOR" && !value.is_empty()) || (name == "TERM" && value == "dumb")
+    })
+}
+
+/// Status emoji keep their meaning as a text marker; every other emoji is dropped
+fn status_marker(c: char) -> Option<&'static str> {
+    match c {
+        '✅' | '✓' => Some("[OK]"),
+        '⚠' | '🟡' => Some("[WARN]"),
+        '❌' | '🔴' => Some("[ERROR]"),
+        _ => None,
+    }
+}
+
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1F000}'..='\u{1FAFF}'
+            | '\u{2600}'..='\u{27BF}'
+            | '\u{2B50}'..='\u{2B55}'
+            | '\u{FE0F}'
+            | '\u{200D}'
+            | '\u{20E3}'
+    )
+}
+
+/// `text` without ANSI escape sequences, with status emoji as `[OK]`/`[WARN]`/`[ERROR]` and
+/// other emoji removed along with the space that followed them
+pub fn to_plain(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // CSI sequences end at the first byte in @..~, other escapes at the first byte after
+            // their intermediate bytes (space../)
+            if chars.next_if_eq(&'[').is_some() {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            } else {
+                while chars.next_if(|c| (' '..='/').contains(c)).is_some() {}
+                chars.next();
+            }
+            continue;
+        }
+        if let Some(marker) = status_marker(c) {
+            plain.push_str(marker);
+            while chars.next_if(|&c| is_emoji(c)).is_some() {}
+            // "⚠️  text" keeps a single space after the marker
+            if chars.next_if_eq(&' ').is_some() {
+                while chars.next_if_eq(&' ').is_some() {}
+                plain.push(' ');
+            }
+        } else if is_emoji(c) {
+            while chars.next_if(|&c| is_emoji(c)).is_some() {}
+            while chars.next_if_eq(&' ').is_some() {}
+            if chars.peek().is_none_or(|&c| c == '\n') {
+                plain.truncate(plain.trim_end_matches(' ').len());
+            }
+        } else {
+            plain.push(c);
+        }
+    }
+    plain
+}
+
+/// `text` as printed: unchanged, or [`to_plain`] in plain output mode
+pub fn styled(text: &str) -> Cow<'_, str> {
+    if plain_output() {
+        Cow::Owned(to_plain(text))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
 
 #[allow(dead_code)]
 pub trait Terminal {
@@ -70,3 +168,47 @@ impl Terminal for MockTerminal {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_plain() {
+        assert_eq!(to_plain("plain text"), "plain text");
+        assert_eq!(
+            to_plain("\u{1b}[1;31mred\u{1b}[0m and \u{1b}(Bnormal"),
+            "red and normal"
+        );
+        assert_eq!(to_plain("✅ Error log cleared."), "[OK] Error log cleared.");
+        assert_eq!(
+            to_plain("⚠️  config.toml cannot be loaded"),
+            "[WARN] config.toml cannot be loaded"
+        );
+        assert_eq!(
+            to_plain("🔴 2024-01-01 | gerrit"),
+            "[ERROR] 2024-01-01 | gerrit"
+        );
+        assert_eq!(to_plain("📄 Wrote report.md"), "Wrote report.md");
+        assert_eq!(to_plain("Summary 📊"), "Summary");
+        assert_eq!(
+            to_plain("• Alice · 3 changes → done"),
+            "• Alice · 3 changes → done"
+        );
+    }
+
+    #[test]
+    fn test_plain_output_requested() {
+        let vars = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<Vec<_>>()
+        };
+        assert!(plain_output_requested(true, vars(&[])));
+        assert!(!plain_output_requested(false, vars(&[("TERM", "xterm")])));
+        assert!(plain_output_requested(false, vars(&[("NO_COLOR", "1")])));
+        assert!(!plain_output_requested(false, vars(&[("NO_COLOR", "")])));
+        assert!(plain_output_requested(false, vars(&[("TERM", "dumb")])));
+    }
+}
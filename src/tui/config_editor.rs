@@ -0,0 +1,942 @@
+//! `reviewr config edit`: add, edit and remove Gerrit, JIRA and GitLab instances
+
+use crate::core::{
+    gerrit::GerritConfig,
+    models::DataPath,
+    secrets::{SecretBackend, SecretBackendKind},
+    unified_config::{
+        DEFAULT_INSTANCE, GitLabConfig, JiraConfig, RequestLimits, UnifiedConfig,
+        UnifiedConfigService, instance_platform_id, validate_platform_url,
+    },
+};
+use crossterm::{
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use ratatui::{
+    Frame, Terminal,
+    backend::{Backend, CrosstermBackend},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+use std::collections::HashMap;
+use std::io;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlatformKind {
+    Gerrit,
+    Jira,
+    GitLab,
+}
+
+impl PlatformKind {
+    fn display_name(&self) -> &'static str {
+        match self {
+            PlatformKind::Gerrit => "Gerrit",
+            PlatformKind::Jira => "JIRA",
+            PlatformKind::GitLab => "GitLab",
+        }
+    }
+
+    /// Form field titles, in Tab order; the last one is the secret
+    fn fields(&self) -> [&'static str; 4] {
+        match self {
+            PlatformKind::Gerrit => ["Instance name", "Gerrit URL", "Username", "HTTP password"],
+            PlatformKind::Jira => ["Instance name", "JIRA URL", "Username", "API token"],
+            PlatformKind::GitLab => [
+                "Instance name",
+                "GitLab URL",
+                "Display name",
+                "Access token",
+            ],
+        }
+    }
+
+    /// Keyring account of an instance, as used by `config set-secret`
+    fn account(&self, instance: &str) -> String {
+        match self {
+            PlatformKind::Gerrit => instance_platform_id("gerrit", instance),
+            PlatformKind::Jira => instance_platform_id("jira", instance),
+            PlatformKind::GitLab => format!("gitlab:{instance}"),
+        }
+    }
+}
+
+const SECRET_FIELD: usize = 3;
+
+/// One configured instance in the list
+#[derive(Debug, Clone, PartialEq)]
+struct Section {
+    kind: PlatformKind,
+    instance: String,
+}
+
+/// Values of the platform form: instance, URL, username (display name for GitLab), secret
+struct PlatformForm {
+    kind: PlatformKind,
+    /// Instance being edited; None when adding one
+    original: Option<String>,
+    values: [String; 4],
+    current_field: usize,
+    /// Validation problem shown below the fields
+    error: Option<String>,
+}
+
+enum EditorMode {
+    List,
+    ChoosePlatform,
+    Form(PlatformForm),
+    ConfirmDelete,
+    ConfirmDiscard,
+}
+
+pub struct ConfigEditor {
+    config: UnifiedConfig,
+    sections: Vec<Section>,
+    list_state: ListState,
+    mode: EditorMode,
+    /// Secrets typed while the keyring is the backend, by account; stored on save
+    pending_secrets: HashMap<String, String>,
+    modified: bool,
+}
+
+impl ConfigEditor {
+    /// Edit the configuration as written in `config.toml`, without keyring secrets
+    pub fn load(data_path: &DataPath) -> io::Result<Self> {
+        Ok(Self::new(UnifiedConfigService::load_config(data_path)?))
+    }
+
+    fn new(config: UnifiedConfig) -> Self {
+        let mut editor = Self {
+            config,
+            sections: Vec::new(),
+            list_state: ListState::default(),
+            mode: EditorMode::List,
+            pending_secrets: HashMap::new(),
+            modified: false,
+        };
+        editor.refresh_sections();
+        editor
+    }
+
+    /// Returns whether the configuration was saved
+    pub fn run(&mut self, data_path: &DataPath, secrets: &dyn SecretBackend) -> io::Result<bool> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let result = self.run_app(&mut terminal, data_path, secrets);
+
+        disable_raw_mode()?;
+        execute!(
+            terminal.backend_mut(),
+            LeaveAlternateScreen,
+            DisableMouseCapture
+        )?;
+        terminal.show_cursor()?;
+
+        result
+    }
+
+    fn run_app<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        data_path: &DataPath,
+        secrets: &dyn SecretBackend,
+    ) -> io::Result<bool> {
+        loop {
+            terminal.draw(|f| self.ui(f))?;
+            if let Event::Key(key) = event::read()?
+                && let Some(saved) = self.handle_key_event(key, data_path, secrets)?
+            {
+                return Ok(saved);
+            }
+        }
+    }
+
+    fn uses_keyring(&self) -> bool {
+        self.config.global_settings.secret_backend == SecretBackendKind::Keyring
+    }
+
+    fn refresh_sections(&mut self) {
+        let platforms = &self.config.platforms;
+        let mut sections = Vec::new();
+        for (kind, mut instances) in [
+            (
+                PlatformKind::Gerrit,
+                platforms.gerrit.keys().collect::<Vec<_>>(),
+            ),
+            (PlatformKind::Jira, platforms.jira.keys().collect()),
+            (PlatformKind::GitLab, platforms.gitlab.keys().collect()),
+        ] {
+            instances.sort();
+            sections.extend(instances.into_iter().map(|instance| Section {
+                kind,
+                instance: instance.clone(),
+            }));
+        }
+        self.sections = sections;
+
+        let selected = self.list_state.selected().unwrap_or(0);
+        self.list_state.select(if self.sections.is_empty() {
+            None
+        } else {
+            Some(selected.min(self.sections.len() - 1))
+        });
+    }
+
+    fn selected_section(&self) -> Option<&Section> {
+        self.list_state
+            .selected()
+            .and_then(|index| self.sections.get(index))
+    }
+
+    fn instance_exists(&self, kind: PlatformKind, instance: &str) -> bool {
+        let platforms = &self.config.platforms;
+        match kind {
+            PlatformKind::Gerrit => platforms.gerrit.contains_key(instance),
+            PlatformKind::Jira => platforms.jira.contains_key(instance),
+            PlatformKind::GitLab => platforms.gitlab.contains_key(instance),
+        }
+    }
+
+    fn add_form(&self, kind: PlatformKind) -> PlatformForm {
+        // The first Gerrit or JIRA server keeps the plain `gerrit`/`jira` platform id
+        let instance =
+            if kind != PlatformKind::GitLab && !self.instance_exists(kind, DEFAULT_INSTANCE) {
+                DEFAULT_INSTANCE.to_string()
+            } else {
+                String::new()
+            };
+        PlatformForm {
+            kind,
+            original: None,
+            values: [instance, String::new(), String::new(), String::new()],
+            current_field: 0,
+            error: None,
+        }
+    }
+
+    fn edit_form(&self, section: &Section) -> PlatformForm {
+        let platforms = &self.config.platforms;
+        let instance = section.instance.clone();
+        let values = match section.kind {
+            PlatformKind::Gerrit => {
+                let gerrit = &platforms.gerrit[&instance];
+                [
+                    instance.clone(),
+                    gerrit.gerrit_url.clone(),
+                    gerrit.username.clone(),
+                    gerrit.http_password.clone(),
+                ]
+            }
+            PlatformKind::Jira => {
+                let jira = &platforms.jira[&instance];
+                [
+                    instance.clone(),
+                    jira.jira_url.clone(),
+                    jira.username.clone(),
+                    jira.api_token.clone(),
+                ]
+            }
+            PlatformKind::GitLab => {
+                let gitlab = &platforms.gitlab[&instance];
+                [
+                    instance.clone(),
+                    gitlab.url.clone(),
+                    gitlab.name.clone(),
+                    gitlab.token.clone(),
+                ]
+            }
+        };
+        PlatformForm {
+            kind: section.kind,
+            original: Some(instance),
+            values,
+            current_field: 0,
+            error: None,
+        }
+    }
+
+    /// Why the form cannot be applied, if anything
+    fn validate(&self, form: &PlatformForm) -> Result<(), String> {
+        let [instance, url, user, secret] = form.values.each_ref().map(|value| value.trim());
+        let fields = form.kind.fields();
+        if instance.is_empty() || instance.contains(|c: char| c == ':' || c.is_whitespace()) {
+            return Err("Instance name cannot be empty or contain ':' or spaces".to_string());
+        }
+        if form.original.as_deref() != Some(instance) && self.instance_exists(form.kind, instance) {
+            return Err(format!(
+                "A {} instance named '{instance}' already exists",
+                form.kind.display_name()
+            ));
+        }
+        validate_platform_url(url).map_err(|e| e.to_string())?;
+        if user.is_empty() {
+            return Err(format!("{} is required", fields[2]));
+        }
+        // With the keyring a blank secret keeps the stored one, which only exists under the
+        // instance's current name
+        let keeps_stored = self.uses_keyring() && form.original.as_deref() == Some(instance);
+        if secret.is_empty() && !keeps_stored {
+            return Err(format!("{} is required", fields[SECRET_FIELD]));
+        }
+        Ok(())
+    }
+
+    /// Write a validated form into the configuration
+    fn apply(&mut self, form: &PlatformForm) {
+        let [instance, url, user, secret] =
+            form.values.each_ref().map(|value| value.trim().to_string());
+        let secret = if self.uses_keyring() {
+            if !secret.is_empty() {
+                self.pending_secrets
+                    .insert(form.kind.account(&instance), secret);
+            }
+            // Never leave a plaintext copy next to a keyring secret
+            String::new()
+        } else {
+            secret
+        };
+
+        let platforms = &mut self.config.platforms;
+        let original = form.original.as_deref().unwrap_or(&instance);
+        match form.kind {
+            PlatformKind::Gerrit => {
+                let mut gerrit = platforms.gerrit.remove(original).unwrap_or(GerritConfig {
+                    gerrit_url: String::new(),
+                    username: String::new(),
+                    http_password: String::new(),
+                    accept_invalid_certs: false,
+                    limits: RequestLimits::default(),
+                });
+                gerrit.gerrit_url = url;
+                gerrit.username = user;
+                gerrit.http_password = secret;
+                platforms.gerrit.insert(instance.clone(), gerrit);
+            }
+            PlatformKind::Jira => {
+                // Project filters, custom fields and field selection are kept as they are
+                let mut jira = platforms.jira.remove(original).unwrap_or(JiraConfig {
+                    jira_url: String::new(),
+                    username: String::new(),
+                    api_token: String::new(),
+                    project_filter: Vec::new(),
+                    custom_fields: HashMap::new(),
+                    fields: Vec::new(),
+                    lazy_details: false,
+                    accept_invalid_certs: false,
+                    limits: RequestLimits::default(),
+                });
+                jira.jira_url = url;
+                jira.username = user;
+                jira.api_token = secret;
+                platforms.jira.insert(instance.clone(), jira);
+            }
+            PlatformKind::GitLab => {
+                let (accept_invalid_certs, limits) = platforms
+                    .gitlab
+                    .remove(original)
+                    .map(|gitlab| (gitlab.accept_invalid_certs, gitlab.limits))
+                    .unwrap_or_default();
+                platforms.gitlab.insert(
+                    instance.clone(),
+                    GitLabConfig {
+                        name: user,
+                        url,
+                        token: secret,
+                        accept_invalid_certs,
+                        limits,
+                    },
+                );
+            }
+        }
+        self.modified = true;
+        self.refresh_sections();
+        let section = Section {
+            kind: form.kind,
+            instance,
+        };
+        let index = self.sections.iter().position(|s| *s == section);
+        self.list_state.select(index);
+    }
+
+    fn delete_selected(&mut self) {
+        let Some(section) = self.selected_section().cloned() else {
+            return;
+        };
+        let platforms = &mut self.config.platforms;
+        match section.kind {
+            PlatformKind::Gerrit => platforms.gerrit.remove(&section.instance).map(|_| ()),
+            PlatformKind::Jira => platforms.jira.remove(&section.instance).map(|_| ()),
+            PlatformKind::GitLab => platforms.gitlab.remove(&section.instance).map(|_| ()),
+        };
+        self.pending_secrets
+            .remove(&section.kind.account(&section.instance));
+        self.modified = true;
+        self.refresh_sections();
+    }
+
+    /// Store typed keyring secrets, then replace `config.toml`
+    fn save(&mut self, data_path: &DataPath, secrets: &dyn SecretBackend) -> io::Result<()> {
+        for (account, secret) in &self.pending_secrets {
+            secrets.set(account, secret)?;
+        }
+        self.pending_secrets.clear();
+        UnifiedConfigService::save_config(&self.config, data_path)?;
+        self.modified = false;
+        Ok(())
+    }
+
+    /// Returns `Some(saved)` when the editor should close
+    fn handle_key_event(
+        &mut self,
+        key: KeyEvent,
+        data_path: &DataPath,
+        secrets: &dyn SecretBackend,
+    ) -> io::Result<Option<bool>> {
+        match &mut self.mode {
+            EditorMode::List => match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => {
+                    if !self.modified {
+                        return Ok(Some(false));
+                    }
+                    self.mode = EditorMode::ConfirmDiscard;
+                }
+                KeyCode::Down | KeyCode::Char('j') if !self.sections.is_empty() => {
+                    let selected = self.list_state.selected().unwrap_or(0);
+                    self.list_state
+                        .select(Some((selected + 1).min(self.sections.len() - 1)));
+                }
+                KeyCode::Up | KeyCode::Char('k') if !self.sections.is_empty() => {
+                    let selected = self.list_state.selected().unwrap_or(0);
+                    self.list_state.select(Some(selected.saturating_sub(1)));
+                }
+                KeyCode::Char('a') => self.mode = EditorMode::ChoosePlatform,
+                KeyCode::Enter | KeyCode::Char('e') => {
+                    if let Some(section) = self.selected_section() {
+                        self.mode = EditorMode::Form(self.edit_form(section));
+                    }
+                }
+                KeyCode::Char('d') if self.selected_section().is_some() => {
+                    self.mode = EditorMode::ConfirmDelete;
+                }
+                KeyCode::Char('s') => {
+                    self.save(data_path, secrets)?;
+                    return Ok(Some(true));
+                }
+                _ => {}
+            },
+            EditorMode::ChoosePlatform => {
+                let kind = match key.code {
+                    KeyCode::Char('g') => Some(PlatformKind::Gerrit),
+                    KeyCode::Char('j') => Some(PlatformKind::Jira),
+                    KeyCode::Char('l') => Some(PlatformKind::GitLab),
+                    KeyCode::Esc => {
+                        self.mode = EditorMode::List;
+                        None
+                    }
+                    _ => None,
+                };
+                if let Some(kind) = kind {
+                    self.mode = EditorMode::Form(self.add_form(kind));
+                }
+            }
+            EditorMode::Form(form) => match key.code {
+                KeyCode::Char(c) => form.values[form.current_field].push(c),
+                KeyCode::Backspace => {
+                    form.values[form.current_field].pop();
+                }
+                KeyCode::Tab | KeyCode::Down => {
+                    form.current_field = (form.current_field + 1) % form.values.len();
+                }
+                KeyCode::BackTab | KeyCode::Up => {
+                    form.current_field =
+                        (form.current_field + form.values.len() - 1) % form.values.len();
+                }
+                KeyCode::Esc => self.mode = EditorMode::List,
+                KeyCode::Enter => {
+                    let EditorMode::Form(form) =
+                        std::mem::replace(&mut self.mode, EditorMode::List)
+                    else {
+                        unreachable!("matched the form mode above");
+                    };
+                    match self.validate(&form) {
+                        Ok(()) => self.apply(&form),
+                        Err(error) => {
+                            self.mode = EditorMode::Form(PlatformForm {
+                                error: Some(error),
+                                ..form
+                            })
+                        }
+                    }
+                }
+                _ => {}
+            },
+            EditorMode::ConfirmDelete => {
+                if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                    self.delete_selected();
+                }
+                self.mode = EditorMode::List;
+            }
+            EditorMode::ConfirmDiscard => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => return Ok(Some(false)),
+                _ => self.mode = EditorMode::List,
+            },
+        }
+        Ok(None)
+    }
+
+    /// URL, user and secret state of a section for the list
+    fn section_summary(&self, section: &Section) -> String {
+        let platforms = &self.config.platforms;
+        let (url, user, secret) = match section.kind {
+            PlatformKind::Gerrit => {
+                let gerrit = &platforms.gerrit[&section.instance];
+                (&gerrit.gerrit_url, &gerrit.username, &gerrit.http_password)
+            }
+            PlatformKind::Jira => {
+                let jira = &platforms.jira[&section.instance];
+                (&jira.jira_url, &jira.username, &jira.api_token)
+            }
+            PlatformKind::GitLab => {
+                let gitlab = &platforms.gitlab[&section.instance];
+                (&gitlab.url, &gitlab.name, &gitlab.token)
+            }
+        };
+        let account = section.kind.account(&section.instance);
+        let secret = if self.pending_secrets.contains_key(&account) {
+            "secret: keyring (unsaved)"
+        } else if !secret.is_empty() {
+            "secret: config.toml"
+        } else if self.uses_keyring() {
+            "secret: keyring"
+        } else {
+            "secret: missing"
+        };
+        format!(
+            "{:<7} {:<12} {url}  {user}  {secret}",
+            section.kind.display_name(),
+            section.instance
+        )
+    }
+
+    fn ui(&mut self, f: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(3)])
+            .split(f.area());
+
+        let secrets = if self.uses_keyring() {
+            "OS keyring"
+        } else {
+            "config.toml"
+        };
+        let modified = if self.modified { " [modified]" } else { "" };
+        let title = format!("Platforms (secrets in {secrets}){modified}");
+        let block = Block::default().borders(Borders::ALL).title(title);
+        if self.sections.is_empty() {
+            let empty = Paragraph::new("No platforms configured. Press 'a' to add one.")
+                .style(Style::default().fg(Color::Gray))
+                .block(block);
+            f.render_widget(empty, chunks[0]);
+        } else {
+            let items: Vec<ListItem> = self
+                .sections
+                .iter()
+                .map(|section| ListItem::new(self.section_summary(section)))
+                .collect();
+            let list = List::new(items)
+                .block(block)
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+                .highlight_symbol("▶ ");
+            f.render_stateful_widget(list, chunks[0], &mut self.list_state);
+        }
+
+        let footer = Paragraph::new(
+            "↑/↓: Move | a: Add | Enter/e: Edit | d: Delete | s: Save & quit | q/Esc: Quit",
+        )
+        .block(Block::default().borders(Borders::ALL));
+        f.render_widget(footer, chunks[1]);
+
+        let area = f.area();
+        match &self.mode {
+            EditorMode::List => {}
+            EditorMode::ChoosePlatform => {
+                let popup_area = centered_rect(50, 30, area);
+                f.render_widget(Clear, popup_area);
+                let choose = Paragraph::new(
+                    "Add which platform?\n\ng: Gerrit\nj: JIRA\nl: GitLab\n\nEsc: Cancel",
+                )
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Add platform")
+                        .style(Style::default().fg(Color::Yellow)),
+                );
+                f.render_widget(choose, popup_area);
+            }
+            EditorMode::Form(form) => self.render_form(f, form),
+            EditorMode::ConfirmDelete => {
+                let Some(section) = self.selected_section() else {
+                    return;
+                };
+                let popup_area = centered_rect(50, 30, area);
+                f.render_widget(Clear, popup_area);
+                let text = format!(
+                    "Remove the {} instance '{}'?\n\nPress Y to confirm, any other key to cancel",
+                    section.kind.display_name(),
+                    section.instance
+                );
+                let confirm = Paragraph::new(text).wrap(Wrap { trim: false }).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Confirm")
+                        .style(Style::default().fg(Color::Red)),
+                );
+                f.render_widget(confirm, popup_area);
+            }
+            EditorMode::ConfirmDiscard => {
+                let popup_area = centered_rect(50, 30, area);
+                f.render_widget(Clear, popup_area);
+                let confirm = Paragraph::new(
+                    "Quit without saving your changes?\n\nPress Y to discard them, any other key to go back",
+                )
+                .wrap(Wrap { trim: false })
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Unsaved changes")
+                        .style(Style::default().fg(Color::Red)),
+                );
+                f.render_widget(confirm, popup_area);
+            }
+        }
+    }
+
+    fn render_form(&self, f: &mut Frame, form: &PlatformForm) {
+        let area = f.area();
+        f.render_widget(Clear, area);
+        let fields = form.kind.fields();
+        let mut constraints = vec![Constraint::Length(3); fields.len()];
+        constraints.push(Constraint::Length(2));
+        constraints.push(Constraint::Min(0));
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(constraints)
+            .split(area);
+
+        for (index, title) in fields.iter().enumerate() {
+            let style = if form.current_field == index {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            let (value, title) = if index == SECRET_FIELD {
+                let title = if self.uses_keyring() && form.original.is_some() {
+                    format!("{title} (blank keeps the keyring secret)")
+                } else {
+                    title.to_string()
+                };
+                ("•".repeat(form.values[index].chars().count()), title)
+            } else {
+                (form.values[index].clone(), title.to_string())
+            };
+            let input = Paragraph::new(value)
+                .style(style)
+                .block(Block::default().borders(Borders::ALL).title(title));
+            f.render_widget(input, chunks[index]);
+        }
+
+        let instructions = match &form.error {
+            Some(error) => Paragraph::new(error.as_str()).style(Style::default().fg(Color::Red)),
+            None => Paragraph::new(format!(
+                "{}: Tab: Next field | Enter: Apply | Esc: Cancel",
+                form.kind.display_name()
+            ))
+            .style(Style::default().fg(Color::Gray)),
+        };
+        f.render_widget(instructions, chunks[fields.len()]);
+    }
+
+    #[cfg(test)]
+    fn draw<B: Backend>(&mut self, terminal: &mut Terminal<B>) {
+        terminal.draw(|f| self.ui(f)).unwrap();
+    }
+}
+
+// Helper function to create centered popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::test_terminal::{key, screen, terminal};
+    use std::sync::Mutex;
+    use tempfile::tempdir;
+
+    #[derive(Default)]
+    struct MemoryBackend {
+        secrets: Mutex<HashMap<String, String>>,
+    }
+
+    impl SecretBackend for MemoryBackend {
+        fn get(&self, account: &str) -> io::Result<Option<String>> {
+            Ok(self.secrets.lock().unwrap().get(account).cloned())
+        }
+
+        fn set(&self, account: &str, secret: &str) -> io::Result<()> {
+            self.secrets
+                .lock()
+                .unwrap()
+                .insert(account.to_string(), secret.to_string());
+            Ok(())
+        }
+    }
+
+    fn press(
+        editor: &mut ConfigEditor,
+        data_path: &DataPath,
+        secrets: &MemoryBackend,
+        code: KeyCode,
+    ) -> Option<bool> {
+        editor
+            .handle_key_event(key(code), data_path, secrets)
+            .unwrap()
+    }
+
+    fn type_text(
+        editor: &mut ConfigEditor,
+        data_path: &DataPath,
+        secrets: &MemoryBackend,
+        text: &str,
+    ) {
+        for c in text.chars() {
+            press(editor, data_path, secrets, KeyCode::Char(c));
+        }
+    }
+
+    fn form_error(editor: &ConfigEditor) -> Option<&str> {
+        match &editor.mode {
+            EditorMode::Form(form) => form.error.as_deref(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_add_gitlab_instance_with_validation() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let secrets = MemoryBackend::default();
+        let mut editor = ConfigEditor::load(&data_path).unwrap();
+        assert!(editor.sections.is_empty());
+
+        press(&mut editor, &data_path, &secrets, KeyCode::Char('a'));
+        press(&mut editor, &data_path, &secrets, KeyCode::Char('l'));
+        type_text(&mut editor, &data_path, &secrets, "work");
+        press(&mut editor, &data_path, &secrets, KeyCode::Tab);
+        type_text(&mut editor, &data_path, &secrets, "gitlab.example.com");
+        press(&mut editor, &data_path, &secrets, KeyCode::Tab);
+        type_text(&mut editor, &data_path, &secrets, "Work GitLab");
+        press(&mut editor, &data_path, &secrets, KeyCode::Tab);
+        type_text(&mut editor, &data_path, &secrets, "glpat-secret");
+
+        press(&mut editor, &data_path, &secrets, KeyCode::Enter);
+        assert!(form_error(&editor).unwrap().contains("Invalid URL"));
+
+        // Back to the URL field to add the scheme
+        press(&mut editor, &data_path, &secrets, KeyCode::BackTab);
+        press(&mut editor, &data_path, &secrets, KeyCode::BackTab);
+        for _ in "gitlab.example.com".chars() {
+            press(&mut editor, &data_path, &secrets, KeyCode::Backspace);
+        }
+        type_text(
+            &mut editor,
+            &data_path,
+            &secrets,
+            "https://gitlab.example.com",
+        );
+        press(&mut editor, &data_path, &secrets, KeyCode::Enter);
+        assert!(matches!(editor.mode, EditorMode::List));
+        assert_eq!(editor.sections.len(), 1);
+        assert!(editor.modified);
+
+        assert_eq!(
+            press(&mut editor, &data_path, &secrets, KeyCode::Char('s')),
+            Some(true)
+        );
+        let config = UnifiedConfigService::load_config(&data_path).unwrap();
+        let gitlab = &config.platforms.gitlab["work"];
+        assert_eq!(gitlab.url, "https://gitlab.example.com");
+        assert_eq!(gitlab.name, "Work GitLab");
+        assert_eq!(gitlab.token, "glpat-secret");
+
+        // A second instance cannot take the same name
+        let mut editor = ConfigEditor::load(&data_path).unwrap();
+        press(&mut editor, &data_path, &secrets, KeyCode::Char('a'));
+        press(&mut editor, &data_path, &secrets, KeyCode::Char('l'));
+        type_text(&mut editor, &data_path, &secrets, "work");
+        press(&mut editor, &data_path, &secrets, KeyCode::Enter);
+        assert!(form_error(&editor).unwrap().contains("already exists"));
+    }
+
+    #[test]
+    fn test_secrets_are_masked() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let secrets = MemoryBackend::default();
+        let mut config = UnifiedConfig::default();
+        config.platforms.jira.insert(
+            DEFAULT_INSTANCE.to_string(),
+            JiraConfig {
+                jira_url: "https://jira.example.com".to_string(),
+                username: "bot".to_string(),
+                api_token: "hunter2".to_string(),
+                project_filter: vec!["CORE".to_string()],
+                custom_fields: HashMap::new(),
+                fields: Vec::new(),
+                lazy_details: false,
+                accept_invalid_certs: false,
+                limits: RequestLimits::default(),
+            },
+        );
+        let mut editor = ConfigEditor::new(config);
+        let mut terminal = terminal(100, 30);
+
+        editor.draw(&mut terminal);
+        let list = screen(&terminal);
+        assert!(
+            list.contains(
+                "JIRA    default      https://jira.example.com  bot  secret: config.toml"
+            )
+        );
+        assert!(!list.contains("hunter2"));
+
+        press(&mut editor, &data_path, &secrets, KeyCode::Enter);
+        editor.draw(&mut terminal);
+        let form = screen(&terminal);
+        assert!(form.contains("https://jira.example.com"));
+        assert!(form.contains("•••••••"));
+        assert!(!form.contains("hunter2"));
+
+        // Editing keeps the project filter
+        press(&mut editor, &data_path, &secrets, KeyCode::Enter);
+        assert_eq!(
+            editor.config.platforms.jira[DEFAULT_INSTANCE].project_filter,
+            vec!["CORE"]
+        );
+    }
+
+    #[test]
+    fn test_keyring_secrets_stored_on_save() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let secrets = MemoryBackend::default();
+        let mut config = UnifiedConfig::default();
+        config.global_settings.secret_backend = SecretBackendKind::Keyring;
+        config.platforms.gerrit.insert(
+            DEFAULT_INSTANCE.to_string(),
+            GerritConfig {
+                gerrit_url: "https://gerrit.example.com".to_string(),
+                username: "bot".to_string(),
+                http_password: String::new(),
+                accept_invalid_certs: false,
+                limits: RequestLimits::default(),
+            },
+        );
+        UnifiedConfigService::save_config(&config, &data_path).unwrap();
+        let mut editor = ConfigEditor::load(&data_path).unwrap();
+
+        // A blank password keeps the stored one
+        press(&mut editor, &data_path, &secrets, KeyCode::Char('e'));
+        press(&mut editor, &data_path, &secrets, KeyCode::Enter);
+        assert!(editor.pending_secrets.is_empty());
+
+        // A typed one goes to the keyring, never into config.toml
+        press(&mut editor, &data_path, &secrets, KeyCode::Char('e'));
+        press(&mut editor, &data_path, &secrets, KeyCode::BackTab);
+        type_text(&mut editor, &data_path, &secrets, "pw");
+        press(&mut editor, &data_path, &secrets, KeyCode::Enter);
+        assert!(secrets.get("gerrit").unwrap().is_none());
+        press(&mut editor, &data_path, &secrets, KeyCode::Char('s'));
+
+        assert_eq!(secrets.get("gerrit").unwrap().as_deref(), Some("pw"));
+        let saved = std::fs::read_to_string(data_path.config_path()).unwrap();
+        assert!(!saved.contains("\"pw\""));
+        let config = UnifiedConfigService::load_config(&data_path).unwrap();
+        assert!(
+            config.platforms.gerrit[DEFAULT_INSTANCE]
+                .http_password
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_delete_and_discard() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let secrets = MemoryBackend::default();
+        let mut config = UnifiedConfig::default();
+        for instance in ["a", "b"] {
+            config.platforms.gitlab.insert(
+                instance.to_string(),
+                GitLabConfig {
+                    name: instance.to_string(),
+                    url: "https://gitlab.example.com".to_string(),
+                    token: "t".to_string(),
+                    accept_invalid_certs: false,
+                    limits: RequestLimits::default(),
+                },
+            );
+        }
+        let mut editor = ConfigEditor::new(config);
+
+        press(&mut editor, &data_path, &secrets, KeyCode::Down);
+        press(&mut editor, &data_path, &secrets, KeyCode::Char('d'));
+        press(&mut editor, &data_path, &secrets, KeyCode::Char('y'));
+        assert_eq!(editor.sections.len(), 1);
+        assert_eq!(editor.sections[0].instance, "a");
+
+        // Quitting with changes asks first; anything but y goes back
+        assert_eq!(
+            press(&mut editor, &data_path, &secrets, KeyCode::Char('q')),
+            None
+        );
+        assert_eq!(
+            press(&mut editor, &data_path, &secrets, KeyCode::Char('n')),
+            None
+        );
+        assert_eq!(
+            press(&mut editor, &data_path, &secrets, KeyCode::Char('q')),
+            None
+        );
+        assert_eq!(
+            press(&mut editor, &data_path, &secrets, KeyCode::Char('y')),
+            Some(false)
+        );
+        assert!(!data_path.config_path().exists());
+    }
+}
@@ -0,0 +1,588 @@
+//! `reviewr dashboard`: one row per employee, one column per platform, with activity counts and
+//! how recent the latest activity is. Cached data is shown at once and refreshed in the background.
+
+use crate::core::cache::ActivityCache;
+use crate::core::models::Employee;
+use crate::core::platform::{DetailedActivities, PlatformRegistry, ReviewPlatform};
+use crate::core::timestamps::parse_timestamp;
+use crate::core::unified_config::PerformanceConfig;
+use chrono::{DateTime, Utc};
+use crossterm::{
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use ratatui::{
+    Frame, Terminal,
+    backend::{Backend, CrosstermBackend},
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::{Id as TaskId, JoinSet};
+
+/// Latest activity at most this many days ago is shown green
+pub const RECENT_DAYS: i64 = 7;
+/// Latest activity at most this many days ago is shown yellow, anything older red
+pub const QUIET_DAYS: i64 = 21;
+
+/// How long ago an employee was last active on a platform
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recency {
+    Recent,
+    Quiet,
+    Inactive,
+}
+
+impl Recency {
+    /// No activity at all counts as inactive
+    pub fn of(latest: Option<DateTime<Utc>>, now: DateTime<Utc>) -> Self {
+        match latest.map(|latest| (now - latest).num_days()) {
+            Some(days) if days <= RECENT_DAYS => Recency::Recent,
+            Some(days) if days <= QUIET_DAYS => Recency::Quiet,
+            _ => Recency::Inactive,
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Recency::Recent => Color::Green,
+            Recency::Quiet => Color::Yellow,
+            Recency::Inactive => Color::Red,
+        }
+    }
+}
+
+/// What is known about one employee on one platform
+#[derive(Debug, Clone, PartialEq)]
+pub enum DashboardCell {
+    /// Excluded by the data policy, or no username or email for the platform
+    NotTracked,
+    /// Nothing cached yet, waiting for the first fetch
+    Pending,
+    Loaded {
+        items: usize,
+        latest: Option<DateTime<Utc>>,
+    },
+    /// The fetch failed and nothing was cached before
+    Failed(String),
+}
+
+impl DashboardCell {
+    fn from_activities(activities: &DetailedActivities) -> Self {
+        let items = activities.items_by_category.values().flatten();
+        DashboardCell::Loaded {
+            items: items.clone().count(),
+            latest: items
+                .filter_map(|item| parse_timestamp(&item.updated))
+                .max(),
+        }
+    }
+}
+
+/// Selecting a cell with Enter asks for the employee's review browser on that platform
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DrillDown {
+    pub employee: String,
+    pub platform_id: String,
+}
+
+pub struct DashboardView {
+    title: String,
+    employees: Vec<String>,
+    platforms: Vec<(String, String)>, // platform_id, display name
+    /// `cells[row][column]`, rows in `employees` order and columns in `platforms` order
+    cells: Vec<Vec<DashboardCell>>,
+    /// Username or email per cell; None where the platform is not tracked
+    identities: Vec<Vec<Option<String>>>,
+    shared: Vec<Arc<dyn ReviewPlatform>>, // per column
+    cache: ActivityCache,
+    days: u32,
+    max_concurrent_platforms: usize,
+    queued: VecDeque<(usize, usize)>,
+    tasks: JoinSet<io::Result<DetailedActivities>>,
+    running: HashMap<TaskId, (usize, usize)>,
+    /// Cells with a refresh queued or running
+    refreshing: HashSet<(usize, usize)>,
+    selected_column: usize,
+    table_state: TableState,
+}
+
+impl DashboardView {
+    /// Fill every cell from the cache, however old, and queue a refresh of the cells whose
+    /// cached data is missing or older than the cache TTL
+    pub fn new(
+        title: String,
+        employees: &[Employee],
+        registry: &PlatformRegistry,
+        cache: ActivityCache,
+        days: u32,
+    ) -> Self {
+        let mut platforms: Vec<(String, String)> = registry
+            .get_configured_platforms()
+            .iter()
+            .map(|platform| {
+                (
+                    platform.get_platform_id().to_string(),
+                    platform.get_platform_name().to_string(),
+                )
+            })
+            .collect();
+        platforms.sort_by(|a, b| (&a.1, &a.0).cmp(&(&b.1, &b.0)));
+        let shared = platforms
+            .iter()
+            .filter_map(|(id, _)| registry.get_shared_platform(id))
+            .collect();
+
+        let mut view = Self {
+            title,
+            employees: employees.iter().map(|e| e.name.clone()).collect(),
+            cells: Vec::new(),
+            identities: employees
+                .iter()
+                .map(|employee| {
+                    platforms
+                        .iter()
+                        .map(|(id, _)| employee.identity_for(id).map(str::to_string))
+                        .collect()
+                })
+                .collect(),
+            platforms,
+            shared,
+            cache,
+            days,
+            max_concurrent_platforms: PerformanceConfig::default().max_concurrent_platforms,
+            queued: VecDeque::new(),
+            tasks: JoinSet::new(),
+            running: HashMap::new(),
+            refreshing: HashSet::new(),
+            selected_column: 0,
+            table_state: TableState::default().with_selected(Some(0)),
+        };
+        view.cells = (0..view.employees.len())
+            .map(|row| {
+                (0..view.platforms.len())
+                    .map(|column| view.initial_cell(row, column))
+                    .collect()
+            })
+            .collect();
+        view
+    }
+
+    fn initial_cell(&mut self, row: usize, column: usize) -> DashboardCell {
+        if self.identities[row][column].is_none() {
+            return DashboardCell::NotTracked;
+        }
+        let (employee, platform_id) = (&self.employees[row], &self.platforms[column].0);
+        if let Some((activities, _)) = self.cache.load(employee, platform_id, self.days) {
+            return DashboardCell::from_activities(&activities);
+        }
+        let stale = self.cache.load_any_age(employee, platform_id, self.days);
+        self.queue_refresh(row, column);
+        match stale {
+            Some((activities, _)) => DashboardCell::from_activities(&activities),
+            None => DashboardCell::Pending,
+        }
+    }
+
+    pub fn set_max_concurrent_platforms(&mut self, limit: usize) {
+        self.max_concurrent_platforms = limit.max(1);
+    }
+
+    pub fn cell(&self, employee: &str, platform_id: &str) -> Option<&DashboardCell> {
+        let row = self.employees.iter().position(|name| name == employee)?;
+        let column = self
+            .platforms
+            .iter()
+            .position(|(id, _)| id == platform_id)?;
+        Some(&self.cells[row][column])
+    }
+
+    pub fn is_refreshing(&self, employee: &str, platform_id: &str) -> bool {
+        let row = self.employees.iter().position(|name| name == employee);
+        let column = self.platforms.iter().position(|(id, _)| id == platform_id);
+        row.zip(column)
+            .is_some_and(|cell| self.refreshing.contains(&cell))
+    }
+
+    fn queue_refresh(&mut self, row: usize, column: usize) {
+        if self.identities[row][column].is_some() && self.refreshing.insert((row, column)) {
+            self.queued.push_back((row, column));
+        }
+    }
+
+    /// Fetch every tracked cell again, e.g. after `r`
+    pub fn refresh_all(&mut self) {
+        for row in 0..self.employees.len() {
+            for column in 0..self.platforms.len() {
+                self.queue_refresh(row, column);
+            }
+        }
+    }
+
+    /// Start queued fetches until max_concurrent_platforms are running
+    fn spawn_queued(&mut self) {
+        while self.tasks.len() < self.max_concurrent_platforms {
+            let Some((row, column)) = self.queued.pop_front() else {
+                break;
+            };
+            let Some(user) = self.identities[row][column].clone() else {
+                continue;
+            };
+            let platform = self.shared[column].clone();
+            let days = self.days;
+            let handle = self
+                .tasks
+                .spawn(async move { platform.get_detailed_activities(&user, days).await });
+            self.running.insert(handle.id(), (row, column));
+        }
+    }
+
+    /// Wait for the next background fetch and show its result; false once none is left
+    pub async fn next_fetch(&mut self) -> bool {
+        self.spawn_queued();
+        let Some(joined) = self.tasks.join_next_with_id().await else {
+            return false;
+        };
+        let (id, result) = match joined {
+            Ok((id, result)) => (id, result),
+            Err(e) => (
+                e.id(),
+                Err(io::Error::other(format!("fetch task failed: {e}"))),
+            ),
+        };
+        if let Some((row, column)) = self.running.remove(&id) {
+            self.finish_fetch(row, column, result);
+        }
+        true
+    }
+
+    fn finish_fetch(&mut self, row: usize, column: usize, result: io::Result<DetailedActivities>) {
+        self.refreshing.remove(&(row, column));
+        let (employee, platform_id) = (&self.employees[row], &self.platforms[column].0);
+        match result {
+            Ok(activities) => {
+                if let Err(e) = self
+                    .cache
+                    .store(employee, platform_id, self.days, &activities)
+                {
+                    log::warn!("Failed to cache {platform_id} activity for {employee}: {e}");
+                }
+                self.cells[row][column] = DashboardCell::from_activities(&activities);
+            }
+            Err(e) => {
+                log::warn!("Failed to refresh {platform_id} activity for {employee}: {e}");
+                // Stale data stays on screen; only an empty cell shows the failure
+                if !matches!(self.cells[row][column], DashboardCell::Loaded { .. }) {
+                    self.cells[row][column] = DashboardCell::Failed(e.to_string());
+                }
+            }
+        }
+    }
+
+    /// Show what the review browser cached for an employee while it was open
+    pub fn reload_employee(&mut self, employee: &str) {
+        let Some(row) = self.employees.iter().position(|name| name == employee) else {
+            return;
+        };
+        for column in 0..self.platforms.len() {
+            if self.identities[row][column].is_none() || self.refreshing.contains(&(row, column)) {
+                continue;
+            }
+            if let Some((activities, _)) =
+                self.cache
+                    .load_any_age(employee, &self.platforms[column].0, self.days)
+            {
+                self.cells[row][column] = DashboardCell::from_activities(&activities);
+            }
+        }
+    }
+
+    /// The employee and platform of the selected cell
+    pub fn selection(&self) -> Option<DrillDown> {
+        let employee = self.employees.get(self.table_state.selected()?)?;
+        let (platform_id, _) = self.platforms.get(self.selected_column)?;
+        Some(DrillDown {
+            employee: employee.clone(),
+            platform_id: platform_id.clone(),
+        })
+    }
+
+    /// Run until the user quits (None) or opens a cell. Background fetches keep running in
+    /// between, so the view can be run again after the review browser closes.
+    pub async fn run(&mut self) -> io::Result<Option<DrillDown>> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let result = self.run_app(&mut terminal).await;
+
+        disable_raw_mode()?;
+        execute!(
+            terminal.backend_mut(),
+            LeaveAlternateScreen,
+            DisableMouseCapture
+        )?;
+        terminal.show_cursor()?;
+
+        result
+    }
+
+    async fn run_app<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+    ) -> io::Result<Option<DrillDown>> {
+        let mut ticker = tokio::time::interval(Duration::from_millis(100));
+        loop {
+            terminal.draw(|f| self.ui(f))?;
+            tokio::select! {
+                true = self.next_fetch() => {}
+                _ = ticker.tick() => {
+                    while event::poll(Duration::ZERO)? {
+                        if let Event::Key(key) = event::read()?
+                            && let Some(action) = self.handle_key_event(key)
+                        {
+                            return Ok(action);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Some(None) closes the dashboard, Some(drill-down) opens the selected cell
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Option<DrillDown>> {
+        let rows = self.employees.len();
+        let columns = self.platforms.len();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Some(None),
+            KeyCode::Enter => {
+                if let Some(selection) = self.selection() {
+                    return Some(Some(selection));
+                }
+            }
+            KeyCode::Down | KeyCode::Char('j') if rows > 0 => {
+                let selected = self.table_state.selected().unwrap_or(0);
+                self.table_state.select(Some((selected + 1).min(rows - 1)));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                let selected = self.table_state.selected().unwrap_or(0);
+                self.table_state.select(Some(selected.saturating_sub(1)));
+            }
+            KeyCode::Right | KeyCode::Char('l') if columns > 0 => {
+                self.selected_column = (self.selected_column + 1).min(columns - 1);
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.selected_column = self.selected_column.saturating_sub(1);
+            }
+            KeyCode::Char('r') => self.refresh_all(),
+            _ => {}
+        }
+        None
+    }
+
+    /// Cell text: the item count behind a recency dot, marked while a refresh is running
+    fn cell_text(&self, row: usize, column: usize) -> String {
+        let refreshing = if self.refreshing.contains(&(row, column)) {
+            " ↻"
+        } else {
+            ""
+        };
+        match &self.cells[row][column] {
+            DashboardCell::NotTracked => "–".to_string(),
+            DashboardCell::Pending => "…".to_string(),
+            DashboardCell::Loaded { items, .. } => format!("● {items}{refreshing}"),
+            DashboardCell::Failed(_) => format!("✗ failed{refreshing}"),
+        }
+    }
+
+    fn cell_style(&self, row: usize, column: usize, now: DateTime<Utc>) -> Style {
+        let style = match &self.cells[row][column] {
+            DashboardCell::Loaded { latest, .. } => {
+                Style::default().fg(Recency::of(*latest, now).color())
+            }
+            DashboardCell::Failed(_) => Style::default().fg(Color::Red),
+            DashboardCell::NotTracked | DashboardCell::Pending => {
+                Style::default().fg(Color::DarkGray)
+            }
+        };
+        if self.table_state.selected() == Some(row) && self.selected_column == column {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        }
+    }
+
+    fn ui(&mut self, f: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(3)])
+            .split(f.area());
+
+        let mut titles = vec!["Employee".to_string()];
+        titles.extend(self.platforms.iter().map(|(_, name)| name.clone()));
+        let header = Row::new(titles.into_iter().map(Cell::from))
+            .style(Style::default().add_modifier(Modifier::BOLD));
+
+        let now = Utc::now();
+        let rows: Vec<Row> = self
+            .employees
+            .iter()
+            .enumerate()
+            .map(|(row, employee)| {
+                let mut cells = vec![Cell::from(employee.clone())];
+                cells.extend((0..self.platforms.len()).map(|column| {
+                    Cell::from(self.cell_text(row, column)).style(self.cell_style(row, column, now))
+                }));
+                Row::new(cells)
+            })
+            .collect();
+
+        let mut widths = vec![Constraint::Min(20)];
+        widths.extend(
+            self.platforms
+                .iter()
+                .map(|(_, name)| Constraint::Length(name.chars().count().max(12) as u16)),
+        );
+
+        let mut title = self.title.clone();
+        if !self.refreshing.is_empty() {
+            title.push_str(&format!(" · refreshing {}", self.refreshing.len()));
+        }
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .highlight_symbol("▶ ");
+        f.render_stateful_widget(table, chunks[0], &mut self.table_state);
+
+        let footer = format!(
+            "Arrows: Move | Enter: Open | r: Refresh | q/Esc: Quit | Last activity: green ≤{RECENT_DAYS}d, yellow ≤{QUIET_DAYS}d, red older"
+        );
+        let footer = Paragraph::new(footer).block(Block::default().borders(Borders::ALL));
+        f.render_widget(footer, chunks[1]);
+    }
+
+    pub fn draw<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
+        terminal.draw(|f| self.ui(f))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::DataPath;
+    use crate::tui::multi_platform_browser_tests::MockPlatform;
+    use crate::tui::test_terminal::{key, screen, terminal};
+    use chrono::TimeZone;
+    use tempfile::TempDir;
+
+    fn employee(name: &str, email: Option<&str>) -> Employee {
+        Employee {
+            name: name.to_string(),
+            title: "Engineer".to_string(),
+            committer_email: email.map(str::to_string),
+            profile: Default::default(),
+            usernames: Default::default(),
+            exclude_platforms: Vec::new(),
+            notes_only: false,
+            notes_recipient: None,
+            out_of_office: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_recency() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let days_ago = |days| Some(now - chrono::Duration::days(days));
+        assert_eq!(Recency::of(days_ago(2), now), Recency::Recent);
+        assert_eq!(Recency::of(days_ago(RECENT_DAYS), now), Recency::Recent);
+        assert_eq!(Recency::of(days_ago(10), now), Recency::Quiet);
+        assert_eq!(
+            Recency::of(days_ago(QUIET_DAYS + 1), now),
+            Recency::Inactive
+        );
+        assert_eq!(Recency::of(None, now), Recency::Inactive);
+    }
+
+    #[tokio::test]
+    async fn test_dashboard_cached_then_refreshed() {
+        let dir = TempDir::new().unwrap();
+        let data_path = DataPath::new(Some(dir.path().to_path_buf())).unwrap();
+        let mut registry = PlatformRegistry::new();
+        registry.register_platform(Box::new(MockPlatform::new_gerrit()));
+        registry.register_platform(Box::new(MockPlatform::new_failing_jira("timeout")));
+        let cache = ActivityCache::new(&data_path, Duration::from_secs(3600));
+        // Alice's Gerrit activity is fresh in the cache and not fetched again
+        cache
+            .store(
+                "Alice",
+                "gerrit",
+                30,
+                &MockPlatform::create_gerrit_test_data(),
+            )
+            .unwrap();
+        let employees = [
+            employee("Alice", Some("alice@example.com")),
+            employee("Bob", Some("bob@example.com")),
+            employee("Carol", None),
+        ];
+
+        let mut view = DashboardView::new("Team".to_string(), &employees, &registry, cache, 30);
+        assert!(matches!(
+            view.cell("Alice", "gerrit"),
+            Some(DashboardCell::Loaded { .. })
+        ));
+        assert!(!view.is_refreshing("Alice", "gerrit"));
+        assert_eq!(view.cell("Bob", "gerrit"), Some(&DashboardCell::Pending));
+        assert!(view.is_refreshing("Bob", "gerrit"));
+        assert_eq!(view.cell("Carol", "jira"), Some(&DashboardCell::NotTracked));
+        assert!(!view.is_refreshing("Carol", "jira"));
+
+        while view.next_fetch().await {}
+        let Some(DashboardCell::Loaded { items, latest }) = view.cell("Bob", "gerrit") else {
+            panic!("Bob's Gerrit activity was not loaded");
+        };
+        let gerrit_items: usize = MockPlatform::create_gerrit_test_data()
+            .items_by_category
+            .values()
+            .map(Vec::len)
+            .sum();
+        assert_eq!(*items, gerrit_items);
+        assert!(latest.is_some());
+        assert_eq!(
+            view.cell("Bob", "jira"),
+            Some(&DashboardCell::Failed("timeout".to_string()))
+        );
+        assert!(!view.is_refreshing("Bob", "gerrit"));
+
+        let mut terminal = terminal(100, 12);
+        view.draw(&mut terminal).unwrap();
+        let screen = screen(&terminal);
+        assert!(screen.contains("Employee"), "{screen}");
+        assert!(screen.contains(&format!("● {gerrit_items}")), "{screen}");
+        assert!(screen.contains("✗ failed"), "{screen}");
+
+        // Enter drills into the selected employee and platform; columns are sorted by name
+        view.handle_key_event(key(KeyCode::Down));
+        view.handle_key_event(key(KeyCode::Right));
+        assert_eq!(
+            view.handle_key_event(key(KeyCode::Enter)),
+            Some(Some(DrillDown {
+                employee: "Bob".to_string(),
+                platform_id: "jira".to_string(),
+            }))
+        );
+        assert_eq!(view.handle_key_event(key(KeyCode::Char('q'))), Some(None));
+
+        view.refresh_all();
+        assert!(view.is_refreshing("Alice", "gerrit"));
+        assert!(!view.is_refreshing("Carol", "gerrit"));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::core::{employee::EmployeeService, models::DataPath};
+use crate::core::{
+    employee::{EmployeeService, RenameConflict},
+    models::{DataPath, EmployeeProfile},
+};
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent},
     execute,
@@ -9,7 +12,7 @@ use ratatui::{
     backend::{Backend, CrosstermBackend},
     layout::{Constraint, Direction, Layout},
     style::{Color, Style},
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 use std::io;
 
@@ -18,6 +21,75 @@ pub struct EmployeeData {
     pub name: String,
     pub title: String,
     pub committer_email: String,
+    pub team: String,
+    pub manager: String,
+    pub start_date: String,
+    pub location: String,
+    /// Comma-separated
+    pub tags: String,
+}
+
+/// Form field titles, in Tab order
+const FIELDS: [&str; 8] = [
+    "Employee Name",
+    "Job Title",
+    "Committer Email (optional)",
+    "Team (optional)",
+    "Manager (optional)",
+    "Start Date YYYY-MM-DD (optional)",
+    "Location (optional)",
+    "Tags, comma-separated (optional)",
+];
+
+fn optional(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+impl EmployeeData {
+    fn field_mut(&mut self, index: usize) -> Option<&mut String> {
+        match index {
+            0 => Some(&mut self.name),
+            1 => Some(&mut self.title),
+            2 => Some(&mut self.committer_email),
+            3 => Some(&mut self.team),
+            4 => Some(&mut self.manager),
+            5 => Some(&mut self.start_date),
+            6 => Some(&mut self.location),
+            7 => Some(&mut self.tags),
+            _ => None,
+        }
+    }
+
+    fn field(&self, index: usize) -> &str {
+        match index {
+            0 => &self.name,
+            1 => &self.title,
+            2 => &self.committer_email,
+            3 => &self.team,
+            4 => &self.manager,
+            5 => &self.start_date,
+            6 => &self.location,
+            7 => &self.tags,
+            _ => "",
+        }
+    }
+
+    pub fn profile(&self) -> EmployeeProfile {
+        EmployeeProfile {
+            team: optional(&self.team),
+            manager: optional(&self.manager),
+            start_date: optional(&self.start_date),
+            location: optional(&self.location),
+            tags: self
+                .tags
+                .split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(|tag| tag.trim_start_matches('#').to_string())
+                .collect(),
+        }
+    }
 }
 
 pub struct EmployeeForm {
@@ -25,12 +97,16 @@ pub struct EmployeeForm {
     original_name: Option<String>,
     current_field: usize,
     mode: FormMode,
+    /// Validation problem shown below the fields
+    error: Option<String>,
 }
 
 #[derive(PartialEq)]
 enum FormMode {
     Edit,
     Confirm,
+    /// Notes or goals already exist under the new name
+    RenameConflict(Vec<String>),
 }
 
 impl Default for EmployeeForm {
@@ -46,23 +122,40 @@ impl EmployeeForm {
                 name: String::new(),
                 title: String::new(),
                 committer_email: String::new(),
+                team: String::new(),
+                manager: String::new(),
+                start_date: String::new(),
+                location: String::new(),
+                tags: String::new(),
             },
             original_name: None,
             current_field: 0,
             mode: FormMode::Edit,
+            error: None,
         }
     }
 
-    pub fn new_with_data(name: String, title: String, committer_email: Option<String>) -> Self {
+    pub fn new_with_data(
+        name: String,
+        title: String,
+        committer_email: Option<String>,
+        profile: EmployeeProfile,
+    ) -> Self {
         Self {
             employee: EmployeeData {
                 name: name.clone(),
                 title,
                 committer_email: committer_email.unwrap_or_default(),
+                team: profile.team.unwrap_or_default(),
+                manager: profile.manager.unwrap_or_default(),
+                start_date: profile.start_date.unwrap_or_default(),
+                location: profile.location.unwrap_or_default(),
+                tags: profile.tags.join(", "),
             },
             original_name: Some(name),
             current_field: 0,
             mode: FormMode::Edit,
+            error: None,
         }
     }
 
@@ -111,169 +204,174 @@ impl EmployeeForm {
         match self.mode {
             FormMode::Edit => match key.code {
                 KeyCode::Char(c) => {
-                    match self.current_field {
-                        0 => self.employee.name.push(c),
-                        1 => self.employee.title.push(c),
-                        2 => self.employee.committer_email.push(c),
-                        _ => {}
+                    if let Some(field) = self.employee.field_mut(self.current_field) {
+                        field.push(c);
                     }
                     Ok(None)
                 }
                 KeyCode::Backspace => {
-                    match self.current_field {
-                        0 => {
-                            self.employee.name.pop();
-                        }
-                        1 => {
-                            self.employee.title.pop();
-                        }
-                        2 => {
-                            self.employee.committer_email.pop();
-                        }
-                        _ => {}
+                    if let Some(field) = self.employee.field_mut(self.current_field) {
+                        field.pop();
                     }
                     Ok(None)
                 }
                 KeyCode::Tab | KeyCode::Down => {
-                    self.current_field = (self.current_field + 1) % 3;
+                    self.current_field = (self.current_field + 1) % FIELDS.len();
                     Ok(None)
                 }
                 KeyCode::BackTab | KeyCode::Up => {
                     self.current_field = if self.current_field == 0 {
-                        2
+                        FIELDS.len() - 1
                     } else {
                         self.current_field - 1
                     };
                     Ok(None)
                 }
                 KeyCode::Enter => {
-                    if !self.employee.name.trim().is_empty()
-                        && !self.employee.title.trim().is_empty()
+                    if self.employee.name.trim().is_empty() || self.employee.title.trim().is_empty()
                     {
-                        self.mode = FormMode::Confirm;
+                        return Ok(None);
+                    }
+                    match self.employee.profile().validate() {
+                        Ok(()) => {
+                            self.error = None;
+                            self.mode = FormMode::Confirm;
+                        }
+                        Err(e) => self.error = Some(e.to_string()),
                     }
                     Ok(None)
                 }
                 KeyCode::Esc => Ok(Some(None)),
                 _ => Ok(None),
             },
-            FormMode::Confirm => {
-                match key.code {
-                    KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
-                        // Save the employee
-                        match &self.original_name {
-                            Some(original_name) => {
-                                // Update existing employee
-                                let email = if self.employee.committer_email.trim().is_empty() {
-                                    None
-                                } else {
-                                    Some(self.employee.committer_email.trim().to_string())
-                                };
-                                EmployeeService::update_employee(
-                                    data_path,
-                                    original_name,
-                                    self.employee.name.trim(),
-                                    self.employee.title.trim(),
-                                    email,
-                                )?;
-                            }
-                            None => {
-                                // Create new employee
-                                let email = if self.employee.committer_email.trim().is_empty() {
-                                    None
-                                } else {
-                                    Some(self.employee.committer_email.trim().to_string())
-                                };
-                                EmployeeService::add_employee_with_data(
-                                    data_path,
-                                    self.employee.name.trim(),
-                                    self.employee.title.trim(),
-                                    email,
-                                )?;
-                            }
+            FormMode::Confirm => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                    if let Some(original_name) = &self.original_name {
+                        let conflicts = EmployeeService::rename_conflicts(
+                            data_path,
+                            original_name,
+                            self.employee.name.trim(),
+                        );
+                        if original_name != self.employee.name.trim() && !conflicts.is_empty() {
+                            let files = conflicts
+                                .iter()
+                                .map(|path| path.display().to_string())
+                                .collect();
+                            self.mode = FormMode::RenameConflict(files);
+                            return Ok(None);
                         }
-                        Ok(Some(Some(self.employee.clone())))
-                    }
-                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
-                        self.mode = FormMode::Edit;
-                        Ok(None)
                     }
-                    _ => Ok(None),
+                    self.save(data_path, RenameConflict::Abort)
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.mode = FormMode::Edit;
+                    Ok(None)
+                }
+                _ => Ok(None),
+            },
+            FormMode::RenameConflict(_) => match key.code {
+                KeyCode::Char('m') | KeyCode::Char('M') => {
+                    self.save(data_path, RenameConflict::Merge)
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.mode = FormMode::Edit;
+                    Ok(None)
                 }
+                _ => Ok(None),
+            },
+        }
+    }
+
+    fn save(
+        &mut self,
+        data_path: &DataPath,
+        on_conflict: RenameConflict,
+    ) -> io::Result<Option<Option<EmployeeData>>> {
+        let email = if self.employee.committer_email.trim().is_empty() {
+            None
+        } else {
+            Some(self.employee.committer_email.trim().to_string())
+        };
+        let result = match &self.original_name {
+            // Update existing employee, moving notes and goals on rename
+            Some(original_name) => EmployeeService::update_employee(
+                data_path,
+                original_name,
+                self.employee.name.trim(),
+                self.employee.title.trim(),
+                email,
+                self.employee.profile(),
+                on_conflict,
+            ),
+            None => EmployeeService::add_employee_with_data(
+                data_path,
+                self.employee.name.trim(),
+                self.employee.title.trim(),
+                email,
+                self.employee.profile(),
+            ),
+        };
+        match result {
+            Ok(()) => Ok(Some(Some(self.employee.clone()))),
+            // e.g. renaming onto another employee; let the user pick a different name
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                self.error = Some(e.to_string());
+                self.mode = FormMode::Edit;
+                Ok(None)
             }
+            Err(e) => Err(e),
         }
     }
 
     fn ui(&self, f: &mut Frame) {
         let area = f.area();
 
-        match self.mode {
+        match &self.mode {
             FormMode::Edit => {
+                let mut constraints = vec![Constraint::Length(3); FIELDS.len()];
+                constraints.push(Constraint::Length(2));
+                constraints.push(Constraint::Min(0));
                 let chunks = Layout::default()
                     .direction(Direction::Vertical)
                     .margin(2)
-                    .constraints([
-                        Constraint::Length(3),
-                        Constraint::Length(3),
-                        Constraint::Length(3),
-                        Constraint::Length(2),
-                        Constraint::Min(0),
-                    ])
+                    .constraints(constraints)
                     .split(area);
 
-                // Name field
-                let name_style = if self.current_field == 0 {
-                    Style::default().fg(Color::Yellow)
-                } else {
-                    Style::default()
-                };
-                let name_input = Paragraph::new(self.employee.name.as_str())
-                    .style(name_style)
-                    .block(
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .title("Employee Name"),
-                    );
-                f.render_widget(name_input, chunks[0]);
+                for (index, title) in FIELDS.iter().enumerate() {
+                    let style = if self.current_field == index {
+                        Style::default().fg(Color::Yellow)
+                    } else {
+                        Style::default()
+                    };
+                    let input = Paragraph::new(self.employee.field(index))
+                        .style(style)
+                        .block(Block::default().borders(Borders::ALL).title(*title));
+                    f.render_widget(input, chunks[index]);
+                }
 
-                // Title field
-                let title_style = if self.current_field == 1 {
-                    Style::default().fg(Color::Yellow)
-                } else {
-                    Style::default()
-                };
-                let title_input = Paragraph::new(self.employee.title.as_str())
-                    .style(title_style)
-                    .block(Block::default().borders(Borders::ALL).title("Job Title"));
-                f.render_widget(title_input, chunks[1]);
-
-                // Committer Email field
-                let email_style = if self.current_field == 2 {
-                    Style::default().fg(Color::Yellow)
-                } else {
-                    Style::default()
+                // Instructions, or the reason the form cannot be saved yet
+                let instructions = match &self.error {
+                    Some(error) => {
+                        Paragraph::new(error.as_str()).style(Style::default().fg(Color::Red))
+                    }
+                    None => Paragraph::new("Tab: Next field | Enter: Save | Esc: Cancel")
+                        .style(Style::default().fg(Color::Gray)),
                 };
-                let email_input = Paragraph::new(self.employee.committer_email.as_str())
-                    .style(email_style)
-                    .block(
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .title("Committer Email (optional)"),
-                    );
-                f.render_widget(email_input, chunks[2]);
-
-                // Instructions
-                let instructions = Paragraph::new("Tab: Next field | Enter: Save | Esc: Cancel")
-                    .style(Style::default().fg(Color::Gray));
-                f.render_widget(instructions, chunks[3]);
+                f.render_widget(instructions, chunks[FIELDS.len()]);
             }
             FormMode::Confirm => {
                 // Confirmation dialog
                 let popup_area = centered_rect(50, 30, area);
                 f.render_widget(Clear, popup_area);
 
+                let profile = self
+                    .employee
+                    .profile()
+                    .summary()
+                    .map(|summary| format!("\n{summary}"))
+                    .unwrap_or_default();
                 let confirmation_text = format!(
-                    "Save employee?\n\nName: {}\nTitle: {}\n\nPress Y to confirm, N to cancel",
+                    "Save employee?\n\nName: {}\nTitle: {}{profile}\n\nPress Y to confirm, N to cancel",
                     self.employee.name.trim(),
                     self.employee.title.trim()
                 );
@@ -288,6 +386,27 @@ impl EmployeeForm {
                     );
                 f.render_widget(confirmation, popup_area);
             }
+            FormMode::RenameConflict(files) => {
+                let popup_area = centered_rect(60, 40, area);
+                f.render_widget(Clear, popup_area);
+
+                let text = format!(
+                    "'{}' already has notes or goals:\n\n{}\n\nPress M to append the history of '{}' to them, N to go back",
+                    self.employee.name.trim(),
+                    files.join("\n"),
+                    self.original_name.as_deref().unwrap_or_default()
+                );
+                let conflict = Paragraph::new(text)
+                    .style(Style::default().fg(Color::White))
+                    .wrap(Wrap { trim: false })
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .title("Rename conflict")
+                            .style(Style::default().fg(Color::Red)),
+                    );
+                f.render_widget(conflict, popup_area);
+            }
         }
     }
 }
@@ -332,8 +451,12 @@ mod tests {
 
     #[test]
     fn test_employee_form_with_existing_data() {
-        let form =
-            EmployeeForm::new_with_data("John Doe".to_string(), "Engineer".to_string(), None);
+        let form = EmployeeForm::new_with_data(
+            "John Doe".to_string(),
+            "Engineer".to_string(),
+            None,
+            EmployeeProfile::default(),
+        );
         assert_eq!(form.employee.name, "John Doe");
         assert_eq!(form.employee.title, "Engineer");
         assert_eq!(form.original_name, Some("John Doe".to_string()));
@@ -345,9 +468,47 @@ mod tests {
             name: "Jane Smith".to_string(),
             title: "Manager".to_string(),
             committer_email: "jane.smith@example.com".to_string(),
+            team: String::new(),
+            manager: String::new(),
+            start_date: String::new(),
+            location: String::new(),
+            tags: String::new(),
         };
         let cloned = data.clone();
         assert_eq!(data.name, cloned.name);
         assert_eq!(data.title, cloned.title);
     }
+
+    #[test]
+    fn test_profile_fields_typed_and_validated() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let mut form = EmployeeForm::new();
+        let press = |form: &mut EmployeeForm, code: KeyCode| {
+            form.handle_key_event(KeyEvent::from(code), &data_path)
+                .unwrap();
+        };
+
+        // BackTab wraps around to the last field: tags
+        press(&mut form, KeyCode::BackTab);
+        for c in "#backend, , oncall".chars() {
+            press(&mut form, KeyCode::Char(c));
+        }
+        form.employee.name = "Sam Lee".to_string();
+        form.employee.title = "Engineer".to_string();
+        form.employee.start_date = "March".to_string();
+
+        let profile = form.employee.profile();
+        assert_eq!(profile.tags, vec!["backend", "oncall"]);
+        assert_eq!(profile.team, None);
+
+        press(&mut form, KeyCode::Enter);
+        assert!(form.mode == FormMode::Edit);
+        assert!(form.error.as_deref().unwrap().contains("YYYY-MM-DD"));
+
+        form.employee.start_date = "2022-03-01".to_string();
+        press(&mut form, KeyCode::Enter);
+        assert!(form.mode == FormMode::Confirm);
+        assert!(form.error.is_none());
+    }
 }
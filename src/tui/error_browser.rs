@@ -0,0 +1,497 @@
+//! Interactive viewer for the error log: filter entries by platform, operation and severity,
+//! read the full response body and open the request URL
+
+use crate::core::platform::{ErrorContext, Severity};
+use crate::tui::url_opener::UrlOpener;
+use crossterm::{
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use ratatui::{
+    Frame, Terminal,
+    backend::{Backend, CrosstermBackend},
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+use std::io;
+
+fn severity_icon(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "🔴",
+        Severity::Warning => "🟡",
+    }
+}
+
+/// The option after `current` in `options`; past the last one the filter is off again
+fn cycle_filter(options: &[String], current: &Option<String>) -> Option<String> {
+    let next = match current {
+        None => 0,
+        Some(current) => match options.iter().position(|option| option == current) {
+            Some(index) => index + 1,
+            None => 0,
+        },
+    };
+    options.get(next).cloned()
+}
+
+/// JSON bodies are indented for reading; anything else is shown as logged
+fn format_body(body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| body.to_string())
+}
+
+pub struct ErrorBrowser {
+    /// Most recent first
+    errors: Vec<ErrorContext>,
+    platform_filter: Option<String>,
+    operation_filter: Option<String>,
+    severity_filter: Option<Severity>,
+    list_state: ListState,
+    detail_scroll: u16,
+    show_help: bool,
+    message: Option<String>,
+    url_opener: UrlOpener,
+}
+
+impl ErrorBrowser {
+    pub fn new(errors: Vec<ErrorContext>) -> Self {
+        let mut list_state = ListState::default();
+        if !errors.is_empty() {
+            list_state.select(Some(0));
+        }
+        Self {
+            errors,
+            platform_filter: None,
+            operation_filter: None,
+            severity_filter: None,
+            list_state,
+            detail_scroll: 0,
+            show_help: false,
+            message: None,
+            url_opener: UrlOpener::default(),
+        }
+    }
+
+    /// Open request URLs with `command` instead of the system browser
+    pub fn set_browser_command(&mut self, command: Option<String>) {
+        self.url_opener = UrlOpener::new(command);
+    }
+
+    /// Start with only the entries of `platform`
+    pub fn set_platform_filter(&mut self, platform: Option<String>) {
+        self.platform_filter = platform;
+        self.reset_selection();
+    }
+
+    pub fn run(&mut self) -> io::Result<()> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let result = self.run_app(&mut terminal);
+
+        disable_raw_mode()?;
+        execute!(
+            terminal.backend_mut(),
+            LeaveAlternateScreen,
+            DisableMouseCapture
+        )?;
+        terminal.show_cursor()?;
+
+        result
+    }
+
+    fn run_app<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
+        loop {
+            terminal.draw(|f| self.ui(f))?;
+            if let Event::Key(key) = event::read()?
+                && !self.handle_key_event(key)
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Entries passing the current filters, most recent first
+    fn visible(&self) -> Vec<&ErrorContext> {
+        self.errors
+            .iter()
+            .filter(|error| {
+                self.platform_filter
+                    .as_ref()
+                    .is_none_or(|platform| &error.platform_id == platform)
+                    && self
+                        .operation_filter
+                        .as_ref()
+                        .is_none_or(|operation| &error.operation == operation)
+                    && self
+                        .severity_filter
+                        .is_none_or(|severity| error.severity == severity)
+            })
+            .collect()
+    }
+
+    fn platforms(&self) -> Vec<String> {
+        let mut platforms: Vec<String> = self
+            .errors
+            .iter()
+            .map(|error| error.platform_id.clone())
+            .collect();
+        platforms.sort();
+        platforms.dedup();
+        platforms
+    }
+
+    /// Operations logged for the filtered platform, or for all of them
+    fn operations(&self) -> Vec<String> {
+        let mut operations: Vec<String> = self
+            .errors
+            .iter()
+            .filter(|error| {
+                self.platform_filter
+                    .as_ref()
+                    .is_none_or(|platform| &error.platform_id == platform)
+            })
+            .map(|error| error.operation.clone())
+            .collect();
+        operations.sort();
+        operations.dedup();
+        operations
+    }
+
+    fn selected(&self) -> Option<&ErrorContext> {
+        self.visible().get(self.list_state.selected()?).copied()
+    }
+
+    fn reset_selection(&mut self) {
+        let any = !self.visible().is_empty();
+        self.list_state.select(any.then_some(0));
+        self.detail_scroll = 0;
+    }
+
+    fn open_request_url(&mut self) {
+        let Some(url) = self.selected().and_then(|error| error.request_url.clone()) else {
+            self.message = Some("This entry has no request URL".to_string());
+            return;
+        };
+        self.message = Some(match self.url_opener.open(&url) {
+            Ok(()) => format!("Opened {url}"),
+            Err(e) => format!("Could not open {url}: {e}"),
+        });
+    }
+
+    /// Returns false when the viewer should close
+    fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        self.message = None;
+        if self.show_help {
+            self.show_help = false;
+            return true;
+        }
+        let rows = self.visible().len();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Down | KeyCode::Char('j') if rows > 0 => {
+                let selected = self.list_state.selected().unwrap_or(0);
+                self.list_state.select(Some((selected + 1).min(rows - 1)));
+                self.detail_scroll = 0;
+            }
+            KeyCode::Up | KeyCode::Char('k') if rows > 0 => {
+                let selected = self.list_state.selected().unwrap_or(0);
+                self.list_state.select(Some(selected.saturating_sub(1)));
+                self.detail_scroll = 0;
+            }
+            KeyCode::PageDown | KeyCode::Char('J') => {
+                self.detail_scroll = self.detail_scroll.saturating_add(5);
+            }
+            KeyCode::PageUp | KeyCode::Char('K') => {
+                self.detail_scroll = self.detail_scroll.saturating_sub(5);
+            }
+            KeyCode::Char('p') => {
+                self.platform_filter = cycle_filter(&self.platforms(), &self.platform_filter);
+                self.operation_filter = None;
+                self.reset_selection();
+            }
+            KeyCode::Char('o') => {
+                self.operation_filter = cycle_filter(&self.operations(), &self.operation_filter);
+                self.reset_selection();
+            }
+            KeyCode::Char('s') => {
+                self.severity_filter = match self.severity_filter {
+                    None => Some(Severity::Error),
+                    Some(Severity::Error) => Some(Severity::Warning),
+                    Some(Severity::Warning) => None,
+                };
+                self.reset_selection();
+            }
+            KeyCode::Char('x') => {
+                self.platform_filter = None;
+                self.operation_filter = None;
+                self.severity_filter = None;
+                self.reset_selection();
+            }
+            KeyCode::Enter | KeyCode::Char('u') => self.open_request_url(),
+            KeyCode::Char('h') | KeyCode::Char('?') | KeyCode::F(1) => self.show_help = true,
+            _ => {}
+        }
+        true
+    }
+
+    fn filter_line(&self) -> Line<'static> {
+        let label = Style::default().fg(Color::DarkGray);
+        let value = Style::default().add_modifier(Modifier::BOLD);
+        let all = || "all".to_string();
+        Line::from(vec![
+            Span::styled("Platform: ", label),
+            Span::styled(self.platform_filter.clone().unwrap_or_else(all), value),
+            Span::styled("  Operation: ", label),
+            Span::styled(self.operation_filter.clone().unwrap_or_else(all), value),
+            Span::styled("  Severity: ", label),
+            Span::styled(
+                self.severity_filter
+                    .map(|severity| severity.as_str().to_string())
+                    .unwrap_or_else(all),
+                value,
+            ),
+            Span::styled(
+                format!(
+                    "  ({} of {} entries)",
+                    self.visible().len(),
+                    self.errors.len()
+                ),
+                label,
+            ),
+        ])
+    }
+
+    fn detail_lines(error: &ErrorContext) -> Vec<Line<'static>> {
+        let field = |name: &str, value: String| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{name}: "),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(value),
+            ])
+        };
+        let mut lines = vec![
+            field(
+                "Severity",
+                format!(
+                    "{} {}",
+                    severity_icon(error.severity),
+                    error.severity.as_str()
+                ),
+            ),
+            field("Time", error.timestamp.clone()),
+            field("Platform", error.platform_id.clone()),
+            field("Operation", error.operation.clone()),
+            field("Type", error.error_type.clone()),
+            field("Message", error.error_message.clone()),
+        ];
+        if let Some(user) = &error.user {
+            lines.push(field("User", user.clone()));
+        }
+        if let Some(status) = error.status_code {
+            lines.push(field("Status", status.to_string()));
+        }
+        if let Some(url) = &error.request_url {
+            lines.push(field("URL", url.clone()));
+        }
+        let mut metadata: Vec<_> = error.metadata.iter().collect();
+        metadata.sort();
+        for (key, value) in metadata {
+            lines.push(field(key, value.clone()));
+        }
+        if let Some(body) = &error.response_body {
+            lines.push(Line::default());
+            lines.push(Line::styled(
+                "Response body:",
+                Style::default().add_modifier(Modifier::BOLD),
+            ));
+            lines.extend(
+                format_body(body)
+                    .lines()
+                    .map(|line| Line::from(line.to_string())),
+            );
+        }
+        lines
+    }
+
+    fn ui(&mut self, f: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Min(5),
+                Constraint::Length(1),
+            ])
+            .split(f.area());
+        f.render_widget(Paragraph::new(self.filter_line()), chunks[0]);
+
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .split(chunks[1]);
+
+        let visible = self.visible();
+        let items: Vec<ListItem> = visible
+            .iter()
+            .map(|error| {
+                let status = error
+                    .status_code
+                    .map(|status| format!(" {status}"))
+                    .unwrap_or_default();
+                let time = error.timestamp.get(..16).unwrap_or(&error.timestamp);
+                ListItem::new(format!(
+                    "{} {} {} {}{status}",
+                    severity_icon(error.severity),
+                    time.replace('T', " "),
+                    error.platform_id,
+                    error.operation
+                ))
+            })
+            .collect();
+        let empty = items.is_empty();
+        let details = match self
+            .list_state
+            .selected()
+            .and_then(|index| visible.get(index))
+        {
+            Some(error) => Self::detail_lines(error),
+            None if self.errors.is_empty() => vec![Line::from("No errors recorded.")],
+            None => vec![Line::from(
+                "No entries match the filters; press x to clear them.",
+            )],
+        };
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title("⚠️ Error Log"))
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▶ ");
+        if empty {
+            f.render_widget(list, panes[0]);
+        } else {
+            f.render_stateful_widget(list, panes[0], &mut self.list_state);
+        }
+        f.render_widget(
+            Paragraph::new(details)
+                .block(Block::default().borders(Borders::ALL).title("Details"))
+                .wrap(Wrap { trim: false })
+                .scroll((self.detail_scroll, 0)),
+            panes[1],
+        );
+
+        let footer = self.message.clone().unwrap_or_else(|| {
+            "j/k: select · J/K: scroll details · p/o/s: filter platform/operation/severity · x: clear · Enter: open URL · q: quit".to_string()
+        });
+        f.render_widget(
+            Paragraph::new(footer).style(Style::default().fg(Color::DarkGray)),
+            chunks[2],
+        );
+
+        if self.show_help {
+            let help = "j/k, ↓/↑     Select an entry
+J/K, PgDn/PgUp  Scroll the details and response body
+p           Next platform filter (then all platforms)
+o           Next operation filter of the platform
+s           Severity filter: error, warning, all
+x           Clear all filters
+Enter/u     Open the request URL in the browser
+q/Esc       Quit";
+            let area = Layout::default()
+                .constraints([Constraint::Percentage(100)])
+                .margin(3)
+                .split(chunks[1])[0];
+            f.render_widget(Clear, area);
+            f.render_widget(
+                Paragraph::new(help).block(Block::default().borders(Borders::ALL).title("Help")),
+                area,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::test_terminal::{key, screen, terminal};
+
+    fn entry(platform: &str, operation: &str, severity: Severity) -> ErrorContext {
+        ErrorContext::new(platform, operation)
+            .with_severity(severity)
+            .with_error("api_error", "HTTP 500")
+            .with_request_details(
+                &format!("https://{platform}.example.com/api"),
+                Some(500),
+                Some(r#"{"message":"boom"}"#),
+            )
+    }
+
+    #[test]
+    fn test_filters_cycle_and_details() {
+        let mut browser = ErrorBrowser::new(vec![
+            entry("gitlab", "get_merge_requests", Severity::Error),
+            entry("jira", "search_issues_count", Severity::Warning),
+            entry("gitlab", "search_users", Severity::Warning),
+        ]);
+        assert_eq!(browser.visible().len(), 3);
+
+        browser.handle_key_event(key(KeyCode::Char('p')));
+        assert_eq!(browser.platform_filter.as_deref(), Some("gitlab"));
+        assert_eq!(browser.visible().len(), 2);
+        browser.handle_key_event(key(KeyCode::Char('o')));
+        assert_eq!(
+            browser.operation_filter.as_deref(),
+            Some("get_merge_requests")
+        );
+        assert_eq!(browser.visible().len(), 1);
+
+        // Switching the platform drops the operation filter; past the last one all are shown
+        browser.handle_key_event(key(KeyCode::Char('p')));
+        assert_eq!(browser.platform_filter.as_deref(), Some("jira"));
+        assert_eq!(browser.operation_filter, None);
+        browser.handle_key_event(key(KeyCode::Char('p')));
+        assert_eq!(browser.platform_filter, None);
+
+        browser.handle_key_event(key(KeyCode::Char('s')));
+        browser.handle_key_event(key(KeyCode::Char('s')));
+        assert_eq!(browser.visible().len(), 2);
+        browser.handle_key_event(key(KeyCode::Char('j')));
+        assert_eq!(browser.selected().unwrap().operation, "search_users");
+
+        let mut terminal = terminal(120, 24);
+        terminal.draw(|f| browser.ui(f)).unwrap();
+        let shown = screen(&terminal);
+        assert!(shown.contains("Severity: warning"), "{shown}");
+        assert!(
+            shown.contains("URL: https://gitlab.example.com/api"),
+            "{shown}"
+        );
+        assert!(shown.contains(r#""message": "boom""#), "{shown}");
+
+        browser.handle_key_event(key(KeyCode::Char('x')));
+        assert_eq!(browser.visible().len(), 3);
+        assert!(!browser.handle_key_event(key(KeyCode::Char('q'))));
+    }
+
+    #[test]
+    fn test_open_without_request_url() {
+        let mut browser = ErrorBrowser::new(vec![ErrorContext::new("gerrit", "get_changes")]);
+        browser.handle_key_event(key(KeyCode::Enter));
+        assert_eq!(
+            browser.message.as_deref(),
+            Some("This entry has no request URL")
+        );
+    }
+}
@@ -0,0 +1,650 @@
+//! `reviewr goals <employee>`: add, edit and remove an employee's goals, with their status,
+//! target date and evidence links
+
+use crate::core::{
+    goals::{Goal, GoalStatus, GoalsService},
+    models::DataPath,
+};
+use chrono::NaiveDate;
+use crossterm::{
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use ratatui::{
+    Frame, Terminal,
+    backend::{Backend, CrosstermBackend},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+use std::io;
+
+/// Form field titles, in Tab order
+const FIELDS: [&str; 7] = [
+    "Title",
+    "Status (Space/←/→ to change)",
+    "Target date YYYY-MM-DD (optional)",
+    "Evidence URLs, separated by spaces (optional)",
+    "Projects counting towards it, comma-separated (optional)",
+    "Labels or components counting towards it, comma-separated (optional)",
+    "Completed items that reach it (optional)",
+];
+
+const STATUS_FIELD: usize = 1;
+
+fn status_color(status: GoalStatus) -> Color {
+    match status {
+        GoalStatus::OnTrack => Color::Cyan,
+        GoalStatus::AtRisk => Color::Red,
+        GoalStatus::Done => Color::Green,
+    }
+}
+
+fn list(value: &str, separator: char) -> Vec<String> {
+    value
+        .split(separator)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Values of the goal form; the status is chosen rather than typed
+struct GoalForm {
+    /// Index of the goal being edited; None when adding one
+    original: Option<usize>,
+    values: [String; 7],
+    status: GoalStatus,
+    current_field: usize,
+    /// Validation problem shown below the fields
+    error: Option<String>,
+}
+
+impl GoalForm {
+    fn new(original: Option<usize>, goal: &Goal) -> Self {
+        Self {
+            original,
+            values: [
+                goal.title.clone(),
+                String::new(),
+                goal.target_date
+                    .map(|date| date.to_string())
+                    .unwrap_or_default(),
+                goal.evidence.join(" "),
+                goal.projects.join(", "),
+                goal.labels.join(", "),
+                goal.target_items
+                    .map(|items| items.to_string())
+                    .unwrap_or_default(),
+            ],
+            status: goal.status,
+            current_field: 0,
+            error: None,
+        }
+    }
+
+    /// `goal` with the form's values; the status is left to the caller so its change is
+    /// recorded with the day
+    fn apply_to(&self, goal: &mut Goal) -> Result<(), String> {
+        let [
+            title,
+            _,
+            target_date,
+            evidence,
+            projects,
+            labels,
+            target_items,
+        ] = self.values.each_ref().map(|value| value.trim());
+        goal.title = title.to_string();
+        goal.target_date = match target_date {
+            "" => None,
+            date => Some(
+                NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                    .map_err(|_| format!("Target date '{date}' must be formatted as YYYY-MM-DD"))?,
+            ),
+        };
+        goal.evidence = evidence.split_whitespace().map(str::to_string).collect();
+        goal.projects = list(projects, ',');
+        goal.labels = list(labels, ',');
+        goal.target_items = match target_items {
+            "" => None,
+            items => Some(
+                items
+                    .parse()
+                    .map_err(|_| format!("Completed items '{items}' must be a number"))?,
+            ),
+        };
+        goal.validate().map_err(|e| e.to_string())
+    }
+}
+
+enum EditorMode {
+    List,
+    Form(Box<GoalForm>),
+    ConfirmDelete,
+    ConfirmDiscard,
+}
+
+pub struct GoalsEditor {
+    employee: String,
+    goals: Vec<Goal>,
+    list_state: ListState,
+    mode: EditorMode,
+    /// Day status changes are recorded with
+    today: NaiveDate,
+    modified: bool,
+}
+
+impl GoalsEditor {
+    pub fn load(data_path: &DataPath, employee: &str) -> io::Result<Self> {
+        let goals = GoalsService::load_goals(data_path, employee)?;
+        Ok(Self::new(
+            employee,
+            goals,
+            chrono::Local::now().date_naive(),
+        ))
+    }
+
+    fn new(employee: &str, goals: Vec<Goal>, today: NaiveDate) -> Self {
+        let mut list_state = ListState::default();
+        list_state.select((!goals.is_empty()).then_some(0));
+        Self {
+            employee: employee.to_string(),
+            goals,
+            list_state,
+            mode: EditorMode::List,
+            today,
+            modified: false,
+        }
+    }
+
+    /// Returns whether the goals were saved
+    pub fn run(&mut self, data_path: &DataPath) -> io::Result<bool> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let result = self.run_app(&mut terminal, data_path);
+
+        disable_raw_mode()?;
+        execute!(
+            terminal.backend_mut(),
+            LeaveAlternateScreen,
+            DisableMouseCapture
+        )?;
+        terminal.show_cursor()?;
+
+        result
+    }
+
+    fn run_app<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        data_path: &DataPath,
+    ) -> io::Result<bool> {
+        loop {
+            terminal.draw(|f| self.ui(f))?;
+            if let Event::Key(key) = event::read()?
+                && let Some(saved) = self.handle_key_event(key, data_path)?
+            {
+                return Ok(saved);
+            }
+        }
+    }
+
+    fn selected(&self) -> Option<usize> {
+        self.list_state
+            .selected()
+            .filter(|index| *index < self.goals.len())
+    }
+
+    /// Write a form into the goals, or keep it open with the reason it cannot be applied
+    fn apply(&mut self, mut form: GoalForm) {
+        let mut goal = form
+            .original
+            .map(|index| self.goals[index].clone())
+            .unwrap_or_default();
+        if let Err(error) = form.apply_to(&mut goal) {
+            form.error = Some(error);
+            self.mode = EditorMode::Form(Box::new(form));
+            return;
+        }
+        // Hand-written goals only get a history once their status changes
+        if form.original.is_none() || goal.status != form.status {
+            goal.set_status(form.status, self.today);
+        }
+        let index = match form.original {
+            Some(index) => {
+                self.goals[index] = goal;
+                index
+            }
+            None => {
+                self.goals.push(goal);
+                self.goals.len() - 1
+            }
+        };
+        self.list_state.select(Some(index));
+        self.modified = true;
+        self.mode = EditorMode::List;
+    }
+
+    /// Returns `Some(saved)` when the editor should close
+    fn handle_key_event(
+        &mut self,
+        key: KeyEvent,
+        data_path: &DataPath,
+    ) -> io::Result<Option<bool>> {
+        match &mut self.mode {
+            EditorMode::List => match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => {
+                    if !self.modified {
+                        return Ok(Some(false));
+                    }
+                    self.mode = EditorMode::ConfirmDiscard;
+                }
+                KeyCode::Down | KeyCode::Char('j') if !self.goals.is_empty() => {
+                    let selected = self.list_state.selected().unwrap_or(0);
+                    self.list_state
+                        .select(Some((selected + 1).min(self.goals.len() - 1)));
+                }
+                KeyCode::Up | KeyCode::Char('k') if !self.goals.is_empty() => {
+                    let selected = self.list_state.selected().unwrap_or(0);
+                    self.list_state.select(Some(selected.saturating_sub(1)));
+                }
+                KeyCode::Char('a') => {
+                    self.mode = EditorMode::Form(Box::new(GoalForm::new(None, &Goal::default())));
+                }
+                KeyCode::Enter | KeyCode::Char('e') => {
+                    if let Some(index) = self.selected() {
+                        self.mode = EditorMode::Form(Box::new(GoalForm::new(
+                            Some(index),
+                            &self.goals[index],
+                        )));
+                    }
+                }
+                KeyCode::Char(' ') => {
+                    if let Some(index) = self.selected() {
+                        let goal = &mut self.goals[index];
+                        goal.set_status(goal.status.next(), self.today);
+                        self.modified = true;
+                    }
+                }
+                KeyCode::Char('d') if self.selected().is_some() => {
+                    self.mode = EditorMode::ConfirmDelete;
+                }
+                KeyCode::Char('s') => {
+                    GoalsService::save_goals(data_path, &self.employee, &self.goals)?;
+                    self.modified = false;
+                    return Ok(Some(true));
+                }
+                _ => {}
+            },
+            EditorMode::Form(form) => match key.code {
+                KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right
+                    if form.current_field == STATUS_FIELD =>
+                {
+                    let steps = if key.code == KeyCode::Left { 2 } else { 1 };
+                    for _ in 0..steps {
+                        form.status = form.status.next();
+                    }
+                }
+                KeyCode::Char(_) | KeyCode::Backspace if form.current_field == STATUS_FIELD => {}
+                KeyCode::Char(c) => form.values[form.current_field].push(c),
+                KeyCode::Backspace => {
+                    form.values[form.current_field].pop();
+                }
+                KeyCode::Tab | KeyCode::Down => {
+                    form.current_field = (form.current_field + 1) % FIELDS.len();
+                }
+                KeyCode::BackTab | KeyCode::Up => {
+                    form.current_field = (form.current_field + FIELDS.len() - 1) % FIELDS.len();
+                }
+                KeyCode::Esc => self.mode = EditorMode::List,
+                KeyCode::Enter => {
+                    let EditorMode::Form(form) =
+                        std::mem::replace(&mut self.mode, EditorMode::List)
+                    else {
+                        unreachable!("matched the form mode above");
+                    };
+                    self.apply(*form);
+                }
+                _ => {}
+            },
+            EditorMode::ConfirmDelete => {
+                if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y'))
+                    && let Some(index) = self.selected()
+                {
+                    self.goals.remove(index);
+                    self.modified = true;
+                    self.list_state.select(if self.goals.is_empty() {
+                        None
+                    } else {
+                        Some(index.min(self.goals.len() - 1))
+                    });
+                }
+                self.mode = EditorMode::List;
+            }
+            EditorMode::ConfirmDiscard => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => return Ok(Some(false)),
+                _ => self.mode = EditorMode::List,
+            },
+        }
+        Ok(None)
+    }
+
+    fn goal_line(goal: &Goal) -> Line<'static> {
+        let mut details = Vec::new();
+        if let Some(date) = goal.target_date {
+            details.push(format!("due {date}"));
+        }
+        if !goal.evidence.is_empty() {
+            details.push(format!("{} evidence", goal.evidence.len()));
+        }
+        if let Some(update) = goal.history.last() {
+            details.push(format!("since {}", update.date));
+        }
+        let details = if details.is_empty() {
+            String::new()
+        } else {
+            format!("  ({})", details.join(", "))
+        };
+        Line::from(vec![
+            Span::styled(
+                format!("{:<9}", goal.status.as_str()),
+                Style::default().fg(status_color(goal.status)),
+            ),
+            Span::raw(format!(" {}{details}", goal.title)),
+        ])
+    }
+
+    fn ui(&mut self, f: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(3)])
+            .split(f.area());
+
+        let modified = if self.modified { " [modified]" } else { "" };
+        let title = format!("Goals for {}{modified}", self.employee);
+        let block = Block::default().borders(Borders::ALL).title(title);
+        if self.goals.is_empty() {
+            let empty = Paragraph::new("No goals yet. Press 'a' to add one.")
+                .style(Style::default().fg(Color::Gray))
+                .block(block);
+            f.render_widget(empty, chunks[0]);
+        } else {
+            let items: Vec<ListItem> = self
+                .goals
+                .iter()
+                .map(|goal| ListItem::new(Self::goal_line(goal)))
+                .collect();
+            let list = List::new(items)
+                .block(block)
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+                .highlight_symbol("▶ ");
+            f.render_stateful_widget(list, chunks[0], &mut self.list_state);
+        }
+
+        let footer = Paragraph::new(
+            "↑/↓: Move | a: Add | Enter/e: Edit | Space: Next status | d: Delete | s: Save & quit | q/Esc: Quit",
+        )
+        .block(Block::default().borders(Borders::ALL));
+        f.render_widget(footer, chunks[1]);
+
+        let area = f.area();
+        match &self.mode {
+            EditorMode::List => {}
+            EditorMode::Form(form) => Self::render_form(f, form),
+            EditorMode::ConfirmDelete => {
+                let Some(index) = self.selected() else {
+                    return;
+                };
+                let popup_area = centered_rect(50, 30, area);
+                f.render_widget(Clear, popup_area);
+                let text = format!(
+                    "Remove the goal '{}'?\n\nPress Y to confirm, any other key to cancel",
+                    self.goals[index].title
+                );
+                let confirm = Paragraph::new(text).wrap(Wrap { trim: false }).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Confirm")
+                        .style(Style::default().fg(Color::Red)),
+                );
+                f.render_widget(confirm, popup_area);
+            }
+            EditorMode::ConfirmDiscard => {
+                let popup_area = centered_rect(50, 30, area);
+                f.render_widget(Clear, popup_area);
+                let confirm = Paragraph::new(
+                    "Quit without saving your changes?\n\nPress Y to discard them, any other key to go back",
+                )
+                .wrap(Wrap { trim: false })
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Unsaved changes")
+                        .style(Style::default().fg(Color::Red)),
+                );
+                f.render_widget(confirm, popup_area);
+            }
+        }
+    }
+
+    fn render_form(f: &mut Frame, form: &GoalForm) {
+        let area = f.area();
+        f.render_widget(Clear, area);
+        let mut constraints = vec![Constraint::Length(3); FIELDS.len()];
+        constraints.push(Constraint::Length(2));
+        constraints.push(Constraint::Min(0));
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(constraints)
+            .split(area);
+
+        for (index, title) in FIELDS.iter().enumerate() {
+            let style = if form.current_field == index {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            let value = if index == STATUS_FIELD {
+                Line::from(
+                    GoalStatus::ALL
+                        .iter()
+                        .map(|status| {
+                            let label = format!(" {status} ");
+                            if *status == form.status {
+                                Span::styled(
+                                    label,
+                                    Style::default()
+                                        .fg(status_color(*status))
+                                        .add_modifier(Modifier::REVERSED),
+                                )
+                            } else {
+                                Span::raw(label)
+                            }
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            } else {
+                Line::from(form.values[index].clone())
+            };
+            let input = Paragraph::new(value)
+                .style(style)
+                .block(Block::default().borders(Borders::ALL).title(*title));
+            f.render_widget(input, chunks[index]);
+        }
+
+        let instructions = match &form.error {
+            Some(error) => Paragraph::new(error.as_str()).style(Style::default().fg(Color::Red)),
+            None => Paragraph::new("Tab: Next field | Enter: Apply | Esc: Cancel")
+                .style(Style::default().fg(Color::Gray)),
+        };
+        f.render_widget(instructions, chunks[FIELDS.len()]);
+    }
+
+    #[cfg(test)]
+    fn draw<B: Backend>(&mut self, terminal: &mut Terminal<B>) {
+        terminal.draw(|f| self.ui(f)).unwrap();
+    }
+}
+
+// Helper function to create centered popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::goals::GoalUpdate;
+    use crate::tui::test_terminal::{key, screen, terminal};
+    use tempfile::tempdir;
+
+    fn press(editor: &mut GoalsEditor, data_path: &DataPath, code: KeyCode) -> Option<bool> {
+        editor.handle_key_event(key(code), data_path).unwrap()
+    }
+
+    fn type_text(editor: &mut GoalsEditor, data_path: &DataPath, text: &str) {
+        for c in text.chars() {
+            press(editor, data_path, KeyCode::Char(c));
+        }
+    }
+
+    fn form_error(editor: &GoalsEditor) -> Option<&str> {
+        match &editor.mode {
+            EditorMode::Form(form) => form.error.as_deref(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_add_goal_with_validation() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let mut editor = GoalsEditor::new("Jane Doe", Vec::new(), today);
+
+        press(&mut editor, &data_path, KeyCode::Char('a'));
+        type_text(&mut editor, &data_path, "Ship auth rewrite");
+        press(&mut editor, &data_path, KeyCode::Tab);
+        // Typing does not change the status, Space does
+        type_text(&mut editor, &data_path, "x ");
+        press(&mut editor, &data_path, KeyCode::Tab);
+        type_text(&mut editor, &data_path, "June");
+        press(&mut editor, &data_path, KeyCode::Tab);
+        type_text(
+            &mut editor,
+            &data_path,
+            "https://docs.example.com/auth docs/auth.md",
+        );
+
+        press(&mut editor, &data_path, KeyCode::Enter);
+        assert!(form_error(&editor).unwrap().contains("YYYY-MM-DD"));
+        press(&mut editor, &data_path, KeyCode::BackTab);
+        for _ in "June".chars() {
+            press(&mut editor, &data_path, KeyCode::Backspace);
+        }
+        type_text(&mut editor, &data_path, "2025-06-30");
+        press(&mut editor, &data_path, KeyCode::Enter);
+        assert!(form_error(&editor).unwrap().contains("'docs/auth.md'"));
+
+        press(&mut editor, &data_path, KeyCode::Tab);
+        for _ in " docs/auth.md".chars() {
+            press(&mut editor, &data_path, KeyCode::Backspace);
+        }
+        press(&mut editor, &data_path, KeyCode::Enter);
+        assert!(matches!(editor.mode, EditorMode::List));
+        assert!(editor.modified);
+
+        let mut terminal = terminal(100, 10);
+        editor.draw(&mut terminal);
+        assert!(screen(&terminal).contains(
+            "▶ at-risk   Ship auth rewrite  (due 2025-06-30, 1 evidence, since 2025-03-01)"
+        ));
+
+        assert_eq!(
+            press(&mut editor, &data_path, KeyCode::Char('s')),
+            Some(true)
+        );
+        let goals = GoalsService::load_goals(&data_path, "Jane Doe").unwrap();
+        assert_eq!(goals[0].status, GoalStatus::AtRisk);
+        assert_eq!(
+            goals[0].history,
+            vec![GoalUpdate {
+                date: today,
+                status: GoalStatus::AtRisk,
+            }]
+        );
+        assert_eq!(goals[0].evidence, vec!["https://docs.example.com/auth"]);
+    }
+
+    #[test]
+    fn test_status_changes_are_dated_and_deletes_confirmed() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let mut written = Goal {
+            title: "Write docs".to_string(),
+            projects: vec!["docs".to_string()],
+            ..Default::default()
+        };
+        written.set_status(
+            GoalStatus::OnTrack,
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        );
+        let hand_written = Goal {
+            title: "Mentor Sam".to_string(),
+            ..Default::default()
+        };
+        let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let mut editor = GoalsEditor::new("Jane Doe", vec![written, hand_written], today);
+
+        press(&mut editor, &data_path, KeyCode::Char(' '));
+        assert_eq!(editor.goals[0].status, GoalStatus::AtRisk);
+        assert_eq!(editor.goals[0].status_on(today), Some(GoalStatus::AtRisk));
+        assert_eq!(editor.goals[0].history.len(), 2);
+
+        // Editing keeps what the form does not show
+        press(&mut editor, &data_path, KeyCode::Enter);
+        press(&mut editor, &data_path, KeyCode::Enter);
+        assert_eq!(editor.goals[0].projects, vec!["docs"]);
+        assert_eq!(editor.goals[0].history.len(), 2);
+
+        press(&mut editor, &data_path, KeyCode::Down);
+        press(&mut editor, &data_path, KeyCode::Char('d'));
+        press(&mut editor, &data_path, KeyCode::Char('n'));
+        assert_eq!(editor.goals.len(), 2);
+        press(&mut editor, &data_path, KeyCode::Char('d'));
+        press(&mut editor, &data_path, KeyCode::Char('y'));
+        assert_eq!(editor.goals.len(), 1);
+
+        // Quitting with changes asks first
+        assert_eq!(press(&mut editor, &data_path, KeyCode::Char('q')), None);
+        assert_eq!(
+            press(&mut editor, &data_path, KeyCode::Char('y')),
+            Some(false)
+        );
+        assert!(!data_path.goals_dir.join("Jane Doe.toml").exists());
+    }
+}
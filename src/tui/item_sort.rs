@@ -0,0 +1,133 @@
+//! Ordering of the item list in the review browser's category view
+
+use crate::core::platform::ActivityItem;
+use crate::core::timestamps::parse_timestamp;
+use std::cmp::Ordering;
+
+/// Item field the category view is ordered by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemSortField {
+    Created,
+    Updated,
+    Status,
+    Project,
+}
+
+impl ItemSortField {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ItemSortField::Created => "created",
+            ItemSortField::Updated => "updated",
+            ItemSortField::Status => "status",
+            ItemSortField::Project => "project",
+        }
+    }
+
+    fn compare(&self, a: &ActivityItem, b: &ActivityItem) -> Ordering {
+        match self {
+            ItemSortField::Created => compare_dates(&a.created, &b.created),
+            ItemSortField::Updated => compare_dates(&a.updated, &b.updated),
+            ItemSortField::Status => a.status.to_lowercase().cmp(&b.status.to_lowercase()),
+            ItemSortField::Project => a.project.to_lowercase().cmp(&b.project.to_lowercase()),
+        }
+    }
+}
+
+/// Unreadable dates sort before every readable one
+fn compare_dates(a: &str, b: &str) -> Ordering {
+    parse_timestamp(a).cmp(&parse_timestamp(b))
+}
+
+/// No field keeps the order the platform returned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ItemSort {
+    pub field: Option<ItemSortField>,
+    pub descending: bool,
+}
+
+impl ItemSort {
+    /// Order by `field`; dates start with the newest, text from A to Z
+    pub fn sort_by(&mut self, field: ItemSortField) {
+        self.field = Some(field);
+        self.descending = matches!(field, ItemSortField::Created | ItemSortField::Updated);
+    }
+
+    pub fn reverse(&mut self) {
+        self.descending = !self.descending;
+    }
+
+    /// Sort `items` in place; ties keep their current order
+    pub fn apply(&self, items: &mut [ActivityItem]) {
+        self.apply_by(items, |item| item);
+    }
+
+    /// Sort `entries` in place by the item each one holds; ties keep their current order
+    pub fn apply_by<T>(&self, entries: &mut [T], item: impl Fn(&T) -> &ActivityItem) {
+        let Some(field) = self.field else {
+            return;
+        };
+        entries.sort_by(|a, b| {
+            let ordering = field.compare(item(a), item(b));
+            if self.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+
+    /// e.g. "updated ▼", or None while unsorted
+    pub fn describe(&self) -> Option<String> {
+        let field = self.field?;
+        let arrow = if self.descending { "▼" } else { "▲" };
+        Some(format!("{} {arrow}", field.label()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_support::ItemBuilder;
+
+    fn item(id: &str, updated: &str, status: &str, project: &str) -> ActivityItem {
+        ItemBuilder::new(id)
+            .with_updated(updated)
+            .with_status(status)
+            .with_project(project)
+            .build()
+    }
+
+    fn ids(items: &[ActivityItem]) -> Vec<&str> {
+        items.iter().map(|item| item.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_item_sort() {
+        let mut items = vec![
+            item("a", "2024-01-10 08:00:00.000000000", "NEW", "web"),
+            item("b", "2024-01-12T09:00:00.000+0000", "MERGED", "Auth"),
+            item("c", "garbage", "ABANDONED", "core"),
+        ];
+        let mut sort = ItemSort::default();
+        sort.apply(&mut items);
+        assert_eq!(ids(&items), vec!["a", "b", "c"]);
+        assert_eq!(sort.describe(), None);
+
+        sort.sort_by(ItemSortField::Updated);
+        sort.apply(&mut items);
+        assert_eq!(ids(&items), vec!["b", "a", "c"]);
+        assert_eq!(sort.describe().as_deref(), Some("updated ▼"));
+
+        sort.reverse();
+        sort.apply(&mut items);
+        assert_eq!(ids(&items), vec!["c", "a", "b"]);
+
+        sort.sort_by(ItemSortField::Project);
+        sort.apply(&mut items);
+        assert_eq!(ids(&items), vec!["b", "c", "a"]);
+
+        sort.sort_by(ItemSortField::Status);
+        sort.apply(&mut items);
+        assert_eq!(ids(&items), vec!["c", "b", "a"]);
+    }
+}
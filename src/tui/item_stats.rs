@@ -0,0 +1,92 @@
+//! Aggregates over the items shown in the review browser's category view
+
+use crate::core::platform::ActivityItem;
+use crate::core::timestamps::parse_timestamp;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ItemStats {
+    pub count: usize,
+    pub open: usize,
+    pub closed: usize,
+    /// Median days since creation; None when no item has a readable creation date
+    pub median_age_days: Option<i64>,
+}
+
+impl ItemStats {
+    pub fn from_items(items: &[ActivityItem], now: DateTime<Utc>) -> Self {
+        let closed = items.iter().filter(|item| item.is_closed()).count();
+        let mut ages: Vec<i64> = items
+            .iter()
+            .filter_map(|item| parse_timestamp(&item.created))
+            .map(|created| (now - created).num_days().max(0))
+            .collect();
+        ages.sort_unstable();
+        let median_age_days = match ages.len() {
+            0 => None,
+            len if len % 2 == 1 => Some(ages[len / 2]),
+            len => Some((ages[len / 2 - 1] + ages[len / 2]) / 2),
+        };
+        Self {
+            count: items.len(),
+            open: items.len() - closed,
+            closed,
+            median_age_days,
+        }
+    }
+
+    /// e.g. "5 items · 2 open / 3 closed · median age 12d"
+    pub fn summary(&self) -> String {
+        let noun = if self.count == 1 { "item" } else { "items" };
+        let mut summary = format!(
+            "{} {noun} · {} open / {} closed",
+            self.count, self.open, self.closed
+        );
+        if let Some(days) = self.median_age_days {
+            summary.push_str(&format!(" · median age {days}d"));
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_support::ItemBuilder;
+
+    fn item(created: &str, status: &str) -> ActivityItem {
+        ItemBuilder::new("1")
+            .with_created(created)
+            .with_status(status)
+            .build()
+    }
+
+    #[test]
+    fn test_item_stats() {
+        let now = parse_timestamp("2024-02-01T00:00:00Z").unwrap();
+        let items = vec![
+            item("2024-01-31T00:00:00Z", "Open"),
+            item("2024-01-22T00:00:00Z", "Merged"),
+            item("2024-01-01T00:00:00Z", "ABANDONED"),
+            item("2024-01-30T00:00:00Z", "In Progress"),
+            item("garbage", "Done"),
+        ];
+        let stats = ItemStats::from_items(&items, now);
+        assert_eq!(
+            stats,
+            ItemStats {
+                count: 5,
+                open: 2,
+                closed: 3,
+                median_age_days: Some(6),
+            }
+        );
+        assert_eq!(
+            stats.summary(),
+            "5 items · 2 open / 3 closed · median age 6d"
+        );
+
+        let empty = ItemStats::from_items(&[], now);
+        assert_eq!(empty.summary(), "0 items · 0 open / 0 closed");
+    }
+}
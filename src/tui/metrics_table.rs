@@ -0,0 +1,205 @@
+//! Category/count/delta table for the metric panels of the review browsers
+
+use ratatui::{
+    layout::Constraint,
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Row, Table},
+};
+use std::cmp::Ordering;
+
+/// Column the metric rows are ordered by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortColumn {
+    #[default]
+    Category,
+    Count,
+    Delta,
+}
+
+impl SortColumn {
+    fn title(&self) -> &'static str {
+        match self {
+            SortColumn::Category => "Category",
+            SortColumn::Count => "Count",
+            SortColumn::Delta => "Δ",
+        }
+    }
+
+    fn next(&self) -> Self {
+        match self {
+            SortColumn::Category => SortColumn::Count,
+            SortColumn::Count => SortColumn::Delta,
+            SortColumn::Delta => SortColumn::Category,
+        }
+    }
+}
+
+/// One category of activity; `delta` is the change against an earlier fetch, when known
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricRow {
+    pub label: String,
+    pub count: usize,
+    pub delta: Option<i64>,
+    /// The category could not be loaded, so there is no count to show
+    pub failed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MetricsSort {
+    pub column: SortColumn,
+    pub descending: bool,
+}
+
+impl MetricsSort {
+    /// Move to the next column; numbers start with the largest first
+    pub fn cycle_column(&mut self) {
+        self.column = self.column.next();
+        self.descending = self.column != SortColumn::Category;
+    }
+
+    pub fn reverse(&mut self) {
+        self.descending = !self.descending;
+    }
+
+    /// Row indices in display order. Ties, and unknown deltas, fall back to the label.
+    pub fn order(&self, rows: &[MetricRow]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..rows.len()).collect();
+        order.sort_by(|a, b| {
+            let (a, b) = (&rows[*a], &rows[*b]);
+            let primary = match self.column {
+                SortColumn::Category => Ordering::Equal,
+                SortColumn::Count => a.count.cmp(&b.count),
+                SortColumn::Delta => a.delta.cmp(&b.delta),
+            };
+            let primary = if self.descending {
+                primary.reverse()
+            } else {
+                primary
+            };
+            let by_label = a.label.cmp(&b.label);
+            let by_label = if self.descending && self.column == SortColumn::Category {
+                by_label.reverse()
+            } else {
+                by_label
+            };
+            primary.then(by_label)
+        });
+        order
+    }
+
+    fn header(&self, column: SortColumn) -> String {
+        if column != self.column {
+            return column.title().to_string();
+        }
+        let arrow = if self.descending { "▼" } else { "▲" };
+        format!("{} {arrow}", column.title())
+    }
+}
+
+/// "+3", "-2", "0", or "–" when there is nothing to compare against
+pub fn format_delta(delta: Option<i64>) -> String {
+    match delta {
+        Some(delta) if delta > 0 => format!("+{delta}"),
+        Some(delta) => delta.to_string(),
+        None => "–".to_string(),
+    }
+}
+
+/// Table of `rows`, already in `sort` order (see [`MetricsSort::order`]), with the sort column
+/// marked in the header
+pub fn metrics_table<'a>(rows: &[MetricRow], sort: &MetricsSort, title: String) -> Table<'a> {
+    let header = Row::new(
+        [SortColumn::Category, SortColumn::Count, SortColumn::Delta]
+            .map(|column| Cell::from(sort.header(column))),
+    )
+    .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let body = rows.iter().map(|row| {
+        if row.failed {
+            return Row::new([
+                Cell::from(format!("⚠️ {}: failed to load (view error)", row.label)),
+                Cell::from(format!("{:>5}", "–")),
+                Cell::from(format!("{:>5}", "–")),
+            ])
+            .style(Style::default().fg(Color::Red));
+        }
+        let delta_style = match row.delta {
+            Some(delta) if delta > 0 => Style::default().fg(Color::Green),
+            Some(delta) if delta < 0 => Style::default().fg(Color::Red),
+            _ => Style::default().fg(Color::Gray),
+        };
+        Row::new([
+            Cell::from(row.label.clone()),
+            Cell::from(format!("{:>5}", row.count)),
+            Cell::from(format!("{:>5}", format_delta(row.delta))).style(delta_style),
+        ])
+    });
+
+    Table::new(
+        body,
+        [
+            Constraint::Min(24),
+            Constraint::Length(7),
+            Constraint::Length(7),
+        ],
+    )
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(title))
+    .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+    .highlight_symbol("▶ ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<MetricRow> {
+        vec![
+            MetricRow {
+                label: "Reviews Given".to_string(),
+                count: 4,
+                delta: Some(-1),
+                failed: false,
+            },
+            MetricRow {
+                label: "Changes Merged".to_string(),
+                count: 9,
+                delta: None,
+                failed: false,
+            },
+            MetricRow {
+                label: "Changes Created".to_string(),
+                count: 4,
+                delta: Some(2),
+                failed: false,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_sort_order() {
+        let rows = rows();
+        let mut sort = MetricsSort::default();
+        assert_eq!(sort.order(&rows), vec![2, 1, 0]);
+
+        sort.cycle_column();
+        assert_eq!(sort.column, SortColumn::Count);
+        // Largest first, ties by label
+        assert_eq!(sort.order(&rows), vec![1, 2, 0]);
+
+        sort.cycle_column();
+        assert_eq!(sort.order(&rows), vec![2, 0, 1]);
+        sort.reverse();
+        assert_eq!(sort.order(&rows), vec![1, 0, 2]);
+        assert_eq!(sort.header(SortColumn::Delta), "Δ ▲");
+        assert_eq!(sort.header(SortColumn::Count), "Count");
+    }
+
+    #[test]
+    fn test_format_delta() {
+        assert_eq!(format_delta(Some(3)), "+3");
+        assert_eq!(format_delta(Some(-2)), "-2");
+        assert_eq!(format_delta(Some(0)), "0");
+        assert_eq!(format_delta(None), "–");
+    }
+}
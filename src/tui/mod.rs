@@ -1,12 +1,34 @@
+pub mod config_editor;
+pub mod dashboard;
 pub mod employee_form;
+pub mod error_browser;
+pub mod goals_editor;
+pub mod item_sort;
+pub mod item_stats;
+pub mod metrics_table;
+pub mod mouse;
 pub mod multi_platform_browser;
+pub mod notes_pager;
 pub mod review_browser;
+pub mod search_view;
 pub mod selector;
+pub mod team_table;
+pub mod trends;
+pub mod url_opener;
 
 #[cfg(test)]
 pub mod multi_platform_browser_tests;
+#[cfg(test)]
+pub mod test_terminal;
 
+pub use config_editor::ConfigEditor;
+pub use dashboard::DashboardView;
 pub use employee_form::EmployeeForm;
+pub use error_browser::ErrorBrowser;
+pub use goals_editor::GoalsEditor;
 pub use multi_platform_browser::MultiPlatformBrowser;
+pub use notes_pager::NotesPager;
 pub use review_browser::ReviewBrowser;
+pub use search_view::SearchView;
 pub use selector::EmployeeSelector;
+pub use team_table::TeamReviewView;
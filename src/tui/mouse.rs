@@ -0,0 +1,135 @@
+//! Mouse hit-testing for the lists and tabs of the TUI, from the areas they were last drawn in
+
+use ratatui::{layout::Rect, text::Line};
+use std::time::{Duration, Instant};
+
+/// A second click on the same cell within this time is a double click
+pub const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// A bordered list or table as last drawn: its area, the index of its first visible row and
+/// the lines of the table header above the rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ListHitArea {
+    pub area: Rect,
+    pub offset: usize,
+    pub header: u16,
+}
+
+impl ListHitArea {
+    /// Index of the row at a screen position; None on the border, the header or outside
+    pub fn row_at(&self, column: u16, row: u16) -> Option<usize> {
+        let inner = Rect {
+            x: self.area.x + 1,
+            y: self.area.y + 1 + self.header,
+            width: self.area.width.saturating_sub(2),
+            height: self.area.height.saturating_sub(2 + self.header),
+        };
+        let inside = column >= inner.left()
+            && column < inner.right()
+            && row >= inner.top()
+            && row < inner.bottom();
+        inside.then(|| self.offset + (row - inner.top()) as usize)
+    }
+}
+
+/// Where each title of a bordered `Tabs` widget with the default padding and divider is drawn,
+/// the padding included, so `tab_at` can tell which one was clicked
+pub fn tab_areas(area: Rect, titles: &[String]) -> Vec<Rect> {
+    let row = area.y + 1;
+    let right = area.right().saturating_sub(1);
+    let mut x = area.x + 1;
+    let mut areas = Vec::new();
+    for title in titles {
+        if x >= right {
+            break;
+        }
+        // " title " followed by the "│" divider
+        let width = (Line::from(title.as_str()).width() as u16 + 2).min(right - x);
+        areas.push(Rect {
+            x,
+            y: row,
+            width,
+            height: 1,
+        });
+        x += width + 1;
+    }
+    areas
+}
+
+/// Index of the tab at a screen position
+pub fn tab_at(areas: &[Rect], column: u16, row: u16) -> Option<usize> {
+    areas
+        .iter()
+        .position(|area| row == area.y && column >= area.left() && column < area.right())
+}
+
+/// Tells double clicks from single ones by the time and cell of the previous click
+#[derive(Debug, Clone, Default)]
+pub struct ClickTracker {
+    last: Option<(Instant, u16, u16)>,
+}
+
+impl ClickTracker {
+    /// Record a click; true when it completes a double click, which starts the count anew
+    pub fn click(&mut self, column: u16, row: u16, at: Instant) -> bool {
+        let double = self.last.is_some_and(|(last, last_column, last_row)| {
+            (last_column, last_row) == (column, row) && at.duration_since(last) <= DOUBLE_CLICK
+        });
+        self.last = if double {
+            None
+        } else {
+            Some((at, column, row))
+        };
+        double
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_at_skips_border_and_header() {
+        let list = ListHitArea {
+            area: Rect::new(0, 5, 40, 10),
+            offset: 3,
+            header: 1,
+        };
+        assert_eq!(list.row_at(2, 5), None); // top border
+        assert_eq!(list.row_at(2, 6), None); // header
+        assert_eq!(list.row_at(2, 7), Some(3));
+        assert_eq!(list.row_at(2, 13), Some(9));
+        assert_eq!(list.row_at(2, 14), None); // bottom border
+        assert_eq!(list.row_at(0, 8), None);
+        assert_eq!(list.row_at(40, 8), None);
+    }
+
+    #[test]
+    fn test_tab_areas() {
+        let titles = ["🔧 Gerrit".to_string(), "🎫 JIRA".to_string()];
+        let areas = tab_areas(Rect::new(0, 0, 40, 3), &titles);
+        // "│ 🔧 Gerrit │ 🎫 JIRA │": the icons are two cells wide
+        assert_eq!(areas[0], Rect::new(1, 1, 11, 1));
+        assert_eq!(areas[1], Rect::new(13, 1, 9, 1));
+        assert_eq!(tab_at(&areas, 5, 1), Some(0));
+        assert_eq!(tab_at(&areas, 12, 1), None); // divider
+        assert_eq!(tab_at(&areas, 14, 1), Some(1));
+        assert_eq!(tab_at(&areas, 14, 0), None);
+
+        // Titles past the right border are not clickable
+        assert_eq!(tab_areas(Rect::new(0, 0, 14, 3), &titles).len(), 1);
+    }
+
+    #[test]
+    fn test_double_click() {
+        let mut clicks = ClickTracker::default();
+        let start = Instant::now();
+        assert!(!clicks.click(3, 4, start));
+        assert!(clicks.click(3, 4, start + Duration::from_millis(200)));
+        // A third click starts over
+        assert!(!clicks.click(3, 4, start + Duration::from_millis(300)));
+        // Too late, or another cell
+        assert!(!clicks.click(3, 4, start + Duration::from_secs(1)));
+        assert!(!clicks.click(3, 5, start + Duration::from_millis(1100)));
+    }
+}
@@ -1,21 +1,64 @@
+use crate::core::annotations::AnnotationStore;
+use crate::core::cache::ActivityCache;
+use crate::core::change_size::{CodeVolume, SizeBreakdown};
+use crate::core::gerrit::{REVIEW_MESSAGES_KEY, REVIEWER_LIST_KEY, VOTES_KEY};
+use crate::core::models::{Absence, DataPath, Employee};
+use crate::core::notes::NotesService;
+use crate::core::period_comparison::{
+    CategoryComparison, PeriodCounts, compare_periods, previous_period_counts,
+};
 use crate::core::platform::{
-    ActivityCategory, ActivityItem, DetailedActivities, ErrorContext, PlatformRegistry,
+    ActivityCategory, ActivityItem, COMMENTS_KEY, ConnectionStatus, DetailedActivities,
+    ErrorContext, ErrorLogReader, PlatformCapabilities, PlatformRegistry, ReviewPlatform, Severity,
+    error_log_path,
+};
+use crate::core::timestamps::{
+    TimestampFormat, UNPARSED_DATES_KEY, parse_timestamp, relative_time,
 };
+use crate::core::unified_config::PerformanceConfig;
+use crate::tui::item_sort::{ItemSort, ItemSortField};
+use crate::tui::item_stats::ItemStats;
+use crate::tui::metrics_table::{MetricRow, MetricsSort, metrics_table};
+use crate::tui::mouse::{ClickTracker, ListHitArea, tab_areas, tab_at};
+use crate::tui::trends::{
+    ActivityTrends, TrendSeries, absent_weeks, trend_sparkline, week_labels, weekly_bar_chart,
+    weekly_buckets, weekly_sums,
+};
+use crate::tui::url_opener::UrlOpener;
+use chrono::{DateTime, Local, Utc};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, MouseButton,
+        MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
-use futures::future::join_all;
 use ratatui::{
     Frame, Terminal,
     backend::{Backend, CrosstermBackend},
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Tabs, Wrap},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table, TableState,
+        Tabs, Wrap,
+    },
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::{Id as TaskId, JoinSet};
+use tokio::time::Instant;
+
+const QUEUED: &str = "⏳ Queued";
+const FETCHING: &str = "🔄 Fetching...";
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Pseudo platform id of the "All platforms" summary entry, whose categories merge the
+/// equivalent categories of every platform (see [`ActivityCategory::combined`])
+pub const ALL_PLATFORMS: &str = "*all";
 
 #[derive(Clone, PartialEq)]
 pub enum ViewMode {
@@ -27,6 +70,10 @@ pub enum ViewMode {
         platform_id: String,
         category: ActivityCategory,
     },
+    /// Weekly activity charts across all platforms
+    TrendsView,
+    /// Items per category against the equally long period before the review period
+    ComparisonView,
 }
 
 impl ViewMode {
@@ -44,30 +91,126 @@ impl ViewMode {
                 let platform_name = platforms.get(platform_id).unwrap_or(platform_id);
                 format!("📋 {} - {}", platform_name, category.display_name())
             }
+            ViewMode::TrendsView => "📈 Activity Trends".to_string(),
+            ViewMode::ComparisonView => "⚖️ Period Comparison".to_string(),
         }
     }
+
+    /// Short label used for this view in the header breadcrumb
+    pub fn breadcrumb_label(&self, platforms: &HashMap<String, String>) -> String {
+        match self {
+            ViewMode::Summary => "Summary".to_string(),
+            ViewMode::PlatformView { platform_id } => platforms
+                .get(platform_id)
+                .unwrap_or(platform_id)
+                .to_string(),
+            ViewMode::CategoryView { category, .. } => category.display_name().to_string(),
+            ViewMode::TrendsView => "Trends".to_string(),
+            ViewMode::ComparisonView => "Comparison".to_string(),
+        }
+    }
+}
+
+/// Outcome of the last load of one platform, shown in the status panel
+#[derive(Debug, Clone)]
+pub struct PlatformHealth {
+    pub connection: ConnectionStatus,
+    /// When the shown data was fetched from the platform, which is earlier for cached data
+    pub fetched_at: Option<DateTime<Local>>,
+    pub items: Option<usize>,
+}
+
+/// Platform fetches of one load, each running as a tokio task so that it can be aborted
+#[derive(Default)]
+pub struct PendingLoad {
+    queued: VecDeque<(String, String, Arc<dyn ReviewPlatform>)>, // platform_id, user, platform
+    tasks: JoinSet<(io::Result<DetailedActivities>, Option<PeriodCounts>)>,
+    running: HashMap<TaskId, (String, String)>, // task -> platform_id, user
+}
+
+/// A previously visited view together with the list selection it had
+#[derive(Clone)]
+struct ViewFrame {
+    view: ViewMode,
+    selected: Option<usize>,
 }
 
 pub struct MultiPlatformBrowser {
-    employee_name: String,
-    employee_email: String,
+    // Whose activity is browsed; its usernames and data policy pick what each platform is asked
+    employee: Employee,
+    profile_summary: Option<String>, // team, manager, ... shown under the header
     platform_activities: HashMap<String, DetailedActivities>,
+    // Activity of every platform by combined category, newest first, shown as ALL_PLATFORMS
+    combined: DetailedActivities,
+    // combined category -> platform_id of each of its items, in the same order
+    combined_sources: HashMap<ActivityCategory, Vec<String>>,
     platform_names: HashMap<String, String>, // platform_id -> display name
     platform_icons: HashMap<String, String>, // platform_id -> icon
+    platform_capabilities: HashMap<String, PlatformCapabilities>,
+    // (platform_id, category) -> formatted list lines, rebuilt when that platform's data changes
+    display_cache: HashMap<(String, ActivityCategory), Vec<String>>,
+    // platform_id -> category counts of the previous fetch, for the Δ column
+    previous_counts: HashMap<String, HashMap<ActivityCategory, usize>>,
+    // platform_id -> counts of the period before the review period, for the summary's Δ column
+    previous_period: HashMap<String, PeriodCounts>,
+    metrics_sort: MetricsSort,
+    item_sort: ItemSort, // order of the items in every category view
     current_view: ViewMode,
+    view_stack: Vec<ViewFrame>,
     selected_platform_index: usize,
     selected_category_index: usize,
     list_state: ListState,
+    // Where the list or table of the current view and the platform tabs were last drawn
+    list_hit: Option<ListHitArea>,
+    tab_hits: Vec<ratatui::layout::Rect>,
+    clicks: ClickTracker,
     show_help: bool,
     platform_order: Vec<String>, // Order of platforms for navigation
     platform_status: HashMap<String, String>, // platform_id -> status message
+    platform_health: HashMap<String, PlatformHealth>,
+    show_status: bool, // per-platform connection status panel
+    // Most recent error.log entry of the platform selected in the status panel, while open
+    error_detail: Option<String>,
     is_loading: bool,
+    spinner_frame: usize,           // advanced while loading
+    auto_refresh: Option<Duration>, // refetch every platform this often while browsing
+    refresh_requested: bool,        // `r` pressed; the run loop starts the refresh
+    refreshing: HashSet<String>,    // platforms refetched in the background, data still shown
+    max_concurrent_platforms: usize,
+    activity_cache: Option<ActivityCache>,
+    offline: bool, // show the last cached fetch of every platform without querying any
+    // When the oldest snapshot shown offline was fetched
+    snapshot_taken: Option<chrono::DateTime<chrono::Local>>,
+    annotations: AnnotationStore,
+    // Text being typed for the selected item's annotation, while the input line is open
+    annotation_input: Option<String>,
+    notes_path: Option<DataPath>, // where `n` adds the selected item to the employee's notes
+    notice: Option<String>,       // outcome of the last key, shown in the footer until the next
+    days: u32,                    // review period queried from every platform
+    requested_days: Option<u32>,  // period picked in the TUI, re-queried after `run` returns
+    out_of_office: Vec<Absence>,  // greyed out in the trends and skipped by their direction
+    // Source platform and item whose pending details `d` asked for, loaded before the next key
+    requested_details: Option<(String, ActivityItem)>,
+    timestamps: TimestampFormat,
+    size_buckets: bool, // add authored changes per XS/S/M/L bucket to the summary
+    url_opener: UrlOpener,
 }
 
 impl MultiPlatformBrowser {
     pub fn new(employee_name: String, employee_email: String, registry: &PlatformRegistry) -> Self {
+        let employee = Employee {
+            name: employee_name,
+            committer_email: Some(employee_email),
+            ..Default::default()
+        };
+        Self::for_employee(employee, registry)
+    }
+
+    /// Browse `employee`, querying each platform with [`Employee::identity_for`]
+    pub fn for_employee(employee: Employee, registry: &PlatformRegistry) -> Self {
         let mut platform_names = HashMap::new();
         let mut platform_icons = HashMap::new();
+        let mut platform_capabilities = HashMap::new();
         let mut platform_order = Vec::new();
 
         // Initialize platform metadata
@@ -75,43 +218,297 @@ impl MultiPlatformBrowser {
             let id = platform.get_platform_id().to_string();
             platform_names.insert(id.clone(), platform.get_platform_name().to_string());
             platform_icons.insert(id.clone(), platform.get_platform_icon().to_string());
+            platform_capabilities.insert(id.clone(), platform.get_capabilities());
             platform_order.push(id);
         }
+        if platform_order.len() > 1 {
+            platform_names.insert(ALL_PLATFORMS.to_string(), "All platforms".to_string());
+            platform_icons.insert(ALL_PLATFORMS.to_string(), "🌐".to_string());
+        }
 
         Self {
-            employee_name,
-            employee_email,
+            employee,
+            profile_summary: None,
             platform_activities: HashMap::new(),
+            combined: DetailedActivities::default(),
+            combined_sources: HashMap::new(),
             platform_names,
             platform_icons,
+            platform_capabilities,
+            display_cache: HashMap::new(),
+            previous_counts: HashMap::new(),
+            previous_period: HashMap::new(),
+            metrics_sort: MetricsSort::default(),
+            item_sort: ItemSort::default(),
             current_view: ViewMode::Summary,
+            view_stack: Vec::new(),
             selected_platform_index: 0,
             selected_category_index: 0,
             list_state: ListState::default(),
+            list_hit: None,
+            tab_hits: Vec::new(),
+            clicks: ClickTracker::default(),
             show_help: false,
             platform_order,
             platform_status: HashMap::new(),
+            platform_health: HashMap::new(),
+            show_status: false,
+            error_detail: None,
             is_loading: false,
+            spinner_frame: 0,
+            auto_refresh: None,
+            refresh_requested: false,
+            refreshing: HashSet::new(),
+            max_concurrent_platforms: PerformanceConfig::default().max_concurrent_platforms,
+            activity_cache: None,
+            offline: false,
+            snapshot_taken: None,
+            annotations: AnnotationStore::default(),
+            annotation_input: None,
+            notes_path: None,
+            notice: None,
+            days: 30,
+            requested_days: None,
+            out_of_office: Vec::new(),
+            requested_details: None,
+            timestamps: TimestampFormat::default(),
+            size_buckets: false,
+            url_opener: UrlOpener::default(),
+        }
+    }
+
+    /// Serve fresh cached activity instead of fetching, and cache what gets fetched
+    pub fn set_activity_cache(&mut self, cache: ActivityCache) {
+        self.activity_cache = Some(cache);
+    }
+
+    /// Load the last cached fetch of every platform, however old, instead of querying platforms
+    pub fn set_offline(&mut self, offline: bool) {
+        self.offline = offline;
+    }
+
+    /// Refetch every platform in the background every `minutes` while browsing; 0 turns it off
+    pub fn set_auto_refresh(&mut self, minutes: u64) {
+        self.auto_refresh = (minutes > 0).then(|| Duration::from_secs(minutes * 60));
+    }
+
+    /// Show and persist item annotations from `store`
+    pub fn set_annotation_store(&mut self, store: AnnotationStore) {
+        self.annotations = store;
+    }
+
+    /// Let `n` add the selected item as evidence to the employee's notes in `data_path`
+    pub fn set_notes_path(&mut self, data_path: DataPath) {
+        self.notes_path = Some(data_path);
+    }
+
+    /// Append the selected item as an evidence bullet to the employee's notes
+    pub fn add_item_to_notes(&mut self) {
+        let Some((_, item)) = self.selected_item() else {
+            return;
+        };
+        let Some(data_path) = &self.notes_path else {
+            self.notice = Some("Notes are not available here".to_string());
+            return;
+        };
+        let line = NotesService::item_evidence_line(item, &self.timestamps);
+        let added = NotesService::append_evidence(
+            data_path,
+            &self.employee.name,
+            &line,
+            &item.url,
+            Local::now().naive_local(),
+        );
+        self.notice = Some(match added {
+            Ok(true) => format!(
+                "📝 Added {} to the notes of {}",
+                item.id, self.employee.name
+            ),
+            Ok(false) => format!(
+                "{} is already in the notes of {}",
+                item.id, self.employee.name
+            ),
+            Err(e) => format!("Could not add {} to the notes: {e}", item.id),
+        });
+    }
+
+    /// The item selected in the category view, with the platform it came from
+    fn selected_item(&self) -> Option<(&str, &ActivityItem)> {
+        let ViewMode::CategoryView {
+            platform_id,
+            category,
+        } = &self.current_view
+        else {
+            return None;
+        };
+        let index = self.list_state.selected()?;
+        let item = self
+            .activities(platform_id)?
+            .items_by_category
+            .get(category)?
+            .get(index)?;
+        Some((self.item_source(platform_id, category, index)?, item))
+    }
+
+    /// Open the annotation input for the selected item, prefilled with its current annotation
+    pub fn start_annotation(&mut self) {
+        let Some((platform_id, item)) = self.selected_item() else {
+            return;
+        };
+        let current = self
+            .annotations
+            .get(platform_id, &item.id)
+            .map(|annotation| annotation.text.clone())
+            .unwrap_or_default();
+        self.annotation_input = Some(current);
+    }
+
+    /// Keys while the annotation input is open: Enter saves (empty text removes), Esc cancels
+    fn handle_annotation_key(&mut self, key: KeyEvent) -> io::Result<()> {
+        let Some(input) = self.annotation_input.as_mut() else {
+            return Ok(());
+        };
+        match key.code {
+            KeyCode::Char(c) => input.push(c),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Esc => self.annotation_input = None,
+            KeyCode::Enter => self.save_annotation()?,
+            _ => {}
         }
+        Ok(())
+    }
+
+    /// Close the annotation input and store its text for the selected item
+    pub fn save_annotation(&mut self) -> io::Result<()> {
+        let Some(text) = self.annotation_input.take() else {
+            return Ok(());
+        };
+        if let Some((platform_id, item)) = self.selected_item() {
+            let (platform_id, item_id) = (platform_id.to_string(), item.id.clone());
+            self.annotations.set(&platform_id, &item_id, &text)?;
+        }
+        Ok(())
+    }
+
+    #[cfg(test)]
+    pub fn annotation_input_mut(&mut self) -> Option<&mut String> {
+        self.annotation_input.as_mut()
+    }
+
+    #[cfg(test)]
+    pub fn annotations(&self) -> &AnnotationStore {
+        &self.annotations
+    }
+
+    /// Review period in days used by the next `load_data`/`load_data_async`
+    pub fn set_days(&mut self, days: u32) {
+        self.days = days.max(1);
+    }
+
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+
+    /// Zone and relative/absolute style of item timestamps
+    pub fn set_timestamp_format(&mut self, format: TimestampFormat) {
+        self.timestamps = format;
+        self.display_cache.clear();
+    }
+
+    /// Switch between absolute and "3 days ago" timestamps
+    pub fn toggle_relative_timestamps(&mut self) {
+        self.set_timestamp_format(TimestampFormat {
+            relative: !self.timestamps.relative,
+            ..self.timestamps
+        });
+    }
+
+    pub fn set_out_of_office(&mut self, absences: Vec<Absence>) {
+        self.out_of_office = absences;
+    }
+
+    pub fn set_size_buckets(&mut self, enabled: bool) {
+        self.size_buckets = enabled;
+    }
+
+    /// Open item URLs with `command` instead of the system browser
+    pub fn set_browser_command(&mut self, command: Option<String>) {
+        self.url_opener = UrlOpener::new(command);
+    }
+
+    /// Limit how many platforms `load_data_async` queries at the same time
+    pub fn set_max_concurrent_platforms(&mut self, limit: usize) {
+        self.max_concurrent_platforms = limit.max(1);
+    }
+
+    /// Query `platform_id` with `user` instead of the employee email
+    /// Structured employee details shown in the dashboard header
+    pub fn set_profile_summary(&mut self, summary: Option<String>) {
+        self.profile_summary = summary;
+    }
+
+    /// Header text: employee, view and period, the optional profile line and the breadcrumb
+    pub fn header_text(&self) -> String {
+        let mut header = format!(
+            "📋 {} ({}) - {} - last {} days",
+            self.employee.name,
+            self.employee.committer_email.as_deref().unwrap_or_default(),
+            self.current_view.title(&self.platform_names),
+            self.days
+        );
+        if self.offline {
+            match self.snapshot_taken {
+                Some(taken) => header.push_str(&format!(
+                    " - 📴 offline snapshot from {}",
+                    taken.format("%Y-%m-%d %H:%M")
+                )),
+                None => header.push_str(" - 📴 offline, no snapshot"),
+            }
+        }
+        header.push('\n');
+        if let Some(summary) = &self.profile_summary {
+            header.push_str(summary);
+            header.push('\n');
+        }
+        header.push_str(&self.breadcrumb());
+        header
+    }
+
+    /// The identifier used for a platform; `None` when neither a username nor an email is known
+    /// or the platform is excluded
+    pub fn identity_for(&self, platform_id: &str) -> Option<String> {
+        self.employee.identity_for(platform_id).map(str::to_string)
     }
 
     pub async fn load_data(&mut self, registry: &PlatformRegistry) -> io::Result<()> {
         for platform in registry.get_configured_platforms() {
             let platform_id = platform.get_platform_id();
-            match platform
-                .get_detailed_activities(&self.employee_email, 30)
-                .await
-            {
+            let Some(user) = self.identity_for(platform_id) else {
+                log::warn!("No username or email for {platform_id}, skipping");
+                continue;
+            };
+            let (result, previous) = tokio::join!(
+                platform.get_detailed_activities(&user, self.days),
+                previous_period_counts(platform, &user, self.days),
+            );
+            match result {
                 Ok(activities) => {
-                    self.platform_activities
-                        .insert(platform_id.to_string(), activities);
+                    if let Some(previous) = previous {
+                        self.previous_period
+                            .insert(platform_id.to_string(), previous);
+                    }
+                    self.record_fetch(platform_id, &activities, Some(Local::now()));
+                    self.set_platform_activities(platform_id.to_string(), activities);
                 }
                 Err(e) => {
+                    self.record_health(platform_id, ConnectionStatus::Error(e.to_string()));
                     // Log detailed error and continue with other platforms
                     ErrorContext::new(platform_id, "load_platform_data")
-                        .with_user(&self.employee_email)
+                        .with_user(&user)
                         .with_error("data_load_error", &e.to_string())
-                        .with_metadata("days", "30")
+                        .with_metadata("days", &self.days.to_string())
                         .log_error();
                     // Simple error message for user
                     log::warn!("Failed to load data from {platform_id}: {e}");
@@ -121,86 +518,437 @@ impl MultiPlatformBrowser {
         Ok(())
     }
 
-    /// Load data from all platforms asynchronously with progress updates
+    /// Load data from all platforms concurrently, without a terminal
     pub async fn load_data_async(&mut self, registry: &PlatformRegistry) -> io::Result<()> {
+        let mut load = self.start_load(registry);
+        while self.next_fetch(&mut load).await {}
+        Ok(())
+    }
+
+    /// Start loading every platform. Cached, offline and skipped platforms are shown right
+    /// away; the others are fetched by tasks whose results `next_fetch` waits for.
+    pub fn start_load(&mut self, registry: &PlatformRegistry) -> PendingLoad {
         self.is_loading = true;
         self.platform_status.clear();
-
-        // Initialize status for all platforms
+        self.platform_health.clear();
+        // Data of a previous period must not survive a failed re-query
+        self.platform_activities.clear();
+        self.previous_period.clear();
+        self.display_cache.clear();
+        self.rebuild_combined();
+        self.snapshot_taken = None;
+
+        let mut load = PendingLoad::default();
         for platform in registry.get_configured_platforms() {
             let platform_id = platform.get_platform_id().to_string();
-            self.platform_status
-                .insert(platform_id, "⏳ Queued".to_string());
+            if !self.employee.allows_platform(&platform_id) {
+                self.platform_status.insert(
+                    platform_id.clone(),
+                    "🚫 Excluded by data policy".to_string(),
+                );
+                self.record_health(
+                    &platform_id,
+                    ConnectionStatus::Warning("Excluded by data policy".to_string()),
+                );
+                continue;
+            }
+            if self.offline {
+                self.load_snapshot(platform_id);
+                continue;
+            }
+            let Some(user) = self.identity_for(&platform_id) else {
+                self.platform_status.insert(
+                    platform_id.clone(),
+                    "⚠️ No username or email configured".to_string(),
+                );
+                self.record_health(&platform_id, ConnectionStatus::NotConfigured);
+                continue;
+            };
+
+            if let Some((activities, age)) = self
+                .activity_cache
+                .as_ref()
+                .and_then(|cache| cache.load(&self.employee.name, &platform_id, self.days))
+            {
+                let items_count: usize = activities.items_by_category.values().map(Vec::len).sum();
+                let status = format!(
+                    "📦 {items_count} items (cached {} min ago)",
+                    age.as_secs() / 60
+                );
+                self.platform_status.insert(platform_id.clone(), status);
+                let fetched_at = Local::now() - chrono::Duration::from_std(age).unwrap_or_default();
+                self.record_fetch(&platform_id, &activities, Some(fetched_at));
+                self.set_platform_activities(platform_id, activities);
+                continue;
+            }
+
+            if let Some(platform) = registry.get_shared_platform(&platform_id) {
+                self.platform_status
+                    .insert(platform_id.clone(), QUEUED.to_string());
+                load.queued.push_back((platform_id, user, platform));
+            }
         }
+        self.spawn_queued(&mut load);
+        load
+    }
 
-        println!("🔄 Starting background data fetch...");
+    /// Ask for every platform to be refetched; ignored offline and while a load or refresh runs
+    pub fn request_refresh(&mut self) {
+        if !self.offline && !self.is_loading && !self.is_refreshing() {
+            self.refresh_requested = true;
+        }
+    }
 
-        // Create concurrent tasks for each platform directly
-        let mut tasks = Vec::new();
+    /// Whether platforms are being refetched in the background
+    pub fn is_refreshing(&self) -> bool {
+        !self.refreshing.is_empty()
+    }
 
+    /// Refetch every queryable platform from its server, bypassing the activity cache. Unlike
+    /// `start_load` the data on screen stays until each platform's result replaces it.
+    pub fn start_refresh(&mut self, registry: &PlatformRegistry) -> PendingLoad {
+        self.refresh_requested = false;
+        let mut load = PendingLoad::default();
+        if self.offline {
+            return load;
+        }
         for platform in registry.get_configured_platforms() {
             let platform_id = platform.get_platform_id().to_string();
-            let user = self.employee_email.clone();
-
-            // Update status to fetching
-            self.platform_status
-                .insert(platform_id.clone(), "🔄 Fetching...".to_string());
-            println!("{platform_id}: 🔄 Fetching...");
+            let Some(user) = self.identity_for(&platform_id) else {
+                continue;
+            };
+            if let Some(platform) = registry.get_shared_platform(&platform_id) {
+                self.platform_status
+                    .insert(platform_id.clone(), QUEUED.to_string());
+                self.refreshing.insert(platform_id.clone());
+                load.queued.push_back((platform_id, user, platform));
+            }
+        }
+        self.spawn_queued(&mut load);
+        load
+    }
 
-            let task = async move {
-                let result = platform.get_detailed_activities(&user, 30).await;
-                (platform_id, result)
+    /// Start queued fetches until max_concurrent_platforms are running
+    fn spawn_queued(&mut self, load: &mut PendingLoad) {
+        while load.tasks.len() < self.max_concurrent_platforms {
+            let Some((platform_id, user, platform)) = load.queued.pop_front() else {
+                break;
             };
+            self.platform_status
+                .insert(platform_id.clone(), FETCHING.to_string());
+            let days = self.days;
+            let task_user = user.clone();
+            let handle = load.tasks.spawn(async move {
+                tokio::join!(
+                    platform.get_detailed_activities(&task_user, days),
+                    previous_period_counts(platform.as_ref(), &task_user, days),
+                )
+            });
+            load.running.insert(handle.id(), (platform_id, user));
+        }
+    }
 
-            tasks.push(task);
+    /// Wait for the next fetch to finish and show its result; false once none is left
+    pub async fn next_fetch(&mut self, load: &mut PendingLoad) -> bool {
+        let Some(joined) = load.tasks.join_next_with_id().await else {
+            self.is_loading = false;
+            return false;
+        };
+        let (id, (result, previous)) = match joined {
+            Ok((id, fetched)) => (id, fetched),
+            Err(e) => (
+                e.id(),
+                (
+                    Err(io::Error::other(format!("fetch task failed: {e}"))),
+                    None,
+                ),
+            ),
+        };
+        if let Some((platform_id, user)) = load.running.remove(&id) {
+            if let Some(previous) = previous {
+                self.previous_period.insert(platform_id.clone(), previous);
+            }
+            self.refreshing.remove(&platform_id);
+            self.finish_fetch(platform_id, &user, result);
         }
+        self.spawn_queued(load);
+        true
+    }
 
-        // Execute all platform tasks concurrently
-        let results = join_all(tasks).await;
+    /// Abort every running and queued fetch; the data loaded so far stays
+    pub fn cancel_load(&mut self, load: &mut PendingLoad) {
+        // Aborted tasks stop at their next await; nothing is left to wait for
+        load.tasks.abort_all();
+        load.tasks.detach_all();
+        let running = load
+            .running
+            .drain()
+            .map(|(_, (platform_id, _))| platform_id);
+        let queued = load.queued.drain(..).map(|(platform_id, ..)| platform_id);
+        let cancelled: Vec<String> = running.chain(queued).collect();
+        self.refreshing.clear();
+        for platform_id in cancelled {
+            self.platform_status
+                .insert(platform_id.clone(), "⏹ Cancelled".to_string());
+            self.record_health(
+                &platform_id,
+                ConnectionStatus::Warning("Cancelled".to_string()),
+            );
+        }
+        self.is_loading = false;
+    }
 
-        // Process results and update status
-        for (platform_id, result) in results {
-            match result {
-                Ok(platform_activities) => {
-                    let items_count: usize = platform_activities
-                        .items_by_category
-                        .values()
-                        .map(|items| items.len())
-                        .sum();
-                    self.platform_activities
-                        .insert(platform_id.clone(), platform_activities);
-                    self.platform_status
-                        .insert(platform_id.clone(), format!("✅ {items_count} items"));
-                    println!("{platform_id}: ✅ {items_count} items");
+    /// Show the result of fetching one platform and cache it
+    fn finish_fetch(
+        &mut self,
+        platform_id: String,
+        user: &str,
+        result: io::Result<DetailedActivities>,
+    ) {
+        let days = self.days;
+        match result {
+            Ok(platform_activities) => {
+                let items_count: usize = platform_activities
+                    .items_by_category
+                    .values()
+                    .map(|items| items.len())
+                    .sum();
+                if let Some((previous, _)) = self
+                    .activity_cache
+                    .as_ref()
+                    .and_then(|cache| cache.load_any_age(&self.employee.name, &platform_id, days))
+                {
+                    self.set_previous_activities(&platform_id, &previous);
                 }
-                Err(e) => {
-                    // Log detailed error and continue with other platforms
-                    ErrorContext::new(&platform_id, "async_load_platform_data")
-                        .with_user(&self.employee_email)
-                        .with_error("data_load_error", &e.to_string())
-                        .with_metadata("days", "30")
-                        .log_error();
-                    self.platform_status
-                        .insert(platform_id.clone(), format!("❌ Failed: {e}"));
-                    println!("{platform_id}: ❌ Failed: {e}");
-                    log::warn!("Failed to load data from {platform_id}: {e}");
+                if let Some(cache) = &self.activity_cache
+                    && let Err(e) = cache.store(
+                        &self.employee.name,
+                        &platform_id,
+                        days,
+                        &platform_activities,
+                    )
+                {
+                    log::warn!("Failed to cache activity from {platform_id}: {e}");
                 }
+                self.record_fetch(&platform_id, &platform_activities, Some(Local::now()));
+                self.set_platform_activities(platform_id.clone(), platform_activities);
+                self.platform_status
+                    .insert(platform_id, format!("✅ {items_count} items"));
+            }
+            Err(e) => {
+                // Log detailed error and continue with other platforms
+                ErrorContext::new(&platform_id, "async_load_platform_data")
+                    .with_user(user)
+                    .with_error("data_load_error", &e.to_string())
+                    .with_metadata("days", &days.to_string())
+                    .log_error();
+                self.platform_status
+                    .insert(platform_id.clone(), format!("❌ Failed: {e}"));
+                self.record_health(&platform_id, ConnectionStatus::Error(e.to_string()));
+                log::warn!("Failed to load data from {platform_id}: {e}");
             }
         }
+    }
 
-        self.is_loading = false;
-        println!("✅ Data fetch completed!");
-        Ok(())
+    /// Offline mode: show the last cached fetch of a platform, whatever its age
+    fn load_snapshot(&mut self, platform_id: String) {
+        let snapshot = self
+            .activity_cache
+            .as_ref()
+            .and_then(|cache| cache.load_any_age(&self.employee.name, &platform_id, self.days));
+        let Some((activities, age)) = snapshot else {
+            let status = "📴 No offline snapshot".to_string();
+            self.record_health(
+                &platform_id,
+                ConnectionStatus::Warning("No offline snapshot".to_string()),
+            );
+            self.platform_status.insert(platform_id, status);
+            return;
+        };
+
+        let taken = chrono::Local::now() - chrono::Duration::from_std(age).unwrap_or_default();
+        if self.snapshot_taken.is_none_or(|oldest| taken < oldest) {
+            self.snapshot_taken = Some(taken);
+        }
+        let items_count: usize = activities.items_by_category.values().map(Vec::len).sum();
+        let status = format!(
+            "📴 {items_count} items (snapshot from {})",
+            taken.format("%Y-%m-%d %H:%M")
+        );
+        self.platform_status.insert(platform_id.clone(), status);
+        self.record_fetch(&platform_id, &activities, Some(taken));
+        self.set_platform_activities(platform_id, activities);
+    }
+
+    /// Record a platform that was not loaded, and why. A failed refresh leaves the earlier data
+    /// on screen, so its item count and fetch time are kept.
+    fn record_health(&mut self, platform_id: &str, connection: ConnectionStatus) {
+        let shown = self
+            .platform_health
+            .get(platform_id)
+            .filter(|_| self.platform_activities.contains_key(platform_id));
+        let (fetched_at, items) =
+            shown.map_or((None, None), |health| (health.fetched_at, health.items));
+        self.platform_health.insert(
+            platform_id.to_string(),
+            PlatformHealth {
+                connection,
+                fetched_at,
+                items,
+            },
+        );
     }
 
-    pub fn run(&mut self) -> io::Result<()> {
+    /// Record a successful load of `activities`, fetched from the platform at `fetched_at`
+    fn record_fetch(
+        &mut self,
+        platform_id: &str,
+        activities: &DetailedActivities,
+        fetched_at: Option<DateTime<Local>>,
+    ) {
+        let items = activities.items_by_category.values().map(Vec::len).sum();
+        self.platform_health.insert(
+            platform_id.to_string(),
+            PlatformHealth {
+                connection: ConnectionStatus::Connected,
+                fetched_at,
+                items: Some(items),
+            },
+        );
+    }
+
+    /// How the last load of each platform went
+    pub fn platform_health(&self) -> &HashMap<String, PlatformHealth> {
+        &self.platform_health
+    }
+
+    /// One line of the status panel: connection icon, platform, state, item count and fetch time
+    pub fn status_line(&self, platform_id: &str) -> String {
+        let icon = self
+            .platform_icons
+            .get(platform_id)
+            .map(String::as_str)
+            .unwrap_or("📄");
+        let name = self
+            .platform_names
+            .get(platform_id)
+            .map(String::as_str)
+            .unwrap_or(platform_id);
+        let Some(health) = self.platform_health.get(platform_id) else {
+            return format!("⏳ {icon} {name} - not loaded");
+        };
+        let state = match &health.connection {
+            ConnectionStatus::Connected => "connected",
+            ConnectionStatus::Warning(message) | ConnectionStatus::Error(message) => message,
+            ConnectionStatus::NotConfigured => "no username or email configured",
+        };
+        let mut line = format!(
+            "{} {icon} {name} - {state}",
+            health.connection.status_icon()
+        );
+        if let Some(items) = health.items {
+            line.push_str(&format!(" · {items} items"));
+        }
+        if let Some(fetched_at) = health.fetched_at {
+            line.push_str(&format!(
+                " · fetched {}",
+                fetched_at.format("%Y-%m-%d %H:%M")
+            ));
+        }
+        line
+    }
+
+    /// When the data shown for a platform was fetched, e.g. "updated 5 minutes ago", or that it
+    /// is being refreshed; for [`ALL_PLATFORMS`] the oldest fetch counts
+    pub fn last_updated(&self, platform_id: &str, now: DateTime<Local>) -> Option<String> {
+        let platforms: Vec<&str> = if platform_id == ALL_PLATFORMS {
+            self.platform_order.iter().map(String::as_str).collect()
+        } else {
+            vec![platform_id]
+        };
+        if platforms.iter().any(|id| self.refreshing.contains(*id)) {
+            return Some("↻ refreshing".to_string());
+        }
+        let fetched_at = platforms
+            .iter()
+            .filter(|id| self.platform_activities.contains_key(**id))
+            .filter_map(|id| self.platform_health.get(*id)?.fetched_at)
+            .min()?;
+        Some(format!(
+            "updated {}",
+            relative_time(fetched_at.with_timezone(&Utc), now.with_timezone(&Utc))
+        ))
+    }
+
+    /// Details of a logged platform error for the status panel
+    pub fn format_error_detail(error: &ErrorContext) -> String {
+        let mut detail = format!(
+            "{} | {}\nType: {}\nMessage: {}",
+            error.timestamp, error.operation, error.error_type, error.error_message
+        );
+        if let Some(url) = &error.request_url {
+            detail.push_str(&format!("\nURL: {url}"));
+        }
+        if let Some(status) = error.status_code {
+            detail.push_str(&format!("\nStatus: {status}"));
+        }
+        if let Some(body) = &error.response_body {
+            detail.push_str(&format!("\nResponse: {body}"));
+        }
+        detail
+    }
+
+    /// Show the most recent error.log entry of the platform selected in the status panel
+    pub fn open_error_detail(&mut self) {
+        let Some(platform_id) = self.platform_order.get(self.selected_platform_index) else {
+            return;
+        };
+        let detail = match ErrorLogReader::read_recent_errors(
+            &error_log_path(),
+            1,
+            Some(platform_id),
+            Some(Severity::Error),
+        ) {
+            Ok(errors) => match errors.first() {
+                Some(error) => Self::format_error_detail(error),
+                None => format!("No errors logged for {platform_id}."),
+            },
+            Err(e) => format!("Failed to read the error log: {e}"),
+        };
+        self.error_detail = Some(detail);
+    }
+
+    /// Keys while the status panel is open: ↑/↓ select a platform, Enter/e shows its last
+    /// logged error, c/Esc closes the error and then the panel
+    fn handle_status_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('c') | KeyCode::Char('q') => {
+                // Closing an open error keeps the panel open
+                self.show_status = self.error_detail.take().is_some();
+            }
+            KeyCode::Enter | KeyCode::Char('e') => self.open_error_detail(),
+            KeyCode::Down | KeyCode::Tab => {
+                self.error_detail = None;
+                self.next_platform();
+            }
+            KeyCode::Up | KeyCode::BackTab => {
+                self.error_detail = None;
+                self.prev_platform();
+            }
+            _ => {}
+        }
+    }
+
+    /// Run the TUI until the user quits, loading the data first and again after every
+    /// period switch
+    pub async fn run(&mut self, registry: &PlatformRegistry) -> io::Result<()> {
         enable_raw_mode()?;
         let mut stdout = io::stdout();
         execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
         let backend = CrosstermBackend::new(stdout);
         let mut terminal = Terminal::new(backend)?;
 
-        let result = self.run_app(&mut terminal);
+        let result = self.run_loop(&mut terminal, registry).await;
 
         disable_raw_mode()?;
         execute!(
@@ -213,20 +961,227 @@ impl MultiPlatformBrowser {
         result
     }
 
-    fn run_app<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
+    async fn run_loop<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        registry: &PlatformRegistry,
+    ) -> io::Result<()> {
         loop {
+            if !self.load_with_progress(terminal, registry).await? {
+                self.run_app(terminal, registry).await?;
+            }
+            // Switching the period returns here to fetch the new window
+            match self.requested_days.take() {
+                Some(days) => self.set_days(days),
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Fill the view with each platform's data as it arrives, below a panel with the progress
+    /// of every platform. Returns true when a key pressed meanwhile exits the browser.
+    async fn load_with_progress<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        registry: &PlatformRegistry,
+    ) -> io::Result<bool> {
+        let mut load = self.start_load(registry);
+        let mut ticker = tokio::time::interval(Duration::from_millis(100));
+        while self.is_loading {
             terminal.draw(|f| self.ui(f))?;
+            tokio::select! {
+                _ = self.next_fetch(&mut load) => {}
+                _ = ticker.tick() => {
+                    self.spinner_frame = self.spinner_frame.wrapping_add(1);
+                    while event::poll(Duration::ZERO)? {
+                        match event::read()? {
+                            Event::Key(key) if self.handle_loading_key(key, &mut load)? => {
+                                return Ok(true);
+                            }
+                            Event::Mouse(mouse) => self.handle_mouse_event(mouse)?,
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+        Ok(false)
+    }
 
-            if let Event::Key(key) = event::read()?
-                && self.handle_key_event(key)?
-            {
-                break;
+    /// Keys while loading: Esc cancels the fetches still running, other keys browse the data
+    /// loaded so far. True when the browser exits, which cancels the load as well.
+    pub fn handle_loading_key(
+        &mut self,
+        key: KeyEvent,
+        load: &mut PendingLoad,
+    ) -> io::Result<bool> {
+        let overlay_open = self.annotation_input.is_some() || self.show_help || self.show_status;
+        if key.code == KeyCode::Esc && !overlay_open {
+            self.cancel_load(load);
+            return Ok(false);
+        }
+        let exit = self.handle_key_event(key)?;
+        if exit {
+            self.cancel_load(load);
+        }
+        Ok(exit)
+    }
+
+    async fn run_app<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        registry: &PlatformRegistry,
+    ) -> io::Result<()> {
+        let mut refresh = PendingLoad::default();
+        let result = self.browse(terminal, registry, &mut refresh).await;
+        // Leaving the browser, e.g. for another period, drops a refresh still running
+        self.cancel_load(&mut refresh);
+        result
+    }
+
+    /// Browse the loaded data until a key exits, refetching every platform in the background
+    /// when `r` is pressed or the auto-refresh interval has passed
+    async fn browse<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        registry: &PlatformRegistry,
+        refresh: &mut PendingLoad,
+    ) -> io::Result<()> {
+        let mut next_refresh = self.auto_refresh.map(|every| Instant::now() + every);
+        let mut ticker = tokio::time::interval(Duration::from_millis(100));
+        loop {
+            terminal.draw(|f| self.ui(f))?;
+
+            // Drawn above as loading; the next key waits until the details are in
+            if let Some((platform_id, item)) = self.requested_details.take() {
+                self.load_item_details(registry, &platform_id, &item).await;
+                continue;
+            }
+            let due = next_refresh.is_some_and(|at| Instant::now() >= at);
+            if due || self.refresh_requested {
+                next_refresh = self.auto_refresh.map(|every| Instant::now() + every);
+                if !self.is_refreshing() {
+                    *refresh = self.start_refresh(registry);
+                }
+            }
+            tokio::select! {
+                _ = self.next_fetch(refresh), if self.is_refreshing() => {}
+                _ = ticker.tick() => {
+                    while event::poll(Duration::ZERO)? {
+                        match event::read()? {
+                            Event::Key(key) if self.handle_key_event(key)? => {
+                                return Ok(());
+                            }
+                            Event::Mouse(mouse) => self.handle_mouse_event(mouse)?,
+                            _ => {}
+                        }
+                    }
+                }
             }
         }
-        Ok(())
+    }
+
+    /// Ask for the full details of the selected item, when its platform fetched only the
+    /// essential fields
+    pub fn request_item_details(&mut self) {
+        let ViewMode::CategoryView {
+            platform_id,
+            category,
+        } = &self.current_view
+        else {
+            return;
+        };
+        let Some(index) = self.list_state.selected() else {
+            return;
+        };
+        let items = self.get_category_items(platform_id, category);
+        let Some(item) = items.get(index) else {
+            return;
+        };
+        if !item.has_pending_details() {
+            return;
+        }
+        let source = self
+            .item_source(platform_id, category, index)
+            .unwrap_or(platform_id)
+            .to_string();
+        if !self.supports_comments(&source) {
+            return;
+        }
+        self.requested_details = Some((source, item.clone()));
+    }
+
+    /// Whether `d` is offered for the items of a platform, or of any platform in the combined
+    /// view
+    fn supports_comments(&self, platform_id: &str) -> bool {
+        let supports = |id: &str| {
+            self.platform_capabilities
+                .get(id)
+                .is_some_and(|capabilities| capabilities.supports_comments)
+        };
+        if platform_id == ALL_PLATFORMS {
+            self.platform_capabilities.keys().any(|id| supports(id))
+        } else {
+            supports(platform_id)
+        }
+    }
+
+    async fn load_item_details(
+        &mut self,
+        registry: &PlatformRegistry,
+        platform_id: &str,
+        item: &ActivityItem,
+    ) {
+        let Some(platform) = registry.get_shared_platform(platform_id) else {
+            return;
+        };
+        match platform.get_item_details(item).await {
+            Ok(detailed) => self.apply_item_details(platform_id, detailed),
+            Err(e) => {
+                ErrorContext::new(platform_id, "load_item_details")
+                    .with_error("data_load_error", &e.to_string())
+                    .with_metadata("item_id", &item.id)
+                    .log_error();
+                self.platform_status.insert(
+                    platform_id.to_string(),
+                    format!("❌ Details of {}: {e}", item.id),
+                );
+            }
+        }
+    }
+
+    /// Replace the item of `platform_id` with the same id and category by `item`
+    pub fn apply_item_details(&mut self, platform_id: &str, item: ActivityItem) {
+        let Some(mut activities) = self.platform_activities.get(platform_id).cloned() else {
+            return;
+        };
+        let Some(existing) = activities
+            .items_by_category
+            .get_mut(&item.category)
+            .and_then(|items| items.iter_mut().find(|existing| existing.id == item.id))
+        else {
+            return;
+        };
+        *existing = item;
+        self.set_platform_activities(platform_id.to_string(), activities);
+    }
+
+    /// Ask for a different review period; returns true when the browser should exit to re-query
+    pub fn request_period(&mut self, days: u32) -> bool {
+        if days == self.days {
+            return false;
+        }
+        self.requested_days = Some(days);
+        self.reset_to_summary();
+        true
     }
 
     fn handle_key_event(&mut self, key: KeyEvent) -> io::Result<bool> {
+        self.notice = None;
+        if self.annotation_input.is_some() {
+            self.handle_annotation_key(key)?;
+            return Ok(false);
+        }
         if self.show_help {
             if matches!(
                 key.code,
@@ -236,31 +1191,75 @@ impl MultiPlatformBrowser {
             }
             return Ok(false);
         }
+        if self.show_status {
+            self.handle_status_key(key);
+            return Ok(false);
+        }
 
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => {
-                // Go back to previous view, or quit if at summary
-                match &self.current_view {
-                    ViewMode::CategoryView { platform_id, .. } => {
-                        self.current_view = ViewMode::PlatformView {
-                            platform_id: platform_id.clone(),
-                        };
-                        self.list_state.select(Some(self.selected_category_index));
-                    }
-                    ViewMode::PlatformView { .. } => {
-                        self.current_view = ViewMode::Summary;
-                        self.list_state.select(Some(self.selected_platform_index));
-                    }
-                    ViewMode::Summary => return Ok(true), // Quit from summary view
-                }
+                // Go back to previous view, or quit if there is nothing to go back to
+                return Ok(!self.pop_view());
             }
             KeyCode::Char('h') | KeyCode::Char('?') => {
                 self.show_help = true;
             }
             KeyCode::Char('s') => {
-                self.current_view = ViewMode::Summary;
-                self.list_state.select(None);
+                self.reset_to_summary();
+            }
+            KeyCode::Char('c') => {
+                self.show_status = true;
+            }
+            KeyCode::Char('t') => {
+                self.toggle_relative_timestamps();
+            }
+            KeyCode::Char('r') => {
+                self.request_refresh();
+            }
+            KeyCode::Char('a') => {
+                self.start_annotation();
+            }
+            KeyCode::Char('d') if self.in_category_view() => {
+                self.request_item_details();
+            }
+            KeyCode::Char('n') if self.in_category_view() => {
+                self.add_item_to_notes();
+            }
+            KeyCode::Char('g') if self.current_view != ViewMode::TrendsView => {
+                self.push_view(ViewMode::TrendsView);
+            }
+            KeyCode::Char('v') if self.current_view != ViewMode::ComparisonView => {
+                self.push_view(ViewMode::ComparisonView);
+            }
+            // Category view: number keys jump between categories, capitals sort the items
+            KeyCode::Char(c @ '1'..='9') if self.in_category_view() => {
+                self.jump_to_category(c as usize - '1' as usize);
+            }
+            KeyCode::Char('C') if self.in_category_view() => {
+                self.sort_items(|sort| sort.sort_by(ItemSortField::Created));
+            }
+            KeyCode::Char('U') if self.in_category_view() => {
+                self.sort_items(|sort| sort.sort_by(ItemSortField::Updated));
             }
+            KeyCode::Char('S') if self.in_category_view() => {
+                self.sort_items(|sort| sort.sort_by(ItemSortField::Status));
+            }
+            KeyCode::Char('P') if self.in_category_view() => {
+                self.sort_items(|sort| sort.sort_by(ItemSortField::Project));
+            }
+            KeyCode::Char('o') if self.in_category_view() => {
+                self.sort_items(ItemSort::reverse);
+            }
+            KeyCode::Char('o') => {
+                self.sort_metrics(MetricsSort::cycle_column);
+            }
+            KeyCode::Char('O') => {
+                self.sort_metrics(MetricsSort::reverse);
+            }
+            KeyCode::Char('1') => return Ok(self.request_period(30)),
+            KeyCode::Char('3') => return Ok(self.request_period(90)),
+            KeyCode::Char('6') => return Ok(self.request_period(180)),
+            KeyCode::Char('y') => return Ok(self.request_period(365)),
             KeyCode::Tab => {
                 self.next_platform();
             }
@@ -268,44 +1267,11 @@ impl MultiPlatformBrowser {
                 self.prev_platform();
             }
             KeyCode::Enter => {
-                if let ViewMode::Summary = self.current_view {
-                    if !self.platform_order.is_empty() {
-                        let platform_id = self.platform_order[self.selected_platform_index].clone();
-                        self.current_view = ViewMode::PlatformView { platform_id };
-                        self.list_state.select(Some(0));
-                        self.selected_category_index = 0;
-                    }
-                } else if let ViewMode::PlatformView { platform_id } = &self.current_view {
-                    if let Some(selected) = self.list_state.selected() {
-                        let categories = self.get_available_categories(platform_id);
-                        if selected < categories.len() {
-                            let category = categories[selected].clone();
-                            self.current_view = ViewMode::CategoryView {
-                                platform_id: platform_id.clone(),
-                                category,
-                            };
-                            self.list_state.select(Some(0));
-                        }
-                    }
-                } else if let ViewMode::CategoryView { .. } = &self.current_view
-                    && let Some(selected) = self.list_state.selected()
-                {
-                    self.open_item_in_browser(selected)?;
-                }
+                self.activate_selected()?;
+            }
+            KeyCode::Backspace => {
+                self.pop_view();
             }
-            KeyCode::Backspace => match &self.current_view {
-                ViewMode::CategoryView { platform_id, .. } => {
-                    self.current_view = ViewMode::PlatformView {
-                        platform_id: platform_id.clone(),
-                    };
-                    self.list_state.select(Some(self.selected_category_index));
-                }
-                ViewMode::PlatformView { .. } => {
-                    self.current_view = ViewMode::Summary;
-                    self.list_state.select(Some(self.selected_platform_index));
-                }
-                ViewMode::Summary => {}
-            },
             KeyCode::Up => {
                 self.previous_item();
             }
@@ -317,10 +1283,150 @@ impl MultiPlatformBrowser {
         Ok(false)
     }
 
+    /// Enter: open the selected platform or category, or the selected item in the browser
+    fn activate_selected(&mut self) -> io::Result<()> {
+        if let ViewMode::Summary = self.current_view {
+            if let Some(platform_id) = self.summary_entries().get(self.selected_platform_index) {
+                let platform_id = platform_id.clone();
+                self.push_view(ViewMode::PlatformView { platform_id });
+                self.selected_category_index = 0;
+            }
+        } else if let ViewMode::PlatformView { platform_id } = &self.current_view {
+            if let Some(selected) = self.list_state.selected()
+                && let Some((category, row)) =
+                    self.category_metrics(platform_id).into_iter().nth(selected)
+            {
+                let platform_id = platform_id.clone();
+                if row.failed {
+                    self.open_category_error(&platform_id, &category);
+                } else {
+                    self.push_view(ViewMode::CategoryView {
+                        platform_id,
+                        category,
+                    });
+                }
+            }
+        } else if let ViewMode::CategoryView { .. } = &self.current_view
+            && let Some(selected) = self.list_state.selected()
+        {
+            self.open_item_in_browser(selected)?;
+        }
+        Ok(())
+    }
+
+    /// Mouse: a click selects a list row or platform tab, a double or middle click on a row
+    /// acts like Enter, and the wheel moves the selection. Ignored while an overlay is open.
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> io::Result<()> {
+        if self.annotation_input.is_some() || self.show_help || self.show_status {
+            return Ok(());
+        }
+        let (column, row) = (mouse.column, mouse.row);
+        let selected = self.list_state.selected().unwrap_or(0);
+        match mouse.kind {
+            // Unlike ↑/↓, the wheel stops at either end of the list
+            MouseEventKind::ScrollDown if selected + 1 < self.item_count() => self.next_item(),
+            MouseEventKind::ScrollUp if selected > 0 => self.previous_item(),
+            MouseEventKind::Down(button @ (MouseButton::Left | MouseButton::Middle)) => {
+                self.notice = None;
+                if let Some(index) = tab_at(&self.tab_hits, column, row) {
+                    self.selected_platform_index = index;
+                    self.list_state.select(Some(index));
+                    return Ok(());
+                }
+                let Some(index) = self
+                    .list_hit
+                    .and_then(|list| list.row_at(column, row))
+                    .filter(|index| *index < self.item_count())
+                else {
+                    return Ok(());
+                };
+                self.select_item(index);
+                let double = self.clicks.click(column, row, std::time::Instant::now());
+                if double || button == MouseButton::Middle {
+                    self.activate_selected()?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Enter a new view, remembering the current one and its selection
+    pub fn push_view(&mut self, view: ViewMode) {
+        let previous = std::mem::replace(&mut self.current_view, view);
+        self.view_stack.push(ViewFrame {
+            view: previous,
+            selected: self.list_state.selected(),
+        });
+        self.list_state.select(Some(0));
+    }
+
+    /// Return to the previous view, restoring its selection; the summary's selection is the
+    /// selected platform. Returns false when there is no previous view.
+    pub fn pop_view(&mut self) -> bool {
+        match self.view_stack.pop() {
+            Some(frame) => {
+                let selected = match frame.view {
+                    ViewMode::Summary => Some(self.selected_platform_index),
+                    _ => frame.selected,
+                };
+                self.current_view = frame.view;
+                self.list_state.select(selected);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Start in the view of one platform, e.g. for a dashboard cell; Esc returns to the summary
+    pub fn open_platform(&mut self, platform_id: &str) {
+        let Some(index) = self
+            .summary_entries()
+            .iter()
+            .position(|id| id == platform_id)
+        else {
+            return;
+        };
+        self.selected_platform_index = index;
+        self.list_state.select(Some(index));
+        self.push_view(ViewMode::PlatformView {
+            platform_id: platform_id.to_string(),
+        });
+        self.selected_category_index = 0;
+    }
+
+    /// Jump back to the summary view, discarding the navigation history
+    pub fn reset_to_summary(&mut self) {
+        self.view_stack.clear();
+        self.current_view = ViewMode::Summary;
+        self.list_state.select(Some(self.selected_platform_index));
+    }
+
+    /// Breadcrumb for the current navigation path, e.g. "Summary ▸ GitLab ▸ Merge Requests Created"
+    pub fn breadcrumb(&self) -> String {
+        self.view_stack
+            .iter()
+            .map(|frame| &frame.view)
+            .chain(std::iter::once(&self.current_view))
+            .map(|view| view.breadcrumb_label(&self.platform_names))
+            .collect::<Vec<_>>()
+            .join(" ▸ ")
+    }
+
+    /// Entries of the summary tabs and list: every platform, then "All platforms" when there
+    /// is more than one
+    pub fn summary_entries(&self) -> Vec<String> {
+        let mut entries = self.platform_order.clone();
+        if entries.len() > 1 {
+            entries.push(ALL_PLATFORMS.to_string());
+        }
+        entries
+    }
+
     pub fn next_platform(&mut self) {
-        if !self.platform_order.is_empty() {
-            self.selected_platform_index =
-                (self.selected_platform_index + 1) % self.platform_order.len();
+        let entries = self.summary_entries().len();
+        if entries > 0 {
+            self.selected_platform_index = (self.selected_platform_index + 1) % entries;
             if let ViewMode::Summary = self.current_view {
                 self.list_state.select(Some(self.selected_platform_index));
             }
@@ -328,9 +1434,10 @@ impl MultiPlatformBrowser {
     }
 
     pub fn prev_platform(&mut self) {
-        if !self.platform_order.is_empty() {
+        let entries = self.summary_entries().len();
+        if entries > 0 {
             self.selected_platform_index = if self.selected_platform_index == 0 {
-                self.platform_order.len() - 1
+                entries - 1
             } else {
                 self.selected_platform_index - 1
             };
@@ -340,71 +1447,277 @@ impl MultiPlatformBrowser {
         }
     }
 
-    fn next_item(&mut self) {
-        let max_items = match &self.current_view {
-            ViewMode::Summary => self.platform_order.len(),
+    /// Rows of the current view's list
+    fn item_count(&self) -> usize {
+        match &self.current_view {
+            ViewMode::Summary => self.summary_entries().len(),
             ViewMode::PlatformView { platform_id } => {
                 self.get_available_categories(platform_id).len()
             }
             ViewMode::CategoryView {
                 platform_id,
                 category,
-            } => self.get_category_items(platform_id, category).len(),
-        };
-
-        if max_items > 0 {
-            let selected = self.list_state.selected().unwrap_or(0);
-            let next = if selected >= max_items.saturating_sub(1) {
-                0
-            } else {
-                selected + 1
+            } => self.get_category_items(platform_id, category).len(),
+            ViewMode::TrendsView | ViewMode::ComparisonView => 0,
+        }
+    }
+
+    /// Select the `index`th row of the current view's list
+    fn select_item(&mut self, index: usize) {
+        self.list_state.select(Some(index));
+
+        // Update indices for navigation
+        match &self.current_view {
+            ViewMode::Summary => self.selected_platform_index = index,
+            ViewMode::PlatformView { .. } => self.selected_category_index = index,
+            ViewMode::CategoryView { .. } | ViewMode::TrendsView | ViewMode::ComparisonView => {}
+        }
+    }
+
+    fn next_item(&mut self) {
+        let max_items = self.item_count();
+        if max_items > 0 {
+            let selected = self.list_state.selected().unwrap_or(0);
+            let next = if selected >= max_items.saturating_sub(1) {
+                0
+            } else {
+                selected + 1
+            };
+            self.select_item(next);
+        }
+    }
+
+    fn previous_item(&mut self) {
+        let max_items = self.item_count();
+        if max_items > 0 {
+            let selected = self.list_state.selected().unwrap_or(0);
+            let prev = if selected == 0 {
+                max_items - 1
+            } else {
+                selected - 1
+            };
+            self.select_item(prev);
+        }
+    }
+
+    /// Categories of a platform in the order of its metrics table
+    pub fn get_available_categories(&self, platform_id: &str) -> Vec<ActivityCategory> {
+        self.category_metrics(platform_id)
+            .into_iter()
+            .map(|(category, _)| category)
+            .collect()
+    }
+
+    /// Count and change since the previous fetch per category, in the table's sort order
+    pub fn category_metrics(&self, platform_id: &str) -> Vec<(ActivityCategory, MetricRow)> {
+        let Some(activities) = self.activities(platform_id) else {
+            return Vec::new();
+        };
+        let previous = self.previous_counts.get(platform_id);
+        let metrics: Vec<(ActivityCategory, MetricRow)> = activities
+            .items_by_category
+            .iter()
+            .map(|(category, items)| {
+                let delta = previous.map(|counts| {
+                    items.len() as i64 - counts.get(category).copied().unwrap_or(0) as i64
+                });
+                let row = MetricRow {
+                    label: category.display_name().to_string(),
+                    count: items.len(),
+                    delta,
+                    failed: false,
+                };
+                (category.clone(), row)
+            })
+            .collect();
+        let rows: Vec<MetricRow> = metrics.iter().map(|(_, row)| row.clone()).collect();
+        let mut ordered: Vec<(ActivityCategory, MetricRow)> = self
+            .metrics_sort
+            .order(&rows)
+            .into_iter()
+            .map(|index| metrics[index].clone())
+            .collect();
+        // Categories that could not be loaded follow, by name, as they have nothing to sort by
+        let mut failed: Vec<&ActivityCategory> = activities.failed_categories.keys().collect();
+        failed.sort_by_key(|category| category.display_name());
+        ordered.extend(failed.into_iter().map(|category| {
+            let row = MetricRow {
+                label: category.display_name().to_string(),
+                count: 0,
+                delta: None,
+                failed: true,
+            };
+            (category.clone(), row)
+        }));
+        ordered
+    }
+
+    /// Show why a category of the platform failed to load in the status panel
+    pub fn open_category_error(&mut self, platform_id: &str, category: &ActivityCategory) {
+        let Some(message) = self
+            .activities(platform_id)
+            .and_then(|activities| activities.failed_categories.get(category))
+        else {
+            return;
+        };
+        let name = self
+            .platform_names
+            .get(platform_id)
+            .map_or(platform_id, String::as_str);
+        let detail = format!(
+            "Failed to load {} from {name}\n{message}",
+            category.display_name()
+        );
+        if let Some(index) = self.platform_order.iter().position(|id| id == platform_id) {
+            self.selected_platform_index = index;
+        }
+        self.error_detail = Some(detail);
+        self.show_status = true;
+    }
+
+    /// Items per combined category across every platform, in the table's sort order, followed
+    /// by their total. Δ is the change against the period before the review period, known
+    /// when every platform listing the category could count it.
+    pub fn summary_metrics(&self) -> Vec<MetricRow> {
+        let rows: Vec<MetricRow> = self
+            .period_comparison()
+            .into_iter()
+            .map(|row| MetricRow {
+                label: row.category.display_name().to_string(),
+                count: row.current,
+                delta: row.delta(),
+                failed: false,
+            })
+            .collect();
+        let total = MetricRow {
+            label: "Total".to_string(),
+            count: rows.iter().map(|row| row.count).sum(),
+            delta: rows.iter().map(|row| row.delta).sum(),
+            failed: false,
+        };
+        let mut ordered: Vec<MetricRow> = self
+            .metrics_sort
+            .order(&rows)
+            .into_iter()
+            .map(|index| rows[index].clone())
+            .collect();
+        if !ordered.is_empty() {
+            ordered.push(total);
+        }
+        ordered
+    }
+
+    /// Items per combined category across every platform against the period before the review
+    /// period, ordered by category name
+    pub fn period_comparison(&self) -> Vec<CategoryComparison> {
+        compare_periods(
+            self.platform_activities
+                .iter()
+                .map(|(platform_id, activities)| {
+                    (activities, self.previous_period.get(platform_id))
+                }),
+        )
+    }
+
+    /// Remember what an earlier fetch returned so the metrics table can show the change
+    pub fn set_previous_activities(&mut self, platform_id: &str, previous: &DetailedActivities) {
+        let counts = previous
+            .items_by_category
+            .iter()
+            .map(|(category, items)| (category.clone(), items.len()))
+            .collect();
+        self.previous_counts.insert(platform_id.to_string(), counts);
+    }
+
+    /// Re-sort the metrics table, keeping the selected category selected
+    pub fn sort_metrics(&mut self, change: impl FnOnce(&mut MetricsSort)) {
+        let ViewMode::PlatformView { platform_id } = &self.current_view else {
+            change(&mut self.metrics_sort);
+            return;
+        };
+        let platform_id = platform_id.clone();
+        let selected = self.list_state.selected().and_then(|index| {
+            self.get_available_categories(&platform_id)
+                .get(index)
+                .cloned()
+        });
+        change(&mut self.metrics_sort);
+        if let Some(selected) = selected {
+            let index = self
+                .get_available_categories(&platform_id)
+                .iter()
+                .position(|category| *category == selected);
+            self.list_state.select(index);
+        }
+    }
+
+    pub fn metrics_sort(&self) -> MetricsSort {
+        self.metrics_sort
+    }
+
+    fn in_category_view(&self) -> bool {
+        matches!(self.current_view, ViewMode::CategoryView { .. })
+    }
+
+    /// Switch the category view to the platform's `index`th category (in metrics table order)
+    /// without going back to the platform view
+    pub fn jump_to_category(&mut self, index: usize) {
+        let ViewMode::CategoryView { platform_id, .. } = &self.current_view else {
+            return;
+        };
+        let platform_id = platform_id.clone();
+        // Failed categories come last and have no items to list
+        if let Some((category, row)) = self.category_metrics(&platform_id).into_iter().nth(index)
+            && !row.failed
+        {
+            self.current_view = ViewMode::CategoryView {
+                platform_id,
+                category,
             };
-            self.list_state.select(Some(next));
-
-            // Update indices for navigation
-            match &self.current_view {
-                ViewMode::Summary => self.selected_platform_index = next,
-                ViewMode::PlatformView { .. } => self.selected_category_index = next,
-                ViewMode::CategoryView { .. } => {}
-            }
+            self.list_state.select(Some(0));
         }
     }
 
-    fn previous_item(&mut self) {
-        let max_items = match &self.current_view {
-            ViewMode::Summary => self.platform_order.len(),
-            ViewMode::PlatformView { platform_id } => {
-                self.get_available_categories(platform_id).len()
+    /// Re-sort the items of every category, keeping the selected item selected
+    pub fn sort_items(&mut self, change: impl FnOnce(&mut ItemSort)) {
+        let selected_id = self.selected_item().map(|(_, item)| item.id.clone());
+        change(&mut self.item_sort);
+        for activities in self.platform_activities.values_mut() {
+            for items in activities.items_by_category.values_mut() {
+                self.item_sort.apply(items);
             }
+        }
+        self.display_cache.clear();
+        self.rebuild_combined();
+        if let (
+            Some(selected_id),
             ViewMode::CategoryView {
                 platform_id,
                 category,
-            } => self.get_category_items(platform_id, category).len(),
-        };
-
-        if max_items > 0 {
-            let selected = self.list_state.selected().unwrap_or(0);
-            let prev = if selected == 0 {
-                max_items - 1
-            } else {
-                selected - 1
-            };
-            self.list_state.select(Some(prev));
-
-            // Update indices for navigation
-            match &self.current_view {
-                ViewMode::Summary => self.selected_platform_index = prev,
-                ViewMode::PlatformView { .. } => self.selected_category_index = prev,
-                ViewMode::CategoryView { .. } => {}
-            }
+            },
+        ) = (selected_id, &self.current_view)
+        {
+            let index = self
+                .get_category_items(platform_id, category)
+                .iter()
+                .position(|item| item.id == selected_id);
+            self.list_state.select(index);
         }
     }
 
-    pub fn get_available_categories(&self, platform_id: &str) -> Vec<ActivityCategory> {
-        if let Some(activities) = self.platform_activities.get(platform_id) {
-            activities.items_by_category.keys().cloned().collect()
-        } else {
-            Vec::new()
+    pub fn item_sort(&self) -> ItemSort {
+        self.item_sort
+    }
+
+    /// Whether a category reached the platform's per-query limit, so more items may exist
+    pub fn is_category_truncated(&self, platform_id: &str, category: &ActivityCategory) -> bool {
+        let max_items = self
+            .platform_capabilities
+            .get(platform_id)
+            .and_then(|capabilities| capabilities.max_items);
+        match max_items {
+            Some(max) => self.get_category_items(platform_id, category).len() >= max,
+            None => false,
         }
     }
 
@@ -413,7 +1726,7 @@ impl MultiPlatformBrowser {
         platform_id: &str,
         category: &ActivityCategory,
     ) -> Vec<ActivityItem> {
-        if let Some(activities) = self.platform_activities.get(platform_id) {
+        if let Some(activities) = self.activities(platform_id) {
             activities
                 .items_by_category
                 .get(category)
@@ -432,7 +1745,7 @@ impl MultiPlatformBrowser {
         {
             let items = self.get_category_items(platform_id, category);
             if let Some(item) = items.get(selected_index)
-                && let Err(e) = webbrowser::open(&item.url)
+                && let Err(e) = self.url_opener.open(&item.url)
             {
                 ErrorContext::new("browser", "open_url")
                     .with_error("browser_open_error", &e.to_string())
@@ -448,62 +1761,102 @@ impl MultiPlatformBrowser {
 
     fn ui(&mut self, f: &mut Frame) {
         let size = f.area();
+        self.list_hit = None;
+        self.tab_hits.clear();
 
         // Create main layout
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3), // Header
+                Constraint::Length(if self.profile_summary.is_some() { 5 } else { 4 }), // Header with breadcrumb
                 Constraint::Min(0),    // Main content
                 Constraint::Length(3), // Footer
             ])
             .split(size);
 
         // Header
-        let header = Paragraph::new(format!(
-            "📋 {} ({}) - {}",
-            self.employee_name,
-            self.employee_email,
-            self.current_view.title(&self.platform_names)
-        ))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("Employee Review Dashboard"),
-        )
-        .wrap(Wrap { trim: true });
+        let header = Paragraph::new(self.header_text())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Employee Review Dashboard"),
+            )
+            .wrap(Wrap { trim: true });
         f.render_widget(header, chunks[0]);
 
-        // Main content
+        // Main content, above the loading progress while platforms are still fetched
+        let content = if self.is_loading {
+            let split = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Min(0),
+                    Constraint::Length(self.platform_order.len() as u16 + 2),
+                ])
+                .split(chunks[1]);
+            self.render_loading_panel(f, split[1]);
+            split[0]
+        } else {
+            chunks[1]
+        };
         let current_view = self.current_view.clone();
         match current_view {
-            ViewMode::Summary => self.render_summary(f, chunks[1]),
+            ViewMode::Summary => self.render_summary(f, content),
             ViewMode::PlatformView { platform_id } => {
-                self.render_platform_view(f, chunks[1], &platform_id)
+                self.render_platform_view(f, content, &platform_id)
             }
             ViewMode::CategoryView {
                 platform_id,
                 category,
-            } => self.render_category_view(f, chunks[1], &platform_id, &category),
+            } => self.render_category_view(f, content, &platform_id, &category),
+            ViewMode::TrendsView => self.render_trends_view(f, content),
+            ViewMode::ComparisonView => self.render_comparison_view(f, content),
         }
 
         // Footer
         let footer_text = match &self.current_view {
             ViewMode::Summary => {
-                "Tab/Shift+Tab: Switch Platform | Enter: View Platform | h: Help | q: Quit"
+                "Tab/Shift+Tab: Switch Platform | Enter: View Platform | c: Status | g: Trends | v: vs Previous | r: Refresh | 1/3/6/y: Period | h: Help | q: Quit"
             }
             ViewMode::PlatformView { .. } => {
-                "↑/↓: Navigate | Enter: View Category | Backspace: Back | h: Help | q: Quit"
+                "↑/↓: Navigate | Enter: View Category | o/O: Sort/Reverse | Backspace: Back | h: Help | q: Quit"
+            }
+            ViewMode::CategoryView { platform_id, .. } if self.supports_comments(platform_id) => {
+                "↑/↓: Navigate | Enter: Open in Browser | 1-9: Category | C/U/S/P: Sort | o: Reverse | a: Annotate | n: Add to Notes | d: Details | Backspace: Back | h: Help | q: Quit"
             }
             ViewMode::CategoryView { .. } => {
-                "↑/↓: Navigate | Enter: Open in Browser | Backspace: Back | h: Help | q: Quit"
+                "↑/↓: Navigate | Enter: Open in Browser | 1-9: Category | C/U/S/P: Sort | o: Reverse | a: Annotate | n: Add to Notes | Backspace: Back | h: Help | q: Quit"
+            }
+            ViewMode::TrendsView | ViewMode::ComparisonView => {
+                "1/3/6/y: Period | Backspace: Back | h: Help | q: Quit"
+            }
+        };
+        // The category view quotes aggregates of the listed items next to its controls
+        let footer_title = match &self.current_view {
+            ViewMode::CategoryView {
+                platform_id,
+                category,
+            } => {
+                let items = self
+                    .activities(platform_id)
+                    .and_then(|activities| activities.items_by_category.get(category))
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                format!(
+                    "Controls · {}",
+                    ItemStats::from_items(items, Utc::now()).summary()
+                )
             }
+            _ => "Controls".to_string(),
         };
-        let footer = Paragraph::new(footer_text)
-            .block(Block::default().borders(Borders::ALL).title("Controls"))
+        let footer = Paragraph::new(self.notice.as_deref().unwrap_or(footer_text))
+            .block(Block::default().borders(Borders::ALL).title(footer_title))
             .wrap(Wrap { trim: true });
         f.render_widget(footer, chunks[2]);
 
+        if self.show_status {
+            self.render_status_panel(f, size);
+        }
+
         // Help overlay
         if self.show_help {
             self.render_help_overlay(f, size);
@@ -511,8 +1864,8 @@ impl MultiPlatformBrowser {
     }
 
     fn render_summary(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
-        let platform_tabs: Vec<String> = self
-            .platform_order
+        let entries = self.summary_entries();
+        let platform_tabs: Vec<String> = entries
             .iter()
             .map(|id| {
                 let default_icon = "📄".to_string();
@@ -530,7 +1883,8 @@ impl MultiPlatformBrowser {
             ])
             .split(area);
 
-        // Platform tabs
+        // Platform tabs, clickable
+        self.tab_hits = tab_areas(content_chunks[0], &platform_tabs);
         let tabs = Tabs::new(platform_tabs)
             .block(
                 Block::default()
@@ -546,33 +1900,16 @@ impl MultiPlatformBrowser {
             );
         f.render_widget(tabs, content_chunks[0]);
 
-        // Platform summary list
-        let platform_items: Vec<ListItem> = self
-            .platform_order
+        // Platform summary list, each entry with the age of its data
+        let now = Local::now();
+        let platform_items: Vec<ListItem> = entries
             .iter()
             .map(|platform_id| {
-                let default_icon = "📄".to_string();
-                let icon = self
-                    .platform_icons
-                    .get(platform_id)
-                    .unwrap_or(&default_icon);
-                let name = self.platform_names.get(platform_id).unwrap_or(platform_id);
-
-                let summary = if let Some(activities) = self.platform_activities.get(platform_id) {
-                    let total_items: usize = activities
-                        .items_by_category
-                        .values()
-                        .map(|items| items.len())
-                        .sum();
-                    let categories_count = activities.items_by_category.len();
-                    format!(
-                        "{icon} {name} - {total_items} items across {categories_count} categories"
-                    )
-                } else {
-                    format!("{icon} {name} - No data available")
-                };
-
-                ListItem::new(summary)
+                let mut line = self.platform_summary(platform_id);
+                if let Some(updated) = self.last_updated(platform_id, now) {
+                    line.push_str(&format!(" · {updated}"));
+                }
+                ListItem::new(line)
             })
             .collect();
 
@@ -585,7 +1922,65 @@ impl MultiPlatformBrowser {
             .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
             .highlight_symbol("▶ ");
 
-        f.render_stateful_widget(platform_list, content_chunks[1], &mut self.list_state);
+        let list_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(content_chunks[1]);
+        f.render_stateful_widget(platform_list, list_chunks[0], &mut self.list_state);
+        self.list_hit = Some(ListHitArea {
+            area: list_chunks[0],
+            offset: self.list_state.offset(),
+            header: 0,
+        });
+
+        let metrics = metrics_table(
+            &self.summary_metrics(),
+            &self.metrics_sort,
+            format!("All platforms · Δ vs previous {} days", self.days),
+        );
+        f.render_widget(metrics, list_chunks[1]);
+    }
+
+    /// One line of the summary list: item and category counts, the code volume when the platform
+    /// reports diffstats or pushes, and the size buckets of authored changes when enabled
+    pub fn platform_summary(&self, platform_id: &str) -> String {
+        let default_icon = "📄".to_string();
+        let icon = self
+            .platform_icons
+            .get(platform_id)
+            .unwrap_or(&default_icon);
+        let name = self
+            .platform_names
+            .get(platform_id)
+            .map(String::as_str)
+            .unwrap_or(platform_id);
+
+        let Some(activities) = self.activities(platform_id) else {
+            return format!("{icon} {name} - No data available");
+        };
+        let total_items: usize = activities
+            .items_by_category
+            .values()
+            .map(|items| items.len())
+            .sum();
+        let categories_count = activities.items_by_category.len();
+        let mut summary =
+            format!("{icon} {name} - {total_items} items across {categories_count} categories");
+        if activities.is_partial() {
+            summary.push_str(&format!(
+                " - ⚠️ {} failed to load",
+                activities.failed_categories.len()
+            ));
+        }
+        let volume = CodeVolume::from_activities([activities]);
+        if !volume.is_empty() {
+            summary.push_str(&format!(" - code volume {}", volume.summary()));
+        }
+        let breakdown = SizeBreakdown::from_activities([activities]);
+        if self.size_buckets && breakdown.total() > 0 {
+            summary.push_str(&format!(" - sizes {}", breakdown.summary()));
+        }
+        summary
     }
 
     fn render_platform_view(
@@ -594,45 +1989,373 @@ impl MultiPlatformBrowser {
         area: ratatui::layout::Rect,
         platform_id: &str,
     ) {
-        let categories = self.get_available_categories(platform_id);
+        let rows: Vec<MetricRow> = self
+            .category_metrics(platform_id)
+            .into_iter()
+            .map(|(_, row)| row)
+            .collect();
+        let table = metrics_table(
+            &rows,
+            &self.metrics_sort,
+            format!(
+                "Categories in {}",
+                self.platform_names
+                    .get(platform_id)
+                    .map_or(platform_id, |name| name.as_str())
+            ),
+        );
+        let mut state = TableState::default().with_selected(self.list_state.selected());
+        f.render_stateful_widget(table, area, &mut state);
+        self.list_hit = Some(ListHitArea {
+            area,
+            offset: state.offset(),
+            header: 1,
+        });
+    }
 
-        let category_items: Vec<ListItem> = categories
+    /// Items per week of the review period, overall, per platform (in tab order) and per
+    /// category (largest first)
+    pub fn activity_trends(&self, now: DateTime<Utc>) -> ActivityTrends {
+        let all_items = || {
+            self.platform_activities
+                .values()
+                .flat_map(|activities| activities.items_by_category.values().flatten())
+        };
+        let absent = absent_weeks(&self.out_of_office, self.days, now);
+        let total = TrendSeries {
+            label: "All platforms".to_string(),
+            weeks: weekly_buckets(all_items(), self.days, now),
+            absent: absent.clone(),
+        };
+
+        let by_platform = self
+            .platform_order
             .iter()
-            .map(|category| {
-                let count = self
-                    .platform_activities
-                    .get(platform_id)
-                    .and_then(|activities| activities.items_by_category.get(category))
-                    .map(|items| items.len())
-                    .unwrap_or(0);
-
-                let icon = match category {
-                    ActivityCategory::ChangesCreated => "📝",
-                    ActivityCategory::ChangesMerged => "✅",
-                    ActivityCategory::ReviewsGiven => "👀",
-                    ActivityCategory::ReviewsReceived => "📥",
-                    ActivityCategory::IssuesCreated => "🎫",
-                    ActivityCategory::IssuesResolved => "✅",
-                    ActivityCategory::IssuesAssigned => "📌",
-                    ActivityCategory::IssuesCommented => "💬",
-                    _ => "📄",
-                };
+            .filter_map(|platform_id| {
+                let activities = self.platform_activities.get(platform_id)?;
+                Some(TrendSeries {
+                    label: self
+                        .platform_names
+                        .get(platform_id)
+                        .unwrap_or(platform_id)
+                        .clone(),
+                    weeks: weekly_buckets(
+                        activities.items_by_category.values().flatten(),
+                        self.days,
+                        now,
+                    ),
+                    absent: absent.clone(),
+                })
+            })
+            .collect();
 
-                ListItem::new(format!("{} {} ({})", icon, category.display_name(), count))
+        let mut categories: Vec<&str> = all_items()
+            .map(|item| item.category.display_name())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        categories.sort();
+        let mut by_category: Vec<TrendSeries> = categories
+            .into_iter()
+            .map(|category| TrendSeries {
+                label: category.to_string(),
+                weeks: weekly_buckets(
+                    all_items().filter(|item| item.category.display_name() == category),
+                    self.days,
+                    now,
+                ),
+                absent: absent.clone(),
             })
             .collect();
+        // Review comments the platforms counted, by the week the reviewed item was created
+        let comments = |item: &ActivityItem| {
+            item.metadata
+                .get(COMMENTS_KEY)
+                .and_then(|count| count.parse().ok())
+                .unwrap_or(0)
+        };
+        if all_items().any(|item| item.metadata.contains_key(COMMENTS_KEY)) {
+            by_category.push(TrendSeries {
+                label: "Review comments left".to_string(),
+                weeks: weekly_sums(all_items(), self.days, now, comments),
+                absent: absent.clone(),
+            });
+        }
+        by_category.sort_by_key(|series| std::cmp::Reverse(series.total()));
 
-        let category_list = List::new(category_items)
-            .block(Block::default().borders(Borders::ALL).title(format!(
-                    "Categories in {}",
-                    self.platform_names
-                        .get(platform_id)
-                        .map_or(platform_id, |name| name.as_str())
-                )))
-            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
-            .highlight_symbol("▶ ");
+        ActivityTrends {
+            total,
+            by_platform,
+            by_category,
+        }
+    }
+
+    fn render_trends_view(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let now = Utc::now();
+        let trends = self.activity_trends(now);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(12), Constraint::Min(0)])
+            .split(area);
+        let chart = weekly_bar_chart(
+            &trends.total,
+            &week_labels(self.days, now),
+            chunks[0].width,
+            format!(
+                "Items created per week, last {} days ({} · {}){}",
+                self.days,
+                trends.total.total(),
+                trends.total.trend().label(),
+                if trends.total.absent.contains(&true) {
+                    " · grey: out of office"
+                } else {
+                    ""
+                }
+            ),
+        );
+        f.render_widget(chart, chunks[0]);
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[1]);
+        for (series, area) in [
+            (&trends.by_platform, columns[0]),
+            (&trends.by_category, columns[1]),
+        ] {
+            // Rows that do not fit are left out
+            let rows = (area.height / 3) as usize;
+            let slots = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(vec![Constraint::Length(3); rows.min(series.len())])
+                .split(area);
+            for (series, slot) in series.iter().zip(slots.iter()) {
+                f.render_widget(trend_sparkline(series), *slot);
+            }
+        }
+    }
+
+    /// Table of every category in both periods, above the changes worded for the review
+    /// conversation, largest first
+    fn render_comparison_view(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let rows = self.period_comparison();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(rows.len() as u16 + 4),
+                Constraint::Min(0),
+            ])
+            .split(area);
+
+        let change_style = |delta: Option<i64>| match delta {
+            Some(delta) if delta > 0 => Style::default().fg(Color::Green),
+            Some(delta) if delta < 0 => Style::default().fg(Color::Red),
+            _ => Style::default().fg(Color::Gray),
+        };
+        let previous =
+            |previous: Option<usize>| previous.map_or("–".to_string(), |n| n.to_string());
+        let total = CategoryComparison {
+            category: ActivityCategory::Other("Total".to_string()),
+            current: rows.iter().map(|row| row.current).sum(),
+            previous: rows.iter().map(|row| row.previous).sum(),
+        };
+        let body = rows
+            .iter()
+            .chain(Some(&total).filter(|_| !rows.is_empty()))
+            .map(|row| {
+                Row::new([
+                    Cell::from(row.category.display_name().to_string()),
+                    Cell::from(format!("{:>8}", previous(row.previous))),
+                    Cell::from(format!("{:>8}", row.current)),
+                    Cell::from(format!("{:>6}", row.change())).style(change_style(row.delta())),
+                ])
+            });
+        let header = Row::new([
+            Cell::from("Category"),
+            Cell::from(format!("{:>8}", "Before")),
+            Cell::from(format!("{:>8}", "Now")),
+            Cell::from(format!("{:>6}", "Change")),
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+        let table = Table::new(
+            body,
+            [
+                Constraint::Min(24),
+                Constraint::Length(9),
+                Constraint::Length(9),
+                Constraint::Length(7),
+            ],
+        )
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            "Last {} days vs the {} days before",
+            self.days, self.days
+        )));
+        f.render_widget(table, chunks[0]);
+
+        let mut changes: Vec<&CategoryComparison> =
+            rows.iter().filter(|row| row.previous.is_some()).collect();
+        changes.sort_by_key(|row| std::cmp::Reverse(row.delta().unwrap_or(0).unsigned_abs()));
+        let mut lines: Vec<Line> = changes
+            .iter()
+            .map(|row| Line::from(format!("• {}", row.describe(self.days))))
+            .collect();
+        if rows.is_empty() {
+            lines.push(Line::from("No activity in this period."));
+        } else if changes.is_empty() {
+            lines.push(Line::from(format!(
+                "No platform could count the {} days before this period.",
+                self.days
+            )));
+        }
+        let uncounted: Vec<&str> = rows
+            .iter()
+            .filter(|row| row.previous.is_none())
+            .map(|row| row.category.display_name())
+            .collect();
+        if !uncounted.is_empty() && !changes.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!("Not counted before: {}", uncounted.join(", ")),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        f.render_widget(
+            Paragraph::new(lines)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Talking points"),
+                )
+                .wrap(Wrap { trim: true }),
+            chunks[1],
+        );
+    }
+
+    /// Format the list line for each item: `[id] title - project · updated`, truncated to fit
+    fn format_item_lines(items: &[ActivityItem], timestamps: &TimestampFormat) -> Vec<String> {
+        fn truncate(text: &str, max: usize) -> String {
+            if text.chars().count() > max {
+                let kept: String = text.chars().take(max - 3).collect();
+                format!("{kept}...")
+            } else {
+                text.to_string()
+            }
+        }
+
+        items
+            .iter()
+            .map(|item| {
+                format!(
+                    "[{}] {} - {} · {}",
+                    item.id,
+                    truncate(&item.title, 60),
+                    truncate(&item.project, 20),
+                    timestamps.format(&item.updated)
+                )
+            })
+            .collect()
+    }
 
-        f.render_stateful_widget(category_list, area, &mut self.list_state);
+    /// Store a platform's activities and precompute its list lines, replacing stale ones
+    fn set_platform_activities(&mut self, platform_id: String, mut activities: DetailedActivities) {
+        self.display_cache.retain(|(id, _), _| *id != platform_id);
+        for items in activities.items_by_category.values_mut() {
+            self.item_sort.apply(items);
+        }
+        for (category, items) in &activities.items_by_category {
+            self.display_cache.insert(
+                (platform_id.clone(), category.clone()),
+                Self::format_item_lines(items, &self.timestamps),
+            );
+        }
+        self.platform_activities.insert(platform_id, activities);
+        self.rebuild_combined();
+    }
+
+    /// Merge the equivalent categories of every platform for the "All platforms" entry. The
+    /// lists are newest first, or follow the item sort when one is chosen.
+    fn rebuild_combined(&mut self) {
+        let mut merged: HashMap<ActivityCategory, Vec<(String, ActivityItem)>> = HashMap::new();
+        let mut failed: HashMap<ActivityCategory, Vec<String>> = HashMap::new();
+        let mut seen = HashSet::new();
+        for platform_id in &self.platform_order {
+            let Some(activities) = self.platform_activities.get(platform_id) else {
+                continue;
+            };
+            let name = self.platform_names.get(platform_id).unwrap_or(platform_id);
+            for (category, message) in &activities.failed_categories {
+                failed
+                    .entry(category.combined())
+                    .or_default()
+                    .push(format!("{name}: {message}"));
+            }
+            for (category, items) in &activities.items_by_category {
+                let combined = category.combined();
+                // e.g. a change listed both as reviewed and as a review given
+                for item in items {
+                    if seen.insert((combined.clone(), platform_id, &item.id)) {
+                        merged
+                            .entry(combined.clone())
+                            .or_default()
+                            .push((platform_id.clone(), item.clone()));
+                    }
+                }
+            }
+        }
+
+        self.display_cache.retain(|(id, _), _| id != ALL_PLATFORMS);
+        self.combined = DetailedActivities::default();
+        self.combined_sources.clear();
+        for (category, messages) in failed {
+            self.combined
+                .failed_categories
+                .insert(category, messages.join("\n"));
+        }
+        for (category, mut entries) in merged {
+            entries.sort_by(|(_, a), (_, b)| {
+                parse_timestamp(&b.updated).cmp(&parse_timestamp(&a.updated))
+            });
+            self.item_sort.apply_by(&mut entries, |(_, item)| item);
+            let (sources, items): (Vec<String>, Vec<ActivityItem>) = entries.into_iter().unzip();
+            let lines = Self::format_item_lines(&items, &self.timestamps)
+                .into_iter()
+                .zip(&sources)
+                .map(|(line, source)| {
+                    let icon = self.platform_icons.get(source).map_or("📄", String::as_str);
+                    format!("{icon} {line}")
+                })
+                .collect();
+            self.display_cache
+                .insert((ALL_PLATFORMS.to_string(), category.clone()), lines);
+            self.combined_sources.insert(category.clone(), sources);
+            self.combined.items_by_category.insert(category, items);
+        }
+    }
+
+    /// A platform's activity, or the merged activity of all platforms for [`ALL_PLATFORMS`]
+    fn activities(&self, platform_id: &str) -> Option<&DetailedActivities> {
+        if platform_id == ALL_PLATFORMS {
+            Some(&self.combined)
+        } else {
+            self.platform_activities.get(platform_id)
+        }
+    }
+
+    /// The platform the `index`th item of a category list came from
+    fn item_source<'a>(
+        &'a self,
+        platform_id: &'a str,
+        category: &ActivityCategory,
+        index: usize,
+    ) -> Option<&'a str> {
+        if platform_id != ALL_PLATFORMS {
+            return Some(platform_id);
+        }
+        self.combined_sources
+            .get(category)?
+            .get(index)
+            .map(String::as_str)
     }
 
     fn render_category_view(
@@ -642,16 +2365,54 @@ impl MultiPlatformBrowser {
         platform_id: &str,
         category: &ActivityCategory,
     ) {
-        let items = self.get_category_items(platform_id, category);
         let selected_idx = self.list_state.selected();
+        let mut list_title = if self.is_category_truncated(platform_id, category) {
+            format!(
+                "{} Items (platform limit reached, list may be incomplete)",
+                category.display_name()
+            )
+        } else {
+            format!("{} Items", category.display_name())
+        };
+        if let Some(sort) = self.item_sort.describe() {
+            list_title.push_str(&format!(" · sorted by {sort}"));
+        }
+
+        let key = (platform_id.to_string(), category.clone());
+        if !self.display_cache.contains_key(&key) {
+            let lines = Self::format_item_lines(
+                self.activities(platform_id)
+                    .and_then(|activities| activities.items_by_category.get(category))
+                    .map(Vec::as_slice)
+                    .unwrap_or_default(),
+                &self.timestamps,
+            );
+            self.display_cache.insert(key.clone(), lines);
+        }
+        // Field borrows rather than `activities()`, which would hold all of `self`
+        let activities = if platform_id == ALL_PLATFORMS {
+            Some(&self.combined)
+        } else {
+            self.platform_activities.get(platform_id)
+        };
+        let items: &[ActivityItem] = activities
+            .and_then(|activities| activities.items_by_category.get(category))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
 
         let (list_area, detail_area) = if let Some(idx) = selected_idx {
             if idx < items.len() {
+                // Review messages get half of the view, other details a fixed height
+                let details = if items[idx].metadata.contains_key(REVIEW_MESSAGES_KEY) {
+                    Constraint::Percentage(50)
+                } else {
+                    Constraint::Length(12)
+                };
                 let chunks = Layout::default()
                     .direction(Direction::Vertical)
                     .constraints([
-                        Constraint::Min(10),   // List takes most space but at least 10 lines
-                        Constraint::Length(8), // Details panel fixed height
+                        Constraint::Min(10), // List takes most space but at least 10 lines
+                        details,
                     ])
                     .split(area);
                 (chunks[0], Some(chunks[1]))
@@ -662,53 +2423,111 @@ impl MultiPlatformBrowser {
             (area, None)
         };
 
-        // Item list
-        let list_items: Vec<ListItem> = items
+        // Item list, from lines formatted once per data load; open changes failing CI in red
+        let list_items: Vec<ListItem> = self.display_cache[&key]
             .iter()
-            .map(|item| {
-                let truncated_title = if item.title.len() > 60 {
-                    format!("{}...", &item.title[..57])
-                } else {
-                    item.title.clone()
-                };
-
-                let project_display = if item.project.len() > 20 {
-                    format!("{}...", &item.project[..17])
+            .zip(items)
+            .enumerate()
+            .map(|(index, (line, item))| {
+                let mut spans = vec![Span::raw(line.as_str())];
+                let source = self.item_source(platform_id, category, index);
+                if source.is_some_and(|source| self.annotations.get(source, &item.id).is_some()) {
+                    spans.push(Span::styled(" 📝", Style::default().fg(Color::Yellow)));
+                }
+                let list_item = ListItem::new(Line::from(spans));
+                if item.is_failing_ci() {
+                    list_item.style(Style::default().fg(Color::Red))
                 } else {
-                    item.project.clone()
-                };
-
-                ListItem::new(format!(
-                    "[{}] {} - {}",
-                    item.id, truncated_title, project_display
-                ))
+                    list_item
+                }
             })
             .collect();
 
         let item_list = List::new(list_items)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(format!("{} Items", category.display_name())),
-            )
+            .block(Block::default().borders(Borders::ALL).title(list_title))
             .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
             .highlight_symbol("▶ ");
 
         f.render_stateful_widget(item_list, list_area, &mut self.list_state);
+        self.list_hit = Some(ListHitArea {
+            area: list_area,
+            offset: self.list_state.offset(),
+            header: 0,
+        });
 
         // Details panel
         if let (Some(detail_area), Some(idx)) = (detail_area, selected_idx)
             && let Some(selected_item) = items.get(idx)
         {
-            let details_text = format!(
+            let mut details_text = format!(
                 "ID: {}\nTitle: {}\nProject: {}\nStatus: {}\nCreated: {}\nUpdated: {}",
                 selected_item.id,
                 selected_item.title,
                 selected_item.project,
                 selected_item.status,
-                selected_item.created,
-                selected_item.updated
+                self.timestamps.format(&selected_item.created),
+                self.timestamps.format(&selected_item.updated)
             );
+            for (key, label) in [
+                ("issue_type", "Type"),
+                ("priority", "Priority"),
+                ("assignee", "Assignee"),
+                ("components", "Components"),
+            ] {
+                if let Some(value) = selected_item.metadata.get(key) {
+                    details_text.push_str(&format!("\n{label}: {value}"));
+                }
+            }
+            if self
+                .requested_details
+                .as_ref()
+                .is_some_and(|(_, requested)| requested.id == selected_item.id)
+            {
+                details_text.push_str("\n⏳ Loading details…");
+            } else if selected_item.has_pending_details()
+                && self
+                    .item_source(platform_id, category, idx)
+                    .is_some_and(|source| self.supports_comments(source))
+            {
+                details_text.push_str("\nPress d to load all fields");
+            }
+            if let Some(ci) = selected_item.metadata.get("ci_status") {
+                details_text.push_str(&format!("\nCI: {ci}"));
+            }
+            if let Some(requirements) = selected_item.metadata.get("submit_requirements") {
+                details_text.push_str(&format!("\nSubmit requirements: {requirements}"));
+            }
+            if let Some(comments) = selected_item.metadata.get(COMMENTS_KEY) {
+                details_text.push_str(&format!("\nReview comments left: {comments}"));
+            }
+            if let Some(reviewers) = selected_item.metadata.get("reviewers") {
+                details_text.push_str(&format!("\nReviewed by: {reviewers}"));
+            }
+            if let Some(votes) = selected_item.metadata.get(VOTES_KEY) {
+                details_text.push_str(&format!("\nVotes: {votes}"));
+            }
+            if let Some(reviewers) = selected_item.metadata.get(REVIEWER_LIST_KEY) {
+                details_text.push_str(&format!("\nReviewers: {reviewers}"));
+            }
+            if let Some(messages) = selected_item.metadata.get(REVIEW_MESSAGES_KEY) {
+                details_text.push_str(&format!("\nMessages:\n{messages}"));
+            }
+            if let Some(fields) = selected_item.metadata.get(UNPARSED_DATES_KEY) {
+                details_text.push_str(&format!("\n⚠️ Unrecognized date format: {fields}"));
+            }
+            let source = self
+                .item_source(platform_id, category, idx)
+                .unwrap_or(platform_id);
+            if platform_id == ALL_PLATFORMS {
+                let name = self
+                    .platform_names
+                    .get(source)
+                    .map_or(source, String::as_str);
+                details_text.push_str(&format!("\nPlatform: {name}"));
+            }
+            if let Some(annotation) = self.annotations.get(source, &selected_item.id) {
+                details_text.push_str(&format!("\nNote: {}", annotation.text));
+            }
 
             let details = Paragraph::new(details_text)
                 .block(Block::default().borders(Borders::ALL).title("Details"))
@@ -716,19 +2535,54 @@ impl MultiPlatformBrowser {
 
             f.render_widget(details, detail_area);
         }
+
+        if let Some(input) = &self.annotation_input {
+            let input_area = ratatui::layout::Rect {
+                y: area.bottom().saturating_sub(3),
+                height: 3.min(area.height),
+                ..area
+            };
+            f.render_widget(Clear, input_area);
+            let input = Paragraph::new(format!("{input}▏"))
+                .style(Style::default().fg(Color::Yellow))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Annotation (Enter: save, empty removes | Esc: cancel)"),
+                );
+            f.render_widget(input, input_area);
+        }
+    }
+
+    /// Render one frame, e.g. onto a `TestBackend`
+    #[cfg(test)]
+    pub fn draw<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
+        terminal.draw(|f| self.ui(f))?;
+        Ok(())
+    }
+
+    /// Handle a key as the event loop would; true means the browser exits
+    #[cfg(test)]
+    pub fn press(&mut self, key: KeyEvent) -> io::Result<bool> {
+        self.handle_key_event(key)
+    }
+
+    /// Handle a mouse event as the event loop would, on the areas of the last drawn frame
+    #[cfg(test)]
+    pub fn mouse(&mut self, mouse: MouseEvent) -> io::Result<()> {
+        self.handle_mouse_event(mouse)
     }
 
     #[cfg(test)]
     pub fn employee_name(&self) -> &str {
-        &self.employee_name
+        &self.employee.name
     }
 
     #[cfg(test)]
     pub fn employee_email(&self) -> &str {
-        &self.employee_email
+        self.employee.committer_email.as_deref().unwrap_or_default()
     }
 
-    #[cfg(test)]
     pub fn platform_names(&self) -> &HashMap<String, String> {
         &self.platform_names
     }
@@ -754,37 +2608,190 @@ impl MultiPlatformBrowser {
     }
 
     #[cfg(test)]
+    pub fn selected_index(&self) -> Option<usize> {
+        self.list_state.selected()
+    }
+
     pub fn platform_activities(&self) -> &HashMap<String, DetailedActivities> {
         &self.platform_activities
     }
 
+    #[cfg(test)]
+    pub fn display_lines(
+        &self,
+        platform_id: &str,
+        category: &ActivityCategory,
+    ) -> Option<&[String]> {
+        self.display_cache
+            .get(&(platform_id.to_string(), category.clone()))
+            .map(Vec::as_slice)
+    }
+
+    #[cfg(test)]
+    pub fn platform_status(&self) -> &HashMap<String, String> {
+        &self.platform_status
+    }
+
     #[cfg(test)]
     pub fn platform_activities_mut(&mut self) -> &mut HashMap<String, DetailedActivities> {
+        self.display_cache.clear();
         &mut self.platform_activities
     }
 
-    #[cfg(test)]
     pub fn platform_order(&self) -> &Vec<String> {
         &self.platform_order
     }
 
+    /// One line of the loading panel: a spinner while the platform is fetched, else its status
+    pub fn loading_line(&self, platform_id: &str) -> String {
+        let icon = self
+            .platform_icons
+            .get(platform_id)
+            .map(String::as_str)
+            .unwrap_or("📄");
+        let name = self
+            .platform_names
+            .get(platform_id)
+            .map(String::as_str)
+            .unwrap_or(platform_id);
+        match self.platform_status.get(platform_id).map(String::as_str) {
+            Some(FETCHING) => format!(
+                "{} {icon} {name} - fetching...",
+                SPINNER[self.spinner_frame % SPINNER.len()]
+            ),
+            Some(status) => format!("{icon} {name} - {status}"),
+            None => format!("{icon} {name} - {QUEUED}"),
+        }
+    }
+
+    fn render_loading_panel(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let done = self
+            .platform_order
+            .iter()
+            .filter(|id| {
+                !matches!(
+                    self.platform_status.get(*id).map(String::as_str),
+                    None | Some(QUEUED) | Some(FETCHING)
+                )
+            })
+            .count();
+        let lines: Vec<Line> = self
+            .platform_order
+            .iter()
+            .map(|id| Line::from(self.loading_line(id)))
+            .collect();
+        let panel =
+            Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(format!(
+                "Loading platform data ({done}/{} done) · Esc: cancel",
+                self.platform_order.len()
+            )));
+        f.render_widget(Clear, area);
+        f.render_widget(panel, area);
+    }
+
+    fn render_status_panel(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let panel_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(100)])
+            .margin(2)
+            .split(area)[0];
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(if self.error_detail.is_some() {
+                [Constraint::Percentage(40), Constraint::Percentage(60)]
+            } else {
+                [Constraint::Percentage(100), Constraint::Length(0)]
+            })
+            .split(panel_area);
+
+        f.render_widget(Clear, panel_area);
+        let items: Vec<ListItem> = self
+            .platform_order
+            .iter()
+            .map(|platform_id| {
+                let line = ListItem::new(self.status_line(platform_id));
+                match self.platform_health.get(platform_id).map(|h| &h.connection) {
+                    Some(ConnectionStatus::Error(_)) => line.style(Style::default().fg(Color::Red)),
+                    Some(ConnectionStatus::Warning(_)) => {
+                        line.style(Style::default().fg(Color::Yellow))
+                    }
+                    _ => line,
+                }
+            })
+            .collect();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Platform Status (Enter/e: last logged error | c/Esc: close)"),
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("▶ ");
+        let mut state = ListState::default().with_selected(Some(self.selected_platform_index));
+        f.render_stateful_widget(list, chunks[0], &mut state);
+
+        if let Some(detail) = &self.error_detail {
+            let detail = Paragraph::new(detail.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Error"))
+                .wrap(Wrap { trim: true });
+            f.render_widget(detail, chunks[1]);
+        }
+    }
+
     fn render_help_overlay(&self, f: &mut Frame, area: ratatui::layout::Rect) {
         let help_text = "📋 Multi-Platform Review Browser Help
 
 NAVIGATION:
   ↑/↓         Navigate through lists
-  Enter       Select item / View details / Open in browser
+  Enter       Select item / View details / Open in browser / Show why a
+              category failed to load
   q/Esc       Go back to previous view (or quit from summary)
+  Esc         While platforms load: cancel the fetches still running
   Backspace   Go back to previous view
   Tab         Switch between platforms (in summary)
   Shift+Tab   Switch platforms backwards
 
+MOUSE:
+  Click       Select a row or a platform tab
+  Double/middle click  Like Enter on the clicked row
+  Wheel       Move the selection
+
 VIEWS:
   s           Go to Summary view
+  c           Platform status: connection, item count, fetch time and errors
+  g           Weekly activity trends per platform and category
+  v           Items per category against the previous period of the same length,
+              with the biggest changes listed as talking points
+  🌐          All platforms (last summary entry): changes, reviews and issues of every
+              platform in one newest-first list, each item badged with its platform
   h/?         Show/hide this help
 
+PERIOD:
+  1/3/6/y     Re-query the last 30/90/180/365 days
+  r           Refetch every platform in the background, keeping the data shown until
+              the new data is in (also every auto_refresh_minutes when configured)
+  t           Toggle relative timestamps (\"3 days ago\")
+
+CATEGORY VIEW:
+  a           Add or edit a note on the selected item (📝), kept across sessions
+  n           Add the selected item (title, link, status and date) as an evidence bullet to
+              the employee's notes, under today's entry
+  d           Load the votes, reviewers and review messages of a Gerrit change, or the
+              remaining fields of an item fetched with JIRA lazy_details (only on
+              platforms that report comments)
+  1-9         Jump to the platform's Nth category (instead of changing the period)
+  C/U         Sort items by created / updated date, newest first
+  S/P         Sort items by status / project
+  o           Reverse the item order
+
+SORTING (summary and platform view):
+  o           Sort categories by name, count or change (summary: against the previous
+              period, platform view: since the last fetch)
+  O           Reverse the sort direction
+
 FEATURES:
-  • Summary: Overview of all configured platforms
+  • Summary: Overview of all configured platforms, with items per category across
+    all of them
   • Platform View: Browse categories within a platform
   • Category View: View specific items (changes, tickets, etc.)
   • Open items directly in your web browser
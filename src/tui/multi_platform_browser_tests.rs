@@ -1,7 +1,11 @@
+use crate::core::models::Employee;
 use crate::core::platform::{
     ActivityCategory, ActivityItem, ActivityMetrics, ConnectionStatus, DetailedActivities,
-    PlatformRegistry, ReviewPlatform,
+    PlatformCapabilities, PlatformRegistry, ReviewPlatform,
 };
+use crate::core::test_support::ItemBuilder;
+use crate::core::timestamps::{DisplayZone, TimestampFormat};
+use crate::tui::metrics_table::{MetricsSort, SortColumn};
 use crate::tui::{MultiPlatformBrowser, multi_platform_browser::ViewMode};
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -15,6 +19,9 @@ pub struct MockPlatform {
     configured: bool,
     activities: DetailedActivities,
     metrics: ActivityMetrics,
+    capabilities: PlatformCapabilities,
+    failure: Option<String>, // error returned by every fetch
+    hangs: bool,             // fetches never finish, e.g. to cancel them
 }
 
 impl MockPlatform {
@@ -26,6 +33,9 @@ impl MockPlatform {
             configured: true,
             activities: Self::create_gerrit_test_data(),
             metrics: Self::create_gerrit_metrics(),
+            capabilities: PlatformCapabilities::default(),
+            failure: None,
+            hangs: false,
         }
     }
 
@@ -37,6 +47,13 @@ impl MockPlatform {
             configured: true,
             activities: Self::create_jira_test_data(),
             metrics: Self::create_jira_metrics(),
+            // A limit of one makes every non-empty JIRA category look truncated
+            capabilities: PlatformCapabilities {
+                supports_comments: true,
+                max_items: Some(1),
+            },
+            failure: None,
+            hangs: false,
         }
     }
 
@@ -48,37 +65,86 @@ impl MockPlatform {
             configured: false,
             activities: DetailedActivities::default(),
             metrics: ActivityMetrics::default(),
+            capabilities: PlatformCapabilities::default(),
+            failure: None,
+            hangs: false,
         }
     }
 
-    fn create_gerrit_test_data() -> DetailedActivities {
-        let mut activities = DetailedActivities::default();
+    /// A configured JIRA whose fetches fail with `error`
+    pub fn new_failing_jira(error: &str) -> Self {
+        Self {
+            failure: Some(error.to_string()),
+            ..Self::new_jira()
+        }
+    }
 
-        let merged_change = ActivityItem {
-            id: "12345".to_string(),
-            title: "Fix critical bug in authentication module".to_string(),
-            status: "MERGED".to_string(),
-            created: "2024-01-15T10:30:00Z".to_string(),
-            updated: "2024-01-15T16:45:00Z".to_string(),
-            url: "https://gerrit.example.com/c/project/+/12345".to_string(),
-            platform: "gerrit".to_string(),
-            category: ActivityCategory::ChangesMerged,
-            project: "auth-service".to_string(),
-            metadata: HashMap::new(),
-        };
+    /// A configured JIRA whose fetches never finish
+    pub fn new_hanging_jira() -> Self {
+        Self {
+            hangs: true,
+            ..Self::new_jira()
+        }
+    }
 
-        let created_change = ActivityItem {
-            id: "12346".to_string(),
-            title: "Add comprehensive unit tests for user service".to_string(),
-            status: "NEW".to_string(),
-            created: "2024-01-16T09:15:00Z".to_string(),
-            updated: "2024-01-16T14:20:00Z".to_string(),
-            url: "https://gerrit.example.com/c/project/+/12346".to_string(),
-            platform: "gerrit".to_string(),
-            category: ActivityCategory::ChangesCreated,
-            project: "user-service".to_string(),
-            metadata: HashMap::new(),
+    /// A GitLab instance with one merge request opened after and one before Gerrit's change
+    pub fn new_gitlab() -> Self {
+        let merge_request = |id: &str, updated: &str| {
+            ItemBuilder::new(id)
+                .with_title(&format!("Merge request {id}"))
+                .with_status("opened")
+                .with_created(updated)
+                .with_updated(updated)
+                .with_url(&format!(
+                    "https://gitlab.example.com/web/-/merge_requests/{id}"
+                ))
+                .with_platform("GitLab")
+                .with_category(ActivityCategory::MergeRequestsCreated)
+                .with_project("web")
+                .build()
         };
+        let mut activities = DetailedActivities::default();
+        activities.items_by_category.insert(
+            ActivityCategory::MergeRequestsCreated,
+            vec![
+                merge_request("!7", "2024-01-17T08:00:00Z"),
+                merge_request("!3", "2024-01-10T08:00:00Z"),
+            ],
+        );
+        Self {
+            platform_id: "gitlab".to_string(),
+            platform_name: "GitLab".to_string(),
+            platform_icon: "🦊".to_string(),
+            configured: true,
+            activities,
+            metrics: ActivityMetrics::default(),
+            capabilities: PlatformCapabilities::default(),
+            failure: None,
+            hangs: false,
+        }
+    }
+
+    pub fn create_gerrit_test_data() -> DetailedActivities {
+        let mut activities = DetailedActivities::default();
+
+        let merged_change = ItemBuilder::new("12345")
+            .with_title("Fix critical bug in authentication module")
+            .with_created("2024-01-15T10:30:00Z")
+            .with_updated("2024-01-15T16:45:00Z")
+            .with_url("https://gerrit.example.com/c/project/+/12345")
+            .with_category(ActivityCategory::ChangesMerged)
+            .with_project("auth-service")
+            .build();
+
+        let created_change = ItemBuilder::new("12346")
+            .with_title("Add comprehensive unit tests for user service")
+            .with_status("NEW")
+            .with_created("2024-01-16T09:15:00Z")
+            .with_updated("2024-01-16T14:20:00Z")
+            .with_url("https://gerrit.example.com/c/project/+/12346")
+            .with_category(ActivityCategory::ChangesCreated)
+            .with_project("user-service")
+            .build();
 
         activities
             .items_by_category
@@ -93,31 +159,27 @@ impl MockPlatform {
     fn create_jira_test_data() -> DetailedActivities {
         let mut activities = DetailedActivities::default();
 
-        let resolved_issue = ActivityItem {
-            id: "PROJ-123".to_string(),
-            title: "Implement OAuth2 integration for third-party services".to_string(),
-            status: "Done".to_string(),
-            created: "2024-01-10T08:00:00Z".to_string(),
-            updated: "2024-01-14T17:30:00Z".to_string(),
-            url: "https://jira.example.com/browse/PROJ-123".to_string(),
-            platform: "jira".to_string(),
-            category: ActivityCategory::IssuesResolved,
-            project: "PROJ".to_string(),
-            metadata: HashMap::new(),
-        };
-
-        let assigned_issue = ActivityItem {
-            id: "PROJ-124".to_string(),
-            title: "Investigate performance issues in search functionality".to_string(),
-            status: "In Progress".to_string(),
-            created: "2024-01-12T11:30:00Z".to_string(),
-            updated: "2024-01-16T15:45:00Z".to_string(),
-            url: "https://jira.example.com/browse/PROJ-124".to_string(),
-            platform: "jira".to_string(),
-            category: ActivityCategory::IssuesAssigned,
-            project: "PROJ".to_string(),
-            metadata: HashMap::new(),
-        };
+        let resolved_issue = ItemBuilder::new("PROJ-123")
+            .with_title("Implement OAuth2 integration for third-party services")
+            .with_status("Done")
+            .with_created("2024-01-10T08:00:00Z")
+            .with_updated("2024-01-14T17:30:00Z")
+            .with_url("https://jira.example.com/browse/PROJ-123")
+            .with_platform("jira")
+            .with_category(ActivityCategory::IssuesResolved)
+            .with_project("PROJ")
+            .build();
+
+        let assigned_issue = ItemBuilder::new("PROJ-124")
+            .with_title("Investigate performance issues in search functionality")
+            .with_status("In Progress")
+            .with_created("2024-01-12T11:30:00Z")
+            .with_updated("2024-01-16T15:45:00Z")
+            .with_url("https://jira.example.com/browse/PROJ-124")
+            .with_platform("jira")
+            .with_category(ActivityCategory::IssuesAssigned)
+            .with_project("PROJ")
+            .build();
 
         activities
             .items_by_category
@@ -130,8 +192,10 @@ impl MockPlatform {
     }
 
     fn create_gerrit_metrics() -> ActivityMetrics {
-        let mut metrics = ActivityMetrics::default();
-        metrics.total_items = 8;
+        let mut metrics = ActivityMetrics {
+            total_items: 8,
+            ..Default::default()
+        };
         metrics
             .items_by_category
             .insert(ActivityCategory::ChangesCreated, 3);
@@ -148,8 +212,10 @@ impl MockPlatform {
     }
 
     fn create_jira_metrics() -> ActivityMetrics {
-        let mut metrics = ActivityMetrics::default();
-        metrics.total_items = 6;
+        let mut metrics = ActivityMetrics {
+            total_items: 6,
+            ..Default::default()
+        };
         metrics
             .items_by_category
             .insert(ActivityCategory::IssuesCreated, 2);
@@ -168,8 +234,15 @@ impl MockPlatform {
 
 #[async_trait]
 impl ReviewPlatform for MockPlatform {
-    async fn get_activity_metrics(&self, _user: &str, _days: u32) -> io::Result<ActivityMetrics> {
-        Ok(self.metrics.clone())
+    /// The metrics per 30 days, repeated for longer periods
+    async fn get_activity_metrics(&self, _user: &str, days: u32) -> io::Result<ActivityMetrics> {
+        let months = days.div_ceil(30).max(1);
+        let mut metrics = self.metrics.clone();
+        metrics.total_items *= months;
+        for count in metrics.items_by_category.values_mut() {
+            *count *= months;
+        }
+        Ok(metrics)
     }
 
     async fn get_detailed_activities(
@@ -177,6 +250,12 @@ impl ReviewPlatform for MockPlatform {
         _user: &str,
         _days: u32,
     ) -> io::Result<DetailedActivities> {
+        if let Some(error) = &self.failure {
+            return Err(io::Error::other(error.clone()));
+        }
+        if self.hangs {
+            std::future::pending::<()>().await;
+        }
         Ok(self.activities.clone())
     }
 
@@ -208,6 +287,10 @@ impl ReviewPlatform for MockPlatform {
         self.configured
     }
 
+    fn get_capabilities(&self) -> PlatformCapabilities {
+        self.capabilities
+    }
+
     async fn test_connection(&self) -> io::Result<ConnectionStatus> {
         if self.configured {
             Ok(ConnectionStatus::Connected)
@@ -323,6 +406,109 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_summary_size_buckets() {
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data(&registry).await.unwrap();
+        assert_eq!(
+            browser.platform_summary("gerrit"),
+            "🔧 Gerrit - 2 items across 2 categories"
+        );
+
+        browser.set_size_buckets(true);
+        let created = browser
+            .platform_activities_mut()
+            .get_mut("gerrit")
+            .unwrap()
+            .items_by_category
+            .get_mut(&ActivityCategory::ChangesCreated)
+            .unwrap();
+        created[0]
+            .metadata
+            .insert("insertions".to_string(), "40".to_string());
+        created[0]
+            .metadata
+            .insert("deletions".to_string(), "2".to_string());
+        assert_eq!(
+            browser.platform_summary("gerrit"),
+            "🔧 Gerrit - 2 items across 2 categories - code volume +40 -2 in 1 change - sizes XS 0 · S 1 · M 0 · L 0"
+        );
+        // JIRA has no authored changes to bucket
+        assert_eq!(
+            browser.platform_summary("jira"),
+            "🎫 JIRA - 2 items across 2 categories"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_summary_metrics_compare_previous_period() {
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data(&registry).await.unwrap();
+
+        // One item per category now against the mocks' monthly counts before
+        let rows: Vec<(String, usize, Option<i64>)> = browser
+            .summary_metrics()
+            .into_iter()
+            .map(|row| (row.label, row.count, row.delta))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("Changes Created".to_string(), 1, Some(-2)),
+                ("Changes Merged".to_string(), 1, Some(-1)),
+                ("Issues Assigned".to_string(), 1, Some(0)),
+                ("Issues Resolved".to_string(), 1, Some(-1)),
+                ("Total".to_string(), 4, Some(-4)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_comparison_view() {
+        use crate::tui::test_terminal::{key, screen, terminal};
+        use crossterm::event::KeyCode;
+
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data(&registry).await.unwrap();
+
+        browser.press(key(KeyCode::Char('v'))).unwrap();
+        assert_eq!(browser.breadcrumb(), "Summary ▸ Comparison");
+        let mut terminal = terminal(100, 30);
+        browser.draw(&mut terminal).unwrap();
+        let shown = screen(&terminal);
+        assert!(
+            shown.contains("Last 30 days vs the 30 days before"),
+            "{shown}"
+        );
+        assert!(shown.contains("Changes Created"), "{shown}");
+        // The biggest change is the first talking point
+        let created = shown
+            .find("• Changes Created: 1, ↓2 vs prior 30 days")
+            .expect(&shown);
+        let assigned = shown
+            .find("• Issues Assigned: 1, = vs prior 30 days")
+            .expect(&shown);
+        assert!(created < assigned);
+
+        browser.press(key(KeyCode::Backspace)).unwrap();
+        assert_eq!(browser.breadcrumb(), "Summary");
+    }
+
     #[test]
     fn test_platform_navigation() {
         let registry = create_test_registry();
@@ -335,9 +521,11 @@ mod tests {
         // Should start with first platform selected
         assert_eq!(browser.selected_platform_index(), 0);
 
-        // Test next platform navigation
+        // Test next platform navigation; "All platforms" follows the two platforms
         browser.next_platform();
         assert_eq!(browser.selected_platform_index(), 1);
+        browser.next_platform();
+        assert_eq!(browser.selected_platform_index(), 2);
 
         // Should wrap around
         browser.next_platform();
@@ -345,10 +533,10 @@ mod tests {
 
         // Test previous platform navigation
         browser.prev_platform();
-        assert_eq!(browser.selected_platform_index(), 1);
+        assert_eq!(browser.selected_platform_index(), 2);
 
         browser.prev_platform();
-        assert_eq!(browser.selected_platform_index(), 0);
+        assert_eq!(browser.selected_platform_index(), 1);
     }
 
     #[test]
@@ -429,6 +617,65 @@ mod tests {
         assert!(empty_categories.is_empty());
     }
 
+    #[test]
+    fn test_category_metrics_sorting_and_delta() {
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        let activities = |merged: usize, reviews: usize| {
+            let mut activities = DetailedActivities::default();
+            for (category, count) in [
+                (ActivityCategory::ChangesMerged, merged),
+                (ActivityCategory::ReviewsGiven, reviews),
+            ] {
+                let items = (0..count)
+                    .map(|id| {
+                        ItemBuilder::new(&id.to_string())
+                            .with_category(category.clone())
+                            .build()
+                    })
+                    .collect();
+                activities.items_by_category.insert(category, items);
+            }
+            activities
+        };
+        browser
+            .platform_activities_mut()
+            .insert("gerrit".to_string(), activities(2, 5));
+
+        // Alphabetical by default, without a delta until an earlier fetch is known
+        let metrics = browser.category_metrics("gerrit");
+        assert_eq!(metrics[0].0, ActivityCategory::ChangesMerged);
+        assert_eq!(metrics[0].1.delta, None);
+
+        browser.set_previous_activities("gerrit", &activities(3, 1));
+        let metrics = browser.category_metrics("gerrit");
+        assert_eq!(metrics[0].1.delta, Some(-1));
+        assert_eq!(metrics[1].1.delta, Some(4));
+
+        // Sorting by count keeps the selected category selected
+        browser.push_view(ViewMode::PlatformView {
+            platform_id: "gerrit".to_string(),
+        });
+        assert_eq!(
+            browser.get_available_categories("gerrit")[0],
+            ActivityCategory::ChangesMerged
+        );
+        browser.sort_metrics(MetricsSort::cycle_column);
+        assert_eq!(browser.metrics_sort().column, SortColumn::Count);
+        assert_eq!(
+            browser.get_available_categories("gerrit"),
+            vec![
+                ActivityCategory::ReviewsGiven,
+                ActivityCategory::ChangesMerged
+            ]
+        );
+        assert_eq!(browser.selected_index(), Some(1));
+    }
+
     #[test]
     fn test_category_items() {
         let registry = create_test_registry();
@@ -537,4 +784,1208 @@ mod tests {
         );
         assert!(browser.platform_icons().get("jira").is_none()); // Unconfigured platform not included
     }
+
+    #[test]
+    fn test_view_stack_push_and_pop() {
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+
+        browser.push_view(ViewMode::PlatformView {
+            platform_id: "gerrit".to_string(),
+        });
+        browser.push_view(ViewMode::CategoryView {
+            platform_id: "gerrit".to_string(),
+            category: ActivityCategory::ChangesMerged,
+        });
+        assert_eq!(browser.breadcrumb(), "Summary ▸ Gerrit ▸ Changes Merged");
+
+        // Popping walks back through the stack one view at a time
+        assert!(browser.pop_view());
+        assert!(matches!(
+            browser.current_view(),
+            ViewMode::PlatformView { .. }
+        ));
+        assert_eq!(browser.breadcrumb(), "Summary ▸ Gerrit");
+
+        assert!(browser.pop_view());
+        assert!(matches!(browser.current_view(), ViewMode::Summary));
+        assert_eq!(browser.breadcrumb(), "Summary");
+
+        // Nothing left to pop at the root
+        assert!(!browser.pop_view());
+    }
+
+    #[test]
+    fn test_pop_to_summary_selects_the_platform() {
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        assert_eq!(browser.selected_index(), None);
+
+        // The summary was left without a list selection, then the tabs moved to another platform
+        browser.push_view(ViewMode::PlatformView {
+            platform_id: "gerrit".to_string(),
+        });
+        browser.next_platform();
+        assert!(browser.pop_view());
+        assert!(matches!(browser.current_view(), ViewMode::Summary));
+        assert_eq!(browser.selected_index(), Some(1));
+        assert_eq!(browser.selected_platform_index(), 1);
+
+        browser.push_view(ViewMode::PlatformView {
+            platform_id: "jira".to_string(),
+        });
+        browser.reset_to_summary();
+        assert_eq!(browser.selected_index(), Some(1));
+    }
+
+    #[test]
+    fn test_reset_to_summary_clears_stack() {
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+
+        browser.push_view(ViewMode::PlatformView {
+            platform_id: "jira".to_string(),
+        });
+        browser.push_view(ViewMode::CategoryView {
+            platform_id: "jira".to_string(),
+            category: ActivityCategory::IssuesResolved,
+        });
+
+        browser.reset_to_summary();
+        assert!(matches!(browser.current_view(), ViewMode::Summary));
+        assert_eq!(browser.breadcrumb(), "Summary");
+        assert!(!browser.pop_view());
+    }
+
+    #[test]
+    fn test_open_platform_from_dashboard() {
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+
+        browser.open_platform("jira");
+        assert_eq!(browser.breadcrumb(), "Summary ▸ JIRA");
+        let jira = browser
+            .summary_entries()
+            .iter()
+            .position(|id| id == "jira")
+            .unwrap();
+        assert_eq!(browser.selected_platform_index(), jira);
+
+        // Back on the summary with JIRA still selected; unknown platforms are ignored
+        assert!(browser.pop_view());
+        assert_eq!(browser.selected_index(), Some(jira));
+        browser.open_platform("gitlab");
+        assert!(matches!(browser.current_view(), ViewMode::Summary));
+    }
+
+    #[tokio::test]
+    async fn test_details_key_follows_comment_support() {
+        use crate::core::platform::DETAILS_PENDING_KEY;
+        use crate::tui::test_terminal::{key, screen, terminal};
+        use crossterm::event::KeyCode;
+
+        // The mock Gerrit reports no comments, unlike the mock JIRA
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data(&registry).await.unwrap();
+        let category = ActivityCategory::ChangesMerged;
+        let mut item = browser.get_category_items("gerrit", &category)[0].clone();
+        item.metadata
+            .insert(DETAILS_PENDING_KEY.to_string(), "true".to_string());
+        browser.apply_item_details("gerrit", item);
+        browser.push_view(ViewMode::CategoryView {
+            platform_id: "gerrit".to_string(),
+            category,
+        });
+
+        let mut terminal = terminal(200, 40);
+        browser.draw(&mut terminal).unwrap();
+        let shown = screen(&terminal);
+        assert!(!shown.contains("Press d"));
+        assert!(!shown.contains("d: Details"));
+        browser.press(key(KeyCode::Char('d'))).unwrap();
+        browser.draw(&mut terminal).unwrap();
+        assert!(!screen(&terminal).contains("Loading details"));
+
+        browser.push_view(ViewMode::CategoryView {
+            platform_id: "jira".to_string(),
+            category: ActivityCategory::IssuesResolved,
+        });
+        browser.draw(&mut terminal).unwrap();
+        assert!(screen(&terminal).contains("d: Details"));
+    }
+
+    #[tokio::test]
+    async fn test_category_truncation_uses_max_items() {
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data(&registry).await.unwrap();
+
+        assert!(browser.is_category_truncated("jira", &ActivityCategory::IssuesResolved));
+        assert!(!browser.is_category_truncated("jira", &ActivityCategory::CommitsPushed));
+        assert!(!browser.is_category_truncated("gerrit", &ActivityCategory::ChangesMerged));
+    }
+
+    #[tokio::test]
+    async fn test_display_lines_precomputed_on_load() {
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        assert!(
+            browser
+                .display_lines("gerrit", &ActivityCategory::ChangesMerged)
+                .is_none()
+        );
+
+        browser.set_timestamp_format(TimestampFormat::new(DisplayZone::Utc, false));
+        browser.load_data(&registry).await.unwrap();
+
+        assert_eq!(
+            browser.display_lines("gerrit", &ActivityCategory::ChangesMerged),
+            Some(
+                &["[12345] Fix critical bug in authentication module - auth-service · 2024-01-15 16:45 UTC".to_string()][..]
+            )
+        );
+
+        // Switching to relative timestamps rebuilds the lines
+        browser.toggle_relative_timestamps();
+        assert!(
+            browser
+                .display_lines("gerrit", &ActivityCategory::ChangesMerged)
+                .is_none()
+        );
+
+        // Mutating the data drops the memoized lines
+        browser.platform_activities_mut();
+        assert!(
+            browser
+                .display_lines("gerrit", &ActivityCategory::ChangesMerged)
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_data_async_uses_activity_cache() {
+        use crate::core::cache::ActivityCache;
+        use crate::core::models::DataPath;
+        use std::time::Duration;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = ActivityCache::new(&data_path, Duration::from_secs(3600));
+        // A cached (empty) Gerrit result must be used instead of the mock's data
+        cache
+            .store("John Doe", "gerrit", 30, &DetailedActivities::default())
+            .unwrap();
+
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.set_activity_cache(cache.clone());
+        browser.load_data_async(&registry).await.unwrap();
+
+        assert!(
+            browser.platform_activities()["gerrit"]
+                .items_by_category
+                .is_empty()
+        );
+        assert!(
+            !browser.platform_activities()["jira"]
+                .items_by_category
+                .is_empty()
+        );
+        // Freshly fetched platforms are written back to the cache
+        assert!(cache.load("John Doe", "jira", 30).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_loading_streams_results_and_esc_cancels() {
+        use crate::tui::test_terminal::{key, screen, terminal};
+        use crossterm::event::KeyCode;
+
+        let mut registry = PlatformRegistry::new();
+        registry.register_platform(Box::new(MockPlatform::new_gerrit()));
+        registry.register_platform(Box::new(MockPlatform::new_hanging_jira()));
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        // Platforms over the concurrency limit wait for a free slot
+        browser.set_max_concurrent_platforms(1);
+        let mut load = browser.start_load(&registry);
+        let mut statuses: Vec<&str> = browser
+            .platform_status()
+            .values()
+            .map(String::as_str)
+            .collect();
+        statuses.sort();
+        assert_eq!(statuses, vec!["⏳ Queued", "🔄 Fetching..."]);
+        browser.cancel_load(&mut load);
+        assert!(
+            browser
+                .platform_status()
+                .values()
+                .all(|status| status == "⏹ Cancelled")
+        );
+
+        // Gerrit shows up while JIRA is still being fetched
+        browser.set_max_concurrent_platforms(2);
+        let mut load = browser.start_load(&registry);
+        while !browser.platform_activities().contains_key("gerrit") {
+            assert!(browser.next_fetch(&mut load).await);
+        }
+        assert_eq!(browser.platform_status()["jira"], "🔄 Fetching...");
+        let mut terminal = terminal(100, 30);
+        browser.draw(&mut terminal).unwrap();
+        let screen = screen(&terminal);
+        assert!(screen.contains("Loading platform data (1/2 done)"));
+        assert!(screen.contains("Gerrit - "));
+        assert_eq!(browser.loading_line("gerrit"), "🔧 Gerrit - ✅ 2 items");
+        assert!(
+            browser
+                .loading_line("jira")
+                .ends_with("🎫 JIRA - fetching...")
+        );
+
+        // Esc aborts the hanging fetch and keeps what was loaded
+        assert!(
+            !browser
+                .handle_loading_key(key(KeyCode::Esc), &mut load)
+                .unwrap()
+        );
+        assert_eq!(browser.platform_status()["jira"], "⏹ Cancelled");
+        assert!(!browser.next_fetch(&mut load).await);
+        assert!(browser.platform_activities().contains_key("gerrit"));
+        assert!(!browser.platform_activities().contains_key("jira"));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_keeps_data_until_refetched() {
+        use crate::core::cache::ActivityCache;
+        use crate::core::models::DataPath;
+        use crate::tui::multi_platform_browser::ALL_PLATFORMS;
+        use crate::tui::test_terminal::{key, screen, terminal};
+        use crossterm::event::KeyCode;
+        use std::time::Duration;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = ActivityCache::new(&data_path, Duration::from_secs(3600));
+        cache
+            .store("John Doe", "gerrit", 30, &DetailedActivities::default())
+            .unwrap();
+        let mut registry = PlatformRegistry::new();
+        registry.register_platform(Box::new(MockPlatform::new_gerrit()));
+        registry.register_platform(Box::new(MockPlatform::new_jira()));
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.set_activity_cache(cache);
+        browser.load_data_async(&registry).await.unwrap();
+        assert!(browser.platform_summary("gerrit").contains("0 items"));
+        let now = chrono::Local::now();
+        assert_eq!(
+            browser.last_updated("jira", now).as_deref(),
+            Some("updated just now")
+        );
+
+        // `r` refetches in the background, past the fresh cache entry
+        browser.press(key(KeyCode::Char('r'))).unwrap();
+        let mut refresh = browser.start_refresh(&registry);
+        assert!(browser.is_refreshing());
+        assert!(browser.platform_summary("gerrit").contains("0 items"));
+        let mut terminal = terminal(140, 30);
+        browser.draw(&mut terminal).unwrap();
+        let summary = screen(&terminal);
+        assert!(summary.contains("↻ refreshing"), "{summary}");
+        assert!(!summary.contains("Loading platform data"));
+        while browser.next_fetch(&mut refresh).await {}
+        assert!(!browser.is_refreshing());
+        assert!(browser.platform_summary("gerrit").contains("2 items"));
+        assert_eq!(
+            browser
+                .last_updated(ALL_PLATFORMS, chrono::Local::now())
+                .as_deref(),
+            Some("updated just now")
+        );
+
+        // A failed refresh leaves the earlier data, and when it was fetched, on screen
+        let mut failing = PlatformRegistry::new();
+        failing.register_platform(Box::new(MockPlatform::new_failing_jira("HTTP 503")));
+        let mut refresh = browser.start_refresh(&failing);
+        while browser.next_fetch(&mut refresh).await {}
+        assert!(browser.platform_activities().contains_key("jira"));
+        let jira_line = browser.status_line("jira");
+        assert!(
+            jira_line.starts_with("❌ 🎫 JIRA - HTTP 503 · "),
+            "{jira_line}"
+        );
+        assert!(jira_line.contains(" · fetched "), "{jira_line}");
+    }
+
+    #[tokio::test]
+    async fn test_offline_mode_uses_snapshots_only() {
+        use crate::core::cache::ActivityCache;
+        use crate::core::models::DataPath;
+        use std::time::Duration;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache = ActivityCache::new(&data_path, Duration::from_secs(3600));
+        cache
+            .store(
+                "John Doe",
+                "gerrit",
+                30,
+                &MockPlatform::new_gerrit().activities,
+            )
+            .unwrap();
+
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        // Expired entries still count as snapshots
+        browser.set_activity_cache(ActivityCache::new(&data_path, Duration::ZERO));
+        browser.set_offline(true);
+        browser.load_data_async(&registry).await.unwrap();
+
+        assert!(browser.platform_activities().contains_key("gerrit"));
+        // JIRA was never fetched, and offline mode must not query it
+        assert!(!browser.platform_activities().contains_key("jira"));
+        assert_eq!(browser.platform_status()["jira"], "📴 No offline snapshot");
+        let header = browser.header_text();
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        assert!(header.contains(&format!("📴 offline snapshot from {today}")));
+    }
+
+    #[test]
+    fn test_period_switch_requests_requery() {
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.set_days(30);
+
+        // Selecting the current period is a no-op
+        assert!(!browser.request_period(30));
+
+        browser.push_view(ViewMode::PlatformView {
+            platform_id: "gerrit".to_string(),
+        });
+        assert!(browser.request_period(90));
+        assert!(matches!(browser.current_view(), ViewMode::Summary));
+        assert_eq!(browser.days(), 30);
+    }
+
+    #[test]
+    fn test_header_shows_profile_summary() {
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        assert_eq!(browser.header_text().lines().count(), 2);
+
+        browser.set_profile_summary(Some("Platform · manager Ann Lee".to_string()));
+        let header = browser.header_text();
+        assert!(header.starts_with("📋 John Doe (john.doe@example.com)"));
+        assert_eq!(header.lines().nth(1), Some("Platform · manager Ann Lee"));
+    }
+
+    #[tokio::test]
+    async fn test_activity_trends_by_week() {
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.set_days(28);
+        browser.load_data(&registry).await.unwrap();
+
+        let now = chrono::DateTime::parse_from_rfc3339("2024-01-20T12:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let trends = browser.activity_trends(now);
+        assert_eq!(trends.total.weeks, vec![0, 0, 2, 2]);
+
+        let gerrit = trends
+            .by_platform
+            .iter()
+            .find(|series| series.label == "Gerrit")
+            .unwrap();
+        assert_eq!(gerrit.weeks, vec![0, 0, 0, 2]);
+        assert_eq!(trends.by_category.len(), 4);
+        assert!(trends.by_category.iter().all(|series| series.total() == 1));
+
+        // Review comments left add up per week in a series of their own
+        let mut reviewed =
+            browser.get_category_items("gerrit", &ActivityCategory::ChangesMerged)[0].clone();
+        reviewed
+            .metadata
+            .insert("comments".to_string(), "3".to_string());
+        browser.apply_item_details("gerrit", reviewed);
+        let trends = browser.activity_trends(now);
+        assert_eq!(trends.by_category[0].label, "Review comments left");
+        assert_eq!(trends.by_category[0].weeks, vec![0, 0, 0, 3]);
+
+        browser.push_view(ViewMode::TrendsView);
+        assert_eq!(browser.breadcrumb(), "Summary ▸ Trends");
+    }
+
+    #[tokio::test]
+    async fn test_item_annotations_persist() {
+        use crate::core::annotations::AnnotationStore;
+        use crate::core::models::DataPath;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let registry = create_test_registry();
+        let category_view = ViewMode::CategoryView {
+            platform_id: "gerrit".to_string(),
+            category: ActivityCategory::ChangesMerged,
+        };
+
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.set_annotation_store(AnnotationStore::load(&data_path));
+        browser.load_data(&registry).await.unwrap();
+
+        // Nothing to annotate outside the category view
+        browser.start_annotation();
+        assert!(browser.annotation_input_mut().is_none());
+
+        browser.push_view(category_view.clone());
+        browser.start_annotation();
+        browser
+            .annotation_input_mut()
+            .unwrap()
+            .push_str("needed 4 rounds");
+        browser.save_annotation().unwrap();
+        assert!(browser.annotation_input_mut().is_none());
+        assert_eq!(
+            browser.annotations().get("gerrit", "12345").unwrap().text,
+            "needed 4 rounds"
+        );
+
+        // A new session shows the stored annotation again
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.set_annotation_store(AnnotationStore::load(&data_path));
+        browser.load_data(&registry).await.unwrap();
+        browser.push_view(category_view);
+        browser.start_annotation();
+        assert_eq!(
+            browser.annotation_input_mut().map(|text| text.as_str()),
+            Some("needed 4 rounds")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_item_to_notes() {
+        use crate::core::models::DataPath;
+        use crate::tui::test_terminal::{key, screen, terminal};
+        use crossterm::event::KeyCode;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        std::fs::create_dir_all(&data_path.notes_dir).unwrap();
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.set_notes_path(data_path.clone());
+        browser.load_data(&registry).await.unwrap();
+
+        // Only items can be added
+        browser.press(key(KeyCode::Char('n'))).unwrap();
+        assert!(!data_path.notes_dir.join("John Doe.md").exists());
+
+        browser.push_view(ViewMode::CategoryView {
+            platform_id: "gerrit".to_string(),
+            category: ActivityCategory::ChangesMerged,
+        });
+        browser.press(key(KeyCode::Char('n'))).unwrap();
+        let notes = std::fs::read_to_string(data_path.notes_dir.join("John Doe.md")).unwrap();
+        assert!(notes.starts_with("# Notes for John Doe\n\n## "), "{notes}");
+        assert!(
+            notes.contains(
+                "- Evidence: [Fix critical bug in authentication module](https://gerrit.example.com/c/project/+/12345) — auth-service (MERGED, updated 2024-01-15"
+            ),
+            "{notes}"
+        );
+        let mut terminal = terminal(160, 30);
+        browser.draw(&mut terminal).unwrap();
+        assert!(screen(&terminal).contains("Added 12345 to the notes of John Doe"));
+
+        browser.press(key(KeyCode::Char('n'))).unwrap();
+        browser.draw(&mut terminal).unwrap();
+        assert!(screen(&terminal).contains("12345 is already in the notes of John Doe"));
+        assert_eq!(
+            std::fs::read_to_string(data_path.notes_dir.join("John Doe.md")).unwrap(),
+            notes
+        );
+    }
+
+    #[tokio::test]
+    async fn test_all_platforms_combines_equivalent_categories() {
+        use crate::core::annotations::AnnotationStore;
+        use crate::core::models::DataPath;
+        use crate::tui::item_sort::{ItemSort, ItemSortField};
+        use crate::tui::multi_platform_browser::ALL_PLATFORMS;
+        use crate::tui::test_terminal::key;
+        use crossterm::event::KeyCode;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let mut registry = create_test_registry();
+        registry.register_platform(Box::new(MockPlatform::new_gitlab()));
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.set_annotation_store(AnnotationStore::load(&data_path));
+        browser.load_data(&registry).await.unwrap();
+
+        assert_eq!(browser.summary_entries().last().unwrap(), ALL_PLATFORMS);
+        assert_eq!(
+            browser.platform_summary(ALL_PLATFORMS),
+            "🌐 All platforms - 6 items across 4 categories"
+        );
+
+        // Gerrit changes and GitLab merge requests form one list, newest first
+        let created = browser.get_category_items(ALL_PLATFORMS, &ActivityCategory::ChangesCreated);
+        let ids: Vec<&str> = created.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, vec!["!7", "12346", "!3"]);
+        let lines = browser
+            .display_lines(ALL_PLATFORMS, &ActivityCategory::ChangesCreated)
+            .unwrap();
+        assert!(lines[0].starts_with("🦊 [!7] Merge request !7"));
+        assert!(lines[1].starts_with("🔧 [12346]"));
+
+        // Enter on the last summary entry opens the combined categories
+        browser.prev_platform();
+        browser.press(key(KeyCode::Enter)).unwrap();
+        assert!(matches!(
+            browser.current_view(),
+            ViewMode::PlatformView { platform_id } if platform_id == ALL_PLATFORMS
+        ));
+        assert_eq!(browser.breadcrumb(), "Summary ▸ All platforms");
+        browser.push_view(ViewMode::CategoryView {
+            platform_id: ALL_PLATFORMS.to_string(),
+            category: ActivityCategory::ChangesCreated,
+        });
+
+        // Annotations belong to the platform the item came from
+        browser.press(key(KeyCode::Down)).unwrap();
+        browser.start_annotation();
+        browser
+            .annotation_input_mut()
+            .unwrap()
+            .push_str("flaky tests");
+        browser.save_annotation().unwrap();
+        assert_eq!(
+            browser.annotations().get("gerrit", "12346").unwrap().text,
+            "flaky tests"
+        );
+
+        // Sorting applies to the combined lists as well
+        browser.sort_items(|sort| sort.sort_by(ItemSortField::Updated));
+        browser.sort_items(ItemSort::reverse);
+        let created = browser.get_category_items(ALL_PLATFORMS, &ActivityCategory::ChangesCreated);
+        assert_eq!(created[0].id, "!3");
+    }
+
+    #[tokio::test]
+    async fn test_load_pending_item_details() {
+        use crate::core::platform::DETAILS_PENDING_KEY;
+        use crate::tui::test_terminal::{key, screen, terminal};
+        use crossterm::event::KeyCode;
+
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data(&registry).await.unwrap();
+        let category = ActivityCategory::IssuesResolved;
+        let mut item = browser.get_category_items("jira", &category)[0].clone();
+        item.metadata.remove("priority");
+        item.metadata
+            .insert(DETAILS_PENDING_KEY.to_string(), "true".to_string());
+        browser.apply_item_details("jira", item.clone());
+        browser.push_view(ViewMode::CategoryView {
+            platform_id: "jira".to_string(),
+            category,
+        });
+
+        let mut terminal = terminal(120, 40);
+        browser.draw(&mut terminal).unwrap();
+        assert!(screen(&terminal).contains("Press d to load all fields"));
+        browser.press(key(KeyCode::Char('d'))).unwrap();
+        browser.draw(&mut terminal).unwrap();
+        assert!(screen(&terminal).contains("Loading details"));
+
+        item.metadata.remove(DETAILS_PENDING_KEY);
+        item.metadata
+            .insert("priority".to_string(), "Blocker".to_string());
+        browser.apply_item_details("jira", item);
+        browser.draw(&mut terminal).unwrap();
+        assert!(screen(&terminal).contains("Priority: Blocker"));
+    }
+
+    #[tokio::test]
+    async fn test_gerrit_review_details_pane() {
+        use crate::core::gerrit::{REVIEW_MESSAGES_KEY, REVIEWER_LIST_KEY, VOTES_KEY};
+        use crate::tui::test_terminal::{screen, terminal};
+
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data(&registry).await.unwrap();
+        let category = ActivityCategory::ChangesCreated;
+        let mut item = browser.get_category_items("gerrit", &category)[0].clone();
+        for (key, value) in [
+            (VOTES_KEY, "Code-Review: +2 Ann; Verified: +1 CI"),
+            (REVIEWER_LIST_KEY, "Ann, Bob (CC: Eve)"),
+            (
+                REVIEW_MESSAGES_KEY,
+                "2024-01-10 08:00 Jane: Uploaded patch set 1.\n2024-01-11 09:30 Ann: Looks good",
+            ),
+        ] {
+            item.metadata.insert(key.to_string(), value.to_string());
+        }
+        browser.apply_item_details("gerrit", item);
+        browser.push_view(ViewMode::CategoryView {
+            platform_id: "gerrit".to_string(),
+            category,
+        });
+
+        let mut terminal = terminal(120, 40);
+        browser.draw(&mut terminal).unwrap();
+        let screen = screen(&terminal);
+        assert!(screen.contains("Votes: Code-Review: +2 Ann; Verified: +1 CI"));
+        assert!(screen.contains("Reviewers: Ann, Bob (CC: Eve)"));
+        assert!(screen.contains("2024-01-11 09:30 Ann: Looks good"));
+    }
+
+    #[tokio::test]
+    async fn test_excluded_platforms_are_not_queried() {
+        let registry = create_test_registry();
+        let employee = Employee {
+            name: "John Doe".to_string(),
+            committer_email: Some("john.doe@example.com".to_string()),
+            exclude_platforms: vec!["jira".to_string()],
+            ..Default::default()
+        };
+        let mut browser = MultiPlatformBrowser::for_employee(employee, &registry);
+
+        browser.load_data_async(&registry).await.unwrap();
+        assert!(browser.platform_activities().contains_key("gerrit"));
+        assert!(!browser.platform_activities().contains_key("jira"));
+    }
+
+    #[tokio::test]
+    async fn test_username_identities_without_email() {
+        let registry = create_test_registry();
+        let employee = Employee {
+            name: "Sam Lee".to_string(),
+            usernames: [("jira".to_string(), "slee".to_string())].into(),
+            ..Default::default()
+        };
+        let mut browser = MultiPlatformBrowser::for_employee(employee, &registry);
+
+        assert_eq!(browser.identity_for("jira"), Some("slee".to_string()));
+        assert_eq!(browser.identity_for("gerrit"), None);
+
+        // Only platforms with an identity are queried
+        browser.load_data(&registry).await.unwrap();
+        assert!(browser.platform_activities().contains_key("jira"));
+        assert!(!browser.platform_activities().contains_key("gerrit"));
+    }
+
+    #[test]
+    fn test_blank_username_falls_back_to_email() {
+        let registry = create_test_registry();
+        let employee = Employee {
+            name: "Sam Lee".to_string(),
+            committer_email: Some("sam@example.com".to_string()),
+            usernames: [("jira".to_string(), "  ".to_string())].into(),
+            ..Default::default()
+        };
+        let browser = MultiPlatformBrowser::for_employee(employee, &registry);
+
+        assert_eq!(
+            browser.identity_for("jira"),
+            Some("sam@example.com".to_string())
+        );
+        assert_eq!(
+            browser.identity_for("gerrit"),
+            Some("sam@example.com".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rendered_navigation() {
+        use crate::tui::test_terminal::{key, screen, terminal};
+        use crossterm::event::KeyCode;
+
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data(&registry).await.unwrap();
+        let mut terminal = terminal(120, 40);
+
+        browser.draw(&mut terminal).unwrap();
+        let summary = screen(&terminal);
+        assert!(summary.contains("Platform Summary"));
+        assert!(summary.contains("Gerrit - 2 items across 2 categories"));
+        assert!(summary.contains("JIRA - 2 items across 2 categories"));
+        assert!(summary.contains("Enter: View Platform"));
+
+        while browser.platform_order()[browser.selected_platform_index()] != "gerrit" {
+            browser.press(key(KeyCode::Tab)).unwrap();
+        }
+        assert!(!browser.press(key(KeyCode::Enter)).unwrap());
+        browser.draw(&mut terminal).unwrap();
+        let platform = screen(&terminal);
+        assert!(platform.contains("Summary ▸ Gerrit"));
+        assert!(platform.contains("Categories in Gerrit"));
+        assert!(platform.contains("▶ Changes Created"));
+
+        browser.press(key(KeyCode::Down)).unwrap();
+        browser.press(key(KeyCode::Enter)).unwrap();
+        browser.draw(&mut terminal).unwrap();
+        let category = screen(&terminal);
+        assert!(category.contains("Summary ▸ Gerrit ▸ Changes Merged"));
+        assert!(category.contains("▶ [12345] Fix critical bug in authentication module"));
+        assert!(category.contains("Status: MERGED"));
+
+        // Going back restores the category selection, and q on the summary quits
+        assert!(!browser.press(key(KeyCode::Char('q'))).unwrap());
+        browser.draw(&mut terminal).unwrap();
+        assert!(screen(&terminal).contains("▶ Changes Merged"));
+        assert!(!browser.press(key(KeyCode::Backspace)).unwrap());
+        browser.draw(&mut terminal).unwrap();
+        assert!(screen(&terminal).contains("Platform Summary"));
+        assert!(browser.press(key(KeyCode::Char('q'))).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_rendered_overlays() {
+        use crate::tui::test_terminal::{key, screen, terminal};
+        use crossterm::event::KeyCode;
+
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data(&registry).await.unwrap();
+        let mut terminal = terminal(120, 50);
+
+        browser.press(key(KeyCode::Char('h'))).unwrap();
+        browser.draw(&mut terminal).unwrap();
+        assert!(screen(&terminal).contains("Multi-Platform Review Browser Help"));
+        // Keys other than h/?/Esc are swallowed by the help overlay
+        assert!(!browser.press(key(KeyCode::Char('q'))).unwrap());
+        browser.press(key(KeyCode::Esc)).unwrap();
+        browser.draw(&mut terminal).unwrap();
+        assert!(!screen(&terminal).contains("Multi-Platform Review Browser Help"));
+
+        browser.press(key(KeyCode::Char('g'))).unwrap();
+        browser.draw(&mut terminal).unwrap();
+        assert!(screen(&terminal).contains("Summary ▸ Trends"));
+        browser.press(key(KeyCode::Char('q'))).unwrap();
+
+        browser.set_current_view(ViewMode::CategoryView {
+            platform_id: "gerrit".to_string(),
+            category: ActivityCategory::ChangesMerged,
+        });
+        browser.press(key(KeyCode::Down)).unwrap();
+        browser.press(key(KeyCode::Char('a'))).unwrap();
+        for c in "slow".chars() {
+            browser.press(key(KeyCode::Char(c))).unwrap();
+        }
+        browser.draw(&mut terminal).unwrap();
+        let annotating = screen(&terminal);
+        assert!(annotating.contains("Annotation (Enter: save"));
+        assert!(annotating.contains("slow"));
+        browser.press(key(KeyCode::Esc)).unwrap();
+        browser.draw(&mut terminal).unwrap();
+        assert!(!screen(&terminal).contains("Annotation (Enter: save"));
+    }
+
+    #[tokio::test]
+    async fn test_platform_status_panel() {
+        use crate::tui::test_terminal::{key, screen, terminal};
+        use crossterm::event::KeyCode;
+
+        let mut registry = PlatformRegistry::new();
+        registry.register_platform(Box::new(MockPlatform::new_gerrit()));
+        registry.register_platform(Box::new(MockPlatform::new_failing_jira(
+            "HTTP 503 from JIRA",
+        )));
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data_async(&registry).await.unwrap();
+
+        let gerrit = &browser.platform_health()["gerrit"];
+        assert_eq!(gerrit.connection, ConnectionStatus::Connected);
+        assert!(gerrit.fetched_at.is_some());
+        assert_eq!(
+            browser.platform_health()["jira"].connection,
+            ConnectionStatus::Error("HTTP 503 from JIRA".to_string())
+        );
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        let gerrit_line = browser.status_line("gerrit");
+        assert!(gerrit_line.starts_with("✅ 🔧 Gerrit - connected · "));
+        assert!(gerrit_line.contains(&format!("fetched {today}")));
+        assert_eq!(
+            browser.status_line("jira"),
+            "❌ 🎫 JIRA - HTTP 503 from JIRA"
+        );
+
+        let mut terminal = terminal(120, 40);
+        browser.press(key(KeyCode::Char('c'))).unwrap();
+        browser.draw(&mut terminal).unwrap();
+        let panel = screen(&terminal);
+        assert!(panel.contains("Platform Status"));
+        assert!(panel.contains("JIRA - HTTP 503 from JIRA"));
+        // q closes the panel instead of quitting the browser
+        assert!(!browser.press(key(KeyCode::Char('q'))).unwrap());
+        browser.draw(&mut terminal).unwrap();
+        assert!(!screen(&terminal).contains("Platform Status"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_category_shows_its_error() {
+        use crate::tui::test_terminal::{key, screen, terminal};
+        use crossterm::event::KeyCode;
+
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data(&registry).await.unwrap();
+        browser
+            .platform_activities_mut()
+            .get_mut("gerrit")
+            .unwrap()
+            .insert_result(
+                ActivityCategory::ReviewsGiven,
+                Err(io::Error::other("HTTP 403 Forbidden")),
+            );
+        assert!(
+            browser
+                .platform_summary("gerrit")
+                .contains("categories - ⚠️ 1 failed to load")
+        );
+
+        // The failed category follows the loaded ones
+        browser.open_platform("gerrit");
+        let (category, row) = browser.category_metrics("gerrit").pop().unwrap();
+        assert_eq!(category, ActivityCategory::ReviewsGiven);
+        assert!(row.failed);
+        let mut terminal = terminal(120, 40);
+        browser.draw(&mut terminal).unwrap();
+        assert!(screen(&terminal).contains("Reviews Given: failed to load (view error)"));
+
+        let rows = browser.category_metrics("gerrit").len();
+        for _ in 1..rows {
+            browser.press(key(KeyCode::Down)).unwrap();
+        }
+        browser.press(key(KeyCode::Enter)).unwrap();
+        browser.draw(&mut terminal).unwrap();
+        let panel = screen(&terminal);
+        assert!(panel.contains("Platform Status"));
+        assert!(panel.contains("Failed to load Reviews Given from Gerrit"));
+        assert!(panel.contains("HTTP 403 Forbidden"));
+        assert!(matches!(
+            browser.current_view(),
+            ViewMode::PlatformView { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mouse_selects_opens_and_scrolls() {
+        use crate::tui::test_terminal::{click, mouse, terminal};
+        use crossterm::event::{MouseButton, MouseEventKind};
+
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data(&registry).await.unwrap();
+        let mut terminal = terminal(120, 40);
+
+        // Summary: the tabs are on row 5 ("│ 🔧 Gerrit │ 🎫 JIRA │ ..."), the list rows from 8
+        browser.draw(&mut terminal).unwrap();
+        browser.mouse(click(14, 5)).unwrap();
+        assert_eq!(browser.selected_platform_index(), 1);
+        browser.mouse(click(6, 8)).unwrap();
+        assert_eq!(browser.selected_platform_index(), 0);
+        // Clicks on the border do nothing
+        browser.mouse(click(5, 7)).unwrap();
+        assert_eq!(browser.selected_platform_index(), 0);
+
+        // A double click opens the platform
+        let jira = browser
+            .platform_order()
+            .iter()
+            .position(|id| id == "jira")
+            .unwrap();
+        let jira_row = 8 + jira as u16;
+        browser.mouse(click(5, jira_row)).unwrap();
+        assert_eq!(browser.selected_platform_index(), jira);
+        assert!(matches!(browser.current_view(), ViewMode::Summary));
+        browser.mouse(click(5, jira_row)).unwrap();
+        assert!(matches!(
+            browser.current_view(),
+            ViewMode::PlatformView { platform_id } if platform_id == "jira"
+        ));
+
+        // The wheel moves the selection without wrapping around
+        browser.draw(&mut terminal).unwrap();
+        browser
+            .mouse(mouse(MouseEventKind::ScrollDown, 5, 6))
+            .unwrap();
+        browser
+            .mouse(mouse(MouseEventKind::ScrollDown, 5, 6))
+            .unwrap();
+        assert_eq!(browser.selected_index(), Some(1));
+        browser
+            .mouse(mouse(MouseEventKind::ScrollUp, 5, 6))
+            .unwrap();
+        browser
+            .mouse(mouse(MouseEventKind::ScrollUp, 5, 6))
+            .unwrap();
+        assert_eq!(browser.selected_index(), Some(0));
+
+        // A middle click opens the category below the table header
+        browser
+            .mouse(mouse(MouseEventKind::Down(MouseButton::Middle), 5, 6))
+            .unwrap();
+        assert!(matches!(
+            browser.current_view(),
+            ViewMode::CategoryView {
+                category: ActivityCategory::IssuesAssigned,
+                ..
+            }
+        ));
+
+        // ... and a double click on an item opens it in the browser
+        let temp_dir = tempfile::tempdir().unwrap();
+        let opened = temp_dir.path().join("opened");
+        browser.set_browser_command(Some(format!("sh -c 'echo \"$0\" > {}'", opened.display())));
+        browser.draw(&mut terminal).unwrap();
+        browser.mouse(click(5, 5)).unwrap();
+        browser.mouse(click(5, 5)).unwrap();
+        for _ in 0..50 {
+            if opened.exists() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        let url = std::fs::read_to_string(&opened).unwrap();
+        assert_eq!(url.trim(), "https://jira.example.com/browse/PROJ-124");
+    }
+
+    #[test]
+    fn test_error_detail_formatting() {
+        let error = crate::core::platform::ErrorContext::new("jira", "search_issues")
+            .with_error("api_error", "HTTP 401")
+            .with_request_details(
+                "https://jira.example.com/rest/api/2/search",
+                Some(401),
+                Some("Unauthorized"),
+            );
+        let detail = MultiPlatformBrowser::format_error_detail(&error);
+        assert!(detail.contains("| search_issues\nType: api_error\nMessage: HTTP 401"));
+        assert!(detail.contains("\nStatus: 401"));
+        assert!(detail.contains("\nResponse: Unauthorized"));
+    }
+
+    #[tokio::test]
+    async fn test_category_view_sorting_and_jumps() {
+        use crate::tui::item_sort::ItemSortField;
+        use crate::tui::test_terminal::{key, screen, terminal};
+        use crossterm::event::KeyCode;
+
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data(&registry).await.unwrap();
+        let merged = browser
+            .platform_activities_mut()
+            .get_mut("gerrit")
+            .unwrap()
+            .items_by_category
+            .get_mut(&ActivityCategory::ChangesMerged)
+            .unwrap();
+        let mut newer = merged[0].clone();
+        newer.id = "12399".to_string();
+        newer.updated = "2024-02-01T09:00:00Z".to_string();
+        newer.project = "api-gateway".to_string();
+        merged.push(newer);
+
+        browser.set_current_view(ViewMode::CategoryView {
+            platform_id: "gerrit".to_string(),
+            category: ActivityCategory::ChangesMerged,
+        });
+        browser.press(key(KeyCode::Down)).unwrap();
+        let ids = |browser: &MultiPlatformBrowser| -> Vec<String> {
+            browser
+                .get_category_items("gerrit", &ActivityCategory::ChangesMerged)
+                .into_iter()
+                .map(|item| item.id)
+                .collect()
+        };
+        assert_eq!(ids(&browser), vec!["12345", "12399"]);
+        assert_eq!(browser.selected_index(), Some(1));
+
+        // Newest first, and the selected change stays selected
+        browser.press(key(KeyCode::Char('U'))).unwrap();
+        assert_eq!(browser.item_sort().field, Some(ItemSortField::Updated));
+        assert_eq!(ids(&browser), vec!["12399", "12345"]);
+        assert_eq!(browser.selected_index(), Some(0));
+        browser.press(key(KeyCode::Char('o'))).unwrap();
+        assert_eq!(ids(&browser), vec!["12345", "12399"]);
+        browser.press(key(KeyCode::Char('P'))).unwrap();
+        assert_eq!(ids(&browser), vec!["12399", "12345"]);
+
+        let mut terminal = terminal(160, 40);
+        browser.draw(&mut terminal).unwrap();
+        assert!(screen(&terminal).contains("Changes Merged Items · sorted by project ▲"));
+
+        // Number keys jump between categories instead of re-querying another period
+        assert!(!browser.press(key(KeyCode::Char('1'))).unwrap());
+        assert!(
+            browser.current_view()
+                == &ViewMode::CategoryView {
+                    platform_id: "gerrit".to_string(),
+                    category: ActivityCategory::ChangesCreated,
+                }
+        );
+        browser.press(key(KeyCode::Char('9'))).unwrap();
+        assert!(matches!(
+            browser.current_view(),
+            ViewMode::CategoryView {
+                category: ActivityCategory::ChangesCreated,
+                ..
+            }
+        ));
+        browser.press(key(KeyCode::Char('2'))).unwrap();
+        assert!(matches!(
+            browser.current_view(),
+            ViewMode::CategoryView {
+                category: ActivityCategory::ChangesMerged,
+                ..
+            }
+        ));
+        assert_eq!(browser.days(), 30);
+    }
+
+    #[tokio::test]
+    async fn test_category_view_footer_stats() {
+        use crate::tui::test_terminal::{screen, terminal};
+
+        let registry = create_test_registry();
+        let mut browser = MultiPlatformBrowser::new(
+            "John Doe".to_string(),
+            "john.doe@example.com".to_string(),
+            &registry,
+        );
+        browser.load_data(&registry).await.unwrap();
+        let merged = browser
+            .platform_activities_mut()
+            .get_mut("gerrit")
+            .unwrap()
+            .items_by_category
+            .get_mut(&ActivityCategory::ChangesMerged)
+            .unwrap();
+        let mut open = merged[0].clone();
+        open.id = "12399".to_string();
+        open.status = "NEW".to_string();
+        merged.push(open);
+
+        browser.set_current_view(ViewMode::CategoryView {
+            platform_id: "gerrit".to_string(),
+            category: ActivityCategory::ChangesMerged,
+        });
+        let mut terminal = terminal(160, 40);
+        browser.draw(&mut terminal).unwrap();
+        assert!(screen(&terminal).contains("Controls · 2 items · 1 open / 1 closed · median age "));
+
+        // Other views keep the plain controls title
+        browser.set_current_view(ViewMode::Summary);
+        browser.draw(&mut terminal).unwrap();
+        assert!(!screen(&terminal).contains("open / "));
+    }
 }
@@ -0,0 +1,981 @@
+//! Read-only pager for an employee's Markdown notes: headings, bullets and links are styled,
+//! long lines wrap to the terminal width, `/` searches the text, `[`/`]` jump between headings,
+//! evidence links are selected with Tab and opened with Enter and `@Employee` mentions of known
+//! employees stand out
+
+use crate::tui::url_opener::UrlOpener;
+use crossterm::{
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+    },
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use ratatui::{
+    Frame, Terminal,
+    backend::{Backend, CrosstermBackend},
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use std::io;
+
+/// A run of text with one style
+type Segment = (String, Style);
+
+/// One line of the notes after Markdown styling, before wrapping
+#[derive(Debug, Clone, PartialEq)]
+struct StyledLine {
+    segments: Vec<Segment>,
+    /// Columns continuation rows are indented by, so wrapped bullets line up
+    indent: usize,
+    heading: bool,
+    /// URLs of the `[text](url)` and bare links, in order
+    links: Vec<String>,
+}
+
+/// A row on screen: part of a styled line that fits the width
+#[derive(Debug, Clone, PartialEq)]
+struct Row {
+    segments: Vec<Segment>,
+}
+
+impl Row {
+    fn text(&self) -> String {
+        self.segments
+            .iter()
+            .map(|(text, _)| text.as_str())
+            .collect()
+    }
+}
+
+fn link_style() -> Style {
+    Style::default()
+        .fg(Color::Blue)
+        .add_modifier(Modifier::UNDERLINED)
+}
+
+fn code_style() -> Style {
+    Style::default().fg(Color::Green)
+}
+
+fn mention_style() -> Style {
+    Style::default()
+        .fg(Color::LightMagenta)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Length in bytes of the `@Name` at the start of `text` when it names one of `employees`
+/// (ignoring ASCII case and ending at a word boundary), preferring the longest name
+fn mention_len(text: &str, employees: &[String]) -> Option<usize> {
+    let name = text.strip_prefix('@')?;
+    employees
+        .iter()
+        .filter(|employee| {
+            name.get(..employee.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(employee))
+                && !name[employee.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_alphanumeric())
+        })
+        .map(|employee| employee.len() + 1)
+        .max()
+}
+
+/// Give the `@Name` mentions of `employees` in a styled line the mention style; code and links
+/// keep theirs
+fn style_mentions(segments: Vec<Segment>, employees: &[String]) -> Vec<Segment> {
+    let mut styled = Vec::new();
+    for (text, style) in segments {
+        if style == code_style() || style == link_style() || !text.contains('@') {
+            styled.push((text, style));
+            continue;
+        }
+        let mut plain = 0;
+        let mut index = 0;
+        while let Some(offset) = text[index..].find('@') {
+            let start = index + offset;
+            match mention_len(&text[start..], employees) {
+                Some(len) => {
+                    if start > plain {
+                        styled.push((text[plain..start].to_string(), style));
+                    }
+                    styled.push((
+                        text[start..start + len].to_string(),
+                        style.patch(mention_style()),
+                    ));
+                    plain = start + len;
+                    index = plain;
+                }
+                None => index = start + 1,
+            }
+        }
+        if plain < text.len() {
+            styled.push((text[plain..].to_string(), style));
+        }
+    }
+    styled
+}
+
+/// Style `**bold**`, `` `code` ``, `[text](url)` and bare `http(s)://` links within a line,
+/// collecting the link URLs in `links`
+fn parse_inline(text: &str, base: Style, links: &mut Vec<String>) -> Vec<Segment> {
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut plain = String::new();
+    let mut bold = false;
+    let mut rest = text;
+    let current = |bold: bool| {
+        if bold {
+            base.add_modifier(Modifier::BOLD)
+        } else {
+            base
+        }
+    };
+    let flush = |plain: &mut String, segments: &mut Vec<Segment>, style: Style| {
+        if !plain.is_empty() {
+            segments.push((std::mem::take(plain), style));
+        }
+    };
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("**") {
+            flush(&mut plain, &mut segments, current(bold));
+            bold = !bold;
+            rest = after;
+        } else if c == '`'
+            && let Some(end) = rest[1..].find('`')
+        {
+            flush(&mut plain, &mut segments, current(bold));
+            segments.push((rest[1..=end].to_string(), code_style()));
+            rest = &rest[end + 2..];
+        } else if c == '['
+            && let Some(close) = rest.find("](")
+            && let Some(end) = rest[close..].find(')')
+        {
+            flush(&mut plain, &mut segments, current(bold));
+            let label = &rest[1..close];
+            let url = &rest[close + 2..close + end];
+            segments.push((label.to_string(), link_style()));
+            segments.push((format!(" ({url})"), Style::default().fg(Color::DarkGray)));
+            links.push(url.to_string());
+            rest = &rest[close + end + 1..];
+        } else if rest.starts_with("https://") || rest.starts_with("http://") {
+            flush(&mut plain, &mut segments, current(bold));
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            segments.push((rest[..end].to_string(), link_style()));
+            links.push(rest[..end].to_string());
+            rest = &rest[end..];
+        } else {
+            plain.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    flush(&mut plain, &mut segments, current(bold));
+    segments
+}
+
+/// Markdown notes as styled lines. Only what notes use is recognised: headings, bullets,
+/// quotes, fenced code and inline emphasis and links.
+fn render_markdown(content: &str) -> Vec<StyledLine> {
+    let mut lines = Vec::new();
+    let mut in_code = false;
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            lines.push(StyledLine {
+                segments: vec![(format!("    {line}"), code_style())],
+                indent: 4,
+                heading: false,
+                links: Vec::new(),
+            });
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        let leading = line.len() - trimmed.len();
+        let mut links = Vec::new();
+        let styled = if let Some(title) = trimmed.strip_prefix("# ") {
+            let style = Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+            StyledLine {
+                segments: parse_inline(title, style, &mut links),
+                indent: 0,
+                heading: true,
+                links,
+            }
+        } else if let Some(title) = trimmed.strip_prefix("## ") {
+            let style = Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD);
+            StyledLine {
+                segments: parse_inline(title, style, &mut links),
+                indent: 0,
+                heading: true,
+                links,
+            }
+        } else if let Some(title) = trimmed
+            .strip_prefix("### ")
+            .or_else(|| trimmed.strip_prefix("#### "))
+        {
+            let style = Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD);
+            StyledLine {
+                segments: parse_inline(title, style, &mut links),
+                indent: 0,
+                heading: true,
+                links,
+            }
+        } else if let Some(item) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|marker| trimmed.strip_prefix(marker))
+        {
+            let bullet = format!("{}• ", " ".repeat(leading));
+            let indent = bullet.chars().count();
+            let mut segments = vec![(bullet, Style::default().fg(Color::Cyan))];
+            segments.extend(parse_inline(item, Style::default(), &mut links));
+            StyledLine {
+                segments,
+                indent,
+                heading: false,
+                links,
+            }
+        } else if let Some(quote) = trimmed.strip_prefix('>') {
+            let style = Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::ITALIC);
+            let mut segments = vec![("│ ".to_string(), Style::default().fg(Color::DarkGray))];
+            segments.extend(parse_inline(quote.trim_start(), style, &mut links));
+            StyledLine {
+                segments,
+                indent: 2,
+                heading: false,
+                links,
+            }
+        } else {
+            StyledLine {
+                segments: parse_inline(line, Style::default(), &mut links),
+                indent: leading,
+                heading: false,
+                links,
+            }
+        };
+        lines.push(styled);
+    }
+    lines
+}
+
+/// Break a styled line into rows of at most `width` columns, at spaces where possible
+fn wrap_line(line: &StyledLine, width: usize) -> Vec<Row> {
+    let width = width.max(1);
+    let indent = if line.indent < width / 2 {
+        line.indent
+    } else {
+        0
+    };
+    let mut rows = Vec::new();
+    let mut row: Vec<Segment> = Vec::new();
+    let mut used = 0;
+
+    for (text, style) in &line.segments {
+        for mut word in text.split_inclusive(' ') {
+            loop {
+                // A space that only overflows at the end of the row is dropped
+                let piece = if used + word.chars().count() <= width {
+                    word
+                } else {
+                    word.trim_end()
+                };
+                if used + piece.chars().count() <= width {
+                    row.push((piece.to_string(), *style));
+                    used += piece.chars().count();
+                    break;
+                }
+                if used > indent {
+                    if let Some((text, _)) = row.last_mut() {
+                        text.truncate(text.trim_end().len());
+                    }
+                    rows.push(Row {
+                        segments: std::mem::take(&mut row),
+                    });
+                    row.push((" ".repeat(indent), Style::default()));
+                    used = indent;
+                    continue;
+                }
+                // A word longer than a row is split where the row ends
+                let split = word
+                    .char_indices()
+                    .nth(width - used)
+                    .map_or(word.len(), |(index, _)| index);
+                row.push((word[..split].to_string(), *style));
+                word = &word[split..];
+                rows.push(Row {
+                    segments: std::mem::take(&mut row),
+                });
+                row.push((" ".repeat(indent), Style::default()));
+                used = indent;
+            }
+        }
+    }
+    if !row.is_empty() || rows.is_empty() {
+        rows.push(Row { segments: row });
+    }
+    rows
+}
+
+/// Byte ranges of `query` in `text`, ignoring ASCII case
+fn find_matches(text: &str, query: &str) -> Vec<(usize, usize)> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let haystack = text.to_ascii_lowercase();
+    let needle = query.to_ascii_lowercase();
+    haystack
+        .match_indices(&needle)
+        .map(|(start, found)| (start, start + found.len()))
+        .collect()
+}
+
+/// Give the parts of a row that match `query` the highlight style
+fn highlight_row(row: &Row, query: &str, highlight: Style) -> Line<'static> {
+    let matches = find_matches(&row.text(), query);
+    let mut spans = Vec::new();
+    let mut offset = 0;
+    for (text, style) in &row.segments {
+        let end = offset + text.len();
+        let mut cursor = offset;
+        for &(start, stop) in &matches {
+            let (start, stop) = (start.max(cursor), stop.min(end));
+            if start >= stop {
+                continue;
+            }
+            if start > cursor {
+                spans.push(Span::styled(
+                    text[cursor - offset..start - offset].to_string(),
+                    *style,
+                ));
+            }
+            spans.push(Span::styled(
+                text[start - offset..stop - offset].to_string(),
+                style.patch(highlight),
+            ));
+            cursor = stop;
+        }
+        if cursor < end {
+            spans.push(Span::styled(text[cursor - offset..].to_string(), *style));
+        }
+        offset = end;
+    }
+    Line::from(spans)
+}
+
+pub struct NotesPager {
+    title: String,
+    lines: Vec<StyledLine>,
+    rows: Vec<Row>,
+    /// First row of each styled line, to keep the position when the width changes
+    line_starts: Vec<usize>,
+    width: usize,
+    height: usize,
+    scroll: usize,
+    /// Text typed after `/`, until Enter or Esc
+    search_input: Option<String>,
+    query: String,
+    /// Rows that contain the query
+    matches: Vec<usize>,
+    current_match: Option<usize>,
+    /// Every link as (styled line, URL), in document order
+    links: Vec<(usize, String)>,
+    selected_link: Option<usize>,
+    message: Option<String>,
+    url_opener: UrlOpener,
+    show_help: bool,
+}
+
+impl NotesPager {
+    pub fn new(title: String, content: &str) -> Self {
+        let lines = render_markdown(content);
+        let links = lines
+            .iter()
+            .enumerate()
+            .flat_map(|(index, line)| line.links.iter().map(move |url| (index, url.clone())))
+            .collect();
+        Self {
+            title,
+            lines,
+            rows: Vec::new(),
+            line_starts: Vec::new(),
+            width: 0,
+            height: 1,
+            scroll: 0,
+            search_input: None,
+            query: String::new(),
+            matches: Vec::new(),
+            current_match: None,
+            links,
+            selected_link: None,
+            message: None,
+            url_opener: UrlOpener::default(),
+            show_help: false,
+        }
+    }
+
+    /// Highlight `@Name` mentions of these employees
+    pub fn set_employees(&mut self, employees: &[String]) {
+        for line in &mut self.lines {
+            line.segments = style_mentions(std::mem::take(&mut line.segments), employees);
+        }
+        // Re-wrap on the next draw
+        self.width = 0;
+    }
+
+    /// Open links with `command` instead of the system browser
+    pub fn set_browser_command(&mut self, command: Option<String>) {
+        self.url_opener = UrlOpener::new(command);
+    }
+
+    pub fn run(&mut self) -> io::Result<()> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let result = self.run_app(&mut terminal);
+
+        disable_raw_mode()?;
+        execute!(
+            terminal.backend_mut(),
+            LeaveAlternateScreen,
+            DisableMouseCapture
+        )?;
+        terminal.show_cursor()?;
+
+        result
+    }
+
+    fn run_app<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
+        loop {
+            terminal.draw(|f| self.ui(f))?;
+            if let Event::Key(key) = event::read()?
+                && !self.handle_key_event(key)
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Re-wrap for a new width, keeping the line at the top of the screen in view
+    fn layout(&mut self, width: usize) {
+        if width == self.width {
+            return;
+        }
+        let top_line = self
+            .line_starts
+            .iter()
+            .rposition(|&start| start <= self.scroll)
+            .unwrap_or(0);
+        self.width = width;
+        self.rows.clear();
+        self.line_starts.clear();
+        for line in &self.lines {
+            self.line_starts.push(self.rows.len());
+            self.rows.extend(wrap_line(line, width));
+        }
+        self.scroll = self.line_starts.get(top_line).copied().unwrap_or(0);
+        self.update_matches();
+        self.clamp_scroll();
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.rows.len().saturating_sub(self.height)
+    }
+
+    fn clamp_scroll(&mut self) {
+        self.scroll = self.scroll.min(self.max_scroll());
+    }
+
+    fn update_matches(&mut self) {
+        self.matches = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| !find_matches(&row.text(), &self.query).is_empty())
+            .map(|(index, _)| index)
+            .collect();
+        self.current_match = None;
+    }
+
+    /// Scroll to the first match at or below the top of the screen
+    fn search(&mut self, query: String) {
+        self.query = query;
+        self.update_matches();
+        self.current_match = self.matches.iter().position(|&row| row >= self.scroll).or(
+            if self.matches.is_empty() {
+                None
+            } else {
+                Some(0)
+            },
+        );
+        self.show_current_match();
+    }
+
+    fn show_current_match(&mut self) {
+        if let Some(index) = self.current_match {
+            self.scroll = self.matches[index];
+            self.clamp_scroll();
+        }
+    }
+
+    fn next_match(&mut self, forward: bool) {
+        if self.matches.is_empty() {
+            return;
+        }
+        let count = self.matches.len();
+        self.current_match = Some(match (self.current_match, forward) {
+            (Some(index), true) => (index + 1) % count,
+            (Some(index), false) => (index + count - 1) % count,
+            (None, true) => 0,
+            (None, false) => count - 1,
+        });
+        self.show_current_match();
+    }
+
+    /// Scroll to the next heading below the top of the screen, or the previous one above it
+    fn jump_to_heading(&mut self, forward: bool) {
+        let mut starts = self
+            .lines
+            .iter()
+            .zip(&self.line_starts)
+            .filter(|(line, _)| line.heading)
+            .map(|(_, &start)| start);
+        let target = if forward {
+            starts.find(|&start| start > self.scroll)
+        } else {
+            starts.rfind(|&start| start < self.scroll)
+        };
+        if let Some(start) = target {
+            self.scroll = start;
+        }
+    }
+
+    /// Select the next or previous link, starting from the first one on screen, and bring it
+    /// into view
+    fn select_link(&mut self, forward: bool) {
+        if self.links.is_empty() {
+            self.message = Some("These notes contain no links".to_string());
+            return;
+        }
+        let count = self.links.len();
+        let row_of = |pager: &Self, index: usize| {
+            pager
+                .line_starts
+                .get(pager.links[index].0)
+                .copied()
+                .unwrap_or(0)
+        };
+        let on_screen = |pager: &Self, index: usize| {
+            let row = row_of(pager, index);
+            row >= pager.scroll && row < pager.scroll + pager.height
+        };
+        let next = match self.selected_link {
+            Some(index) if on_screen(self, index) => {
+                if forward {
+                    (index + 1) % count
+                } else {
+                    (index + count - 1) % count
+                }
+            }
+            _ if forward => (0..count)
+                .find(|&index| row_of(self, index) >= self.scroll)
+                .unwrap_or(0),
+            _ => (0..count)
+                .rev()
+                .find(|&index| row_of(self, index) < self.scroll + self.height)
+                .unwrap_or(count - 1),
+        };
+        self.selected_link = Some(next);
+        let row = row_of(self, next);
+        if row < self.scroll || row >= self.scroll + self.height {
+            self.scroll = row;
+        }
+    }
+
+    fn open_selected_link(&mut self) {
+        let Some((_, url)) = self.selected_link.and_then(|index| self.links.get(index)) else {
+            return;
+        };
+        let url = url.clone();
+        self.message = Some(match self.url_opener.open(&url) {
+            Ok(()) => format!("Opened {url}"),
+            Err(e) => format!("Could not open {url}: {e}"),
+        });
+    }
+
+    /// Returns false when the pager should close
+    fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        self.message = None;
+        if let Some(input) = &mut self.search_input {
+            match key.code {
+                KeyCode::Esc => self.search_input = None,
+                KeyCode::Enter => {
+                    let query = std::mem::take(input);
+                    self.search_input = None;
+                    self.search(query);
+                }
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) => input.push(c),
+                _ => {}
+            }
+            return true;
+        }
+        if self.show_help {
+            self.show_help = false;
+            return true;
+        }
+
+        let page = self.height.max(1);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.scroll += page / 2;
+            }
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.scroll = self.scroll.saturating_sub(page / 2);
+            }
+            KeyCode::Enter if self.selected_link.is_some() => self.open_selected_link(),
+            KeyCode::Down | KeyCode::Char('j') | KeyCode::Enter => self.scroll += 1,
+            KeyCode::Up | KeyCode::Char('k') => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::PageDown | KeyCode::Char(' ') | KeyCode::Char('f') => self.scroll += page,
+            KeyCode::PageUp | KeyCode::Char('b') => self.scroll = self.scroll.saturating_sub(page),
+            KeyCode::Home | KeyCode::Char('g') => self.scroll = 0,
+            KeyCode::End | KeyCode::Char('G') => self.scroll = self.max_scroll(),
+            KeyCode::Char('/') => self.search_input = Some(String::new()),
+            KeyCode::Char('n') => self.next_match(true),
+            KeyCode::Char('N') => self.next_match(false),
+            KeyCode::Char(']') => self.jump_to_heading(true),
+            KeyCode::Char('[') => self.jump_to_heading(false),
+            KeyCode::Tab => self.select_link(true),
+            KeyCode::BackTab => self.select_link(false),
+            KeyCode::Char('h') | KeyCode::Char('?') | KeyCode::F(1) => self.show_help = true,
+            _ => {}
+        }
+        self.clamp_scroll();
+        true
+    }
+
+    fn status_line(&self) -> Line<'static> {
+        if let Some(input) = &self.search_input {
+            return Line::from(format!("/{input}"));
+        }
+        if let Some(message) = &self.message {
+            return Line::from(message.clone());
+        }
+        let position = if self.rows.len() <= self.height {
+            "All".to_string()
+        } else if self.scroll >= self.max_scroll() {
+            "Bot".to_string()
+        } else if self.scroll == 0 {
+            "Top".to_string()
+        } else {
+            format!("{}%", self.scroll * 100 / self.max_scroll())
+        };
+        let search = match (self.query.is_empty(), self.current_match) {
+            (true, _) => String::new(),
+            (false, _) if self.matches.is_empty() => format!(" · '{}' not found", self.query),
+            (false, Some(index)) => {
+                format!(
+                    " · match {}/{} for '{}'",
+                    index + 1,
+                    self.matches.len(),
+                    self.query
+                )
+            }
+            (false, None) => format!(" · {} matches for '{}'", self.matches.len(), self.query),
+        };
+        let link = match self.selected_link {
+            Some(index) => format!(
+                " · link {}/{}: {}",
+                index + 1,
+                self.links.len(),
+                self.links[index].1
+            ),
+            None => String::new(),
+        };
+        Line::from(vec![
+            Span::styled(position, Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(search),
+            Span::raw(link),
+            Span::styled(
+                "  q: quit · /: search · [/]: headings · Tab: links · h: help",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ])
+    }
+
+    fn ui(&mut self, f: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(f.area());
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.title.clone());
+        let inner = block.inner(chunks[0]);
+        self.height = inner.height.max(1) as usize;
+        self.layout(inner.width as usize);
+        self.clamp_scroll();
+
+        let current_row = self.current_match.map(|index| self.matches[index]);
+        // Rows of the line holding the selected link show it highlighted instead of matches
+        let selected_link = self.selected_link.map(|index| {
+            let (line, url) = &self.links[index];
+            let start = self.line_starts[*line];
+            let end = self
+                .line_starts
+                .get(line + 1)
+                .copied()
+                .unwrap_or(self.rows.len());
+            (start..end, url.as_str())
+        });
+        let lines: Vec<Line> = self
+            .rows
+            .iter()
+            .enumerate()
+            .skip(self.scroll)
+            .take(self.height)
+            .map(|(index, row)| match &selected_link {
+                Some((rows, url)) if rows.contains(&index) => {
+                    let highlight = Style::default().bg(Color::Blue).fg(Color::White);
+                    highlight_row(row, url, highlight)
+                }
+                _ => {
+                    let highlight = if Some(index) == current_row {
+                        Style::default().bg(Color::Yellow).fg(Color::Black)
+                    } else {
+                        Style::default().add_modifier(Modifier::REVERSED)
+                    };
+                    highlight_row(row, &self.query, highlight)
+                }
+            })
+            .collect();
+        f.render_widget(Paragraph::new(lines).block(block), chunks[0]);
+        f.render_widget(Paragraph::new(self.status_line()), chunks[1]);
+
+        if self.show_help {
+            let help = [
+                "j/k, ↓/↑      scroll one line",
+                "space/b       next/previous page",
+                "Ctrl-d/Ctrl-u half a page down/up",
+                "g/G           top/bottom",
+                "/             search (ignores case)",
+                "n/N           next/previous match",
+                "]/[           next/previous heading",
+                "Tab/Shift-Tab select the next/previous link",
+                "Enter         open the selected link",
+                "q/Esc         quit",
+            ];
+            let area = chunks[0];
+            let width = 44.min(area.width);
+            let height = (help.len() as u16 + 2).min(area.height);
+            let popup = ratatui::layout::Rect {
+                x: area.x + (area.width - width) / 2,
+                y: area.y + (area.height - height) / 2,
+                width,
+                height,
+            };
+            f.render_widget(Clear, popup);
+            f.render_widget(
+                Paragraph::new(
+                    help.iter()
+                        .map(|line| Line::from(*line))
+                        .collect::<Vec<_>>(),
+                )
+                .block(Block::default().borders(Borders::ALL).title("Help")),
+                popup,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::test_terminal::{key, screen, terminal};
+
+    const NOTES: &str = "# Notes for Jane\n\n## 2025-01-10\n- Shipped the **login** flow, see [MR 42](https://gitlab.example.com/mr/42)\n- Pairs well with `core` team\n\n## 2025-02-01\nLed the incident review for the outage.\n";
+
+    #[test]
+    fn test_render_markdown() {
+        let lines = render_markdown(NOTES);
+        let text = |line: &StyledLine| -> String {
+            line.segments
+                .iter()
+                .map(|(text, _)| text.as_str())
+                .collect()
+        };
+        assert_eq!(text(&lines[0]), "Notes for Jane");
+        assert_eq!(text(&lines[2]), "2025-01-10");
+        assert_eq!(
+            text(&lines[3]),
+            "• Shipped the login flow, see MR 42 (https://gitlab.example.com/mr/42)"
+        );
+        assert_eq!(lines[3].indent, 2);
+        let bold = lines[3]
+            .segments
+            .iter()
+            .find(|(text, _)| text == "login")
+            .unwrap();
+        assert!(bold.1.add_modifier.contains(Modifier::BOLD));
+        let link = lines[3]
+            .segments
+            .iter()
+            .find(|(text, _)| text == "MR 42")
+            .unwrap();
+        assert_eq!(link.1, link_style());
+        assert_eq!(text(&lines[4]), "• Pairs well with core team");
+    }
+
+    #[test]
+    fn test_mentions_of_known_employees_are_styled() {
+        let employees = vec!["Jane Doe".to_string(), "Jane".to_string()];
+        let mut pager = NotesPager::new(
+            "Notes".to_string(),
+            "- Paired with @jane doe and @Janet, mailed jane@example.com\n- `@Jane Doe` in code\n",
+        );
+        pager.set_employees(&employees);
+
+        let mentions: Vec<&str> = pager
+            .lines
+            .iter()
+            .flat_map(|line| &line.segments)
+            .filter(|(_, style)| style.fg == Some(Color::LightMagenta))
+            .map(|(text, _)| text.as_str())
+            .collect();
+        assert_eq!(mentions, ["@jane doe"]);
+
+        let mut terminal = terminal(60, 6);
+        terminal.draw(|f| pager.ui(f)).unwrap();
+        assert!(screen(&terminal).contains("Paired with @jane doe and @Janet"));
+    }
+
+    #[test]
+    fn test_heading_navigation_and_links() {
+        let mut content = String::from("# Notes for Jane\n");
+        for day in 1..=3 {
+            content.push_str(&format!("\n## 2025-01-{day:02}\n"));
+            for line in 0..8 {
+                content.push_str(&format!("- Routine work {line}\n"));
+            }
+        }
+        content.push_str("- Evidence: https://gerrit.example.com/c/7 and [MR 9](https://gitlab.example.com/mr/9)\n");
+        let mut pager = NotesPager::new("Notes".to_string(), &content);
+        pager.set_browser_command(Some("true".to_string()));
+        let mut terminal = terminal(90, 8);
+        terminal.draw(|f| pager.ui(f)).unwrap();
+        assert_eq!(
+            pager
+                .links
+                .iter()
+                .map(|(_, url)| url.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "https://gerrit.example.com/c/7",
+                "https://gitlab.example.com/mr/9"
+            ]
+        );
+
+        pager.handle_key_event(key(KeyCode::Char(']')));
+        pager.handle_key_event(key(KeyCode::Char(']')));
+        terminal.draw(|f| pager.ui(f)).unwrap();
+        let shown = screen(&terminal);
+        assert!(
+            shown.lines().nth(1).unwrap().contains("2025-01-02"),
+            "{shown}"
+        );
+        pager.handle_key_event(key(KeyCode::Char('[')));
+        terminal.draw(|f| pager.ui(f)).unwrap();
+        assert!(
+            screen(&terminal)
+                .lines()
+                .nth(1)
+                .unwrap()
+                .contains("2025-01-01")
+        );
+
+        // Enter scrolls until a link is selected; Tab brings the first link into view
+        pager.handle_key_event(key(KeyCode::Enter));
+        assert_eq!(pager.message, None);
+        pager.handle_key_event(key(KeyCode::Tab));
+        terminal.draw(|f| pager.ui(f)).unwrap();
+        let shown = screen(&terminal);
+        assert!(
+            shown.contains("Evidence: https://gerrit.example.com/c/7"),
+            "{shown}"
+        );
+        assert!(
+            shown.contains("link 1/2: https://gerrit.example.com/c/7"),
+            "{shown}"
+        );
+        pager.handle_key_event(key(KeyCode::Tab));
+        pager.handle_key_event(key(KeyCode::Enter));
+        assert_eq!(
+            pager.message.as_deref(),
+            Some("Opened https://gitlab.example.com/mr/9")
+        );
+        pager.handle_key_event(key(KeyCode::BackTab));
+        assert_eq!(pager.selected_link, Some(0));
+    }
+
+    #[test]
+    fn test_wrap_keeps_bullet_indent() {
+        let line = &render_markdown("- one two three four")[0];
+        let rows: Vec<String> = wrap_line(line, 10).iter().map(Row::text).collect();
+        assert_eq!(rows, vec!["• one two", "  three", "  four"]);
+        let long = &render_markdown("abcdefghijkl")[0];
+        let rows: Vec<String> = wrap_line(long, 5).iter().map(Row::text).collect();
+        assert_eq!(rows, vec!["abcde", "fghij", "kl"]);
+    }
+
+    #[test]
+    fn test_search_scrolls_to_matches() {
+        let mut content = String::from("# Notes for Jane\n");
+        for day in 1..=20 {
+            content.push_str(&format!("\n## 2025-01-{day:02}\n- Routine work\n"));
+        }
+        content.push_str("- Fixed the Outage alerts\n");
+        let mut pager = NotesPager::new("Notes".to_string(), &content);
+        let mut terminal = terminal(50, 12);
+        terminal.draw(|f| pager.ui(f)).unwrap();
+        assert!(screen(&terminal).contains("Notes for Jane"));
+
+        for code in [KeyCode::Char('/'), KeyCode::Char('o'), KeyCode::Char('u')] {
+            pager.handle_key_event(key(code));
+        }
+        terminal.draw(|f| pager.ui(f)).unwrap();
+        assert!(screen(&terminal).contains("/ou"));
+        for code in "tage".chars().map(KeyCode::Char).chain([KeyCode::Enter]) {
+            pager.handle_key_event(key(code));
+        }
+        terminal.draw(|f| pager.ui(f)).unwrap();
+        let shown = screen(&terminal);
+        assert!(shown.contains("Fixed the Outage alerts"), "{shown}");
+        assert!(shown.contains("match 1/1 for 'outage'"), "{shown}");
+        assert!(!shown.contains("Notes for Jane"));
+
+        pager.handle_key_event(key(KeyCode::Char('g')));
+        terminal.draw(|f| pager.ui(f)).unwrap();
+        assert!(screen(&terminal).contains("Notes for Jane"));
+        assert!(!pager.handle_key_event(key(KeyCode::Char('q'))));
+    }
+}
@@ -1,4 +1,7 @@
 use crate::core::gerrit::{ChangeInfo, DetailedActivityMetrics};
+use crate::core::platform::ErrorContext;
+use crate::tui::metrics_table::{MetricRow, MetricsSort, metrics_table};
+use crate::tui::url_opener::UrlOpener;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent},
     execute,
@@ -9,7 +12,7 @@ use ratatui::{
     backend::{Backend, CrosstermBackend},
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, TableState, Wrap},
 };
 use std::io;
 
@@ -42,6 +45,8 @@ pub struct ReviewBrowser {
     current_view: ViewMode,
     list_state: ListState,
     show_help: bool,
+    metrics_sort: MetricsSort,
+    url_opener: UrlOpener,
 }
 
 impl ReviewBrowser {
@@ -59,9 +64,16 @@ impl ReviewBrowser {
             current_view: ViewMode::Summary,
             list_state: ListState::default(),
             show_help: false,
+            metrics_sort: MetricsSort::default(),
+            url_opener: UrlOpener::default(),
         }
     }
 
+    /// Open changes with `command` instead of the system browser
+    pub fn set_browser_command(&mut self, command: Option<String>) {
+        self.url_opener = UrlOpener::new(command);
+    }
+
     pub fn run(&mut self) -> io::Result<()> {
         enable_raw_mode()?;
         let mut stdout = io::stdout();
@@ -121,40 +133,38 @@ impl ReviewBrowser {
                 self.current_view = ViewMode::ReviewsReceived;
                 self.list_state.select(Some(0));
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if self.current_view != ViewMode::Summary {
-                    let changes = self.get_current_changes();
-                    if !changes.is_empty() {
-                        let i = match self.list_state.selected() {
-                            Some(i) => {
-                                if i >= changes.len() - 1 {
-                                    0
-                                } else {
-                                    i + 1
-                                }
+            KeyCode::Char('o') => self.metrics_sort.cycle_column(),
+            KeyCode::Char('O') => self.metrics_sort.reverse(),
+            KeyCode::Down | KeyCode::Char('j') if self.current_view != ViewMode::Summary => {
+                let changes = self.get_current_changes();
+                if !changes.is_empty() {
+                    let i = match self.list_state.selected() {
+                        Some(i) => {
+                            if i >= changes.len() - 1 {
+                                0
+                            } else {
+                                i + 1
                             }
-                            None => 0,
-                        };
-                        self.list_state.select(Some(i));
-                    }
+                        }
+                        None => 0,
+                    };
+                    self.list_state.select(Some(i));
                 }
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                if self.current_view != ViewMode::Summary {
-                    let changes = self.get_current_changes();
-                    if !changes.is_empty() {
-                        let i = match self.list_state.selected() {
-                            Some(i) => {
-                                if i == 0 {
-                                    changes.len() - 1
-                                } else {
-                                    i - 1
-                                }
+            KeyCode::Up | KeyCode::Char('k') if self.current_view != ViewMode::Summary => {
+                let changes = self.get_current_changes();
+                if !changes.is_empty() {
+                    let i = match self.list_state.selected() {
+                        Some(i) => {
+                            if i == 0 {
+                                changes.len() - 1
+                            } else {
+                                i - 1
                             }
-                            None => 0,
-                        };
-                        self.list_state.select(Some(i));
-                    }
+                        }
+                        None => 0,
+                    };
+                    self.list_state.select(Some(i));
                 }
             }
             KeyCode::Enter => {
@@ -167,20 +177,13 @@ impl ReviewBrowser {
                             "{}/c/{}/+/{}",
                             self.gerrit_base_url, change.project, change.number
                         );
-                        println!("Opening: {url}");
-                        // Try to open the URL in the default browser
-                        #[cfg(target_os = "linux")]
-                        std::process::Command::new("xdg-open")
-                            .arg(&url)
-                            .spawn()
-                            .ok();
-                        #[cfg(target_os = "macos")]
-                        std::process::Command::new("open").arg(&url).spawn().ok();
-                        #[cfg(target_os = "windows")]
-                        std::process::Command::new("cmd")
-                            .args(["/c", "start", &url])
-                            .spawn()
-                            .ok();
+                        if let Err(e) = self.url_opener.open(&url) {
+                            ErrorContext::new("browser", "open_url")
+                                .with_error("browser_open_error", &e.to_string())
+                                .with_metadata("url", &url)
+                                .log_error();
+                            log::warn!("Failed to open URL in browser: {e}");
+                        }
                     }
                 }
             }
@@ -249,27 +252,16 @@ impl ReviewBrowser {
         f.render_widget(header, chunks[0]);
 
         // Metrics overview
-        let metrics_text = format!(
-            "📈 Activity Metrics:\n\
-             • [M]erged Commits:   {:>3} | • [G]iven Reviews:    {:>3}\n\
-             • [C]reated Changes:  {:>3} | • [R]eceived Reviews: {:>3}\n\
-             \n\
-             Press the letter keys to explore each category in detail.",
-            self.metrics.commits_merged.len(),
-            self.metrics.reviews_given.len(),
-            self.metrics.changes_created.len(),
-            self.metrics.reviews_received.len()
-        );
-
-        let metrics = Paragraph::new(metrics_text)
-            .style(Style::default())
-            .block(Block::default().borders(Borders::ALL).title("Quick Access"));
-        f.render_widget(metrics, chunks[1]);
+        let rows = self.metric_rows();
+        let order = self.metrics_sort.order(&rows);
+        let rows: Vec<MetricRow> = order.into_iter().map(|index| rows[index].clone()).collect();
+        let metrics = metrics_table(&rows, &self.metrics_sort, "Quick Access".to_string());
+        f.render_stateful_widget(metrics, chunks[1], &mut TableState::default());
 
         // Instructions
         let instructions = Paragraph::new(
             "📋 Navigation:\n\
-             • Use letter keys (s/m/c/g/r) to switch views\n\
+             • Use letter keys (s/m/c/g/r) to switch views, o/O to sort the table\n\
              • In lists: ↑/↓ or j/k to navigate, Enter to open in browser\n\
              • Press 'h' for detailed help, 'q' to quit",
         )
@@ -279,6 +271,24 @@ impl ReviewBrowser {
         f.render_widget(instructions, chunks[2]);
     }
 
+    /// Summary rows, labelled with the key that opens each list
+    fn metric_rows(&self) -> Vec<MetricRow> {
+        [
+            ("[M]erged Commits", &self.metrics.commits_merged),
+            ("[C]reated Changes", &self.metrics.changes_created),
+            ("[G]iven Reviews", &self.metrics.reviews_given),
+            ("[R]eceived Reviews", &self.metrics.reviews_received),
+        ]
+        .into_iter()
+        .map(|(label, changes)| MetricRow {
+            label: label.to_string(),
+            count: changes.len(),
+            delta: None,
+            failed: false,
+        })
+        .collect()
+    }
+
     fn render_change_list(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
         let changes = self.get_current_changes();
         let changes_len = changes.len();
@@ -418,6 +428,7 @@ Navigation:\n\
   c       - Show created changes\n\
   g       - Show reviews given\n\
   r       - Show reviews received\n\
+  o / O   - Sort the summary table / reverse the order\n\
 \n\
 In List Views:\n\
   ↑/↓     - Navigate up/down\n\
@@ -0,0 +1,342 @@
+//! Interactive search over the offline index of cached items and notes: results update while
+//! typing, and items open in the browser
+
+use crate::core::search_index::{DocumentKind, SearchHit, SearchIndex};
+use crate::tui::url_opener::UrlOpener;
+use crossterm::{
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+    },
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use ratatui::{
+    Frame, Terminal,
+    backend::{Backend, CrosstermBackend},
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+};
+use std::io;
+
+/// Most hits listed for one query
+const MAX_HITS: usize = 200;
+
+pub struct SearchView {
+    index: SearchIndex,
+    employee: Option<String>,
+    query: String,
+    hits: Vec<SearchHit>,
+    list_state: ListState,
+    message: Option<String>,
+    url_opener: UrlOpener,
+}
+
+impl SearchView {
+    /// Search `index`, only for hits of `employee` when given, starting with `query`
+    pub fn new(index: SearchIndex, employee: Option<String>, query: String) -> Self {
+        let mut view = Self {
+            index,
+            employee,
+            query,
+            hits: Vec::new(),
+            list_state: ListState::default(),
+            message: None,
+            url_opener: UrlOpener::default(),
+        };
+        view.search();
+        view
+    }
+
+    /// Open item URLs with `command` instead of the system browser
+    pub fn set_browser_command(&mut self, command: Option<String>) {
+        self.url_opener = UrlOpener::new(command);
+    }
+
+    pub fn run(&mut self) -> io::Result<()> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let result = self.run_app(&mut terminal);
+
+        disable_raw_mode()?;
+        execute!(
+            terminal.backend_mut(),
+            LeaveAlternateScreen,
+            DisableMouseCapture
+        )?;
+        terminal.show_cursor()?;
+
+        result
+    }
+
+    fn run_app<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
+        loop {
+            terminal.draw(|f| self.ui(f))?;
+            if let Event::Key(key) = event::read()?
+                && !self.handle_key_event(key)
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    fn search(&mut self) {
+        self.hits = self
+            .index
+            .search(&self.query, self.employee.as_deref(), MAX_HITS);
+        self.list_state.select((!self.hits.is_empty()).then_some(0));
+    }
+
+    fn selected(&self) -> Option<&SearchHit> {
+        self.hits.get(self.list_state.selected()?)
+    }
+
+    fn open_selected(&mut self) {
+        let Some(hit) = self.selected() else {
+            return;
+        };
+        let document = &hit.document;
+        if document.url.is_empty() {
+            self.message = Some(match document.kind {
+                DocumentKind::Note => format!(
+                    "Notes entries have no URL; run `reviewr notes show \"{}\"` to read them",
+                    document.employee
+                ),
+                DocumentKind::Item => format!("{} has no URL", document.id),
+            });
+            return;
+        }
+        let url = document.url.clone();
+        self.message = Some(match self.url_opener.open(&url) {
+            Ok(()) => format!("Opened {url}"),
+            Err(e) => format!("Could not open {url}: {e}"),
+        });
+    }
+
+    /// Returns false when the view should close
+    fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        self.message = None;
+        let rows = self.hits.len();
+        match key.code {
+            KeyCode::Esc => return false,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.query.clear();
+                self.search();
+            }
+            KeyCode::Down if rows > 0 => {
+                let selected = self.list_state.selected().unwrap_or(0);
+                self.list_state.select(Some((selected + 1).min(rows - 1)));
+            }
+            KeyCode::Up if rows > 0 => {
+                let selected = self.list_state.selected().unwrap_or(0);
+                self.list_state.select(Some(selected.saturating_sub(1)));
+            }
+            KeyCode::Enter => self.open_selected(),
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.search();
+            }
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                self.search();
+            }
+            _ => {}
+        }
+        true
+    }
+
+    fn hit_line(hit: &SearchHit) -> ListItem<'static> {
+        let document = &hit.document;
+        let (icon, title) = match document.kind {
+            DocumentKind::Item => ("🔗", format!("{} {}", document.id, document.title)),
+            DocumentKind::Note => ("📝", document.id.clone()),
+        };
+        ListItem::new(Line::from(vec![
+            Span::raw(format!("{icon} {title} ")),
+            Span::styled(
+                format!("· {} · {}", document.employee, document.source),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]))
+    }
+
+    fn detail_lines(hit: &SearchHit) -> Vec<Line<'static>> {
+        let document = &hit.document;
+        let field = |name: &str, value: String| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{name}: "),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(value),
+            ])
+        };
+        let mut lines = vec![
+            field("Employee", document.employee.clone()),
+            field("Source", document.source.clone()),
+            field("Date", document.date.clone()),
+        ];
+        match document.kind {
+            DocumentKind::Item => {
+                lines.push(field("Title", document.title.clone()));
+                lines.push(field("Details", document.detail.clone()));
+                if !document.url.is_empty() {
+                    lines.push(field("URL", document.url.clone()));
+                }
+            }
+            DocumentKind::Note => {
+                lines.push(field("Entry", document.id.clone()));
+                lines.push(Line::default());
+                lines.extend(
+                    document
+                        .body
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+            }
+        }
+        lines
+    }
+
+    fn ui(&mut self, f: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(5),
+                Constraint::Length(1),
+            ])
+            .split(f.area());
+
+        let scope = match &self.employee {
+            Some(employee) => format!("🔍 Search {employee}"),
+            None => "🔍 Search all employees".to_string(),
+        };
+        f.render_widget(
+            Paragraph::new(format!("{}▏", self.query))
+                .block(Block::default().borders(Borders::ALL).title(scope)),
+            chunks[0],
+        );
+
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[1]);
+        let items: Vec<ListItem> = self.hits.iter().map(Self::hit_line).collect();
+        let empty = items.is_empty();
+        let details = match self.selected() {
+            Some(hit) => Self::detail_lines(hit),
+            None if self.query.trim().is_empty() => vec![Line::from(
+                "Type to search fetched items and notes of every employee.",
+            )],
+            None => vec![Line::from("Nothing matches every word of the query.")],
+        };
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Results ({})", self.hits.len())),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▶ ");
+        if empty {
+            f.render_widget(list, panes[0]);
+        } else {
+            f.render_stateful_widget(list, panes[0], &mut self.list_state);
+        }
+        f.render_widget(
+            Paragraph::new(details)
+                .block(Block::default().borders(Borders::ALL).title("Details"))
+                .wrap(Wrap { trim: false }),
+            panes[1],
+        );
+
+        let footer = self.message.clone().unwrap_or_else(|| {
+            "type: search · ↓/↑: select · Enter: open URL · Ctrl-U: clear · Esc: quit".to_string()
+        });
+        f.render_widget(
+            Paragraph::new(footer).style(Style::default().fg(Color::DarkGray)),
+            chunks[2],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::DataPath;
+    use crate::core::search_index::SearchIndexService;
+    use crate::tui::test_terminal::{ctrl, key, screen, terminal};
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn index() -> SearchIndex {
+        let dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        fs::write(
+            data_path.notes_dir.join("Jane Doe.md"),
+            "## 2024-03-04 1:1\n\nTalked about the release plan.\n\n## 2024-03-11\n\nRelease went out.\n",
+        )
+        .unwrap();
+        fs::write(
+            data_path.notes_dir.join("Sam Lee.md"),
+            "## 2024-03-05\n\nReviewed the release checklist.\n",
+        )
+        .unwrap();
+        SearchIndexService::load_or_build(&data_path).unwrap()
+    }
+
+    #[test]
+    fn test_results_follow_the_query() {
+        let mut view = SearchView::new(index(), None, String::new());
+        assert!(view.hits.is_empty());
+
+        for c in "rel".chars() {
+            view.handle_key_event(key(KeyCode::Char(c)));
+        }
+        assert_eq!(view.hits.len(), 3);
+        for c in " plan".chars() {
+            view.handle_key_event(key(KeyCode::Char(c)));
+        }
+        assert_eq!(view.hits.len(), 1);
+        view.handle_key_event(key(KeyCode::Backspace));
+        assert_eq!(view.query, "rel pla");
+
+        let mut terminal = terminal(120, 20);
+        terminal.draw(|f| view.ui(f)).unwrap();
+        let shown = screen(&terminal);
+        assert!(shown.contains("rel pla▏"), "{shown}");
+        assert!(
+            shown.contains("2024-03-04 1:1 · Jane Doe · notes"),
+            "{shown}"
+        );
+        assert!(shown.contains("Talked about the release plan."), "{shown}");
+
+        // Notes have no URL to open
+        view.handle_key_event(key(KeyCode::Enter));
+        assert!(view.message.as_deref().unwrap().contains("notes show"));
+
+        view.handle_key_event(ctrl('u'));
+        assert!(view.query.is_empty() && view.hits.is_empty());
+        assert!(!view.handle_key_event(key(KeyCode::Esc)));
+    }
+
+    #[test]
+    fn test_employee_scope() {
+        let mut view = SearchView::new(index(), Some("Sam Lee".to_string()), "release".into());
+        assert_eq!(view.hits.len(), 1);
+        view.handle_key_event(key(KeyCode::Down));
+        assert_eq!(view.selected().unwrap().document.employee, "Sam Lee");
+    }
+}
@@ -1,6 +1,14 @@
-use crate::core::{employee::EmployeeService, models::DataPath};
+use crate::core::{
+    employee_index::{EmployeeIndexService, IndexedEmployee},
+    models::DataPath,
+    note_encryption::NoteEncryptionService,
+    notes::NotesService,
+    recent::RecentService,
+};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
@@ -11,36 +19,153 @@ use ratatui::{
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
 };
+use std::collections::HashMap;
 use std::io;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// How long to wait for a key before re-reading the employees directory
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Lines of the highlighted employee's notes shown in the preview pane
+const PREVIEW_LINES: usize = 40;
 
 pub struct EmployeeSelector {
+    data_path: DataPath,
     employees: Vec<String>,
+    /// Title, email and last notes date by employee
+    details: HashMap<String, IndexedEmployee>,
+    /// Notes preview of the employee it was last read for
+    preview: Option<(String, Vec<String>)>,
+    /// Scan of the employees directory running in the background
+    pending_scan: Option<Receiver<io::Result<Vec<IndexedEmployee>>>>,
+    /// Recently opened employees, most recent first
+    recent: Vec<String>,
     filtered_employees: Vec<(String, u32)>,
-    list_state: ListState,
+    table_state: TableState,
     input: String,
     matcher: Matcher,
 }
 
 impl EmployeeSelector {
+    /// Starts from the employee index when there is one; only the very first start reads the
+    /// employees directory before showing the list
     pub fn new(data_path: &DataPath) -> io::Result<Self> {
-        let employees = EmployeeService::list_employees(data_path)?;
-        let filtered_employees: Vec<(String, u32)> =
-            employees.iter().map(|e| (e.clone(), 0)).collect();
+        let index = match EmployeeIndexService::load(data_path) {
+            Some(index) => index,
+            None => EmployeeIndexService::rebuild(data_path)?,
+        };
+        let recent = RecentService::load(data_path);
+
+        let mut selector = Self {
+            data_path: data_path.clone(),
+            employees: Vec::new(),
+            details: HashMap::new(),
+            preview: None,
+            pending_scan: None,
+            recent,
+            filtered_employees: Vec::new(),
+            table_state: TableState::default(),
+            input: String::new(),
+            matcher: Matcher::new(Config::DEFAULT),
+        };
+        selector.set_employees(index);
+        selector.filter_employees();
+        Ok(selector)
+    }
+
+    fn set_employees(&mut self, index: Vec<IndexedEmployee>) {
+        self.employees = index.iter().map(|entry| entry.name.clone()).collect();
+        self.details = index
+            .into_iter()
+            .map(|entry| (entry.name.clone(), entry))
+            .collect();
+        // Notes may have changed along with the list
+        self.preview = None;
+    }
+
+    /// Re-read the employee list, keeping the search and, if it still exists, the selection.
+    /// Returns whether the list changed.
+    fn refresh(&mut self) -> io::Result<bool> {
+        let index = EmployeeIndexService::rebuild(&self.data_path)?;
+        Ok(self.update_employees(index))
+    }
 
-        let mut list_state = ListState::default();
-        if !filtered_employees.is_empty() {
-            list_state.select(Some(0));
+    /// Scan the employees directory on another thread unless a scan is already running
+    fn start_background_refresh(&mut self) {
+        if self.pending_scan.is_some() {
+            return;
         }
+        let (sender, receiver) = mpsc::channel();
+        let data_path = self.data_path.clone();
+        thread::spawn(move || {
+            // The selector may have closed already
+            let _ = sender.send(EmployeeIndexService::rebuild(&data_path));
+        });
+        self.pending_scan = Some(receiver);
+    }
 
-        Ok(Self {
-            employees,
-            filtered_employees,
-            list_state,
-            input: String::new(),
-            matcher: Matcher::new(Config::DEFAULT),
-        })
+    /// Apply a finished background scan, waiting at most `timeout` for it. Returns whether the
+    /// list changed.
+    fn poll_background_refresh(&mut self, timeout: Duration) -> bool {
+        let Some(receiver) = &self.pending_scan else {
+            return false;
+        };
+        let result = match receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => return false,
+            Err(RecvTimeoutError::Disconnected) => {
+                self.pending_scan = None;
+                return false;
+            }
+        };
+        self.pending_scan = None;
+        match result {
+            Ok(index) => self.update_employees(index),
+            Err(e) => {
+                log::warn!("Failed to reload employees: {e}");
+                false
+            }
+        }
+    }
+
+    /// Replace the list in place, keeping the search and, if it still exists, the selection
+    fn update_employees(&mut self, index: Vec<IndexedEmployee>) -> bool {
+        let unchanged = index.len() == self.employees.len()
+            && index
+                .iter()
+                .zip(&self.employees)
+                .all(|(entry, name)| self.details.get(name) == Some(entry));
+        if unchanged {
+            return false;
+        }
+
+        let selected = self
+            .table_state
+            .selected()
+            .and_then(|index| self.filtered_employees.get(index))
+            .map(|(name, _)| name.clone());
+        self.set_employees(index);
+        self.filter_employees();
+        if let Some(index) = selected.and_then(|selected| {
+            self.filtered_employees
+                .iter()
+                .position(|(name, _)| *name == selected)
+        }) {
+            self.table_state.select(Some(index));
+        }
+        true
+    }
+
+    /// Position in the recent list; employees never opened sort after all recent ones
+    fn recency_rank(&self, employee: &str) -> usize {
+        self.recent
+            .iter()
+            .position(|name| name == employee)
+            .unwrap_or(usize::MAX)
     }
 
     pub fn run(&mut self) -> io::Result<Option<String>> {
@@ -64,9 +189,18 @@ impl EmployeeSelector {
     }
 
     fn run_app<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<Option<String>> {
+        // The list shown first may come from the index; bring it up to date without blocking
+        self.start_background_refresh();
         loop {
             terminal.draw(|f| self.ui(f))?;
 
+            // Pick up employees added or removed elsewhere while the selector is open
+            if !event::poll(REFRESH_INTERVAL)? {
+                if !self.poll_background_refresh(Duration::ZERO) {
+                    self.start_background_refresh();
+                }
+                continue;
+            }
             if let Event::Key(key) = event::read()? {
                 match self.handle_key_event(key) {
                     Some(result) => return Ok(result),
@@ -78,6 +212,12 @@ impl EmployeeSelector {
 
     fn handle_key_event(&mut self, key: KeyEvent) -> Option<Option<String>> {
         match key.code {
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Err(e) = self.refresh() {
+                    log::warn!("Failed to reload employees: {e}");
+                }
+                None
+            }
             KeyCode::Char(c) => {
                 self.input.push(c);
                 self.filter_employees();
@@ -89,7 +229,7 @@ impl EmployeeSelector {
                 None
             }
             KeyCode::Enter => {
-                if let Some(selected) = self.list_state.selected()
+                if let Some(selected) = self.table_state.selected()
                     && selected < self.filtered_employees.len()
                 {
                     return Some(Some(self.filtered_employees[selected].0.clone()));
@@ -97,20 +237,20 @@ impl EmployeeSelector {
                 None
             }
             KeyCode::Up => {
-                if let Some(selected) = self.list_state.selected()
+                if let Some(selected) = self.table_state.selected()
                     && selected > 0
                 {
-                    self.list_state.select(Some(selected - 1));
+                    self.table_state.select(Some(selected - 1));
                 }
                 None
             }
             KeyCode::Down => {
-                if let Some(selected) = self.list_state.selected()
+                if let Some(selected) = self.table_state.selected()
                     && selected + 1 < self.filtered_employees.len()
                 {
-                    self.list_state.select(Some(selected + 1));
+                    self.table_state.select(Some(selected + 1));
                 } else if !self.filtered_employees.is_empty() {
-                    self.list_state.select(Some(0));
+                    self.table_state.select(Some(0));
                 }
                 None
             }
@@ -119,25 +259,65 @@ impl EmployeeSelector {
         }
     }
 
+    /// Name, title and email, so a query can match any of them
+    fn searched_text(&self, employee: &str) -> String {
+        match self.details.get(employee) {
+            Some(details) => format!("{employee}  {}  {}", details.title, details.email),
+            None => employee.to_string(),
+        }
+    }
+
+    fn selected_employee(&self) -> Option<&str> {
+        self.filtered_employees
+            .get(self.table_state.selected()?)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// The top of the highlighted employee's notes, read once per highlighted employee
+    fn preview_lines(&mut self) -> Vec<String> {
+        let Some(employee) = self.selected_employee().map(str::to_string) else {
+            return Vec::new();
+        };
+        if let Some((name, lines)) = &self.preview
+            && *name == employee
+        {
+            return lines.clone();
+        }
+        let lines = if NoteEncryptionService::encrypted_path(&self.data_path, &employee).exists() {
+            vec!["🔒 The notes are encrypted".to_string()]
+        } else {
+            NotesService::preview(&self.data_path, &employee, PREVIEW_LINES)
+                .unwrap_or_else(|| vec!["No notes yet".to_string()])
+        };
+        self.preview = Some((employee, lines.clone()));
+        lines
+    }
+
     fn filter_employees(&mut self) {
         if self.input.is_empty() {
-            self.filtered_employees = self.employees.iter().map(|e| (e.clone(), 0)).collect();
+            let mut employees: Vec<(String, u32)> =
+                self.employees.iter().map(|e| (e.clone(), 0)).collect();
+            employees.sort_by_key(|(name, _)| self.recency_rank(name));
+            self.filtered_employees = employees;
         } else {
             let mut matches = Vec::new();
             for employee in &self.employees {
                 let mut haystack_buf = Vec::new();
                 let mut needle_buf = Vec::new();
-                let haystack = Utf32Str::new(employee, &mut haystack_buf);
+                let searched = self.searched_text(employee);
+                let haystack = Utf32Str::new(&searched, &mut haystack_buf);
                 let needle = Utf32Str::new(&self.input, &mut needle_buf);
                 if let Some(score) = self.matcher.fuzzy_match(haystack, needle) {
                     matches.push((employee.clone(), score as u32));
                 }
             }
-            matches.sort_by(|a, b| b.1.cmp(&a.1));
+            // Equal scores keep recently opened employees on top
+            matches
+                .sort_by_key(|(name, score)| (std::cmp::Reverse(*score), self.recency_rank(name)));
             self.filtered_employees = matches;
         }
 
-        self.list_state
+        self.table_state
             .select(if self.filtered_employees.is_empty() {
                 None
             } else {
@@ -156,32 +336,74 @@ impl EmployeeSelector {
             .block(Block::default().borders(Borders::ALL).title("Search"));
         f.render_widget(input, chunks[0]);
 
-        let items: Vec<ListItem> = self
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+            .split(chunks[1]);
+
+        let header = Row::new(["Name", "Title", "Email", "Last note"].map(Cell::from))
+            .style(Style::default().add_modifier(Modifier::BOLD));
+        let dim = Style::default().fg(Color::Gray);
+        let rows: Vec<Row> = self
             .filtered_employees
             .iter()
-            .map(|(name, score)| {
-                let content = if *score > 0 {
-                    Line::from(vec![
-                        Span::raw(name.clone()),
-                        Span::styled(format!(" ({score})"), Style::default().fg(Color::Gray)),
-                    ])
-                } else {
-                    Line::from(Span::raw(name.clone()))
+            .map(|(name, _)| {
+                let mut spans = vec![Span::raw(name.clone())];
+                if self.recent.contains(name) {
+                    spans.push(Span::styled(
+                        " · recent",
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                let details = self.details.get(name);
+                let field = |value: Option<String>| {
+                    Cell::from(value.filter(|value| !value.is_empty()).unwrap_or_default())
+                        .style(dim)
                 };
-                ListItem::new(content)
+                Row::new(vec![
+                    Cell::from(Line::from(spans)),
+                    field(details.map(|details| details.title.clone())),
+                    field(details.map(|details| details.email.clone())),
+                    field(details.and_then(|details| {
+                        details
+                            .last_note
+                            .map(|date| date.format("%Y-%m-%d").to_string())
+                    })),
+                ])
             })
             .collect();
-
-        let items = List::new(items)
-            .block(Block::default().borders(Borders::ALL).title("Employees"))
+        let widths = [
+            Constraint::Percentage(30),
+            Constraint::Percentage(25),
+            Constraint::Percentage(30),
+            Constraint::Length(10),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Employees (Ctrl+R to reload)"),
+            )
             .highlight_style(
                 Style::default()
                     .add_modifier(Modifier::BOLD)
                     .bg(Color::DarkGray),
             )
             .highlight_symbol(">> ");
-
-        f.render_stateful_widget(items, chunks[1], &mut self.list_state);
+        f.render_stateful_widget(table, panes[0], &mut self.table_state);
+
+        let title = match self.selected_employee() {
+            Some(employee) => format!("Notes of {employee}"),
+            None => "Notes".to_string(),
+        };
+        let preview: Vec<Line> = self.preview_lines().into_iter().map(Line::from).collect();
+        f.render_widget(
+            Paragraph::new(preview)
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .wrap(Wrap { trim: false }),
+            panes[1],
+        );
     }
 }
 
@@ -298,7 +520,7 @@ title = "Designer""#,
     }
 
     #[test]
-    fn test_list_state_selection() {
+    fn test_table_state_selection() {
         let temp_dir = tempdir().unwrap();
         let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
 
@@ -312,7 +534,259 @@ title = "Designer""#,
         let selector = EmployeeSelector::new(&data_path).unwrap();
 
         // Should auto-select first item when there are employees
-        assert_eq!(selector.list_state.selected(), Some(0));
+        assert_eq!(selector.table_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_recent_employees_first_when_query_empty() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        for name in ["alice", "bob", "carol"] {
+            fs::write(
+                data_path.employees_dir.join(format!("{name}.toml")),
+                format!("name = \"{name}\"\ntitle = \"Dev\""),
+            )
+            .unwrap();
+        }
+        RecentService::record(&data_path, "bob").unwrap();
+        RecentService::record(&data_path, "carol").unwrap();
+
+        let mut selector = EmployeeSelector::new(&data_path).unwrap();
+        let names: Vec<&str> = selector
+            .filtered_employees
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
+        assert_eq!(names[..2], ["carol", "bob"]);
+        assert_eq!(names.len(), 3);
+
+        // A query ranks by fuzzy score again
+        selector.input = "ali".to_string();
+        selector.filter_employees();
+        assert_eq!(selector.filtered_employees[0].0, "alice");
+    }
+
+    #[test]
+    fn test_refresh_picks_up_new_employees() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        for name in ["alice", "carol"] {
+            fs::write(
+                data_path.employees_dir.join(format!("{name}.toml")),
+                format!("name = \"{name}\"\ntitle = \"Dev\""),
+            )
+            .unwrap();
+        }
+
+        let mut selector = EmployeeSelector::new(&data_path).unwrap();
+        selector.handle_key_event(KeyEvent::from(KeyCode::Down));
+        assert_eq!(selector.table_state.selected(), Some(1));
+        assert!(!selector.refresh().unwrap());
+
+        fs::write(
+            data_path.employees_dir.join("bob.toml"),
+            "name = \"bob\"\ntitle = \"Dev\"",
+        )
+        .unwrap();
+        let reload = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        assert_eq!(selector.handle_key_event(reload), None);
+
+        assert_eq!(selector.employees, vec!["alice", "bob", "carol"]);
+        assert!(selector.input.is_empty());
+        // "carol" stays selected although it moved down
+        assert_eq!(selector.table_state.selected(), Some(2));
+    }
+
+    #[test]
+    fn test_rendered_search_and_selection() {
+        use crate::tui::test_terminal::{ctrl, key, screen, terminal};
+
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        for name in ["alice", "bob", "carol"] {
+            fs::write(
+                data_path.employees_dir.join(format!("{name}.toml")),
+                format!("name = \"{name}\"\ntitle = \"Dev\""),
+            )
+            .unwrap();
+        }
+
+        let mut selector = EmployeeSelector::new(&data_path).unwrap();
+        let mut terminal = terminal(60, 12);
+        terminal.draw(|f| selector.ui(f)).unwrap();
+        let initial = screen(&terminal);
+        assert!(initial.contains("Employees (Ctrl+R to reload)"));
+        assert!(initial.contains(">> alice"));
+
+        for c in "car".chars() {
+            assert_eq!(selector.handle_key_event(key(KeyCode::Char(c))), None);
+        }
+        terminal.draw(|f| selector.ui(f)).unwrap();
+        let filtered = screen(&terminal);
+        assert!(filtered.contains("│car"));
+        assert!(filtered.contains(">> carol"));
+        assert!(!filtered.contains("alice"));
+
+        for _ in 0..3 {
+            selector.handle_key_event(key(KeyCode::Backspace));
+        }
+        selector.handle_key_event(key(KeyCode::Down));
+        fs::write(
+            data_path.employees_dir.join("dave.toml"),
+            "name = \"dave\"\ntitle = \"Dev\"",
+        )
+        .unwrap();
+        selector.handle_key_event(ctrl('r'));
+        terminal.draw(|f| selector.ui(f)).unwrap();
+        let reloaded = screen(&terminal);
+        assert!(reloaded.contains(">> bob"));
+        assert!(reloaded.contains("dave"));
+
+        assert_eq!(
+            selector.handle_key_event(key(KeyCode::Enter)),
+            Some(Some("bob".to_string()))
+        );
+        assert_eq!(selector.handle_key_event(key(KeyCode::Esc)), Some(None));
+    }
+
+    #[test]
+    fn test_starts_from_index_then_refreshes_in_background() {
+        use crate::tui::test_terminal::{screen, terminal};
+
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        for (name, title) in [("alice", "Engineer"), ("bob", "Designer")] {
+            fs::write(
+                data_path.employees_dir.join(format!("{name}.toml")),
+                format!("name = \"{name}\"\ntitle = \"{title}\""),
+            )
+            .unwrap();
+        }
+        EmployeeSelector::new(&data_path).unwrap();
+
+        // Changes made since the last run only show up after the background scan
+        fs::remove_file(data_path.employees_dir.join("alice.toml")).unwrap();
+        fs::write(
+            data_path.employees_dir.join("carol.toml"),
+            "name = \"carol\"\ntitle = \"Manager\"",
+        )
+        .unwrap();
+        let mut selector = EmployeeSelector::new(&data_path).unwrap();
+        assert_eq!(selector.employees, vec!["alice", "bob"]);
+        selector.handle_key_event(KeyEvent::from(KeyCode::Down));
+        let mut terminal = terminal(120, 12);
+        terminal.draw(|f| selector.ui(f)).unwrap();
+        let shown = screen(&terminal);
+        assert!(
+            shown
+                .lines()
+                .any(|line| line.contains(">> bob") && line.contains("Designer")),
+            "{shown}"
+        );
+
+        selector.start_background_refresh();
+        assert!(selector.poll_background_refresh(Duration::from_secs(10)));
+        assert_eq!(selector.employees, vec!["bob", "carol"]);
+        assert_eq!(selector.table_state.selected(), Some(0));
+        terminal.draw(|f| selector.ui(f)).unwrap();
+        let shown = screen(&terminal);
+        assert!(
+            shown
+                .lines()
+                .any(|line| line.contains("carol") && line.contains("Manager")),
+            "{shown}"
+        );
+
+        // Nothing pending, nothing changed
+        assert!(!selector.poll_background_refresh(Duration::ZERO));
+        selector.start_background_refresh();
+        assert!(!selector.poll_background_refresh(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn test_failed_periodic_reload_keeps_the_list() {
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        fs::write(
+            data_path.employees_dir.join("alice.toml"),
+            "name = \"alice\"\ntitle = \"Dev\"",
+        )
+        .unwrap();
+        let mut selector = EmployeeSelector::new(&data_path).unwrap();
+        assert_eq!(selector.employees, vec!["alice"]);
+
+        // The employees directory can no longer be read
+        fs::remove_dir_all(&data_path.employees_dir).unwrap();
+        fs::write(&data_path.employees_dir, "not a directory").unwrap();
+        selector.start_background_refresh();
+        assert!(!selector.poll_background_refresh(Duration::from_secs(10)));
+        assert_eq!(selector.employees, vec!["alice"]);
+        assert!(selector.pending_scan.is_none());
+    }
+
+    #[test]
+    fn test_columns_field_search_and_notes_preview() {
+        use crate::tui::test_terminal::{key, screen, terminal};
+
+        let temp_dir = tempdir().unwrap();
+        let data_path = DataPath::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        fs::create_dir_all(&data_path.employees_dir).unwrap();
+        fs::create_dir_all(&data_path.notes_dir).unwrap();
+        fs::write(
+            data_path.employees_dir.join("alice.toml"),
+            "name = \"alice\"\ntitle = \"Engineer\"\ncommitter_email = \"alice@corp.test\"",
+        )
+        .unwrap();
+        fs::write(
+            data_path.employees_dir.join("bob.toml"),
+            "name = \"bob\"\ntitle = \"Designer\"\ncommitter_email = \"bob@studio.test\"",
+        )
+        .unwrap();
+        fs::write(
+            data_path.notes_dir.join("alice.md"),
+            "# Notes for alice\n\n## 2024-03-04 1:1\nTalked about the release plan.\n",
+        )
+        .unwrap();
+
+        let mut selector = EmployeeSelector::new(&data_path).unwrap();
+        let mut terminal = terminal(120, 12);
+        terminal.draw(|f| selector.ui(f)).unwrap();
+        let shown = screen(&terminal);
+        assert!(shown.contains("Last note"), "{shown}");
+        assert!(
+            shown.lines().any(|line| line.contains(">> alice")
+                && line.contains("alice@corp.test")
+                && line.contains("2024-03-04")),
+            "{shown}"
+        );
+        assert!(shown.contains("Notes of alice"), "{shown}");
+        assert!(shown.contains("Talked about the release plan."), "{shown}");
+
+        // Titles and emails match too
+        for c in "studio".chars() {
+            selector.handle_key_event(key(KeyCode::Char(c)));
+        }
+        assert_eq!(selector.selected_employee(), Some("bob"));
+        terminal.draw(|f| selector.ui(f)).unwrap();
+        let shown = screen(&terminal);
+        assert!(shown.contains("Notes of bob"), "{shown}");
+        assert!(shown.contains("No notes yet"), "{shown}");
+
+        for _ in 0..6 {
+            selector.handle_key_event(key(KeyCode::Backspace));
+        }
+        for c in "engin".chars() {
+            selector.handle_key_event(key(KeyCode::Char(c)));
+        }
+        assert_eq!(selector.filtered_employees.len(), 1);
+        assert_eq!(selector.selected_employee(), Some("alice"));
     }
 
     #[test]
@@ -325,6 +799,6 @@ title = "Designer""#,
         let selector = EmployeeSelector::new(&data_path).unwrap();
 
         // Should have no selection when empty
-        assert_eq!(selector.list_state.selected(), None);
+        assert_eq!(selector.table_state.selected(), None);
     }
 }
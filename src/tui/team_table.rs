@@ -0,0 +1,296 @@
+//! Comparative table of `reviewr team-review`: one row per person, one column per category
+
+use crate::core::platform::ActivityCategory;
+use crate::core::scoring::format_index;
+use crate::core::team::{TeamComparison, TeamMember};
+use crossterm::{
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use ratatui::{
+    Frame, Terminal,
+    backend::{Backend, CrosstermBackend},
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+use std::io;
+
+pub struct TeamReviewView {
+    title: String,
+    team: TeamComparison,
+    categories: Vec<ActivityCategory>,
+    /// 0 is the name, then one per category, then the total and the activity index
+    sort_column: usize,
+    descending: bool,
+    table_state: TableState,
+}
+
+impl TeamReviewView {
+    pub fn new(title: String, team: TeamComparison) -> Self {
+        let categories = team.categories();
+        let table_state = TableState::default().with_selected(Some(0));
+        Self {
+            title,
+            team,
+            categories,
+            sort_column: 0,
+            descending: false,
+            table_state,
+        }
+    }
+
+    pub fn run(&mut self) -> io::Result<()> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let result = self.run_app(&mut terminal);
+
+        disable_raw_mode()?;
+        execute!(
+            terminal.backend_mut(),
+            LeaveAlternateScreen,
+            DisableMouseCapture
+        )?;
+        terminal.show_cursor()?;
+
+        result
+    }
+
+    fn run_app<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
+        loop {
+            terminal.draw(|f| self.ui(f))?;
+            if let Event::Key(key) = event::read()?
+                && !self.handle_key_event(key)
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Returns false when the view should close
+    fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        let rows = self.team.members.len();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Down | KeyCode::Char('j') if rows > 0 => {
+                let selected = self.table_state.selected().unwrap_or(0);
+                self.table_state.select(Some((selected + 1).min(rows - 1)));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                let selected = self.table_state.selected().unwrap_or(0);
+                self.table_state.select(Some(selected.saturating_sub(1)));
+            }
+            KeyCode::Char('o') => self.cycle_sort_column(),
+            KeyCode::Char('O') => self.descending = !self.descending,
+            _ => {}
+        }
+        true
+    }
+
+    fn column_count(&self) -> usize {
+        self.categories.len() + 2 + usize::from(self.team.has_activity_index())
+    }
+
+    /// Move to the next column; numbers start with the largest first
+    pub fn cycle_sort_column(&mut self) {
+        self.sort_column = (self.sort_column + 1) % self.column_count();
+        self.descending = self.sort_column != 0;
+    }
+
+    fn sort_value(&self, member: &TeamMember) -> f64 {
+        match self.sort_column {
+            0 => 0.0,
+            column if column <= self.categories.len() => {
+                member.count(&self.categories[column - 1]) as f64
+            }
+            column if column == self.categories.len() + 1 => member.total() as f64,
+            _ => member.activity_index.unwrap_or_default(),
+        }
+    }
+
+    /// Members in display order; ties fall back to the name
+    pub fn ordered_members(&self) -> Vec<&TeamMember> {
+        let mut members: Vec<&TeamMember> = self.team.members.iter().collect();
+        members.sort_by(|a, b| {
+            let primary = self.sort_value(a).total_cmp(&self.sort_value(b));
+            let by_name = a.name.cmp(&b.name);
+            if self.descending {
+                primary.reverse().then(if self.sort_column == 0 {
+                    by_name.reverse()
+                } else {
+                    by_name
+                })
+            } else {
+                primary.then(by_name)
+            }
+        });
+        members
+    }
+
+    fn header(&self, column: usize, title: &str) -> String {
+        if column != self.sort_column {
+            return title.to_string();
+        }
+        let arrow = if self.descending { "▼" } else { "▲" };
+        format!("{title} {arrow}")
+    }
+
+    fn ui(&mut self, f: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(3)])
+            .split(f.area());
+
+        let mut titles = vec![self.header(0, "Employee")];
+        titles.extend(
+            self.categories
+                .iter()
+                .enumerate()
+                .map(|(i, category)| self.header(i + 1, category.display_name())),
+        );
+        titles.push(self.header(self.categories.len() + 1, "Total"));
+        let with_index = self.team.has_activity_index();
+        if with_index {
+            titles.push(self.header(self.categories.len() + 2, "Index*"));
+        }
+        let header = Row::new(titles.into_iter().map(Cell::from))
+            .style(Style::default().add_modifier(Modifier::BOLD));
+
+        let rows: Vec<Row> =
+            self.ordered_members()
+                .into_iter()
+                .map(|member| {
+                    let name = if member.failed_platforms.is_empty() {
+                        Cell::from(member.name.clone())
+                    } else {
+                        // Incomplete counts are marked, listing the platforms that failed
+                        Cell::from(format!(
+                            "{} ⚠ {}",
+                            member.name,
+                            member.failed_platforms.join(", ")
+                        ))
+                        .style(Style::default().fg(Color::Yellow))
+                    };
+                    let mut cells = vec![name];
+                    cells.extend(self.categories.iter().map(|category| {
+                        Cell::from(format!("{:>5}", member.count_label(category)))
+                    }));
+                    cells.push(Cell::from(format!("{:>5}", member.total())));
+                    if let Some(index) = member.activity_index {
+                        cells.push(Cell::from(format!("{:>7}", format_index(index))));
+                    }
+                    Row::new(cells)
+                })
+                .collect();
+
+        let mut widths = vec![Constraint::Min(20)];
+        widths.extend(
+            self.categories
+                .iter()
+                .map(|category| Constraint::Length(category.display_name().len().max(7) as u16)),
+        );
+        widths.push(Constraint::Length(7));
+        if with_index {
+            widths.push(Constraint::Length(9));
+        }
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.title.clone()),
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("▶ ");
+        f.render_stateful_widget(table, chunks[0], &mut self.table_state);
+
+        let mut footer = "↑/↓: Move | o: Sort column | O: Reverse | q/Esc: Quit".to_string();
+        if with_index {
+            footer.push_str(" | *Index: heuristic from the [scoring] weights, not a rating");
+        }
+        let footer = Paragraph::new(footer).block(Block::default().borders(Borders::ALL));
+        f.render_widget(footer, chunks[1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::platform::DetailedActivities;
+    use crate::core::scoring::ScoringConfig;
+    use crate::core::test_support::ItemBuilder;
+
+    fn activities(category: ActivityCategory, count: usize) -> DetailedActivities {
+        let items = (0..count)
+            .map(|id| {
+                ItemBuilder::new(&id.to_string())
+                    .with_category(category.clone())
+                    .build()
+            })
+            .collect();
+        let mut activities = DetailedActivities::default();
+        activities.items_by_category.insert(category, items);
+        activities
+    }
+
+    fn names(view: &TeamReviewView) -> Vec<&str> {
+        view.ordered_members()
+            .into_iter()
+            .map(|member| member.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_team_sort_order() {
+        let mut team = TeamComparison::new(["Alice", "Bob", "Carol"]);
+        team.add("Alice", &activities(ActivityCategory::ChangesMerged, 1));
+        team.add("Bob", &activities(ActivityCategory::ChangesMerged, 4));
+        team.add("Carol", &activities(ActivityCategory::ReviewsGiven, 6));
+        let mut view = TeamReviewView::new("Team".to_string(), team);
+        assert_eq!(names(&view), vec!["Alice", "Bob", "Carol"]);
+
+        // Changes Merged, largest first
+        view.cycle_sort_column();
+        assert_eq!(view.header(1, "Changes Merged"), "Changes Merged ▼");
+        assert_eq!(names(&view), vec!["Bob", "Alice", "Carol"]);
+
+        // Reviews Given, then the total
+        view.cycle_sort_column();
+        assert_eq!(names(&view), vec!["Carol", "Alice", "Bob"]);
+        view.cycle_sort_column();
+        assert_eq!(names(&view), vec!["Carol", "Bob", "Alice"]);
+
+        view.cycle_sort_column();
+        assert_eq!(view.sort_column, 0);
+        view.descending = true;
+        assert_eq!(names(&view), vec!["Carol", "Bob", "Alice"]);
+    }
+
+    #[test]
+    fn test_sort_by_activity_index() {
+        let mut team = TeamComparison::new(["Alice", "Bob"]);
+        team.add("Alice", &activities(ActivityCategory::ChangesMerged, 1));
+        team.add("Bob", &activities(ActivityCategory::ReviewsGiven, 3));
+        let scoring = ScoringConfig {
+            weights: [("Changes Merged".to_string(), 5.0)].into(),
+            ..ScoringConfig::default()
+        };
+        scoring.apply(&mut team, 30);
+        let mut view = TeamReviewView::new("Team".to_string(), team);
+
+        // Changes Merged, Reviews Given, Total, then the index
+        for _ in 0..4 {
+            view.cycle_sort_column();
+        }
+        assert_eq!(view.header(4, "Index*"), "Index* ▼");
+        assert_eq!(names(&view), vec!["Alice", "Bob"]);
+        view.cycle_sort_column();
+        assert_eq!(view.sort_column, 0);
+    }
+}
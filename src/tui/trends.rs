@@ -0,0 +1,287 @@
+//! Weekly activity buckets and the charts of the browser's trends view
+
+use crate::core::models::{Absence, days_absent};
+use crate::core::platform::ActivityItem;
+use crate::core::timestamps::parse_timestamp;
+use chrono::{DateTime, Duration, Utc};
+use ratatui::{
+    style::{Color, Style},
+    text::Line,
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Sparkline},
+};
+
+/// Direction of activity over the review period
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Growing,
+    Shrinking,
+    Steady,
+}
+
+impl Trend {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Trend::Growing => "↗ growing",
+            Trend::Shrinking => "↘ shrinking",
+            Trend::Steady => "→ steady",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Trend::Growing => Color::Green,
+            Trend::Shrinking => Color::Red,
+            Trend::Steady => Color::Gray,
+        }
+    }
+}
+
+/// Items per week, oldest week first
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrendSeries {
+    pub label: String,
+    pub weeks: Vec<u64>,
+    /// Weeks spent mostly out of office, parallel to `weeks`; empty when there are none
+    pub absent: Vec<bool>,
+}
+
+impl TrendSeries {
+    pub fn total(&self) -> u64 {
+        self.weeks.iter().sum()
+    }
+
+    fn is_absent(&self, week: usize) -> bool {
+        self.absent.get(week).copied().unwrap_or(false)
+    }
+
+    /// Compare the second half of the period with the first, skipping weeks out of office; a
+    /// change of 10% or less is steady
+    pub fn trend(&self) -> Trend {
+        let weeks: Vec<u64> = (0..self.weeks.len())
+            .filter(|&week| !self.is_absent(week))
+            .map(|week| self.weeks[week])
+            .collect();
+        let half = weeks.len() / 2;
+        if half == 0 {
+            return Trend::Steady;
+        }
+        let earlier: u64 = weeks[..half].iter().sum();
+        let later: u64 = weeks[weeks.len() - half..].iter().sum();
+        if later * 10 > earlier * 11 {
+            Trend::Growing
+        } else if later * 10 < earlier * 9 {
+            Trend::Shrinking
+        } else {
+            Trend::Steady
+        }
+    }
+
+    fn title(&self) -> String {
+        format!(
+            "{}: {} · {}",
+            self.label,
+            self.total(),
+            self.trend().label()
+        )
+    }
+}
+
+/// Weekly series for the whole period, each platform and each category
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActivityTrends {
+    pub total: TrendSeries,
+    pub by_platform: Vec<TrendSeries>,
+    pub by_category: Vec<TrendSeries>,
+}
+
+/// Number of weekly buckets covering `days`
+pub fn week_count(days: u32) -> usize {
+    days.div_ceil(7).max(1) as usize
+}
+
+/// Count items by the week their `created` timestamp falls in, the last bucket being the week
+/// ending at `now`. Items outside the last `days` days or without a readable date are skipped.
+pub fn weekly_buckets<'a>(
+    items: impl IntoIterator<Item = &'a ActivityItem>,
+    days: u32,
+    now: DateTime<Utc>,
+) -> Vec<u64> {
+    weekly_sums(items, days, now, |_| 1)
+}
+
+/// Like [`weekly_buckets`], but each item adds `weight(item)` to its week instead of one
+pub fn weekly_sums<'a>(
+    items: impl IntoIterator<Item = &'a ActivityItem>,
+    days: u32,
+    now: DateTime<Utc>,
+    weight: impl Fn(&ActivityItem) -> u64,
+) -> Vec<u64> {
+    let weeks = week_count(days);
+    let mut buckets = vec![0; weeks];
+    for item in items {
+        let Some(created) = parse_timestamp(&item.created) else {
+            continue;
+        };
+        // Clock skew can put fresh items slightly in the future
+        let age = (now - created).max(Duration::zero());
+        if age > Duration::days(days as i64) {
+            continue;
+        }
+        let weeks_ago = (age.num_days() / 7) as usize;
+        if weeks_ago < weeks {
+            buckets[weeks - 1 - weeks_ago] += weight(item);
+        }
+    }
+    buckets
+}
+
+/// Which buckets of [`weekly_buckets`] were spent out of office: four or more of their seven
+/// days fall in `absences`
+pub fn absent_weeks(absences: &[Absence], days: u32, now: DateTime<Utc>) -> Vec<bool> {
+    if absences.is_empty() {
+        return Vec::new();
+    }
+    let weeks = week_count(days);
+    let today = now.date_naive();
+    (0..weeks)
+        .map(|week| {
+            let weeks_ago = (weeks - 1 - week) as i64;
+            let last = today - Duration::days(weeks_ago * 7);
+            days_absent(absences, last - Duration::days(6), last) >= 4
+        })
+        .collect()
+}
+
+/// Start date (`MM-DD`) of each bucket returned by [`weekly_buckets`]
+pub fn week_labels(days: u32, now: DateTime<Utc>) -> Vec<String> {
+    let weeks = week_count(days);
+    (0..weeks)
+        .map(|week| {
+            let weeks_ago = (weeks - week) as i64;
+            (now - Duration::days(weeks_ago * 7 - 1))
+                .format("%m-%d")
+                .to_string()
+        })
+        .collect()
+}
+
+/// Bar chart of all activity per week, weeks out of office in grey; labels are dropped when
+/// the bars get too narrow
+pub fn weekly_bar_chart<'a>(
+    series: &TrendSeries,
+    labels: &[String],
+    width: u16,
+    title: String,
+) -> BarChart<'a> {
+    let weeks = series.weeks.len().max(1) as u16;
+    let bar_width = (width.saturating_sub(2) / weeks).saturating_sub(1).max(1);
+    let bars: Vec<Bar> = series
+        .weeks
+        .iter()
+        .zip(labels)
+        .enumerate()
+        .map(|(week, (count, label))| {
+            let mut bar = Bar::default().value(*count);
+            if series.is_absent(week) {
+                bar = bar.style(Style::default().fg(Color::DarkGray));
+            }
+            if bar_width >= 5 {
+                bar.label(Line::from(label.clone()))
+            } else {
+                bar.text_value(String::new())
+            }
+        })
+        .collect();
+
+    BarChart::default()
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .bar_style(Style::default().fg(series.trend().color()))
+}
+
+/// One-row sparkline of a series, titled with its total and trend
+pub fn trend_sparkline(series: &TrendSeries) -> Sparkline<'_> {
+    Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title(series.title()))
+        .data(&series.weeks)
+        .style(Style::default().fg(series.trend().color()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_support::ItemBuilder;
+
+    fn item(created: &str) -> ActivityItem {
+        ItemBuilder::new("1")
+            .with_created(created)
+            .with_updated(created)
+            .build()
+    }
+
+    #[test]
+    fn test_weekly_buckets() {
+        let now = DateTime::parse_from_rfc3339("2024-03-29T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let items = [
+            item("2024-03-29T08:00:00Z"), // this week
+            item("2024-03-30T08:00:00Z"), // slightly in the future
+            item("2024-03-20T08:00:00Z"), // 9 days ago
+            item("2024-03-01 10:00:00"),  // 28 days ago
+            item("2024-02-01T10:00:00Z"), // outside the period
+            item("not a date"),
+        ];
+
+        assert_eq!(week_count(28), 4);
+        assert_eq!(week_count(30), 5);
+        assert_eq!(weekly_buckets(&items, 28, now), vec![0, 0, 1, 2]);
+        assert_eq!(weekly_buckets(&items, 30, now), vec![1, 0, 0, 1, 2]);
+        assert_eq!(
+            week_labels(28, now),
+            vec!["03-02", "03-09", "03-16", "03-23"]
+        );
+    }
+
+    #[test]
+    fn test_trend_direction() {
+        let series = |weeks: Vec<u64>| TrendSeries {
+            label: "All".to_string(),
+            weeks,
+            absent: Vec::new(),
+        };
+        assert_eq!(series(vec![1, 2, 3, 5]).trend(), Trend::Growing);
+        assert_eq!(series(vec![5, 3, 2, 1]).trend(), Trend::Shrinking);
+        assert_eq!(series(vec![5, 5, 9, 4, 6]).trend(), Trend::Steady);
+        assert_eq!(series(vec![7]).trend(), Trend::Steady);
+        assert_eq!(series(vec![1, 2, 3, 5]).total(), 11);
+
+        // A vacation in the last weeks is no dip
+        let vacation = TrendSeries {
+            absent: vec![false, false, false, true, true],
+            ..series(vec![4, 5, 4, 0, 0])
+        };
+        assert_eq!(vacation.trend(), Trend::Steady);
+    }
+
+    #[test]
+    fn test_absent_weeks() {
+        let now = DateTime::parse_from_rfc3339("2024-03-29T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let date = |s| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let absences = [Absence {
+            start: date("2024-03-11"),
+            end: date("2024-03-19"),
+            reason: None,
+        }];
+        // Weeks end on 03-08, 03-15, 03-22 and 03-29; 03-16 to 03-22 has four days off
+        assert_eq!(
+            absent_weeks(&absences, 28, now),
+            vec![false, true, true, false]
+        );
+        assert!(absent_weeks(&[], 28, now).is_empty());
+    }
+}
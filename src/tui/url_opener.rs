@@ -0,0 +1,101 @@
+//! Opening item URLs, in the system browser or through the configured `browser_command`
+
+use std::io;
+use std::process::{Command, Stdio};
+
+/// Placeholder in `browser_command` replaced by the URL
+const URL_PLACEHOLDER: &str = "{url}";
+
+#[derive(Debug, Clone, Default)]
+pub struct UrlOpener {
+    command: Option<String>,
+}
+
+impl UrlOpener {
+    /// A blank command falls back to the system browser
+    pub fn new(command: Option<String>) -> Self {
+        Self {
+            command: command.filter(|command| !command.trim().is_empty()),
+        }
+    }
+
+    /// Program and arguments that open `url`, or None for the system browser. The URL is
+    /// appended as the last argument when the command has no `{url}` placeholder.
+    pub fn command_line(&self, url: &str) -> io::Result<Option<Vec<String>>> {
+        let Some(command) = &self.command else {
+            return Ok(None);
+        };
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Invalid browser_command: '{command}'"),
+            )
+        };
+        let mut args = shlex::split(command)
+            .filter(|args| !args.is_empty())
+            .ok_or_else(invalid)?;
+        if args.iter().any(|arg| arg.contains(URL_PLACEHOLDER)) {
+            for arg in &mut args {
+                *arg = arg.replace(URL_PLACEHOLDER, url);
+            }
+        } else {
+            args.push(url.to_string());
+        }
+        Ok(Some(args))
+    }
+
+    /// Open `url` without waiting for the browser to exit
+    pub fn open(&self, url: &str) -> io::Result<()> {
+        match self.command_line(url)? {
+            Some(args) => {
+                Command::new(&args[0])
+                    .args(&args[1..])
+                    .stdin(Stdio::null())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .spawn()?;
+                Ok(())
+            }
+            None => webbrowser::open(url),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_line() {
+        let url = "https://gerrit.example.com/c/project/+/12345";
+        assert!(UrlOpener::new(None).command_line(url).unwrap().is_none());
+        assert!(
+            UrlOpener::new(Some("  ".to_string()))
+                .command_line(url)
+                .unwrap()
+                .is_none()
+        );
+
+        let opener = UrlOpener::new(Some(
+            r#"google-chrome --profile-directory="Profile 2" --new-tab={url}"#.to_string(),
+        ));
+        assert_eq!(
+            opener.command_line(url).unwrap().unwrap(),
+            vec![
+                "google-chrome".to_string(),
+                "--profile-directory=Profile 2".to_string(),
+                format!("--new-tab={url}"),
+            ]
+        );
+
+        // Without a placeholder the URL comes last
+        let opener = UrlOpener::new(Some("firefox -P work".to_string()));
+        assert_eq!(
+            opener.command_line(url).unwrap().unwrap().last().unwrap(),
+            url
+        );
+
+        let unbalanced = UrlOpener::new(Some("chrome \"--profile".to_string()));
+        assert!(unbalanced.command_line(url).is_err());
+    }
+}
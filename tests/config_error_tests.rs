@@ -286,7 +286,7 @@ fn test_concurrent_config_access() {
     // At least some operations should succeed
     let success_count = results
         .iter()
-        .filter(|r| r.as_ref().map_or(false, |output| output.status.success()))
+        .filter(|r| r.as_ref().is_ok_and(|output| output.status.success()))
         .count();
     assert!(
         success_count > 0,
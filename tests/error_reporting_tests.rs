@@ -1,4 +1,5 @@
 use assert_cmd::Command;
+use predicates::prelude::*;
 use predicates::str;
 use std::fs;
 use tempfile::TempDir;
@@ -71,6 +72,7 @@ fn test_error_help() {
     cmd.assert()
         .success()
         .stdout(str::contains("Available error commands:"))
+        .stdout(str::contains("browse"))
         .stdout(str::contains("list"))
         .stdout(str::contains("stats"))
         .stdout(str::contains("export"))
@@ -156,3 +158,76 @@ fn test_error_list_with_limit() {
         .success()
         .stdout(str::contains("No errors found."));
 }
+
+#[test]
+fn test_error_list_severity_filter() {
+    let home = TempDir::new().expect("Failed to create temp dir");
+    fs::create_dir_all(home.path().join(".reviewr")).unwrap();
+    let entry = |severity: &str, operation: &str| {
+        format!(
+            r#"{{"severity":"{severity}","platform_id":"gerrit","operation":"{operation}","user":null,"timestamp":"2024-01-15T10:30:00Z","error_type":"network_error","error_message":"operation timed out","request_url":null,"status_code":null,"response_body":null,"metadata":{{}}}}"#
+        )
+    };
+    fs::write(
+        home.path().join(".reviewr/error.log"),
+        format!(
+            "{}\n{}\n",
+            entry("warning", "test_connection"),
+            entry("error", "query_changes")
+        ),
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.env("HOME", home.path())
+        .args(["errors", "list", "--severity", "warning"]);
+    cmd.assert()
+        .success()
+        .stdout(str::contains(
+            "🟡 2024-01-15T10:30:00Z | gerrit | test_connection",
+        ))
+        .stdout(str::contains("query_changes").not());
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.env("HOME", home.path()).args(["errors", "stats"]);
+    cmd.assert()
+        .success()
+        .stdout(str::contains("Total errors: 1"))
+        .stdout(str::contains("Warnings: 1"));
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.env("HOME", home.path())
+        .args(["errors", "list", "--severity", "info"]);
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_error_log_follows_data_path() {
+    let home = TempDir::new().expect("Failed to create temp dir");
+    let data_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        data_dir.path().join("error.log"),
+        r#"{"severity":"error","platform_id":"jira","operation":"search_issues","user":null,"timestamp":"2024-01-15T10:30:00Z","error_type":"api_error","error_message":"HTTP 401","request_url":null,"status_code":401,"response_body":null,"metadata":{}}"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.env("HOME", home.path())
+        .arg("--data-path")
+        .arg(data_dir.path())
+        .args(["errors", "list"]);
+    cmd.assert()
+        .success()
+        .stdout(str::contains("jira | search_issues"));
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.env("HOME", home.path())
+        .arg("--data-path")
+        .arg(data_dir.path())
+        .args(["errors", "clear"]);
+    cmd.assert()
+        .success()
+        .stdout(str::contains("Error log cleared."));
+    assert!(!data_dir.path().join("error.log").exists());
+    assert!(!home.path().join(".reviewr/error.log").exists());
+}
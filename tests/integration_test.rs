@@ -43,6 +43,73 @@ fn test_config_set_get() {
         .stdout(predicate::str::contains("github.com"));
 }
 
+#[test]
+fn test_config_export_import() {
+    let source = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(source.path())
+        .arg("config")
+        .arg("set")
+        .arg("allowed_domains")
+        .arg("github.com");
+    cmd.assert().success();
+    fs::create_dir_all(source.path().join("templates")).unwrap();
+    fs::write(
+        source.path().join("templates/retro.md"),
+        "## {{date}} Retro\n",
+    )
+    .unwrap();
+
+    let bundle = source.path().join("bundle.tar");
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(source.path())
+        .arg("config")
+        .arg("export")
+        .arg("--output")
+        .arg(&bundle);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("templates/retro.md"));
+
+    let target = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(target.path())
+        .arg("config")
+        .arg("import")
+        .arg(&bundle);
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(target.path())
+        .arg("config")
+        .arg("get")
+        .arg("allowed_domains");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("github.com"));
+    assert!(target.path().join("templates/retro.md").exists());
+
+    // Importing again would overwrite the files
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(target.path())
+        .arg("config")
+        .arg("import")
+        .arg(&bundle);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--force"));
+}
+
 #[test]
 fn test_notes_evidence() {
     let dir = tempdir().unwrap();
@@ -118,6 +185,28 @@ fn test_list_employees() {
         .stdout(predicate::str::contains("Bob Johnson - Developer"));
 }
 
+#[test]
+fn test_list_verbose_shows_profile() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    fs::write(
+        dir.path().join("employees/Alice Smith.toml"),
+        "name = \"Alice Smith\"\ntitle = \"Manager\"\nteam = \"Platform\"\ntags = [\"oncall\"]\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("list")
+        .arg("--verbose");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Alice Smith - Manager"))
+        .stdout(predicate::str::contains("  Platform · #oncall"));
+}
+
 #[test]
 fn test_list_empty() {
     let dir = tempdir().unwrap();
@@ -184,3 +273,1674 @@ fn test_edit_nonexistent_employee() {
         "Employee 'Nonexistent User' not found.",
     ));
 }
+
+#[test]
+fn test_unknown_employee_suggestions_and_completions() {
+    let dir = tempdir().unwrap();
+    for name in ["Jane Smith", "John Doe"] {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.arg("--data-path").arg(dir.path()).arg("add").arg(name);
+        cmd.write_stdin("Engineer\ntest.user@example.com\n");
+        cmd.assert().success();
+    }
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("edit")
+        .arg("jane smth");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Employee 'jane smth' not found."))
+        .stdout(predicate::str::contains("Did you mean 'Jane Smith'?"));
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path").arg(dir.path()).arg("completions");
+    cmd.assert()
+        .success()
+        .stdout(predicate::eq("Jane Smith\nJohn Doe\n"));
+}
+
+#[test]
+fn test_archive_and_remove_employee() {
+    let dir = tempdir().unwrap();
+    for name in ["Jane Smith", "John Doe"] {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.arg("--data-path").arg(dir.path()).arg("add").arg(name);
+        cmd.write_stdin("Engineer\ntest.user@example.com\n");
+        cmd.assert().success();
+    }
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("archive")
+        .arg("John Doe");
+    cmd.assert().success();
+    assert!(dir.path().join("archive/employees/John Doe.toml").exists());
+
+    // Declining the confirmation keeps the employee
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("remove")
+        .arg("Jane Smith");
+    cmd.write_stdin("n\n");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Removal cancelled."));
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("list")
+        .arg("--all");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Jane Smith - Engineer"))
+        .stdout(predicate::str::contains("Archived (1):"))
+        .stdout(predicate::str::contains("• John Doe"));
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("remove")
+        .arg("Jane Smith")
+        .arg("--yes");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path").arg(dir.path()).arg("list");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No employees found."));
+}
+
+#[test]
+fn test_rename_employee_takes_notes_and_reports() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("add")
+        .arg("Jane Doe");
+    cmd.write_stdin("Engineer\n\n");
+    cmd.assert().success();
+    fs::create_dir_all(dir.path().join("notes")).unwrap();
+    fs::write(dir.path().join("notes/Jane Doe.md"), "## 2024-01-10\nNew\n").unwrap();
+    fs::write(
+        dir.path().join("notes/Jane Smith.md"),
+        "## 2023-05-01\nOld\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.path().join("reports")).unwrap();
+    fs::write(dir.path().join("reports/Jane Doe-2024-01-31.md"), "").unwrap();
+
+    // Without a terminal to ask, existing notes under the new name stop the rename
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .args(["rename", "Jane Doe", "Jane Smith"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("already exist"));
+    assert!(dir.path().join("employees/Jane Doe.toml").exists());
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .args(["rename", "Jane Doe", "Jane Smith", "--merge"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Renamed 'Jane Doe' to 'Jane Smith'",
+        ))
+        .stdout(predicate::str::contains("Jane Smith-2024-01-31.md"));
+    assert!(!dir.path().join("employees/Jane Doe.toml").exists());
+    assert!(dir.path().join("reports/Jane Smith-2024-01-31.md").exists());
+    let notes = fs::read_to_string(dir.path().join("notes/Jane Smith.md")).unwrap();
+    assert_eq!(notes, "## 2023-05-01\nOld\n\n## 2024-01-10\nNew\n");
+}
+
+#[test]
+fn test_edit_raw_unchanged() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("add")
+        .arg("John Doe");
+    cmd.write_stdin("Engineer\ntest.user@example.com\n");
+    cmd.assert().success();
+
+    // `true` exits without touching the file
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.env("EDITOR", "true")
+        .arg("--data-path")
+        .arg(dir.path())
+        .arg("edit")
+        .arg("John Doe")
+        .arg("--raw");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No changes to 'John Doe'."));
+}
+
+#[test]
+fn test_config_clipboard_evidence() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("config")
+        .arg("set")
+        .arg("clipboard_evidence")
+        .arg("false");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("config")
+        .arg("get")
+        .arg("clipboard_evidence");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("clipboard_evidence: false"));
+}
+
+#[test]
+fn test_config_performance() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("config")
+        .arg("set")
+        .arg("page_size")
+        .arg("0");
+    cmd.assert().failure();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("config")
+        .arg("set")
+        .arg("page_size")
+        .arg("25");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path").arg(dir.path()).arg("config");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("page_size: 25"))
+        .stdout(predicate::str::contains("max_concurrent_platforms: 4"));
+}
+
+#[test]
+fn test_config_browser_command() {
+    let dir = tempdir().unwrap();
+    let config = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.arg("--data-path")
+            .arg(dir.path())
+            .arg("config")
+            .args(args);
+        cmd.assert()
+    };
+
+    // Unbalanced quotes are rejected before anything is saved
+    config(&["set", "browser_command", "chrome \"--profile"]).failure();
+    config(&["set", "browser_command", "firefox -P work {url}"]).success();
+    config(&["get", "browser_command"])
+        .success()
+        .stdout(predicate::str::contains(
+            "browser_command: firefox -P work {url}",
+        ));
+    config(&["set", "browser_command", ""]).success();
+    config(&["get", "browser_command"])
+        .success()
+        .stdout(predicate::str::contains("browser_command: (not set)"));
+}
+
+#[test]
+fn test_config_env_override() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("config")
+        .arg("set")
+        .arg("page_size")
+        .arg("25");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.env("REVIEWR_PAGE_SIZE", "50")
+        .arg("--data-path")
+        .arg(dir.path())
+        .arg("config")
+        .arg("get")
+        .arg("page_size");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "page_size: 50 (from REVIEWR_PAGE_SIZE)",
+    ));
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.env("REVIEWR_PAGE_SIZE", "many")
+        .arg("--data-path")
+        .arg(dir.path())
+        .arg("config")
+        .arg("get")
+        .arg("page_size");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("REVIEWR_PAGE_SIZE=many"));
+}
+
+#[test]
+fn test_config_set_secret_requires_configured_platform() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("config")
+        .arg("set-secret")
+        .arg("jira");
+    cmd.write_stdin("token\n");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("'jira' is not configured"));
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path").arg(dir.path()).arg("config");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("secret_backend: config"));
+}
+
+#[test]
+fn test_notes_without_clipboard() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("add")
+        .arg("Jane Doe");
+    cmd.write_stdin("Engineer\ntest.user@example.com\n");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.env("EDITOR", "true");
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("notes")
+        .arg("Jane Doe")
+        .arg("--no-clipboard");
+    cmd.assert().success();
+
+    let notes_path = dir.path().join("notes/Jane Doe.md");
+    let notes = fs::read_to_string(notes_path).unwrap();
+    assert!(notes.contains("# Notes for Jane Doe"));
+    assert!(!notes.contains("- Evidence:"));
+}
+
+#[test]
+fn test_journal_weekly_pass() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("notes")).unwrap();
+    for name in ["Alice", "Bob"] {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.arg("--data-path").arg(dir.path()).arg("add").arg(name);
+        cmd.write_stdin("Engineer\n\n");
+        cmd.assert().success();
+    }
+    fs::write(
+        dir.path().join("notes/Alice.md"),
+        "# Notes for Alice\n\n## 2024-01-15\nShipped search\n",
+    )
+    .unwrap();
+
+    let journal = |stdin: &str| {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.env("EDITOR", "true");
+        cmd.arg("--data-path").arg(dir.path()).arg("journal");
+        cmd.write_stdin(stdin.to_string());
+        cmd.assert().success()
+    };
+
+    // Write Alice's notes, skip Bob
+    journal("\ns\n")
+        .stdout(predicate::str::contains("2 of 2 employees to go"))
+        .stdout(predicate::str::contains("[1/2] Alice"))
+        .stdout(predicate::str::contains(
+            "No cached activity in the last 7 days.",
+        ))
+        .stdout(predicate::str::contains("1 left for"));
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let notes = fs::read_to_string(dir.path().join("notes/Alice.md")).unwrap();
+    assert!(notes.ends_with(&format!("Shipped search\n\n## {today}\n\n")));
+    assert!(!dir.path().join("notes/Bob.md").exists());
+
+    // Only Bob is left this week
+    journal("\n")
+        .stdout(predicate::str::contains("1 of 2 employees to go"))
+        .stdout(predicate::str::contains("[1/1] Bob"));
+    assert!(dir.path().join("notes/Bob.md").exists());
+
+    journal("").stdout(predicate::str::contains("All 2 employees are journaled"));
+}
+
+#[test]
+fn test_notes_append_and_list() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("add")
+        .arg("Jane");
+    cmd.write_stdin("Engineer\n\n");
+    cmd.assert().success();
+
+    let notes = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.arg("--data-path")
+            .arg(dir.path())
+            .args(["notes", "Jane"])
+            .args(args);
+        cmd.assert()
+    };
+    notes(&["--list"])
+        .success()
+        .stdout(predicate::str::contains("No dated entries"));
+    notes(&["--append", "Unblocked the release"])
+        .success()
+        .stdout(predicate::str::contains("Added entry"));
+    notes(&["--append", "Paired on the parser fix"]).success();
+    notes(&["--append", "x", "--template", "one-on-one"]).failure();
+
+    let content = fs::read_to_string(dir.path().join("notes/Jane.md")).unwrap();
+    assert!(content.starts_with("# Notes for Jane\n"));
+    assert!(content.contains("Unblocked the release\n"));
+    notes(&["--list"])
+        .success()
+        .stdout(predicate::str::contains("Notes entries for Jane (2):"))
+        .stdout(predicate::str::contains("- 5 words"));
+}
+
+#[test]
+fn test_notes_of_employee_named_like_a_subcommand() {
+    let dir = tempdir().unwrap();
+    let notes = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.arg("--data-path")
+            .arg(dir.path())
+            .arg("notes")
+            .args(args);
+        cmd.assert()
+    };
+    // Without such an employee the subcommand still wants its argument
+    notes(&["stats", "--list"]).failure();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .args(["add", "stats"]);
+    cmd.write_stdin("Engineer\n\n");
+    cmd.assert().success();
+
+    notes(&["stats", "--append", "Ran the retro"])
+        .success()
+        .stdout(predicate::str::contains("to stats's notes"));
+    notes(&["stats", "--list"])
+        .success()
+        .stdout(predicate::str::contains("Notes entries for stats (1):"));
+    // A complete subcommand still wins
+    notes(&["stats", "stats"])
+        .success()
+        .stdout(predicate::str::contains("Notes statistics for stats"));
+
+    // A data directory called "notes" is not mistaken for the subcommand
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.current_dir(dir.path())
+        .args(["--data-path", "notes", "add", "stats"]);
+    cmd.write_stdin("Engineer\n\n");
+    cmd.assert().success();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.current_dir(dir.path())
+        .args(["--data-path", "notes", "notes", "stats", "--list"]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "No dated entries in stats's notes.",
+    ));
+}
+
+#[test]
+fn test_notes_encrypt_sets_recipient() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("add")
+        .arg("Jane");
+    cmd.write_stdin("Engineer\n\n");
+    cmd.assert().success();
+
+    let encrypt = |recipient: &str| {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.arg("--data-path")
+            .arg(dir.path())
+            .args(["notes", "encrypt", "Jane", recipient]);
+        cmd.assert()
+    };
+    encrypt("hunter2")
+        .failure()
+        .stderr(predicate::str::contains("Invalid age recipient"));
+
+    // Without notes yet there is nothing to encrypt, only the recipient is recorded
+    encrypt("age1qyqszqgpqyqszqgpqyqszqgp")
+        .success()
+        .stdout(predicate::str::contains("Jane.md.age"));
+    let employee = fs::read_to_string(dir.path().join("employees/Jane.toml")).unwrap();
+    assert!(employee.contains("notes_recipient = \"age1qyqszqgpqyqszqgpqyqszqgp\""));
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.arg("--data-path").arg(dir.path()).args([
+        "config",
+        "set",
+        "age_identity",
+        "~/.config/age/keys.txt",
+    ]);
+    cmd.assert().success();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .args(["config", "get", "age_identity"]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "age_identity: ~/.config/age/keys.txt",
+    ));
+}
+
+#[test]
+fn test_notes_template() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("add")
+        .arg("Jane Doe");
+    cmd.write_stdin("Engineer\ntest.user@example.com\n");
+    cmd.assert().success();
+
+    for _ in 0..2 {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.env("EDITOR", "true");
+        cmd.arg("--data-path")
+            .arg(dir.path())
+            .arg("notes")
+            .arg("Jane Doe")
+            .arg("--no-clipboard")
+            .arg("--template")
+            .arg("one-on-one");
+        cmd.assert().success();
+    }
+
+    let notes = fs::read_to_string(dir.path().join("notes/Jane Doe.md")).unwrap();
+    assert!(notes.starts_with("# Notes for Jane Doe"));
+    assert_eq!(notes.matches(" 1:1").count(), 2);
+    assert!(dir.path().join("templates/one-on-one.md").exists());
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.env("EDITOR", "true");
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("notes")
+        .arg("Jane Doe")
+        .arg("--no-clipboard")
+        .arg("--template")
+        .arg("retro");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown template 'retro'"));
+}
+
+#[test]
+fn test_notes_stats() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("add")
+        .arg("Jane Doe");
+    cmd.write_stdin("Engineer\ntest.user@example.com\n");
+    cmd.assert().success();
+
+    fs::write(
+        dir.path().join("notes/Jane Doe.md"),
+        "# Notes for Jane Doe\n\n## 2024-01-15\nGreat design review #design\n- Evidence: https://example.com/1\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("notes")
+        .arg("stats")
+        .arg("Jane Doe");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Notes statistics for Jane Doe (1 sections)",
+        ))
+        .stdout(predicate::str::contains(
+            "2024-01-15 - 4 words, 1 evidence links",
+        ))
+        .stdout(predicate::str::contains("#design (1)"));
+}
+
+#[test]
+fn test_notes_show_piped() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("add")
+        .arg("Jane Doe");
+    cmd.write_stdin("Engineer\ntest.user@example.com\n");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .args(["notes", "show", "Jane Doe"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No notes found for 'Jane Doe'"));
+
+    let notes = "# Notes for Jane Doe\n\n## 2024-01-15\n- Led the **incident** review\n";
+    fs::write(dir.path().join("notes/Jane Doe.md"), notes).unwrap();
+
+    // Without a terminal the pager steps aside and the Markdown is printed unchanged
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .args(["notes", "show", "Jane Doe"]);
+    cmd.assert().success().stdout(notes);
+
+    // `notes <employee> --view` opens the same pager
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .args(["notes", "Jane Doe", "--view"]);
+    cmd.assert().success().stdout(notes);
+}
+
+#[test]
+fn test_report_export() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("add")
+        .arg("Jane Doe");
+    cmd.write_stdin("Engineer\ntest.user@example.com\n");
+    cmd.assert().success();
+
+    fs::write(
+        dir.path().join("notes/Jane Doe.md"),
+        "# Notes for Jane Doe\n\n## 2024-01-15\nLed the incident review\n",
+    )
+    .unwrap();
+
+    let output = dir.path().join("packet.md");
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("report")
+        .arg("Jane Doe")
+        .arg("--output")
+        .arg(&output);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Review packet written to"));
+
+    let packet = fs::read_to_string(output).unwrap();
+    assert!(packet.contains("# Review Packet: Jane Doe"));
+    assert!(packet.contains("## Summary"));
+    assert!(packet.contains("### 2024-01-15\nLed the incident review"));
+}
+
+#[test]
+fn test_review_cycle() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    fs::write(
+        dir.path().join("employees/Jane.toml"),
+        "name = \"Jane\"\ntitle = \"Engineer\"\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.path().join("notes")).unwrap();
+    fs::write(
+        dir.path().join("notes/Jane.md"),
+        "# Notes for Jane\n\n## 2020-01-15\nFrom an earlier cycle\n",
+    )
+    .unwrap();
+    let reviewr = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.arg("--data-path").arg(dir.path()).args(args);
+        cmd.assert()
+    };
+
+    reviewr(&["cycle", "status"])
+        .success()
+        .stdout(predicate::str::contains("No review cycle is active"));
+    reviewr(&["cycle", "close"]).failure();
+    reviewr(&["cycle", "start", "2025-H1"])
+        .success()
+        .stdout(predicate::str::contains("Started review cycle 2025-H1"));
+    reviewr(&["cycle", "start", "2025-H2"]).failure();
+    reviewr(&["notes", "Jane", "--append", "Shipped the importer"]).success();
+    let notes = fs::read_to_string(dir.path().join("notes/Jane.md")).unwrap();
+    assert!(notes.contains(" [2025-H1]\n\nShipped the importer"));
+    reviewr(&["cycle", "status"])
+        .success()
+        .stdout(predicate::str::contains("Active cycle: 2025-H1"))
+        .stdout(predicate::str::contains("Jane: 1 notes entries"));
+
+    // The active cycle scopes the packet without --cycle
+    let output = dir.path().join("packet.md");
+    let output_arg = output.to_str().unwrap();
+    reviewr(&["report", "Jane", "--output", output_arg]).success();
+    let packet = fs::read_to_string(&output).unwrap();
+    assert!(packet.contains("· cycle 2025-H1 ("));
+    assert!(packet.contains("_1 of 2 dated entries belong to cycle 2025-H1._"));
+    assert!(packet.contains("Shipped the importer"));
+    assert!(!packet.contains("From an earlier cycle"));
+
+    reviewr(&["cycle", "close"])
+        .success()
+        .stdout(predicate::str::contains("Closed review cycle 2025-H1"));
+    reviewr(&["report", "Jane", "--output", output_arg]).success();
+    assert!(
+        fs::read_to_string(&output)
+            .unwrap()
+            .contains("last 30 days")
+    );
+    reviewr(&[
+        "report", "Jane", "--output", output_arg, "--cycle", "2025-H1",
+    ])
+    .success();
+    assert!(
+        fs::read_to_string(&output)
+            .unwrap()
+            .contains("Shipped the importer")
+    );
+    reviewr(&["report", "Jane", "--cycle", "2024-H2"])
+        .failure()
+        .stderr(predicate::str::contains("No review cycle named '2024-H2'"));
+}
+
+#[test]
+fn test_summary_email() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    fs::write(
+        dir.path().join("employees/Jane Doe.toml"),
+        "name = \"Jane Doe\"\ntitle = \"Engineer\"\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.path().join("notes")).unwrap();
+    fs::write(
+        dir.path().join("notes/Jane Doe.md"),
+        "# Notes for Jane Doe\n\n## 2024-01-15\nPrivate remark\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path").arg(dir.path()).args([
+        "summary",
+        "Jane Doe",
+        "--days",
+        "14",
+        "--compare",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "Activity summary for Jane Doe (Engineer), last 14 days up to ",
+        ))
+        .stdout(predicate::str::contains(
+            "No review platforms were queried.",
+        ))
+        .stdout(predicate::str::contains("Private remark").not())
+        // Nothing was fetched, so there is nothing to compare
+        .stdout(predicate::str::contains("Compared to").not());
+
+    let output = dir.path().join("summary.html");
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .args(["summary", "Jane Doe", "--format", "email-html", "--output"])
+        .arg(&output);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Summary written to"));
+    assert!(
+        fs::read_to_string(output)
+            .unwrap()
+            .starts_with("<p>Activity summary for Jane Doe (Engineer)")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_custom_command_platform() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    fs::write(
+        dir.path().join("employees/Jane Doe.toml"),
+        "name = \"Jane Doe\"\ntitle = \"Engineer\"\ncommitter_email = \"jane@example.com\"\n\
+         [usernames]\n\"custom:codeflow\" = \"jdoe\"\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("config.toml"),
+        r#"[ui_preferences]
+
+[platforms.custom.codeflow]
+command = "sh"
+args = ["-c", "echo '[{\"id\": \"7\", \"title\": \"Review by '$REVIEWR_USER'\", \"category\": \"ReviewsGiven\", \"project\": \"core\", \"status\": \"done\"}]'"]
+display_name = "Codeflow"
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(10));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .args(["summary", "Jane Doe"]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "Codeflow\n  Reviews Given: 1\n    - Review by jdoe (core, done)",
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_json_output_for_scripts() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    fs::write(
+        dir.path().join("employees/Jane Doe.toml"),
+        "name = \"Jane Doe\"\ntitle = \"Engineer 🚀\"\n[usernames]\n\"custom:codeflow\" = \"jdoe\"\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("config.toml"),
+        r#"[ui_preferences]
+
+[platforms.custom.codeflow]
+command = "sh"
+args = ["-c", "echo '[{\"id\": \"7\", \"title\": \"Review\", \"category\": \"ReviewsGiven\", \"project\": \"core\", \"status\": \"done\"}]'"]
+display_name = "Codeflow"
+"#,
+    )
+    .unwrap();
+    let json = |args: &[&str]| -> serde_json::Value {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(10));
+        cmd.arg("--data-path").arg(dir.path()).args(args);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        serde_json::from_slice(&output).unwrap()
+    };
+
+    let list = json(&["list", "--output", "json"]);
+    assert_eq!(list["employees"][0]["name"], "Jane Doe");
+    // Values are printed as they are, even with --no-color
+    let list = json(&["--no-color", "list", "--output", "json"]);
+    assert_eq!(list["employees"][0]["title"], "Engineer 🚀");
+
+    let setting = json(&[
+        "config",
+        "get",
+        "default_time_period_days",
+        "--output",
+        "json",
+    ]);
+    assert_eq!(setting["value"], 30);
+    assert_eq!(setting["source"], "default");
+
+    let review = json(&["review", "Jane Doe", "--days", "7", "--output", "json"]);
+    assert_eq!(review["days"], 7);
+    let platform = &review["platforms"][0];
+    assert_eq!(platform["id"], "custom:codeflow");
+    assert_eq!(platform["status"], "ok");
+    assert_eq!(platform["total_items"], 1);
+    assert_eq!(platform["items_by_category"]["Reviews Given"], 1);
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path").arg(dir.path()).args([
+        "config",
+        "get",
+        "no_such_key",
+        "--output",
+        "json",
+    ]);
+    cmd.assert().failure().stdout(predicate::str::is_empty());
+}
+
+#[test]
+fn test_handoff() {
+    let source = tempdir().unwrap();
+    fs::create_dir_all(source.path().join("employees")).unwrap();
+    fs::create_dir_all(source.path().join("notes")).unwrap();
+    fs::write(
+        source.path().join("employees/Jane Doe.toml"),
+        "name = \"Jane Doe\"\ntitle = \"Engineer\"\n",
+    )
+    .unwrap();
+    fs::write(
+        source.path().join("notes/Jane Doe.md"),
+        "Led the SSO rollout\n",
+    )
+    .unwrap();
+    let folder = source.path().join("handoff");
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(source.path())
+        .args(["handoff", "Jane Doe", "--output"])
+        .arg(&folder);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("notes/Jane Doe.md"));
+    assert!(folder.join("handoff.toml").exists());
+
+    let target = tempdir().unwrap();
+    let import = || {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.arg("--data-path")
+            .arg(target.path())
+            .args(["handoff", "--import"])
+            .arg(&folder);
+        cmd.assert()
+    };
+    import()
+        .success()
+        .stdout(predicate::str::contains("Added Jane Doe (2 files"));
+    assert_eq!(
+        fs::read_to_string(target.path().join("notes/Jane Doe.md")).unwrap(),
+        "Led the SSO rollout\n"
+    );
+    // The employee exists now
+    import().failure();
+
+    // Without --import an output folder is required
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(source.path())
+        .args(["handoff", "Jane Doe"]);
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_bulk_edit() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    for (name, team) in [("Alice", "Core"), ("Bob", "core"), ("Carol", "Web")] {
+        fs::write(
+            dir.path().join(format!("employees/{name}.toml")),
+            format!("name = \"{name}\"\ntitle = \"Engineer\"\nteam = \"{team}\"\n"),
+        )
+        .unwrap();
+    }
+    let bulk_edit = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.arg("--data-path")
+            .arg(dir.path())
+            .arg("bulk-edit")
+            .args(args);
+        cmd.assert()
+    };
+
+    bulk_edit(&[
+        "--team",
+        "core",
+        "--set",
+        "title=Senior Engineer",
+        "--dry-run",
+    ])
+    .success()
+    .stdout(predicate::str::contains(
+        "• Alice\n    title: 'Engineer' → 'Senior Engineer'",
+    ))
+    .stdout(predicate::str::contains(
+        "Dry run: 2 employee(s) would be updated.",
+    ));
+    let alice = || fs::read_to_string(dir.path().join("employees/Alice.toml")).unwrap();
+    assert!(alice().contains("title = \"Engineer\""));
+
+    bulk_edit(&["--team", "core", "--set", "title=Senior Engineer"])
+        .success()
+        .stdout(predicate::str::contains("Updated 2 employee(s)."));
+    assert!(alice().contains("title = \"Senior Engineer\""));
+    assert!(
+        fs::read_to_string(dir.path().join("employees/Carol.toml"))
+            .unwrap()
+            .contains("title = \"Engineer\"")
+    );
+
+    // A filter or --all is required, and unknown fields are rejected
+    bulk_edit(&["--set", "title=Staff Engineer"]).failure();
+    bulk_edit(&["--all", "--set", "name=Bob"]).failure();
+}
+
+#[test]
+fn test_import_csv() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    fs::write(
+        dir.path().join("employees/Alice.toml"),
+        "name = \"Alice\"\ntitle = \"Engineer\"\n",
+    )
+    .unwrap();
+    let csv = dir.path().join("people.csv");
+    fs::write(
+        &csv,
+        "Name,Title,Email,Team\nAlice,Staff Engineer,,\n\"Doe, Jane\",Designer,jane@example.com,Web\n",
+    )
+    .unwrap();
+    let import = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.arg("--data-path")
+            .arg(dir.path())
+            .args(["import", "csv"])
+            .arg(&csv)
+            .args(args);
+        cmd.assert()
+    };
+    let jane = dir.path().join("employees/Doe, Jane.toml");
+
+    import(&["--dry-run"])
+        .success()
+        .stdout(predicate::str::contains(
+            "• Doe, Jane (new): Designer, jane@example.com, team Web",
+        ))
+        .stdout(predicate::str::contains(
+            "Skipping line 2 (Alice): 'Alice' already exists",
+        ))
+        .stdout(predicate::str::contains(
+            "Dry run: 1 employee(s) would be created, 0 updated and 1 skipped.",
+        ));
+    assert!(!jane.exists());
+
+    // Abort refuses the whole file when anyone exists
+    import(&["--on-conflict", "abort"])
+        .failure()
+        .stderr(predicate::str::contains("already exist: Alice"));
+    assert!(!jane.exists());
+
+    import(&["--on-conflict", "update"])
+        .success()
+        .stdout(predicate::str::contains(
+            "• Alice (update)\n    title: 'Engineer' → 'Staff Engineer'",
+        ))
+        .stdout(predicate::str::contains(
+            "Created 1 employee(s), updated 1 and skipped 0.",
+        ));
+    let jane = fs::read_to_string(jane).unwrap();
+    assert!(jane.contains("committer_email = \"jane@example.com\""));
+    assert!(jane.contains("team = \"Web\""));
+    assert!(
+        fs::read_to_string(dir.path().join("employees/Alice.toml"))
+            .unwrap()
+            .contains("title = \"Staff Engineer\"")
+    );
+
+    import(&["--on-conflict", "replace"]).failure();
+}
+
+#[test]
+fn test_redacted_exports() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    fs::write(
+        dir.path().join("employees/Jane Doe.toml"),
+        "name = \"Jane Doe\"\ntitle = \"Engineer\"\ncommitter_email = \"jane@example.com\"\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.path().join("notes")).unwrap();
+    fs::write(
+        dir.path().join("notes/Jane Doe.md"),
+        "# Notes for Jane Doe\n\n## 2024-01-15\nJane Doe fixed http://wiki/incident-7\n",
+    )
+    .unwrap();
+
+    let output = dir.path().join("packet.md");
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .args(["report", "Jane Doe", "--redact", "--output"])
+        .arg(&output);
+    cmd.assert().success();
+    let packet = fs::read_to_string(output).unwrap();
+    assert!(packet.starts_with("# Review Packet: Person-"));
+    assert!(packet.contains("fixed [internal link]"));
+    assert!(!packet.contains("Jane"));
+    assert!(!packet.contains("jane@example.com"));
+    // Pseudonyms stay stable across runs of the same data directory
+    let pseudonym = packet
+        .lines()
+        .next()
+        .unwrap()
+        .trim_start_matches("# Review Packet: ");
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .args(["team-review", "--export", "csv"])
+        .env("REVIEWR_REDACT_EXPORTS", "true");
+    cmd.assert()
+        .success()
+        .stdout(predicate::eq(format!("Employee,Total\n{pseudonym},0\n")));
+}
+
+#[test]
+fn test_org_stats_without_cache() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("org-stats")
+        .arg("--days")
+        .arg("90");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "No cached activity for the last 90 days.",
+        ))
+        .stdout(predicate::str::contains(
+            "reviewr review <employee> --days 90",
+        ));
+}
+
+#[test]
+fn test_org_stats_applies_data_policy() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    fs::write(
+        dir.path().join("employees/Jane Doe.toml"),
+        "name = \"Jane Doe\"\ntitle = \"Engineer\"\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("employees/Sam.toml"),
+        "name = \"Sam\"\ntitle = \"Engineer\"\nnotes_only = true\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.path().join("cache/activity")).unwrap();
+    for (file, employee) in [("jane", "Jane Doe"), ("sam", "Sam")] {
+        fs::write(
+            dir.path().join(format!("cache/activity/{file}.json")),
+            format!(
+                r#"{{"employee":"{employee}","platform_id":"gerrit","days":30,"fetched_at":0,
+                "items_by_category":[["ChangesMerged",[{{"id":"42","title":"Fix login bug",
+                "status":"MERGED","created":"2024-01-10T10:00:00Z",
+                "updated":"2024-01-10T10:00:00Z","url":"https://gerrit.example.com/42",
+                "platform":"gerrit","category":"ChangesMerged","project":"auth",
+                "metadata":{{}}}}]]]}}"#
+            ),
+        )
+        .unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .args(["org-stats", "--days", "30"]);
+    // The notes_only employee's cached fetch is not counted, nor is Sam listed as missing
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Org statistics for the last 30 days (1 employees with cached activity):",
+        ))
+        .stdout(predicate::str::contains("Sam").not());
+}
+
+#[test]
+fn test_feedback_requests_from_cached_activity() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    fs::write(
+        dir.path().join("employees/Jane Doe.toml"),
+        "name = \"Jane Doe\"\ntitle = \"Engineer\"\n",
+    )
+    .unwrap();
+
+    let feedback = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.arg("--data-path")
+            .arg(dir.path())
+            .args(["feedback", "Jane Doe", "--days", "30"])
+            .args(args);
+        cmd.assert()
+    };
+    feedback(&[])
+        .success()
+        .stdout(predicate::str::contains("No cached activity for Jane Doe"))
+        .stdout(predicate::str::contains("Hi there,"));
+
+    fs::create_dir_all(dir.path().join("cache/activity")).unwrap();
+    fs::write(
+        dir.path().join("cache/activity/jane.json"),
+        r#"{"employee":"Jane Doe","platform_id":"gerrit","days":30,"fetched_at":0,
+            "items_by_category":[["ChangesMerged",[{"id":"42","title":"Fix login bug",
+            "status":"MERGED","created":"2024-01-10T10:00:00Z","updated":"2024-01-10T10:00:00Z",
+            "url":"https://gerrit.example.com/42","platform":"gerrit","category":"ChangesMerged",
+            "project":"auth","metadata":{"reviewers":"Ann Lee, Jane Doe"}}]]]}"#,
+    )
+    .unwrap();
+    let output = dir.path().join("feedback.md");
+    feedback(&["--output", output.to_str().unwrap()])
+        .success()
+        .stdout(predicate::str::contains("Ann Lee - 1 shared item(s)"));
+    let text = fs::read_to_string(output).unwrap();
+    assert!(text.contains("Hi Ann Lee,"));
+    assert!(
+        text.contains("- [Fix login bug](https://gerrit.example.com/42) (Changes Merged, gerrit)")
+    );
+}
+
+#[test]
+fn test_search_cached_items_and_notes() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    fs::write(
+        dir.path().join("employees/Jane Doe.toml"),
+        "name = \"Jane Doe\"\ntitle = \"Engineer\"\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.path().join("cache/activity")).unwrap();
+    fs::write(
+        dir.path().join("cache/activity/jane.json"),
+        r#"{"employee":"Jane Doe","platform_id":"gerrit","days":30,"fetched_at":0,
+            "items_by_category":[["ChangesMerged",[{"id":"42","title":"Fix login bug",
+            "status":"MERGED","created":"2024-01-10T10:00:00Z","updated":"2024-01-10T10:00:00Z",
+            "url":"https://gerrit.example.com/42","platform":"gerrit","category":"ChangesMerged",
+            "project":"auth","metadata":{}}]]]}"#,
+    )
+    .unwrap();
+    fs::create_dir_all(dir.path().join("notes")).unwrap();
+    fs::write(
+        dir.path().join("notes/Sam Lee.md"),
+        "## 2024-01-12 1:1\n\nHelped Jane debug the login flow.\n",
+    )
+    .unwrap();
+
+    let search = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("reviewr").unwrap();
+        cmd.timeout(Duration::from_secs(5));
+        cmd.arg("--data-path")
+            .arg(dir.path())
+            .arg("search")
+            .args(args);
+        cmd.assert()
+    };
+    search(&["login"])
+        .success()
+        .stdout(predicate::str::contains("Results for 'login' (2):"))
+        .stdout(predicate::str::contains(
+            "• [gerrit] Jane Doe: 42 Fix login bug",
+        ))
+        .stdout(predicate::str::contains(
+            "2024-01-10 · Changes Merged · auth · MERGED",
+        ))
+        .stdout(predicate::str::contains(
+            "• [notes] Sam Lee: 2024-01-12 1:1",
+        ))
+        .stdout(predicate::str::contains(
+            "Helped Jane debug the login flow.",
+        ));
+    assert!(dir.path().join("cache/search_index.json").exists());
+
+    search(&["login", "--employee", "jane doe", "--output", "json"])
+        .success()
+        .stdout(predicate::str::contains(
+            r#""url": "https://gerrit.example.com/42""#,
+        ))
+        .stdout(predicate::str::contains("Sam Lee").not());
+    search(&["login", "deploy"])
+        .success()
+        .stdout(predicate::str::contains(
+            "No items or notes match 'login deploy'.",
+        ));
+    // Without a query and a terminal there is nothing to show
+    search(&[])
+        .failure()
+        .stderr(predicate::str::contains("Give a query to search for"));
+}
+
+#[test]
+fn test_dashboard_without_employees_or_platforms() {
+    let dir = tempdir().unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("dashboard")
+        .arg("--team")
+        .arg("Platform");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "No employees found in team 'Platform'.",
+    ));
+
+    fs::write(
+        dir.path().join("employees/Alice.toml"),
+        "name = \"Alice\"\ntitle = \"Engineer\"\nteam = \"Platform\"\n",
+    )
+    .unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("dashboard")
+        .arg("--team")
+        .arg("Platform");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "No review platforms are configured",
+    ));
+}
+
+#[test]
+fn test_team_review_csv_export() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    for (name, team) in [
+        ("Alice", "Platform"),
+        ("Bob", "platform"),
+        ("Carol", "Mobile"),
+    ] {
+        fs::write(
+            dir.path().join(format!("employees/{name}.toml")),
+            format!("name = \"{name}\"\ntitle = \"Engineer\"\nteam = \"{team}\"\n"),
+        )
+        .unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("team-review")
+        .arg("--team")
+        .arg("Platform")
+        .arg("--export")
+        .arg("csv");
+    cmd.assert()
+        .success()
+        .stdout(predicate::eq("Employee,Total\nAlice,0\nBob,0\n"))
+        .stderr(predicate::str::contains(
+            "No review platforms are configured",
+        ));
+
+    let output = dir.path().join("team.csv");
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("team-review")
+        .arg("--export")
+        .arg("csv")
+        .arg("-o")
+        .arg(&output);
+    cmd.assert().success();
+    assert_eq!(
+        fs::read_to_string(output).unwrap(),
+        "Employee,Total\nAlice,0\nBob,0\nCarol,0\n"
+    );
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("team-review")
+        .arg("--team")
+        .arg("Infra");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "No employees found in team 'Infra'.",
+    ));
+}
+
+#[test]
+fn test_config_validate() {
+    let dir = tempdir().unwrap();
+    let config = dir.path().join("config.toml");
+    fs::write(
+        &config,
+        "[platforms.gitlab.work]\nname = \"work\"\nurl = \"gitlab.example.com\"\ntokn = \"x\"\n\n[ui_preferences]\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("config")
+        .arg("validate");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "config.toml:1:1: missing required key 'token' in [platforms.gitlab.work]",
+        ))
+        .stdout(predicate::str::contains(
+            "config.toml:3:7: url in [platforms.gitlab.work]: Invalid URL",
+        ))
+        .stdout(predicate::str::contains(
+            "config.toml:4:1: unknown key 'tokn' in [platforms.gitlab.work]",
+        ))
+        .stderr(predicate::str::contains("3 problem(s)"));
+
+    fs::write(
+        &config,
+        "[platforms.gitlab.work]\nname = \"work\"\nurl = \"https://gitlab.example.com\"\ntoken = \"x\"\n\n[ui_preferences]\n",
+    )
+    .unwrap();
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("config")
+        .arg("validate");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("is valid"));
+}
+
+#[test]
+fn test_review_explain_prints_queries_without_secrets() {
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join("config.toml"),
+        "[platforms.gitlab.work]\nname = \"work\"\nurl = \"https://gitlab.example.com\"\ntoken = \"s3cret\"\n\n[ui_preferences]\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    fs::write(
+        dir.path().join("employees/Alice.toml"),
+        "name = \"Alice\"\ntitle = \"Engineer\"\ncommitter_email = \"alice@example.com\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("review")
+        .arg("Alice")
+        .arg("--explain")
+        .arg("--days")
+        .arg("14");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Queries for Alice over the last 14 days",
+        ))
+        .stdout(predicate::str::contains("Auth:   Bearer token [REDACTED]"))
+        .stdout(predicate::str::contains(
+            "GET https://gitlab.example.com/api/v4/merge_requests?author_username=alice&created_after=",
+        ))
+        .stdout(predicate::str::contains("s3cret").not());
+}
+
+#[test]
+fn test_enforced_allowed_domains_reject_platforms_before_requests() {
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join("config.toml"),
+        "[global_settings]\nallowed_domains = [\"corp.example.com\"]\n\n[platforms.gitlab.work]\nname = \"work\"\nurl = \"https://gitlab.example.com\"\ntoken = \"s3cret\"\n\n[ui_preferences]\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.path().join("employees")).unwrap();
+    fs::write(
+        dir.path().join("employees/Alice.toml"),
+        "name = \"Alice\"\ntitle = \"Engineer\"\ncommitter_email = \"alice@example.com\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("config")
+        .arg("set")
+        .arg("enforce_allowed_domains")
+        .arg("true");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("enforce_allowed_domains set to: true"))
+        .stdout(predicate::str::contains(
+            "GitLab 'work' (https://gitlab.example.com): host 'gitlab.example.com' is not in allowed_domains",
+        ));
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("review")
+        .arg("Alice")
+        .arg("--explain");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("no request was sent"))
+        .stdout(predicate::str::contains("GET https://gitlab.example.com").not());
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("config")
+        .arg("validate");
+    cmd.assert().failure().stdout(predicate::str::contains(
+        "url in [platforms.gitlab.work]: host 'gitlab.example.com' is not in allowed_domains",
+    ));
+}
+
+#[test]
+fn test_doctor_reports_checks() {
+    let dir = tempdir().unwrap();
+    let employees_dir = dir.path().join("employees");
+    fs::create_dir_all(&employees_dir).unwrap();
+    fs::write(
+        employees_dir.join("Alice.toml"),
+        "name = \"Alice\"\ntitle = \"Engineer\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(10));
+    cmd.arg("--data-path").arg(dir.path()).arg("doctor");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No config file"))
+        .stdout(predicate::str::contains("✅ Alice.toml"))
+        .stdout(predicate::str::contains("jira: not configured"))
+        .stdout(predicate::str::contains("0 failed"));
+}
+
+#[test]
+fn test_no_color_prints_plain_text() {
+    let dir = tempdir().unwrap();
+    let employees_dir = dir.path().join("employees");
+    fs::create_dir_all(&employees_dir).unwrap();
+    fs::write(
+        employees_dir.join("Alice.toml"),
+        "name = \"Alice\"\ntitle = \"Engineer\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(10));
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .arg("doctor")
+        .arg("--no-color");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("[OK] Alice.toml"))
+        .stdout(predicate::str::contains("✅").not());
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(10));
+    cmd.env("NO_COLOR", "1");
+    cmd.arg("--data-path").arg(dir.path()).arg("doctor");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("[OK] Alice.toml"));
+}
+
+#[test]
+fn test_doctor_fails_on_invalid_employee_file() {
+    let dir = tempdir().unwrap();
+    let employees_dir = dir.path().join("employees");
+    fs::create_dir_all(&employees_dir).unwrap();
+    fs::write(
+        employees_dir.join("Bob.toml"),
+        "name = \"Bob\"\ntitle = [\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(10));
+    cmd.arg("--data-path").arg(dir.path()).arg("doctor");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("❌ Bob.toml"))
+        .stdout(predicate::str::contains("reviewr edit \"Bob\" --raw"))
+        .stdout(predicate::str::contains("1 failed"));
+}
+
+#[test]
+fn test_init_wizard() {
+    let dir = tempdir().unwrap();
+    let csv = dir.path().join("people.csv");
+    fs::write(&csv, "Name,Title\nAlice,Engineer\n").unwrap();
+    // Fail fast against the closed port below
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.arg("--data-path")
+        .arg(dir.path())
+        .args(["config", "set", "http_max_attempts", "1"]);
+    cmd.assert().success();
+
+    let answers = [
+        "3",
+        "http://127.0.0.1:9",
+        "",
+        "jdoe",
+        "token",
+        // Re-enter after the failed connection, keeping every answer, then keep them
+        "r",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "k",
+        "example.com",
+        "y",
+        "missing.csv",
+        csv.to_str().unwrap(),
+    ];
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(30));
+    cmd.arg("--data-path").arg(dir.path()).arg("init");
+    cmd.write_stdin(answers.join("\n") + "\n");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "🔌 Connecting to http://127.0.0.1:9",
+        ))
+        .stdout(predicate::str::contains(
+            "Left enforce_allowed_domains off.",
+        ))
+        .stdout(predicate::str::contains("Could not read missing.csv"))
+        .stdout(predicate::str::contains("✅ Created 1 employee(s)"))
+        .stdout(predicate::str::contains("reviewr is set up"));
+
+    let config = fs::read_to_string(dir.path().join("config.toml")).unwrap();
+    assert!(config.contains("[platforms.jira]"), "{config}");
+    assert!(
+        config.contains("jira_url = \"http://127.0.0.1:9\""),
+        "{config}"
+    );
+    assert!(config.contains("api_token = \"token\""), "{config}");
+    assert!(config.contains("\"example.com\""), "{config}");
+    assert!(dir.path().join("employees/Alice.toml").exists());
+
+    // Input that ends early cancels
+    let mut cmd = Command::cargo_bin("reviewr").unwrap();
+    cmd.timeout(Duration::from_secs(5));
+    cmd.arg("--data-path").arg(dir.path()).arg("init");
+    cmd.write_stdin("2\n");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Cancelled"));
+}